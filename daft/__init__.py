@@ -78,14 +78,21 @@ from daft.io import (
     DataCatalogTable,
     DataCatalogType,
     from_glob_path,
+    read_avro,
     read_csv,
     read_deltalake,
     read_hudi,
     read_iceberg,
+    read_ipc,
     read_json,
+    read_orc,
+    read_xlsx,
     read_parquet,
     read_sql,
     read_lance,
+    read_text,
+    read_fixed_width,
+    read_files,
 )
 from daft.series import Series
 from daft.sql.sql import sql, sql_expr
@@ -104,6 +111,13 @@ __all__ = [
     "from_glob_path",
     "read_csv",
     "read_json",
+    "read_ipc",
+    "read_avro",
+    "read_orc",
+    "read_xlsx",
+    "read_text",
+    "read_fixed_width",
+    "read_files",
     "read_parquet",
     "read_hudi",
     "read_iceberg",
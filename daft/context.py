@@ -310,6 +310,8 @@ def set_execution_config(
     shuffle_aggregation_default_partitions: int | None = None,
     read_sql_partition_size_bytes: int | None = None,
     enable_aqe: bool | None = None,
+    aqe_target_partition_size_bytes: int | None = None,
+    aqe_skewed_partition_factor: float | None = None,
     enable_native_executor: bool | None = None,
     default_morsel_size: int | None = None,
 ) -> DaftContext:
@@ -346,6 +348,11 @@ def set_execution_config(
         shuffle_aggregation_default_partitions: Minimum number of partitions to create when performing aggregations. Defaults to 200, unless the number of input partitions is less than 200.
         read_sql_partition_size_bytes: Target size of partition when reading from SQL databases. Defaults to 512MB
         enable_aqe: Enables Adaptive Query Execution, Defaults to False
+        aqe_target_partition_size_bytes: With Adaptive Query Execution, the target size of the partitions of a hash join
+            once both of its sides have been shuffled: smaller partitions are coalesced and skewed partitions are split
+            towards this size. Defaults to 128MB
+        aqe_skewed_partition_factor: With Adaptive Query Execution, a partition of a hash join is considered skewed if it
+            is this many times larger than the median partition (and larger than the target partition size). Defaults to 5.0
         enable_native_executor: Enables new local executor. Defaults to False
         default_morsel_size: Default size of morsels used for the new local executor. Defaults to 131072 rows.
     """
@@ -371,6 +378,8 @@ def set_execution_config(
             shuffle_aggregation_default_partitions=shuffle_aggregation_default_partitions,
             read_sql_partition_size_bytes=read_sql_partition_size_bytes,
             enable_aqe=enable_aqe,
+            aqe_target_partition_size_bytes=aqe_target_partition_size_bytes,
+            aqe_skewed_partition_factor=aqe_skewed_partition_factor,
             enable_native_executor=enable_native_executor,
             default_morsel_size=default_morsel_size,
         )
@@ -193,6 +193,13 @@ class FileFormat(Enum):
     Parquet: int
     Csv: int
     Json: int
+    Ipc: int
+    Avro: int
+    Orc: int
+    Xlsx: int
+    Text: int
+    FixedWidth: int
+    Files: int
 
     def ext(self): ...
 
@@ -256,6 +263,88 @@ class JsonSourceConfig:
         chunk_size: int | None = None,
     ): ...
 
+class IpcSourceConfig:
+    """
+    Configuration of an Arrow IPC (Feather V2) data source.
+    """
+
+    record_batches: list[list[int] | None] | None
+
+    def __init__(
+        self,
+        record_batches: list[list[int] | None] | None = None,
+    ): ...
+
+class AvroSourceConfig:
+    """
+    Configuration of an Avro data source.
+    """
+
+    def __init__(self): ...
+
+class OrcSourceConfig:
+    """
+    Configuration of an ORC data source.
+    """
+
+    stripes: list[list[int] | None] | None
+
+    def __init__(
+        self,
+        stripes: list[list[int] | None] | None = None,
+    ): ...
+
+class XlsxSourceConfig:
+    """
+    Configuration of an XLSX data source.
+    """
+
+    sheet_name: str | None
+    sheet_index: int | None
+    header_row: int | None
+
+    def __init__(
+        self,
+        sheet_name: str | None = None,
+        sheet_index: int | None = None,
+        header_row: int | None = 0,
+    ): ...
+
+class TextSourceConfig:
+    """
+    Configuration of a text data source.
+    """
+
+    skip_blank_lines: bool
+    path_column: str | None
+
+    def __init__(
+        self,
+        skip_blank_lines: bool = False,
+        path_column: str | None = None,
+    ): ...
+
+class FixedWidthSourceConfig:
+    """
+    Configuration of a fixed-width text data source.
+    """
+
+    columns: list[tuple[str, int, int, PyDataType]]
+    skip_rows: int
+
+    def __init__(
+        self,
+        columns: list[tuple[str, int, int, PyDataType]],
+        skip_rows: int = 0,
+    ): ...
+
+class FilesSourceConfig:
+    """
+    Configuration of a data source of whole files.
+    """
+
+    def __init__(self): ...
+
 class DatabaseSourceConfig:
     """
     Configuration of a database data source.
@@ -271,7 +360,7 @@ class FileFormatConfig:
     Configuration for parsing a particular file format (Parquet, CSV, JSON).
     """
 
-    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | DatabaseSourceConfig
+    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | IpcSourceConfig | AvroSourceConfig | OrcSourceConfig | XlsxSourceConfig | TextSourceConfig | FixedWidthSourceConfig | FilesSourceConfig | DatabaseSourceConfig
 
     @staticmethod
     def from_parquet_config(config: ParquetSourceConfig) -> FileFormatConfig:
@@ -294,6 +383,55 @@ class FileFormatConfig:
         """
         ...
 
+    @staticmethod
+    def from_ipc_config(config: IpcSourceConfig) -> FileFormatConfig:
+        """
+        Create an Arrow IPC file format config.
+        """
+        ...
+
+    @staticmethod
+    def from_avro_config(config: AvroSourceConfig) -> FileFormatConfig:
+        """
+        Create an Avro file format config.
+        """
+        ...
+
+    @staticmethod
+    def from_orc_config(config: OrcSourceConfig) -> FileFormatConfig:
+        """
+        Create an ORC file format config.
+        """
+        ...
+
+    @staticmethod
+    def from_xlsx_config(config: XlsxSourceConfig) -> FileFormatConfig:
+        """
+        Create an XLSX file format config.
+        """
+        ...
+
+    @staticmethod
+    def from_text_config(config: TextSourceConfig) -> FileFormatConfig:
+        """
+        Create a text file format config.
+        """
+        ...
+
+    @staticmethod
+    def from_fixed_width_config(config: FixedWidthSourceConfig) -> FileFormatConfig:
+        """
+        Create a fixed-width text file format config.
+        """
+        ...
+
+    @staticmethod
+    def from_files_config(config: FilesSourceConfig) -> FileFormatConfig:
+        """
+        Create a whole-file format config.
+        """
+        ...
+
     @staticmethod
     def from_database_config(config: DatabaseSourceConfig) -> FileFormatConfig:
         """
@@ -450,8 +588,48 @@ class HTTPConfig:
     """
 
     bearer_token: str | None
+    max_connections: int
+    retry_initial_backoff_ms: int
+    connect_timeout_ms: int
+    read_timeout_ms: int
+    num_tries: int
 
-    def __init__(self, bearer_token: str | None = None): ...
+    def __init__(
+        self,
+        bearer_token: str | None = None,
+        max_connections: int | None = None,
+        retry_initial_backoff_ms: int | None = None,
+        connect_timeout_ms: int | None = None,
+        read_timeout_ms: int | None = None,
+        num_tries: int | None = None,
+    ): ...
+
+class CacheConfig:
+    """
+    I/O configuration for the local on-disk cache of data read from remote storage.
+    """
+
+    enabled: bool
+    directory: str | None
+    max_size_bytes: int
+    metadata_ttl_seconds: int
+
+    def __init__(
+        self,
+        enabled: bool | None = None,
+        directory: str | None = None,
+        max_size_bytes: int | None = None,
+        metadata_ttl_seconds: int | None = None,
+    ): ...
+    def replace(
+        self,
+        enabled: bool | None = None,
+        directory: str | None = None,
+        max_size_bytes: int | None = None,
+        metadata_ttl_seconds: int | None = None,
+    ) -> CacheConfig:
+        """Replaces values if provided, returning a new CacheConfig"""
+        ...
 
 class S3Config:
     """
@@ -477,6 +655,8 @@ class S3Config:
     requester_pays: bool | None
     force_virtual_addressing: bool | None
     profile_name: str | None
+    verify_checksums: bool | None
+    max_requests_per_second: int | None
 
     def __init__(
         self,
@@ -500,6 +680,8 @@ class S3Config:
         requester_pays: bool | None = None,
         force_virtual_addressing: bool | None = None,
         profile_name: str | None = None,
+        verify_checksums: bool | None = None,
+        max_requests_per_second: int | None = None,
     ): ...
     def replace(
         self,
@@ -522,6 +704,8 @@ class S3Config:
         requester_pays: bool | None = None,
         force_virtual_addressing: bool | None = None,
         profile_name: str | None = None,
+        verify_checksums: bool | None = None,
+        max_requests_per_second: int | None = None,
     ) -> S3Config:
         """Replaces values if provided, returning a new S3Config"""
         ...
@@ -561,6 +745,13 @@ class AzureConfig:
     anonymous: bool | None
     endpoint_url: str | None = None
     use_ssl: bool | None = None
+    verify_checksums: bool | None = None
+    max_requests_per_second: int | None = None
+    max_connections: int
+    retry_initial_backoff_ms: int
+    connect_timeout_ms: int
+    read_timeout_ms: int
+    num_tries: int
 
     def __init__(
         self,
@@ -575,6 +766,13 @@ class AzureConfig:
         anonymous: bool | None = None,
         endpoint_url: str | None = None,
         use_ssl: bool | None = None,
+        verify_checksums: bool | None = None,
+        max_requests_per_second: int | None = None,
+        max_connections: int | None = None,
+        retry_initial_backoff_ms: int | None = None,
+        connect_timeout_ms: int | None = None,
+        read_timeout_ms: int | None = None,
+        num_tries: int | None = None,
     ): ...
     def replace(
         self,
@@ -589,6 +787,13 @@ class AzureConfig:
         anonymous: bool | None = None,
         endpoint_url: str | None = None,
         use_ssl: bool | None = None,
+        verify_checksums: bool | None = None,
+        max_requests_per_second: int | None = None,
+        max_connections: int | None = None,
+        retry_initial_backoff_ms: int | None = None,
+        connect_timeout_ms: int | None = None,
+        read_timeout_ms: int | None = None,
+        num_tries: int | None = None,
     ) -> AzureConfig:
         """Replaces values if provided, returning a new AzureConfig"""
         ...
@@ -602,6 +807,13 @@ class GCSConfig:
     credentials: str | None
     token: str | None
     anonymous: bool
+    verify_checksums: bool
+    max_requests_per_second: int | None
+    max_connections: int
+    retry_initial_backoff_ms: int
+    connect_timeout_ms: int
+    read_timeout_ms: int
+    num_tries: int
 
     def __init__(
         self,
@@ -609,6 +821,13 @@ class GCSConfig:
         credentials: str | None = None,
         token: str | None = None,
         anonymous: bool | None = None,
+        verify_checksums: bool | None = None,
+        max_requests_per_second: int | None = None,
+        max_connections: int | None = None,
+        retry_initial_backoff_ms: int | None = None,
+        connect_timeout_ms: int | None = None,
+        read_timeout_ms: int | None = None,
+        num_tries: int | None = None,
     ): ...
     def replace(
         self,
@@ -616,6 +835,13 @@ class GCSConfig:
         credentials: str | None = None,
         token: str | None = None,
         anonymous: bool | None = None,
+        verify_checksums: bool | None = None,
+        max_requests_per_second: int | None = None,
+        max_connections: int | None = None,
+        retry_initial_backoff_ms: int | None = None,
+        connect_timeout_ms: int | None = None,
+        read_timeout_ms: int | None = None,
+        num_tries: int | None = None,
     ) -> GCSConfig:
         """Replaces values if provided, returning a new GCSConfig"""
         ...
@@ -629,6 +855,7 @@ class IOConfig:
     azure: AzureConfig
     gcs: GCSConfig
     http: HTTPConfig
+    cache: CacheConfig
 
     def __init__(
         self,
@@ -636,6 +863,7 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        cache: CacheConfig | None = None,
     ): ...
     @staticmethod
     def from_json(input: str) -> IOConfig:
@@ -650,6 +878,7 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        cache: CacheConfig | None = None,
     ) -> IOConfig:
         """Replaces values if provided, returning a new IOConfig"""
         ...
@@ -783,6 +1012,22 @@ class ScanOperatorHandle:
         storage_config: StorageConfig,
         infer_schema: bool,
         schema: PySchema | None = None,
+        hive_partitioning: bool = False,
+        union_by_name: bool = False,
+        max_schema_inference_files: int | None = None,
+    ) -> ScanOperatorHandle: ...
+    @staticmethod
+    def iceberg_scan(
+        table_location: str,
+        storage_config: StorageConfig,
+        snapshot_id: int | None = None,
+    ) -> ScanOperatorHandle: ...
+    @staticmethod
+    def deltalake_scan(
+        table_uri: str,
+        storage_config: StorageConfig,
+        version: int | None = None,
+        timestamp_ms: int | None = None,
     ) -> ScanOperatorHandle: ...
     @staticmethod
     def from_python_scan_operator(operator: ScanOperator) -> ScanOperatorHandle: ...
@@ -920,6 +1165,73 @@ def read_json_schema(
     io_config: IOConfig | None = None,
     multithreaded_io: bool | None = None,
 ): ...
+def read_ipc(
+    uri: str,
+    columns: list[str] | None = None,
+    num_rows: int | None = None,
+    record_batches: list[int] | None = None,
+    predicate: PyExpr | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_ipc_schema(
+    uri: str,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def write_ipc(
+    tables: list[PyTable],
+    uri: str,
+    compression: str | None = None,
+    io_config: IOConfig | None = None,
+): ...
+def read_avro(
+    uri: str,
+    columns: list[str] | None = None,
+    num_rows: int | None = None,
+    byte_range: tuple[int, int] | None = None,
+    predicate: PyExpr | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_avro_schema(
+    uri: str,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_orc(
+    uri: str,
+    columns: list[str] | None = None,
+    num_rows: int | None = None,
+    stripes: list[int] | None = None,
+    predicate: PyExpr | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_orc_schema(
+    uri: str,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_xlsx(
+    uri: str,
+    columns: list[str] | None = None,
+    num_rows: int | None = None,
+    sheet_name: str | None = None,
+    sheet_index: int | None = None,
+    header_row: int | None = 0,
+    predicate: PyExpr | None = None,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
+def read_xlsx_schema(
+    uri: str,
+    sheet_name: str | None = None,
+    sheet_index: int | None = None,
+    header_row: int | None = 0,
+    io_config: IOConfig | None = None,
+    multithreaded_io: bool | None = None,
+): ...
 
 class PyTimeUnit:
     @staticmethod
@@ -1611,6 +1923,7 @@ class AdaptivePhysicalPlanScheduler:
         num_partitions: int,
         size_bytes: int,
         num_rows: int,
+        partition_size_bytes: list[int] | None,
     ) -> None: ...
 
 class LogicalPlanBuilder:
@@ -1713,6 +2026,18 @@ class LogicalPlanBuilder:
         kwargs: dict[str, Any] | None = None,
     ) -> LogicalPlanBuilder: ...
     def schema(self) -> PySchema: ...
+    def cache(
+        self,
+        partition_key: str,
+        cache_entry: PartitionCacheEntry,
+        num_partitions: int,
+        size_bytes: int,
+        num_rows: int,
+    ) -> LogicalPlanBuilder: ...
+    @staticmethod
+    def set_result_cache_capacity_bytes(capacity_bytes: int) -> None: ...
+    @staticmethod
+    def clear_result_cache() -> None: ...
     def optimize(self) -> LogicalPlanBuilder: ...
     def to_physical_plan_scheduler(self, cfg: PyDaftExecutionConfig) -> PhysicalPlanScheduler: ...
     def to_adaptive_physical_plan_scheduler(self, cfg: PyDaftExecutionConfig) -> AdaptivePhysicalPlanScheduler: ...
@@ -1749,6 +2074,8 @@ class PyDaftExecutionConfig:
         shuffle_aggregation_default_partitions: int | None = None,
         read_sql_partition_size_bytes: int | None = None,
         enable_aqe: bool | None = None,
+        aqe_target_partition_size_bytes: int | None = None,
+        aqe_skewed_partition_factor: float | None = None,
         enable_native_executor: bool | None = None,
         default_morsel_size: int | None = None,
     ) -> PyDaftExecutionConfig: ...
@@ -1783,6 +2110,10 @@ class PyDaftExecutionConfig:
     @property
     def enable_aqe(self) -> bool: ...
     @property
+    def aqe_target_partition_size_bytes(self) -> int: ...
+    @property
+    def aqe_skewed_partition_factor(self) -> float: ...
+    @property
     def enable_native_executor(self) -> bool: ...
     @property
     def default_morsel_size(self) -> int: ...
@@ -1813,6 +2144,12 @@ def io_glob(
     page_size: int | None = None,
     limit: int | None = None,
 ) -> list[dict]: ...
+def io_head(
+    path: str,
+    multithreaded_io: bool | None = None,
+    io_config: IOConfig | None = None,
+) -> dict: ...
+def register_object_source(scheme: str, source: Any) -> None: ...
 
 class SystemInfo:
     """
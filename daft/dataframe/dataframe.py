@@ -618,6 +618,69 @@ class DataFrame:
                 }
             )
 
+    @DataframePublicAPI
+    def write_ipc(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        compression: Optional[str] = None,
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as Arrow IPC files, returning a new DataFrame with paths to the files that were written
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names. Each partition is
+        streamed to its file as it's serialized, using multipart uploads for object stores.
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        Args:
+            root_dir (str): root file path to write Arrow IPC files to.
+            compression (str, optional): compression of the record batches, one of "lz4" or "zstd". Defaults to None.
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.Ipc,
+            compression=compression,
+            io_config=io_config,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        if len(write_df) > 0:
+            # Populate and return a new disconnected DataFrame
+            result_df = DataFrame(write_df._builder)
+            result_df._result_cache = write_df._result_cache
+            result_df._preview = write_df._preview
+            return result_df
+        else:
+            from daft import from_pydict
+            from daft.table.table_io import write_empty_tabular
+
+            file_path = write_empty_tabular(
+                root_dir, FileFormat.Ipc, self.schema(), compression=compression, io_config=io_config
+            )
+
+            return from_pydict(
+                {
+                    "path": [file_path],
+                }
+            )
+
     @DataframePublicAPI
     def write_iceberg(self, table: "pyiceberg.table.Table", mode: str = "append") -> "DataFrame":
         """Writes the DataFrame to an `Iceberg <https://iceberg.apache.org/docs/nightly/>`__ table, returning a new DataFrame with the operations that occurred.
@@ -2353,6 +2416,40 @@ class DataFrame:
             self._num_preview_rows = dataframe_len
         return self
 
+    @DataframePublicAPI
+    def cache(self) -> "DataFrame":
+        """Executes the entire DataFrame and caches its results, so that other DataFrames which recompute the same
+        plan (e.g. when re-running a notebook cell) reuse these results instead of recomputing them.
+
+        Cached results are evicted least-recently-used first once the cache grows past its capacity. Plans that read
+        files are only matched if the files they read still have the same paths, sizes, ETags and last-modified times,
+        and plans over files that report neither an ETag nor a last-modified time aren't cached.
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        Returns:
+            DataFrame: DataFrame with materialized results.
+        """
+        self._materialize_results()
+
+        result_cache = self._result_cache
+        assert result_cache is not None
+        num_partitions = result_cache.num_partitions()
+        size_bytes = result_cache.size_bytes()
+        num_rows = result_cache.num_rows()
+        assert (
+            num_partitions is not None and size_bytes is not None and num_rows is not None
+        ), "Partition set should always be set on cache entry"
+
+        self._get_current_builder().cache(
+            result_cache,
+            num_partitions=num_partitions,
+            size_bytes=size_bytes,
+            num_rows=num_rows,
+        )
+        return self
+
     def _construct_show_display(self, n: int) -> "DataFrameDisplay":
         """Helper for .show() which will construct the underlying DataFrameDisplay object"""
         preview_partition = self._preview.preview_partition
@@ -1366,6 +1366,56 @@ def split(
             )
 
 
+def skew_split(
+    child_plan: InProgressPhysicalPlan[PartitionT],
+    num_outputs: list[int],
+    replicate: list[bool],
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Split skewed partitions of one side of a partition-wise join into multiple partitions.
+
+    Partitions flagged in `replicate` are duplicated into each of their outputs instead, so that they stay aligned
+    with the split partitions of the other side of the join.
+    """
+
+    assert len(num_outputs) == len(replicate), f"Mismatched skew split spec: {num_outputs} vs. {replicate}."
+
+    materializations: deque[SingleOutputPartitionTask[PartitionT]] = deque()
+    stage_id = next(stage_id_counter)
+    for step in child_plan:
+        if isinstance(step, PartitionTaskBuilder):
+            step = step.finalize_partition_task_single_output(stage_id=stage_id)
+            materializations.append(step)
+        yield step
+
+    while any(not _.done() for _ in materializations):
+        logger.debug("skew_split blocked on completion of all sources: %s", materializations)
+        yield None
+
+    assert len(materializations) == len(
+        num_outputs
+    ), f"Expected {len(num_outputs)} partitions to skew split, but got {len(materializations)}."
+
+    for task, num_out, should_replicate in zip(consume_deque(materializations), num_outputs, replicate):
+        if num_out == 1 or should_replicate:
+            for _ in range(num_out):
+                yield PartitionTaskBuilder[PartitionT](
+                    inputs=[task.partition()],
+                    partial_metadatas=[task.partition_metadata()],
+                    resource_request=ResourceRequest(memory_bytes=task.partition_metadata().size_bytes),
+                )
+        else:
+            num_rows = task.partition_metadata().num_rows
+            boundaries = [math.ceil(num_rows * i / num_out) for i in range(num_out + 1)]
+            starts, ends = boundaries[:-1], boundaries[1:]
+            yield PartitionTaskBuilder[PartitionT](
+                inputs=[task.partition()],
+                partial_metadatas=[task.partition_metadata()],
+                resource_request=ResourceRequest(memory_bytes=task.partition_metadata().size_bytes),
+            ).add_instruction(
+                instruction=execution_step.FanoutSlices(_num_outputs=num_out, slices=list(zip(starts, ends)))
+            )
+
+
 def coalesce(
     child_plan: InProgressPhysicalPlan[PartitionT],
     from_num_partitions: int,
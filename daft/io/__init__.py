@@ -4,22 +4,29 @@ import sys
 
 from daft.daft import (
     AzureConfig,
+    CacheConfig,
     GCSConfig,
     IOConfig,
     HTTPConfig,
     S3Config,
     S3Credentials,
 )
+from daft.io._avro import read_avro
 from daft.io._csv import read_csv
 from daft.io._delta_lake import read_deltalake
 from daft.io._hudi import read_hudi
 from daft.io._iceberg import read_iceberg
+from daft.io._ipc import read_ipc
 from daft.io._json import read_json
 from daft.io._lance import read_lance
+from daft.io._orc import read_orc
 from daft.io._parquet import read_parquet
 from daft.io._sql import read_sql
+from daft.io._text import read_files, read_fixed_width, read_text
+from daft.io._xlsx import read_xlsx
 from daft.io.catalog import DataCatalogTable, DataCatalogType
 from daft.io.file_path import from_glob_path
+from daft.io.object_source import ObjectInfo, ObjectSource, register_object_source
 
 
 def _set_linux_cert_paths():
@@ -39,6 +46,13 @@ if sys.platform == "linux":
 __all__ = [
     "read_csv",
     "read_json",
+    "read_ipc",
+    "read_avro",
+    "read_orc",
+    "read_xlsx",
+    "read_text",
+    "read_fixed_width",
+    "read_files",
     "from_glob_path",
     "read_parquet",
     "read_hudi",
@@ -52,6 +66,10 @@ __all__ = [
     "AzureConfig",
     "GCSConfig",
     "HTTPConfig",
+    "CacheConfig",
     "DataCatalogType",
     "DataCatalogTable",
+    "ObjectSource",
+    "ObjectInfo",
+    "register_object_source",
 ]
@@ -0,0 +1,83 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    AvroSourceConfig,
+    FileFormatConfig,
+    IOConfig,
+    NativeStorageConfig,
+    StorageConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_avro(
+    path: Union[str, List[str]],
+    infer_schema: bool = True,
+    schema: Optional[Dict[str, DataType]] = None,
+    io_config: Optional["IOConfig"] = None,
+    hive_partitioning: bool = False,
+    union_by_name: bool = False,
+    max_schema_inference_files: Optional[int] = 100,
+    _multithreaded_io: Optional[bool] = None,
+) -> DataFrame:
+    """Creates a DataFrame from Avro object container file(s)
+
+    Avro records are read as structs, arrays as lists and maps as maps with string keys. The date, time, timestamp and
+    decimal logical types are read as their corresponding Daft types. Files compressed with any of the codecs of the
+    Avro specification (deflate, snappy, bzip2, xz and zstandard) can be read.
+
+    Example:
+        >>> df = daft.read_avro("/path/to/file.avro")
+        >>> df = daft.read_avro("/path/to/directory")
+        >>> df = daft.read_avro("/path/to/files-*.avro")
+        >>> df = daft.read_avro("s3://path/to/files-*.avro")
+
+    Args:
+        path (str): Path to Avro files (allows for wildcards)
+        infer_schema (bool): Whether to infer the schema of the Avro files, defaults to True.
+        schema (dict[str, DataType]): A schema that is used as the definitive schema for the files if infer_schema is False, otherwise it is used as a schema hint that is applied after the schema is inferred.
+        io_config (IOConfig): Config to be used with the native downloader
+        hive_partitioning (bool): Whether to infer partition columns from Hive-style `key=value` directories in the file paths, defaults to False.
+        union_by_name (bool): Whether to infer the schema by merging the schemas of all files by column name rather than from the first file only, defaults to False. Columns that are missing from a file are filled with nulls, and columns whose types differ across files are promoted to a common supertype.
+        max_schema_inference_files (int): Maximum number of files to infer schemas from when `union_by_name` is True, and partition columns from when `hive_partitioning` is True, defaults to 100. Set to None to infer from all files. Reading files with partition keys or values that the inferred partition columns can't hold fails.
+        _multithreaded_io (bool): Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
+            the amount of system resources (number of connections and thread contention) when running in the Ray runner.
+            Defaults to None, which will let Daft decide based on the runner it is currently using.
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of Avro filepaths")
+
+    if not infer_schema and schema is None:
+        raise ValueError(
+            "Cannot read DataFrame with infer_schema=False and schema=None, please provide a schema or set infer_schema=True"
+        )
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    # If running on Ray, we want to limit the amount of concurrency and requests being made.
+    # This is because each Ray worker process receives its own pool of thread workers and connections
+    multithreaded_io = not context.get_context().is_ray_runner if _multithreaded_io is None else _multithreaded_io
+
+    file_format_config = FileFormatConfig.from_avro_config(AvroSourceConfig())
+    storage_config = StorageConfig.native(NativeStorageConfig(multithreaded_io, io_config))
+    builder = get_tabular_files_scan(
+        path=path,
+        infer_schema=infer_schema,
+        schema=schema,
+        file_format_config=file_format_config,
+        storage_config=storage_config,
+        hive_partitioning=hive_partitioning,
+        union_by_name=union_by_name,
+        max_schema_inference_files=max_schema_inference_files,
+    )
+    return DataFrame(builder)
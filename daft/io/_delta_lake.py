@@ -1,10 +1,12 @@
 # isort: dont-add-import: from __future__ import annotations
 
+from datetime import datetime, timezone
 from typing import Optional, Union
 
+import daft.exceptions
 from daft import context
 from daft.api_annotations import PublicAPI
-from daft.daft import IOConfig, NativeStorageConfig, ScanOperatorHandle, StorageConfig
+from daft.daft import IOConfig, NativeStorageConfig, S3Config, ScanOperatorHandle, StorageConfig
 from daft.dataframe import DataFrame
 from daft.io.catalog import DataCatalogTable
 from daft.logical.builder import LogicalPlanBuilder
@@ -16,10 +18,38 @@ except ImportError:
     _UNITY_CATALOG_AVAILABLE = False
 
 
+def _with_s3_credentials_from_env(io_config: IOConfig) -> IOConfig:
+    """Fills in the S3 credentials and region of an IOConfig that doesn't have them from the environment, using Daft's
+    credentials chain.
+
+    The credentials are resolved once when the table is read, so that its log and all of its data files are read with
+    the same credentials and region, as they were by the Python Delta Lake reader.
+    """
+    s3_config = io_config.s3
+    if s3_config.key_id is not None and s3_config.region_name is not None:
+        return io_config
+    try:
+        s3_config_from_env = S3Config.from_env()
+    # Sometimes S3Config.from_env throws an error, for example on CI machines with weird metadata servers.
+    except daft.exceptions.DaftCoreException:
+        return io_config
+    if s3_config.key_id is None and s3_config.access_key is None and s3_config.session_token is None:
+        s3_config = s3_config.replace(
+            key_id=s3_config_from_env.key_id,
+            access_key=s3_config_from_env.access_key,
+            session_token=s3_config_from_env.session_token,
+        )
+    if s3_config.region_name is None:
+        s3_config = s3_config.replace(region_name=s3_config_from_env.region_name)
+    return io_config.replace(s3=s3_config)
+
+
 @PublicAPI
 def read_deltalake(
     table: Union[str, DataCatalogTable, "UnityCatalogTable"],
     io_config: Optional["IOConfig"] = None,
+    version: Optional[int] = None,
+    timestamp: Optional[Union[str, datetime]] = None,
     _multithreaded_io: Optional[bool] = None,
 ) -> DataFrame:
     """Create a DataFrame from a Delta Lake table.
@@ -31,15 +61,22 @@ def read_deltalake(
         >>> # the read operation from Delta Lake.
         >>> df = df.where(df["foo"] > 5)
         >>> df.show()
+        >>>
+        >>> # Past versions of the table can be read by version or by timestamp
+        >>> df = daft.read_deltalake("some-table-uri", version=3)
+        >>> df = daft.read_deltalake("some-table-uri", timestamp="2024-01-01T00:00:00Z")
 
     .. NOTE::
-        This function requires the use of `deltalake <https://delta-io.github.io/delta-rs/>`_, a Python library for
-        interacting with Delta Lake.
+        Tables are read natively, by replaying their transaction logs of JSON commits and Parquet checkpoints.
+        Deletion vectors and column mapping are supported.
 
     Args:
         table: Either a URI for the Delta Lake table or a :class:`~daft.io.catalog.DataCatalogTable` instance
             referencing a table in a data catalog, such as AWS Glue Data Catalog or Databricks Unity Catalog.
         io_config: A custom :class:`~daft.daft.IOConfig` to use when accessing Delta Lake object storage data. Defaults to None.
+        version: Version of the table to read. Defaults to None, which reads the latest version.
+        timestamp: Reads the latest version of the table that was committed at or before this timestamp, which is
+            either a datetime or an ISO 8601 string. Timestamps without time zones are in UTC. Defaults to None.
         _multithreaded_io: Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
             the amount of system resources (number of connections and thread contention) when running in the Ray runner.
             Defaults to None, which will let Daft decide based on the runner it is currently using.
@@ -47,13 +84,22 @@ def read_deltalake(
     Returns:
         DataFrame: A DataFrame with the schema converted from the specified Delta Lake table.
     """
-    from daft.delta_lake.delta_lake_scan import DeltaLakeScanOperator
+    if version is not None and timestamp is not None:
+        raise ValueError("Only one of version and timestamp can be given to read a Delta Lake table")
+    timestamp_ms = None
+    if timestamp is not None:
+        if isinstance(timestamp, str):
+            timestamp = datetime.fromisoformat(timestamp.replace("Z", "+00:00"))
+        if timestamp.tzinfo is None:
+            timestamp = timestamp.replace(tzinfo=timezone.utc)
+        timestamp_ms = int(timestamp.timestamp() * 1000)
 
     # If running on Ray, we want to limit the amount of concurrency and requests being made.
     # This is because each Ray worker process receives its own pool of thread workers and connections
     multithreaded_io = not context.get_context().is_ray_runner if _multithreaded_io is None else _multithreaded_io
 
     io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+    io_config = _with_s3_credentials_from_env(io_config)
     storage_config = StorageConfig.native(NativeStorageConfig(multithreaded_io, io_config))
 
     if isinstance(table, str):
@@ -71,8 +117,7 @@ def read_deltalake(
         raise ValueError(
             f"table argument must be a table URI string, DataCatalogTable or UnityCatalogTable instance, but got: {type(table)}, {table}"
         )
-    delta_lake_operator = DeltaLakeScanOperator(table_uri, storage_config=storage_config)
 
-    handle = ScanOperatorHandle.from_python_scan_operator(delta_lake_operator)
+    handle = ScanOperatorHandle.deltalake_scan(table_uri, storage_config, version=version, timestamp_ms=timestamp_ms)
     builder = LogicalPlanBuilder.from_tabular_scan(scan_operator=handle)
     return DataFrame(builder)
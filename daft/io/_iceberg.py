@@ -1,6 +1,6 @@
 # isort: dont-add-import: from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any, Dict, Optional
+from typing import TYPE_CHECKING, Any, Dict, Optional, Union
 
 from daft import context
 from daft.api_annotations import PublicAPI
@@ -84,7 +84,7 @@ def _convert_iceberg_file_io_properties_to_io_config(props: Dict[str, Any]) -> O
 
 @PublicAPI
 def read_iceberg(
-    pyiceberg_table: "PyIcebergTable",
+    pyiceberg_table: Union[str, "PyIcebergTable"],
     snapshot_id: Optional[int] = None,
     io_config: Optional["IOConfig"] = None,
 ) -> DataFrame:
@@ -100,25 +100,38 @@ def read_iceberg(
         >>> # the read operation from Iceberg
         >>> df = df.where(df["foo"] > 5)
         >>> df.show()
+        >>>
+        >>> # Tables can also be read from their location, without a catalog
+        >>> df = daft.read_iceberg("s3://bucket/warehouse/db/table")
 
     .. NOTE::
-        This function requires the use of `PyIceberg <https://py.iceberg.apache.org/>`_, which is the Apache Iceberg's
-        official project for Python.
+        Reading a table object requires the use of `PyIceberg <https://py.iceberg.apache.org/>`_, which is the Apache
+        Iceberg's official project for Python. Tables that are given by their location are read natively, by reading
+        their metadata, manifest lists and manifests directly.
 
     Args:
-        pyiceberg_table: Iceberg table created using the PyIceberg library
+        pyiceberg_table: Iceberg table created using the PyIceberg library, or the location of an Iceberg table, which is either
+            the root directory of the table or one of its ``*.metadata.json`` files. The current metadata file of a
+            root directory is found from its ``metadata/version-hint.text`` file, or is otherwise the metadata file of
+            the highest version.
         snapshot_id: Snapshot ID of the table to query
         io_config: A custom IOConfig to use when accessing Iceberg object storage data. Defaults to None.
 
     Returns:
         DataFrame: a DataFrame with the schema converted from the specified Iceberg table
     """
+    if isinstance(pyiceberg_table, str):
+        io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+        multithreaded_io = not context.get_context().is_ray_runner
+        storage_config = StorageConfig.native(NativeStorageConfig(multithreaded_io, io_config))
+        handle = ScanOperatorHandle.iceberg_scan(pyiceberg_table, storage_config, snapshot_id=snapshot_id)
+        builder = LogicalPlanBuilder.from_tabular_scan(scan_operator=handle)
+        return DataFrame(builder)
+
     from daft.iceberg.iceberg_scan import IcebergScanOperator
 
     io_config = (
-        _convert_iceberg_file_io_properties_to_io_config(pyiceberg_table.io.properties)
-        if io_config is None
-        else io_config
+        _convert_iceberg_file_io_properties_to_io_config(pyiceberg_table.io.properties) if io_config is None else io_config
     )
     io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
 
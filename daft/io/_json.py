@@ -24,6 +24,9 @@ def read_json(
     schema: Optional[Dict[str, DataType]] = None,
     io_config: Optional["IOConfig"] = None,
     use_native_downloader: bool = True,
+    hive_partitioning: bool = False,
+    union_by_name: bool = False,
+    max_schema_inference_files: Optional[int] = 100,
     schema_hints: Optional[Dict[str, DataType]] = None,
     _buffer_size: Optional[int] = None,
     _chunk_size: Optional[int] = None,
@@ -43,6 +46,9 @@ def read_json(
         io_config (IOConfig): Config to be used with the native downloader
         use_native_downloader: Whether to use the native downloader instead of PyArrow for reading Parquet. This
             is currently experimental.
+        hive_partitioning (bool): Whether to infer partition columns from Hive-style `key=value` directories in the file paths, defaults to False.
+        union_by_name (bool): Whether to infer the schema by merging the schemas of all files by column name rather than from the first file only, defaults to False. Columns that are missing from a file are filled with nulls, and columns whose types differ across files are promoted to a common supertype.
+        max_schema_inference_files (int): Maximum number of files to infer schemas from when `union_by_name` is True, and partition columns from when `hive_partitioning` is True, defaults to 100. Set to None to infer from all files. Reading files with partition keys or values that the inferred partition columns can't hold fails.
 
     returns:
         DataFrame: parsed DataFrame
@@ -74,5 +80,8 @@ def read_json(
         schema=schema,
         file_format_config=file_format_config,
         storage_config=storage_config,
+        hive_partitioning=hive_partitioning,
+        union_by_name=union_by_name,
+        max_schema_inference_files=max_schema_inference_files,
     )
     return DataFrame(builder)
@@ -0,0 +1,170 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import List, Optional, Tuple, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    FileFormatConfig,
+    FilesSourceConfig,
+    FixedWidthSourceConfig,
+    IOConfig,
+    NativeStorageConfig,
+    StorageConfig,
+    TextSourceConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_text(
+    path: Union[str, List[str]],
+    skip_blank_lines: bool = False,
+    path_column: Optional[str] = None,
+    io_config: Optional["IOConfig"] = None,
+    hive_partitioning: bool = False,
+    _multithreaded_io: Optional[bool] = None,
+) -> DataFrame:
+    """Creates a DataFrame from text file(s), with a row per line in a ``text`` column
+
+    Lines are split on ``\\n`` or ``\\r\\n``, and the line terminators aren't included in the rows. Files compressed
+    with a codec that can be inferred from their extension, such as ``.gz`` or ``.zst``, are decompressed on read.
+
+    Example:
+        >>> df = daft.read_text("/path/to/file.txt")
+        >>> df = daft.read_text("/path/to/directory")
+        >>> df = daft.read_text("/path/to/files-*.txt", skip_blank_lines=True)
+        >>> df = daft.read_text("s3://path/to/files-*.log.gz", path_column="path")
+
+    Args:
+        path (str): Path to text files (allows for wildcards)
+        skip_blank_lines (bool): Whether to skip lines that are empty or only hold whitespace, defaults to False.
+        path_column (str): Name of a column to add with the path of the file that each line was read from, defaults to None.
+        io_config (IOConfig): Config to be used with the native downloader
+        hive_partitioning (bool): Whether to infer partition columns from Hive-style `key=value` directories in the file paths, defaults to False.
+        _multithreaded_io (bool): Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
+            the amount of system resources (number of connections and thread contention) when running in the Ray runner.
+            Defaults to None, which will let Daft decide based on the runner it is currently using.
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of text filepaths")
+
+    file_format_config = FileFormatConfig.from_text_config(
+        TextSourceConfig(skip_blank_lines=skip_blank_lines, path_column=path_column)
+    )
+    return _read_with_fixed_schema(path, file_format_config, io_config, hive_partitioning, _multithreaded_io)
+
+
+@PublicAPI
+def read_fixed_width(
+    path: Union[str, List[str]],
+    columns: List[Tuple[str, int, int, DataType]],
+    skip_rows: int = 0,
+    io_config: Optional["IOConfig"] = None,
+    hive_partitioning: bool = False,
+    _multithreaded_io: Optional[bool] = None,
+) -> DataFrame:
+    """Creates a DataFrame from fixed-width text file(s), with a row per line split into columns by character positions
+
+    Each column holds the characters ``[start, start + width)`` of each line, with surrounding whitespace trimmed, and
+    is parsed into its type like a CSV field. Columns that are blank or past the end of a line are null, and blank lines
+    are skipped. Large uncompressed files are read in parallel by splitting them into byte ranges.
+
+    Example:
+        >>> df = daft.read_fixed_width(
+        ...     "/path/to/file.txt",
+        ...     columns=[("id", 0, 8, daft.DataType.int64()), ("name", 8, 20, daft.DataType.string())],
+        ...     skip_rows=1,
+        ... )
+
+    Args:
+        path (str): Path to fixed-width text files (allows for wildcards)
+        columns (list[tuple[str, int, int, DataType]]): Name, position of the first character starting from 0, number of characters and type of each column.
+        skip_rows (int): Number of lines to skip at the start of each file, such as header lines, defaults to 0.
+        io_config (IOConfig): Config to be used with the native downloader
+        hive_partitioning (bool): Whether to infer partition columns from Hive-style `key=value` directories in the file paths, defaults to False.
+        _multithreaded_io (bool): Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
+            the amount of system resources (number of connections and thread contention) when running in the Ray runner.
+            Defaults to None, which will let Daft decide based on the runner it is currently using.
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of fixed-width text filepaths")
+
+    if len(columns) == 0:
+        raise ValueError("Cannot read fixed-width text files without any columns")
+
+    file_format_config = FileFormatConfig.from_fixed_width_config(
+        FixedWidthSourceConfig(
+            columns=[(name, start, width, dtype._dtype) for name, start, width, dtype in columns],
+            skip_rows=skip_rows,
+        )
+    )
+    return _read_with_fixed_schema(path, file_format_config, io_config, hive_partitioning, _multithreaded_io)
+
+
+@PublicAPI
+def read_files(
+    path: Union[str, List[str]],
+    io_config: Optional["IOConfig"] = None,
+    hive_partitioning: bool = False,
+    _multithreaded_io: Optional[bool] = None,
+) -> DataFrame:
+    """Creates a DataFrame from whole files, with a row per file and its ``path``, ``size`` and ``content`` columns
+
+    The content of each file is read as raw bytes without any decompression. It's only downloaded if the ``content``
+    column is used, so selecting only the ``path`` and ``size`` columns doesn't read the files.
+
+    Example:
+        >>> df = daft.read_files("/path/to/images/*.jpeg")
+        >>> df = daft.read_files("s3://path/to/directory").select("path", "size")
+
+    Args:
+        path (str): Path to files (allows for wildcards)
+        io_config (IOConfig): Config to be used with the native downloader
+        hive_partitioning (bool): Whether to infer partition columns from Hive-style `key=value` directories in the file paths, defaults to False.
+        _multithreaded_io (bool): Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
+            the amount of system resources (number of connections and thread contention) when running in the Ray runner.
+            Defaults to None, which will let Daft decide based on the runner it is currently using.
+
+    returns:
+        DataFrame: DataFrame with a row per file
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of filepaths")
+
+    file_format_config = FileFormatConfig.from_files_config(FilesSourceConfig())
+    return _read_with_fixed_schema(path, file_format_config, io_config, hive_partitioning, _multithreaded_io)
+
+
+def _read_with_fixed_schema(
+    path: Union[str, List[str]],
+    file_format_config: FileFormatConfig,
+    io_config: Optional["IOConfig"],
+    hive_partitioning: bool,
+    _multithreaded_io: Optional[bool],
+) -> DataFrame:
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    # If running on Ray, we want to limit the amount of concurrency and requests being made.
+    # This is because each Ray worker process receives its own pool of thread workers and connections
+    multithreaded_io = not context.get_context().is_ray_runner if _multithreaded_io is None else _multithreaded_io
+
+    storage_config = StorageConfig.native(NativeStorageConfig(multithreaded_io, io_config))
+    # The schema of these sources doesn't depend on the files, so "inferring" it doesn't read them.
+    builder = get_tabular_files_scan(
+        path=path,
+        infer_schema=True,
+        schema=None,
+        file_format_config=file_format_config,
+        storage_config=storage_config,
+        hive_partitioning=hive_partitioning,
+    )
+    return DataFrame(builder)
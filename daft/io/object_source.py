@@ -0,0 +1,97 @@
+from __future__ import annotations
+
+from abc import ABC, abstractmethod
+from dataclasses import dataclass
+from datetime import datetime
+
+from daft.daft import register_object_source as _register_object_source
+
+
+@dataclass(frozen=True)
+class ObjectInfo:
+    """Metadata for an entry returned by :meth:`ObjectSource.ls` or :meth:`ObjectSource.head`.
+
+    Args:
+        path: Full URL of the entry, including the scheme. Directory paths must end with ``"/"``.
+        size: Size of the object in bytes, or None if unknown (e.g. for directories).
+        is_dir: Whether the entry is a directory rather than an object.
+        etag: Opaque identifier of the object's content that changes whenever the object is overwritten, if known.
+        last_modified: Time at which the object was last modified, if known. Naive datetimes are assumed to be in
+            local time.
+        content_type: MIME type of the object, if known.
+    """
+
+    path: str
+    size: int | None = None
+    is_dir: bool = False
+    etag: str | None = None
+    last_modified: datetime | None = None
+    content_type: str | None = None
+
+
+class ObjectSource(ABC):
+    """Interface for plugging a custom storage backend into Daft's I/O layer.
+
+    All methods receive full URLs (e.g. ``"myblob://bucket/key"``). Raise ``FileNotFoundError`` for missing objects
+    and ``NotImplementedError`` for operations the backend doesn't support. Methods are called from Daft's I/O threads,
+    so implementations must be thread-safe.
+
+    Example:
+        >>> class MyBlobSource(daft.io.ObjectSource):
+        ...     ...
+        >>> daft.io.register_object_source("myblob", MyBlobSource())
+        >>> df = daft.read_parquet("myblob://bucket/data/*.parquet")
+    """
+
+    @abstractmethod
+    def get(self, path: str, range: tuple[int, int] | None = None) -> bytes:
+        """Returns the contents of the object at ``path``, restricted to the half-open byte ``range`` if provided."""
+        ...
+
+    @abstractmethod
+    def get_size(self, path: str) -> int:
+        """Returns the size in bytes of the object at ``path``."""
+        ...
+
+    def head(self, path: str) -> ObjectInfo:
+        """Returns the metadata of the object at ``path``.
+
+        Defaults to only returning the size of the object from :meth:`get_size`.
+        """
+        return ObjectInfo(path=path, size=self.get_size(path))
+
+    @abstractmethod
+    def ls(self, path: str, posix: bool = True) -> list[ObjectInfo]:
+        """Lists entries under ``path``.
+
+        If ``posix`` is True, only the immediate children of ``path`` are returned, with nested "directories" returned
+        as entries with ``is_dir=True``. Otherwise all objects whose path starts with ``path`` are returned.
+        """
+        ...
+
+    def put(self, path: str, data: bytes) -> None:
+        """Writes ``data`` to the object at ``path``, overwriting it if it exists."""
+        raise NotImplementedError()
+
+    def delete(self, path: str) -> None:
+        """Deletes the object at ``path``."""
+        raise NotImplementedError()
+
+    def copy(self, src: str, dest: str) -> None:
+        """Copies the object at ``src`` to ``dest``."""
+        raise NotImplementedError()
+
+
+def register_object_source(scheme: str, source: ObjectSource) -> None:
+    """Registers ``source`` to handle all URLs with the provided ``scheme`` (e.g. ``"myblob"`` for ``myblob://...``).
+
+    Registering a scheme again replaces the previously registered source. Built-in schemes such as ``s3`` cannot be
+    overridden.
+
+    Args:
+        scheme: URL scheme to register the source for
+        source: Source that serves reads, writes and listings for URLs with that scheme
+    """
+    if not isinstance(source, ObjectSource):
+        raise TypeError(f"Expected a daft.io.ObjectSource, but got: {type(source)}")
+    _register_object_source(scheme, source)
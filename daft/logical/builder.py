@@ -111,6 +111,20 @@ class LogicalPlanBuilder:
         builder = self._builder.optimize()
         return LogicalPlanBuilder(builder)
 
+    def cache(
+        self,
+        partition: PartitionCacheEntry,
+        num_partitions: int,
+        size_bytes: int,
+        num_rows: int,
+    ) -> LogicalPlanBuilder:
+        """
+        Caches the materialized results of the current logical plan, so that later plans containing an identical
+        subplan scan these results instead of recomputing them. Returns a builder that scans the cached results.
+        """
+        builder = self._builder.cache(partition.key, partition, num_partitions, size_bytes, num_rows)
+        return LogicalPlanBuilder(builder)
+
     @classmethod
     @_apply_daft_planning_config_to_initializer
     def from_in_memory_scan(
@@ -277,8 +291,10 @@ class LogicalPlanBuilder:
         partition_cols: list[Expression] | None = None,
         compression: str | None = None,
     ) -> LogicalPlanBuilder:
-        if file_format != FileFormat.Csv and file_format != FileFormat.Parquet:
-            raise ValueError(f"Writing is only supported for Parquet and CSV file formats, but got: {file_format}")
+        if file_format not in (FileFormat.Csv, FileFormat.Parquet, FileFormat.Ipc):
+            raise ValueError(
+                f"Writing is only supported for Parquet, CSV and Arrow IPC file formats, but got: {file_format}"
+            )
         part_cols_pyexprs = [expr._expr for expr in partition_cols] if partition_cols is not None else None
         builder = self._builder.table_write(str(root_dir), file_format, part_cols_pyexprs, compression, io_config)
         return LogicalPlanBuilder(builder)
@@ -88,4 +88,5 @@ class AdaptivePhysicalPlanScheduler:
             num_partitions=num_partitions,
             size_bytes=size_bytes,
             num_rows=num_rows,
+            partition_size_bytes=cache_entry.partition_size_bytes(),
         )
@@ -303,6 +303,15 @@ class PartitionCacheEntry:
     def num_rows(self) -> int | None:
         return len(self.value) if self.value is not None else None
 
+    def partition_size_bytes(self) -> list[int] | None:
+        """Returns the size in bytes of each partition, ordered by partition ID, or None if any size is unknown."""
+        if self.value is None:
+            return None
+        sizes = [part.metadata().size_bytes for part in self.value.values()]
+        if any(size is None for size in sizes):
+            return None
+        return [size for size in sizes if size is not None]
+
 
 class PartitionSetCache:
     def __init__(self) -> None:
@@ -463,7 +463,7 @@ def write_tabular(
         assert compression is None
         inflation_factor = execution_config.csv_inflation_factor
         target_file_size = execution_config.csv_target_filesize
-    else:
+    elif file_format != FileFormat.Ipc:
         raise ValueError(f"Unsupported file format {file_format}")
 
     for i, (tab, pf) in enumerate(zip(tables_to_write, part_keys_postfix_per_table)):
@@ -471,6 +471,16 @@ def write_tabular(
         if pf is not None and len(pf) > 0:
             full_path = f"{full_path}/{pf}"
 
+        if file_format == FileFormat.Ipc:
+            # Arrow IPC files are written natively, streaming each table to a single file.
+            if is_local_fs:
+                pathlib.Path(full_path).mkdir(parents=True, exist_ok=True)
+            file_path = f"{full_path}/{_generate_basename_template(file_format.ext()).format(i=0)}"
+            _write_ipc_file(tab, file_path, protocol, is_local_fs, compression, io_config)
+            visited_paths.append(file_path)
+            partition_idx.append(i)
+            continue
+
         arrow_table = tab.to_arrow()
 
         size_bytes = arrow_table.nbytes
@@ -513,6 +523,20 @@ def write_tabular(
     return MicroPartition.from_pydict(data_dict)
 
 
+def _write_ipc_file(
+    table: MicroPartition,
+    file_path: str,
+    protocol: str,
+    is_local_fs: bool,
+    compression: str | None,
+    io_config: IOConfig | None,
+) -> None:
+    from daft.daft import write_ipc
+
+    uri = file_path if is_local_fs else f"{protocol}://{file_path}"
+    write_ipc([table.to_table()._table], uri, compression=compression, io_config=io_config)
+
+
 def coerce_pyarrow_table_to_schema(pa_table: pa.Table, input_schema: pa.Schema) -> pa.Table:
     """Coerces a PyArrow table to the supplied schema
 
@@ -914,6 +938,11 @@ def write_empty_tabular(
             )
         elif file_format == FileFormat.Csv:
             pacsv.write_csv(table, file_path)
+        elif file_format == FileFormat.Ipc:
+            fs.create_dir(resolved_path, recursive=True)
+            with fs.open_output_stream(file_path) as sink:
+                with pa.ipc.new_file(sink, table.schema, options=pa.ipc.IpcWriteOptions(compression=compression)):
+                    pass
         else:
             raise ValueError(f"Unsupported file format {file_format}")
 
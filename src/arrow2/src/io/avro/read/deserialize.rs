@@ -32,7 +32,7 @@ fn make_mutable(
             Box::new(MutableUtf8Array::<i32>::with_capacity(capacity)) as Box<dyn MutableArray>
         }
         PhysicalType::Dictionary(_) => {
-            if let Some(AvroSchema::Enum(Enum { symbols, .. })) = avro_field {
+            if let Some(AvroSchema::Enum(Enum { symbols, .. })) = avro_field.map(non_null_schema) {
                 let values = Utf8Array::<i32>::from_slice(symbols);
                 Box::new(FixedItemsUtf8Dictionary::with_capacity(values, capacity))
                     as Box<dyn MutableArray>
@@ -42,7 +42,11 @@ fn make_mutable(
         }
         _ => match data_type {
             DataType::List(inner) => {
-                let values = make_mutable(inner.data_type(), None, 0)?;
+                let avro_inner = match avro_field.map(non_null_schema) {
+                    Some(AvroSchema::Array(inner)) => Some(inner.as_ref()),
+                    _ => None,
+                };
+                let values = make_mutable(inner.data_type(), avro_inner, 0)?;
                 Box::new(DynMutableListArray::<i32>::new_from(
                     values,
                     data_type.clone(),
@@ -54,9 +58,18 @@ fn make_mutable(
                     as Box<dyn MutableArray>
             }
             DataType::Struct(fields) => {
+                let avro_fields = match avro_field.map(non_null_schema) {
+                    Some(AvroSchema::Record(Record { fields, .. })) => Some(fields),
+                    _ => None,
+                };
                 let values = fields
                     .iter()
-                    .map(|field| make_mutable(field.data_type(), None, capacity))
+                    .enumerate()
+                    .map(|(index, field)| {
+                        let avro_field =
+                            avro_fields.and_then(|fields| fields.get(index)).map(|f| &f.schema);
+                        make_mutable(field.data_type(), avro_field, capacity)
+                    })
                     .collect::<Result<Vec<_>>>()?;
                 Box::new(DynMutableStructArray::new(values, data_type.clone()))
                     as Box<dyn MutableArray>
@@ -70,6 +83,17 @@ fn make_mutable(
     })
 }
 
+/// Returns the non-null variant of a nullable union, or `avro_field` itself if it isn't a union.
+fn non_null_schema(avro_field: &AvroSchema) -> &AvroSchema {
+    match avro_field {
+        AvroSchema::Union(u) => match u.as_slice() {
+            [AvroSchema::Null, e] | [e, AvroSchema::Null] => e,
+            _ => avro_field,
+        },
+        _ => avro_field,
+    }
+}
+
 fn is_union_null_first(avro_field: &AvroSchema) -> bool {
     if let AvroSchema::Union(schemas) = avro_field {
         schemas[0] == AvroSchema::Null
@@ -22,6 +22,7 @@ impl From<parquet2::error::Error> for Error {
             parquet2::error::Error::Transport(msg) => {
                 Error::Io(std::io::Error::new(std::io::ErrorKind::Other, msg))
             }
+            parquet2::error::Error::IoError(error) => Error::Io(error),
             _ => Error::ExternalFormat(error.to_string()),
         }
     }
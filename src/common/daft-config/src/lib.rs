@@ -57,6 +57,8 @@ pub struct DaftExecutionConfig {
     pub shuffle_aggregation_default_partitions: usize,
     pub read_sql_partition_size_bytes: usize,
     pub enable_aqe: bool,
+    pub aqe_target_partition_size_bytes: usize,
+    pub aqe_skewed_partition_factor: f64,
     pub enable_native_executor: bool,
     pub default_morsel_size: usize,
 }
@@ -80,6 +82,8 @@ impl Default for DaftExecutionConfig {
             shuffle_aggregation_default_partitions: 200,
             read_sql_partition_size_bytes: 512 * 1024 * 1024, // 512MB
             enable_aqe: false,
+            aqe_target_partition_size_bytes: 128 * 1024 * 1024, // 128MB
+            aqe_skewed_partition_factor: 5.0,
             enable_native_executor: false,
             default_morsel_size: 128 * 1024,
         }
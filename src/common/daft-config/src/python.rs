@@ -113,6 +113,8 @@ impl PyDaftExecutionConfig {
         shuffle_aggregation_default_partitions: Option<usize>,
         read_sql_partition_size_bytes: Option<usize>,
         enable_aqe: Option<bool>,
+        aqe_target_partition_size_bytes: Option<usize>,
+        aqe_skewed_partition_factor: Option<f64>,
         enable_native_executor: Option<bool>,
         default_morsel_size: Option<usize>,
     ) -> PyResult<PyDaftExecutionConfig> {
@@ -171,6 +173,12 @@ impl PyDaftExecutionConfig {
         if let Some(enable_aqe) = enable_aqe {
             config.enable_aqe = enable_aqe;
         }
+        if let Some(aqe_target_partition_size_bytes) = aqe_target_partition_size_bytes {
+            config.aqe_target_partition_size_bytes = aqe_target_partition_size_bytes;
+        }
+        if let Some(aqe_skewed_partition_factor) = aqe_skewed_partition_factor {
+            config.aqe_skewed_partition_factor = aqe_skewed_partition_factor;
+        }
         if let Some(enable_native_executor) = enable_native_executor {
             config.enable_native_executor = enable_native_executor;
         }
@@ -257,6 +265,14 @@ impl PyDaftExecutionConfig {
         Ok(self.config.enable_aqe)
     }
     #[getter]
+    fn aqe_target_partition_size_bytes(&self) -> PyResult<usize> {
+        Ok(self.config.aqe_target_partition_size_bytes)
+    }
+    #[getter]
+    fn aqe_skewed_partition_factor(&self) -> PyResult<f64> {
+        Ok(self.config.aqe_skewed_partition_factor)
+    }
+    #[getter]
     fn enable_native_executor(&self) -> PyResult<bool> {
         Ok(self.config.enable_native_executor)
     }
@@ -4,7 +4,7 @@ use std::fmt::Formatter;
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::ObfuscatedString;
+use crate::{ObfuscatedString, RetryConfig};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct AzureConfig {
@@ -19,6 +19,13 @@ pub struct AzureConfig {
     pub anonymous: bool,
     pub endpoint_url: Option<String>,
     pub use_ssl: bool,
+    pub verify_checksums: bool,
+    pub max_requests_per_second: Option<u32>,
+    pub max_connections_per_io_thread: u32,
+    pub retry_initial_backoff_ms: u64,
+    pub connect_timeout_ms: u64,
+    pub read_timeout_ms: u64,
+    pub num_tries: u32,
 }
 
 impl Default for AzureConfig {
@@ -35,11 +42,27 @@ impl Default for AzureConfig {
             anonymous: false,
             endpoint_url: None,
             use_ssl: true,
+            verify_checksums: false,
+            max_requests_per_second: None,
+            max_connections_per_io_thread: 64,
+            retry_initial_backoff_ms: 1000,
+            connect_timeout_ms: 30_000,
+            read_timeout_ms: 30_000,
+            num_tries: 5,
         }
     }
 }
 
 impl AzureConfig {
+    pub fn retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            num_tries: self.num_tries,
+            initial_backoff_ms: self.retry_initial_backoff_ms,
+            connect_timeout_ms: self.connect_timeout_ms,
+            read_timeout_ms: self.read_timeout_ms,
+        }
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         if let Some(storage_account) = &self.storage_account {
@@ -72,6 +95,24 @@ impl AzureConfig {
             res.push(format!("Endpoint URL = {}", endpoint_url));
         }
         res.push(format!("Use SSL = {}", self.use_ssl));
+        res.push(format!("Verify checksums = {}", self.verify_checksums));
+        if let Some(max_requests_per_second) = self.max_requests_per_second {
+            res.push(format!(
+                "Max requests per second = {}",
+                max_requests_per_second
+            ));
+        }
+        res.push(format!(
+            "Max connections = {}",
+            self.max_connections_per_io_thread
+        ));
+        res.push(format!(
+            "Retry initial backoff ms = {}",
+            self.retry_initial_backoff_ms
+        ));
+        res.push(format!("Connect timeout ms = {}", self.connect_timeout_ms));
+        res.push(format!("Read timeout ms = {}", self.read_timeout_ms));
+        res.push(format!("Max retries = {}", self.num_tries));
         res
     }
 }
@@ -91,7 +132,14 @@ impl Display for AzureConfig {
     use_fabric_endpoint: {:?}
     anonymous: {:?}
     endpoint_url: {:?}
-    use_ssl: {:?}",
+    use_ssl: {:?}
+    verify_checksums: {:?}
+    max_requests_per_second: {:?}
+    max_connections: {}
+    retry_initial_backoff_ms: {}
+    connect_timeout_ms: {}
+    read_timeout_ms: {}
+    num_tries: {}",
             self.storage_account,
             self.access_key,
             self.sas_token,
@@ -102,7 +150,14 @@ impl Display for AzureConfig {
             self.use_fabric_endpoint,
             self.anonymous,
             self.endpoint_url,
-            self.use_ssl
+            self.use_ssl,
+            self.verify_checksums,
+            self.max_requests_per_second,
+            self.max_connections_per_io_thread,
+            self.retry_initial_backoff_ms,
+            self.connect_timeout_ms,
+            self.read_timeout_ms,
+            self.num_tries
         )
     }
 }
@@ -0,0 +1,53 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CacheConfig {
+    pub enabled: bool,
+    pub directory: Option<String>,
+    pub max_size_bytes: u64,
+    pub metadata_ttl_seconds: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfig {
+            enabled: false,
+            directory: None,
+            max_size_bytes: 10 * 1024 * 1024 * 1024,
+            metadata_ttl_seconds: 60,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("Enabled = {}", self.enabled)];
+        if let Some(directory) = &self.directory {
+            res.push(format!("Directory = {}", directory));
+        }
+        res.push(format!("Max size bytes = {}", self.max_size_bytes));
+        res.push(format!(
+            "Metadata TTL seconds = {}",
+            self.metadata_ttl_seconds
+        ));
+        res
+    }
+}
+
+impl Display for CacheConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "CacheConfig
+    enabled: {}
+    directory: {:?}
+    max_size_bytes: {}
+    metadata_ttl_seconds: {}",
+            self.enabled, self.directory, self.max_size_bytes, self.metadata_ttl_seconds
+        )
+    }
+}
@@ -5,13 +5,14 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::HTTPConfig;
-use crate::{AzureConfig, GCSConfig, S3Config};
+use crate::{AzureConfig, CacheConfig, GCSConfig, S3Config};
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct IOConfig {
     pub s3: S3Config,
     pub azure: AzureConfig,
     pub gcs: GCSConfig,
     pub http: HTTPConfig,
+    pub cache: CacheConfig,
 }
 
 impl IOConfig {
@@ -33,6 +34,10 @@ impl IOConfig {
             "HTTP config = {{ {} }}",
             self.http.multiline_display().join(", ")
         ));
+        res.push(format!(
+            "Cache config = {{ {} }}",
+            self.cache.multiline_display().join(", ")
+        ));
         res
     }
 }
@@ -45,8 +50,9 @@ impl Display for IOConfig {
 {}
 {}
 {}
+{}
 {}",
-            self.s3, self.azure, self.gcs, self.http,
+            self.s3, self.azure, self.gcs, self.http, self.cache,
         )
     }
 }
@@ -4,23 +4,75 @@ use std::fmt::Formatter;
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::ObfuscatedString;
+use crate::{ObfuscatedString, RetryConfig};
 
-#[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct GCSConfig {
     pub project_id: Option<String>,
     pub credentials: Option<ObfuscatedString>,
     pub token: Option<String>,
     pub anonymous: bool,
+    pub verify_checksums: bool,
+    pub max_requests_per_second: Option<u32>,
+    pub max_connections_per_io_thread: u32,
+    pub retry_initial_backoff_ms: u64,
+    pub connect_timeout_ms: u64,
+    pub read_timeout_ms: u64,
+    pub num_tries: u32,
+}
+
+impl Default for GCSConfig {
+    fn default() -> Self {
+        Self {
+            project_id: None,
+            credentials: None,
+            token: None,
+            anonymous: false,
+            verify_checksums: false,
+            max_requests_per_second: None,
+            max_connections_per_io_thread: 64,
+            retry_initial_backoff_ms: 1000,
+            connect_timeout_ms: 30_000,
+            read_timeout_ms: 30_000,
+            num_tries: 5,
+        }
+    }
 }
 
 impl GCSConfig {
+    pub fn retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            num_tries: self.num_tries,
+            initial_backoff_ms: self.retry_initial_backoff_ms,
+            connect_timeout_ms: self.connect_timeout_ms,
+            read_timeout_ms: self.read_timeout_ms,
+        }
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         if let Some(project_id) = &self.project_id {
             res.push(format!("Project ID = {}", project_id));
         }
         res.push(format!("Anonymous = {}", self.anonymous));
+        res.push(format!("Verify checksums = {}", self.verify_checksums));
+        if let Some(max_requests_per_second) = self.max_requests_per_second {
+            res.push(format!(
+                "Max requests per second = {}",
+                max_requests_per_second
+            ));
+        }
+        res.push(format!(
+            "Max connections = {}",
+            self.max_connections_per_io_thread
+        ));
+        res.push(format!(
+            "Retry initial backoff ms = {}",
+            self.retry_initial_backoff_ms
+        ));
+        res.push(format!("Connect timeout ms = {}", self.connect_timeout_ms));
+        res.push(format!("Read timeout ms = {}", self.read_timeout_ms));
+        res.push(format!("Max retries = {}", self.num_tries));
         res
     }
 }
@@ -31,8 +83,23 @@ impl Display for GCSConfig {
             f,
             "GCSConfig
     project_id: {:?}
-    anonymous: {:?}",
-            self.project_id, self.anonymous
+    anonymous: {:?}
+    verify_checksums: {:?}
+    max_requests_per_second: {:?}
+    max_connections: {}
+    retry_initial_backoff_ms: {}
+    connect_timeout_ms: {}
+    read_timeout_ms: {}
+    num_tries: {}",
+            self.project_id,
+            self.anonymous,
+            self.verify_checksums,
+            self.max_requests_per_second,
+            self.max_connections_per_io_thread,
+            self.retry_initial_backoff_ms,
+            self.connect_timeout_ms,
+            self.read_timeout_ms,
+            self.num_tries
         )
     }
 }
@@ -4,12 +4,17 @@ use std::fmt::Formatter;
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::ObfuscatedString;
+use crate::{ObfuscatedString, RetryConfig};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct HTTPConfig {
     pub user_agent: String,
     pub bearer_token: Option<ObfuscatedString>,
+    pub max_connections_per_io_thread: u32,
+    pub retry_initial_backoff_ms: u64,
+    pub connect_timeout_ms: u64,
+    pub read_timeout_ms: u64,
+    pub num_tries: u32,
 }
 
 impl Default for HTTPConfig {
@@ -17,6 +22,11 @@ impl Default for HTTPConfig {
         HTTPConfig {
             user_agent: "daft/0.0.1".to_string(), // NOTE: Ideally we grab the version of Daft, but that requires a dependency on daft-core
             bearer_token: None,
+            max_connections_per_io_thread: 64,
+            retry_initial_backoff_ms: 1000,
+            connect_timeout_ms: 30_000,
+            read_timeout_ms: 30_000,
+            num_tries: 5,
         }
     }
 }
@@ -28,6 +38,15 @@ impl HTTPConfig {
             ..Default::default()
         }
     }
+
+    pub fn retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            num_tries: self.num_tries,
+            initial_backoff_ms: self.retry_initial_backoff_ms,
+            connect_timeout_ms: self.connect_timeout_ms,
+            read_timeout_ms: self.read_timeout_ms,
+        }
+    }
 }
 
 impl HTTPConfig {
@@ -36,6 +55,17 @@ impl HTTPConfig {
         if let Some(bearer_token) = &self.bearer_token {
             v.push(format!("bearer_token = {}", bearer_token));
         }
+        v.push(format!(
+            "max_connections = {}",
+            self.max_connections_per_io_thread
+        ));
+        v.push(format!(
+            "retry_initial_backoff_ms = {}",
+            self.retry_initial_backoff_ms
+        ));
+        v.push(format!("connect_timeout_ms = {}", self.connect_timeout_ms));
+        v.push(format!("read_timeout_ms = {}", self.read_timeout_ms));
+        v.push(format!("num_tries = {}", self.num_tries));
 
         v
     }
@@ -46,8 +76,18 @@ impl Display for HTTPConfig {
         write!(
             f,
             "HTTPConfig
-    user_agent: {}",
+    user_agent: {}
+    max_connections: {}
+    retry_initial_backoff_ms: {}
+    connect_timeout_ms: {}
+    read_timeout_ms: {}
+    num_tries: {}",
             self.user_agent,
+            self.max_connections_per_io_thread,
+            self.retry_initial_backoff_ms,
+            self.connect_timeout_ms,
+            self.read_timeout_ms,
+            self.num_tries,
         )?;
 
         if let Some(bearer_token) = &self.bearer_token {
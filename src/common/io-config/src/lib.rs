@@ -2,9 +2,11 @@
 pub mod python;
 
 mod azure;
+mod cache;
 mod config;
 mod gcs;
 mod http;
+mod retry;
 mod s3;
 
 use std::{
@@ -16,8 +18,8 @@ use secrecy::{ExposeSecret, Secret};
 use serde::{Deserialize, Deserializer, Serialize};
 
 pub use crate::{
-    azure::AzureConfig, config::IOConfig, gcs::GCSConfig, http::HTTPConfig, s3::S3Config,
-    s3::S3Credentials,
+    azure::AzureConfig, cache::CacheConfig, config::IOConfig, gcs::GCSConfig, http::HTTPConfig,
+    retry::RetryConfig, s3::S3Config, s3::S3Credentials,
 };
 
 #[derive(Clone)]
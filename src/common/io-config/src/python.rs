@@ -39,6 +39,8 @@ use crate::{config, s3::S3CredentialsProvider};
 ///     requester_pays (bool, optional): Whether or not the authenticated user will assume transfer costs, which is required by some providers of bulk data, defaults to False
 ///     force_virtual_addressing (bool, optional): Force S3 client to use virtual addressing in all cases. If False, virtual addressing will only be used if `endpoint_url` is empty, defaults to False
 ///     profile_name (str, optional): Name of AWS_PROFILE to load, defaults to None which will then check the Environment Variable `AWS_PROFILE` then fall back to `default`
+///     verify_checksums (bool, optional): Whether to send a Content-MD5 header on uploads and verify full-object downloads against their ETag, defaults to False
+///     max_requests_per_second (int, optional): Maximum number of requests per second to send to any single bucket, defaults to None (no limit)
 ///
 /// Example:
 ///     >>> io_config = IOConfig(s3=S3Config(key_id="xxx", access_key="xxx"))
@@ -87,6 +89,13 @@ pub struct S3Credentials {
 ///     anonymous (bool, optional): Whether or not to use "anonymous mode", which will access Azure without any credentials
 ///     endpoint_url (str, optional): Custom URL to the Azure endpoint, e.g. ``https://my-account-name.blob.core.windows.net``. Overrides `use_fabric_endpoint` if set
 ///     use_ssl (bool, optional): Whether or not to use SSL, which require accessing Azure over HTTPS rather than HTTP, defaults to True
+///     verify_checksums (bool, optional): Whether to send a Content-MD5 header on uploads and verify full-object downloads against their stored Content-MD5, defaults to False
+///     max_requests_per_second (int, optional): Maximum number of requests per second to send to any single storage account, defaults to None (no limit)
+///     max_connections (int, optional): Maximum number of connections to Azure per IO thread at any time, defaults to 64
+///     retry_initial_backoff_ms (int, optional): Initial backoff duration in milliseconds for a Azure retry, defaults to 1000ms
+///     connect_timeout_ms (int, optional): Timeout duration to wait to make a connection to Azure in milliseconds, defaults to 30 seconds
+///     read_timeout_ms (int, optional): Timeout duration to wait to read the first byte from Azure in milliseconds, defaults to 30 seconds
+///     num_tries (int, optional): Number of attempts to make a request, defaults to 5
 ///
 /// Example:
 ///     >>> io_config = IOConfig(azure=AzureConfig(storage_account="dafttestdata", access_key="xxx"))
@@ -105,6 +114,13 @@ pub struct AzureConfig {
 ///     credentials (str, optional): Path to credentials file or JSON string with credentials
 ///     token (str, optional): OAuth2 token to use for authentication. You likely want to use `credentials` instead, since it can be used to refresh the token. This value is used when vended by a data catalog.
 ///     anonymous (bool, optional): Whether or not to use "anonymous mode", which will access Google Storage without any credentials. Defaults to false
+///     verify_checksums (bool, optional): Whether to send CRC32C and MD5 checksums with uploads, which GCS validates before creating the object, and verify full-object downloads against the object's CRC32C, defaults to False
+///     max_requests_per_second (int, optional): Maximum number of requests per second to send to any single bucket, defaults to None (no limit)
+///     max_connections (int, optional): Maximum number of connections to GCS per IO thread at any time, defaults to 64
+///     retry_initial_backoff_ms (int, optional): Initial backoff duration in milliseconds for a GCS retry, defaults to 1000ms
+///     connect_timeout_ms (int, optional): Timeout duration to wait to make a connection to GCS in milliseconds, defaults to 30 seconds
+///     read_timeout_ms (int, optional): Timeout duration to wait to read the first byte from GCS in milliseconds, defaults to 30 seconds
+///     num_tries (int, optional): Number of attempts to make a request, defaults to 5
 ///
 /// Example:
 ///     >>> io_config = IOConfig(gcs=GCSConfig(anonymous=True))
@@ -121,6 +137,8 @@ pub struct GCSConfig {
 ///     s3: Configuration to use when accessing URLs with the `s3://` scheme
 ///     azure: Configuration to use when accessing URLs with the `az://` or `abfs://` scheme
 ///     gcs: Configuration to use when accessing URLs with the `gs://` or `gcs://` scheme
+///     http: Configuration to use when accessing URLs with the `http://` or `https://` scheme
+///     cache: Configuration for the local on-disk cache of data read from remote storage
 /// Example:
 ///     >>> io_config = IOConfig(s3=S3Config(key_id="xxx", access_key="xxx", num_tries=10), azure=AzureConfig(anonymous=True), gcs=GCSConfig(...))
 ///     >>> daft.read_parquet(["s3://some-path", "az://some-other-path", "gs://path3"], io_config=io_config)
@@ -135,6 +153,11 @@ pub struct IOConfig {
 /// Args:
 ///     user_agent (str, optional): The value for the user-agent header, defaults to "daft/{__version__}" if not provided
 ///     bearer_token (str, optional): Bearer token to use for authentication. This will be used as the value for the `Authorization` header. such as "Authorization: Bearer xxx"
+///     max_connections (int, optional): Maximum number of connections to the server per IO thread at any time, defaults to 64
+///     retry_initial_backoff_ms (int, optional): Initial backoff duration in milliseconds for a the server retry, defaults to 1000ms
+///     connect_timeout_ms (int, optional): Timeout duration to wait to make a connection to the server in milliseconds, defaults to 30 seconds
+///     read_timeout_ms (int, optional): Timeout duration to wait to read the first byte from the server in milliseconds, defaults to 30 seconds
+///     num_tries (int, optional): Number of attempts to make a request, defaults to 5
 ///
 /// Example:
 ///     >>> io_config = IOConfig(http=HTTPConfig(user_agent="my_application/0.0.1", bearer_token="xxx"))
@@ -145,6 +168,29 @@ pub struct HTTPConfig {
     pub config: crate::HTTPConfig,
 }
 
+/// Create configurations for the local on-disk cache of data read from remote storage.
+///
+/// When enabled, byte ranges read from remote storage are stored in a local directory and reused by subsequent reads
+/// of the same objects, evicting the least recently used data once the cache grows beyond `max_size_bytes`. Cached
+/// data is keyed by each object's ETag and last modified time. These are taken from file listings or fetched with a
+/// metadata request, and reused for `metadata_ttl_seconds`, so objects overwritten within that time of being listed
+/// or read may still be served from the cache.
+///
+/// Args:
+///     enabled (bool, optional): Whether or not to cache remote reads on local disk, defaults to False
+///     directory (str, optional): Directory to store cached data in, defaults to a `daft-io-cache` directory in the system's temporary directory
+///     max_size_bytes (int, optional): Maximum total size of the cached data in bytes, defaults to 10GiB
+///     metadata_ttl_seconds (int, optional): How long the ETag and last modified time of an object are reused before being fetched again, defaults to 60. Set to 0 to check them on every read
+///
+/// Example:
+///     >>> io_config = IOConfig(cache=CacheConfig(enabled=True, directory="/mnt/nvme/daft-cache"))
+///     >>> daft.read_parquet("s3://some-path", io_config=io_config)
+#[derive(Clone, Default)]
+#[pyclass]
+pub struct CacheConfig {
+    pub config: crate::CacheConfig,
+}
+
 #[pymethods]
 impl IOConfig {
     #[new]
@@ -153,6 +199,7 @@ impl IOConfig {
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
+        cache: Option<CacheConfig>,
     ) -> Self {
         IOConfig {
             config: config::IOConfig {
@@ -160,6 +207,7 @@ impl IOConfig {
                 azure: azure.unwrap_or_default().config,
                 gcs: gcs.unwrap_or_default().config,
                 http: http.unwrap_or_default().config,
+                cache: cache.unwrap_or_default().config,
             },
         }
     }
@@ -170,6 +218,7 @@ impl IOConfig {
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
+        cache: Option<CacheConfig>,
     ) -> Self {
         IOConfig {
             config: config::IOConfig {
@@ -181,6 +230,9 @@ impl IOConfig {
                 http: http
                     .map(|http| http.config)
                     .unwrap_or(self.config.http.clone()),
+                cache: cache
+                    .map(|cache| cache.config)
+                    .unwrap_or(self.config.cache.clone()),
             },
         }
     }
@@ -221,6 +273,14 @@ impl IOConfig {
         })
     }
 
+    /// Configuration for the local on-disk cache of remote reads
+    #[getter]
+    pub fn cache(&self) -> PyResult<CacheConfig> {
+        Ok(CacheConfig {
+            config: self.config.cache.clone(),
+        })
+    }
+
     #[staticmethod]
     pub fn from_json(input: &str) -> PyResult<Self> {
         let config: config::IOConfig = serde_json::from_str(input).map_err(DaftError::from)?;
@@ -274,6 +334,8 @@ impl S3Config {
         requester_pays: Option<bool>,
         force_virtual_addressing: Option<bool>,
         profile_name: Option<String>,
+        verify_checksums: Option<bool>,
+        max_requests_per_second: Option<u32>,
     ) -> PyResult<Self> {
         let def = crate::S3Config::default();
         Ok(S3Config {
@@ -307,6 +369,8 @@ impl S3Config {
                 force_virtual_addressing: force_virtual_addressing
                     .unwrap_or(def.force_virtual_addressing),
                 profile_name: profile_name.or(def.profile_name),
+                verify_checksums: verify_checksums.unwrap_or(def.verify_checksums),
+                max_requests_per_second: max_requests_per_second.or(def.max_requests_per_second),
             },
         })
     }
@@ -335,6 +399,8 @@ impl S3Config {
         requester_pays: Option<bool>,
         force_virtual_addressing: Option<bool>,
         profile_name: Option<String>,
+        verify_checksums: Option<bool>,
+        max_requests_per_second: Option<u32>,
     ) -> PyResult<Self> {
         Ok(S3Config {
             config: crate::S3Config {
@@ -371,6 +437,9 @@ impl S3Config {
                 force_virtual_addressing: force_virtual_addressing
                     .unwrap_or(self.config.force_virtual_addressing),
                 profile_name: profile_name.or_else(|| self.config.profile_name.clone()),
+                verify_checksums: verify_checksums.unwrap_or(self.config.verify_checksums),
+                max_requests_per_second: max_requests_per_second
+                    .or(self.config.max_requests_per_second),
             },
         })
     }
@@ -527,6 +596,18 @@ impl S3Config {
     pub fn profile_name(&self) -> PyResult<Option<String>> {
         Ok(self.config.profile_name.clone())
     }
+
+    /// Whether to send and verify checksums
+    #[getter]
+    pub fn verify_checksums(&self) -> PyResult<Option<bool>> {
+        Ok(Some(self.config.verify_checksums))
+    }
+
+    /// Maximum number of requests per second to send to a single host
+    #[getter]
+    pub fn max_requests_per_second(&self) -> PyResult<Option<u32>> {
+        Ok(self.config.max_requests_per_second)
+    }
 }
 
 #[pymethods]
@@ -690,6 +771,13 @@ impl AzureConfig {
         anonymous: Option<bool>,
         endpoint_url: Option<String>,
         use_ssl: Option<bool>,
+        verify_checksums: Option<bool>,
+        max_requests_per_second: Option<u32>,
+        max_connections: Option<u32>,
+        retry_initial_backoff_ms: Option<u64>,
+        connect_timeout_ms: Option<u64>,
+        read_timeout_ms: Option<u64>,
+        num_tries: Option<u32>,
     ) -> Self {
         let def = crate::AzureConfig::default();
         AzureConfig {
@@ -705,6 +793,15 @@ impl AzureConfig {
                 anonymous: anonymous.unwrap_or(def.anonymous),
                 endpoint_url: endpoint_url.or(def.endpoint_url),
                 use_ssl: use_ssl.unwrap_or(def.use_ssl),
+                verify_checksums: verify_checksums.unwrap_or(def.verify_checksums),
+                max_requests_per_second: max_requests_per_second.or(def.max_requests_per_second),
+                max_connections_per_io_thread: max_connections
+                    .unwrap_or(def.max_connections_per_io_thread),
+                retry_initial_backoff_ms: retry_initial_backoff_ms
+                    .unwrap_or(def.retry_initial_backoff_ms),
+                connect_timeout_ms: connect_timeout_ms.unwrap_or(def.connect_timeout_ms),
+                read_timeout_ms: read_timeout_ms.unwrap_or(def.read_timeout_ms),
+                num_tries: num_tries.unwrap_or(def.num_tries),
             },
         }
     }
@@ -723,6 +820,13 @@ impl AzureConfig {
         anonymous: Option<bool>,
         endpoint_url: Option<String>,
         use_ssl: Option<bool>,
+        verify_checksums: Option<bool>,
+        max_requests_per_second: Option<u32>,
+        max_connections: Option<u32>,
+        retry_initial_backoff_ms: Option<u64>,
+        connect_timeout_ms: Option<u64>,
+        read_timeout_ms: Option<u64>,
+        num_tries: Option<u32>,
     ) -> Self {
         AzureConfig {
             config: crate::AzureConfig {
@@ -741,6 +845,16 @@ impl AzureConfig {
                 anonymous: anonymous.unwrap_or(self.config.anonymous),
                 endpoint_url: endpoint_url.or_else(|| self.config.endpoint_url.clone()),
                 use_ssl: use_ssl.unwrap_or(self.config.use_ssl),
+                verify_checksums: verify_checksums.unwrap_or(self.config.verify_checksums),
+                max_requests_per_second: max_requests_per_second
+                    .or(self.config.max_requests_per_second),
+                max_connections_per_io_thread: max_connections
+                    .unwrap_or(self.config.max_connections_per_io_thread),
+                retry_initial_backoff_ms: retry_initial_backoff_ms
+                    .unwrap_or(self.config.retry_initial_backoff_ms),
+                connect_timeout_ms: connect_timeout_ms.unwrap_or(self.config.connect_timeout_ms),
+                read_timeout_ms: read_timeout_ms.unwrap_or(self.config.read_timeout_ms),
+                num_tries: num_tries.unwrap_or(self.config.num_tries),
             },
         }
     }
@@ -821,6 +935,48 @@ impl AzureConfig {
     pub fn use_ssl(&self) -> PyResult<bool> {
         Ok(self.config.use_ssl)
     }
+
+    /// Whether to send and verify checksums
+    #[getter]
+    pub fn verify_checksums(&self) -> PyResult<bool> {
+        Ok(self.config.verify_checksums)
+    }
+
+    /// Maximum number of requests per second to send to a single host
+    #[getter]
+    pub fn max_requests_per_second(&self) -> PyResult<Option<u32>> {
+        Ok(self.config.max_requests_per_second)
+    }
+
+    /// Maximum number of connections per IO thread
+    #[getter]
+    pub fn max_connections(&self) -> PyResult<u32> {
+        Ok(self.config.max_connections_per_io_thread)
+    }
+
+    /// Retry Initial Backoff Time in Milliseconds
+    #[getter]
+    pub fn retry_initial_backoff_ms(&self) -> PyResult<u64> {
+        Ok(self.config.retry_initial_backoff_ms)
+    }
+
+    /// Connection Timeout in Milliseconds
+    #[getter]
+    pub fn connect_timeout_ms(&self) -> PyResult<u64> {
+        Ok(self.config.connect_timeout_ms)
+    }
+
+    /// Read Timeout in Milliseconds
+    #[getter]
+    pub fn read_timeout_ms(&self) -> PyResult<u64> {
+        Ok(self.config.read_timeout_ms)
+    }
+
+    /// Number of attempts to make a request
+    #[getter]
+    pub fn num_tries(&self) -> PyResult<u32> {
+        Ok(self.config.num_tries)
+    }
 }
 
 #[pymethods]
@@ -832,6 +988,13 @@ impl GCSConfig {
         credentials: Option<String>,
         token: Option<String>,
         anonymous: Option<bool>,
+        verify_checksums: Option<bool>,
+        max_requests_per_second: Option<u32>,
+        max_connections: Option<u32>,
+        retry_initial_backoff_ms: Option<u64>,
+        connect_timeout_ms: Option<u64>,
+        read_timeout_ms: Option<u64>,
+        num_tries: Option<u32>,
     ) -> Self {
         let def = crate::GCSConfig::default();
         GCSConfig {
@@ -840,16 +1003,33 @@ impl GCSConfig {
                 credentials: credentials.map(|v| v.into()).or(def.credentials),
                 token: token.or(def.token),
                 anonymous: anonymous.unwrap_or(def.anonymous),
+                verify_checksums: verify_checksums.unwrap_or(def.verify_checksums),
+                max_requests_per_second: max_requests_per_second.or(def.max_requests_per_second),
+                max_connections_per_io_thread: max_connections
+                    .unwrap_or(def.max_connections_per_io_thread),
+                retry_initial_backoff_ms: retry_initial_backoff_ms
+                    .unwrap_or(def.retry_initial_backoff_ms),
+                connect_timeout_ms: connect_timeout_ms.unwrap_or(def.connect_timeout_ms),
+                read_timeout_ms: read_timeout_ms.unwrap_or(def.read_timeout_ms),
+                num_tries: num_tries.unwrap_or(def.num_tries),
             },
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn replace(
         &self,
         project_id: Option<String>,
         credentials: Option<String>,
         token: Option<String>,
         anonymous: Option<bool>,
+        verify_checksums: Option<bool>,
+        max_requests_per_second: Option<u32>,
+        max_connections: Option<u32>,
+        retry_initial_backoff_ms: Option<u64>,
+        connect_timeout_ms: Option<u64>,
+        read_timeout_ms: Option<u64>,
+        num_tries: Option<u32>,
     ) -> Self {
         GCSConfig {
             config: crate::GCSConfig {
@@ -859,6 +1039,16 @@ impl GCSConfig {
                     .or_else(|| self.config.credentials.clone()),
                 token: token.or_else(|| self.config.token.clone()),
                 anonymous: anonymous.unwrap_or(self.config.anonymous),
+                verify_checksums: verify_checksums.unwrap_or(self.config.verify_checksums),
+                max_requests_per_second: max_requests_per_second
+                    .or(self.config.max_requests_per_second),
+                max_connections_per_io_thread: max_connections
+                    .unwrap_or(self.config.max_connections_per_io_thread),
+                retry_initial_backoff_ms: retry_initial_backoff_ms
+                    .unwrap_or(self.config.retry_initial_backoff_ms),
+                connect_timeout_ms: connect_timeout_ms.unwrap_or(self.config.connect_timeout_ms),
+                read_timeout_ms: read_timeout_ms.unwrap_or(self.config.read_timeout_ms),
+                num_tries: num_tries.unwrap_or(self.config.num_tries),
             },
         }
     }
@@ -894,6 +1084,48 @@ impl GCSConfig {
     pub fn anonymous(&self) -> PyResult<bool> {
         Ok(self.config.anonymous)
     }
+
+    /// Whether to send and verify checksums
+    #[getter]
+    pub fn verify_checksums(&self) -> PyResult<bool> {
+        Ok(self.config.verify_checksums)
+    }
+
+    /// Maximum number of requests per second to send to a single host
+    #[getter]
+    pub fn max_requests_per_second(&self) -> PyResult<Option<u32>> {
+        Ok(self.config.max_requests_per_second)
+    }
+
+    /// Maximum number of connections per IO thread
+    #[getter]
+    pub fn max_connections(&self) -> PyResult<u32> {
+        Ok(self.config.max_connections_per_io_thread)
+    }
+
+    /// Retry Initial Backoff Time in Milliseconds
+    #[getter]
+    pub fn retry_initial_backoff_ms(&self) -> PyResult<u64> {
+        Ok(self.config.retry_initial_backoff_ms)
+    }
+
+    /// Connection Timeout in Milliseconds
+    #[getter]
+    pub fn connect_timeout_ms(&self) -> PyResult<u64> {
+        Ok(self.config.connect_timeout_ms)
+    }
+
+    /// Read Timeout in Milliseconds
+    #[getter]
+    pub fn read_timeout_ms(&self) -> PyResult<u64> {
+        Ok(self.config.read_timeout_ms)
+    }
+
+    /// Number of attempts to make a request
+    #[getter]
+    pub fn num_tries(&self) -> PyResult<u32> {
+        Ok(self.config.num_tries)
+    }
 }
 
 impl From<config::IOConfig> for IOConfig {
@@ -905,15 +1137,129 @@ impl From<config::IOConfig> for IOConfig {
 #[pymethods]
 impl HTTPConfig {
     #[new]
-    pub fn new(bearer_token: Option<String>) -> Self {
+    pub fn new(
+        bearer_token: Option<String>,
+        max_connections: Option<u32>,
+        retry_initial_backoff_ms: Option<u64>,
+        connect_timeout_ms: Option<u64>,
+        read_timeout_ms: Option<u64>,
+        num_tries: Option<u32>,
+    ) -> Self {
+        let def = crate::HTTPConfig::new(bearer_token);
         HTTPConfig {
-            config: crate::HTTPConfig::new(bearer_token),
+            config: crate::HTTPConfig {
+                max_connections_per_io_thread: max_connections
+                    .unwrap_or(def.max_connections_per_io_thread),
+                retry_initial_backoff_ms: retry_initial_backoff_ms
+                    .unwrap_or(def.retry_initial_backoff_ms),
+                connect_timeout_ms: connect_timeout_ms.unwrap_or(def.connect_timeout_ms),
+                read_timeout_ms: read_timeout_ms.unwrap_or(def.read_timeout_ms),
+                num_tries: num_tries.unwrap_or(def.num_tries),
+                ..def
+            },
+        }
+    }
+
+    pub fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{}", self.config))
+    }
+
+    /// Maximum number of connections per IO thread
+    #[getter]
+    pub fn max_connections(&self) -> PyResult<u32> {
+        Ok(self.config.max_connections_per_io_thread)
+    }
+
+    /// Retry Initial Backoff Time in Milliseconds
+    #[getter]
+    pub fn retry_initial_backoff_ms(&self) -> PyResult<u64> {
+        Ok(self.config.retry_initial_backoff_ms)
+    }
+
+    /// Connection Timeout in Milliseconds
+    #[getter]
+    pub fn connect_timeout_ms(&self) -> PyResult<u64> {
+        Ok(self.config.connect_timeout_ms)
+    }
+
+    /// Read Timeout in Milliseconds
+    #[getter]
+    pub fn read_timeout_ms(&self) -> PyResult<u64> {
+        Ok(self.config.read_timeout_ms)
+    }
+
+    /// Number of attempts to make a request
+    #[getter]
+    pub fn num_tries(&self) -> PyResult<u32> {
+        Ok(self.config.num_tries)
+    }
+}
+
+#[pymethods]
+impl CacheConfig {
+    #[new]
+    pub fn new(
+        enabled: Option<bool>,
+        directory: Option<String>,
+        max_size_bytes: Option<u64>,
+        metadata_ttl_seconds: Option<u64>,
+    ) -> Self {
+        let def = crate::CacheConfig::default();
+        CacheConfig {
+            config: crate::CacheConfig {
+                enabled: enabled.unwrap_or(def.enabled),
+                directory: directory.or(def.directory),
+                max_size_bytes: max_size_bytes.unwrap_or(def.max_size_bytes),
+                metadata_ttl_seconds: metadata_ttl_seconds.unwrap_or(def.metadata_ttl_seconds),
+            },
+        }
+    }
+
+    pub fn replace(
+        &self,
+        enabled: Option<bool>,
+        directory: Option<String>,
+        max_size_bytes: Option<u64>,
+        metadata_ttl_seconds: Option<u64>,
+    ) -> Self {
+        CacheConfig {
+            config: crate::CacheConfig {
+                enabled: enabled.unwrap_or(self.config.enabled),
+                directory: directory.or_else(|| self.config.directory.clone()),
+                max_size_bytes: max_size_bytes.unwrap_or(self.config.max_size_bytes),
+                metadata_ttl_seconds: metadata_ttl_seconds
+                    .unwrap_or(self.config.metadata_ttl_seconds),
+            },
         }
     }
 
     pub fn __repr__(&self) -> PyResult<String> {
         Ok(format!("{}", self.config))
     }
+
+    /// Whether caching of remote reads is enabled
+    #[getter]
+    pub fn enabled(&self) -> PyResult<bool> {
+        Ok(self.config.enabled)
+    }
+
+    /// Directory that cached data is stored in
+    #[getter]
+    pub fn directory(&self) -> PyResult<Option<String>> {
+        Ok(self.config.directory.clone())
+    }
+
+    /// Maximum total size of the cached data in bytes
+    #[getter]
+    pub fn max_size_bytes(&self) -> PyResult<u64> {
+        Ok(self.config.max_size_bytes)
+    }
+
+    /// How long the ETag and last modified time of an object are reused before being fetched again
+    #[getter]
+    pub fn metadata_ttl_seconds(&self) -> PyResult<u64> {
+        Ok(self.config.metadata_ttl_seconds)
+    }
 }
 
 pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
@@ -921,6 +1267,7 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<GCSConfig>()?;
     parent.add_class::<S3Config>()?;
     parent.add_class::<HTTPConfig>()?;
+    parent.add_class::<CacheConfig>()?;
     parent.add_class::<S3Credentials>()?;
     parent.add_class::<IOConfig>()?;
     Ok(())
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+/// Retry and timeout settings for requests to an object store, as configured in the config of each store.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RetryConfig {
+    /// Maximum number of attempts of a request, including the first one.
+    pub num_tries: u32,
+    /// Upper bound of the jittered backoff before the first retry, which doubles with every subsequent retry.
+    pub initial_backoff_ms: u64,
+    /// Maximum time to wait for a connection to be established.
+    pub connect_timeout_ms: u64,
+    /// Maximum time to wait for the response to a request, up until its headers are received.
+    pub read_timeout_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            num_tries: 5,
+            initial_backoff_ms: 1000,
+            connect_timeout_ms: 30_000,
+            read_timeout_ms: 30_000,
+        }
+    }
+}
+
+impl RetryConfig {
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.connect_timeout_ms)
+    }
+
+    pub fn read_timeout(&self) -> Duration {
+        Duration::from_millis(self.read_timeout_ms)
+    }
+}
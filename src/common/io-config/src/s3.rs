@@ -12,6 +12,7 @@ use std::hash::Hasher;
 use std::time::SystemTime;
 
 pub use crate::ObfuscatedString;
+use crate::RetryConfig;
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct S3Config {
@@ -35,6 +36,8 @@ pub struct S3Config {
     pub requester_pays: bool,
     pub force_virtual_addressing: bool,
     pub profile_name: Option<String>,
+    pub verify_checksums: bool,
+    pub max_requests_per_second: Option<u32>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -134,10 +137,28 @@ impl S3Config {
         if let Some(name) = &self.profile_name {
             res.push(format!("Profile Name = {}", name));
         }
+        res.push(format!("Verify checksums = {}", self.verify_checksums));
+        if let Some(max_requests_per_second) = self.max_requests_per_second {
+            res.push(format!(
+                "Max requests per second = {}",
+                max_requests_per_second
+            ));
+        }
         res
     }
 }
 
+impl S3Config {
+    pub fn retry_config(&self) -> RetryConfig {
+        RetryConfig {
+            num_tries: self.num_tries,
+            initial_backoff_ms: self.retry_initial_backoff_ms,
+            connect_timeout_ms: self.connect_timeout_ms,
+            read_timeout_ms: self.read_timeout_ms,
+        }
+    }
+}
+
 impl Default for S3Config {
     fn default() -> Self {
         S3Config {
@@ -163,6 +184,8 @@ impl Default for S3Config {
             requester_pays: false,
             force_virtual_addressing: false,
             profile_name: None,
+            verify_checksums: false,
+            max_requests_per_second: None,
         }
     }
 }
@@ -190,7 +213,9 @@ impl Display for S3Config {
     verify_ssl: {},
     check_hostname_ssl: {}
     requester_pays: {}
-    force_virtual_addressing: {}",
+    force_virtual_addressing: {}
+    verify_checksums: {}
+    max_requests_per_second: {:?}",
             self.region_name,
             self.endpoint_url,
             self.key_id,
@@ -209,7 +234,9 @@ impl Display for S3Config {
             self.verify_ssl,
             self.check_hostname_ssl,
             self.requester_pays,
-            self.force_virtual_addressing
+            self.force_virtual_addressing,
+            self.verify_checksums,
+            self.max_requests_per_second
         )?;
         Ok(())
     }
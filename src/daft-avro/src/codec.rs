@@ -0,0 +1,69 @@
+use bytes::Bytes;
+use daft_compression::CompressionCodec;
+use snafu::ResultExt;
+use tokio::io::AsyncReadExt;
+
+use crate::StdIOSnafu;
+
+/// Compression codec of the blocks of an Avro file, from the `avro.codec` metadata of its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Codec {
+    Null,
+    Deflate,
+    Snappy,
+    Bzip2,
+    Xz,
+    Zstandard,
+}
+
+impl Codec {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "null" => Some(Self::Null),
+            "deflate" => Some(Self::Deflate),
+            "snappy" => Some(Self::Snappy),
+            "bzip2" => Some(Self::Bzip2),
+            "xz" => Some(Self::Xz),
+            "zstandard" => Some(Self::Zstandard),
+            _ => None,
+        }
+    }
+
+    /// Decompresses the data of a block.
+    pub async fn decompress(&self, data: Bytes) -> crate::Result<Bytes> {
+        let codec = match self {
+            Self::Null => return Ok(data),
+            Self::Snappy => return decompress_snappy(&data),
+            Self::Deflate => CompressionCodec::Deflate,
+            Self::Bzip2 => CompressionCodec::Bz,
+            Self::Xz => CompressionCodec::Xz,
+            Self::Zstandard => CompressionCodec::Zstd,
+        };
+        let mut decoder = codec.to_decoder(std::io::Cursor::new(data));
+        let mut decompressed = vec![];
+        decoder
+            .read_to_end(&mut decompressed)
+            .await
+            .context(StdIOSnafu)?;
+        Ok(decompressed.into())
+    }
+}
+
+/// Snappy blocks are followed by the big-endian CRC32 checksum of the decompressed data.
+fn decompress_snappy(data: &[u8]) -> crate::Result<Bytes> {
+    let decode_error = |reason: String| crate::Error::DecodeError { reason };
+    let Some((compressed, checksum)) = data.split_last_chunk::<4>() else {
+        return Err(decode_error(
+            "snappy block is missing its checksum".to_string(),
+        ));
+    };
+    let decompressed = snap::raw::Decoder::new()
+        .decompress_vec(compressed)
+        .map_err(|e| decode_error(format!("invalid snappy block: {e}")))?;
+    if crc32fast::hash(&decompressed) != u32::from_be_bytes(*checksum) {
+        return Err(decode_error(
+            "checksum mismatch of snappy block".to_string(),
+        ));
+    }
+    Ok(decompressed.into())
+}
@@ -0,0 +1,104 @@
+use crate::schema::AvroSchema;
+
+pub(crate) fn decode_error(reason: impl Into<String>) -> crate::Error {
+    crate::Error::DecodeError {
+        reason: reason.into(),
+    }
+}
+
+pub(crate) fn take<'a>(buf: &mut &'a [u8], len: usize) -> crate::Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(decode_error("unexpected end of data"));
+    }
+    let (bytes, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(bytes)
+}
+
+/// Reads a zigzag-encoded variable-length long, which Avro uses for both `int` and `long` values.
+pub(crate) fn read_long(buf: &mut &[u8]) -> crate::Result<i64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = take(buf, 1)?[0];
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+        }
+    }
+    Err(decode_error("variable-length long is too long"))
+}
+
+pub(crate) fn read_int(buf: &mut &[u8]) -> crate::Result<i32> {
+    i32::try_from(read_long(buf)?).map_err(|_| decode_error("int value out of range"))
+}
+
+fn read_len(buf: &mut &[u8]) -> crate::Result<usize> {
+    usize::try_from(read_long(buf)?).map_err(|_| decode_error("negative length"))
+}
+
+pub(crate) fn read_bytes<'a>(buf: &mut &'a [u8]) -> crate::Result<&'a [u8]> {
+    let len = read_len(buf)?;
+    take(buf, len)
+}
+
+/// Reads the number of items in the next block of an array or map. Blocks with a negative count are followed by their
+/// size in bytes, which allows them to be skipped without decoding their items.
+pub(crate) fn read_block_header(buf: &mut &[u8]) -> crate::Result<(usize, Option<usize>)> {
+    let count = read_long(buf)?;
+    if count < 0 {
+        Ok((count.unsigned_abs() as usize, Some(read_len(buf)?)))
+    } else {
+        Ok((count as usize, None))
+    }
+}
+
+/// Decodes a big-endian two's-complement integer of at most 16 bytes.
+pub(crate) fn decode_decimal(bytes: &[u8]) -> crate::Result<i128> {
+    if bytes.len() > 16 {
+        return Err(decode_error("decimal value doesn't fit in 128 bits"));
+    }
+    let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+        0xff
+    } else {
+        0
+    };
+    let mut buf = [fill; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(buf))
+}
+
+pub(crate) fn union_branch<'a>(
+    branches: &'a [AvroSchema],
+    buf: &mut &[u8],
+) -> crate::Result<&'a AvroSchema> {
+    let index = read_long(buf)?;
+    usize::try_from(index)
+        .ok()
+        .and_then(|i| branches.get(i))
+        .ok_or_else(|| decode_error(format!("union branch {index} is out of bounds")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_decimal, read_long};
+
+    #[test]
+    fn test_read_zigzag_longs() {
+        let mut buf: &[u8] = &[
+            0x00, 0x01, 0x02, 0x7f, 0x80, 0x01, 0xff, 0xff, 0xff, 0xff, 0x0f,
+        ];
+        let values = (0..5)
+            .map(|_| read_long(&mut buf).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![0, -1, 1, -64, 64]);
+        assert_eq!(read_long(&mut buf).unwrap(), i32::MIN as i64);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_decode_decimal() {
+        assert_eq!(decode_decimal(&[0x01, 0x00]).unwrap(), 256);
+        assert_eq!(decode_decimal(&[0xff, 0x00]).unwrap(), -256);
+        assert_eq!(decode_decimal(&[]).unwrap(), 0);
+    }
+}
@@ -0,0 +1,66 @@
+#![feature(let_chains)]
+use common_error::DaftError;
+use snafu::Snafu;
+
+mod codec;
+mod decode;
+pub mod metadata;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod read;
+pub mod schema;
+pub mod value;
+
+pub use metadata::{read_avro_schema, read_avro_schema_bulk};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use read::AvroReadOptions;
+pub use value::{read_avro_values, AvroValue};
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("{source}"))]
+    StdIOError { source: std::io::Error },
+    #[snafu(display("File: {} is not a valid Avro file: {}", path, reason))]
+    InvalidAvroFile { path: String, reason: String },
+    #[snafu(display("Invalid Avro schema: {}", reason))]
+    InvalidAvroSchema { reason: String },
+    #[snafu(display("Error decoding Avro data: {}", reason))]
+    DecodeError { reason: String },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<Error> for pyo3::PyErr {
+    fn from(value: Error) -> Self {
+        let daft_error: DaftError = value.into();
+        daft_error.into()
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_avro))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_avro_schema))?;
+    Ok(())
+}
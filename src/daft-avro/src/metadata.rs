@@ -0,0 +1,173 @@
+use std::{ops::Range, sync::Arc};
+
+use bytes::Bytes;
+use common_error::DaftResult;
+use daft_core::schema::Schema;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use futures::{StreamExt, TryStreamExt};
+use snafu::ResultExt;
+
+use crate::{
+    codec::Codec,
+    decode::{read_bytes, read_long},
+    schema::{parse_schema, root_record, to_daft_schema, AvroSchema},
+    JoinSnafu,
+};
+
+/// Magic bytes at the start of an Avro object container file.
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+/// Size of the sync marker that follows the header and every block of an Avro file.
+pub(crate) const SYNC_MARKER_SIZE: usize = 16;
+/// Number of bytes to fetch from the start of the file when reading the header. Larger headers are fetched by doubling
+/// the size of the read.
+const DEFAULT_HEADER_READ_SIZE: usize = 64 * 1024;
+
+/// Header of an Avro object container file.
+#[derive(Debug, Clone)]
+pub struct AvroHeader {
+    pub(crate) schema: AvroSchema,
+    pub(crate) codec: Codec,
+    pub(crate) sync_marker: [u8; SYNC_MARKER_SIZE],
+    /// Size of the header in bytes, which is the offset of the first block.
+    pub(crate) header_size: usize,
+    pub(crate) file_size: usize,
+}
+
+pub(crate) async fn fetch_range(
+    uri: &str,
+    range: Range<usize>,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<Bytes> {
+    Ok(io_client
+        .single_url_get(uri.to_string(), Some(range), io_stats)
+        .await?
+        .bytes()
+        .await?)
+}
+
+/// Reads the header of the Avro file at `uri`, which holds the schema and compression codec of the file.
+pub(crate) async fn read_avro_header(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<AvroHeader> {
+    let invalid_file = |reason: String| super::Error::InvalidAvroFile {
+        path: uri.to_string(),
+        reason,
+    };
+    let file_size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    let mut read_size = DEFAULT_HEADER_READ_SIZE.min(file_size);
+    loop {
+        let bytes = fetch_range(uri, 0..read_size, &io_client, io_stats.clone()).await?;
+        if !bytes.starts_with(AVRO_MAGIC) {
+            return Err(invalid_file("missing magic bytes".to_string()));
+        }
+        match parse_header(&bytes[AVRO_MAGIC.len()..]) {
+            Ok((metadata, sync_marker, size)) => {
+                let schema = metadata
+                    .iter()
+                    .find(|(key, _)| key == "avro.schema")
+                    .ok_or_else(|| invalid_file("missing avro.schema metadata".to_string()))?;
+                let schema = parse_schema(&String::from_utf8_lossy(&schema.1))?;
+                let codec = match metadata.iter().find(|(key, _)| key == "avro.codec") {
+                    Some((_, codec)) => {
+                        let codec = String::from_utf8_lossy(codec);
+                        Codec::from_name(&codec).ok_or_else(|| {
+                            invalid_file(format!("unsupported compression codec: {codec}"))
+                        })?
+                    }
+                    None => Codec::Null,
+                };
+                return Ok(AvroHeader {
+                    schema,
+                    codec,
+                    sync_marker,
+                    header_size: AVRO_MAGIC.len() + size,
+                    file_size,
+                });
+            }
+            // The header may not have fit in the initial read.
+            Err(_) if read_size < file_size => read_size = (read_size * 2).min(file_size),
+            Err(e) => return Err(invalid_file(format!("invalid header: {e}"))),
+        }
+    }
+}
+
+type HeaderMetadata = Vec<(String, Vec<u8>)>;
+
+/// Parses the metadata map and sync marker that follow the magic bytes of the header, returning them along with their
+/// size in bytes.
+fn parse_header(bytes: &[u8]) -> super::Result<(HeaderMetadata, [u8; SYNC_MARKER_SIZE], usize)> {
+    let mut buf = bytes;
+    let mut metadata = vec![];
+    loop {
+        let count = read_long(&mut buf)?;
+        if count == 0 {
+            break;
+        }
+        if count < 0 {
+            // Negative block counts are followed by the size of the block in bytes.
+            read_long(&mut buf)?;
+        }
+        for _ in 0..count.unsigned_abs() {
+            let key = String::from_utf8_lossy(read_bytes(&mut buf)?).to_string();
+            let value = read_bytes(&mut buf)?.to_vec();
+            metadata.push((key, value));
+        }
+    }
+    let sync_marker = buf
+        .first_chunk::<SYNC_MARKER_SIZE>()
+        .copied()
+        .ok_or_else(|| super::Error::DecodeError {
+            reason: "unexpected end of data".to_string(),
+        })?;
+    let size = bytes.len() - buf.len() + SYNC_MARKER_SIZE;
+    Ok((metadata, sync_marker, size))
+}
+
+/// Reads the Avro schema of the file at `uri`.
+pub async fn read_avro_metadata(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<AvroSchema> {
+    Ok(read_avro_header(uri, io_client, io_stats).await?.schema)
+}
+
+pub fn read_avro_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let runtime_handle = get_runtime(true)?;
+    let schema = runtime_handle
+        .block_on_current_thread(async { read_avro_metadata(uri, io_client, io_stats).await })?;
+    to_daft_schema(root_record(&schema)?)
+}
+
+pub fn read_avro_schema_bulk(
+    uris: &[&str],
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Schema>> {
+    let runtime_handle = get_runtime(true)?;
+    let schemas = runtime_handle.block_on_current_thread(async move {
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, io_client, io_stats) = (uri.to_string(), io_client.clone(), io_stats.clone());
+            tokio::task::spawn(async move { read_avro_metadata(&uri, io_client, io_stats).await })
+        }));
+        task_stream
+            .buffered(num_parallel_tasks)
+            .try_collect::<Vec<_>>()
+            .await
+            .context(JoinSnafu)
+    })?;
+    schemas
+        .into_iter()
+        .map(|schema| to_daft_schema(root_record(&schema?)?))
+        .collect()
+}
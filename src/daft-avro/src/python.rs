@@ -0,0 +1,62 @@
+pub mod pylib {
+    use std::sync::Arc;
+
+    use daft_core::python::schema::PySchema;
+    use daft_dsl::python::PyExpr;
+    use daft_file_reader::{read_file, ReadPushdowns};
+    use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
+    use daft_table::python::PyTable;
+    use pyo3::{pyfunction, PyResult, Python};
+
+    use crate::AvroReadOptions;
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyfunction]
+    pub fn read_avro(
+        py: Python,
+        uri: &str,
+        columns: Option<Vec<&str>>,
+        num_rows: Option<usize>,
+        byte_range: Option<(usize, usize)>,
+        predicate: Option<PyExpr>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PyTable> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_avro: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            Ok(read_file(
+                &AvroReadOptions { byte_range },
+                uri,
+                ReadPushdowns::new(columns.as_deref(), num_rows, predicate.map(|e| e.expr)),
+                io_client,
+                Some(io_stats),
+                multithreaded_io.unwrap_or(true),
+            )?
+            .into())
+        })
+    }
+
+    #[pyfunction]
+    pub fn read_avro_schema(
+        py: Python,
+        uri: &str,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PySchema> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_avro_schema: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let schema = crate::metadata::read_avro_schema(uri, io_client, Some(io_stats))?;
+            Ok(Arc::new(schema).into())
+        })
+    }
+}
@@ -0,0 +1,621 @@
+use std::sync::Arc;
+
+use arrow2::{
+    datatypes::DataType as ArrowType,
+    io::avro::{avro_schema::file::Block, read::deserialize},
+};
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use common_error::DaftResult;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_file_reader::{FileFormatReader, ReadPushdowns};
+use daft_io::{IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{stream::BoxStream, StreamExt};
+use snafu::ResultExt;
+
+use crate::{
+    decode::read_long,
+    metadata::{fetch_range, read_avro_header, AvroHeader, SYNC_MARKER_SIZE},
+    schema::{
+        arrow_record, cast_to_daft_compatible, daft_compatible_fields, root_record, to_daft_schema,
+    },
+    JoinSnafu,
+};
+
+/// Number of blocks of a file that are decompressed and decoded concurrently.
+const NUM_BLOCKS_IN_FLIGHT: usize = 8;
+/// Minimum number of bytes fetched by each ranged read of a file's blocks.
+const MIN_FETCH_SIZE: usize = 4 * 1024 * 1024;
+/// Maximum size of the header of a block, made up of two variable-length longs.
+const MAX_BLOCK_HEADER_SIZE: usize = 20;
+
+/// Reader of the blocks of an Avro file, which fetches the file with ranged reads of at least [`MIN_FETCH_SIZE`] bytes.
+pub(crate) struct BlockReader {
+    uri: String,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    header: Arc<AvroHeader>,
+    buffer: Bytes,
+    buffer_start: usize,
+}
+
+impl BlockReader {
+    pub fn new(
+        uri: &str,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+        header: Arc<AvroHeader>,
+    ) -> Self {
+        Self {
+            uri: uri.to_string(),
+            io_client,
+            io_stats,
+            header,
+            buffer: Bytes::new(),
+            buffer_start: 0,
+        }
+    }
+
+    /// Returns the bytes of the file in `[start, start + len)`, or fewer bytes if the range extends past the end of the
+    /// file.
+    async fn read(&mut self, start: usize, len: usize) -> crate::Result<Bytes> {
+        let end = (start + len).min(self.header.file_size);
+        if start >= end {
+            return Ok(Bytes::new());
+        }
+        let buffer_end = self.buffer_start + self.buffer.len();
+        if start < self.buffer_start || end > buffer_end {
+            let fetch_end = end.max(start + MIN_FETCH_SIZE).min(self.header.file_size);
+            // Keep the part of the buffer that overlaps the requested range, so that it isn't fetched again.
+            let (fetch_start, mut buffer) = if (self.buffer_start..buffer_end).contains(&start) {
+                let mut buffer = BytesMut::with_capacity(fetch_end - start);
+                buffer.extend_from_slice(&self.buffer[start - self.buffer_start..]);
+                (buffer_end, buffer)
+            } else {
+                (start, BytesMut::with_capacity(fetch_end - start))
+            };
+            let bytes = fetch_range(
+                &self.uri,
+                fetch_start..fetch_end,
+                &self.io_client,
+                self.io_stats.clone(),
+            )
+            .await?;
+            buffer.extend_from_slice(&bytes);
+            self.buffer = buffer.freeze();
+            self.buffer_start = start;
+        }
+        Ok(self
+            .buffer
+            .slice(start - self.buffer_start..end - self.buffer_start))
+    }
+
+    /// Returns the offset of the first block that starts at or after `start`, by searching for the sync marker that
+    /// precedes it.
+    async fn find_first_block(&mut self, start: usize, end: usize) -> crate::Result<Option<usize>> {
+        let header_size = self.header.header_size;
+        if start <= header_size {
+            return Ok(Some(header_size));
+        }
+        let mut position = (start - SYNC_MARKER_SIZE).max(header_size);
+        // Blocks that start at or after `end` belong to the next range.
+        while position + SYNC_MARKER_SIZE < end {
+            let bytes = self.read(position, MIN_FETCH_SIZE).await?;
+            if bytes.len() < SYNC_MARKER_SIZE {
+                break;
+            }
+            if let Some(offset) = bytes
+                .windows(SYNC_MARKER_SIZE)
+                .position(|w| w == self.header.sync_marker)
+            {
+                return Ok(Some(position + offset + SYNC_MARKER_SIZE));
+            }
+            position += bytes.len() - SYNC_MARKER_SIZE + 1;
+        }
+        Ok(None)
+    }
+
+    /// Reads the block at `position`, returning its number of records, its compressed data and the offset of the next
+    /// block.
+    pub async fn read_block(&mut self, position: usize) -> crate::Result<(usize, Bytes, usize)> {
+        let uri = self.uri.clone();
+        let invalid_block = |reason: &str| crate::Error::InvalidAvroFile {
+            path: uri.clone(),
+            reason: format!("invalid block at offset {position}: {reason}"),
+        };
+        let header = self.read(position, MAX_BLOCK_HEADER_SIZE).await?;
+        let mut buf = header.as_ref();
+        let count = read_long(&mut buf)?;
+        let size = read_long(&mut buf)?;
+        let (Ok(count), Ok(size)) = (usize::try_from(count), usize::try_from(size)) else {
+            return Err(invalid_block("negative record count or size"));
+        };
+        let data_start = position + header.len() - buf.len();
+        let bytes = self.read(data_start, size + SYNC_MARKER_SIZE).await?;
+        if bytes.len() < size + SYNC_MARKER_SIZE {
+            return Err(invalid_block("unexpected end of file"));
+        }
+        if bytes[size..] != self.header.sync_marker {
+            return Err(invalid_block("sync marker mismatch"));
+        }
+        Ok((
+            count,
+            bytes.slice(..size),
+            data_start + size + SYNC_MARKER_SIZE,
+        ))
+    }
+}
+
+/// Options for reading Avro files, which are read as a table per block.
+#[derive(Clone, Debug, Default)]
+pub struct AvroReadOptions {
+    /// Byte range `[start, end)` of the file to read, which is read as the blocks that start within the range.
+    pub byte_range: Option<(usize, usize)>,
+}
+
+#[async_trait]
+impl FileFormatReader for AvroReadOptions {
+    const FORMAT_NAME: &'static str = "Avro";
+
+    type Metadata = Arc<AvroHeader>;
+
+    async fn read_metadata(
+        &self,
+        uri: &str,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<(Self::Metadata, SchemaRef)> {
+        let header = read_avro_header(uri, io_client, io_stats).await?;
+        let schema = Arc::new(to_daft_schema(root_record(&header.schema)?)?);
+        Ok((Arc::new(header), schema))
+    }
+
+    async fn decode(
+        &self,
+        uri: &str,
+        header: Self::Metadata,
+        read_schema: SchemaRef,
+        _pushdowns: &ReadPushdowns,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+        let file_record = root_record(&header.schema)?;
+        let (record, arrow_fields) = arrow_record(file_record)?;
+        let projection: Arc<[bool]> = record
+            .fields
+            .iter()
+            .map(|f| read_schema.fields.contains_key(&f.name))
+            .collect();
+        let daft_types: Arc<[ArrowType]> = daft_compatible_fields(file_record, &arrow_fields)
+            .into_iter()
+            .zip(projection.iter())
+            .filter(|(_, projected)| **projected)
+            .map(|(f, _)| f.data_type)
+            .collect();
+        let (record, arrow_fields) = (Arc::new(record), Arc::new(arrow_fields));
+
+        let (start, end) = self.byte_range.unwrap_or((0, header.file_size));
+        let end = end.min(header.file_size);
+        let mut reader = BlockReader::new(uri, io_client, io_stats, header.clone());
+        let first_block = reader.find_first_block(start, end).await?;
+
+        let blocks = futures::stream::try_unfold(
+            (reader, first_block),
+            move |(mut reader, position)| async move {
+                match position {
+                    Some(position) if position < end && position < reader.header.file_size => {
+                        let (count, data, next_position) = reader.read_block(position).await?;
+                        Ok(Some(((count, data), (reader, Some(next_position)))))
+                    }
+                    _ => Ok(None),
+                }
+            },
+        );
+        let stream = blocks
+            .map(move |block: crate::Result<(usize, Bytes)>| {
+                let (header, record, arrow_fields, projection, daft_types, read_schema) = (
+                    header.clone(),
+                    record.clone(),
+                    arrow_fields.clone(),
+                    projection.clone(),
+                    daft_types.clone(),
+                    read_schema.clone(),
+                );
+                async move {
+                    let (count, data) = block?;
+                    let data = header.codec.decompress(data).await?;
+                    tokio::task::spawn_blocking(move || {
+                        let block = Block::new(count, data.into());
+                        let chunk =
+                            deserialize(&block, &arrow_fields, &record.fields, &projection)?;
+                        let columns = chunk
+                            .into_arrays()
+                            .into_iter()
+                            .zip(daft_types.iter())
+                            .zip(read_schema.fields.values())
+                            .map(|((array, data_type), field)| {
+                                Series::try_from_field_and_arrow_array(
+                                    Arc::new(field.clone()),
+                                    cast_array_for_daft_if_needed(cast_to_daft_compatible(
+                                        array, data_type,
+                                    )?),
+                                )
+                            })
+                            .collect::<DaftResult<Vec<_>>>()?;
+                        Table::new_with_size(read_schema, columns, count)
+                    })
+                    .await
+                    .context(JoinSnafu)?
+                }
+            })
+            .buffered(NUM_BLOCKS_IN_FLIGHT);
+        Ok(stream.boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_file_reader::{read_file, read_files, ReadPushdowns};
+    use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+    use rstest::rstest;
+
+    use super::AvroReadOptions;
+    use crate::read_avro_schema;
+
+    const SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Row",
+        "fields": [{"name": "id", "type": "long"}, {"name": "name", "type": ["null", "string"]}]
+    }"#;
+    const SYNC_MARKER: [u8; 16] = *b"0123456789abcdef";
+
+    fn write_long(out: &mut Vec<u8>, value: i64) {
+        let mut n = ((value << 1) ^ (value >> 63)) as u64;
+        while n >= 0x80 {
+            out.push((n as u8) | 0x80);
+            n >>= 7;
+        }
+        out.push(n as u8);
+    }
+
+    fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+        write_long(out, bytes.len() as i64);
+        out.extend_from_slice(bytes);
+    }
+
+    fn compress(codec: &str, data: Vec<u8>) -> Vec<u8> {
+        match codec {
+            "null" => data,
+            "snappy" => {
+                let mut compressed = snap::raw::Encoder::new().compress_vec(&data).unwrap();
+                compressed.extend_from_slice(&crc32fast::hash(&data).to_be_bytes());
+                compressed
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Writes an Avro file with the given blocks of encoded records, returning the offsets at which the blocks start.
+    fn write_avro_file(
+        dir: &tempfile::TempDir,
+        schema: &str,
+        codec: &str,
+        blocks: Vec<(i64, Vec<u8>)>,
+    ) -> (String, Vec<usize>) {
+        let mut out = b"Obj\x01".to_vec();
+        write_long(&mut out, 2);
+        write_bytes(&mut out, b"avro.schema");
+        write_bytes(&mut out, schema.as_bytes());
+        write_bytes(&mut out, b"avro.codec");
+        write_bytes(&mut out, codec.as_bytes());
+        write_long(&mut out, 0);
+        out.extend_from_slice(&SYNC_MARKER);
+        let mut offsets = vec![];
+        for (num_rows, data) in blocks {
+            offsets.push(out.len());
+            let data = compress(codec, data);
+            write_long(&mut out, num_rows);
+            write_long(&mut out, data.len() as i64);
+            out.extend_from_slice(&data);
+            out.extend_from_slice(&SYNC_MARKER);
+        }
+        let path = dir.path().join("test.avro");
+        std::fs::write(&path, out).unwrap();
+        (path.to_string_lossy().to_string(), offsets)
+    }
+
+    /// Writes an Avro file with a block per range of ids, returning the offsets at which the blocks start.
+    fn write_test_file(
+        dir: &tempfile::TempDir,
+        codec: &str,
+        blocks: &[(i64, i64)],
+    ) -> (String, Vec<usize>) {
+        let blocks = blocks
+            .iter()
+            .map(|&(start, num_rows)| {
+                let mut data = vec![];
+                for id in start..start + num_rows {
+                    write_long(&mut data, id);
+                    // The union branch of the name.
+                    write_long(&mut data, 1);
+                    write_bytes(&mut data, format!("name{id}").as_bytes());
+                }
+                (num_rows, data)
+            })
+            .collect();
+        write_avro_file(dir, SCHEMA, codec, blocks)
+    }
+
+    fn make_table(start: i64, num_rows: i64) -> DaftResult<Table> {
+        let ids = (start..start + num_rows).collect::<Vec<_>>();
+        let names = ids.iter().map(|i| format!("name{i}")).collect::<Vec<_>>();
+        Table::from_nonempty_columns(vec![
+            Int64Array::from(("id", ids)).into_series(),
+            Utf8Array::from((
+                "name",
+                names
+                    .iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            ))
+            .into_series(),
+        ])
+    }
+
+    fn assert_tables_eq(left: &Table, right: &Table) {
+        assert_eq!(left.schema, right.schema);
+        assert_eq!(left.len(), right.len());
+        for i in 0..left.num_columns() {
+            assert_eq!(
+                left.get_column_by_index(i).unwrap(),
+                right.get_column_by_index(i).unwrap()
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_avro_roundtrip(#[values("null", "snappy")] codec: &str) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let (uri, _) = write_test_file(&dir, codec, &[(0, 10), (10, 5), (15, 10)]);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let expected = make_table(0, 25)?;
+        let schema = read_avro_schema(&uri, io_client.clone(), None)?;
+        assert_eq!(&schema, expected.schema.as_ref());
+
+        let table = read_file(
+            &AvroReadOptions::default(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_tables_eq(&table, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_avro_read_pushdowns() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let (uri, offsets) = write_test_file(&dir, "null", &[(0, 10), (10, 5), (15, 10)]);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        // Projection reorders the columns.
+        let table = read_file(
+            &AvroReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(Some(&["name", "id"]), None, None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["name", "id"]);
+        assert_eq!(table.len(), 25);
+
+        let table = read_file(
+            &AvroReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(None, Some(12), None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_tables_eq(&table, &make_table(0, 12)?);
+
+        // Predicate columns don't have to be in the projection.
+        let table = read_file(
+            &AvroReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(Some(&["name"]), None, Some(col("id").gt_eq(lit(20)))),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["name"]);
+        assert_eq!(table.len(), 5);
+
+        // A byte range reads the blocks that start within it, so adjacent ranges read each block exactly once.
+        let split = offsets[1] + 1;
+        let options = AvroReadOptions {
+            byte_range: Some((0, split)),
+        };
+        let tables = read_files(
+            vec![(uri.as_str(), options.clone()), (uri.as_str(), options)],
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            2,
+            true,
+        )?;
+        assert_tables_eq(&tables[0], &make_table(0, 15)?);
+        let table = read_file(
+            &AvroReadOptions {
+                byte_range: Some((split, usize::MAX)),
+            },
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_tables_eq(&table, &make_table(15, 10)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_avro_read_enums() -> DaftResult<()> {
+        let schema = r#"{
+            "type": "record",
+            "name": "Row",
+            "fields": [
+                {"name": "kind", "type": ["null", {"type": "enum", "name": "Kind", "symbols": ["A", "B"]}]},
+                {"name": "kinds", "type": {"type": "array", "items": "Kind"}},
+                {"name": "point", "type": {
+                    "type": "record",
+                    "name": "Point",
+                    "fields": [{"name": "kind", "type": "Kind"}]
+                }}
+            ]
+        }"#;
+        let mut data = vec![];
+        // kind = "B", kinds = ["A", "B"], point = {kind: "A"}
+        for value in [1, 1, 2, 0, 1, 0, 0] {
+            write_long(&mut data, value);
+        }
+        // kind = null, kinds = [], point = {kind: "B"}
+        for value in [0, 0, 1] {
+            write_long(&mut data, value);
+        }
+        let dir = tempfile::tempdir()?;
+        let (uri, _) = write_avro_file(&dir, schema, "null", vec![(2, data)]);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_file(
+            &AvroReadOptions::default(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(
+            table.schema.as_ref(),
+            &Schema::new(vec![
+                Field::new("kind", DataType::Utf8),
+                Field::new("kinds", DataType::List(Box::new(DataType::Utf8))),
+                Field::new(
+                    "point",
+                    DataType::Struct(vec![Field::new("kind", DataType::Utf8)])
+                ),
+            ])?
+        );
+        let kind = table.get_column("kind")?;
+        assert_eq!(kind.utf8()?.get(0), Some("B"));
+        assert_eq!(kind.utf8()?.get(1), None);
+        let kinds = table.get_column("kinds")?.list()?;
+        assert_eq!(
+            kinds
+                .get(0)
+                .unwrap()
+                .utf8()?
+                .as_arrow()
+                .values_iter()
+                .collect::<Vec<_>>(),
+            vec!["A", "B"]
+        );
+        assert_eq!(kinds.get(1).unwrap().len(), 0);
+        let point_kinds = table.get_column("point")?.struct_()?.children[0].clone();
+        assert_eq!(point_kinds.utf8()?.get(0), Some("A"));
+        assert_eq!(point_kinds.utf8()?.get(1), Some("B"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_avro_read_maps() -> DaftResult<()> {
+        let schema = r#"{
+            "type": "record",
+            "name": "Row",
+            "fields": [
+                {"name": "counts", "type": {"type": "map", "values": "long"}},
+                {"name": "kinds", "type": ["null", {
+                    "type": "map",
+                    "values": {"type": "enum", "name": "Kind", "symbols": ["A", "B"]}
+                }]}
+            ]
+        }"#;
+        let mut data = vec![];
+        // counts = {"a": 1, "b": 2}, kinds = {"x": "B"}
+        write_long(&mut data, 2);
+        write_bytes(&mut data, b"a");
+        write_long(&mut data, 1);
+        write_bytes(&mut data, b"b");
+        write_long(&mut data, 2);
+        write_long(&mut data, 0);
+        write_long(&mut data, 1);
+        write_long(&mut data, 1);
+        write_bytes(&mut data, b"x");
+        write_long(&mut data, 1);
+        write_long(&mut data, 0);
+        // counts = {"b": 3} in a block with its size in bytes, kinds = null
+        write_long(&mut data, -1);
+        write_long(&mut data, 3);
+        write_bytes(&mut data, b"b");
+        write_long(&mut data, 3);
+        write_long(&mut data, 0);
+        write_long(&mut data, 0);
+        let dir = tempfile::tempdir()?;
+        let (uri, _) = write_avro_file(&dir, schema, "null", vec![(2, data)]);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let expected_schema = Schema::new(vec![
+            Field::new(
+                "counts",
+                DataType::Map(Box::new(DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8),
+                    Field::new("value", DataType::Int64),
+                ]))),
+            ),
+            Field::new(
+                "kinds",
+                DataType::Map(Box::new(DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8),
+                    Field::new("value", DataType::Utf8),
+                ]))),
+            ),
+        ])?;
+        assert_eq!(
+            read_avro_schema(&uri, io_client.clone(), None)?,
+            expected_schema
+        );
+        let table = read_file(
+            &AvroReadOptions::default(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.as_ref(), &expected_schema);
+        let key = |k: &str| Utf8Array::from(("key", [k].as_slice())).into_series();
+        let counts = table.get_column("counts")?;
+        let a_counts = counts.map_get(&key("a"))?;
+        assert_eq!(a_counts.i64()?.get(0), Some(1));
+        assert_eq!(a_counts.i64()?.get(1), None);
+        let b_counts = counts.map_get(&key("b"))?;
+        assert_eq!(b_counts.i64()?.get(0), Some(2));
+        assert_eq!(b_counts.i64()?.get(1), Some(3));
+        let kinds = table.get_column("kinds")?;
+        assert_eq!(kinds.map_get(&key("x"))?.utf8()?.get(0), Some("B"));
+        assert!(!kinds.validity().unwrap().get_bit(1));
+        Ok(())
+    }
+}
@@ -0,0 +1,472 @@
+use std::collections::HashMap;
+
+pub use arrow2::io::avro::avro_schema::schema::{
+    Field as AvroField, Record as AvroRecord, Schema as AvroSchema,
+};
+use arrow2::{
+    array::{Array, ListArray, MapArray, StructArray},
+    compute::cast,
+    datatypes::{DataType as ArrowType, Field as ArrowField, TimeUnit as ArrowTimeUnit},
+    io::avro::{
+        avro_schema::schema::{Fixed as AvroFixed, FixedLogical},
+        read::infer_schema,
+    },
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use serde_json::{Map, Value};
+
+const PRIMITIVE_TYPES: [&str; 8] = [
+    "null", "boolean", "int", "long", "float", "double", "bytes", "string",
+];
+
+fn invalid_schema(reason: String) -> crate::Error {
+    crate::Error::InvalidAvroSchema { reason }
+}
+
+/// Parses an Avro schema from its JSON representation.
+///
+/// `avro_schema` only understands self-contained schemas, so references to named types are first replaced by the
+/// definitions of those types, and the field defaults, which it would otherwise parse as schemas, are dropped.
+pub fn parse_schema(json: &str) -> crate::Result<AvroSchema> {
+    let value: Value = serde_json::from_str(json).map_err(|e| invalid_schema(e.to_string()))?;
+    let value = SchemaResolver::default().resolve(value, None)?;
+    serde_json::from_value(value).map_err(|e| invalid_schema(e.to_string()))
+}
+
+/// Resolver of the references to named types in an Avro schema.
+#[derive(Default)]
+struct SchemaResolver {
+    named_types: HashMap<String, Value>,
+}
+
+impl SchemaResolver {
+    fn resolve(&mut self, value: Value, namespace: Option<&str>) -> crate::Result<Value> {
+        match value {
+            Value::String(name) if PRIMITIVE_TYPES.contains(&name.as_str()) => {
+                Ok(Value::String(name))
+            }
+            Value::String(name) => namespace
+                .and_then(|ns| self.named_types.get(&format!("{ns}.{name}")))
+                .or_else(|| self.named_types.get(&name))
+                .cloned()
+                .ok_or_else(|| invalid_schema(format!("unknown type: {name}"))),
+            Value::Array(branches) => Ok(Value::Array(
+                branches
+                    .into_iter()
+                    .map(|b| self.resolve(b, namespace))
+                    .collect::<crate::Result<_>>()?,
+            )),
+            Value::Object(object) => self.resolve_object(object, namespace),
+            _ => Err(invalid_schema(format!("unexpected schema: {value}"))),
+        }
+    }
+
+    fn resolve_object(
+        &mut self,
+        mut object: Map<String, Value>,
+        namespace: Option<&str>,
+    ) -> crate::Result<Value> {
+        let type_name = match object.get("type") {
+            Some(Value::String(type_name)) => type_name.clone(),
+            // The type is itself a schema, such as `{"type": {"type": "array", ...}}`.
+            Some(_) => return self.resolve(object.remove("type").unwrap(), namespace),
+            None => {
+                return Err(invalid_schema(format!(
+                    "missing type in schema: {object:?}"
+                )))
+            }
+        };
+        match type_name.as_str() {
+            "record" | "error" => {
+                let (full_name, namespace) = full_name(&object, namespace)?;
+                let fields = match object.remove("fields") {
+                    Some(Value::Array(fields)) => fields,
+                    _ => return Err(invalid_schema(format!("record {full_name} has no fields"))),
+                };
+                let fields = fields
+                    .into_iter()
+                    .map(|field| {
+                        let Value::Object(mut field) = field else {
+                            return Err(invalid_schema(format!(
+                                "invalid field in record {full_name}: {field}"
+                            )));
+                        };
+                        field.remove("default");
+                        let field_type = field.remove("type").ok_or_else(|| {
+                            invalid_schema(format!("field of record {full_name} has no type"))
+                        })?;
+                        field.insert(
+                            "type".to_string(),
+                            self.resolve(field_type, namespace.as_deref())?,
+                        );
+                        Ok(Value::Object(field))
+                    })
+                    .collect::<crate::Result<_>>()?;
+                object.insert("type".to_string(), Value::String("record".to_string()));
+                object.insert("fields".to_string(), Value::Array(fields));
+                let value = Value::Object(object);
+                self.named_types.insert(full_name, value.clone());
+                Ok(value)
+            }
+            "enum" | "fixed" => {
+                let (full_name, _) = full_name(&object, namespace)?;
+                object.remove("default");
+                let value = Value::Object(object);
+                self.named_types.insert(full_name, value.clone());
+                Ok(value)
+            }
+            "array" => {
+                let items = object
+                    .remove("items")
+                    .ok_or_else(|| invalid_schema("array schema has no items".to_string()))?;
+                object.insert("items".to_string(), self.resolve(items, namespace)?);
+                Ok(Value::Object(object))
+            }
+            "map" => {
+                let values = object
+                    .remove("values")
+                    .ok_or_else(|| invalid_schema("map schema has no values".to_string()))?;
+                object.insert("values".to_string(), self.resolve(values, namespace)?);
+                Ok(Value::Object(object))
+            }
+            name if PRIMITIVE_TYPES.contains(&name) => Ok(Value::Object(object)),
+            // A reference to a named type, possibly annotated with attributes that don't apply to it.
+            name => self.resolve(Value::String(name.to_string()), namespace),
+        }
+    }
+}
+
+/// Returns the full name of a named type and the namespace of the types that it contains.
+fn full_name(
+    object: &Map<String, Value>,
+    namespace: Option<&str>,
+) -> crate::Result<(String, Option<String>)> {
+    let name = object
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid_schema(format!("named type without a name: {object:?}")))?;
+    if let Some((namespace, _)) = name.rsplit_once('.') {
+        return Ok((name.to_string(), Some(namespace.to_string())));
+    }
+    let namespace = object
+        .get("namespace")
+        .and_then(Value::as_str)
+        .or(namespace)
+        .filter(|ns| !ns.is_empty());
+    Ok((
+        namespace.map_or_else(|| name.to_string(), |ns| format!("{ns}.{name}")),
+        namespace.map(str::to_string),
+    ))
+}
+
+/// Checks that arrow2 can deserialize values of `schema` into arrays that Daft can read, which excludes unions of more
+/// than one non-null type, durations and nulls.
+fn check_supported(schema: &AvroSchema) -> DaftResult<()> {
+    let unsupported = |what: &str| {
+        Err(DaftError::TypeError(format!(
+            "Reading Avro {what} is not supported: {schema:?}"
+        )))
+    };
+    match schema {
+        AvroSchema::Null => unsupported("null fields"),
+        AvroSchema::Fixed(AvroFixed {
+            logical: Some(FixedLogical::Duration),
+            ..
+        }) => unsupported("durations"),
+        AvroSchema::Array(items) => check_supported(items),
+        AvroSchema::Map(values) => check_supported(values),
+        AvroSchema::Record(record) => record
+            .fields
+            .iter()
+            .try_for_each(|f| check_supported(&f.schema)),
+        AvroSchema::Union(branches) => match branches.as_slice() {
+            [AvroSchema::Null, branch] | [branch, AvroSchema::Null]
+                if *branch != AvroSchema::Null =>
+            {
+                check_supported(branch)
+            }
+            _ => unsupported("unions of more than one non-null type"),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Returns the root record of the schema of an Avro file, whose fields are read as columns.
+pub fn root_record(schema: &AvroSchema) -> DaftResult<&AvroRecord> {
+    match schema {
+        AvroSchema::Record(record) => Ok(record),
+        _ => Err(DaftError::TypeError(format!(
+            "Only Avro files with a record schema can be read, found: {schema:?}"
+        ))),
+    }
+}
+
+/// Returns the record of the key and value of an entry of a map with values of the schema `values`.
+fn map_entries(values: AvroSchema) -> AvroRecord {
+    AvroRecord::new(
+        "entries",
+        vec![
+            AvroField::new("key", AvroSchema::String(None)),
+            AvroField::new("value", values),
+        ],
+    )
+}
+
+/// Replaces the maps in `schema` by arrays of their entries.
+///
+/// arrow2 can't deserialize maps, but a map is encoded the same way as an array of records of its keys and values, so
+/// maps are deserialized as such arrays and then converted to maps by [`cast_to_daft_compatible`].
+fn maps_as_arrays(schema: &AvroSchema) -> AvroSchema {
+    match schema {
+        AvroSchema::Map(values) => AvroSchema::Array(Box::new(AvroSchema::Record(map_entries(
+            maps_as_arrays(values),
+        )))),
+        AvroSchema::Array(items) => AvroSchema::Array(Box::new(maps_as_arrays(items))),
+        AvroSchema::Union(branches) => {
+            AvroSchema::Union(branches.iter().map(maps_as_arrays).collect())
+        }
+        AvroSchema::Record(record) => AvroSchema::Record(AvroRecord {
+            fields: record
+                .fields
+                .iter()
+                .map(|f| AvroField {
+                    schema: maps_as_arrays(&f.schema),
+                    ..f.clone()
+                })
+                .collect(),
+            ..record.clone()
+        }),
+        _ => schema.clone(),
+    }
+}
+
+/// Returns the record that arrow2 deserializes the records of `record` with, and the Arrow fields that it deserializes
+/// its fields into.
+pub(crate) fn arrow_record(record: &AvroRecord) -> DaftResult<(AvroRecord, Vec<ArrowField>)> {
+    record
+        .fields
+        .iter()
+        .try_for_each(|f| check_supported(&f.schema))?;
+    let AvroSchema::Record(record) = maps_as_arrays(&AvroSchema::Record(record.clone())) else {
+        unreachable!("maps_as_arrays preserves records");
+    };
+    let fields = infer_schema(&record)?.fields;
+    Ok((record, fields))
+}
+
+/// Returns the Daft schema of the records of an Avro file with the schema `record`.
+pub fn to_daft_schema(record: &AvroRecord) -> DaftResult<Schema> {
+    let (_, arrow_fields) = arrow_record(record)?;
+    Schema::new(
+        daft_compatible_fields(record, &arrow_fields)
+            .iter()
+            .map(|f| Field::new(f.name.as_str(), (&f.data_type).into()))
+            .collect(),
+    )
+}
+
+/// Returns the fields that the arrays deserialized by arrow2 from the fields of `record` are cast to before being read by
+/// Daft, given the `arrow_fields` that arrow2 deserializes them into.
+pub(crate) fn daft_compatible_fields(
+    record: &AvroRecord,
+    arrow_fields: &[ArrowField],
+) -> Vec<ArrowField> {
+    record
+        .fields
+        .iter()
+        .zip(arrow_fields)
+        .map(|(avro_field, f)| {
+            ArrowField::new(
+                f.name.clone(),
+                daft_compatible_type(&avro_field.schema, &f.data_type),
+                f.is_nullable,
+            )
+        })
+        .collect()
+}
+
+/// Returns the type that arrays of `data_type`, deserialized by arrow2 from values of `schema`, have to be cast to
+/// before being read by Daft.
+///
+/// Maps are read as maps rather than arrays of entries, enums as strings rather than dictionaries, times as
+/// microseconds, and timestamps that are adjusted to UTC have the UTC timezone.
+fn daft_compatible_type(schema: &AvroSchema, data_type: &ArrowType) -> ArrowType {
+    match (schema, data_type) {
+        (AvroSchema::Union(branches), _) => match branches.as_slice() {
+            [AvroSchema::Null, branch] | [branch, AvroSchema::Null] => {
+                daft_compatible_type(branch, data_type)
+            }
+            _ => data_type.clone(),
+        },
+        (AvroSchema::Map(values), ArrowType::List(entries)) => {
+            let entries_schema = AvroSchema::Record(map_entries(values.as_ref().clone()));
+            ArrowType::Map(
+                Box::new(ArrowField::new(
+                    entries.name.clone(),
+                    daft_compatible_type(&entries_schema, entries.data_type()),
+                    false,
+                )),
+                false,
+            )
+        }
+        (AvroSchema::Array(items), ArrowType::List(field)) => {
+            ArrowType::List(Box::new(ArrowField::new(
+                field.name.clone(),
+                daft_compatible_type(items, field.data_type()),
+                field.is_nullable,
+            )))
+        }
+        (AvroSchema::Record(record), ArrowType::Struct(fields)) => {
+            ArrowType::Struct(daft_compatible_fields(record, fields))
+        }
+        (_, ArrowType::Dictionary(..)) => ArrowType::Utf8,
+        (_, ArrowType::Time32(_)) => ArrowType::Time64(ArrowTimeUnit::Microsecond),
+        (_, ArrowType::Timestamp(unit, Some(tz))) if tz == "00:00" => {
+            ArrowType::Timestamp(*unit, Some("UTC".to_string()))
+        }
+        _ => data_type.clone(),
+    }
+}
+
+/// Casts an array deserialized by arrow2 to the type that Daft reads it as, see [`daft_compatible_type`].
+pub(crate) fn cast_to_daft_compatible(
+    array: Box<dyn Array>,
+    data_type: &ArrowType,
+) -> DaftResult<Box<dyn Array>> {
+    if array.data_type() == data_type {
+        return Ok(array);
+    }
+    Ok(match (array.data_type(), data_type) {
+        (ArrowType::List(_), ArrowType::Map(entries, _)) => {
+            let list = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Box::new(MapArray::try_new(
+                data_type.clone(),
+                list.offsets().clone(),
+                cast_to_daft_compatible(list.values().clone(), entries.data_type())?,
+                list.validity().cloned(),
+            )?)
+        }
+        (ArrowType::List(_), ArrowType::List(field)) => {
+            let list = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Box::new(ListArray::<i32>::try_new(
+                data_type.clone(),
+                list.offsets().clone(),
+                cast_to_daft_compatible(list.values().clone(), field.data_type())?,
+                list.validity().cloned(),
+            )?)
+        }
+        (ArrowType::Struct(_), ArrowType::Struct(fields)) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            Box::new(StructArray::try_new(
+                data_type.clone(),
+                array
+                    .values()
+                    .iter()
+                    .zip(fields)
+                    .map(|(values, f)| cast_to_daft_compatible(values.clone(), f.data_type()))
+                    .collect::<DaftResult<_>>()?,
+                array.validity().cloned(),
+            )?)
+        }
+        _ => cast::cast(array.as_ref(), data_type, Default::default())?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+
+    use super::{parse_schema, to_daft_schema, AvroSchema};
+
+    fn parse_record(json: &str) -> DaftResult<super::AvroRecord> {
+        match parse_schema(json)? {
+            AvroSchema::Record(record) => Ok(record),
+            schema => panic!("expected a record schema, found: {schema:?}"),
+        }
+    }
+
+    #[test]
+    fn test_avro_schema_to_daft_schema() -> DaftResult<()> {
+        let record = parse_record(
+            r#"{
+                "type": "record",
+                "name": "Event",
+                "namespace": "com.example",
+                "fields": [
+                    {"name": "id", "type": "long", "default": 0},
+                    {"name": "name", "type": ["null", "string"], "default": null},
+                    {"name": "kind", "type": {"type": "enum", "name": "Kind", "symbols": ["A", "B"]}},
+                    {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+                    {"name": "local_ts", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+                    {"name": "day", "type": {"type": "int", "logicalType": "date"}},
+                    {"name": "time", "type": {"type": "int", "logicalType": "time-millis"}},
+                    {"name": "price", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+                    {"name": "tags", "type": {"type": "array", "items": "string"}},
+                    {"name": "attrs", "type": {"type": "map", "values": ["null", "Kind"]}},
+                    {"name": "point", "type": {
+                        "type": "record",
+                        "name": "Point",
+                        "fields": [{"name": "x", "type": "float"}, {"name": "y", "type": "float"}]
+                    }},
+                    {"name": "other_point", "type": ["null", "com.example.Point"]},
+                    {"name": "hash", "type": {"type": "fixed", "name": "Hash", "size": 4}},
+                    {"name": "unknown_logical", "type": {"type": "string", "logicalType": "unknown"}}
+                ]
+            }"#,
+        )?;
+        let point = DataType::Struct(vec![
+            Field::new("x", DataType::Float32),
+            Field::new("y", DataType::Float32),
+        ]);
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("name", DataType::Utf8),
+            Field::new("kind", DataType::Utf8),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string())),
+            ),
+            Field::new(
+                "local_ts",
+                DataType::Timestamp(TimeUnit::Milliseconds, None),
+            ),
+            Field::new("day", DataType::Date),
+            Field::new("time", DataType::Time(TimeUnit::Microseconds)),
+            Field::new("price", DataType::Decimal128(10, 2)),
+            Field::new("tags", DataType::List(Box::new(DataType::Utf8))),
+            Field::new(
+                "attrs",
+                DataType::Map(Box::new(DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8),
+                    Field::new("value", DataType::Utf8),
+                ]))),
+            ),
+            Field::new("point", point.clone()),
+            Field::new("other_point", point),
+            Field::new("hash", DataType::FixedSizeBinary(4)),
+            Field::new("unknown_logical", DataType::Utf8),
+        ])?;
+        assert_eq!(to_daft_schema(&record)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_avro_schema_rejects_unsupported_types() -> DaftResult<()> {
+        for field_type in [
+            r#"["int", "string"]"#,
+            r#"{"type": "fixed", "name": "D", "size": 12, "logicalType": "duration"}"#,
+        ] {
+            let record = parse_record(&format!(
+                r#"{{"type": "record", "name": "R", "fields": [{{"name": "v", "type": {field_type}}}]}}"#
+            ))?;
+            assert!(to_daft_schema(&record).is_err());
+        }
+        assert!(parse_schema(
+            r#"{"type": "record", "name": "R", "fields": [{"name": "v", "type": "Missing"}]}"#
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use arrow2::io::avro::avro_schema::schema::{BytesLogical, Enum, Fixed, FixedLogical, Record};
+use common_error::DaftResult;
+use daft_io::{IOClient, IOStatsRef};
+
+use crate::{
+    decode::{
+        decode_decimal, decode_error, read_block_header, read_bytes, read_int, read_long, take,
+        union_branch,
+    },
+    metadata::read_avro_header,
+    read::BlockReader,
+    schema::AvroSchema,
+};
+
+/// A single decoded Avro value, for reading small files record by record rather than as tables.
+///
+/// Values of logical types are kept as their underlying type, e.g. dates are `Int`s of days since the UNIX epoch, and
+/// union values are the value of their branch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AvroValue {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Bytes(Vec<u8>),
+    String(String),
+    Fixed(Vec<u8>),
+    /// Unscaled value of a decimal.
+    Decimal(i128),
+    Enum(String),
+    Array(Vec<AvroValue>),
+    Map(Vec<(String, AvroValue)>),
+    Record(Vec<(String, AvroValue)>),
+}
+
+impl AvroValue {
+    /// Returns the value of the field `name` of a record, or `None` if this isn't a record or it doesn't have the
+    /// field.
+    pub fn field(&self, name: &str) -> Option<&AvroValue> {
+        match self {
+            Self::Record(fields) => fields.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Returns the value of an `int` or a `long`.
+    pub fn as_long(&self) -> Option<i64> {
+        match self {
+            Self::Int(v) => Some(*v as i64),
+            Self::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) | Self::Enum(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of `bytes` or `fixed`.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(v) | Self::Fixed(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[AvroValue]> {
+        match self {
+            Self::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+fn decode_value(schema: &AvroSchema, buf: &mut &[u8]) -> crate::Result<AvroValue> {
+    Ok(match schema {
+        AvroSchema::Null => AvroValue::Null,
+        AvroSchema::Boolean => AvroValue::Boolean(take(buf, 1)?[0] != 0),
+        AvroSchema::Int(_) => AvroValue::Int(read_int(buf)?),
+        AvroSchema::Long(_) => AvroValue::Long(read_long(buf)?),
+        AvroSchema::Float => {
+            AvroValue::Float(f32::from_le_bytes(take(buf, 4)?.try_into().unwrap()))
+        }
+        AvroSchema::Double => {
+            AvroValue::Double(f64::from_le_bytes(take(buf, 8)?.try_into().unwrap()))
+        }
+        AvroSchema::Bytes(Some(BytesLogical::Decimal(..))) => {
+            AvroValue::Decimal(decode_decimal(read_bytes(buf)?)?)
+        }
+        AvroSchema::Bytes(None) => AvroValue::Bytes(read_bytes(buf)?.to_vec()),
+        AvroSchema::String(_) => AvroValue::String(
+            String::from_utf8(read_bytes(buf)?.to_vec())
+                .map_err(|_| decode_error("invalid UTF-8 in string value"))?,
+        ),
+        AvroSchema::Fixed(Fixed {
+            size,
+            logical: Some(FixedLogical::Decimal(..)),
+            ..
+        }) => AvroValue::Decimal(decode_decimal(take(buf, *size)?)?),
+        AvroSchema::Fixed(Fixed { size, .. }) => AvroValue::Fixed(take(buf, *size)?.to_vec()),
+        AvroSchema::Enum(Enum { symbols, .. }) => {
+            let index = read_long(buf)?;
+            let symbol = usize::try_from(index)
+                .ok()
+                .and_then(|i| symbols.get(i))
+                .ok_or_else(|| decode_error(format!("enum symbol {index} is out of bounds")))?;
+            AvroValue::Enum(symbol.clone())
+        }
+        AvroSchema::Array(item_schema) => {
+            let mut items = vec![];
+            loop {
+                let (count, _) = read_block_header(buf)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    items.push(decode_value(item_schema, buf)?);
+                }
+            }
+            AvroValue::Array(items)
+        }
+        AvroSchema::Map(value_schema) => {
+            let mut entries = vec![];
+            loop {
+                let (count, _) = read_block_header(buf)?;
+                if count == 0 {
+                    break;
+                }
+                for _ in 0..count {
+                    let key = String::from_utf8_lossy(read_bytes(buf)?).into_owned();
+                    entries.push((key, decode_value(value_schema, buf)?));
+                }
+            }
+            AvroValue::Map(entries)
+        }
+        AvroSchema::Record(Record { fields, .. }) => AvroValue::Record(
+            fields
+                .iter()
+                .map(|f| Ok((f.name.clone(), decode_value(&f.schema, buf)?)))
+                .collect::<crate::Result<Vec<_>>>()?,
+        ),
+        AvroSchema::Union(branches) => decode_value(union_branch(branches, buf)?, buf)?,
+    })
+}
+
+/// Reads all of the records of the Avro file at `uri` as values, returning them along with the schema of the file.
+///
+/// This is meant for small files whose records are processed individually, such as the manifests of table formats.
+pub async fn read_avro_values(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(AvroSchema, Vec<AvroValue>)> {
+    let header = Arc::new(read_avro_header(uri, io_client.clone(), io_stats.clone()).await?);
+    let mut reader = BlockReader::new(uri, io_client, io_stats, header.clone());
+    let mut values = vec![];
+    let mut position = header.header_size;
+    while position < header.file_size {
+        let (count, data, next_position) = reader.read_block(position).await?;
+        let data = header.codec.decompress(data).await?;
+        let mut buf = data.as_ref();
+        for _ in 0..count {
+            values.push(decode_value(&header.schema, &mut buf)?);
+        }
+        position = next_position;
+    }
+    Ok((header.schema.clone(), values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_value, AvroValue};
+    use crate::schema::parse_schema;
+
+    #[test]
+    fn test_decode_value() {
+        let schema = parse_schema(
+            r#"{
+                "type": "record",
+                "name": "r",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "name", "type": ["null", "string"]},
+                    {"name": "bounds", "type": {"type": "array", "items": {
+                        "type": "record",
+                        "name": "kv",
+                        "fields": [{"name": "key", "type": "int"}, {"name": "value", "type": "bytes"}]
+                    }}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        // id = 3, name = null, bounds = [{key: 1, value: b"ab"}]
+        let mut buf: &[u8] = &[0x06, 0x00, 0x02, 0x02, 0x04, b'a', b'b', 0x00];
+        let value = decode_value(&schema, &mut buf).unwrap();
+        assert!(buf.is_empty());
+        assert_eq!(value.field("id").and_then(AvroValue::as_long), Some(3));
+        assert!(value.field("name").unwrap().is_null());
+        let bounds = value.field("bounds").and_then(AvroValue::as_array).unwrap();
+        assert_eq!(bounds[0].field("key"), Some(&AvroValue::Int(1)));
+        assert_eq!(
+            bounds[0].field("value").and_then(AvroValue::as_bytes),
+            Some(&b"ab"[..])
+        );
+    }
+}
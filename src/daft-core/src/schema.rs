@@ -12,7 +12,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     datatypes::Field,
-    utils::display_table::{make_comfy_table, make_schema_vertical_table},
+    utils::{
+        display_table::{make_comfy_table, make_schema_vertical_table},
+        supertype::try_get_supertype,
+    },
 };
 
 use common_error::{DaftError, DaftResult};
@@ -112,6 +115,30 @@ impl Schema {
         }
     }
 
+    /// Merges the fields of `other` into this schema by name. Fields that are only in `other` are appended, and fields
+    /// that are in both schemas are promoted to the supertype of their types.
+    pub fn union_by_name(&self, other: &Schema) -> DaftResult<Schema> {
+        let mut fields = self.fields.clone();
+        for (name, other_field) in other.fields.iter() {
+            match fields.get_mut(name) {
+                None => {
+                    fields.insert(name.clone(), other_field.clone());
+                }
+                Some(field) if field.dtype != other_field.dtype => {
+                    field.dtype =
+                        try_get_supertype(&field.dtype, &other_field.dtype).map_err(|_| {
+                            DaftError::SchemaMismatch(format!(
+                                "Cannot union field \"{name}\" of type {} with type {}",
+                                field.dtype, other_field.dtype
+                            ))
+                        })?;
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Schema { fields })
+    }
+
     pub fn apply_hints(&self, hints: &Schema) -> DaftResult<Schema> {
         let applied_fields = self
             .fields
@@ -320,3 +347,35 @@ impl TryFrom<&arrow2::datatypes::Schema> for Schema {
         Self::new(daft_fields)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::Schema;
+    use crate::datatypes::{DataType, Field};
+
+    #[test]
+    fn test_union_by_name() -> DaftResult<()> {
+        let left = Schema::new(vec![
+            Field::new("a", DataType::Int32),
+            Field::new("b", DataType::Utf8),
+        ])?;
+        let right = Schema::new(vec![
+            Field::new("c", DataType::Boolean),
+            Field::new("a", DataType::Int64),
+        ])?;
+        assert_eq!(
+            left.union_by_name(&right)?,
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Utf8),
+                Field::new("c", DataType::Boolean),
+            ])?
+        );
+
+        let incompatible = Schema::new(vec![Field::new("b", DataType::Binary)])?;
+        assert!(left.union_by_name(&incompatible).is_err());
+        Ok(())
+    }
+}
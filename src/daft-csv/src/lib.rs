@@ -10,13 +10,12 @@ pub mod options;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
-mod schema;
 
 pub use metadata::read_csv_schema_bulk;
 pub use options::{char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
-pub use read::{read_csv, read_csv_bulk, stream_csv};
+pub use read::{read_csv, read_csv_bulk, sample_has_multiline_records, stream_csv};
 
 #[derive(Debug, Snafu)]
 pub enum Error {
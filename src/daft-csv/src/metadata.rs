@@ -14,9 +14,9 @@ use tokio::{
 };
 use tokio_util::io::StreamReader;
 
-use crate::{schema::merge_schema, CsvParseOptions};
+use crate::CsvParseOptions;
 use daft_compression::CompressionCodec;
-use daft_decoding::inference::infer;
+use daft_decoding::inference::{infer, merge_schema};
 
 const DEFAULT_COLUMN_PREFIX: &str = "column_";
 
@@ -71,7 +71,7 @@ pub fn read_csv_schema(
     })
 }
 
-pub async fn read_csv_schema_bulk(
+pub fn read_csv_schema_bulk(
     uris: &[&str],
     parse_options: Option<CsvParseOptions>,
     max_bytes: Option<usize>,
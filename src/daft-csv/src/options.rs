@@ -313,6 +313,11 @@ impl_bincode_py_state_serialization!(CsvParseOptions);
 pub struct CsvReadOptions {
     pub buffer_size: Option<usize>,
     pub chunk_size: Option<usize>,
+    /// Byte range `(start, end)` of the file to read. The range is read from the first record boundary after `start`
+    /// up to the first record boundary after `end`, which are found in the same way, so that a file can be split into
+    /// byte ranges that are read independently. Reading fails if a record spans the boundary after `end`, which can
+    /// happen if quoted fields contain newlines.
+    pub byte_range: Option<(usize, usize)>,
 }
 
 impl CsvReadOptions {
@@ -320,21 +325,23 @@ impl CsvReadOptions {
         Self {
             buffer_size,
             chunk_size,
+            byte_range: None,
         }
     }
 
     pub fn with_buffer_size(self, buffer_size: Option<usize>) -> Self {
         Self {
             buffer_size,
-            chunk_size: self.chunk_size,
+            ..self
         }
     }
 
     pub fn with_chunk_size(self, chunk_size: Option<usize>) -> Self {
-        Self {
-            buffer_size: self.buffer_size,
-            chunk_size,
-        }
+        Self { chunk_size, ..self }
+    }
+
+    pub fn with_byte_range(self, byte_range: Option<(usize, usize)>) -> Self {
+        Self { byte_range, ..self }
     }
 }
 
@@ -2,7 +2,10 @@ use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
 
 use arrow2::{
     datatypes::Field,
-    io::csv::read_async::{read_rows, AsyncReaderBuilder, ByteRecord},
+    io::csv::{
+        read::{ByteRecord as SyncByteRecord, ReaderBuilder},
+        read_async::{read_rows, AsyncReaderBuilder, ByteRecord},
+    },
 };
 use async_compat::{Compat, CompatExt};
 use common_error::{DaftError, DaftResult};
@@ -23,7 +26,7 @@ use snafu::{
 };
 use tokio::{
     fs::File,
-    io::{AsyncBufRead, AsyncRead, BufReader},
+    io::{AsyncBufRead, AsyncRead, AsyncReadExt, AsyncSeekExt, BufReader, SeekFrom},
     task::JoinHandle,
 };
 use tokio_util::io::StreamReader;
@@ -33,6 +36,13 @@ use crate::{metadata::read_csv_schema_single, CsvConvertOptions, CsvParseOptions
 use daft_compression::CompressionCodec;
 use daft_decoding::deserialize::deserialize_column;
 
+// Number of bytes to initially search for a record boundary at the start of a byte range, which is doubled until a
+// boundary is found or the maximum is reached.
+const RECORD_BOUNDARY_PROBE_BYTES: usize = 1024 * 1024;
+const MAX_RECORD_BOUNDARY_PROBE_BYTES: usize = 64 * 1024 * 1024;
+// Number of records that must parse after a newline for it to be accepted as a record boundary.
+const NUM_RECORDS_TO_VALIDATE: usize = 8;
+
 trait ByteRecordChunkStream: Stream<Item = super::Result<Vec<ByteRecord>>> {}
 impl<S> ByteRecordChunkStream for S where S: Stream<Item = super::Result<Vec<ByteRecord>>> {}
 
@@ -158,6 +168,48 @@ pub async fn stream_csv(
     Ok(Box::pin(stream))
 }
 
+/// Returns whether any record in the first `sample_size` bytes of the file at `uri` spans multiple lines because a
+/// quoted field contains a newline. Newlines within fields can't be told apart from record boundaries without reading
+/// the file from the start, so such files can't reliably be split into byte ranges.
+pub async fn sample_has_multiline_records(
+    uri: &str,
+    sample_size: usize,
+    parse_options: &CsvParseOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<bool> {
+    let sample = io_client
+        .single_url_get(uri.to_string(), Some(0..sample_size), io_stats)
+        .await?
+        .bytes()
+        .await?;
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .delimiter(parse_options.delimiter)
+        .double_quote(parse_options.double_quote)
+        .quote(parse_options.quote)
+        .escape(parse_options.escape_char)
+        .comment(parse_options.comment)
+        .flexible(true)
+        .from_reader(sample.as_ref());
+    let mut record = SyncByteRecord::new();
+    loop {
+        match reader.read_byte_record(&mut record) {
+            Ok(true) => {
+                if record
+                    .iter()
+                    .any(|field| field.contains(&b'\n') || field.contains(&b'\r'))
+                {
+                    return Ok(true);
+                }
+            }
+            Ok(false) => return Ok(false),
+            // Leave surfacing malformed records to the read, which won't split the file.
+            Err(_) => return Ok(true),
+        }
+    }
+}
+
 // Parallel version of table concat
 // get rid of this once Table APIs are parallel
 fn tables_concat(mut tables: Vec<Table>) -> DaftResult<Table> {
@@ -455,14 +507,37 @@ async fn read_csv_single_into_stream(
             .collect::<Vec<_>>()
             .into();
     }
+    let byte_range = read_options.as_ref().and_then(|opts| opts.byte_range);
+    if byte_range.is_some() && CompressionCodec::from_uri(uri).is_some() {
+        return Err(DaftError::ValueError(format!(
+            "Cannot read a byte range of compressed CSV file: {uri}"
+        )));
+    }
+    // Start reading from the byte before the range, so that a record which begins exactly at the start of the range
+    // is found after the preceding newline.
+    let read_start = byte_range.map_or(0, |(start, _)| start.saturating_sub(1));
+    let file_size = if byte_range.is_some() {
+        Some(
+            io_client
+                .single_url_get_size(uri.to_string(), io_stats.clone())
+                .await?,
+        )
+    } else {
+        None
+    };
+    let get_range = file_size
+        .filter(|_| read_start > 0)
+        .map(|size| read_start..size);
     let (reader, buffer_size, chunk_size): (Box<dyn AsyncBufRead + Unpin + Send>, usize, usize) =
         match io_client
-            .single_url_get(uri.to_string(), None, io_stats)
+            .single_url_get(uri.to_string(), get_range, io_stats.clone())
             .await?
         {
             GetResult::File(file) => {
+                let mut file = File::open(file.path).await?;
+                file.seek(SeekFrom::Start(read_start as u64)).await?;
                 (
-                    Box::new(BufReader::new(File::open(file.path).await?)),
+                    Box::new(BufReader::new(file)),
                     // Use user-provided buffer size, falling back to 8 * the user-provided chunk size if that exists, otherwise falling back to 512 KiB as the default.
                     read_options
                         .as_ref()
@@ -490,8 +565,42 @@ async fn read_csv_single_into_stream(
         Some(compression) => Box::new(compression.to_decoder(reader)),
         None => reader,
     };
+    // Ranges after the start of the file begin at the first record boundary in the range, so they have no header. Each
+    // range ends at the record boundary that the next range begins at, which is found in the same way.
+    let (reader, has_header, max_bytes) = match (byte_range, file_size) {
+        (Some((start, end)), Some(file_size)) => {
+            let (reader, has_header, record_start) = if start > 0 {
+                let (reader, record_start) = seek_to_record_boundary(
+                    reader,
+                    read_start,
+                    &parse_options,
+                    schema.fields.len(),
+                )
+                .await?;
+                (reader, false, record_start)
+            } else {
+                (reader, parse_options.has_header, 0)
+            };
+            let record_end = find_record_boundary(
+                uri,
+                end,
+                file_size,
+                &parse_options,
+                schema.fields.len(),
+                io_client,
+                io_stats,
+            )
+            .await?;
+            (
+                reader,
+                has_header,
+                Some(record_end.saturating_sub(record_start)),
+            )
+        }
+        _ => (reader, parse_options.has_header, None),
+    };
     let reader = AsyncReaderBuilder::new()
-        .has_headers(parse_options.has_header)
+        .has_headers(has_header)
         .delimiter(parse_options.delimiter)
         .double_quote(parse_options.double_quote)
         .quote(parse_options.quote)
@@ -504,6 +613,7 @@ async fn read_csv_single_into_stream(
         reader,
         schema.fields.len(),
         convert_options.limit,
+        max_bytes,
         chunk_size,
         estimated_mean_row_size,
         estimated_std_row_size,
@@ -521,10 +631,172 @@ async fn read_csv_single_into_stream(
     Ok((stream, fields))
 }
 
+/// Returns the offset of the record boundary that a byte range starting at `offset` of the file at `uri` begins at, or
+/// `file_size` if no record begins at or after `offset`.
+async fn find_record_boundary(
+    uri: &str,
+    offset: usize,
+    file_size: usize,
+    parse_options: &CsvParseOptions,
+    num_fields: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<usize> {
+    if offset >= file_size {
+        return Ok(file_size);
+    }
+    let read_start = offset.saturating_sub(1);
+    let reader: Box<dyn AsyncRead + Unpin + Send> = match io_client
+        .single_url_get(uri.to_string(), Some(read_start..file_size), io_stats)
+        .await?
+    {
+        GetResult::File(file) => {
+            let mut file = File::open(file.path).await?;
+            file.seek(SeekFrom::Start(read_start as u64)).await?;
+            Box::new(file)
+        }
+        GetResult::Stream(stream, ..) => Box::new(StreamReader::new(stream)),
+    };
+    let (_, boundary) =
+        seek_to_record_boundary(reader, read_start, parse_options, num_fields).await?;
+    Ok(boundary)
+}
+
+/// Finds the first record boundary after `read_start` in `reader`, which must be positioned at `read_start`. Returns a
+/// reader positioned at the boundary and the offset of the boundary in the file.
+///
+/// Since newlines within quoted fields don't end records, a newline is only accepted as a record boundary if the
+/// records that follow it parse into the expected number of fields.
+async fn seek_to_record_boundary(
+    mut reader: Box<dyn AsyncRead + Unpin + Send>,
+    read_start: usize,
+    parse_options: &CsvParseOptions,
+    num_fields: usize,
+) -> DaftResult<(Box<dyn AsyncRead + Unpin + Send>, usize)> {
+    let mut probe = Vec::new();
+    let mut probe_size = RECORD_BOUNDARY_PROBE_BYTES;
+    loop {
+        let bytes_to_read = probe_size - probe.len();
+        let bytes_read = (&mut reader)
+            .take(bytes_to_read as u64)
+            .read_to_end(&mut probe)
+            .await?;
+        let at_eof = bytes_read < bytes_to_read;
+        let mut candidates = probe
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .map(|(i, _)| i + 1)
+            .peekable();
+        let first_candidate = candidates.peek().copied();
+        let boundary = candidates
+            .find(|i| is_record_boundary(&probe[*i..], at_eof, parse_options, num_fields))
+            // At the end of the file, let the parser surface any malformed records after the first newline.
+            .or(if at_eof { first_candidate } else { None });
+        match boundary {
+            Some(boundary) => {
+                let rest = probe.split_off(boundary);
+                return Ok((
+                    Box::new(std::io::Cursor::new(rest).chain(reader)),
+                    read_start + boundary,
+                ));
+            }
+            // No record begins after `read_start`.
+            None if at_eof => return Ok((Box::new(tokio::io::empty()), read_start + probe.len())),
+            None if probe_size >= MAX_RECORD_BOUNDARY_PROBE_BYTES => {
+                return Err(DaftError::ValueError(format!(
+                    "Unable to find a CSV record boundary within {} bytes of byte {}",
+                    MAX_RECORD_BOUNDARY_PROBE_BYTES, read_start
+                )))
+            }
+            None => probe_size *= 2,
+        }
+    }
+}
+
+/// Returns whether `bytes` plausibly begins at a record boundary, by checking that the records at its start parse into
+/// `num_fields` fields. The last record in `bytes` may be truncated unless `at_eof` is set.
+fn is_record_boundary(
+    bytes: &[u8],
+    at_eof: bool,
+    parse_options: &CsvParseOptions,
+    num_fields: usize,
+) -> bool {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .delimiter(parse_options.delimiter)
+        .double_quote(parse_options.double_quote)
+        .quote(parse_options.quote)
+        .escape(parse_options.escape_char)
+        .comment(parse_options.comment)
+        .flexible(true)
+        .from_reader(bytes);
+    let mut record = SyncByteRecord::new();
+    let mut num_records = 0;
+    while num_records < NUM_RECORDS_TO_VALIDATE {
+        match reader.read_byte_record(&mut record) {
+            Ok(true) => {
+                if !at_eof && reader.position().byte() as usize >= bytes.len() {
+                    break;
+                }
+                if !parse_options.allow_variable_columns && record.len() != num_fields {
+                    return false;
+                }
+                num_records += 1;
+            }
+            Ok(false) => return at_eof || num_records > 0,
+            Err(_) => return false,
+        }
+    }
+    num_records > 0
+}
+
+/// Reads rows into `rows` like [`read_rows`], but stops at the first row that begins at or after byte `max_bytes` of
+/// the reader. Also returns whether such a row or the end of the reader was reached.
+///
+/// Fails if a row spans byte `max_bytes`, since the next byte range begins there and would read the rest of the row as
+/// rows of its own.
+async fn read_rows_before<R>(
+    reader: &mut AsyncReader<R>,
+    rows: &mut [ByteRecord],
+    max_bytes: usize,
+) -> arrow2::error::Result<(usize, bool)>
+where
+    R: futures::AsyncRead + Unpin + Send,
+{
+    let mut row_number = 0;
+    for row in rows.iter_mut() {
+        let parsed_bytes = reader.position().byte() as usize;
+        let has_more = reader.read_byte_record(row).await.map_err(|e| {
+            arrow2::error::Error::External(format!(" at line {}", row_number), Box::new(e))
+        })?;
+        if !has_more
+            || row
+                .position()
+                .map_or(false, |p| p.byte() as usize >= max_bytes)
+        {
+            if parsed_bytes > max_bytes {
+                return Err(arrow2::error::Error::ExternalFormat(format!(
+                    "A CSV record spans the record boundary at which the next byte range of the file begins, which \
+                     can happen if quoted fields contain newlines. Increase `scan_tasks_max_size_bytes` to read the \
+                     file without splitting it. The boundary is {} bytes into the range, and the record ends {} \
+                     bytes into the range.",
+                    max_bytes, parsed_bytes
+                )));
+            }
+            return Ok((row_number, true));
+        }
+        row_number += 1;
+    }
+    Ok((row_number, false))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn read_into_byterecord_chunk_stream<R>(
     mut reader: AsyncReader<Compat<R>>,
     num_fields: usize,
     num_rows: Option<usize>,
+    max_bytes: Option<usize>,
     chunk_size: usize,
     estimated_mean_row_size: Option<f64>,
     estimated_std_row_size: Option<f64>,
@@ -543,7 +815,9 @@ where
         let mut total_rows_read = 0;
         let mut mean = 0f64;
         let mut m2 = 0f64;
-        while rows_read > 0 && total_rows_read < num_rows {
+        // Whether the end of the byte range of the reader has been reached.
+        let mut reached_max_bytes = false;
+        while rows_read > 0 && total_rows_read < num_rows && !reached_max_bytes {
             // Allocate a record buffer of size 1 standard above the observed mean record size.
             // If the record sizes are normally distributed, this should result in ~85% of the records not requiring
             // reallocation during reading.
@@ -561,7 +835,14 @@ where
             ];
 
             let byte_pos_before = reader.position().byte();
-            rows_read = read_rows(&mut reader, 0, chunk_buffer.as_mut_slice()).await.context(ArrowSnafu {})?;
+            rows_read = match max_bytes {
+                None => read_rows(&mut reader, 0, chunk_buffer.as_mut_slice()).await.context(ArrowSnafu {})?,
+                Some(max_bytes) => {
+                    let (rows_read, reached) = read_rows_before(&mut reader, chunk_buffer.as_mut_slice(), max_bytes).await.context(ArrowSnafu {})?;
+                    reached_max_bytes = reached;
+                    rows_read
+                }
+            };
             let bytes_read = reader.position().byte() - byte_pos_before;
 
             // Update stats.
@@ -1371,6 +1652,109 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    fn test_csv_read_local_byte_ranges(
+        #[values("iris_tiny.csv", "iris_tiny_quoted_newlines.csv")] file: &str,
+        #[values(1, 17, 64, 1000)] range_size: usize,
+    ) -> DaftResult<()> {
+        let file = format!("{}/test/{}", env!("CARGO_MANIFEST_DIR"), file);
+        let file_size = std::fs::metadata(&file)?.len() as usize;
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let tables = (0..file_size)
+            .step_by(range_size)
+            .map(|start| {
+                read_csv(
+                    file.as_ref(),
+                    None,
+                    None,
+                    Some(
+                        CsvReadOptions::default()
+                            .with_byte_range(Some((start, (start + range_size).min(file_size)))),
+                    ),
+                    io_client.clone(),
+                    None,
+                    true,
+                    None,
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let table = Table::concat(&tables)?;
+        assert_eq!(table.len(), 20);
+        check_equal_local_arrow2(
+            file.as_ref(),
+            &table,
+            true,
+            None,
+            true,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_csv_read_local_byte_ranges_quoted_record_lines(
+        #[values(1, 17, 64, 100, 250, 1000)] range_size: usize,
+    ) -> DaftResult<()> {
+        // Quoted fields in this file hold lines that parse as records on their own, so a range
+        // starting inside one can't tell it apart from a record boundary.
+        let file = format!(
+            "{}/test/quoted_record_lines.csv",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let file_size = std::fs::metadata(&file)?.len() as usize;
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let unsplit = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(unsplit.len(), 12);
+
+        let tables = (0..file_size)
+            .step_by(range_size)
+            .map(|start| {
+                read_csv(
+                    file.as_ref(),
+                    None,
+                    None,
+                    Some(
+                        CsvReadOptions::default()
+                            .with_byte_range(Some((start, (start + range_size).min(file_size)))),
+                    ),
+                    io_client.clone(),
+                    None,
+                    true,
+                    None,
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>();
+        // Splitting such a file must either fail loudly or return every row exactly once.
+        if let Ok(tables) = tables {
+            let num_rows: usize = tables.iter().map(Table::len).sum();
+            assert_eq!(num_rows, unsplit.len());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_csv_read_local_throttled_streaming() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use arrow2::datatypes::{DataType, TimeUnit};
 use chrono::Timelike;
 
@@ -49,6 +51,47 @@ pub fn infer_string(string: &str) -> DataType {
     }
 }
 
+/// Merges the types inferred for the values of each column into a field per column, falling back to
+/// [`DataType::Utf8`] for columns whose values have conflicting types.
+pub fn merge_schema(
+    headers: &[String],
+    column_types: &mut [HashSet<DataType>],
+) -> Vec<arrow2::datatypes::Field> {
+    headers
+        .iter()
+        .zip(column_types.iter_mut())
+        .map(|(field_name, possibilities)| merge_fields(field_name, possibilities))
+        .collect()
+}
+
+fn merge_fields(
+    field_name: &str,
+    possibilities: &mut HashSet<DataType>,
+) -> arrow2::datatypes::Field {
+    if possibilities.len() > 1 {
+        // Drop nulls from possibilities.
+        possibilities.remove(&DataType::Null);
+    }
+    // determine data type based on possible types
+    // if there are incompatible types, use DataType::Utf8
+    let data_type = match possibilities.len() {
+        1 => possibilities.drain().next().unwrap(),
+        2 => {
+            if possibilities.contains(&DataType::Int64)
+                && possibilities.contains(&DataType::Float64)
+            {
+                // we have an integer and double, fall down to double
+                DataType::Float64
+            } else {
+                // default to Utf8 for conflicting datatypes (e.g bool and int)
+                DataType::Utf8
+            }
+        }
+        _ => DataType::Utf8,
+    };
+    arrow2::datatypes::Field::new(field_name, data_type, true)
+}
+
 fn is_null(bytes: &[u8]) -> bool {
     bytes.is_empty()
 }
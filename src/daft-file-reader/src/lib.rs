@@ -0,0 +1,291 @@
+//! Reading of files of any format into tables with the pushdowns of a read, which formats plug into by decoding the
+//! columns of a file with a [`FileFormatReader`].
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
+
+/// Pushdowns of a read of files into tables.
+#[derive(Clone, Debug, Default)]
+pub struct ReadPushdowns {
+    /// Columns of the returned tables, in order, or `None` for all of the columns of the file.
+    pub columns: Option<Vec<String>>,
+    /// Maximum number of rows to return.
+    pub limit: Option<usize>,
+    /// Predicate that the returned rows satisfy.
+    pub predicate: Option<ExprRef>,
+}
+
+impl ReadPushdowns {
+    pub fn new(columns: Option<&[&str]>, limit: Option<usize>, predicate: Option<ExprRef>) -> Self {
+        Self {
+            columns: columns.map(|cols| cols.iter().map(|col| col.to_string()).collect()),
+            limit,
+            predicate,
+        }
+    }
+
+    /// Returns the limit that can be applied while decoding a file, which is only the limit of the read if no rows are
+    /// filtered out by the predicate.
+    pub fn decode_limit(&self) -> Option<usize> {
+        self.limit.filter(|_| self.predicate.is_none())
+    }
+
+    /// Returns the schema of the columns that are read from a file with schema `file_schema`, which are the requested
+    /// columns and the columns that the predicate requires in the order of the file, and the schema of the returned
+    /// tables, which only have the requested columns.
+    pub fn read_and_output_schemas(
+        &self,
+        file_schema: SchemaRef,
+        file_kind: &str,
+    ) -> DaftResult<(SchemaRef, SchemaRef)> {
+        // Columns that the predicate requires are read in addition to the requested columns.
+        let read_columns = match (&self.columns, &self.predicate) {
+            (Some(columns), Some(predicate)) => {
+                let mut read_columns = columns.clone();
+                for rc in get_required_columns(predicate) {
+                    if !read_columns.contains(&rc) {
+                        read_columns.push(rc);
+                    }
+                }
+                Some(read_columns)
+            }
+            (columns, _) => columns.clone(),
+        };
+        let read_schema = match &read_columns {
+            Some(read_columns) => {
+                if let Some(col) = read_columns
+                    .iter()
+                    .find(|col| !file_schema.fields.contains_key(col.as_str()))
+                {
+                    return Err(DaftError::ValueError(format!(
+                        "Column {} in the projection doesn't exist in the {} file; existing columns = {:?}",
+                        col,
+                        file_kind,
+                        file_schema.names()
+                    )));
+                }
+                Arc::new(Schema::new(
+                    file_schema
+                        .fields
+                        .values()
+                        .filter(|f| read_columns.contains(&f.name))
+                        .cloned()
+                        .collect(),
+                )?)
+            }
+            None => file_schema,
+        };
+        let output_schema = match &self.columns {
+            Some(columns) => Arc::new(read_schema.clone().project(columns)?),
+            None => read_schema.clone(),
+        };
+        Ok((read_schema, output_schema))
+    }
+
+    /// Filters a table of the read schema by the predicate, and then drops the columns that were only read for the
+    /// predicate and orders the columns as requested.
+    pub fn filter_and_project(&self, table: Table) -> DaftResult<Table> {
+        let table = match &self.predicate {
+            Some(predicate) => table.filter(&[predicate.clone()])?,
+            None => table,
+        };
+        match &self.columns {
+            Some(columns) => table.get_columns(columns),
+            None => Ok(table),
+        }
+    }
+
+    /// Applies the pushdowns to a stream of tables of the read schema, ending the stream once it has returned `limit`
+    /// rows.
+    pub fn apply(
+        self,
+        tables: impl Stream<Item = DaftResult<Table>> + Send + 'static,
+    ) -> BoxStream<'static, DaftResult<Table>> {
+        let mut remaining_rows = self.limit.map(|limit| limit as i64);
+        tables
+            .map(move |table| self.filter_and_project(table?))
+            .try_take_while(move |table| {
+                match remaining_rows {
+                    // Limit has been met, early-terminate.
+                    Some(rows_left) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    Some(rows_left) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // No limit, never early-terminate.
+                    None => futures::future::ready(Ok(true)),
+                }
+            })
+            .boxed()
+    }
+}
+
+/// Reader of the files of a format, holding the options of reading a file. Formats only decode the columns of a file
+/// into tables, and [`read_file`], [`read_files`] and [`stream_file`] apply the pushdowns of a read to those tables.
+#[async_trait]
+pub trait FileFormatReader: Clone + Send + Sync + 'static {
+    /// Name of the format in error messages, e.g. "Arrow IPC".
+    const FORMAT_NAME: &'static str;
+
+    /// Metadata of a file that is read before its columns are decoded, such as its footer.
+    type Metadata: Send;
+
+    /// Reads the metadata of the file at `uri`, returning it along with the schema of the file.
+    async fn read_metadata(
+        &self,
+        uri: &str,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<(Self::Metadata, SchemaRef)>;
+
+    /// Decodes the columns of `read_schema`, which are in the order of the file, into a stream of tables.
+    ///
+    /// The pushdowns are applied to the stream afterwards, so decoders only use them to skip data that wouldn't be
+    /// returned, e.g. with [`ReadPushdowns::decode_limit`].
+    async fn decode(
+        &self,
+        uri: &str,
+        metadata: Self::Metadata,
+        read_schema: SchemaRef,
+        pushdowns: &ReadPushdowns,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>>;
+}
+
+/// Reads the file at `uri` into a table.
+pub fn read_file<R: FileFormatReader>(
+    reader: &R,
+    uri: &str,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> DaftResult<Table> {
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    runtime_handle.block_on_current_thread(async {
+        read_file_into_table(reader, uri, pushdowns, io_client, io_stats).await
+    })
+}
+
+/// Reads each file into a table with its reader, reading at most `num_parallel_tasks` files at a time and stopping
+/// once `limit` rows have been read.
+pub fn read_files<R: FileFormatReader>(
+    files: Vec<(&str, R)>,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+    multithreaded_io: bool,
+) -> DaftResult<Vec<Table>> {
+    let limit = pushdowns.limit;
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    let tables = runtime_handle.block_on_current_thread(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(files.into_iter().map(|(uri, reader)| {
+            let (uri, pushdowns, io_client, io_stats) = (
+                uri.to_string(),
+                pushdowns.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_file_into_table(&reader, &uri, pushdowns, io_client, io_stats).await
+            })
+        }));
+        let mut remaining_rows = limit.map(|limit| limit as i64);
+        task_stream
+            // Limit the number of file reads we have in flight at any given time.
+            .buffered(num_parallel_tasks)
+            .map(|result| result.map_err(|e| DaftError::External(e.into())))
+            // Terminate the stream if we have already reached the row limit. With the upstream buffering, we will still read up to
+            // num_parallel_tasks redundant files.
+            .try_take_while(|result| {
+                match (result, remaining_rows) {
+                    // Limit has been met, early-terminate.
+                    (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    (Ok(table), Some(rows_left)) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // (1) No limit, never early-terminate.
+                    // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
+                    (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
+                }
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+/// Reads the file at `uri` as a stream of tables.
+pub async fn stream_file<R: FileFormatReader>(
+    reader: &R,
+    uri: &str,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+    let (stream, _) = read_file_into_stream(reader, uri, pushdowns, io_client, io_stats).await?;
+    Ok(stream)
+}
+
+async fn read_file_into_table<R: FileFormatReader>(
+    reader: &R,
+    uri: &str,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let limit = pushdowns.limit;
+    let (stream, schema) =
+        read_file_into_stream(reader, uri, pushdowns, io_client, io_stats).await?;
+    collect_tables(stream, schema, limit).await
+}
+
+/// Concatenates a stream of tables of schema `schema` into a table of at most `limit` rows.
+pub async fn collect_tables(
+    tables: BoxStream<'static, DaftResult<Table>>,
+    schema: SchemaRef,
+    limit: Option<usize>,
+) -> DaftResult<Table> {
+    let tables = tables.try_collect::<Vec<_>>().await?;
+    // Handle empty table case.
+    if tables.is_empty() {
+        return Table::empty(Some(schema));
+    }
+    let table = Table::concat(&tables)?;
+    match limit {
+        // Apply head in case that the last table went over the limit.
+        Some(limit) if table.len() > limit => table.head(limit),
+        _ => Ok(table),
+    }
+}
+
+/// Reads the file at `uri` as a stream of tables. Returns the stream and the schema of the tables.
+async fn read_file_into_stream<R: FileFormatReader>(
+    reader: &R,
+    uri: &str,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(BoxStream<'static, DaftResult<Table>>, SchemaRef)> {
+    let (metadata, file_schema) = reader
+        .read_metadata(uri, io_client.clone(), io_stats.clone())
+        .await?;
+    let (read_schema, output_schema) =
+        pushdowns.read_and_output_schemas(file_schema, R::FORMAT_NAME)?;
+    let tables = reader
+        .decode(uri, metadata, read_schema, &pushdowns, io_client, io_stats)
+        .await?;
+    Ok((pushdowns.apply(tables), output_schema))
+}
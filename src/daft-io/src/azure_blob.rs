@@ -1,28 +1,37 @@
 use async_trait::async_trait;
-use azure_core::{auth::TokenCredential, new_http_client};
+use azure_core::{
+    auth::TokenCredential, error::ErrorKind, new_http_client, request_options::IfMatchCondition,
+    ExponentialRetryOptions, HttpClient, RetryOptions, TransportOptions,
+};
 use azure_identity::{ClientSecretCredential, DefaultAzureCredential};
 use azure_storage::{prelude::*, CloudLocation};
 use azure_storage_blobs::{
-    blob::operations::GetBlobResponse,
+    blob::{operations::GetBlobResponse, BlobProperties, CopyStatus},
     container::{operations::BlobItem, Container},
     prelude::*,
 };
+use chrono::DateTime;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::{IntoError, ResultExt, Snafu};
-use std::{ops::Range, sync::Arc};
+use std::{ops::Range, sync::Arc, time::Duration};
 
 use crate::{
+    checksum::Checksum,
+    get_io_pool_num_threads,
+    multipart::{into_parts, upload_parts, PartSizes, PutStream, DEFAULT_MAX_IN_FLIGHT_PARTS},
     object_io::{FileMetadata, FileType, LSResult, ObjectSource},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
+    throttle::{is_throttle_status, report_throttled_attempt, RequestLimiter},
     FileFormat, GetResult,
 };
-use common_io_config::AzureConfig;
+use common_io_config::{AzureConfig, RetryConfig};
 
 const AZURE_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
 const AZURE_STORAGE_RESOURCE: &str = "https://storage.azure.com";
 const AZURE_STORE_SUFFIX: &str = ".dfs.core.windows.net";
+const COPY_STATUS_POLL_INTERVAL_MS: u64 = 100;
 
 #[derive(Debug, Snafu)]
 enum Error {
@@ -40,6 +49,8 @@ enum Error {
     StorageAccountNotSet,
     #[snafu(display("Azure client generic error: {}", source))]
     AzureGeneric { source: azure_storage::Error },
+    #[snafu(display("Unable to create Http Client {}", source))]
+    UnableToCreateClient { source: reqwest::Error },
     #[snafu(display("Unable to open {}: {}", path, source))]
     UnableToOpenFile {
         path: String,
@@ -52,6 +63,32 @@ enum Error {
         source: azure_storage::Error,
     },
 
+    #[snafu(display("Unable to put file to {}: {}", path, source))]
+    UnableToPutFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display("Unable to copy {} to {}: {}", path, dest, source))]
+    UnableToCopyFile {
+        path: String,
+        dest: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display("Copy of {} to {} did not complete, status: {}", path, dest, status))]
+    CopyNotCompleted {
+        path: String,
+        dest: String,
+        status: String,
+    },
+
     #[snafu(display("Unable to read metadata about {}: {}", path, source))]
     RequestFailedForPath {
         path: String,
@@ -107,7 +144,11 @@ impl From<Error> for super::Error {
     fn from(error: Error) -> Self {
         use Error::*;
         match error {
-            UnableToReadBytes { path, source } | UnableToOpenFile { path, source } => {
+            UnableToReadBytes { path, source }
+            | UnableToOpenFile { path, source }
+            | UnableToPutFile { path, source }
+            | UnableToDeleteFile { path, source }
+            | UnableToCopyFile { path, source, .. } => {
                 match source.as_http_error().map(|v| v.status().into()) {
                     Some(404) | Some(410) => super::Error::NotFound {
                         path,
@@ -129,6 +170,10 @@ impl From<Error> for super::Error {
                 source: error.into(),
             },
             NotAFile { path } => super::Error::NotAFile { path },
+            UnableToCreateClient { source } => super::Error::UnableToCreateClient {
+                store: super::SourceType::AzureBlob,
+                source: source.into(),
+            },
             _ => super::Error::Generic {
                 store: super::SourceType::AzureBlob,
                 source: error.into(),
@@ -137,11 +182,77 @@ impl From<Error> for super::Error {
     }
 }
 
+/// Returns whether Azure rejected a request because it is being sent requests too quickly (e.g. `503 ServerBusy`).
+fn is_throttle_error(err: &azure_core::Error) -> bool {
+    err.as_http_error()
+        .is_some_and(|err| is_throttle_status(err.status().into()))
+}
+
+/// HTTP transport for the Azure SDK that fails requests for which no response is received within the read timeout.
+///
+/// Timeouts are reported as IO errors, which the retry policy of the SDK retries like dropped connections. Since the
+/// transport sees every attempt that the SDK makes, it also reports throttled attempts to the `RequestLimiter` that
+/// the request was sent through.
+#[derive(Debug)]
+struct TimeoutHttpClient {
+    client: reqwest::Client,
+    read_timeout: Duration,
+}
+
+impl TimeoutHttpClient {
+    fn new(retry_config: &RetryConfig) -> super::Result<Self> {
+        Ok(Self {
+            client: reqwest::ClientBuilder::default()
+                .connect_timeout(retry_config.connect_timeout())
+                .build()
+                .context(UnableToCreateClientSnafu)?,
+            read_timeout: retry_config.read_timeout(),
+        })
+    }
+}
+
+#[async_trait]
+impl HttpClient for TimeoutHttpClient {
+    async fn execute_request(
+        &self,
+        request: &azure_core::Request,
+    ) -> azure_core::Result<azure_core::Response> {
+        let response =
+            tokio::time::timeout(self.read_timeout, self.client.execute_request(request))
+                .await
+                .map_err(|err| azure_core::Error::new(ErrorKind::Io, err))??;
+        if is_throttle_status(response.status().into()) {
+            report_throttled_attempt();
+        }
+        Ok(response)
+    }
+}
+
 pub(crate) struct AzureBlobSource {
     blob_client: Arc<BlobServiceClient>,
+    /// Limits the requests sent to each container.
+    request_limiter: Arc<RequestLimiter>,
+    verify_checksums: bool,
 }
 
 impl AzureBlobSource {
+    fn blob_client_for(&self, uri: &str) -> super::Result<BlobClient> {
+        let (_, container_and_key) = parse_azure_uri(uri)?;
+        let (container, key) = container_and_key.ok_or_else(|| Error::InvalidUrl {
+            path: uri.into(),
+            source: url::ParseError::EmptyHost,
+        })?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        Ok(self
+            .blob_client
+            .container_client(container)
+            .blob_client(key))
+    }
+
     pub async fn get_client(config: &AzureConfig) -> super::Result<Arc<Self>> {
         let storage_account = if let Some(storage_account) = &config.storage_account {
             storage_account.clone()
@@ -206,12 +317,11 @@ impl AzureBlobSource {
         } else {
             None
         };
-        let blob_client = if let Some(endpoint_url) = endpoint_url {
+        let client_builder = if let Some(endpoint_url) = endpoint_url {
             ClientBuilder::with_location(
                 CloudLocation::Custom { uri: endpoint_url },
                 storage_credentials,
             )
-            .blob_service_client()
         } else if config.use_fabric_endpoint {
             ClientBuilder::with_location(
                 CloudLocation::Custom {
@@ -219,13 +329,30 @@ impl AzureBlobSource {
                 },
                 storage_credentials,
             )
-            .blob_service_client()
         } else {
-            BlobServiceClient::new(storage_account, storage_credentials)
+            ClientBuilder::new(storage_account, storage_credentials)
         };
+        let retry_config = config.retry_config();
+        let blob_client = client_builder
+            .retry(RetryOptions::exponential(
+                ExponentialRetryOptions::default()
+                    .max_retries(retry_config.num_tries.saturating_sub(1))
+                    .initial_delay(Duration::from_millis(retry_config.initial_backoff_ms)),
+            ))
+            .transport(TransportOptions::new(Arc::new(TimeoutHttpClient::new(
+                &retry_config,
+            )?)))
+            .blob_service_client();
 
         Ok(AzureBlobSource {
             blob_client: blob_client.into(),
+            request_limiter: RequestLimiter::new(
+                config.max_connections_per_io_thread as usize
+                    * get_io_pool_num_threads().expect("Should be running in tokio pool"),
+                config.max_requests_per_second,
+            )
+            .into(),
+            verify_checksums: config.verify_checksums,
         }
         .into())
     }
@@ -423,7 +550,12 @@ impl AzureBlobSource {
             responses_stream = responses_stream.delimiter(AZURE_DELIMITER.to_string());
         }
 
-        let responses_stream = responses_stream.into_stream();
+        let responses_stream = self.request_limiter.clone().limit_stream(
+            container_name.clone(),
+            io_stats.clone(),
+            responses_stream.into_stream(),
+            is_throttle_error,
+        );
 
         // Map each page of results to a page of standardized FileMetadata.
         responses_stream
@@ -464,6 +596,9 @@ impl AzureBlobSource {
             filepath: format!("{protocol}://{}/", &container.name),
             size: None,
             filetype: FileType::Directory,
+            etag: None,
+            last_modified: None,
+            content_type: None,
         }
     }
 
@@ -474,20 +609,37 @@ impl AzureBlobSource {
         blob_item: &BlobItem,
     ) -> FileMetadata {
         match blob_item {
-            BlobItem::Blob(blob) => FileMetadata {
-                filepath: format!("{protocol}://{}/{}", container_name, &blob.name),
-                size: Some(blob.properties.content_length),
-                filetype: FileType::File,
-            },
+            BlobItem::Blob(blob) => blob_properties_to_file_metadata(
+                format!("{protocol}://{}/{}", container_name, &blob.name),
+                &blob.properties,
+            ),
             BlobItem::BlobPrefix(prefix) => FileMetadata {
                 filepath: format!("{protocol}://{}/{}", container_name, &prefix.name),
                 size: None,
                 filetype: FileType::Directory,
+                etag: None,
+                last_modified: None,
+                content_type: None,
             },
         }
     }
 }
 
+fn blob_properties_to_file_metadata(filepath: String, properties: &BlobProperties) -> FileMetadata {
+    let last_modified = properties.last_modified;
+    FileMetadata {
+        filepath,
+        size: Some(properties.content_length),
+        filetype: FileType::File,
+        etag: Some(properties.etag.to_string()),
+        last_modified: DateTime::from_timestamp(
+            last_modified.unix_timestamp(),
+            last_modified.nanosecond(),
+        ),
+        content_type: Some(properties.content_type.clone()).filter(|c| !c.is_empty()),
+    }
+}
+
 #[async_trait]
 impl ObjectSource for AzureBlobSource {
     async fn get(
@@ -506,15 +658,44 @@ impl ObjectSource for AzureBlobSource {
             return Err(Error::NotAFile { path: uri.into() }.into());
         }
 
-        let container_client = self.blob_client.container_client(container);
+        let container_client = self.blob_client.container_client(&container);
         let blob_client = container_client.blob_client(key);
         let request_builder = blob_client.get();
-        let request_builder = if let Some(range) = range {
-            request_builder.range(range)
+        let (request_builder, expected_checksum) = if let Some(range) = range {
+            (request_builder.range(range), None)
+        } else if self.verify_checksums {
+            // Blobs are downloaded in chunks with ranged requests, whose responses don't include the Content-MD5 of
+            // the blob, so it's fetched upfront. The download is pinned to the same version of the blob with its ETag.
+            let properties = self
+                .request_limiter
+                .send(
+                    &container,
+                    io_stats.clone(),
+                    blob_client.get_properties().into_future(),
+                    is_throttle_error,
+                )
+                .await
+                .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?
+                .blob
+                .properties;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_head_requests(1)
+            }
+            (
+                request_builder.if_match(IfMatchCondition::Match(properties.etag.to_string())),
+                properties
+                    .content_md5
+                    .map(|md5| Checksum::Md5(*md5.as_slice())),
+            )
         } else {
-            request_builder
+            (request_builder, None)
         };
-        let blob_stream = request_builder.into_stream();
+        let blob_stream = self.request_limiter.clone().limit_stream(
+            container,
+            io_stats.clone(),
+            request_builder.into_stream(),
+            is_throttle_error,
+        );
 
         let owned_string = uri.to_string();
         let stream = blob_stream
@@ -525,12 +706,17 @@ impl ObjectSource for AzureBlobSource {
                 }
                 .into_error(e)
                 .into()
-            });
+            })
+            .boxed();
+        let stream = match expected_checksum {
+            Some(expected) => crate::checksum::verify_bytestream(stream, uri.into(), expected),
+            None => stream,
+        };
         if let Some(is) = io_stats.as_ref() {
             is.mark_get_requests(1)
         }
         Ok(GetResult::Stream(
-            io_stats_on_bytestream(Box::pin(stream), io_stats),
+            io_stats_on_bytestream(stream, io_stats),
             None,
             None,
             None,
@@ -539,14 +725,198 @@ impl ObjectSource for AzureBlobSource {
 
     async fn put(
         &self,
-        _uri: &str,
-        _data: bytes::Bytes,
-        _io_stats: Option<IOStatsRef>,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
-        todo!("PUTs to Azure blob store are not yet supported! Please file an issue.");
+        self.put_stream(
+            uri,
+            futures::stream::once(async { Ok(data) }).boxed(),
+            io_stats,
+        )
+        .await
+    }
+
+    async fn put_stream(
+        &self,
+        uri: &str,
+        data: PutStream,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let (_, container_and_key) = parse_azure_uri(uri)?;
+        let (container, key) = container_and_key.ok_or_else(|| Error::InvalidUrl {
+            path: uri.into(),
+            source: url::ParseError::EmptyHost,
+        })?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let container_client = self.blob_client.container_client(&container);
+        let blob_client = container_client.blob_client(key);
+
+        // Azure doesn't compute the MD5 of blobs created from a block list, so when checksums are enabled, it's
+        // computed while streaming the parts and stored with the blob, in addition to validating each block.
+        let blob_hasher = self
+            .verify_checksums
+            .then(|| Arc::new(std::sync::Mutex::new(md5::Context::new())));
+        let data = match &blob_hasher {
+            Some(hasher) => {
+                let hasher = hasher.clone();
+                data.inspect_ok(move |chunk| hasher.lock().unwrap().consume(chunk))
+                    .boxed()
+            }
+            None => data,
+        };
+
+        // Stage each part as an uncommitted block, then commit the block list to create the blob. If the upload
+        // fails, the staged blocks are never committed and are garbage collected by Azure, so there's nothing to
+        // abort.
+        let block_ids = upload_parts(
+            into_parts(data, PartSizes::AZURE),
+            DEFAULT_MAX_IN_FLIGHT_PARTS,
+            |index, part| {
+                let (blob_client, container, io_stats) = (&blob_client, &container, &io_stats);
+                async move {
+                    // All block ids of a blob must have the same length.
+                    let block_id = BlockId::new(format!("{index:032}"));
+                    let num_bytes = part.len();
+                    let request = blob_client.put_block(block_id.clone(), part.clone());
+                    let request = if self.verify_checksums {
+                        request.hash(Hash::MD5(md5::compute(&part).0))
+                    } else {
+                        request
+                    };
+                    self.request_limiter
+                        .send_with_body(
+                            container,
+                            io_stats.clone(),
+                            request.into_future(),
+                            is_throttle_error,
+                        )
+                        .await
+                        .context(UnableToPutFileSnafu::<String> { path: uri.into() })?;
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_put_requests(1);
+                        is.mark_bytes_uploaded(num_bytes);
+                    }
+                    Ok(block_id)
+                }
+            },
+        )
+        .await?;
+
+        let request = blob_client.put_block_list(BlockList {
+            blocks: block_ids.into_iter().map(BlobBlockType::Latest).collect(),
+        });
+        let request = match blob_hasher {
+            Some(hasher) => request.content_md5(hasher.lock().unwrap().clone().compute().0),
+            None => request,
+        };
+        self.request_limiter
+            .send(
+                &container,
+                io_stats.clone(),
+                request.into_future(),
+                is_throttle_error,
+            )
+            .await
+            .context(UnableToPutFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let blob_client = self.blob_client_for(uri)?;
+        self.request_limiter
+            .send(
+                blob_client.container_client().container_name(),
+                io_stats.clone(),
+                blob_client.delete().into_future(),
+                is_throttle_error,
+            )
+            .await
+            .context(UnableToDeleteFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src_url = self
+            .blob_client_for(src)?
+            .url()
+            .context(UnableToCopyFileSnafu::<String, String> {
+                path: src.into(),
+                dest: dest.into(),
+            })?;
+        let dest_client = self.blob_client_for(dest)?;
+        let container = dest_client.container_client().container_name();
+        let response = self
+            .request_limiter
+            .send(
+                container,
+                io_stats.clone(),
+                dest_client.copy(src_url).into_future(),
+                is_throttle_error,
+            )
+            .await
+            .context(UnableToCopyFileSnafu::<String, String> {
+                path: src.into(),
+                dest: dest.into(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1);
+        }
+
+        // Copies within a storage account are usually synchronous, but may complete asynchronously, in which case we
+        // poll the destination blob until the copy finishes.
+        let mut status = response.copy_status;
+        while status == CopyStatus::Pending {
+            tokio::time::sleep(Duration::from_millis(COPY_STATUS_POLL_INTERVAL_MS)).await;
+            let properties = self
+                .request_limiter
+                .send(
+                    container,
+                    io_stats.clone(),
+                    dest_client.get_properties().into_future(),
+                    is_throttle_error,
+                )
+                .await
+                .context(UnableToCopyFileSnafu::<String, String> {
+                    path: src.into(),
+                    dest: dest.into(),
+                })?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_head_requests(1);
+            }
+            status = properties
+                .blob
+                .properties
+                .copy_status
+                .unwrap_or(CopyStatus::Success);
+        }
+        if status != CopyStatus::Success {
+            return Err(Error::CopyNotCompleted {
+                path: src.into(),
+                dest: dest.into(),
+                status: format!("{status:?}"),
+            }
+            .into());
+        }
+        Ok(())
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let metadata = self.head(uri, io_stats).await?;
+        Ok(metadata.size.unwrap_or_default() as usize)
+    }
+
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
         let (_, container_and_key) = parse_azure_uri(uri)?;
         let (container, key) = container_and_key.ok_or_else(|| Error::InvalidUrl {
             path: uri.into(),
@@ -557,17 +927,26 @@ impl ObjectSource for AzureBlobSource {
             return Err(Error::NotAFile { path: uri.into() }.into());
         }
 
-        let container_client = self.blob_client.container_client(container);
+        let container_client = self.blob_client.container_client(&container);
         let blob_client = container_client.blob_client(key);
-        let metadata = blob_client
-            .get_properties()
+        let metadata = self
+            .request_limiter
+            .send(
+                &container,
+                io_stats.clone(),
+                blob_client.get_properties().into_future(),
+                is_throttle_error,
+            )
             .await
             .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1)
         }
 
-        Ok(metadata.blob.properties.content_length as usize)
+        Ok(blob_properties_to_file_metadata(
+            uri.to_string(),
+            &metadata.blob.properties,
+        ))
     }
 
     async fn glob(
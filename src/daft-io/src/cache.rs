@@ -0,0 +1,694 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use common_io_config::{CacheConfig, RetryConfig};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use lazy_static::lazy_static;
+use snafu::{ResultExt, Snafu};
+
+use crate::multipart::PutStream;
+use crate::object_io::{
+    FileMetadata, FileType, GetResult, LSResult, ObjectSource, StreamingRetryParams,
+};
+use crate::stats::IOStatsRef;
+use crate::FileFormat;
+
+const DEFAULT_CACHE_DIRECTORY_NAME: &str = "daft-io-cache";
+const TEMP_FILE_SUFFIX: &str = ".tmp";
+
+/// Objects larger than this fraction of the cache are not cached, so that a single large read can't evict the
+/// entire cache.
+const MAX_ENTRY_FRACTION: u64 = 4;
+
+/// Upper bound on the number of objects whose metadata is remembered, so that listing huge prefixes can't grow memory
+/// without bound.
+const MAX_RECORDED_METADATA: usize = 1 << 20;
+
+#[derive(Debug, Snafu)]
+enum Error {
+    #[snafu(display("Unable to create cache directory {}: {}", path, source))]
+    UnableToCreateCacheDirectory {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to list cache directory {}: {}", path, source))]
+    UnableToListCacheDirectory {
+        path: String,
+        source: std::io::Error,
+    },
+}
+
+impl From<Error> for super::Error {
+    fn from(error: Error) -> Self {
+        use Error::*;
+        match error {
+            UnableToCreateCacheDirectory { path, source }
+            | UnableToListCacheDirectory { path, source } => {
+                super::Error::UnableToWriteToFile { path, source }
+            }
+        }
+    }
+}
+
+lazy_static! {
+    /// Caches are shared by all clients in the process that use the same directory, so that they evict from a single
+    /// LRU and respect a single size limit.
+    static ref DISK_CACHES: Mutex<HashMap<PathBuf, Arc<DiskCache>>> = Mutex::new(HashMap::new());
+}
+
+struct CacheEntry {
+    size: u64,
+    last_access: u64,
+}
+
+#[derive(Default)]
+struct LruState {
+    entries: HashMap<String, CacheEntry>,
+    // Entry names ordered by last access, from least to most recently used.
+    access_order: BTreeMap<u64, String>,
+    total_bytes: u64,
+    clock: u64,
+}
+
+impl LruState {
+    fn touch(&mut self, name: &str) -> bool {
+        self.clock += 1;
+        let clock = self.clock;
+        match self.entries.get_mut(name) {
+            Some(entry) => {
+                self.access_order.remove(&entry.last_access);
+                entry.last_access = clock;
+                self.access_order.insert(clock, name.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn insert(&mut self, name: String, size: u64) {
+        self.remove(&name);
+        self.clock += 1;
+        self.access_order.insert(self.clock, name.clone());
+        self.entries.insert(
+            name,
+            CacheEntry {
+                size,
+                last_access: self.clock,
+            },
+        );
+        self.total_bytes += size;
+    }
+
+    fn remove(&mut self, name: &str) {
+        if let Some(entry) = self.entries.remove(name) {
+            self.access_order.remove(&entry.last_access);
+            self.total_bytes -= entry.size;
+        }
+    }
+
+    /// Evict least recently used entries until the cache fits in `max_size_bytes`, returning the evicted names.
+    fn evict(&mut self, max_size_bytes: u64) -> Vec<String> {
+        let mut evicted = vec![];
+        while self.total_bytes > max_size_bytes
+            && let Some((_, name)) = self.access_order.pop_first()
+        {
+            if let Some(entry) = self.entries.remove(&name) {
+                self.total_bytes -= entry.size;
+            }
+            evicted.push(name);
+        }
+        evicted
+    }
+}
+
+/// A bounded directory of cached byte ranges, evicting the least recently used data once it grows beyond its size
+/// limit.
+///
+/// Each entry is stored in its own file, which is written to a temporary file and then renamed into place so that
+/// readers never observe partially-written entries.
+pub(crate) struct DiskCache {
+    directory: PathBuf,
+    max_size_bytes: u64,
+    state: Mutex<LruState>,
+}
+
+impl DiskCache {
+    pub fn get_or_create(config: &CacheConfig) -> super::Result<Arc<Self>> {
+        let directory = config
+            .directory
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::temp_dir().join(DEFAULT_CACHE_DIRECTORY_NAME));
+        let mut caches = DISK_CACHES.lock().unwrap();
+        if let Some(cache) = caches.get(&directory) {
+            return Ok(cache.clone());
+        }
+        let cache = Arc::new(Self::new(directory.clone(), config.max_size_bytes)?);
+        caches.insert(directory, cache.clone());
+        Ok(cache)
+    }
+
+    /// Open the cache in `directory`, picking up entries left behind by previous processes.
+    fn new(directory: PathBuf, max_size_bytes: u64) -> super::Result<Self> {
+        let path = directory.to_string_lossy().to_string();
+        std::fs::create_dir_all(&directory)
+            .with_context(|_| UnableToCreateCacheDirectorySnafu { path: path.clone() })?;
+
+        let mut existing = vec![];
+        for dir_entry in std::fs::read_dir(&directory)
+            .with_context(|_| UnableToListCacheDirectorySnafu { path: path.clone() })?
+        {
+            let dir_entry = dir_entry
+                .with_context(|_| UnableToListCacheDirectorySnafu { path: path.clone() })?;
+            let name = dir_entry.file_name().to_string_lossy().to_string();
+            let Ok(metadata) = dir_entry.metadata() else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+            if name.ends_with(TEMP_FILE_SUFFIX) {
+                // Left behind by a process that died while writing an entry.
+                let _ = std::fs::remove_file(dir_entry.path());
+                continue;
+            }
+            existing.push((metadata.modified().ok(), name, metadata.len()));
+        }
+        // Treat the least recently modified entries as the least recently used.
+        existing.sort();
+
+        let cache = Self {
+            directory,
+            max_size_bytes,
+            state: Mutex::new(LruState::default()),
+        };
+        let evicted = {
+            let mut state = cache.state.lock().unwrap();
+            for (_, name, size) in existing {
+                state.insert(name, size);
+            }
+            state.evict(max_size_bytes)
+        };
+        for name in evicted {
+            let _ = std::fs::remove_file(cache.entry_path(&name));
+        }
+        Ok(cache)
+    }
+
+    /// Name of the entry holding `range` of the version of the object at `uri` described by `metadata`, or `None`
+    /// if the metadata doesn't identify a version of the object.
+    ///
+    /// The object's ETag, last modified time and size are all included in the key, so that entries are invalidated
+    /// whenever an object is overwritten, including by other processes.
+    fn entry_name(
+        uri: &str,
+        metadata: &FileMetadata,
+        range: Option<&Range<usize>>,
+    ) -> Option<String> {
+        if metadata.etag.is_none() && metadata.last_modified.is_none() {
+            return None;
+        }
+        let range = range.map_or_else(|| "full".to_string(), |r| format!("{}-{}", r.start, r.end));
+        let etag = metadata.etag.as_deref().unwrap_or_default();
+        let last_modified = metadata
+            .last_modified
+            .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true))
+            .unwrap_or_default();
+        let size = metadata.size.map(|s| s.to_string()).unwrap_or_default();
+        Some(format!(
+            "{:x}",
+            md5::compute(format!("{uri}\n{etag}\n{last_modified}\n{size}\n{range}"))
+        ))
+    }
+
+    fn entry_path(&self, name: &str) -> PathBuf {
+        self.directory.join(name)
+    }
+
+    fn should_cache(&self, num_bytes: usize) -> bool {
+        num_bytes as u64 <= self.max_size_bytes / MAX_ENTRY_FRACTION
+    }
+
+    async fn read(&self, name: &str) -> Option<Bytes> {
+        if !self.state.lock().unwrap().touch(name) {
+            return None;
+        }
+        match tokio::fs::read(self.entry_path(name)).await {
+            Ok(data) => Some(data.into()),
+            Err(err) => {
+                // The entry may have been evicted by another process sharing the cache directory.
+                log::debug!("Failed to read cache entry {name}: {err}");
+                self.state.lock().unwrap().remove(name);
+                None
+            }
+        }
+    }
+
+    async fn write(&self, name: &str, data: &Bytes) {
+        let path = self.entry_path(name);
+        let temp_path = path.with_extension(format!("{}{TEMP_FILE_SUFFIX}", rand::random::<u64>()));
+        if let Err(err) = write_atomic(&temp_path, &path, data).await {
+            log::warn!("Failed to write cache entry to {}: {err}", path.display());
+            let _ = tokio::fs::remove_file(&temp_path).await;
+            return;
+        }
+        let evicted = {
+            let mut state = self.state.lock().unwrap();
+            state.insert(name.to_string(), data.len() as u64);
+            state.evict(self.max_size_bytes)
+        };
+        for name in evicted {
+            if let Err(err) = tokio::fs::remove_file(self.entry_path(&name)).await {
+                log::debug!("Failed to remove evicted cache entry {name}: {err}");
+            }
+        }
+    }
+}
+
+async fn write_atomic(temp_path: &Path, path: &Path, data: &Bytes) -> std::io::Result<()> {
+    tokio::fs::write(temp_path, data).await?;
+    tokio::fs::rename(temp_path, path).await
+}
+
+/// An `ObjectSource` that serves reads through a local `DiskCache`, falling back to the wrapped source on misses.
+///
+/// Cache entries are keyed by the version of the object described by its metadata. The metadata recorded when the
+/// object was listed, or fetched with `head` by an earlier read, is reused for `metadata_ttl` so that reads don't each
+/// issue a metadata request, which would double the latency of the small reads the cache is for. Objects whose
+/// metadata has neither an ETag nor a last modified time are never cached.
+pub(crate) struct CachingObjectSource {
+    inner: Arc<dyn ObjectSource>,
+    cache: Arc<DiskCache>,
+    /// Retry settings for reads from the wrapped source that are streamed into the cache.
+    retry_config: RetryConfig,
+    metadata_ttl: Duration,
+    /// Metadata of objects by URI, with the time it was recorded.
+    metadata: Mutex<HashMap<String, (FileMetadata, Instant)>>,
+}
+
+impl CachingObjectSource {
+    pub fn new(
+        inner: Arc<dyn ObjectSource>,
+        cache: Arc<DiskCache>,
+        retry_config: RetryConfig,
+        metadata_ttl: Duration,
+    ) -> Self {
+        Self {
+            inner,
+            cache,
+            retry_config,
+            metadata_ttl,
+            metadata: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Metadata of the object at `uri`, reusing the recorded metadata if it is younger than `metadata_ttl`.
+    async fn object_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<FileMetadata> {
+        if let Some((metadata, recorded_at)) = self.metadata.lock().unwrap().get(uri)
+            && recorded_at.elapsed() < self.metadata_ttl
+        {
+            return Ok(metadata.clone());
+        }
+        let metadata = self.inner.head(uri, io_stats).await?;
+        self.record_metadata(uri, &metadata);
+        Ok(metadata)
+    }
+
+    fn record_metadata(&self, uri: &str, metadata: &FileMetadata) {
+        if self.metadata_ttl.is_zero()
+            || metadata.filetype != FileType::File
+            || (metadata.etag.is_none() && metadata.last_modified.is_none())
+        {
+            return;
+        }
+        let mut recorded = self.metadata.lock().unwrap();
+        if recorded.len() >= MAX_RECORDED_METADATA {
+            recorded.retain(|_, (_, recorded_at)| recorded_at.elapsed() < self.metadata_ttl);
+            if recorded.len() >= MAX_RECORDED_METADATA {
+                recorded.clear();
+            }
+        }
+        recorded.insert(uri.to_string(), (metadata.clone(), Instant::now()));
+    }
+
+    /// Forget the recorded metadata of `uri` after it is modified through this source.
+    fn forget_metadata(&self, uri: &str) {
+        self.metadata.lock().unwrap().remove(uri);
+    }
+}
+
+fn bytes_get_result(data: Bytes) -> GetResult {
+    let num_bytes = data.len();
+    GetResult::Stream(
+        futures::stream::once(async move { Ok(data) }).boxed(),
+        Some(num_bytes),
+        None,
+        None,
+    )
+}
+
+#[async_trait]
+impl ObjectSource for CachingObjectSource {
+    async fn get(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<GetResult> {
+        let metadata = match self.object_metadata(uri, io_stats.clone()).await {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                // Let the wrapped source surface the error (if any) for the read itself.
+                log::debug!("Bypassing cache for {uri}, unable to fetch object metadata: {err}");
+                return self.inner.get(uri, range, io_stats).await;
+            }
+        };
+        let (Some(name), Some(object_size)) = (
+            DiskCache::entry_name(uri, &metadata, range.as_ref()),
+            metadata.size,
+        ) else {
+            log::debug!("Bypassing cache for {uri}, its metadata doesn't identify its version");
+            return self.inner.get(uri, range, io_stats).await;
+        };
+        if let Some(data) = self.cache.read(&name).await {
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_cache_hits(1);
+            }
+            return Ok(bytes_get_result(data));
+        }
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_cache_misses(1);
+        }
+
+        let get_result = self.inner.get(uri, range.clone(), io_stats.clone()).await?;
+        let num_bytes = range.as_ref().map_or(object_size as usize, |r| r.len());
+        if !self.cache.should_cache(num_bytes) {
+            return Ok(get_result);
+        }
+        let data = get_result
+            .with_retry(StreamingRetryParams::new(
+                self.inner.clone(),
+                uri.to_string(),
+                range,
+                io_stats,
+                self.retry_config,
+            ))
+            .bytes()
+            .await?;
+        self.cache.write(&name, &data).await;
+        Ok(bytes_get_result(data))
+    }
+
+    async fn put(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.forget_metadata(uri);
+        self.inner.put(uri, data, io_stats).await
+    }
+
+    async fn put_stream(
+        &self,
+        uri: &str,
+        data: PutStream,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.forget_metadata(uri);
+        self.inner.put_stream(uri, data, io_stats).await
+    }
+
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        self.inner.get_size(uri, io_stats).await
+    }
+
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
+        let metadata = self.inner.head(uri, io_stats).await?;
+        self.record_metadata(uri, &metadata);
+        Ok(metadata)
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.forget_metadata(uri);
+        self.inner.delete(uri, io_stats).await
+    }
+
+    async fn delete_many(
+        &self,
+        uris: &[String],
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        for uri in uris {
+            self.forget_metadata(uri);
+        }
+        self.inner.delete_many(uris, io_stats).await
+    }
+
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.forget_metadata(dest);
+        self.inner.copy(src, dest, io_stats).await
+    }
+
+    async fn rename(
+        &self,
+        src: &str,
+        dest: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.forget_metadata(src);
+        self.forget_metadata(dest);
+        self.inner.rename(src, dest, io_stats).await
+    }
+
+    async fn glob(
+        self: Arc<Self>,
+        glob_path: &str,
+        fanout_limit: Option<usize>,
+        page_size: Option<i32>,
+        limit: Option<usize>,
+        io_stats: Option<IOStatsRef>,
+        file_format: Option<FileFormat>,
+    ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+        let files = self
+            .inner
+            .clone()
+            .glob(
+                glob_path,
+                fanout_limit,
+                page_size,
+                limit,
+                io_stats,
+                file_format,
+            )
+            .await?;
+        Ok(files
+            .inspect(move |file| {
+                if let Ok(file) = file {
+                    self.record_metadata(&file.filepath, file);
+                }
+            })
+            .boxed())
+    }
+
+    async fn ls(
+        &self,
+        path: &str,
+        posix: bool,
+        continuation_token: Option<&str>,
+        page_size: Option<i32>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<LSResult> {
+        let result = self
+            .inner
+            .ls(path, posix, continuation_token, page_size, io_stats)
+            .await?;
+        for file in &result.files {
+            self.record_metadata(&file.filepath, file);
+        }
+        Ok(result)
+    }
+
+    async fn iter_dir(
+        &self,
+        uri: &str,
+        posix: bool,
+        page_size: Option<i32>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<BoxStream<super::Result<FileMetadata>>> {
+        let files = self.inner.iter_dir(uri, posix, page_size, io_stats).await?;
+        Ok(files
+            .inspect(|file| {
+                if let Ok(file) = file {
+                    self.record_metadata(&file.filepath, file);
+                }
+            })
+            .boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    use bytes::Bytes;
+
+    use super::{CachingObjectSource, DiskCache};
+    use crate::object_io::ObjectSource;
+    use crate::retry::FALLBACK_RETRY_CONFIG;
+    use crate::{IOStatsContext, LocalSource, Result};
+
+    #[tokio::test]
+    async fn test_disk_cache_evicts_lru_by_bytes() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(dir.path().to_path_buf(), 100)?;
+
+        cache.write("a", &Bytes::from(vec![0u8; 20])).await;
+        cache.write("b", &Bytes::from(vec![1u8; 20])).await;
+        cache.write("c", &Bytes::from(vec![2u8; 50])).await;
+        // Reading "a" makes "b" the least recently used entry.
+        assert_eq!(cache.read("a").await.unwrap().len(), 20);
+        cache.write("d", &Bytes::from(vec![3u8; 20])).await;
+
+        assert!(cache.read("b").await.is_none());
+        assert!(!dir.path().join("b").exists());
+        assert!(cache.read("a").await.is_some());
+        assert!(cache.read("c").await.is_some());
+        assert!(cache.read("d").await.is_some());
+
+        // Reopening the cache picks up the existing entries.
+        let reopened = DiskCache::new(dir.path().to_path_buf(), 100)?;
+        assert_eq!(
+            reopened.read("c").await.unwrap(),
+            Bytes::from(vec![2u8; 50])
+        );
+        assert!(reopened.read("b").await.is_none());
+        Ok(())
+    }
+
+    /// Overwrite the file at `path` outside of the cache, e.g. by another process, with data of the same size.
+    fn overwrite_externally(path: &std::path::Path, data: &[u8]) {
+        std::fs::write(path, data).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_caching_object_source() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Arc::new(DiskCache::new(dir.path().join("cache"), 1024 * 1024)?);
+        // Without a metadata TTL, every read checks the object's current version.
+        let source = CachingObjectSource::new(
+            LocalSource::get_client().await?,
+            cache,
+            FALLBACK_RETRY_CONFIG,
+            Duration::ZERO,
+        );
+        let path = format!("file://{}/data.bin", dir.path().to_string_lossy());
+        let io_stats = IOStatsContext::new("test_caching_object_source");
+
+        source
+            .put(&path, Bytes::from_static(b"hello world"), None)
+            .await?;
+        for _ in 0..2 {
+            let data = source
+                .get(&path, Some(0..5), Some(io_stats.clone()))
+                .await?
+                .bytes()
+                .await?;
+            assert_eq!(&data[..], b"hello");
+        }
+        assert_eq!(io_stats.load_cache_misses(), 1);
+        assert_eq!(io_stats.load_cache_hits(), 1);
+
+        // Overwriting the object through the cache invalidates the cached data.
+        source
+            .put(&path, Bytes::from_static(b"goodbye world"), None)
+            .await?;
+        let data = source
+            .get(&path, Some(0..5), Some(io_stats.clone()))
+            .await?
+            .bytes()
+            .await?;
+        assert_eq!(&data[..], b"goodb");
+        assert_eq!(io_stats.load_cache_misses(), 2);
+
+        // So does overwriting it with the same size outside of the cache, e.g. by another process.
+        overwrite_externally(&dir.path().join("data.bin"), b"farewell ... ");
+        let data = source
+            .get(&path, Some(0..5), Some(io_stats.clone()))
+            .await?
+            .bytes()
+            .await?;
+        assert_eq!(&data[..], b"farew");
+        assert_eq!(io_stats.load_cache_misses(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_caching_object_source_reuses_metadata() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Arc::new(DiskCache::new(dir.path().join("cache"), 1024 * 1024)?);
+        let source = Arc::new(CachingObjectSource::new(
+            LocalSource::get_client().await?,
+            cache,
+            FALLBACK_RETRY_CONFIG,
+            Duration::from_secs(3600),
+        ));
+        let path = format!("file://{}/data.bin", dir.path().to_string_lossy());
+        let io_stats = IOStatsContext::new("test_caching_object_source_reuses_metadata");
+        std::fs::write(dir.path().join("data.bin"), b"hello world").unwrap();
+
+        // The metadata recorded when listing the object is used by reads until the TTL expires, so an external
+        // overwrite isn't noticed by the second read.
+        let listed = source
+            .ls(
+                &format!("file://{}", dir.path().to_string_lossy()),
+                true,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        assert!(listed.files.iter().any(|f| f.filepath == path));
+        let read = |source: Arc<CachingObjectSource>| {
+            let (path, io_stats) = (path.clone(), io_stats.clone());
+            async move {
+                source
+                    .get(&path, Some(0..5), Some(io_stats))
+                    .await?
+                    .bytes()
+                    .await
+            }
+        };
+        assert_eq!(&read(source.clone()).await?[..], b"hello");
+        overwrite_externally(&dir.path().join("data.bin"), b"goodbye ...");
+        assert_eq!(&read(source.clone()).await?[..], b"hello");
+        assert_eq!(io_stats.load_cache_misses(), 1);
+        assert_eq!(io_stats.load_cache_hits(), 1);
+
+        // Writing through the cache forgets the recorded metadata.
+        source
+            .put(&path, Bytes::from_static(b"farewell ..."), None)
+            .await?;
+        assert_eq!(&read(source.clone()).await?[..], b"farew");
+        assert_eq!(io_stats.load_cache_misses(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::{Display, Formatter};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bytes::Bytes;
+use futures::{stream::BoxStream, StreamExt};
+
+/// A checksum of the full contents of an object, as reported by (or sent to) an object store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Checksum {
+    Md5([u8; 16]),
+    Crc32c(u32),
+}
+
+impl Checksum {
+    /// Parses an S3 ETag, which is the hex-encoded MD5 of the object for objects uploaded in a single request.
+    ///
+    /// Returns None for ETags of multipart uploads (`"<hex>-<num parts>"`), which aren't a digest of the object.
+    pub(crate) fn from_s3_etag(etag: &str) -> Option<Self> {
+        let etag = etag.trim_matches('"');
+        if etag.len() != 32 {
+            return None;
+        }
+        let mut digest = [0u8; 16];
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(etag.get(2 * i..2 * i + 2)?, 16).ok()?;
+        }
+        Some(Self::Md5(digest))
+    }
+
+    /// Parses a base64-encoded big-endian CRC32C, as used by Google Cloud Storage.
+    pub(crate) fn crc32c_from_base64(encoded: &str) -> Option<Self> {
+        let crc = STANDARD.decode(encoded).ok()?;
+        Some(Self::Crc32c(u32::from_be_bytes(crc.try_into().ok()?)))
+    }
+
+    /// Returns the checksum in the base64 encoding used by HTTP headers.
+    pub(crate) fn to_base64(self) -> String {
+        match self {
+            Self::Md5(digest) => STANDARD.encode(digest),
+            Self::Crc32c(crc) => STANDARD.encode(crc.to_be_bytes()),
+        }
+    }
+}
+
+impl Display for Checksum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Md5(digest) => write!(f, "MD5 {:x}", md5::Digest(*digest)),
+            Self::Crc32c(crc) => write!(f, "CRC32C {crc:08x}"),
+        }
+    }
+}
+
+/// Incrementally computes a [`Checksum`].
+pub(crate) enum Hasher {
+    Md5(md5::Context),
+    Crc32c(u32),
+}
+
+impl Hasher {
+    pub(crate) fn md5() -> Self {
+        Self::Md5(md5::Context::new())
+    }
+
+    pub(crate) fn crc32c() -> Self {
+        Self::Crc32c(0)
+    }
+
+    /// Returns a hasher for the same algorithm as `checksum`.
+    pub(crate) fn for_checksum(checksum: &Checksum) -> Self {
+        match checksum {
+            Checksum::Md5(_) => Self::md5(),
+            Checksum::Crc32c(_) => Self::crc32c(),
+        }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Md5(context) => context.consume(data),
+            Self::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, data),
+        }
+    }
+
+    pub(crate) fn finalize(self) -> Checksum {
+        match self {
+            Self::Md5(context) => Checksum::Md5(context.compute().0),
+            Self::Crc32c(crc) => Checksum::Crc32c(crc),
+        }
+    }
+}
+
+/// Wraps a stream of the full contents of the object at `path`, failing with [`super::Error::ChecksumMismatch`]
+/// once the stream is exhausted if the contents don't match `expected`.
+pub(crate) fn verify_bytestream(
+    mut stream: BoxStream<'static, super::Result<Bytes>>,
+    path: String,
+    expected: Checksum,
+) -> BoxStream<'static, super::Result<Bytes>> {
+    let mut hasher = Hasher::for_checksum(&expected);
+    async_stream::stream! {
+        while let Some(val) = stream.next().await {
+            if let Ok(ref val) = val {
+                hasher.update(val);
+            }
+            let is_err = val.is_err();
+            yield val;
+            if is_err {
+                return;
+            }
+        }
+        let actual = hasher.finalize();
+        if actual != expected {
+            yield Err(super::Error::ChecksumMismatch {
+                path,
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+    }
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::{verify_bytestream, Checksum, Hasher};
+
+    #[test]
+    fn test_parse_checksums() {
+        let md5 = Checksum::Md5(md5::compute(b"hello world").0);
+        assert_eq!(
+            Checksum::from_s3_etag("\"5eb63bbbe01eeed093cb22bb8f5acdc3\""),
+            Some(md5)
+        );
+        assert_eq!(
+            Checksum::from_s3_etag("\"5eb63bbbe01eeed093cb22bb8f5acdc3-2\""),
+            None
+        );
+        assert_eq!(md5.to_base64(), "XrY7u+Ae7tCTyyK7j1rNww==");
+
+        let crc = Checksum::crc32c_from_base64("yZRlqg==").unwrap();
+        assert_eq!(crc, Checksum::Crc32c(0xc99465aa));
+        let mut hasher = Hasher::for_checksum(&crc);
+        hasher.update(b"hello world");
+        assert_eq!(hasher.finalize(), crc);
+        assert_eq!(crc.to_base64(), "yZRlqg==");
+    }
+
+    #[tokio::test]
+    async fn test_verify_bytestream() -> crate::Result<()> {
+        let chunks = || futures::stream::iter(["hello ", "world"].map(|s| Ok(s.into()))).boxed();
+        let mut hasher = Hasher::crc32c();
+        hasher.update(b"hello world");
+        let expected = hasher.finalize();
+
+        let verified: Vec<_> = verify_bytestream(chunks(), "a".into(), expected)
+            .try_collect()
+            .await?;
+        assert_eq!(verified.concat(), b"hello world");
+
+        let err = verify_bytestream(chunks(), "a".into(), Checksum::Crc32c(0))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::ChecksumMismatch { ref path, .. } if path == "a"));
+        Ok(())
+    }
+}
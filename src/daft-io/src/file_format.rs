@@ -14,6 +14,13 @@ pub enum FileFormat {
     Parquet,
     Csv,
     Json,
+    Ipc,
+    Avro,
+    Orc,
+    Xlsx,
+    Text,
+    FixedWidth,
+    Files,
     Database,
     Python,
 }
@@ -26,6 +33,13 @@ impl FileFormat {
             Self::Parquet => "parquet",
             Self::Csv => "csv",
             Self::Json => "json",
+            Self::Ipc => "arrow",
+            Self::Avro => "avro",
+            Self::Orc => "orc",
+            Self::Xlsx => "xlsx",
+            Self::Text => "txt",
+            Self::FixedWidth => "txt",
+            Self::Files => "bin",
             Self::Database => "db",
             Self::Python => "py",
         }
@@ -44,6 +58,29 @@ impl FromStr for FileFormat {
             Ok(Csv)
         } else if file_format.trim().eq_ignore_ascii_case("json") {
             Ok(Json)
+        } else if ["ipc", "arrow", "feather"]
+            .iter()
+            .any(|name| file_format.trim().eq_ignore_ascii_case(name))
+        {
+            Ok(Ipc)
+        } else if file_format.trim().eq_ignore_ascii_case("avro") {
+            Ok(Avro)
+        } else if file_format.trim().eq_ignore_ascii_case("orc") {
+            Ok(Orc)
+        } else if ["xlsx", "excel"]
+            .iter()
+            .any(|name| file_format.trim().eq_ignore_ascii_case(name))
+        {
+            Ok(Xlsx)
+        } else if file_format.trim().eq_ignore_ascii_case("text") {
+            Ok(Text)
+        } else if ["fixed_width", "fixed-width"]
+            .iter()
+            .any(|name| file_format.trim().eq_ignore_ascii_case(name))
+        {
+            Ok(FixedWidth)
+        } else if file_format.trim().eq_ignore_ascii_case("files") {
+            Ok(Files)
         } else if file_format.trim().eq_ignore_ascii_case("database") {
             Ok(Database)
         } else {
@@ -1,31 +1,47 @@
+use std::future::Future;
 use std::ops::Range;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use futures::stream::BoxStream;
-use futures::TryStreamExt;
+use futures::{StreamExt, TryStreamExt};
 use google_cloud_storage::client::google_cloud_auth::credentials::CredentialsFile;
 use google_cloud_storage::client::ClientConfig;
 use google_cloud_token::{TokenSource, TokenSourceProvider};
 
 use async_trait::async_trait;
+use chrono::DateTime;
 use google_cloud_storage::client::Client;
+use google_cloud_storage::http::objects::delete::DeleteObjectRequest;
 use google_cloud_storage::http::objects::get::GetObjectRequest;
+use google_cloud_storage::http::objects::rewrite::RewriteObjectRequest;
 
+use google_cloud_storage::http::error::ErrorResponse;
 use google_cloud_storage::http::objects::list::ListObjectsRequest;
+use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
+use google_cloud_storage::http::objects::Object;
+use google_cloud_storage::http::resumable_upload_client::{
+    ChunkSize, ResumableUploadClient, UploadStatus,
+};
 use google_cloud_storage::http::Error as GError;
 use snafu::IntoError;
 use snafu::ResultExt;
 use snafu::Snafu;
 
+use crate::checksum::{Checksum, Hasher};
+use crate::get_io_pool_num_threads;
+use crate::multipart::{into_parts, PartSizes, PutStream, DEFAULT_PART_SIZE};
 use crate::object_io::FileMetadata;
 use crate::object_io::FileType;
 use crate::object_io::LSResult;
 use crate::object_io::ObjectSource;
+use crate::retry::{is_retryable_status, with_retries};
 use crate::stats::IOStatsRef;
 use crate::stream_utils::io_stats_on_bytestream;
+use crate::throttle::{is_throttle_status, RequestLimiter};
 use crate::FileFormat;
 use crate::GetResult;
-use common_io_config::GCSConfig;
+use common_io_config::{GCSConfig, RetryConfig};
 
 const GCS_DELIMITER: &str = "/";
 const GCS_SCHEME: &str = "gs";
@@ -42,11 +58,32 @@ enum Error {
     #[snafu(display("Unable to read data from {}: {}", path, source))]
     UnableToReadBytes { path: String, source: GError },
 
+    #[snafu(display("Unable to put file to {}: {}", path, source))]
+    UnableToPutFile { path: String, source: GError },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile { path: String, source: GError },
+
+    #[snafu(display("Unable to copy {} to {}: {}", path, dest, source))]
+    UnableToCopyFile {
+        path: String,
+        dest: String,
+        source: GError,
+    },
+
+    #[snafu(display("Timed out waiting for a response for {}", path))]
+    ReadTimeout {
+        path: String,
+        source: tokio::time::error::Elapsed,
+    },
+
     #[snafu(display("Unable to parse URL: \"{}\"", path))]
     InvalidUrl {
         path: String,
         source: url::ParseError,
     },
+    #[snafu(display("Unable to create Http Client {}", source))]
+    UnableToCreateClient { source: reqwest::Error },
     #[snafu(display("Unable to load Credentials: {}", source))]
     UnableToLoadCredentials {
         source: google_cloud_storage::client::google_cloud_auth::error::Error,
@@ -63,6 +100,9 @@ impl From<Error> for super::Error {
         match error {
             UnableToReadBytes { path, source }
             | UnableToOpenFile { path, source }
+            | UnableToPutFile { path, source }
+            | UnableToDeleteFile { path, source }
+            | UnableToCopyFile { path, source, .. }
             | UnableToListObjects { path, source } => match source {
                 GError::HttpClient(err) => match err.status().map(|s| s.as_u16()) {
                     Some(404) | Some(410) => super::Error::NotFound {
@@ -103,7 +143,15 @@ impl From<Error> for super::Error {
                 path: path.into(),
                 source: error.into(),
             },
+            ReadTimeout { path, source } => super::Error::ReadTimeout {
+                path,
+                source: source.into(),
+            },
             InvalidUrl { path, source } => super::Error::InvalidUrl { path, source },
+            UnableToCreateClient { source } => super::Error::UnableToCreateClient {
+                store: super::SourceType::GCS,
+                source: source.into(),
+            },
             UnableToLoadCredentials { source } => super::Error::UnableToLoadCredentials {
                 store: super::SourceType::GCS,
                 source: source.into(),
@@ -113,7 +161,95 @@ impl From<Error> for super::Error {
     }
 }
 
-struct GCSClientWrapper(Client);
+/// Returns whether GCS rejected a request because it is being sent requests too quickly (e.g. `429 Too Many Requests`).
+fn is_throttle_error(err: &GError) -> bool {
+    match err {
+        GError::HttpClient(err) => err
+            .status()
+            .is_some_and(|status| is_throttle_status(status.as_u16())),
+        GError::Response(err) => is_throttle_status(err.code),
+        GError::TokenSource(_) => false,
+    }
+}
+
+/// Returns whether a request that failed with `err` may succeed if it's retried, e.g. because the connection was
+/// dropped or GCS was temporarily unavailable.
+fn is_retryable_error(err: &Error) -> bool {
+    use Error::*;
+    match err {
+        ReadTimeout { .. } => true,
+        UnableToReadBytes { source, .. }
+        | UnableToOpenFile { source, .. }
+        | UnableToPutFile { source, .. }
+        | UnableToDeleteFile { source, .. }
+        | UnableToCopyFile { source, .. }
+        | UnableToListObjects { source, .. } => match source {
+            GError::HttpClient(err) => match err.status() {
+                Some(status) => is_retryable_status(status.as_u16()),
+                None => err.is_connect() || err.is_timeout() || err.is_request(),
+            },
+            GError::Response(err) => is_retryable_status(err.code),
+            GError::TokenSource(_) => false,
+        },
+        _ => false,
+    }
+}
+
+struct GCSClientWrapper {
+    client: Client,
+    /// HTTP client used by `client`, for requests that its API doesn't support.
+    http: reqwest::Client,
+    /// Limits the requests sent to each bucket.
+    request_limiter: RequestLimiter,
+    retry_config: RetryConfig,
+    verify_checksums: bool,
+}
+
+/// Uploads the last chunk of a resumable upload along with the CRC32C and MD5 of the whole object in an
+/// `x-goog-hash` header, which GCS validates before finalizing the upload.
+///
+/// The resumable upload client doesn't support sending extra headers, so this sends the request directly to the
+/// upload's session URL.
+async fn upload_last_chunk(
+    http: &reqwest::Client,
+    uploader: &ResumableUploadClient,
+    data: bytes::Bytes,
+    chunk_size: &ChunkSize,
+    checksums: &[Checksum],
+) -> Result<UploadStatus, GError> {
+    #[derive(serde::Deserialize)]
+    struct ErrorWrapper {
+        error: ErrorResponse,
+    }
+
+    let hashes = checksums
+        .iter()
+        .map(|checksum| match checksum {
+            Checksum::Crc32c(_) => format!("crc32c={}", checksum.to_base64()),
+            Checksum::Md5(_) => format!("md5={}", checksum.to_base64()),
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    let response = http
+        .put(uploader.url())
+        .header(reqwest::header::CONTENT_RANGE, chunk_size.to_string())
+        .header(reqwest::header::CONTENT_LENGTH, chunk_size.size())
+        .header("x-goog-hash", hashes)
+        .body(data)
+        .send()
+        .await?;
+    if response.status().as_u16() == 308 {
+        return Ok(UploadStatus::ResumeIncomplete);
+    }
+    if let Err(err) = response.error_for_status_ref() {
+        return Err(response
+            .json::<ErrorWrapper>()
+            .await
+            .map(|wrapper| GError::Response(wrapper.error))
+            .unwrap_or(GError::HttpClient(err)));
+    }
+    Ok(UploadStatus::Ok(response.json().await?))
+}
 
 fn parse_uri(uri: &url::Url) -> super::Result<(&str, &str)> {
     let bucket = match uri.host_str() {
@@ -128,7 +264,50 @@ fn parse_uri(uri: &url::Url) -> super::Result<(&str, &str)> {
     Ok((bucket, key))
 }
 
+fn object_to_file_metadata(filepath: String, object: Object) -> FileMetadata {
+    FileMetadata {
+        filepath,
+        size: Some(object.size as u64),
+        filetype: FileType::File,
+        etag: Some(object.etag),
+        last_modified: object.updated.and_then(|updated| {
+            DateTime::from_timestamp(updated.unix_timestamp(), updated.nanosecond())
+        }),
+        content_type: object.content_type,
+    }
+}
+
 impl GCSClientWrapper {
+    /// Sends the request built by `request` to `bucket` once the bucket admits it, timing out if no response is
+    /// received within the read timeout and retrying on transient errors, which are wrapped with `context`.
+    async fn send<T, Fut, C>(
+        &self,
+        bucket: &str,
+        path: &str,
+        io_stats: Option<IOStatsRef>,
+        context: C,
+        request: impl Fn() -> Fut,
+    ) -> Result<T, Error>
+    where
+        Fut: Future<Output = Result<T, GError>>,
+        C: IntoError<Error, Source = GError> + Clone,
+    {
+        with_retries(
+            &self.retry_config,
+            &format!("send request for {path}"),
+            is_retryable_error,
+            || async {
+                let permit = self.request_limiter.acquire(bucket, io_stats.clone()).await;
+                let response = tokio::time::timeout(self.retry_config.read_timeout(), request())
+                    .await
+                    .context(ReadTimeoutSnafu { path })?;
+                permit.finish(&response, is_throttle_error);
+                response.map_err(|err| context.clone().into_error(err))
+            },
+        )
+        .await
+    }
+
     async fn get(
         &self,
         uri: &str,
@@ -141,35 +320,71 @@ impl GCSClientWrapper {
             return Err(Error::NotAFile { path: uri.into() }.into());
         }
 
-        let client = &self.0;
-        let req = GetObjectRequest {
+        let client = &self.client;
+        let mut req = GetObjectRequest {
             bucket: bucket.into(),
             object: key.into(),
             ..Default::default()
         };
         use google_cloud_storage::http::objects::download::Range as GRange;
-        let (grange, size) = if let Some(range) = range {
+        let (grange, size, expected_checksum) = if let Some(range) = range {
             (
                 GRange(Some(range.start as u64), Some(range.end as u64)),
                 Some(range.len()),
+                None,
+            )
+        } else if self.verify_checksums {
+            // The CRC32C of the object isn't exposed on download responses, so it's fetched from the object's
+            // metadata upfront and the download is pinned to the same generation of the object.
+            let object = self
+                .send(
+                    bucket,
+                    uri.as_str(),
+                    io_stats.clone(),
+                    UnableToOpenFileSnafu { path: uri.as_str() },
+                    || client.get_object(&req),
+                )
+                .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_head_requests(1)
+            }
+            req.generation = Some(object.generation);
+            (
+                GRange::default(),
+                Some(object.size as usize),
+                object
+                    .crc32c
+                    .as_deref()
+                    .and_then(Checksum::crc32c_from_base64),
             )
         } else {
-            (GRange::default(), None)
+            (GRange::default(), None, None)
         };
         let owned_uri = uri.to_string();
-        let response = client
-            .download_streamed_object(&req, &grange)
-            .await
-            .context(UnableToOpenFileSnafu {
-                path: uri.to_string(),
-            })?;
-        let response = response.map_err(move |e| {
-            UnableToReadBytesSnafu::<String> {
-                path: owned_uri.clone(),
+        let response = self
+            .send(
+                bucket,
+                uri.as_str(),
+                io_stats.clone(),
+                UnableToOpenFileSnafu { path: uri.as_str() },
+                || client.download_streamed_object(&req, &grange),
+            )
+            .await?;
+        let response = response
+            .map_err(move |e| {
+                UnableToReadBytesSnafu::<String> {
+                    path: owned_uri.clone(),
+                }
+                .into_error(e)
+                .into()
+            })
+            .boxed();
+        let response = match expected_checksum {
+            Some(expected) => {
+                crate::checksum::verify_bytestream(response, uri.to_string(), expected)
             }
-            .into_error(e)
-            .into()
-        });
+            None => response,
+        };
         if let Some(is) = io_stats.as_ref() {
             is.mark_get_requests(1)
         }
@@ -181,30 +396,211 @@ impl GCSClientWrapper {
         ))
     }
 
-    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+    async fn put_stream(
+        &self,
+        uri: &str,
+        data: PutStream,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let url = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&url)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+
+        let client = &self.client;
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            ..Default::default()
+        };
+        let upload_type = UploadType::Simple(Media::new(key.to_string()));
+        let uploader = self
+            .send(
+                bucket,
+                uri,
+                io_stats.clone(),
+                UnableToPutFileSnafu { path: uri },
+                || client.prepare_resumable_upload(&req, &upload_type),
+            )
+            .await?;
+
+        // Chunks of a resumable upload have to be uploaded in order, so unlike S3 and Azure, only one part is in
+        // flight at a time.
+        let mut parts = into_parts(data, PartSizes::fixed(DEFAULT_PART_SIZE)).peekable();
+        // When checksums are enabled, the CRC32C and MD5 of the uploaded data are sent with the last chunk, so that GCS
+        // rejects the upload instead of creating the object if the data it received doesn't match.
+        let mut hashers = self
+            .verify_checksums
+            .then(|| [Hasher::crc32c(), Hasher::md5()]);
+        let upload_result: super::Result<()> = async {
+            let mut offset = 0u64;
+            loop {
+                let part = parts.next().await.transpose()?.unwrap_or_default();
+                let is_last = Pin::new(&mut parts).peek().await.is_none();
+                if let Some(hashers) = hashers.as_mut() {
+                    hashers.iter_mut().for_each(|hasher| hasher.update(&part));
+                }
+                let checksums = if is_last {
+                    hashers.take().map(|hashers| hashers.map(Hasher::finalize))
+                } else {
+                    None
+                };
+                let num_bytes = part.len() as u64;
+                // The total object size has to be provided with the last chunk.
+                let chunk_size = if num_bytes == 0 {
+                    ChunkSize::new(offset, offset, Some(offset))
+                } else {
+                    ChunkSize::new(
+                        offset,
+                        offset + num_bytes - 1,
+                        is_last.then_some(offset + num_bytes),
+                    )
+                };
+                // Chunk uploads aren't subject to the read timeout, since their response only arrives once the whole
+                // chunk was sent.
+                with_retries(
+                    &self.retry_config,
+                    &format!("upload chunk at offset {offset} of {uri}"),
+                    is_retryable_error,
+                    || async {
+                        let request = async {
+                            match checksums.as_ref() {
+                                Some(checksums) => {
+                                    upload_last_chunk(
+                                        &self.http,
+                                        &uploader,
+                                        part.clone(),
+                                        &chunk_size,
+                                        checksums,
+                                    )
+                                    .await
+                                }
+                                None => {
+                                    uploader
+                                        .upload_multiple_chunk(part.clone(), &chunk_size)
+                                        .await
+                                }
+                            }
+                        };
+                        self.request_limiter
+                            .send_with_body(bucket, io_stats.clone(), request, is_throttle_error)
+                            .await
+                            .context(UnableToPutFileSnafu { path: uri })
+                    },
+                )
+                .await?;
+                if let Some(is) = io_stats.as_ref() {
+                    is.mark_put_requests(1);
+                    is.mark_bytes_uploaded(part.len());
+                }
+                offset += num_bytes;
+                if is_last {
+                    return Ok(());
+                }
+            }
+        }
+        .await;
+        if upload_result.is_err() {
+            if let Err(err) = uploader.cancel().await {
+                log::warn!("Failed to cancel resumable upload to {uri} after upload error: {err}");
+            }
+        }
+        upload_result
+    }
+
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
         let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
         let (bucket, key) = parse_uri(&uri)?;
         if key.is_empty() {
             return Err(Error::NotAFile { path: uri.into() }.into());
         }
-        let client = &self.0;
+        let client = &self.client;
         let req = GetObjectRequest {
             bucket: bucket.into(),
             object: key.into(),
             ..Default::default()
         };
 
-        let response = client
-            .get_object(&req)
-            .await
-            .context(UnableToOpenFileSnafu {
-                path: uri.to_string(),
-            })?;
+        let response = self
+            .send(
+                bucket,
+                uri.as_str(),
+                io_stats.clone(),
+                UnableToOpenFileSnafu { path: uri.as_str() },
+                || client.get_object(&req),
+            )
+            .await?;
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1)
         }
-        Ok(response.size as usize)
+        Ok(object_to_file_metadata(uri.to_string(), response))
     }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let req = DeleteObjectRequest {
+            bucket: bucket.into(),
+            object: key.into(),
+            ..Default::default()
+        };
+        self.send(
+            bucket,
+            uri.as_str(),
+            io_stats.clone(),
+            UnableToDeleteFileSnafu { path: uri.as_str() },
+            || self.client.delete_object(&req),
+        )
+        .await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src_uri = url::Url::parse(src).with_context(|_| InvalidUrlSnafu { path: src })?;
+        let dest_uri = url::Url::parse(dest).with_context(|_| InvalidUrlSnafu { path: dest })?;
+        let (src_bucket, src_key) = parse_uri(&src_uri)?;
+        let (dest_bucket, dest_key) = parse_uri(&dest_uri)?;
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if dest_key.is_empty() {
+            return Err(Error::NotAFile { path: dest.into() }.into());
+        }
+        let mut req = RewriteObjectRequest {
+            source_bucket: src_bucket.into(),
+            source_object: src_key.into(),
+            destination_bucket: dest_bucket.into(),
+            destination_object: dest_key.into(),
+            ..Default::default()
+        };
+        // Large objects (or copies across locations or storage classes) may take multiple rewrite calls to complete,
+        // each of which returns a token to resume the rewrite with.
+        loop {
+            let response = self
+                .send(
+                    dest_bucket,
+                    src,
+                    io_stats.clone(),
+                    UnableToCopyFileSnafu { path: src, dest },
+                    || self.client.rewrite_object(&req),
+                )
+                .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_copy_requests(1);
+            }
+            if response.done {
+                return Ok(());
+            }
+            req.rewrite_token = response.rewrite_token;
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn _ls_impl(
         &self,
@@ -228,27 +624,34 @@ impl GCSClientWrapper {
             projection: None,
             versions: None,
         };
-        let ls_response = client
-            .list_objects(&req)
-            .await
-            .context(UnableToListObjectsSnafu {
-                path: format!("{GCS_SCHEME}://{}/{}", bucket, key),
-            })?;
+        let path = format!("{GCS_SCHEME}://{}/{}", bucket, key);
+        let ls_response = self
+            .send(
+                bucket,
+                path.as_str(),
+                io_stats.cloned(),
+                UnableToListObjectsSnafu {
+                    path: path.as_str(),
+                },
+                || client.list_objects(&req),
+            )
+            .await?;
         if let Some(is) = io_stats.as_ref() {
             is.mark_list_requests(1)
         }
 
         let response_items = ls_response.items.unwrap_or_default();
         let response_prefixes = ls_response.prefixes.unwrap_or_default();
-        let files = response_items.iter().map(|obj| FileMetadata {
-            filepath: format!("{GCS_SCHEME}://{}/{}", bucket, obj.name),
-            size: Some(obj.size as u64),
-            filetype: FileType::File,
+        let files = response_items.into_iter().map(|obj| {
+            object_to_file_metadata(format!("{GCS_SCHEME}://{}/{}", bucket, obj.name), obj)
         });
         let dirs = response_prefixes.iter().map(|pref| FileMetadata {
             filepath: format!("{GCS_SCHEME}://{}/{}", bucket, pref),
             size: None,
             filetype: FileType::Directory,
+            etag: None,
+            last_modified: None,
+            content_type: None,
         });
         Ok(LSResult {
             files: files.chain(dirs).collect(),
@@ -266,7 +669,7 @@ impl GCSClientWrapper {
     ) -> super::Result<LSResult> {
         let uri = url::Url::parse(path).with_context(|_| InvalidUrlSnafu { path })?;
         let (bucket, key) = parse_uri(&uri)?;
-        let client = &self.0;
+        let client = &self.client;
 
         if posix {
             // Attempt to forcefully ls the key as a directory (by ensuring a "/" suffix)
@@ -397,10 +800,26 @@ impl GCSSource {
         if config.project_id.is_some() {
             client_config.project_id.clone_from(&config.project_id);
         }
+        let retry_config = config.retry_config();
+        let http = reqwest::ClientBuilder::default()
+            .connect_timeout(retry_config.connect_timeout())
+            .build()
+            .context(UnableToCreateClientSnafu)?;
+        client_config.http = Some(http.clone());
 
         let client = Client::new(client_config);
         Ok(GCSSource {
-            client: GCSClientWrapper(client),
+            client: GCSClientWrapper {
+                client,
+                http,
+                request_limiter: RequestLimiter::new(
+                    config.max_connections_per_io_thread as usize
+                        * get_io_pool_num_threads().expect("Should be running in tokio pool"),
+                    config.max_requests_per_second,
+                ),
+                retry_config,
+                verify_checksums: config.verify_checksums,
+            },
         }
         .into())
     }
@@ -419,15 +838,43 @@ impl ObjectSource for GCSSource {
 
     async fn put(
         &self,
-        _uri: &str,
-        _data: bytes::Bytes,
-        _io_stats: Option<IOStatsRef>,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.client
+            .put_stream(
+                uri,
+                futures::stream::once(async { Ok(data) }).boxed(),
+                io_stats,
+            )
+            .await
+    }
+
+    async fn put_stream(
+        &self,
+        uri: &str,
+        data: PutStream,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
-        todo!("PUTS to GCS are not yet supported! Please file an issue.");
+        self.client.put_stream(uri, data, io_stats).await
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
-        self.client.get_size(uri, io_stats).await
+        let metadata = self.client.head(uri, io_stats).await?;
+        Ok(metadata.size.unwrap_or_default() as usize)
+    }
+
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
+        self.client.head(uri, io_stats).await
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.delete(uri, io_stats).await
+    }
+
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.copy(src, dest, io_stats).await
     }
 
     async fn glob(
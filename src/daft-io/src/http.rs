@@ -1,26 +1,37 @@
-use std::{num::ParseIntError, ops::Range, string::FromUtf8Error, sync::Arc};
+use std::{
+    collections::VecDeque, num::ParseIntError, ops::Range, string::FromUtf8Error, sync::Arc,
+};
 
 use async_trait::async_trait;
-use common_io_config::HTTPConfig;
-use futures::{stream::BoxStream, TryStreamExt};
+use chrono::{DateTime, Utc};
+use common_io_config::{HTTPConfig, RetryConfig};
+use futures::{
+    stream::{BoxStream, FuturesUnordered},
+    FutureExt, StreamExt, TryStreamExt,
+};
 
 use hyper::header;
 use lazy_static::lazy_static;
 use regex::Regex;
-use reqwest::header::{CONTENT_LENGTH, RANGE};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE, ETAG, LAST_MODIFIED, RANGE};
 use snafu::{IntoError, ResultExt, Snafu};
 use url::Position;
 
 use crate::{
+    get_io_pool_num_threads,
     object_io::{FileMetadata, FileType, LSResult},
+    retry::{is_retryable_status, with_retries},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
+    throttle::{is_throttle_status, RequestLimiter},
     FileFormat,
 };
 
 use super::object_io::{GetResult, ObjectSource};
 
 const HTTP_DELIMITER: &str = "/";
+/// Maximum number of directory indexes that are listed at once when listing all files under a directory.
+const PREFIX_LIST_CONCURRENCY: usize = 32;
 
 lazy_static! {
     // Taken from: https://stackoverflow.com/a/15926317/3821154
@@ -42,6 +53,12 @@ enum Error {
         source: reqwest::Error,
     },
 
+    #[snafu(display("Timed out waiting for a response for {}", path))]
+    ReadTimeout {
+        path: String,
+        source: tokio::time::error::Elapsed,
+    },
+
     #[snafu(display("Unable to determine size of {}", path))]
     UnableToDetermineSize { path: String },
 
@@ -133,6 +150,9 @@ fn _get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<Fil
                 // for populating `size` if necessary
                 size: None,
                 filetype,
+                etag: None,
+                last_modified: None,
+                content_type: None,
             }))
         })
         .collect::<super::Result<Vec<_>>>()?;
@@ -140,8 +160,51 @@ fn _get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<Fil
     Ok(metas.into_iter().flatten().collect())
 }
 
+/// Extracts the metadata of the file at `path` from the headers of a response to a request for it.
+pub(crate) fn file_metadata_from_headers(path: &str, headers: &header::HeaderMap) -> FileMetadata {
+    let get_header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+    FileMetadata {
+        filepath: path.to_string(),
+        size: get_header(CONTENT_LENGTH).and_then(|size| size.parse().ok()),
+        filetype: FileType::File,
+        etag: get_header(ETAG).map(|etag| etag.to_string()),
+        last_modified: get_header(LAST_MODIFIED)
+            .and_then(|last_modified| DateTime::parse_from_rfc2822(last_modified).ok())
+            .map(|last_modified| last_modified.with_timezone(&Utc)),
+        content_type: get_header(CONTENT_TYPE).map(|content_type| content_type.to_string()),
+    }
+}
+
+/// Returns whether a server rejected a request because it is being sent requests too quickly.
+fn is_throttle_error(err: &Error) -> bool {
+    match err {
+        Error::UnableToOpenFile { source, .. } => source
+            .status()
+            .is_some_and(|status| is_throttle_status(status.as_u16())),
+        _ => false,
+    }
+}
+
+/// Returns whether a request that failed with `err` may succeed if it's retried, e.g. because the connection was
+/// dropped or the server was temporarily unavailable.
+fn is_retryable_error(err: &Error) -> bool {
+    match err {
+        Error::ReadTimeout { .. } => true,
+        Error::UnableToConnect { source, .. } => {
+            source.is_connect() || source.is_timeout() || source.is_request()
+        }
+        Error::UnableToOpenFile { source, .. } => source
+            .status()
+            .is_some_and(|status| is_retryable_status(status.as_u16())),
+        _ => false,
+    }
+}
+
 pub(crate) struct HttpSource {
     pub(crate) client: reqwest::Client,
+    retry_config: RetryConfig,
+    /// Limits the requests sent to each host.
+    request_limiter: RequestLimiter,
 }
 
 impl From<Error> for super::Error {
@@ -158,6 +221,10 @@ impl From<Error> for super::Error {
                     source: source.into(),
                 },
             },
+            ReadTimeout { path, source } => super::Error::ReadTimeout {
+                path,
+                source: source.into(),
+            },
             UnableToDetermineSize { path } => super::Error::UnableToDetermineSize { path },
             _ => super::Error::Generic {
                 store: super::SourceType::Http,
@@ -168,6 +235,29 @@ impl From<Error> for super::Error {
 }
 
 impl HttpSource {
+    /// Creates a source with the connection, timeout and retry settings of `config` that sends `default_headers`
+    /// with every request.
+    pub(crate) fn new(
+        config: &HTTPConfig,
+        default_headers: header::HeaderMap,
+    ) -> super::Result<Self> {
+        let retry_config = config.retry_config();
+        Ok(HttpSource {
+            client: reqwest::ClientBuilder::default()
+                .pool_max_idle_per_host(70)
+                .connect_timeout(retry_config.connect_timeout())
+                .default_headers(default_headers)
+                .build()
+                .context(UnableToCreateClientSnafu)?,
+            retry_config,
+            request_limiter: RequestLimiter::new(
+                config.max_connections_per_io_thread as usize
+                    * get_io_pool_num_threads().expect("Should be running in tokio pool"),
+                None,
+            ),
+        })
+    }
+
     pub async fn get_client(config: &HTTPConfig) -> super::Result<Arc<Self>> {
         let mut default_headers = header::HeaderMap::new();
         default_headers.append(
@@ -176,14 +266,122 @@ impl HttpSource {
                 .context(UnableToCreateHeaderSnafu)?,
         );
 
-        Ok(HttpSource {
-            client: reqwest::ClientBuilder::default()
-                .pool_max_idle_per_host(70)
-                .default_headers(default_headers)
-                .build()
-                .context(UnableToCreateClientSnafu)?,
+        Ok(HttpSource::new(config, default_headers)?.into())
+    }
+
+    /// Sends the request built by `request` once the host of `uri` admits it, timing out if no response is received
+    /// within the read timeout and retrying on transient errors. Responses with an error status are returned as errors.
+    async fn send(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+        request: impl Fn() -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, Error> {
+        let url = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let host = url.host_str().unwrap_or_default();
+        with_retries(
+            &self.retry_config,
+            &format!("send request for {uri}"),
+            is_retryable_error,
+            || async {
+                let permit = self.request_limiter.acquire(host, io_stats.clone()).await;
+                let response =
+                    tokio::time::timeout(self.retry_config.read_timeout(), request().send())
+                        .await
+                        .context(ReadTimeoutSnafu { path: uri })?
+                        .context(UnableToConnectSnafu { path: uri })
+                        .and_then(|response| {
+                            response
+                                .error_for_status()
+                                .context(UnableToOpenFileSnafu { path: uri })
+                        });
+                permit.finish(&response, is_throttle_error);
+                response
+            },
+        )
+        .await
+    }
+
+    /// Lists the files and directories of the directory index at `path`, or `path` itself if it's a file.
+    async fn ls_dir(&self, path: &str, io_stats: Option<IOStatsRef>) -> super::Result<LSResult> {
+        let response = self
+            .send(path, io_stats.clone(), || self.client.get(path))
+            .await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_list_requests(1)
+        }
+
+        // Reconstruct the actual path of the request, which may have been redirected via a 301
+        // This is important because downstream URL joining logic relies on proper trailing-slashes/index.html
+        let path = response.url().to_string();
+        let path = if path.ends_with(HTTP_DELIMITER) {
+            format!("{}/", path.trim_end_matches(HTTP_DELIMITER))
+        } else {
+            path
+        };
+
+        match response.headers().get("content-type") {
+            // If the content-type is text/html, we treat the data on this path as a traversable "directory"
+            Some(header_value) if header_value.to_str().map_or(false, |v| v == "text/html") => {
+                let text = response
+                    .text()
+                    .await
+                    .with_context(|_| UnableToParseUtf8BodySnafu {
+                        path: path.to_string(),
+                    })?;
+                let file_metadatas = _get_file_metadata_from_html(path.as_str(), text.as_str())?;
+                Ok(LSResult {
+                    files: file_metadatas,
+                    continuation_token: None,
+                })
+            }
+            // All other forms of content-type is treated as a raw file
+            _ => Ok(LSResult {
+                files: vec![file_metadata_from_headers(
+                    path.as_str(),
+                    response.headers(),
+                )],
+                continuation_token: None,
+            }),
         }
-        .into())
+    }
+
+    /// Lists all files under the directory index at `path` by crawling its subdirectories, listing up to
+    /// `PREFIX_LIST_CONCURRENCY` of them at a time.
+    async fn prefix_ls(
+        &self,
+        path: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Vec<FileMetadata>> {
+        let mut files = vec![];
+        let mut dirs = VecDeque::new();
+        let mut in_flight = FuturesUnordered::new();
+        in_flight.push(self.ls_dir(path, io_stats.clone()).boxed());
+        while let Some(result) = in_flight.next().await {
+            let entries = match result {
+                Ok(result) => result.files,
+                // Directory indexes may contain broken links to subdirectories, which are skipped rather than failing
+                // the whole listing.
+                Err(super::Error::NotFound { path: missing, .. }) if missing != path => {
+                    log::debug!("Skipping missing directory {missing} while listing {path}");
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            for entry in entries {
+                match entry.filetype {
+                    FileType::File => files.push(entry),
+                    FileType::Directory => dirs.push_back(entry.filepath),
+                }
+            }
+            while in_flight.len() < PREFIX_LIST_CONCURRENCY
+                && let Some(dir) = dirs.pop_front()
+            {
+                let io_stats = io_stats.clone();
+                in_flight.push(async move { self.ls_dir(&dir, io_stats).await }.boxed());
+            }
+        }
+        Ok(files)
     }
 }
 
@@ -195,22 +393,18 @@ impl ObjectSource for HttpSource {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
-        let request = self.client.get(uri);
-        let request = match range {
-            None => request,
-            Some(range) => request.header(
-                RANGE,
-                format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
-            ),
-        };
-
-        let response = request
-            .send()
-            .await
-            .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
-        let response = response
-            .error_for_status()
-            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
+        let response = self
+            .send(uri, io_stats.clone(), || {
+                let request = self.client.get(uri);
+                match &range {
+                    None => request,
+                    Some(range) => request.header(
+                        RANGE,
+                        format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
+                    ),
+                }
+            })
+            .await?;
         if let Some(is) = io_stats.as_ref() {
             is.mark_get_requests(1)
         }
@@ -241,15 +435,41 @@ impl ObjectSource for HttpSource {
         todo!("PUTs to HTTP URLs are not yet supported! Please file an issue.");
     }
 
+    async fn delete(&self, _uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        Err(super::Error::UnsupportedOperation {
+            store: super::SourceType::Http,
+            operation: "delete".to_string(),
+        })
+    }
+
+    async fn copy(
+        &self,
+        _src: &str,
+        _dest: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::UnsupportedOperation {
+            store: super::SourceType::Http,
+            operation: "copy".to_string(),
+        })
+    }
+
+    async fn rename(
+        &self,
+        _src: &str,
+        _dest: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::UnsupportedOperation {
+            store: super::SourceType::Http,
+            operation: "rename".to_string(),
+        })
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
-        let request = self.client.head(uri);
-        let response = request
-            .send()
-            .await
-            .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
-        let response = response
-            .error_for_status()
-            .context(UnableToOpenFileSnafu::<String> { path: uri.into() })?;
+        let response = self
+            .send(uri, io_stats.clone(), || self.client.head(uri))
+            .await?;
 
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1)
@@ -270,10 +490,22 @@ impl ObjectSource for HttpSource {
         }
     }
 
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
+        let response = self
+            .send(uri, io_stats.clone(), || self.client.head(uri))
+            .await?;
+
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_head_requests(1)
+        }
+
+        Ok(file_metadata_from_headers(uri, response.headers()))
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
-        _fanout_limit: Option<usize>,
+        fanout_limit: Option<usize>,
         _page_size: Option<i32>,
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
@@ -281,8 +513,7 @@ impl ObjectSource for HttpSource {
     ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
         use crate::object_store_glob::glob;
 
-        // Ensure fanout_limit is None because HTTP ObjectSource does not support prefix listing
-        let fanout_limit = None;
+        // Directory indexes aren't paginated
         let page_size = None;
 
         glob(self, glob_path, fanout_limit, page_size, limit, io_stats).await
@@ -296,54 +527,13 @@ impl ObjectSource for HttpSource {
         _page_size: Option<i32>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<LSResult> {
-        if !posix {
-            unimplemented!("Prefix-listing is not implemented for HTTP listing");
-        }
-
-        let request = self.client.get(path);
-        let response = request
-            .send()
-            .await
-            .context(UnableToConnectSnafu::<String> { path: path.into() })?
-            .error_for_status()
-            .with_context(|_| UnableToOpenFileSnafu { path })?;
-        if let Some(is) = io_stats.as_ref() {
-            is.mark_list_requests(1)
-        }
-
-        // Reconstruct the actual path of the request, which may have been redirected via a 301
-        // This is important because downstream URL joining logic relies on proper trailing-slashes/index.html
-        let path = response.url().to_string();
-        let path = if path.ends_with(HTTP_DELIMITER) {
-            format!("{}/", path.trim_end_matches(HTTP_DELIMITER))
+        if posix {
+            self.ls_dir(path, io_stats).await
         } else {
-            path
-        };
-
-        match response.headers().get("content-type") {
-            // If the content-type is text/html, we treat the data on this path as a traversable "directory"
-            Some(header_value) if header_value.to_str().map_or(false, |v| v == "text/html") => {
-                let text = response
-                    .text()
-                    .await
-                    .with_context(|_| UnableToParseUtf8BodySnafu {
-                        path: path.to_string(),
-                    })?;
-                let file_metadatas = _get_file_metadata_from_html(path.as_str(), text.as_str())?;
-                Ok(LSResult {
-                    files: file_metadatas,
-                    continuation_token: None,
-                })
-            }
-            // All other forms of content-type is treated as a raw file
-            _ => Ok(LSResult {
-                files: vec![FileMetadata {
-                    filepath: path.to_string(),
-                    filetype: FileType::File,
-                    size: response.content_length(),
-                }],
+            Ok(LSResult {
+                files: self.prefix_ls(path, io_stats).await?,
                 continuation_token: None,
-            }),
+            })
         }
     }
 }
@@ -353,10 +543,45 @@ mod tests {
 
     use std::default;
 
-    use crate::object_io::ObjectSource;
+    use chrono::{TimeZone, Utc};
+    use hyper::header::{self, HeaderMap, HeaderValue};
+
+    use crate::http::file_metadata_from_headers;
+    use crate::object_io::{FileMetadata, FileType, ObjectSource};
     use crate::HttpSource;
     use crate::Result;
 
+    #[test]
+    fn test_file_metadata_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("1024"));
+        headers.insert(header::ETAG, HeaderValue::from_static("\"abc123\""));
+        headers.insert(
+            header::LAST_MODIFIED,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/csv"));
+        assert_eq!(
+            file_metadata_from_headers("https://example.com/a.csv", &headers),
+            FileMetadata {
+                filepath: "https://example.com/a.csv".to_string(),
+                size: Some(1024),
+                filetype: FileType::File,
+                etag: Some("\"abc123\"".to_string()),
+                last_modified: Some(Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap()),
+                content_type: Some("text/csv".to_string()),
+            }
+        );
+
+        // Missing or malformed headers are left unset.
+        let mut headers = HeaderMap::new();
+        headers.insert(header::LAST_MODIFIED, HeaderValue::from_static("yesterday"));
+        let metadata = file_metadata_from_headers("https://example.com/a.csv", &headers);
+        assert_eq!(metadata.size, None);
+        assert_eq!(metadata.etag, None);
+        assert_eq!(metadata.last_modified, None);
+    }
+
     #[tokio::test]
     async fn test_full_get_from_http() -> Result<()> {
         let parquet_file_path = "https://daft-public-data.s3.us-west-2.amazonaws.com/test_fixtures/parquet_small/0dad4c3f-da0d-49db-90d8-98684571391b-0.parquet";
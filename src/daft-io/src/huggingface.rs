@@ -18,7 +18,7 @@ use reqwest::{
 use snafu::{IntoError, ResultExt, Snafu};
 
 use crate::{
-    http::HttpSource,
+    http::{file_metadata_from_headers, HttpSource},
     object_io::{FileMetadata, FileType, LSResult},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
@@ -28,6 +28,8 @@ use serde::{Deserialize, Serialize};
 
 use super::object_io::{GetResult, ObjectSource};
 
+const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+
 #[derive(Debug, Snafu)]
 enum Error {
     #[snafu(display("Unable to connect to {}: {}", path, source))]
@@ -51,9 +53,6 @@ enum Error {
         source: reqwest::Error,
     },
 
-    #[snafu(display("Unable to create Http Client {}", source))]
-    UnableToCreateClient { source: reqwest::Error },
-
     #[snafu(display(
         "Unable to parse data as Utf8 while reading header for file: {path}. {source}"
     ))]
@@ -259,18 +258,44 @@ impl HFSource {
         }
 
         Ok(HFSource {
-            http_source: HttpSource {
-                client: reqwest::ClientBuilder::default()
-                    .pool_max_idle_per_host(70)
-                    .default_headers(default_headers)
-                    .build()
-                    .context(UnableToCreateClientSnafu)?,
-            },
+            http_source: HttpSource::new(config, default_headers)?,
         }
         .into())
     }
 }
 
+impl HFSource {
+    /// Sends a HEAD request for the file at `file_uri`, as returned by [`HFPathParts::get_file_uri`].
+    async fn head_file(
+        &self,
+        file_uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<reqwest::Response, Error> {
+        let request = self.http_source.client.head(file_uri);
+        let response = request
+            .send()
+            .await
+            .context(UnableToConnectSnafu::<String> {
+                path: file_uri.into(),
+            })?;
+        let response = response.error_for_status().map_err(|e| {
+            if let Some(401) = e.status().map(|s| s.as_u16()) {
+                Error::Unauthorized
+            } else {
+                Error::UnableToOpenFile {
+                    path: file_uri.into(),
+                    source: e,
+                }
+            }
+        })?;
+
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_head_requests(1)
+        }
+        Ok(response)
+    }
+}
+
 #[async_trait]
 impl ObjectSource for HFSource {
     async fn get(
@@ -336,29 +361,41 @@ impl ObjectSource for HFSource {
         todo!("PUTs to HTTP URLs are not yet supported! Please file an issue.");
     }
 
+    async fn delete(&self, _uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        Err(super::Error::UnsupportedOperation {
+            store: super::SourceType::HF,
+            operation: "delete".to_string(),
+        })
+    }
+
+    async fn copy(
+        &self,
+        _src: &str,
+        _dest: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::UnsupportedOperation {
+            store: super::SourceType::HF,
+            operation: "copy".to_string(),
+        })
+    }
+
+    async fn rename(
+        &self,
+        _src: &str,
+        _dest: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::UnsupportedOperation {
+            store: super::SourceType::HF,
+            operation: "rename".to_string(),
+        })
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
         let path_parts = uri.parse::<HFPathParts>()?;
         let uri = &path_parts.get_file_uri();
-
-        let request = self.http_source.client.head(uri);
-        let response = request
-            .send()
-            .await
-            .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
-        let response = response.error_for_status().map_err(|e| {
-            if let Some(401) = e.status().map(|s| s.as_u16()) {
-                Error::Unauthorized
-            } else {
-                Error::UnableToOpenFile {
-                    path: uri.clone(),
-                    source: e,
-                }
-            }
-        })?;
-
-        if let Some(is) = io_stats.as_ref() {
-            is.mark_head_requests(1)
-        }
+        let response = self.head_file(uri, io_stats).await?;
 
         let headers = response.headers();
         match headers.get(CONTENT_LENGTH) {
@@ -375,11 +412,17 @@ impl ObjectSource for HFSource {
         }
     }
 
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
+        let path_parts = uri.parse::<HFPathParts>()?;
+        let response = self.head_file(&path_parts.get_file_uri(), io_stats).await?;
+        Ok(file_metadata_from_headers(uri, response.headers()))
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
-        _fanout_limit: Option<usize>,
-        _page_size: Option<i32>,
+        fanout_limit: Option<usize>,
+        page_size: Option<i32>,
         limit: Option<usize>,
         io_stats: Option<IOStatsRef>,
         file_format: Option<FileFormat>,
@@ -408,23 +451,26 @@ impl ObjectSource for HFSource {
             }
         }
 
-        glob(self, glob_path, None, None, limit, io_stats).await
+        let fanout_limit = fanout_limit.or(Some(DEFAULT_GLOB_FANOUT_LIMIT));
+        glob(self, glob_path, fanout_limit, page_size, limit, io_stats).await
     }
 
     async fn ls(
         &self,
         path: &str,
         posix: bool,
-        _continuation_token: Option<&str>,
+        continuation_token: Option<&str>,
         _page_size: Option<i32>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<LSResult> {
-        if !posix {
-            unimplemented!("Prefix-listing is not implemented for HTTP listing");
-        }
         let path_parts = path.parse::<HFPathParts>()?;
 
-        let api_uri = path_parts.get_api_uri();
+        // The continuation token is the URI of the next page of the listing, as returned by the API.
+        let api_uri = match continuation_token {
+            Some(token) => token.to_string(),
+            None if posix => path_parts.get_api_uri(),
+            None => format!("{}?recursive=true", path_parts.get_api_uri()),
+        };
 
         let request = self.http_source.client.get(api_uri.clone());
         let response = request
@@ -448,6 +494,7 @@ impl ObjectSource for HFSource {
         if let Some(is) = io_stats.as_ref() {
             is.mark_list_requests(1)
         }
+        let continuation_token = next_page_uri(response.headers());
         let response = response
             .json::<Vec<Item>>()
             .await
@@ -457,6 +504,8 @@ impl ObjectSource for HFSource {
 
         let files = response
             .into_iter()
+            // Prefix listings only return files, like those of the other object stores.
+            .filter(|item| posix || matches!(item.r#type, ItemType::File))
             .map(|item| {
                 let filepath = HFPathParts {
                     bucket: path_parts.bucket.clone(),
@@ -469,27 +518,46 @@ impl ObjectSource for HFSource {
                     0 => None,
                     size => Some(size),
                 };
-                let filepath = filepath.to_string();
-
-                let filetype = match item.r#type {
-                    ItemType::File => FileType::File,
-                    ItemType::Directory => FileType::Directory,
+                // The git object ID of a file changes whenever its content does, so it's used as its ETag.
+                let (filepath, filetype, etag) = match item.r#type {
+                    ItemType::File => (filepath.to_string(), FileType::File, Some(item.oid)),
+                    ItemType::Directory => (format!("{filepath}/"), FileType::Directory, None),
                 };
 
                 FileMetadata {
                     filepath,
                     size,
                     filetype,
+                    etag,
+                    last_modified: None,
+                    content_type: None,
                 }
             })
             .collect();
         Ok(LSResult {
             files,
-            continuation_token: None,
+            continuation_token,
         })
     }
 }
 
+/// Extracts the URI of the next page of a paginated API response from its `Link` header, if there is one.
+fn next_page_uri(headers: &header::HeaderMap) -> Option<String> {
+    let link = headers.get(header::LINK)?.to_str().ok()?;
+    link.split(',').find_map(|link| {
+        let (uri, params) = link.split_once(';')?;
+        params
+            .split(';')
+            .any(|param| param.trim() == r#"rel="next""#)
+            .then(|| {
+                uri.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
 async fn try_parquet_api(
     glob_path: &str,
     limit: Option<usize>,
@@ -550,6 +618,9 @@ async fn try_parquet_api(
                     filepath: uri,
                     size: None,
                     filetype: FileType::File,
+                    etag: None,
+                    last_modified: None,
+                    content_type: None,
                 })
             });
 
@@ -564,8 +635,34 @@ async fn try_parquet_api(
 #[cfg(test)]
 mod tests {
     use common_error::DaftResult;
+    use hyper::header::{HeaderMap, HeaderValue, LINK};
+
+    use crate::huggingface::{next_page_uri, HFPathParts};
+
+    #[test]
+    fn test_next_page_uri() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(next_page_uri(&headers), None);
+
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                r#"<https://huggingface.co/api/datasets/user/repo/tree/main/data?cursor=abc>; rel="next""#,
+            ),
+        );
+        assert_eq!(
+            next_page_uri(&headers).as_deref(),
+            Some("https://huggingface.co/api/datasets/user/repo/tree/main/data?cursor=abc")
+        );
 
-    use crate::huggingface::HFPathParts;
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                r#"<https://huggingface.co/api/datasets/user/repo/tree/main?cursor=a>; rel="prev""#,
+            ),
+        );
+        assert_eq!(next_page_uri(&headers), None);
+    }
 
     #[test]
     fn test_parse_hf_parts() -> DaftResult<()> {
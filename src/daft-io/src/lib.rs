@@ -3,31 +3,44 @@
 #![feature(io_error_more)]
 #![feature(if_let_guard)]
 mod azure_blob;
+mod cache;
+mod checksum;
 mod google_cloud;
 mod http;
 mod huggingface;
 mod local;
+mod memory;
+mod multipart;
 mod object_io;
 mod object_store_glob;
+mod retry;
 mod s3_like;
 mod stats;
 mod stream_utils;
+mod throttle;
 use azure_blob::AzureBlobSource;
+use cache::{CachingObjectSource, DiskCache};
 use futures::FutureExt;
 use google_cloud::GCSSource;
 use huggingface::HFSource;
 use lazy_static::lazy_static;
+use memory::MemorySource;
 mod file_format;
 #[cfg(feature = "python")]
 pub mod python;
 pub use file_format::FileFormat;
 
+use common_io_config::RetryConfig;
 pub use common_io_config::{AzureConfig, IOConfig, S3Config};
+pub use multipart::PutStream;
 pub use object_io::FileMetadata;
 pub use object_io::GetResult;
 use object_io::StreamingRetryParams;
+pub use object_io::{FileType, LSResult, ObjectSource};
+pub use object_store_glob::glob;
 #[cfg(feature = "python")]
 pub use python::register_modules;
+use retry::FALLBACK_RETRY_CONFIG;
 pub use stats::{IOStatsContext, IOStatsRef};
 use tokio::runtime::RuntimeFlavor;
 use tokio::task::JoinHandle;
@@ -48,7 +61,7 @@ use snafu::prelude::*;
 use common_error::{DaftError, DaftResult};
 use s3_like::S3LikeSource;
 
-use self::{http::HttpSource, local::LocalSource, object_io::ObjectSource};
+use self::{http::HttpSource, local::LocalSource};
 
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -129,6 +142,12 @@ pub enum Error {
     #[snafu(display("Source not yet implemented: {}", store))]
     NotImplementedSource { store: String },
 
+    #[snafu(display("{operation} is not supported for store: {store}"))]
+    UnsupportedOperation {
+        store: SourceType,
+        operation: String,
+    },
+
     #[snafu(display("Unhandled Error for path: {}\nDetails:\n{}", path, msg))]
     Unhandled { path: String, msg: String },
 
@@ -141,6 +160,18 @@ pub enum Error {
     #[snafu(display("Error joining spawned task: {}", source), context(false))]
     JoinError { source: tokio::task::JoinError },
 
+    #[snafu(display(
+        "Checksum mismatch for {}: expected {} but got {}",
+        path,
+        expected,
+        actual
+    ))]
+    ChecksumMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+
     #[snafu(display("Cached error: {}", source))]
     CachedError { source: Arc<Error> },
 }
@@ -177,7 +208,7 @@ impl From<Error> for std::io::Error {
     }
 }
 
-type Result<T, E = Error> = std::result::Result<T, E>;
+pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[derive(Default)]
 pub struct IOClient {
@@ -193,12 +224,79 @@ impl IOClient {
         })
     }
 
+    /// Register `source` to handle URLs with the provided `scheme` for this client, taking precedence over sources
+    /// registered for the same scheme with [`register_object_source`].
+    pub async fn register_source(&self, scheme: &str, source: Arc<dyn ObjectSource>) -> Result<()> {
+        let scheme = validate_custom_scheme(scheme)?;
+        let source_type = SourceType::Custom(scheme.into());
+        let source = self.with_cache(&source_type, source)?;
+        self.source_type_to_store
+            .write()
+            .await
+            .insert(source_type, source);
+        Ok(())
+    }
+
+    fn with_cache(
+        &self,
+        source_type: &SourceType,
+        source: Arc<dyn ObjectSource>,
+    ) -> Result<Arc<dyn ObjectSource>> {
+        let is_remote = !matches!(source_type, SourceType::File | SourceType::Memory);
+        if self.config.cache.enabled && is_remote {
+            let disk_cache = DiskCache::get_or_create(&self.config.cache)?;
+            Ok(Arc::new(CachingObjectSource::new(
+                source,
+                disk_cache,
+                self.retry_config(source_type),
+                std::time::Duration::from_secs(self.config.cache.metadata_ttl_seconds),
+            )))
+        } else {
+            Ok(source)
+        }
+    }
+
+    /// Retry settings for reads from `source_type` that fail mid-stream.
+    fn retry_config(&self, source_type: &SourceType) -> RetryConfig {
+        match source_type {
+            SourceType::AzureBlob => self.config.azure.retry_config(),
+            SourceType::GCS => self.config.gcs.retry_config(),
+            SourceType::Http | SourceType::HF => self.config.http.retry_config(),
+            // Requests to S3 are already retried by the AWS SDK with `S3Config::num_tries`, so re-issuing the whole
+            // read only needs a few extra attempts.
+            _ => FALLBACK_RETRY_CONFIG,
+        }
+    }
+
+    /// Whether the store serving `path` opted into verifying checksums, e.g. of the pages of Parquet files read from it.
+    pub fn verify_checksums(&self, path: &str) -> bool {
+        match parse_url(path) {
+            Ok((SourceType::S3, _)) => self.config.s3.verify_checksums,
+            Ok((SourceType::AzureBlob, _)) => self.config.azure.verify_checksums,
+            Ok((SourceType::GCS, _)) => self.config.gcs.verify_checksums,
+            _ => false,
+        }
+    }
+
     async fn get_source(&self, source_type: &SourceType) -> Result<Arc<dyn ObjectSource>> {
         {
             if let Some(client) = self.source_type_to_store.read().await.get(source_type) {
                 return Ok(client.clone());
             }
         }
+        if let SourceType::Custom(scheme) = source_type {
+            // Sources registered for the whole process are looked up on every call, so that (re-)registrations apply
+            // to existing clients too.
+            let source = OBJECT_SOURCE_REGISTRY
+                .read()
+                .unwrap()
+                .get(scheme.as_ref())
+                .cloned()
+                .ok_or_else(|| Error::NotImplementedSource {
+                    store: scheme.to_string(),
+                })?;
+            return self.with_cache(source_type, source);
+        }
         let mut w_handle = self.source_type_to_store.write().await;
 
         if let Some(client) = w_handle.get(source_type) {
@@ -223,10 +321,13 @@ impl IOClient {
             SourceType::HF => {
                 HFSource::get_client(&self.config.http).await? as Arc<dyn ObjectSource>
             }
+            SourceType::Memory => MemorySource::get_client().await? as Arc<dyn ObjectSource>,
+            SourceType::Custom(_) => unreachable!("Custom sources are handled above"),
         };
+        let new_source = self.with_cache(source_type, new_source)?;
 
         if w_handle.get(source_type).is_none() {
-            w_handle.insert(*source_type, new_source.clone());
+            w_handle.insert(source_type.clone(), new_source.clone());
         }
         Ok(new_source)
     }
@@ -266,7 +367,14 @@ impl IOClient {
         let get_result = source
             .get(path.as_ref(), range.clone(), io_stats.clone())
             .await?;
-        Ok(get_result.with_retry(StreamingRetryParams::new(source, input, range, io_stats)))
+        let retry_config = self.retry_config(&scheme);
+        Ok(get_result.with_retry(StreamingRetryParams::new(
+            source,
+            input,
+            range,
+            io_stats,
+            retry_config,
+        )))
     }
 
     pub async fn single_url_put(
@@ -280,6 +388,91 @@ impl IOClient {
         source.put(dest.as_ref(), data, io_stats.clone()).await
     }
 
+    /// Upload a stream of bytes to `dest`, using a multipart (or resumable) upload where the store supports it, so
+    /// that the object never needs to be fully buffered in memory.
+    pub async fn single_url_put_stream(
+        &self,
+        dest: &str,
+        data: PutStream,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, dest) = parse_url(dest)?;
+        let source = self.get_source(&scheme).await?;
+        source.put_stream(dest.as_ref(), data, io_stats).await
+    }
+
+    pub async fn single_url_delete(&self, input: &str, io_stats: Option<IOStatsRef>) -> Result<()> {
+        let (scheme, path) = parse_url(input)?;
+        let source = self.get_source(&scheme).await?;
+        source.delete(path.as_ref(), io_stats).await
+    }
+
+    /// Delete multiple objects, batching the requests per store where supported.
+    pub async fn delete_many(&self, inputs: &[String], io_stats: Option<IOStatsRef>) -> Result<()> {
+        let mut paths_by_scheme: HashMap<SourceType, Vec<String>> = HashMap::new();
+        for input in inputs {
+            let (scheme, path) = parse_url(input)?;
+            paths_by_scheme
+                .entry(scheme)
+                .or_default()
+                .push(path.into_owned());
+        }
+        for (scheme, paths) in paths_by_scheme {
+            let source = self.get_source(&scheme).await?;
+            source.delete_many(&paths, io_stats.clone()).await?;
+        }
+        Ok(())
+    }
+
+    /// Copy the object at `src` to `dest`.
+    ///
+    /// Copies within a store are done server-side where supported; copies across stores stream the object from the
+    /// source into an upload to the destination.
+    pub async fn single_url_copy(
+        &self,
+        src: &str,
+        dest: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (src_scheme, src_path) = parse_url(src)?;
+        let (dest_scheme, dest_path) = parse_url(dest)?;
+        if src_scheme == dest_scheme {
+            let source = self.get_source(&src_scheme).await?;
+            source
+                .copy(src_path.as_ref(), dest_path.as_ref(), io_stats)
+                .await
+        } else {
+            let data = self
+                .single_url_get(src.to_string(), None, io_stats.clone())
+                .await?
+                .into_stream();
+            self.single_url_put_stream(dest, data, io_stats).await
+        }
+    }
+
+    /// Rename the object at `src` to `dest`.
+    ///
+    /// This is only atomic for local files; for object stores, and across stores, the object is copied and the source
+    /// is then deleted.
+    pub async fn single_url_rename(
+        &self,
+        src: &str,
+        dest: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (src_scheme, src_path) = parse_url(src)?;
+        let (dest_scheme, dest_path) = parse_url(dest)?;
+        if src_scheme == dest_scheme {
+            let source = self.get_source(&src_scheme).await?;
+            source
+                .rename(src_path.as_ref(), dest_path.as_ref(), io_stats)
+                .await
+        } else {
+            self.single_url_copy(src, dest, io_stats.clone()).await?;
+            self.single_url_delete(src, io_stats).await
+        }
+    }
+
     pub async fn single_url_get_size(
         &self,
         input: String,
@@ -290,6 +483,16 @@ impl IOClient {
         source.get_size(path.as_ref(), io_stats).await
     }
 
+    pub async fn single_url_head(
+        &self,
+        input: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<FileMetadata> {
+        let (scheme, path) = parse_url(&input)?;
+        let source = self.get_source(&scheme).await?;
+        source.head(path.as_ref(), io_stats).await
+    }
+
     pub async fn single_url_download(
         &self,
         index: usize,
@@ -352,7 +555,7 @@ impl IOClient {
     }
 }
 
-#[derive(Debug, Hash, PartialEq, std::cmp::Eq, Clone, Copy)]
+#[derive(Debug, Hash, PartialEq, std::cmp::Eq, Clone)]
 pub enum SourceType {
     File,
     Http,
@@ -360,6 +563,9 @@ pub enum SourceType {
     AzureBlob,
     GCS,
     HF,
+    Memory,
+    /// A scheme handled by a user-provided `ObjectSource`, see [`register_object_source`].
+    Custom(Arc<str>),
 }
 
 impl std::fmt::Display for SourceType {
@@ -371,6 +577,8 @@ impl std::fmt::Display for SourceType {
             SourceType::AzureBlob => write!(f, "AzureBlob"),
             SourceType::GCS => write!(f, "gcs"),
             SourceType::HF => write!(f, "hf"),
+            SourceType::Memory => write!(f, "memory"),
+            SourceType::Custom(scheme) => write!(f, "{scheme}"),
         }
     }
 }
@@ -403,20 +611,62 @@ pub fn parse_url(input: &str) -> Result<(SourceType, Cow<'_, str>)> {
     .context(InvalidUrlSnafu { path: input })?;
 
     let scheme = url.scheme().to_lowercase();
-    match scheme.as_ref() {
-        "file" => Ok((SourceType::File, fixed_input)),
-        "http" | "https" => Ok((SourceType::Http, fixed_input)),
-        "s3" | "s3a" => Ok((SourceType::S3, fixed_input)),
-        "az" | "abfs" | "abfss" => Ok((SourceType::AzureBlob, fixed_input)),
-        "gcs" | "gs" => Ok((SourceType::GCS, fixed_input)),
-        "hf" => Ok((SourceType::HF, fixed_input)),
+    match builtin_source_type(&scheme) {
+        Some(source_type) => Ok((source_type, fixed_input)),
         #[cfg(target_env = "msvc")]
-        _ if scheme.len() == 1 && ("a" <= scheme.as_str() && (scheme.as_str() <= "z")) => {
+        None if scheme.len() == 1 && ("a" <= scheme.as_str() && (scheme.as_str() <= "z")) => {
             Ok((SourceType::File, Cow::Owned(format!("file://{input}"))))
         }
-        _ => Err(Error::NotImplementedSource { store: scheme }),
+        // Whether a source is registered for the scheme is only checked when the source is used.
+        None => Ok((SourceType::Custom(scheme.into()), fixed_input)),
     }
 }
+
+fn builtin_source_type(scheme: &str) -> Option<SourceType> {
+    match scheme {
+        "file" => Some(SourceType::File),
+        "http" | "https" => Some(SourceType::Http),
+        "s3" | "s3a" => Some(SourceType::S3),
+        "az" | "abfs" | "abfss" => Some(SourceType::AzureBlob),
+        "gcs" | "gs" => Some(SourceType::GCS),
+        "hf" => Some(SourceType::HF),
+        "memory" => Some(SourceType::Memory),
+        _ => None,
+    }
+}
+
+fn validate_custom_scheme(scheme: &str) -> Result<String> {
+    let scheme = scheme.to_lowercase();
+    // See https://datatracker.ietf.org/doc/html/rfc3986#section-3.1
+    let is_valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !is_valid {
+        return Err(Error::InvalidArgument {
+            msg: format!("Invalid URL scheme: {scheme:?}"),
+        });
+    }
+    if builtin_source_type(&scheme).is_some() {
+        return Err(Error::InvalidArgument {
+            msg: format!("Cannot register a source for the built-in scheme: {scheme}"),
+        });
+    }
+    Ok(scheme)
+}
+
+/// Register `source` to handle URLs with the provided `scheme` (e.g. `"myblob"` for `myblob://bucket/key`) for all
+/// `IOClient`s in the process, replacing any source previously registered for the scheme.
+///
+/// Built-in schemes such as `s3` or `file` cannot be overridden.
+pub fn register_object_source(scheme: &str, source: Arc<dyn ObjectSource>) -> Result<()> {
+    let scheme = validate_custom_scheme(scheme)?;
+    OBJECT_SOURCE_REGISTRY
+        .write()
+        .unwrap()
+        .insert(scheme, source);
+    Ok(())
+}
 type CacheKey = (bool, Arc<IOConfig>);
 
 static THREADED_RUNTIME: OnceLock<RuntimeRef> = OnceLock::new();
@@ -427,6 +677,8 @@ lazy_static! {
     static ref THREADED_RUNTIME_NUM_WORKER_THREADS: usize = 8.min(*NUM_CPUS);
     static ref CLIENT_CACHE: std::sync::RwLock<HashMap<CacheKey, Arc<IOClient>>> =
         std::sync::RwLock::new(HashMap::new());
+    static ref OBJECT_SOURCE_REGISTRY: std::sync::RwLock<HashMap<String, Arc<dyn ObjectSource>>> =
+        std::sync::RwLock::new(HashMap::new());
 }
 
 pub fn get_io_client(multi_thread: bool, config: Arc<IOConfig>) -> DaftResult<Arc<IOClient>> {
@@ -549,3 +801,211 @@ pub fn get_io_pool_num_threads() -> Option<usize> {
 }
 
 type DynError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+#[cfg(test)]
+mod tests {
+    use std::{ops::Range, sync::Arc};
+
+    use async_trait::async_trait;
+    use common_io_config::IOConfig;
+    use futures::stream::BoxStream;
+
+    use crate::{
+        memory::MemorySource, parse_url, register_object_source, stats::IOStatsRef, Error,
+        FileFormat, FileMetadata, GetResult, IOClient, LSResult, ObjectSource, Result, SourceType,
+    };
+
+    /// Serves `<scheme>://...` URLs out of the in-memory store under `memory://<scheme>/...`.
+    struct PrefixedMemorySource {
+        scheme: &'static str,
+        inner: Arc<MemorySource>,
+    }
+
+    impl PrefixedMemorySource {
+        fn to_memory_uri(&self, uri: &str) -> String {
+            uri.replacen(
+                &format!("{}://", self.scheme),
+                &format!("memory://{}/", self.scheme),
+                1,
+            )
+        }
+    }
+
+    #[async_trait]
+    impl ObjectSource for PrefixedMemorySource {
+        async fn get(
+            &self,
+            uri: &str,
+            range: Option<Range<usize>>,
+            io_stats: Option<IOStatsRef>,
+        ) -> Result<GetResult> {
+            self.inner
+                .get(&self.to_memory_uri(uri), range, io_stats)
+                .await
+        }
+
+        async fn put(
+            &self,
+            uri: &str,
+            data: bytes::Bytes,
+            io_stats: Option<IOStatsRef>,
+        ) -> Result<()> {
+            self.inner
+                .put(&self.to_memory_uri(uri), data, io_stats)
+                .await
+        }
+
+        async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> Result<usize> {
+            self.inner
+                .get_size(&self.to_memory_uri(uri), io_stats)
+                .await
+        }
+
+        async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> Result<()> {
+            self.inner.delete(&self.to_memory_uri(uri), io_stats).await
+        }
+
+        async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> Result<()> {
+            self.inner
+                .copy(
+                    &self.to_memory_uri(src),
+                    &self.to_memory_uri(dest),
+                    io_stats,
+                )
+                .await
+        }
+
+        async fn glob(
+            self: Arc<Self>,
+            glob_path: &str,
+            fanout_limit: Option<usize>,
+            page_size: Option<i32>,
+            limit: Option<usize>,
+            io_stats: Option<IOStatsRef>,
+            _file_format: Option<FileFormat>,
+        ) -> Result<BoxStream<'static, Result<FileMetadata>>> {
+            crate::glob(self, glob_path, fanout_limit, page_size, limit, io_stats).await
+        }
+
+        async fn ls(
+            &self,
+            path: &str,
+            posix: bool,
+            continuation_token: Option<&str>,
+            page_size: Option<i32>,
+            io_stats: Option<IOStatsRef>,
+        ) -> Result<LSResult> {
+            let mut lsr = self
+                .inner
+                .ls(
+                    &self.to_memory_uri(path),
+                    posix,
+                    continuation_token,
+                    page_size,
+                    io_stats,
+                )
+                .await?;
+            for file in lsr.files.iter_mut() {
+                file.filepath = file.filepath.replacen(
+                    &format!("memory://{}/", self.scheme),
+                    &format!("{}://", self.scheme),
+                    1,
+                );
+            }
+            Ok(lsr)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_object_source() -> Result<()> {
+        let scheme = "registrytest";
+        let (source_type, _) = parse_url("registrytest://bucket/a.txt")?;
+        assert_eq!(source_type, SourceType::Custom(scheme.into()));
+
+        let client = IOClient::new(IOConfig::default().into())?;
+        let err = client
+            .single_url_get_size("registrytest://bucket/a.txt".to_string(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotImplementedSource { .. }));
+
+        register_object_source(
+            scheme,
+            Arc::new(PrefixedMemorySource {
+                scheme,
+                inner: MemorySource::get_client().await?,
+            }),
+        )?;
+        client
+            .single_url_put("registrytest://bucket/a.txt", "hello".into(), None)
+            .await?;
+        let data = client
+            .single_url_get("registrytest://bucket/a.txt".to_string(), None, None)
+            .await?
+            .bytes()
+            .await?;
+        assert_eq!(data.as_ref(), b"hello");
+
+        let files = client
+            .glob(
+                "registrytest://bucket/*.txt".to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+        let files: Vec<_> = futures::TryStreamExt::try_collect(files?).await?;
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].filepath, "registrytest://bucket/a.txt");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_copy_across_stores() -> Result<()> {
+        let data = (0..20 * 1024 * 1024)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<_>>();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, &data).unwrap();
+        let src = format!("file://{}", file.path().to_string_lossy());
+
+        let client = IOClient::new(IOConfig::default().into())?;
+        client
+            .single_url_copy(&src, "memory://copy-across-stores/a.bin", None)
+            .await?;
+        let copied = client
+            .single_url_get("memory://copy-across-stores/a.bin".to_string(), None, None)
+            .await?
+            .bytes()
+            .await?;
+        assert_eq!(copied.as_ref(), data.as_slice());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_register_object_source_invalid_scheme() -> Result<()> {
+        let source = MemorySource::get_client().await? as Arc<dyn ObjectSource>;
+        for scheme in ["s3", "file", "1abc", "a/b", ""] {
+            assert!(matches!(
+                register_object_source(scheme, source.clone()),
+                Err(Error::InvalidArgument { .. })
+            ));
+        }
+        let client = IOClient::new(IOConfig::default().into())?;
+        assert!(client.register_source("memory", source).await.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_checksums_per_store() -> Result<()> {
+        let mut config = IOConfig::default();
+        config.s3.verify_checksums = true;
+        let client = IOClient::new(config.into())?;
+        assert!(client.verify_checksums("s3://bucket/a.parquet"));
+        assert!(!client.verify_checksums("gs://bucket/a.parquet"));
+        assert!(!client.verify_checksums("/tmp/a.parquet"));
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use std::io::{SeekFrom, Write};
 use std::ops::Range;
 use std::path::PathBuf;
 
+use crate::multipart::PutStream;
 use crate::object_io::{self, FileMetadata, LSResult};
 use crate::stats::IOStatsRef;
 use crate::FileFormat;
@@ -10,13 +11,14 @@ use super::object_io::{GetResult, ObjectSource};
 use super::Result;
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::DateTime;
 use common_error::DaftError;
 use futures::stream::BoxStream;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use snafu::{ResultExt, Snafu};
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
 /// NOTE: We hardcode this even for Windows
 ///
@@ -72,6 +74,26 @@ enum Error {
     #[snafu(display("Unexpected symlink when processing directory {}: {}", path, source))]
     UnexpectedSymlink { path: String, source: DaftError },
 
+    #[snafu(display("Unable to delete file {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to copy file {} to {}: {}", src, dest, source))]
+    UnableToCopyFile {
+        src: String,
+        dest: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to rename file {} to {}: {}", src, dest, source))]
+    UnableToRenameFile {
+        src: String,
+        dest: String,
+        source: std::io::Error,
+    },
+
     #[snafu(display("Unable to convert URL \"{}\" to local file path", path))]
     InvalidFilePath { path: String },
 }
@@ -106,6 +128,22 @@ impl From<Error> for super::Error {
                     },
                 }
             }
+            UnableToDeleteFile { path, source }
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                super::Error::NotFound {
+                    path,
+                    source: source.into(),
+                }
+            }
+            UnableToCopyFile { src, source, .. } | UnableToRenameFile { src, source, .. }
+                if source.kind() == std::io::ErrorKind::NotFound =>
+            {
+                super::Error::NotFound {
+                    path: src,
+                    source: source.into(),
+                }
+            }
             UnableToReadBytes { path, source } => super::Error::UnableToReadBytes { path, source },
             UnableToWriteToFile { path, source } | UnableToOpenFileForWriting { path, source } => {
                 super::Error::UnableToWriteToFile { path, source }
@@ -170,26 +208,127 @@ impl ObjectSource for LocalSource {
         }
     }
 
-    async fn get_size(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+    async fn put_stream(
+        &self,
+        uri: &str,
+        mut data: PutStream,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(stripped_uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        let mut file = tokio::fs::File::create(stripped_uri)
+            .await
+            .with_context(|_| UnableToOpenFileForWritingSnafu { path: uri })?;
+        let write_result: super::Result<()> = async {
+            while let Some(chunk) = data.next().await {
+                let chunk = chunk?;
+                file.write_all(&chunk)
+                    .await
+                    .with_context(|_| UnableToWriteToFileSnafu { path: uri })?;
+                if let Some(is) = io_stats.as_ref() {
+                    is.mark_bytes_uploaded(chunk.len());
+                }
+            }
+            Ok(file
+                .flush()
+                .await
+                .with_context(|_| UnableToWriteToFileSnafu { path: uri })?)
+        }
+        .await;
+        if write_result.is_err() {
+            // Don't leave a partially-written file behind.
+            drop(file);
+            if let Err(err) = tokio::fs::remove_file(stripped_uri).await {
+                log::warn!("Failed to remove partially-written file {uri}: {err}");
+            }
+        }
+        write_result
+    }
+
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let metadata = self.head(uri, io_stats).await?;
+        Ok(metadata.size.unwrap_or_default() as usize)
+    }
+
+    async fn head(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
         const LOCAL_PROTOCOL: &str = "file://";
-        let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
+        let Some(path) = uri.strip_prefix(LOCAL_PROTOCOL) else {
             return Err(Error::InvalidFilePath { path: uri.into() }.into());
         };
-        let meta = tokio::fs::metadata(uri)
+        let meta = tokio::fs::metadata(path)
             .await
             .context(UnableToFetchFileMetadataSnafu {
-                path: uri.to_string(),
+                path: path.to_string(),
             })?;
 
         if meta.is_dir() {
             Err(super::Error::NotAFile {
-                path: uri.to_owned(),
+                path: path.to_owned(),
             })
         } else {
-            Ok(meta.len() as usize)
+            Ok(FileMetadata {
+                filepath: uri.to_string(),
+                size: Some(meta.len()),
+                filetype: object_io::FileType::File,
+                etag: None,
+                last_modified: meta.modified().ok().map(DateTime::from),
+                content_type: None,
+            })
         }
     }
 
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(stripped_uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: uri.into() }.into());
+        };
+        tokio::fs::remove_file(stripped_uri)
+            .await
+            .with_context(|_| UnableToDeleteFileSnafu { path: uri })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(stripped_src) = src.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: src.into() }.into());
+        };
+        let Some(stripped_dest) = dest.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: dest.into() }.into());
+        };
+        tokio::fs::copy(stripped_src, stripped_dest)
+            .await
+            .with_context(|_| UnableToCopyFileSnafu { src, dest })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn rename(
+        &self,
+        src: &str,
+        dest: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        const LOCAL_PROTOCOL: &str = "file://";
+        let Some(stripped_src) = src.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: src.into() }.into());
+        };
+        let Some(stripped_dest) = dest.strip_prefix(LOCAL_PROTOCOL) else {
+            return Err(Error::InvalidFilePath { path: dest.into() }.into());
+        };
+        // Unlike object stores, local renames are atomic (within a single filesystem).
+        Ok(tokio::fs::rename(stripped_src, stripped_dest)
+            .await
+            .with_context(|_| UnableToRenameFileSnafu { src, dest })?)
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -276,6 +415,9 @@ impl ObjectSource for LocalSource {
                 filepath: format!("{}{}", LOCAL_PROTOCOL, uri),
                 size: Some(meta.len()),
                 filetype: object_io::FileType::File,
+                etag: None,
+                last_modified: meta.modified().ok().map(DateTime::from),
+                content_type: None,
             })])
             .boxed());
         }
@@ -323,6 +465,9 @@ impl ObjectSource for LocalSource {
                             path: entry.path().to_string_lossy().to_string(),
                         }
                     })?,
+                    etag: None,
+                    last_modified: meta.modified().ok().map(DateTime::from),
+                    content_type: None,
                 })
             }
         });
@@ -368,12 +513,56 @@ pub(crate) async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
     Ok(Bytes::from(buf))
 }
 
+/// Size of the chunks that `stream_file` reads a local file in.
+const STREAM_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Stream the bytes of a local file in chunks, rather than reading the whole file into memory.
+pub(crate) fn stream_file(local_file: LocalFile) -> BoxStream<'static, Result<Bytes>> {
+    let s = async_stream::try_stream! {
+        let path = local_file.path;
+        let mut file = tokio::fs::File::open(&path)
+            .await
+            .context(UnableToOpenFileSnafu {
+                path: path.to_string_lossy(),
+            })?;
+        let length = match local_file.range {
+            None => u64::MAX,
+            Some(range) => {
+                file.seek(SeekFrom::Start(range.start as u64))
+                    .await
+                    .context(UnableToSeekSnafu {
+                        path: path.to_string_lossy(),
+                    })?;
+                (range.end - range.start) as u64
+            }
+        };
+        let mut file = file.take(length);
+        loop {
+            let mut buf = Vec::with_capacity(STREAM_CHUNK_SIZE);
+            let num_bytes = (&mut file)
+                .take(STREAM_CHUNK_SIZE as u64)
+                .read_to_end(&mut buf)
+                .await
+                .context(UnableToReadBytesSnafu {
+                    path: path.to_string_lossy(),
+                })?;
+            if num_bytes == 0 {
+                break;
+            }
+            yield Bytes::from(buf);
+        }
+    };
+    s.boxed()
+}
+
 #[cfg(test)]
 
 mod tests {
     use std::default;
     use std::io::Write;
 
+    use chrono::DateTime;
+
     use crate::object_io::{FileMetadata, FileType, ObjectSource};
     use crate::Result;
     use crate::{HttpSource, LocalSource};
@@ -445,6 +634,111 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_local_put_stream() -> Result<()> {
+        use futures::StreamExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = format!("file://{}/out.bin", dir.path().to_string_lossy());
+        let client = LocalSource::get_client().await?;
+
+        let chunks = (0..4u8)
+            .map(|i| Ok(bytes::Bytes::from(vec![i; 1000])))
+            .collect::<Vec<_>>();
+        client
+            .put_stream(&path, futures::stream::iter(chunks).boxed(), None)
+            .await?;
+        let written = client.get(&path, None, None).await?.bytes().await?;
+        assert_eq!(written.len(), 4000);
+        assert_eq!(
+            &written[..],
+            &[[0u8; 1000], [1; 1000], [2; 1000], [3; 1000]].concat()[..]
+        );
+
+        // A failure partway through the stream shouldn't leave a partially-written file behind.
+        let chunks = vec![
+            Ok(bytes::Bytes::from(vec![0u8; 1000])),
+            Err(crate::Error::Unhandled {
+                path: path.clone(),
+                msg: "stream failure".to_string(),
+            }),
+        ];
+        let result = client
+            .put_stream(&path, futures::stream::iter(chunks).boxed(), None)
+            .await;
+        assert!(result.is_err());
+        assert!(!dir.path().join("out.bin").exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_local_stream_file() -> Result<()> {
+        use futures::TryStreamExt;
+
+        let data = (0..(super::STREAM_CHUNK_SIZE * 2 + 10))
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<_>>();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&data).unwrap();
+
+        let chunks: Vec<_> = super::stream_file(super::LocalFile {
+            path: file.path().into(),
+            range: None,
+        })
+        .try_collect()
+        .await?;
+        assert_eq!(
+            chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![super::STREAM_CHUNK_SIZE, super::STREAM_CHUNK_SIZE, 10]
+        );
+        assert_eq!(chunks.concat(), data);
+
+        let range = 5..(super::STREAM_CHUNK_SIZE + 7);
+        let chunks: Vec<_> = super::stream_file(super::LocalFile {
+            path: file.path().into(),
+            range: Some(range.clone()),
+        })
+        .try_collect()
+        .await?;
+        assert_eq!(chunks.concat(), &data[range]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_local_delete_copy_rename() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = dir.path().to_string_lossy().to_string();
+        let path = |name: &str| format!("file://{dir_path}/{name}");
+        let client = LocalSource::get_client().await?;
+        let io_stats = crate::IOStatsContext::new("test_local_delete_copy_rename".to_string());
+
+        client
+            .put(&path("a"), bytes::Bytes::from_static(b"hello"), None)
+            .await?;
+        client
+            .copy(&path("a"), &path("b"), Some(io_stats.clone()))
+            .await?;
+        client
+            .rename(&path("b"), &path("c"), Some(io_stats.clone()))
+            .await?;
+        assert!(dir.path().join("a").exists());
+        assert!(!dir.path().join("b").exists());
+        let renamed = client.get(&path("c"), None, None).await?.bytes().await?;
+        assert_eq!(&renamed[..], b"hello");
+
+        client
+            .delete_many(&[path("a"), path("c")], Some(io_stats.clone()))
+            .await?;
+        assert!(!dir.path().join("a").exists());
+        assert!(!dir.path().join("c").exists());
+        assert_eq!(io_stats.load_copy_requests(), 1);
+        assert_eq!(io_stats.load_delete_requests(), 2);
+
+        let result = client.delete(&path("a"), None).await;
+        assert!(matches!(result, Err(crate::Error::NotFound { .. })));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_local_full_ls() -> Result<()> {
         let dir = tempfile::tempdir().unwrap();
@@ -470,6 +764,15 @@ mod tests {
                 ),
                 size: Some(file1.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: None,
+                last_modified: file1
+                    .as_file()
+                    .metadata()
+                    .unwrap()
+                    .modified()
+                    .ok()
+                    .map(DateTime::from),
+                content_type: None,
             },
             FileMetadata {
                 filepath: format!(
@@ -479,6 +782,15 @@ mod tests {
                 ),
                 size: Some(file2.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: None,
+                last_modified: file2
+                    .as_file()
+                    .metadata()
+                    .unwrap()
+                    .modified()
+                    .ok()
+                    .map(DateTime::from),
+                content_type: None,
             },
             FileMetadata {
                 filepath: format!(
@@ -488,6 +800,15 @@ mod tests {
                 ),
                 size: Some(file3.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                etag: None,
+                last_modified: file3
+                    .as_file()
+                    .metadata()
+                    .unwrap()
+                    .modified()
+                    .ok()
+                    .map(DateTime::from),
+                content_type: None,
             },
         ];
         expected.sort_by(|a, b| a.filepath.cmp(&b.filepath));
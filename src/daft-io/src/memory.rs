@@ -0,0 +1,430 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use lazy_static::lazy_static;
+use snafu::Snafu;
+
+use crate::object_io::{FileMetadata, FileType, GetResult, LSResult, ObjectSource};
+use crate::stats::IOStatsRef;
+use crate::stream_utils::io_stats_on_bytestream;
+use crate::FileFormat;
+
+const MEMORY_PROTOCOL: &str = "memory://";
+const MEMORY_DELIMITER: &str = "/";
+const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+
+lazy_static! {
+    /// Objects in the in-memory store, keyed by their URI without the `memory://` prefix. The store is shared by all
+    /// clients in the process and lives until the process exits.
+    static ref MEMORY_STORE: RwLock<BTreeMap<String, Bytes>> = RwLock::new(BTreeMap::new());
+}
+
+#[derive(Debug, Snafu)]
+enum Error {
+    #[snafu(display("Unable to convert URL \"{}\" to an in-memory object key", path))]
+    InvalidUrl { path: String },
+
+    #[snafu(display("Not Found: \"{}\"", path))]
+    NotFound { path: String },
+
+    #[snafu(display("Not a File: \"{}\"", path))]
+    NotAFile { path: String },
+
+    #[snafu(display(
+        "Invalid range {}..{} for {} of size {}",
+        range.start,
+        range.end,
+        path,
+        size
+    ))]
+    InvalidRange {
+        path: String,
+        range: Range<usize>,
+        size: usize,
+    },
+}
+
+impl From<Error> for super::Error {
+    fn from(error: Error) -> Self {
+        use Error::*;
+        match error {
+            NotFound { ref path } => super::Error::NotFound {
+                path: path.into(),
+                source: error.into(),
+            },
+            NotAFile { path } => super::Error::NotAFile { path },
+            _ => super::Error::Generic {
+                store: super::SourceType::Memory,
+                source: error.into(),
+            },
+        }
+    }
+}
+
+fn parse_key(uri: &str) -> super::Result<&str> {
+    uri.strip_prefix(MEMORY_PROTOCOL)
+        .ok_or_else(|| Error::InvalidUrl { path: uri.into() }.into())
+}
+
+fn parse_object_key(uri: &str) -> super::Result<&str> {
+    let key = parse_key(uri)?;
+    if key.is_empty() || key.ends_with(MEMORY_DELIMITER) {
+        return Err(Error::NotAFile { path: uri.into() }.into());
+    }
+    Ok(key)
+}
+
+fn file_metadata(key: &str, data: &Bytes) -> FileMetadata {
+    FileMetadata {
+        filepath: format!("{MEMORY_PROTOCOL}{key}"),
+        size: Some(data.len() as u64),
+        filetype: FileType::File,
+        etag: None,
+        last_modified: None,
+        content_type: None,
+    }
+}
+
+/// An `ObjectSource` backed by a process-wide in-memory map, for tests and ephemeral data.
+///
+/// Keys are treated like object store keys, with `/` delimiting directories: directories exist implicitly as long as
+/// they contain at least one object.
+pub(crate) struct MemorySource {}
+
+impl MemorySource {
+    pub async fn get_client() -> super::Result<Arc<Self>> {
+        Ok(MemorySource {}.into())
+    }
+}
+
+#[async_trait]
+impl ObjectSource for MemorySource {
+    async fn get(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<GetResult> {
+        let key = parse_object_key(uri)?;
+        let data = MEMORY_STORE
+            .read()
+            .unwrap()
+            .get(key)
+            .cloned()
+            .ok_or_else(|| Error::NotFound { path: uri.into() })?;
+        let data = match range {
+            // Like object stores, truncate ranges that extend past the end of the object.
+            Some(range) if range.start <= range.end && range.start <= data.len() => {
+                data.slice(range.start..range.end.min(data.len()))
+            }
+            Some(range) => {
+                return Err(Error::InvalidRange {
+                    path: uri.into(),
+                    range,
+                    size: data.len(),
+                }
+                .into())
+            }
+            None => data,
+        };
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_get_requests(1)
+        }
+        let num_bytes = data.len();
+        Ok(GetResult::Stream(
+            io_stats_on_bytestream(futures::stream::iter([Ok(data)]), io_stats),
+            Some(num_bytes),
+            None,
+            None,
+        ))
+    }
+
+    async fn put(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let key = parse_object_key(uri)?;
+        let num_bytes = data.len();
+        MEMORY_STORE.write().unwrap().insert(key.to_string(), data);
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let key = parse_object_key(uri)?;
+        let size = MEMORY_STORE
+            .read()
+            .unwrap()
+            .get(key)
+            .map(|data| data.len())
+            .ok_or_else(|| Error::NotFound { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_head_requests(1)
+        }
+        Ok(size)
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let key = parse_object_key(uri)?;
+        MEMORY_STORE
+            .write()
+            .unwrap()
+            .remove(key)
+            .ok_or_else(|| Error::NotFound { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src_key = parse_object_key(src)?;
+        let dest_key = parse_object_key(dest)?;
+        let mut store = MEMORY_STORE.write().unwrap();
+        let data = store
+            .get(src_key)
+            .cloned()
+            .ok_or_else(|| Error::NotFound { path: src.into() })?;
+        store.insert(dest_key.to_string(), data);
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn rename(
+        &self,
+        src: &str,
+        dest: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let src_key = parse_object_key(src)?;
+        let dest_key = parse_object_key(dest)?;
+        // Renames are atomic, since they happen under a single write lock.
+        let mut store = MEMORY_STORE.write().unwrap();
+        let data = store
+            .remove(src_key)
+            .ok_or_else(|| Error::NotFound { path: src.into() })?;
+        store.insert(dest_key.to_string(), data);
+        Ok(())
+    }
+
+    async fn glob(
+        self: Arc<Self>,
+        glob_path: &str,
+        fanout_limit: Option<usize>,
+        page_size: Option<i32>,
+        limit: Option<usize>,
+        io_stats: Option<IOStatsRef>,
+        _file_format: Option<FileFormat>,
+    ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+        use crate::object_store_glob::glob;
+
+        // Ensure fanout_limit is not None to prevent runaway concurrency
+        let fanout_limit = fanout_limit.or(Some(DEFAULT_GLOB_FANOUT_LIMIT));
+
+        glob(self, glob_path, fanout_limit, page_size, limit, io_stats).await
+    }
+
+    async fn ls(
+        &self,
+        path: &str,
+        posix: bool,
+        _continuation_token: Option<&str>,
+        _page_size: Option<i32>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<LSResult> {
+        let key = parse_key(path)?;
+        let store = MEMORY_STORE.read().unwrap();
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_list_requests(1)
+        }
+
+        if !posix {
+            // Prefix-based list of all objects with this prefix.
+            let files = store
+                .range(key.to_string()..)
+                .take_while(|(k, _)| k.starts_with(key))
+                .map(|(k, data)| file_metadata(k, data))
+                .collect();
+            return Ok(LSResult {
+                files,
+                continuation_token: None,
+            });
+        }
+
+        // Directory-based list of the entries in the next level, assuming that `path` is a directory first.
+        let prefix = if key.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "{}{MEMORY_DELIMITER}",
+                key.trim_end_matches(MEMORY_DELIMITER)
+            )
+        };
+        let mut files: Vec<FileMetadata> = vec![];
+        for (k, data) in store
+            .range(prefix.clone()..)
+            .take_while(|(k, _)| k.starts_with(&prefix))
+        {
+            match k[prefix.len()..].split_once(MEMORY_DELIMITER) {
+                Some((dir, _)) => {
+                    let filepath = format!("{MEMORY_PROTOCOL}{prefix}{dir}{MEMORY_DELIMITER}");
+                    // Keys are sorted, so all objects in a directory are listed consecutively.
+                    if files.last().map(|fm| &fm.filepath) != Some(&filepath) {
+                        files.push(FileMetadata {
+                            filepath,
+                            size: None,
+                            filetype: FileType::Directory,
+                            etag: None,
+                            last_modified: None,
+                            content_type: None,
+                        });
+                    }
+                }
+                None => files.push(file_metadata(k, data)),
+            }
+        }
+
+        if files.is_empty() && !key.is_empty() {
+            // Might be a file.
+            let key = key.trim_end_matches(MEMORY_DELIMITER);
+            match store.get(key) {
+                Some(data) => files.push(file_metadata(key, data)),
+                // Isn't a file or a directory.
+                None => return Err(Error::NotFound { path: path.into() }.into()),
+            }
+        }
+        Ok(LSResult {
+            files,
+            continuation_token: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use crate::object_io::{FileType, ObjectSource};
+    use crate::{IOStatsContext, MemorySource, Result};
+
+    #[tokio::test]
+    async fn test_memory_get_put() -> Result<()> {
+        let client = MemorySource::get_client().await?;
+        let path = "memory://test_memory_get_put/data.bin";
+        let io_stats = IOStatsContext::new("test_memory_get_put");
+
+        client
+            .put(path, bytes::Bytes::from_static(b"hello world"), None)
+            .await?;
+        let data = client
+            .get(path, None, Some(io_stats.clone()))
+            .await?
+            .bytes()
+            .await?;
+        assert_eq!(&data[..], b"hello world");
+        let data = client
+            .get(path, Some(6..100), Some(io_stats.clone()))
+            .await?
+            .bytes()
+            .await?;
+        assert_eq!(&data[..], b"world");
+        assert_eq!(client.get_size(path, None).await?, 11);
+        assert_eq!(io_stats.load_get_requests(), 2);
+        assert_eq!(io_stats.load_bytes_read(), 16);
+
+        assert!(client.get(path, Some(12..13), None).await.is_err());
+        assert!(matches!(
+            client
+                .get("memory://test_memory_get_put/missing.bin", None, None)
+                .await,
+            Err(crate::Error::NotFound { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_memory_ls_and_glob() -> Result<()> {
+        let client = MemorySource::get_client().await?;
+        for key in ["a/1.parquet", "a/2.csv", "a/b/3.parquet", "a/b/c/4.parquet"] {
+            client
+                .put(
+                    &format!("memory://test_memory_ls_and_glob/{key}"),
+                    bytes::Bytes::from_static(b"data"),
+                    None,
+                )
+                .await?;
+        }
+
+        let lsr = client
+            .ls("memory://test_memory_ls_and_glob/a", true, None, None, None)
+            .await?;
+        let entries = lsr
+            .files
+            .iter()
+            .map(|fm| (fm.filepath.as_str(), fm.filetype.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    "memory://test_memory_ls_and_glob/a/1.parquet",
+                    FileType::File
+                ),
+                ("memory://test_memory_ls_and_glob/a/2.csv", FileType::File),
+                ("memory://test_memory_ls_and_glob/a/b/", FileType::Directory),
+            ]
+        );
+
+        // Listing a file returns just that file.
+        let lsr = client
+            .ls(
+                "memory://test_memory_ls_and_glob/a/2.csv",
+                true,
+                None,
+                None,
+                None,
+            )
+            .await?;
+        assert_eq!(lsr.files.len(), 1);
+        assert!(client
+            .ls("memory://test_memory_ls_and_glob/z", true, None, None, None)
+            .await
+            .is_err());
+
+        let mut globbed = client
+            .clone()
+            .glob(
+                "memory://test_memory_ls_and_glob/a/**/*.parquet",
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?
+            .map_ok(|fm| fm.filepath)
+            .try_collect::<Vec<_>>()
+            .await?;
+        globbed.sort();
+        assert_eq!(
+            globbed,
+            vec![
+                "memory://test_memory_ls_and_glob/a/1.parquet",
+                "memory://test_memory_ls_and_glob/a/b/3.parquet",
+                "memory://test_memory_ls_and_glob/a/b/c/4.parquet",
+            ]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+use std::future::Future;
+
+use async_stream::stream;
+use bytes::{Bytes, BytesMut};
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+
+use crate::retry::{with_retries, FALLBACK_RETRY_CONFIG};
+
+/// Stream of bytes to upload to an object store, as passed to `ObjectSource::put_stream`.
+pub type PutStream = BoxStream<'static, super::Result<Bytes>>;
+
+/// Default size of each of the first parts of a multipart upload.
+///
+/// Note that S3 requires all parts but the last to be at least 5MiB, and GCS requires all chunks but the last to be a
+/// multiple of 256KiB.
+pub(crate) const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// Default maximum number of parts of a single upload that are in flight at once, which bounds the memory used by a
+/// streaming upload to roughly `part_size * (DEFAULT_MAX_IN_FLIGHT_PARTS + 1)`.
+pub(crate) const DEFAULT_MAX_IN_FLIGHT_PARTS: usize = 4;
+
+/// Sizes of the parts of a multipart upload.
+///
+/// Since the size of a streamed upload isn't known upfront, the part size starts small and doubles every
+/// `parts_per_size` parts, up to `max_part_size`, so that large uploads stay within an object store's limit on the
+/// number of parts, while small uploads don't buffer large parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PartSizes {
+    pub initial_part_size: usize,
+    pub parts_per_size: usize,
+    pub max_part_size: usize,
+}
+
+impl PartSizes {
+    /// Part sizes for S3, which allows at most 10,000 parts of at most 5GiB each, and objects of at most 5TiB.
+    ///
+    /// Parts of 8MiB doubling every 1,000 parts allow for uploads of up to ~8TiB.
+    pub const S3: Self = Self {
+        initial_part_size: DEFAULT_PART_SIZE,
+        parts_per_size: 1_000,
+        max_part_size: 5 * 1024 * 1024 * 1024,
+    };
+
+    /// Part sizes for Azure block blobs, which allow at most 50,000 blocks of at most 4000MiB each.
+    pub const AZURE: Self = Self {
+        initial_part_size: DEFAULT_PART_SIZE,
+        parts_per_size: 5_000,
+        max_part_size: 4000 * 1024 * 1024,
+    };
+
+    /// Parts of a fixed size, for object stores that don't limit the number of parts, such as GCS resumable uploads.
+    pub const fn fixed(part_size: usize) -> Self {
+        Self {
+            initial_part_size: part_size,
+            parts_per_size: usize::MAX,
+            max_part_size: part_size,
+        }
+    }
+
+    /// Size of the part with the provided 0-based index.
+    pub fn part_size(&self, index: usize) -> usize {
+        let doublings = (index / self.parts_per_size).min(usize::BITS as usize) as u32;
+        if self.initial_part_size.leading_zeros() < doublings {
+            return self.max_part_size;
+        }
+        (self.initial_part_size << doublings).min(self.max_part_size)
+    }
+}
+
+/// Re-chunk a stream of bytes into parts of exactly the sizes given by `part_sizes`, except for the last part, which
+/// may be smaller. An empty stream yields no parts.
+pub(crate) fn into_parts(mut data: PutStream, part_sizes: PartSizes) -> PutStream {
+    let s = stream! {
+        let mut buf = BytesMut::new();
+        let mut num_parts = 0;
+        let mut part_size = part_sizes.part_size(num_parts);
+        while let Some(chunk) = data.next().await {
+            let mut chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+            while !chunk.is_empty() {
+                if buf.is_empty() && chunk.len() >= part_size {
+                    // Avoid copying if the incoming chunk already contains a full part.
+                    yield Ok(chunk.split_to(part_size));
+                } else {
+                    let num_bytes = (part_size - buf.len()).min(chunk.len());
+                    buf.extend_from_slice(&chunk.split_to(num_bytes));
+                    if buf.len() < part_size {
+                        continue;
+                    }
+                    yield Ok(buf.split().freeze());
+                }
+                num_parts += 1;
+                part_size = part_sizes.part_size(num_parts);
+            }
+        }
+        if !buf.is_empty() {
+            yield Ok(buf.freeze());
+        }
+    };
+    s.boxed()
+}
+
+/// Upload each part of `parts` with `upload_part`, which is called with the 0-based index of the part and its bytes.
+///
+/// At most `max_in_flight` parts are buffered or being uploaded at any time, and each part is retried on failure.
+/// Returns the outputs of `upload_part` in part order, or the first error encountered, in which case all in-flight
+/// uploads are cancelled.
+pub(crate) async fn upload_parts<T, F, Fut>(
+    parts: PutStream,
+    max_in_flight: usize,
+    upload_part: F,
+) -> super::Result<Vec<T>>
+where
+    F: Fn(usize, Bytes) -> Fut,
+    Fut: Future<Output = super::Result<T>>,
+{
+    let upload_part = &upload_part;
+    parts
+        .enumerate()
+        .map(|(index, part)| async move {
+            let part = part?;
+            with_retries(
+                &FALLBACK_RETRY_CONFIG,
+                &format!("upload part {index}"),
+                |_| true,
+                || upload_part(index, part.clone()),
+            )
+            .await
+        })
+        .buffered(max_in_flight.max(1))
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use bytes::Bytes;
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::{into_parts, upload_parts, PartSizes, PutStream};
+    use crate::Result;
+
+    fn chunks(sizes: &[usize]) -> PutStream {
+        let chunks = sizes
+            .iter()
+            .enumerate()
+            .map(|(i, size)| Ok(Bytes::from(vec![i as u8; *size])))
+            .collect::<Vec<_>>();
+        futures::stream::iter(chunks).boxed()
+    }
+
+    #[tokio::test]
+    async fn test_into_parts() -> Result<()> {
+        let parts: Vec<_> = into_parts(chunks(&[3, 10, 1, 0, 4]), PartSizes::fixed(4))
+            .try_collect()
+            .await?;
+        let sizes = parts.iter().map(|part| part.len()).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![4, 4, 4, 4, 2]);
+        assert_eq!(
+            parts.concat(),
+            [vec![0; 3], vec![1; 10], vec![2; 1], vec![4; 4]].concat()
+        );
+
+        let parts: Vec<_> = into_parts(chunks(&[]), PartSizes::fixed(4))
+            .try_collect()
+            .await?;
+        assert!(parts.is_empty());
+        Ok(())
+    }
+
+    /// Tests that the part size grows, so that an upload that would need more than the maximum number of parts at
+    /// the initial part size stays within it.
+    #[tokio::test]
+    async fn test_into_parts_growing() -> Result<()> {
+        const MAX_NUM_PARTS: usize = 10;
+        let part_sizes = PartSizes {
+            initial_part_size: 2,
+            parts_per_size: 3,
+            max_part_size: 16,
+        };
+        // 58 bytes would take 29 parts of the initial size.
+        let parts: Vec<_> = into_parts(chunks(&[7, 1, 30, 20]), part_sizes)
+            .try_collect()
+            .await?;
+        let sizes = parts.iter().map(|part| part.len()).collect::<Vec<_>>();
+        assert_eq!(sizes, vec![2, 2, 2, 4, 4, 4, 8, 8, 8, 16]);
+        assert!(sizes.len() <= MAX_NUM_PARTS);
+        assert_eq!(sizes.iter().sum::<usize>(), 58);
+
+        assert_eq!(part_sizes.part_size(9), 16);
+        assert_eq!(part_sizes.part_size(usize::MAX), 16);
+        Ok(())
+    }
+
+    /// Tests that S3 and Azure part sizes allow for uploads of at least 5TiB within their limits on the number of
+    /// parts.
+    #[test]
+    fn test_part_size_limits() {
+        const MIB: usize = 1024 * 1024;
+        const TIB: usize = 1024 * 1024 * MIB;
+        for (part_sizes, max_num_parts) in [(PartSizes::S3, 10_000), (PartSizes::AZURE, 50_000)] {
+            let sizes = (0..max_num_parts)
+                .map(|index| part_sizes.part_size(index))
+                .collect::<Vec<_>>();
+            assert!(sizes.iter().all(|size| *size >= 5 * MIB));
+            assert!(sizes.iter().all(|size| *size <= part_sizes.max_part_size));
+            assert!(sizes.iter().sum::<usize>() >= 5 * TIB);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_parts_bounded_with_retries() -> Result<()> {
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        let attempts = AtomicUsize::new(0);
+        let outputs = upload_parts(
+            into_parts(chunks(&[10; 4]), PartSizes::fixed(5)),
+            2,
+            |index, part| {
+                let (in_flight, max_in_flight, attempts) = (&in_flight, &max_in_flight, &attempts);
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    tokio::task::yield_now().await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    // Fail the first attempt at uploading the third part.
+                    if index == 2 && attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                        return Err(crate::Error::Unhandled {
+                            path: "test".to_string(),
+                            msg: "transient failure".to_string(),
+                        });
+                    }
+                    Ok((index, part.len()))
+                }
+            },
+        )
+        .await?;
+        assert_eq!(outputs, (0..8).map(|i| (i, 5)).collect::<Vec<_>>());
+        assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+}
@@ -1,38 +1,46 @@
 use std::ops::Range;
 use std::sync::Arc;
-use std::time::Duration;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use common_error::DaftError;
+use common_io_config::RetryConfig;
 use futures::stream::{BoxStream, Stream};
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 
 use tokio::sync::OwnedSemaphorePermit;
 
-use crate::local::{collect_file, LocalFile};
+use crate::local::{collect_file, stream_file, LocalFile};
+use crate::multipart::PutStream;
+use crate::retry::backoff;
 use crate::stats::IOStatsRef;
 use crate::FileFormat;
 
+const DEFAULT_DELETE_MANY_CONCURRENCY: usize = 32;
+
 pub struct StreamingRetryParams {
     source: Arc<dyn ObjectSource>,
     input: String,
     range: Option<Range<usize>>,
     io_stats: Option<IOStatsRef>,
+    retry_config: RetryConfig,
 }
 
 impl StreamingRetryParams {
-    pub(crate) fn new(
+    pub fn new(
         source: Arc<dyn ObjectSource>,
         input: String,
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
+        retry_config: RetryConfig,
     ) -> Self {
         Self {
             source,
             input,
             range,
             io_stats,
+            retry_config,
         }
     }
 }
@@ -81,28 +89,26 @@ impl GetResult {
         match get_result {
             File(f) => collect_file(f).await,
             Stream(stream, size, permit, retry_params) => {
-                use rand::Rng;
-                const NUM_TRIES: u64 = 3;
-                const JITTER_MS: u64 = 2_500;
-                const MAX_BACKOFF_MS: u64 = 20_000;
+                let num_tries = retry_params
+                    .as_ref()
+                    .map_or(1, |rp| rp.retry_config.num_tries);
 
                 let mut result = collect_bytes(stream, size, permit).await; // drop permit to ensure quota
-                for attempt in 1..NUM_TRIES {
+                for attempt in 1..num_tries {
                     match result {
                         Err(super::Error::SocketError { .. })
                         | Err(super::Error::UnableToReadBytes { .. })
+                        | Err(super::Error::ChecksumMismatch { .. })
                             if let Some(rp) = &retry_params =>
                         {
-                            let jitter = rand::thread_rng()
-                                .gen_range(0..((1 << (attempt - 1)) * JITTER_MS))
-                                as u64;
-                            let jitter = jitter.min(MAX_BACKOFF_MS);
+                            let backoff = backoff(&rp.retry_config, attempt);
 
                             log::warn!(
-                                "Received Socket Error when streaming bytes! Attempt {attempt} out of {NUM_TRIES} tries. Trying again in {jitter}ms\nDetails\n{}",
+                                "Received error when streaming bytes! Attempt {attempt} out of {num_tries} tries. Trying again in {}ms\nDetails\n{}",
+                                backoff.as_millis(),
                                 result.err().unwrap()
                             );
-                            tokio::time::sleep(Duration::from_millis(jitter)).await;
+                            tokio::time::sleep(backoff).await;
 
                             get_result = rp
                                 .source
@@ -122,6 +128,22 @@ impl GetResult {
         }
     }
 
+    /// Stream the bytes of the object without collecting them into memory, e.g. to upload them elsewhere.
+    ///
+    /// Unlike `bytes`, a stream that fails partway through is not retried.
+    pub fn into_stream(self) -> BoxStream<'static, super::Result<Bytes>> {
+        match self {
+            GetResult::File(f) => stream_file(f),
+            GetResult::Stream(stream, _, permit, _) => stream
+                .map(move |bytes| {
+                    // Hold on to the permit until the stream is dropped.
+                    let _permit = &permit;
+                    bytes
+                })
+                .boxed(),
+        }
+    }
+
     pub fn with_retry(self, params: StreamingRetryParams) -> Self {
         match self {
             GetResult::File(..) => self,
@@ -162,6 +184,10 @@ pub struct FileMetadata {
     pub filepath: String,
     pub size: Option<u64>,
     pub filetype: FileType,
+    /// Opaque identifier of the object's content, which changes whenever the object is overwritten.
+    pub etag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub content_type: Option<String>,
 }
 #[derive(Debug)]
 pub struct LSResult {
@@ -171,8 +197,13 @@ pub struct LSResult {
 
 use async_stream::stream;
 
+/// A backend for reading and writing objects addressed by URL.
+///
+/// Implementations outside this crate can be plugged into an `IOClient` for a custom URL scheme with
+/// [`crate::register_object_source`] or [`crate::IOClient::register_source`]. Implement it with `#[async_trait]`;
+/// `glob` can usually be delegated to [`crate::glob`] on top of `ls`.
 #[async_trait]
-pub(crate) trait ObjectSource: Sync + Send {
+pub trait ObjectSource: Sync + Send {
     async fn get(
         &self,
         uri: &str,
@@ -187,8 +218,77 @@ pub(crate) trait ObjectSource: Sync + Send {
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<()>;
 
+    /// Upload a stream of bytes to `uri` without buffering the entire object in memory.
+    ///
+    /// The default implementation collects the stream and issues a single `put`; sources that support multipart or
+    /// resumable uploads override this.
+    async fn put_stream(
+        &self,
+        uri: &str,
+        data: PutStream,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let chunks: Vec<Bytes> = data.try_collect().await?;
+        self.put(uri, chunks.concat().into(), io_stats).await
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    /// Fetch the metadata of the object at `uri` without reading its content.
+    ///
+    /// The default implementation only fetches the size of the object; sources whose stores return more metadata
+    /// override this.
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
+        let size = self.get_size(uri, io_stats).await?;
+        Ok(FileMetadata {
+            filepath: uri.to_string(),
+            size: Some(size as u64),
+            filetype: FileType::File,
+            etag: None,
+            last_modified: None,
+            content_type: None,
+        })
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()>;
+
+    /// Delete multiple objects.
+    ///
+    /// The default implementation issues a delete request per object, with bounded concurrency; sources that support
+    /// batched deletes override this.
+    async fn delete_many(
+        &self,
+        uris: &[String],
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let deletes = uris
+            .iter()
+            .map(|uri| self.delete(uri, io_stats.clone()))
+            .collect::<Vec<_>>();
+        futures::stream::iter(deletes)
+            .buffer_unordered(DEFAULT_DELETE_MANY_CONCURRENCY)
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(())
+    }
+
+    /// Copy the object at `src` to `dest` within this source, server-side where supported.
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()>;
+
+    /// Rename the object at `src` to `dest` within this source.
+    ///
+    /// Object stores don't support renames natively, so the default implementation copies the object and then deletes
+    /// the source, which is NOT atomic.
+    async fn rename(
+        &self,
+        src: &str,
+        dest: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.copy(src, dest, io_stats.clone()).await?;
+        self.delete(src, io_stats).await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
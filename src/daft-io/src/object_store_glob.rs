@@ -357,7 +357,7 @@ fn _should_return(fm: &FileMetadata) -> bool {
 ///     parallel connections (usually defaulting to 64).
 /// * page_size: control the returned results page size, or None to use the ObjectSource's defaults. Usually only used for testing
 ///     but may yield some performance improvements depending on the workload.
-pub(crate) async fn glob(
+pub async fn glob(
     source: Arc<dyn ObjectSource>,
     glob: &str,
     fanout_limit: Option<usize>,
@@ -376,10 +376,10 @@ pub(crate) async fn glob(
             if !glob.ends_with(GLOB_DELIMITER) {
                 attempt_as_dir = false;
                 // If doesn't have a glob character and doesn't end with a delimiter, assume its a file first.
-                let maybe_size = source.get_size(&glob, io_stats.clone()).await;
-                match maybe_size {
-                    Ok(size_bytes) => yield Ok(FileMetadata{filepath: glob.clone(), size: Some(size_bytes as u64), filetype: FileType::File  }),
-                    Err(crate::Error::NotAFile {..} | crate::Error::NotFound { .. } | crate::Error::UnableToDetermineSize { .. }) => {attempt_as_dir = true;},
+                let maybe_metadata = source.head(&glob, io_stats.clone()).await;
+                match maybe_metadata {
+                    Ok(metadata) if metadata.size.is_some() => yield Ok(FileMetadata{filepath: glob.clone(), filetype: FileType::File, ..metadata}),
+                    Ok(_) | Err(crate::Error::NotAFile {..} | crate::Error::NotFound { .. } | crate::Error::UnableToDetermineSize { .. }) => {attempt_as_dir = true;},
                     Err(err) => yield Err(err),
                 }
             }
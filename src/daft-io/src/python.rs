@@ -2,14 +2,284 @@ pub use common_io_config::python::{AzureConfig, GCSConfig, IOConfig};
 pub use py::register_modules;
 
 mod py {
-    use crate::{get_io_client, get_runtime, parse_url, s3_like, stats::IOStatsContext};
-    use common_error::DaftResult;
-    use futures::TryStreamExt;
+    use std::{ops::Range, sync::Arc};
+
+    use crate::{
+        get_io_client, get_runtime,
+        object_io::GetResult,
+        parse_url, s3_like,
+        stats::{IOStatsContext, IOStatsRef},
+        FileFormat, FileMetadata, FileType, LSResult, ObjectSource, SourceType,
+    };
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use chrono::DateTime;
+    use common_error::{DaftError, DaftResult};
+    use futures::{stream::BoxStream, TryStreamExt};
     use pyo3::{
+        exceptions::{PyFileNotFoundError, PyNotImplementedError},
         prelude::*,
-        types::{PyDict, PyList},
+        types::{PyBytes, PyDict, PyList},
     };
 
+    const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+
+    /// Adapts a Python object implementing `daft.io.ObjectSource` to the [`ObjectSource`] trait.
+    ///
+    /// Python methods are called on tokio's blocking thread pool so that holding the GIL doesn't stall the IO runtime.
+    struct PythonObjectSource {
+        obj: Arc<PyObject>,
+        scheme: Arc<str>,
+    }
+
+    impl PythonObjectSource {
+        async fn call<T, F>(&self, path: &str, operation: &'static str, f: F) -> crate::Result<T>
+        where
+            T: Send + 'static,
+            F: FnOnce(Python, &PyAny) -> PyResult<T> + Send + 'static,
+        {
+            let obj = self.obj.clone();
+            let path = path.to_string();
+            let store = SourceType::Custom(self.scheme.clone());
+            tokio::task::spawn_blocking(move || {
+                Python::with_gil(|py| {
+                    f(py, (*obj).as_ref(py)).map_err(|err| {
+                        if err.is_instance_of::<PyFileNotFoundError>(py) {
+                            crate::Error::NotFound {
+                                path,
+                                source: err.into(),
+                            }
+                        } else if err.is_instance_of::<PyNotImplementedError>(py) {
+                            crate::Error::UnsupportedOperation {
+                                store,
+                                operation: operation.to_string(),
+                            }
+                        } else {
+                            crate::Error::Generic {
+                                store,
+                                source: err.into(),
+                            }
+                        }
+                    })
+                })
+            })
+            .await
+            .map_err(|err| crate::Error::JoinError { source: err })?
+        }
+    }
+
+    #[async_trait]
+    impl ObjectSource for PythonObjectSource {
+        async fn get(
+            &self,
+            uri: &str,
+            range: Option<Range<usize>>,
+            io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<GetResult> {
+            let path = uri.to_string();
+            let data = self
+                .call(uri, "get", move |_, obj| {
+                    let range = range.map(|r| (r.start, r.end));
+                    let data = obj.call_method1("get", (path, range))?;
+                    Ok(Bytes::copy_from_slice(
+                        data.downcast::<PyBytes>()?.as_bytes(),
+                    ))
+                })
+                .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_get_requests(1);
+            }
+            let size = data.len();
+            let stream = futures::stream::iter([Ok(data)]);
+            Ok(GetResult::Stream(
+                crate::stream_utils::io_stats_on_bytestream(stream, io_stats),
+                Some(size),
+                None,
+                None,
+            ))
+        }
+
+        async fn put(
+            &self,
+            uri: &str,
+            data: Bytes,
+            io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<()> {
+            let path = uri.to_string();
+            let num_bytes = data.len();
+            self.call(uri, "put", move |py, obj| {
+                obj.call_method1("put", (path, PyBytes::new(py, &data)))?;
+                Ok(())
+            })
+            .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_put_requests(1);
+                is.mark_bytes_uploaded(num_bytes);
+            }
+            Ok(())
+        }
+
+        async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> crate::Result<usize> {
+            let path = uri.to_string();
+            let size = self
+                .call(uri, "get_size", move |_, obj| {
+                    obj.call_method1("get_size", (path,))?.extract()
+                })
+                .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_head_requests(1);
+            }
+            Ok(size)
+        }
+
+        async fn head(
+            &self,
+            uri: &str,
+            io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<FileMetadata> {
+            let path = uri.to_string();
+            let metadata = self
+                .call(uri, "head", move |_, obj| {
+                    object_info_to_file_metadata(obj.call_method1("head", (path,))?)
+                })
+                .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_head_requests(1);
+            }
+            Ok(metadata)
+        }
+
+        async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> crate::Result<()> {
+            let path = uri.to_string();
+            self.call(uri, "delete", move |_, obj| {
+                obj.call_method1("delete", (path,))?;
+                Ok(())
+            })
+            .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_delete_requests(1);
+            }
+            Ok(())
+        }
+
+        async fn copy(
+            &self,
+            src: &str,
+            dest: &str,
+            io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<()> {
+            let (src_path, dest_path) = (src.to_string(), dest.to_string());
+            self.call(src, "copy", move |_, obj| {
+                obj.call_method1("copy", (src_path, dest_path))?;
+                Ok(())
+            })
+            .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_copy_requests(1);
+            }
+            Ok(())
+        }
+
+        async fn glob(
+            self: Arc<Self>,
+            glob_path: &str,
+            fanout_limit: Option<usize>,
+            page_size: Option<i32>,
+            limit: Option<usize>,
+            io_stats: Option<IOStatsRef>,
+            _file_format: Option<FileFormat>,
+        ) -> crate::Result<BoxStream<'static, crate::Result<FileMetadata>>> {
+            // Ensure fanout_limit is not None to prevent runaway concurrency
+            let fanout_limit = fanout_limit.or(Some(DEFAULT_GLOB_FANOUT_LIMIT));
+            crate::glob(self, glob_path, fanout_limit, page_size, limit, io_stats).await
+        }
+
+        async fn ls(
+            &self,
+            path: &str,
+            posix: bool,
+            _continuation_token: Option<&str>,
+            _page_size: Option<i32>,
+            io_stats: Option<IOStatsRef>,
+        ) -> crate::Result<LSResult> {
+            let dir_path = path.to_string();
+            let files = self
+                .call(path, "ls", move |_, obj| {
+                    obj.call_method1("ls", (dir_path, posix))?
+                        .iter()?
+                        .map(|info| object_info_to_file_metadata(info?))
+                        .collect::<PyResult<Vec<_>>>()
+                })
+                .await?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_list_requests(1);
+            }
+            Ok(LSResult {
+                files,
+                continuation_token: None,
+            })
+        }
+    }
+
+    /// Converts a Python `daft.io.ObjectInfo` to a [`FileMetadata`].
+    fn object_info_to_file_metadata(info: &PyAny) -> PyResult<FileMetadata> {
+        let is_dir: bool = info.getattr("is_dir")?.extract()?;
+        let last_modified = info.getattr("last_modified")?;
+        let last_modified = if last_modified.is_none() {
+            None
+        } else {
+            let timestamp: f64 = last_modified.call_method0("timestamp")?.extract()?;
+            DateTime::from_timestamp_micros((timestamp * 1e6).round() as i64)
+        };
+        Ok(FileMetadata {
+            filepath: info.getattr("path")?.extract()?,
+            size: info.getattr("size")?.extract()?,
+            filetype: if is_dir {
+                FileType::Directory
+            } else {
+                FileType::File
+            },
+            etag: info.getattr("etag")?.extract()?,
+            last_modified,
+            content_type: info.getattr("content_type")?.extract()?,
+        })
+    }
+
+    /// Converts a [`FileMetadata`] to the dict returned by `io_glob` and `io_head`, with `last_modified` as a
+    /// timezone-aware `datetime`.
+    fn file_metadata_to_pydict(py: Python, file: FileMetadata) -> PyResult<&PyDict> {
+        let last_modified = file
+            .last_modified
+            .map(|last_modified| -> PyResult<_> {
+                let datetime = py.import("datetime")?;
+                let utc = datetime.getattr("timezone")?.getattr("utc")?;
+                let timestamp = last_modified.timestamp_micros() as f64 / 1e6;
+                datetime
+                    .getattr("datetime")?
+                    .call_method1("fromtimestamp", (timestamp, utc))
+            })
+            .transpose()?;
+        let dict = PyDict::new(py);
+        dict.set_item("type", format!("{:?}", file.filetype))?;
+        dict.set_item("path", file.filepath)?;
+        dict.set_item("size", file.size)?;
+        dict.set_item("etag", file.etag)?;
+        dict.set_item("last_modified", last_modified)?;
+        dict.set_item("content_type", file.content_type)?;
+        Ok(dict)
+    }
+
+    /// Registers a Python `daft.io.ObjectSource` to handle URLs with the provided scheme.
+    #[pyfunction]
+    fn register_object_source(scheme: &str, source: PyObject) -> PyResult<()> {
+        let source = PythonObjectSource {
+            obj: Arc::new(source),
+            scheme: scheme.to_lowercase().into(),
+        };
+        crate::register_object_source(scheme, Arc::new(source)).map_err(DaftError::from)?;
+        Ok(())
+    }
+
     #[pyfunction]
     fn io_glob(
         py: Python,
@@ -52,15 +322,35 @@ mod py {
         let lsr = lsr?;
         let mut to_rtn = vec![];
         for file in lsr {
-            let dict = PyDict::new(py);
-            dict.set_item("type", format!("{:?}", file.filetype))?;
-            dict.set_item("path", file.filepath)?;
-            dict.set_item("size", file.size)?;
-            to_rtn.push(dict);
+            to_rtn.push(file_metadata_to_pydict(py, file)?);
         }
         Ok(PyList::new(py, to_rtn))
     }
 
+    /// Fetches the metadata of the object at `path` without reading its content.
+    #[pyfunction]
+    fn io_head(
+        py: Python,
+        path: String,
+        multithreaded_io: Option<bool>,
+        io_config: Option<common_io_config::python::IOConfig>,
+    ) -> PyResult<&PyDict> {
+        let multithreaded_io = multithreaded_io.unwrap_or(true);
+        let io_stats = IOStatsContext::new(format!("io_head for {path}"));
+
+        let metadata: DaftResult<FileMetadata> = py.allow_threads(|| {
+            let io_client = get_io_client(
+                multithreaded_io,
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let runtime_handle = get_runtime(multithreaded_io)?;
+            runtime_handle.block_on_current_thread(async move {
+                Ok(io_client.single_url_head(path, Some(io_stats)).await?)
+            })
+        });
+        file_metadata_to_pydict(py, metadata?)
+    }
+
     /// Creates an S3Config from the current environment, auto-discovering variables such as
     /// credentials, regions and more.
     #[pyfunction]
@@ -75,7 +365,9 @@ mod py {
     pub fn register_modules(py: Python, parent: &PyModule) -> PyResult<()> {
         common_io_config::python::register_modules(py, parent)?;
         parent.add_function(wrap_pyfunction!(io_glob, parent)?)?;
+        parent.add_function(wrap_pyfunction!(io_head, parent)?)?;
         parent.add_function(wrap_pyfunction!(s3_config_from_env, parent)?)?;
+        parent.add_function(wrap_pyfunction!(register_object_source, parent)?)?;
         Ok(())
     }
 }
@@ -0,0 +1,138 @@
+use std::{fmt::Display, future::Future, time::Duration};
+
+use common_io_config::RetryConfig;
+use rand::Rng;
+
+/// Upper bound of the backoff between two attempts of a request, regardless of the number of attempts made so far.
+const MAX_BACKOFF_MS: u64 = 20_000;
+
+/// Retry settings for requests that don't belong to a store with retry settings of its own, such as mid-stream retries
+/// of S3 reads (whose individual requests are already retried by the AWS SDK) or parts of multipart uploads.
+pub(crate) const FALLBACK_RETRY_CONFIG: RetryConfig = RetryConfig {
+    num_tries: 3,
+    initial_backoff_ms: 2_500,
+    connect_timeout_ms: 30_000,
+    read_timeout_ms: 30_000,
+};
+
+/// Returns whether an HTTP status code indicates a transient failure, for which a request may succeed if it's retried.
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// Returns the jittered exponential backoff to wait for after the `attempt`-th (1-based) failed attempt of a request.
+pub(crate) fn backoff(config: &RetryConfig, attempt: u32) -> Duration {
+    let max_backoff_ms = config
+        .initial_backoff_ms
+        .saturating_mul(1u64 << attempt.saturating_sub(1).min(32))
+        .clamp(1, MAX_BACKOFF_MS);
+    Duration::from_millis(rand::thread_rng().gen_range(0..max_backoff_ms))
+}
+
+/// Run the provided request up to `config.num_tries` times, retrying with jittered exponential backoff for as long as
+/// it fails with an error for which `is_retryable` returns true.
+pub(crate) async fn with_retries<T, E, F, Fut>(
+    config: &RetryConfig,
+    description: &str,
+    is_retryable: impl Fn(&E) -> bool,
+    request: F,
+) -> Result<T, E>
+where
+    E: Display,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let num_tries = config.num_tries.max(1);
+    let mut attempt = 1;
+    loop {
+        match request().await {
+            Err(err) if attempt < num_tries && is_retryable(&err) => {
+                let backoff = backoff(config, attempt);
+                log::warn!(
+                    "Failed to {description}! Attempt {attempt} out of {num_tries} tries. Trying again in {}ms\nDetails\n{err}",
+                    backoff.as_millis()
+                );
+                tokio::time::sleep(backoff).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use common_io_config::RetryConfig;
+
+    use super::{backoff, with_retries, MAX_BACKOFF_MS};
+
+    const CONFIG: RetryConfig = RetryConfig {
+        num_tries: 3,
+        initial_backoff_ms: 1,
+        connect_timeout_ms: 1_000,
+        read_timeout_ms: 1_000,
+    };
+
+    #[test]
+    fn test_backoff_is_bounded() {
+        let config = RetryConfig {
+            initial_backoff_ms: 1_000,
+            ..CONFIG
+        };
+        for _ in 0..100 {
+            assert!(backoff(&config, 1).as_millis() < 1_000);
+            assert!(backoff(&config, 3).as_millis() < 4_000);
+            assert!(backoff(&config, 64).as_millis() < MAX_BACKOFF_MS as u128);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_with_retries() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<u32, String> = with_retries(
+            &CONFIG,
+            "succeed eventually",
+            |_| true,
+            || async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err("transient".to_string()),
+                    attempt => Ok(attempt),
+                }
+            },
+        )
+        .await;
+        assert_eq!(result, Ok(1));
+
+        // Gives up after `num_tries` attempts.
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), String> = with_retries(
+            &CONFIG,
+            "always fail",
+            |_| true,
+            || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err("transient".to_string())
+            },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // Errors that aren't retryable are returned immediately.
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), String> = with_retries(
+            &CONFIG,
+            "fail permanently",
+            |err: &String| err != "permanent",
+            || async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err("permanent".to_string())
+            },
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
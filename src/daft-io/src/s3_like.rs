@@ -2,17 +2,31 @@ use async_trait::async_trait;
 use aws_config::meta::credentials::CredentialsProviderChain;
 use aws_config::retry::RetryMode;
 use aws_config::timeout::TimeoutConfig;
+use aws_sdk_s3::operation::abort_multipart_upload::AbortMultipartUploadError;
+use aws_sdk_s3::operation::complete_multipart_upload::CompleteMultipartUploadError;
+use aws_sdk_s3::operation::copy_object::CopyObjectError;
+use aws_sdk_s3::operation::create_multipart_upload::CreateMultipartUploadError;
+use aws_sdk_s3::operation::delete_object::DeleteObjectError;
+use aws_sdk_s3::operation::delete_objects::DeleteObjectsError;
 use aws_sdk_s3::operation::put_object::PutObjectError;
+use aws_sdk_s3::operation::upload_part::UploadPartError;
+use aws_sdk_s3::operation::upload_part_copy::UploadPartCopyError;
 use aws_smithy_async::rt::sleep::TokioSleep;
+use chrono::{DateTime, Utc};
 use futures::stream::BoxStream;
 use reqwest::StatusCode;
 use s3::operation::head_object::HeadObjectError;
 use s3::operation::list_objects_v2::ListObjectsV2Error;
 use tokio::sync::{OwnedSemaphorePermit, SemaphorePermit};
 
+use crate::checksum::Checksum;
+use crate::multipart::{
+    into_parts, upload_parts, PartSizes, PutStream, DEFAULT_MAX_IN_FLIGHT_PARTS,
+};
 use crate::object_io::{FileMetadata, FileType, LSResult};
 use crate::stats::IOStatsRef;
 use crate::stream_utils::io_stats_on_bytestream;
+use crate::throttle::{is_throttle_status, report_throttled_attempt, RequestLimiter};
 use crate::{get_io_pool_num_threads, FileFormat, InvalidArgumentSnafu, SourceType};
 use aws_config::SdkConfig;
 use aws_credential_types::cache::{
@@ -32,21 +46,26 @@ use url::{ParseError, Position};
 use super::object_io::{GetResult, ObjectSource};
 use async_recursion::async_recursion;
 use aws_sdk_s3 as s3;
-use aws_sdk_s3::primitives::ByteStreamError;
+use aws_sdk_s3::primitives::{ByteStreamError, SdkBody};
+use aws_smithy_client::erase::DynConnector;
 
 use std::collections::HashMap;
 
 use std::io;
 use std::ops::Range;
+use std::pin::Pin;
 use std::string::FromUtf8Error;
 use std::sync::Arc;
 use std::time::Duration;
 
 const S3_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+/// Maximum number of keys that can be deleted with a single DeleteObjects request.
+const MAX_DELETE_OBJECTS_KEYS: usize = 1000;
 pub(crate) struct S3LikeSource {
     region_to_client_map: tokio::sync::RwLock<HashMap<Region, Arc<s3::Client>>>,
     connection_pool_sema: Arc<tokio::sync::Semaphore>,
+    request_limiter: RequestLimiter,
     default_region: Region,
     s3_config: S3Config,
     anonymous: bool,
@@ -70,6 +89,104 @@ enum Error {
         source: SdkError<PutObjectError, Response>,
     },
 
+    #[snafu(display(
+        "Unable to create multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCreateMultipartUpload {
+        path: String,
+        source: SdkError<CreateMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to upload part {} to {}: {}",
+        part_number,
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToUploadPart {
+        path: String,
+        part_number: i32,
+        source: SdkError<UploadPartError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to complete multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCompleteMultipartUpload {
+        path: String,
+        source: SdkError<CompleteMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to abort multipart upload to {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToAbortMultipartUpload {
+        path: String,
+        source: SdkError<AbortMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to delete {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToDeleteFile {
+        path: String,
+        source: SdkError<DeleteObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to delete objects in bucket {}: {}",
+        bucket,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToDeleteObjects {
+        bucket: String,
+        source: SdkError<DeleteObjectsError, Response>,
+    },
+
+    #[snafu(display("Unable to delete {path}: {code}: {msg}"))]
+    UnableToDeleteObject {
+        path: String,
+        code: String,
+        msg: String,
+    },
+
+    #[snafu(display(
+        "Unable to copy {} to {}: {}",
+        src,
+        dest,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCopyFile {
+        src: String,
+        dest: String,
+        source: SdkError<CopyObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to copy part {} of {} to {}: {}",
+        part_number,
+        src,
+        dest,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCopyPart {
+        src: String,
+        dest: String,
+        part_number: i32,
+        source: SdkError<UploadPartCopyError, Response>,
+    },
+
+    #[snafu(display("Missing upload id in response when creating multipart upload to {path}"))]
+    MissingUploadId { path: String },
+
     #[snafu(display("Unable to head {}: {}", path, s3::error::DisplayErrorContext(source)))]
     UnableToHeadFile {
         path: String,
@@ -119,6 +236,9 @@ enum Error {
 
     #[snafu(display("Uploads cannot be anonymous. Please disable anonymous S3 access."))]
     UploadsCannotBeAnonymous {},
+
+    #[snafu(display("Deletes cannot be anonymous. Please disable anonymous S3 access."))]
+    DeletesCannotBeAnonymous {},
 }
 
 impl From<Error> for super::Error {
@@ -299,6 +419,80 @@ pub(crate) async fn s3_config_from_env() -> super::Result<S3Config> {
     })
 }
 
+/// Returns the MD5 of the full object from the ETag of a GetObject response, if the ETag is one.
+///
+/// The ETag is only the MD5 of the object's contents for objects that were uploaded in a single request and are
+/// unencrypted or encrypted with SSE-S3.
+fn expected_checksum_from_get(
+    response: &s3::operation::get_object::GetObjectOutput,
+) -> Option<Checksum> {
+    let is_kms_encrypted = matches!(
+        response.server_side_encryption(),
+        Some(s3::types::ServerSideEncryption::AwsKms)
+    );
+    if is_kms_encrypted || response.sse_customer_algorithm().is_some() {
+        return None;
+    }
+    Checksum::from_s3_etag(response.e_tag()?)
+}
+
+/// Maximum size of an object that can be copied with a single CopyObject request.
+const MAX_SINGLE_COPY_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// Size of each part of a multipart copy. S3 allows at most 10,000 parts, so parts of 1GiB cover objects of up to the
+/// maximum object size of 5TiB.
+const COPY_PART_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// Splits an object of `size` bytes into the byte ranges of the parts of a multipart copy.
+fn copy_part_ranges(size: u64, part_size: u64) -> Vec<Range<u64>> {
+    (0..size)
+        .step_by(part_size as usize)
+        .map(|start| start..(start + part_size).min(size))
+        .collect()
+}
+
+/// Aborts a multipart upload so that the parts uploaded so far don't linger (and get billed) in the bucket.
+async fn abort_multipart_upload(
+    client: &s3::Client,
+    bucket: &str,
+    key: &str,
+    upload_id: &str,
+    request_payer: Option<s3::types::RequestPayer>,
+    uri: &str,
+) {
+    let abort_result = client
+        .abort_multipart_upload()
+        .bucket(bucket)
+        .key(key)
+        .upload_id(upload_id)
+        .set_request_payer(request_payer)
+        .send()
+        .await
+        .context(UnableToAbortMultipartUploadSnafu { path: uri });
+    if let Err(err) = abort_result {
+        log::warn!("Failed to abort multipart upload after upload error: {err}");
+    }
+}
+
+/// Percent-encodes an object key for use in the `x-amz-copy-source` header of a CopyObject request, leaving the
+/// unreserved characters and path delimiters as-is.
+fn encode_copy_source_key(key: &str) -> String {
+    let mut encoded = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn to_chrono_date_time(date_time: &s3::primitives::DateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(date_time.secs(), date_time.subsec_nanos())
+}
+
 /// Helper to parse S3 URLs, returning (scheme, bucket, key)
 fn parse_url(uri: &str) -> super::Result<(String, String, String)> {
     let parsed = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
@@ -346,10 +540,43 @@ fn handle_https_client_settings(
                 .build(),
         )
         .build(https_connector);
-    let builder = builder.http_connector(smithy_client);
+    let builder = builder.http_connector(DynConnector::new(ThrottleReportingConnector(
+        DynConnector::new(smithy_client),
+    )));
     Ok(builder)
 }
 
+/// HTTP connector that reports throttled responses to the `RequestLimiter` that the request was sent through.
+///
+/// The SDK retries throttled requests internally, so wrapping the connector, which sees every attempt, lets the
+/// limiter back off as soon as S3 asks to slow down instead of only once the SDK's retries are used up.
+#[derive(Clone)]
+struct ThrottleReportingConnector(DynConnector);
+
+impl hyper::service::Service<hyper::http::Request<SdkBody>> for ThrottleReportingConnector {
+    type Response = hyper::http::Response<SdkBody>;
+    type Error = <DynConnector as hyper::service::Service<hyper::http::Request<SdkBody>>>::Error;
+    type Future = futures::future::BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: hyper::http::Request<SdkBody>) -> Self::Future {
+        let response = self.0.call(req);
+        Box::pin(async move {
+            let response = response.await?;
+            if is_throttle_status(response.status().as_u16()) {
+                report_throttled_attempt();
+            }
+            Ok(response)
+        })
+    }
+}
+
 async fn build_s3_conf(
     config: &S3Config,
     credentials_cache: Option<SharedCredentialsCache>,
@@ -543,12 +770,12 @@ async fn build_client(config: &S3Config) -> super::Result<S3LikeSource> {
     let mut client_map = HashMap::new();
     let default_region = client.conf().region().unwrap().clone();
     client_map.insert(default_region.clone(), client.into());
+    let max_connections = (config.max_connections_per_io_thread as usize)
+        * get_io_pool_num_threads().expect("Should be running in tokio pool");
     Ok(S3LikeSource {
         region_to_client_map: tokio::sync::RwLock::new(client_map),
-        connection_pool_sema: Arc::new(tokio::sync::Semaphore::new(
-            (config.max_connections_per_io_thread as usize)
-                * get_io_pool_num_threads().expect("Should be running in tokio pool"),
-        )),
+        connection_pool_sema: Arc::new(tokio::sync::Semaphore::new(max_connections)),
+        request_limiter: RequestLimiter::new(max_connections, config.max_requests_per_second),
         s3_config: config.clone(),
         default_region,
         anonymous,
@@ -556,11 +783,26 @@ async fn build_client(config: &S3Config) -> super::Result<S3LikeSource> {
 }
 const REGION_HEADER: &str = "x-amz-bucket-region";
 
+/// Returns whether S3 rejected a request because it is being sent requests too quickly (e.g. `503 SlowDown`).
+fn is_throttle_error<E>(err: &SdkError<E, Response>) -> bool {
+    match err {
+        SdkError::ServiceError(err) => is_throttle_status(err.raw().http().status().as_u16()),
+        _ => false,
+    }
+}
+
 impl S3LikeSource {
     pub async fn get_client(config: &S3Config) -> super::Result<Arc<S3LikeSource>> {
         Ok(build_client(config).await?.into())
     }
 
+    /// Returns the `Content-MD5` header to send with uploads of `data`, if checksums are enabled.
+    fn content_md5(&self, data: &[u8]) -> Option<String> {
+        self.s3_config
+            .verify_checksums
+            .then(|| Checksum::Md5(md5::compute(data).0).to_base64())
+    }
+
     async fn get_s3_client(&self, region: &Region) -> super::Result<Arc<s3::Client>> {
         {
             if let Some(client) = self.region_to_client_map.read().await.get(region) {
@@ -596,6 +838,7 @@ impl S3LikeSource {
         uri: &str,
         range: Option<Range<usize>>,
         region: &Region,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
         log::debug!("S3 get at {uri}, range: {range:?}, in region: {region}");
         let (_scheme, bucket, key) = parse_url(uri)?;
@@ -608,7 +851,7 @@ impl S3LikeSource {
                 .get_s3_client(region)
                 .await?
                 .get_object()
-                .bucket(bucket)
+                .bucket(&bucket)
                 .key(key);
 
             let request = if self.s3_config.requester_pays {
@@ -626,32 +869,46 @@ impl S3LikeSource {
                 )),
             };
 
-            let response = if self.anonymous {
-                request
-                    .customize_middleware()
-                    .await
-                    .unwrap()
-                    .map_operation::<Error>(|mut o| {
-                        {
-                            let mut properties = o.properties_mut();
-                            #[allow(unused_mut)]
-                            let mut config = properties
-                                .get_mut::<::aws_sig_auth::signer::OperationSigningConfig>()
-                                .expect("signing config added by make_operation()");
-
-                            config.signing_requirements = SigningRequirements::Disabled;
-                        }
-                        Ok(o)
-                    })
-                    .unwrap()
-                    .send()
-                    .await
-            } else {
-                request.send().await
-            };
+            let request_permit = self
+                .request_limiter
+                .acquire(&bucket, io_stats.clone())
+                .await;
+            let response = request_permit
+                .scope(async {
+                    if self.anonymous {
+                        request
+                            .customize_middleware()
+                            .await
+                            .unwrap()
+                            .map_operation::<Error>(|mut o| {
+                                {
+                                    let mut properties = o.properties_mut();
+                                    #[allow(unused_mut)]
+                                    let mut config = properties
+                                        .get_mut::<::aws_sig_auth::signer::OperationSigningConfig>()
+                                        .expect("signing config added by make_operation()");
+
+                                    config.signing_requirements = SigningRequirements::Disabled;
+                                }
+                                Ok(o)
+                            })
+                            .unwrap()
+                            .send()
+                            .await
+                    } else {
+                        request.send().await
+                    }
+                })
+                .await;
+            request_permit.finish(&response, is_throttle_error);
 
             match response {
                 Ok(v) => {
+                    let expected_checksum = if self.s3_config.verify_checksums && range.is_none() {
+                        expected_checksum_from_get(&v)
+                    } else {
+                        None
+                    };
                     let body = v.body;
                     let owned_string = uri.to_owned();
                     let stream = body
@@ -663,6 +920,12 @@ impl S3LikeSource {
                             .into()
                         })
                         .boxed();
+                    let stream = match expected_checksum {
+                        Some(expected) => {
+                            crate::checksum::verify_bytestream(stream, uri.to_owned(), expected)
+                        }
+                        None => stream,
+                    };
                     Ok(GetResult::Stream(
                         stream,
                         Some(v.content_length as usize),
@@ -689,7 +952,8 @@ impl S3LikeSource {
 
                             let new_region = Region::new(region_name);
                             log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting GET in that region with new client", new_region, region);
-                            self._get_impl(permit, uri, range, &new_region).await
+                            self._get_impl(permit, uri, range, &new_region, io_stats)
+                                .await
                         }
                         _ => Err(UnableToOpenFileSnafu { path: uri }
                             .into_error(SdkError::ServiceError(err))
@@ -707,7 +971,8 @@ impl S3LikeSource {
         _permit: SemaphorePermit<'async_recursion>,
         uri: &str,
         region: &Region,
-    ) -> super::Result<usize> {
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<FileMetadata> {
         log::debug!("S3 head at {uri} in region: {region}");
         let (_scheme, bucket, key) = parse_url(uri)?;
 
@@ -719,7 +984,7 @@ impl S3LikeSource {
                 .get_s3_client(region)
                 .await?
                 .head_object()
-                .bucket(bucket)
+                .bucket(&bucket)
                 .key(key);
 
             let request = if self.s3_config.requester_pays {
@@ -728,32 +993,50 @@ impl S3LikeSource {
                 request
             };
 
-            let response = if self.anonymous {
-                request
-                    .customize_middleware()
-                    .await
-                    .unwrap()
-                    .map_operation::<Error>(|mut o| {
-                        {
-                            let mut properties = o.properties_mut();
-                            #[allow(unused_mut)]
-                            let mut config = properties
-                                .get_mut::<::aws_sig_auth::signer::OperationSigningConfig>()
-                                .expect("signing config added by make_operation()");
-
-                            config.signing_requirements = SigningRequirements::Disabled;
-                        }
-                        Ok(o)
-                    })
-                    .unwrap()
-                    .send()
-                    .await
-            } else {
-                request.send().await
-            };
+            let request_permit = self
+                .request_limiter
+                .acquire(&bucket, io_stats.clone())
+                .await;
+            let response = request_permit
+                .scope(async {
+                    if self.anonymous {
+                        request
+                            .customize_middleware()
+                            .await
+                            .unwrap()
+                            .map_operation::<Error>(|mut o| {
+                                {
+                                    let mut properties = o.properties_mut();
+                                    #[allow(unused_mut)]
+                                    let mut config = properties
+                                        .get_mut::<::aws_sig_auth::signer::OperationSigningConfig>()
+                                        .expect("signing config added by make_operation()");
+
+                                    config.signing_requirements = SigningRequirements::Disabled;
+                                }
+                                Ok(o)
+                            })
+                            .unwrap()
+                            .send()
+                            .await
+                    } else {
+                        request.send().await
+                    }
+                })
+                .await;
+            request_permit.finish(&response, is_throttle_error);
 
             match response {
-                Ok(v) => Ok(v.content_length() as usize),
+                Ok(v) => Ok(FileMetadata {
+                    filepath: uri.to_string(),
+                    size: Some(v.content_length() as u64),
+                    filetype: FileType::File,
+                    etag: v.e_tag().map(|etag| etag.to_string()),
+                    last_modified: v.last_modified().and_then(to_chrono_date_time),
+                    content_type: v
+                        .content_type()
+                        .map(|content_type| content_type.to_string()),
+                }),
                 Err(SdkError::ServiceError(err)) => {
                     let bad_response = err.raw().http();
                     match bad_response.status() {
@@ -772,7 +1055,7 @@ impl S3LikeSource {
 
                             let new_region = Region::new(region_name);
                             log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting HEAD in that region with new client", new_region, region);
-                            self._head_impl(_permit, uri, &new_region).await
+                            self._head_impl(_permit, uri, &new_region, io_stats).await
                         }
                         _ => Err(UnableToHeadFileSnafu { path: uri }
                             .into_error(SdkError::ServiceError(err))
@@ -796,6 +1079,7 @@ impl S3LikeSource {
         continuation_token: Option<String>,
         region: &Region,
         page_size: Option<i32>,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<LSResult> {
         log::debug!("S3 list_objects: Bucket: {bucket}, Key: {key}, continuation_token: {continuation_token:?} in region: {region}");
         let request = self
@@ -825,29 +1109,35 @@ impl S3LikeSource {
             request
         };
 
-        let response = if self.anonymous {
-            request
-                .customize_middleware()
-                .await
-                .unwrap()
-                .map_operation::<Error>(|mut o| {
-                    {
-                        let mut properties = o.properties_mut();
-                        #[allow(unused_mut)]
-                        let mut config = properties
-                            .get_mut::<::aws_sig_auth::signer::OperationSigningConfig>()
-                            .expect("signing config added by make_operation()");
-
-                        config.signing_requirements = SigningRequirements::Disabled;
-                    }
-                    Ok(o)
-                })
-                .unwrap()
-                .send()
-                .await
-        } else {
-            request.send().await
-        };
+        let request_permit = self.request_limiter.acquire(bucket, io_stats.clone()).await;
+        let response = request_permit
+            .scope(async {
+                if self.anonymous {
+                    request
+                        .customize_middleware()
+                        .await
+                        .unwrap()
+                        .map_operation::<Error>(|mut o| {
+                            {
+                                let mut properties = o.properties_mut();
+                                #[allow(unused_mut)]
+                                let mut config = properties
+                                    .get_mut::<::aws_sig_auth::signer::OperationSigningConfig>()
+                                    .expect("signing config added by make_operation()");
+
+                                config.signing_requirements = SigningRequirements::Disabled;
+                            }
+                            Ok(o)
+                        })
+                        .unwrap()
+                        .send()
+                        .await
+                } else {
+                    request.send().await
+                }
+            })
+            .await;
+        request_permit.finish(&response, is_throttle_error);
         let uri = &format!("{scheme}://{bucket}/{key}");
         match response {
             Ok(v) => {
@@ -871,6 +1161,9 @@ impl S3LikeSource {
                             ),
                             size: None,
                             filetype: FileType::Directory,
+                            etag: None,
+                            last_modified: None,
+                            content_type: None,
                         };
                         all_files.push(fmeta);
                     }
@@ -884,6 +1177,9 @@ impl S3LikeSource {
                             ),
                             size: Some(f.size() as u64),
                             filetype: FileType::File,
+                            etag: f.e_tag().map(|etag| etag.to_string()),
+                            last_modified: f.last_modified().and_then(to_chrono_date_time),
+                            content_type: None,
                         };
                         all_files.push(fmeta);
                     }
@@ -920,6 +1216,7 @@ impl S3LikeSource {
                             continuation_token.clone(),
                             &new_region,
                             page_size,
+                            io_stats,
                         )
                         .await
                     }
@@ -941,6 +1238,7 @@ impl S3LikeSource {
         uri: &str,
         data: bytes::Bytes,
         region: &Region,
+        io_stats: Option<IOStatsRef>,
     ) -> super::Result<()> {
         log::debug!(
             "S3 put at {uri}, num_bytes: {}, in region: {region}",
@@ -952,12 +1250,14 @@ impl S3LikeSource {
             Err(Error::NotAFile { path: uri.into() }.into())
         } else {
             log::debug!("S3 put parsed uri: {uri} into Bucket: {bucket}, Key: {key}");
+            let content_md5 = self.content_md5(&data);
             let request = self
                 .get_s3_client(region)
                 .await?
                 .put_object()
+                .set_content_md5(content_md5)
                 .body(data.into())
-                .bucket(bucket)
+                .bucket(&bucket)
                 .key(key);
 
             let request = if self.s3_config.requester_pays {
@@ -969,7 +1269,9 @@ impl S3LikeSource {
             let response = if self.anonymous {
                 return Err(Error::UploadsCannotBeAnonymous {}.into());
             } else {
-                request.send().await
+                self.request_limiter
+                    .send_with_body(&bucket, io_stats, request.send(), is_throttle_error)
+                    .await
             };
 
             match response {
@@ -978,6 +1280,335 @@ impl S3LikeSource {
             }
         }
     }
+
+    async fn _put_stream_impl(
+        &self,
+        uri: &str,
+        data: PutStream,
+        region: &Region,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let mut parts = into_parts(data, PartSizes::S3).peekable();
+        let first_part = parts.next().await.transpose()?;
+        if Pin::new(&mut parts).peek().await.is_none() {
+            // The entire object fits in a single part, so upload it with a single request.
+            return self
+                .put(uri, first_part.unwrap_or_default(), io_stats)
+                .await;
+        }
+        let parts = futures::stream::iter(first_part.map(Ok))
+            .chain(parts)
+            .boxed();
+
+        let (_scheme, bucket, key) = parse_url(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        if self.anonymous {
+            return Err(Error::UploadsCannotBeAnonymous {}.into());
+        }
+        log::debug!("S3 multipart put parsed uri: {uri} into Bucket: {bucket}, Key: {key}");
+        let client = self.get_s3_client(region).await?;
+        let request_payer = self
+            .s3_config
+            .requester_pays
+            .then_some(s3::types::RequestPayer::Requester);
+
+        let upload_id = {
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            let request = client
+                .create_multipart_upload()
+                .bucket(&bucket)
+                .key(&key)
+                .set_request_payer(request_payer.clone())
+                .send();
+            self.request_limiter
+                .send(&bucket, io_stats.clone(), request, is_throttle_error)
+                .await
+                .context(UnableToCreateMultipartUploadSnafu { path: uri })?
+                .upload_id()
+                .ok_or_else(|| Error::MissingUploadId { path: uri.into() })?
+                .to_string()
+        };
+
+        let upload_result: super::Result<()> = async {
+            let completed_parts =
+                upload_parts(parts, DEFAULT_MAX_IN_FLIGHT_PARTS, |index, part| {
+                    let (client, bucket, key, upload_id, request_payer, io_stats) = (
+                        &client,
+                        &bucket,
+                        &key,
+                        &upload_id,
+                        &request_payer,
+                        &io_stats,
+                    );
+                    async move {
+                        // S3 part numbers are 1-based.
+                        let part_number = index as i32 + 1;
+                        let num_bytes = part.len();
+                        let content_md5 = self.content_md5(&part);
+                        let _permit = self
+                            .connection_pool_sema
+                            .acquire()
+                            .await
+                            .context(UnableToGrabSemaphoreSnafu)?;
+                        let request = client
+                            .upload_part()
+                            .bucket(bucket)
+                            .key(key)
+                            .upload_id(upload_id)
+                            .part_number(part_number)
+                            .set_content_md5(content_md5)
+                            .body(part.into())
+                            .set_request_payer(request_payer.clone())
+                            .send();
+                        let response = self
+                            .request_limiter
+                            .send_with_body(bucket, io_stats.clone(), request, is_throttle_error)
+                            .await
+                            .context(UnableToUploadPartSnafu {
+                                path: uri,
+                                part_number,
+                            })?;
+                        if let Some(is) = io_stats.as_ref() {
+                            is.mark_put_requests(1);
+                            is.mark_bytes_uploaded(num_bytes);
+                        }
+                        Ok(s3::types::CompletedPart::builder()
+                            .set_e_tag(response.e_tag().map(String::from))
+                            .part_number(part_number)
+                            .build())
+                    }
+                })
+                .await?;
+
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            let request = client
+                .complete_multipart_upload()
+                .bucket(&bucket)
+                .key(&key)
+                .upload_id(&upload_id)
+                .multipart_upload(
+                    s3::types::CompletedMultipartUpload::builder()
+                        .set_parts(Some(completed_parts))
+                        .build(),
+                )
+                .set_request_payer(request_payer.clone())
+                .send();
+            self.request_limiter
+                .send(&bucket, io_stats.clone(), request, is_throttle_error)
+                .await
+                .context(UnableToCompleteMultipartUploadSnafu { path: uri })?;
+            Ok(())
+        }
+        .await;
+
+        if upload_result.is_err() {
+            abort_multipart_upload(&client, &bucket, &key, &upload_id, request_payer, uri).await;
+        }
+        upload_result
+    }
+
+    /// Copies `src` to `dest` with a multipart upload whose parts are copied server-side with UploadPartCopy, for
+    /// objects that are too large for a single CopyObject request.
+    #[allow(clippy::too_many_arguments)]
+    async fn _copy_multipart_impl(
+        &self,
+        src: &str,
+        dest: &str,
+        copy_source: &str,
+        dest_bucket: &str,
+        dest_key: &str,
+        size: u64,
+        region: &Region,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let client = self.get_s3_client(region).await?;
+        let request_payer = self
+            .s3_config
+            .requester_pays
+            .then_some(s3::types::RequestPayer::Requester);
+
+        let upload_id = {
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            let request = client
+                .create_multipart_upload()
+                .bucket(dest_bucket)
+                .key(dest_key)
+                .set_request_payer(request_payer.clone())
+                .send();
+            self.request_limiter
+                .send(dest_bucket, io_stats.clone(), request, is_throttle_error)
+                .await
+                .context(UnableToCreateMultipartUploadSnafu { path: dest })?
+                .upload_id()
+                .ok_or_else(|| Error::MissingUploadId { path: dest.into() })?
+                .to_string()
+        };
+
+        let copy_result: super::Result<()> = async {
+            let completed_parts = futures::stream::iter(
+                copy_part_ranges(size, COPY_PART_SIZE)
+                    .into_iter()
+                    .enumerate(),
+            )
+            .map(|(index, range)| {
+                let (client, upload_id, request_payer, io_stats) =
+                    (&client, &upload_id, &request_payer, &io_stats);
+                async move {
+                    // S3 part numbers are 1-based.
+                    let part_number = index as i32 + 1;
+                    let _permit = self
+                        .connection_pool_sema
+                        .acquire()
+                        .await
+                        .context(UnableToGrabSemaphoreSnafu)?;
+                    let request = client
+                        .upload_part_copy()
+                        .copy_source(copy_source)
+                        .copy_source_range(format!("bytes={}-{}", range.start, range.end - 1))
+                        .bucket(dest_bucket)
+                        .key(dest_key)
+                        .upload_id(upload_id)
+                        .part_number(part_number)
+                        .set_request_payer(request_payer.clone())
+                        .send();
+                    let response = self
+                        .request_limiter
+                        .send(dest_bucket, io_stats.clone(), request, is_throttle_error)
+                        .await
+                        .context(UnableToCopyPartSnafu {
+                            src,
+                            dest,
+                            part_number,
+                        })?;
+                    if let Some(is) = io_stats.as_ref() {
+                        is.mark_copy_requests(1);
+                    }
+                    Ok::<_, super::Error>(
+                        s3::types::CompletedPart::builder()
+                            .set_e_tag(
+                                response
+                                    .copy_part_result()
+                                    .and_then(|result| result.e_tag())
+                                    .map(String::from),
+                            )
+                            .part_number(part_number)
+                            .build(),
+                    )
+                }
+            })
+            .buffered(DEFAULT_MAX_IN_FLIGHT_PARTS)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            let request = client
+                .complete_multipart_upload()
+                .bucket(dest_bucket)
+                .key(dest_key)
+                .upload_id(&upload_id)
+                .multipart_upload(
+                    s3::types::CompletedMultipartUpload::builder()
+                        .set_parts(Some(completed_parts))
+                        .build(),
+                )
+                .set_request_payer(request_payer.clone())
+                .send();
+            self.request_limiter
+                .send(dest_bucket, io_stats.clone(), request, is_throttle_error)
+                .await
+                .context(UnableToCompleteMultipartUploadSnafu { path: dest })?;
+            Ok(())
+        }
+        .await;
+
+        if copy_result.is_err() {
+            abort_multipart_upload(
+                &client,
+                dest_bucket,
+                dest_key,
+                &upload_id,
+                request_payer,
+                dest,
+            )
+            .await;
+        }
+        copy_result
+    }
+
+    async fn _delete_many_impl(
+        &self,
+        bucket: &str,
+        keys: &[String],
+        region: &Region,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        if self.anonymous {
+            return Err(Error::DeletesCannotBeAnonymous {}.into());
+        }
+        let client = self.get_s3_client(region).await?;
+        let request_payer = self
+            .s3_config
+            .requester_pays
+            .then_some(s3::types::RequestPayer::Requester);
+        for chunk in keys.chunks(MAX_DELETE_OBJECTS_KEYS) {
+            let objects = chunk
+                .iter()
+                .map(|key| s3::types::ObjectIdentifier::builder().key(key).build())
+                .collect::<Vec<_>>();
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            let request = client
+                .delete_objects()
+                .bucket(bucket)
+                .delete(
+                    s3::types::Delete::builder()
+                        .set_objects(Some(objects))
+                        .quiet(true)
+                        .build(),
+                )
+                .set_request_payer(request_payer.clone())
+                .send();
+            let response = self
+                .request_limiter
+                .send(bucket, io_stats.clone(), request, is_throttle_error)
+                .await
+                .context(UnableToDeleteObjectsSnafu { bucket })?;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_delete_requests(1);
+            }
+            // In quiet mode, the response only lists the keys that failed to be deleted.
+            if let Some(err) = response.errors().and_then(|errors| errors.first()) {
+                return Err(Error::UnableToDeleteObject {
+                    path: format!("{bucket}/{}", err.key().unwrap_or_default()),
+                    code: err.code().unwrap_or_default().to_string(),
+                    msg: err.message().unwrap_or_default().to_string(),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -995,7 +1626,7 @@ impl ObjectSource for S3LikeSource {
             .await
             .context(UnableToGrabSemaphoreSnafu)?;
         let get_result = self
-            ._get_impl(permit, uri, range, &self.default_region)
+            ._get_impl(permit, uri, range, &self.default_region, io_stats.clone())
             .await?;
 
         if io_stats.is_some() {
@@ -1030,7 +1661,7 @@ impl ObjectSource for S3LikeSource {
             .acquire_owned()
             .await
             .context(UnableToGrabSemaphoreSnafu)?;
-        self._put_impl(permit, uri, data, &self.default_region)
+        self._put_impl(permit, uri, data, &self.default_region, io_stats.clone())
             .await?;
 
         if let Some(io_stats) = io_stats {
@@ -1041,13 +1672,145 @@ impl ObjectSource for S3LikeSource {
         Ok(())
     }
 
+    async fn put_stream(
+        &self,
+        uri: &str,
+        data: PutStream,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self._put_stream_impl(uri, data, &self.default_region, io_stats)
+            .await
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (_scheme, bucket, key) = parse_url(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        if self.anonymous {
+            return Err(Error::DeletesCannotBeAnonymous {}.into());
+        }
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let request = self
+            .get_s3_client(&self.default_region)
+            .await?
+            .delete_object()
+            .bucket(&bucket)
+            .key(key)
+            .set_request_payer(
+                self.s3_config
+                    .requester_pays
+                    .then_some(s3::types::RequestPayer::Requester),
+            )
+            .send();
+        self.request_limiter
+            .send(&bucket, io_stats.clone(), request, is_throttle_error)
+            .await
+            .context(UnableToDeleteFileSnafu { path: uri })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1);
+        }
+        Ok(())
+    }
+
+    async fn delete_many(
+        &self,
+        uris: &[String],
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let mut keys_by_bucket: HashMap<String, Vec<String>> = HashMap::new();
+        for uri in uris {
+            let (_scheme, bucket, key) = parse_url(uri)?;
+            if key.is_empty() {
+                return Err(Error::NotAFile { path: uri.clone() }.into());
+            }
+            keys_by_bucket.entry(bucket).or_default().push(key);
+        }
+        for (bucket, keys) in keys_by_bucket {
+            self._delete_many_impl(&bucket, &keys, &self.default_region, io_stats.clone())
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dest: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (_scheme, src_bucket, src_key) = parse_url(src)?;
+        let (_scheme, dest_bucket, dest_key) = parse_url(dest)?;
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if dest_key.is_empty() {
+            return Err(Error::NotAFile { path: dest.into() }.into());
+        }
+        if self.anonymous {
+            return Err(Error::UploadsCannotBeAnonymous {}.into());
+        }
+        let copy_source = format!("{src_bucket}/{}", encode_copy_source_key(&src_key));
+        let size = self
+            .head(src, io_stats.clone())
+            .await?
+            .size
+            .unwrap_or_default();
+        if size > MAX_SINGLE_COPY_SIZE {
+            return self
+                ._copy_multipart_impl(
+                    src,
+                    dest,
+                    &copy_source,
+                    &dest_bucket,
+                    &dest_key,
+                    size,
+                    &self.default_region,
+                    io_stats,
+                )
+                .await;
+        }
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let request = self
+            .get_s3_client(&self.default_region)
+            .await?
+            .copy_object()
+            .copy_source(copy_source)
+            .bucket(&dest_bucket)
+            .key(dest_key)
+            .set_request_payer(
+                self.s3_config
+                    .requester_pays
+                    .then_some(s3::types::RequestPayer::Requester),
+            )
+            .send();
+        self.request_limiter
+            .send(&dest_bucket, io_stats.clone(), request, is_throttle_error)
+            .await
+            .context(UnableToCopyFileSnafu { src, dest })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1);
+        }
+        Ok(())
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let metadata = self.head(uri, io_stats).await?;
+        Ok(metadata.size.unwrap_or_default() as usize)
+    }
+
+    async fn head(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<FileMetadata> {
         let permit = self
             .connection_pool_sema
             .acquire()
             .await
             .context(UnableToGrabSemaphoreSnafu)?;
-        let head_result = self._head_impl(permit, uri, &self.default_region).await?;
+        let head_result = self
+            ._head_impl(permit, uri, &self.default_region, io_stats.clone())
+            .await?;
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1)
         }
@@ -1113,6 +1876,7 @@ impl ObjectSource for S3LikeSource {
                     continuation_token.map(String::from),
                     &self.default_region,
                     page_size,
+                    io_stats.clone(),
                 )
                 .await?
             };
@@ -1138,6 +1902,7 @@ impl ObjectSource for S3LikeSource {
                         continuation_token.map(String::from),
                         &self.default_region,
                         page_size,
+                        io_stats.clone(),
                     )
                     .await?;
                 if let Some(is) = io_stats.as_ref() {
@@ -1172,6 +1937,7 @@ impl ObjectSource for S3LikeSource {
                     continuation_token.map(String::from),
                     &self.default_region,
                     page_size,
+                    io_stats.clone(),
                 )
                 .await?
             };
@@ -1188,6 +1954,7 @@ impl ObjectSource for S3LikeSource {
 mod tests {
 
     use crate::object_io::ObjectSource;
+    use crate::IOStatsContext;
     use crate::Result;
     use crate::S3LikeSource;
     use common_io_config::S3Config;
@@ -1256,4 +2023,95 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_encode_copy_source_key() {
+        use super::encode_copy_source_key;
+
+        assert_eq!(
+            encode_copy_source_key("dir/sub-dir/file_1.parquet"),
+            "dir/sub-dir/file_1.parquet"
+        );
+        assert_eq!(
+            encode_copy_source_key("dir/a b+c=d?.csv"),
+            "dir/a%20b%2Bc%3Dd%3F.csv"
+        );
+        assert_eq!(encode_copy_source_key("é"), "%C3%A9");
+    }
+
+    #[tokio::test]
+    async fn test_throttled_attempts_reported_to_limiter() -> Result<()> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // A local S3 endpoint that throttles the first two attempts of every request.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            for attempt in 0.. {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = vec![];
+                let mut buf = [0u8; 4096];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let num_bytes = socket.read(&mut buf).await.unwrap();
+                    if num_bytes == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..num_bytes]);
+                }
+                let (status, body) = if attempt < 2 {
+                    (
+                        "503 Slow Down",
+                        "<Error><Code>SlowDown</Code><Message>Please reduce your request rate.</Message></Error>",
+                    )
+                } else {
+                    ("200 OK", "hello")
+                };
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+                socket.shutdown().await.unwrap();
+            }
+        });
+
+        let config = S3Config {
+            endpoint_url: Some(endpoint),
+            region_name: Some("us-east-1".to_string()),
+            anonymous: true,
+            num_tries: 3,
+            retry_initial_backoff_ms: 1,
+            ..Default::default()
+        };
+        let client = S3LikeSource::get_client(&config).await?;
+        let max_concurrency = client.request_limiter.concurrency_limit("unused-bucket");
+        let io_stats = IOStatsContext::new("test_throttled_attempts_reported_to_limiter");
+
+        // The SDK retries the throttled attempts itself, but the limiter still backs off.
+        let data = client
+            .get("s3://bucket/key", None, Some(io_stats.clone()))
+            .await?
+            .bytes()
+            .await?;
+        assert_eq!(&data[..], b"hello");
+        assert_eq!(io_stats.load_throttled_requests(), 2);
+        assert!(client.request_limiter.concurrency_limit("bucket") < max_concurrency);
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_part_ranges() {
+        use super::{copy_part_ranges, COPY_PART_SIZE, MAX_SINGLE_COPY_SIZE};
+
+        assert_eq!(copy_part_ranges(10, 4), vec![0..4, 4..8, 8..10]);
+        assert_eq!(copy_part_ranges(8, 4), vec![0..4, 4..8]);
+
+        let size = MAX_SINGLE_COPY_SIZE + 1;
+        let ranges = copy_part_ranges(size, COPY_PART_SIZE);
+        assert_eq!(ranges.len(), 6);
+        assert_eq!(ranges.last().unwrap().end, size);
+
+        // The largest S3 object, of 5TiB, fits within the limit of 10,000 parts.
+        assert!(copy_part_ranges(5 * 1024 * 1024 * 1024 * 1024, COPY_PART_SIZE).len() <= 10_000);
+    }
 }
@@ -15,6 +15,11 @@ pub struct IOStatsContext {
     num_head_requests: atomic::AtomicUsize,
     num_list_requests: atomic::AtomicUsize,
     num_put_requests: atomic::AtomicUsize,
+    num_delete_requests: atomic::AtomicUsize,
+    num_copy_requests: atomic::AtomicUsize,
+    num_cache_hits: atomic::AtomicUsize,
+    num_cache_misses: atomic::AtomicUsize,
+    num_throttled_requests: atomic::AtomicUsize,
     bytes_read: atomic::AtomicUsize,
     bytes_uploaded: atomic::AtomicUsize,
 }
@@ -28,11 +33,16 @@ impl Drop for IOStatsContext {
         let mean_get_size = (bytes_read as f64) / (num_gets as f64);
         let mean_put_size = (bytes_uploaded as f64) / (num_puts as f64);
         log::info!(
-            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}, BytesUploaded: {}, AvgPutSize: {}",
+            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, Deletes: {}, Copies: {}, CacheHits: {}, CacheMisses: {}, Throttled: {}, BytesRead: {}, AvgGetSize: {}, BytesUploaded: {}, AvgPutSize: {}",
             self.name,
             num_gets,
             self.load_head_requests(),
             self.load_list_requests(),
+            self.load_delete_requests(),
+            self.load_copy_requests(),
+            self.load_cache_hits(),
+            self.load_cache_misses(),
+            self.load_throttled_requests(),
             bytes_read,
             mean_get_size as i64,
             bytes_uploaded,
@@ -55,6 +65,11 @@ impl IOStatsContext {
             num_head_requests: atomic::AtomicUsize::new(0),
             num_list_requests: atomic::AtomicUsize::new(0),
             num_put_requests: atomic::AtomicUsize::new(0),
+            num_delete_requests: atomic::AtomicUsize::new(0),
+            num_copy_requests: atomic::AtomicUsize::new(0),
+            num_cache_hits: atomic::AtomicUsize::new(0),
+            num_cache_misses: atomic::AtomicUsize::new(0),
+            num_throttled_requests: atomic::AtomicUsize::new(0),
             bytes_read: atomic::AtomicUsize::new(0),
             bytes_uploaded: atomic::AtomicUsize::new(0),
         })
@@ -67,23 +82,53 @@ impl IOStatsContext {
     }
 
     #[inline]
-    pub(crate) fn mark_head_requests(&self, num_requests: usize) {
+    pub fn mark_head_requests(&self, num_requests: usize) {
         self.num_head_requests
             .fetch_add(num_requests, atomic::Ordering::Relaxed);
     }
 
     #[inline]
-    pub(crate) fn mark_list_requests(&self, num_requests: usize) {
+    pub fn mark_list_requests(&self, num_requests: usize) {
         self.num_list_requests
             .fetch_add(num_requests, atomic::Ordering::Relaxed);
     }
 
     #[inline]
-    pub(crate) fn mark_put_requests(&self, num_requests: usize) {
+    pub fn mark_put_requests(&self, num_requests: usize) {
         self.num_put_requests
             .fetch_add(num_requests, atomic::Ordering::Relaxed);
     }
 
+    #[inline]
+    pub fn mark_delete_requests(&self, num_requests: usize) {
+        self.num_delete_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn mark_copy_requests(&self, num_requests: usize) {
+        self.num_copy_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn mark_cache_hits(&self, num_hits: usize) {
+        self.num_cache_hits
+            .fetch_add(num_hits, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn mark_cache_misses(&self, num_misses: usize) {
+        self.num_cache_misses
+            .fetch_add(num_misses, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn mark_throttled_requests(&self, num_requests: usize) {
+        self.num_throttled_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
     #[inline]
     pub fn load_get_requests(&self) -> usize {
         self.num_get_requests.load(atomic::Ordering::Acquire)
@@ -104,6 +149,31 @@ impl IOStatsContext {
         self.num_put_requests.load(atomic::Ordering::Acquire)
     }
 
+    #[inline]
+    pub fn load_delete_requests(&self) -> usize {
+        self.num_delete_requests.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_copy_requests(&self) -> usize {
+        self.num_copy_requests.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_cache_hits(&self) -> usize {
+        self.num_cache_hits.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_cache_misses(&self) -> usize {
+        self.num_cache_misses.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_throttled_requests(&self) -> usize {
+        self.num_throttled_requests.load(atomic::Ordering::Acquire)
+    }
+
     #[inline]
     pub fn mark_bytes_read(&self, bytes_read: usize) {
         self.bytes_read
@@ -111,7 +181,7 @@ impl IOStatsContext {
     }
 
     #[inline]
-    pub(crate) fn mark_bytes_uploaded(&self, bytes_uploaded: usize) {
+    pub fn mark_bytes_uploaded(&self, bytes_uploaded: usize) {
         self.bytes_uploaded
             .fetch_add(bytes_uploaded, atomic::Ordering::Relaxed);
     }
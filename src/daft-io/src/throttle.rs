@@ -0,0 +1,455 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use futures::{stream::BoxStream, Stream, StreamExt};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::stats::IOStatsRef;
+
+/// Factor the concurrency limit of a host is multiplied by when it throttles a request.
+const DECREASE_FACTOR: f64 = 0.5;
+/// Successful requests slower than this multiple of the host's smoothed latency don't grow its concurrency limit.
+const LATENCY_TOLERANCE: f64 = 2.0;
+/// Weight of each new latency sample in the smoothed latency of a host.
+const LATENCY_SMOOTHING: f64 = 0.1;
+/// Minimum time between two decreases of the concurrency limit of a host, so that a burst of throttled requests that
+/// were all in flight at the same time only counts as a single congestion event.
+const MIN_DECREASE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Returns whether an HTTP status code is one that object stores use to ask clients to slow down.
+pub(crate) fn is_throttle_status(status: u16) -> bool {
+    matches!(status, 429 | 503)
+}
+
+tokio::task_local! {
+    /// The request that is currently being sent through a `RequestLimiter` by this task.
+    static CURRENT_REQUEST: Arc<RequestContext>;
+}
+
+/// Reports that an attempt of the request currently being sent through a `RequestLimiter` was throttled.
+///
+/// Object store SDKs retry throttled attempts internally, so their HTTP transports call this for every throttled
+/// response, which lets the limiter back off before the SDK's retries are used up. Does nothing outside of a request
+/// sent through a `RequestLimiter`.
+pub(crate) fn report_throttled_attempt() {
+    let _ = CURRENT_REQUEST.try_with(|request| request.on_throttled());
+}
+
+/// Limits the requests sent to each host (e.g. an S3 bucket or Azure container) of an object store.
+///
+/// Each host gets its own concurrency limit, which is adjusted with AIMD (additive increase, multiplicative
+/// decrease): it is halved whenever the host throttles a request and grows by one for every window of successful
+/// requests, up to `max_concurrency`. Hosts can additionally be capped to a fixed number of requests per second.
+///
+/// Permits are only held until the response headers are received, so the limit applies to the rate at which requests
+/// are issued rather than to the number of open connections.
+pub(crate) struct RequestLimiter {
+    max_concurrency: usize,
+    max_requests_per_second: Option<u32>,
+    hosts: Mutex<HashMap<String, Arc<HostLimiter>>>,
+}
+
+impl RequestLimiter {
+    pub(crate) fn new(max_concurrency: usize, max_requests_per_second: Option<u32>) -> Self {
+        Self {
+            max_concurrency: max_concurrency.max(1),
+            max_requests_per_second,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn host(&self, host: &str) -> Arc<HostLimiter> {
+        let mut hosts = self.hosts.lock().unwrap();
+        if let Some(limiter) = hosts.get(host) {
+            return limiter.clone();
+        }
+        let limiter = Arc::new(HostLimiter::new(
+            self.max_concurrency,
+            self.max_requests_per_second,
+        ));
+        hosts.insert(host.to_string(), limiter.clone());
+        limiter
+    }
+
+    /// Waits until `host` admits another request.
+    pub(crate) async fn acquire(&self, host: &str, io_stats: Option<IOStatsRef>) -> RequestPermit {
+        let host = self.host(host);
+        if let Some(rate) = host.rate.as_ref() {
+            rate.wait().await;
+        }
+        let permit = host
+            .semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("RequestLimiter semaphores are never closed");
+        RequestPermit {
+            permit: Some(permit),
+            request: Arc::new(RequestContext {
+                host,
+                io_stats,
+                throttled: AtomicBool::new(false),
+            }),
+            start: Instant::now(),
+            sample_latency: true,
+        }
+    }
+
+    /// Sends `request` to `host` once it admits it, using `is_throttled` to detect whether the host throttled it.
+    pub(crate) async fn send<T, E>(
+        &self,
+        host: &str,
+        io_stats: Option<IOStatsRef>,
+        request: impl Future<Output = Result<T, E>>,
+        is_throttled: impl FnOnce(&E) -> bool,
+    ) -> Result<T, E> {
+        let permit = self.acquire(host, io_stats).await;
+        let result = CURRENT_REQUEST.scope(permit.request.clone(), request).await;
+        permit.finish(&result, is_throttled);
+        result
+    }
+
+    /// Same as [`Self::send`], but for requests with a body (e.g. uploads), whose latency depends on the amount of
+    /// data sent and therefore isn't used as a congestion signal.
+    pub(crate) async fn send_with_body<T, E>(
+        &self,
+        host: &str,
+        io_stats: Option<IOStatsRef>,
+        request: impl Future<Output = Result<T, E>>,
+        is_throttled: impl FnOnce(&E) -> bool,
+    ) -> Result<T, E> {
+        let mut permit = self.acquire(host, io_stats).await;
+        permit.sample_latency = false;
+        let result = CURRENT_REQUEST.scope(permit.request.clone(), request).await;
+        permit.finish(&result, is_throttled);
+        result
+    }
+
+    /// Limits a stream in which every item is the response to a separate request to `host`, such as the pages of a
+    /// listing, so that each request is only sent once the host admits it.
+    pub(crate) fn limit_stream<T, E>(
+        self: Arc<Self>,
+        host: String,
+        io_stats: Option<IOStatsRef>,
+        stream: impl Stream<Item = Result<T, E>> + Send + 'static,
+        is_throttled: fn(&E) -> bool,
+    ) -> BoxStream<'static, Result<T, E>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        async_stream::stream! {
+            futures::pin_mut!(stream);
+            loop {
+                let permit = self.acquire(&host, io_stats.clone()).await;
+                let Some(result) = CURRENT_REQUEST.scope(permit.request.clone(), stream.next()).await else {
+                    break;
+                };
+                permit.finish(&result, is_throttled);
+                yield result;
+            }
+        }
+        .boxed()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn concurrency_limit(&self, host: &str) -> usize {
+        self.host(host).state.lock().unwrap().limit
+    }
+}
+
+struct HostLimiter {
+    semaphore: Arc<Semaphore>,
+    state: Mutex<AimdState>,
+    rate: Option<RateLimit>,
+}
+
+struct AimdState {
+    limit: usize,
+    max_limit: usize,
+    /// Number of successful requests since the limit last grew.
+    successes: usize,
+    smoothed_latency: Option<Duration>,
+    last_decrease: Option<Instant>,
+    /// Number of permits that still have to be removed from the semaphore after a decrease, which are taken out of
+    /// circulation as in-flight requests complete.
+    pending_decrease: usize,
+}
+
+impl HostLimiter {
+    fn new(max_concurrency: usize, max_requests_per_second: Option<u32>) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_concurrency)),
+            state: Mutex::new(AimdState {
+                limit: max_concurrency,
+                max_limit: max_concurrency,
+                successes: 0,
+                smoothed_latency: None,
+                last_decrease: None,
+                pending_decrease: 0,
+            }),
+            rate: max_requests_per_second
+                .filter(|rps| *rps > 0)
+                .map(|rps| RateLimit {
+                    interval: Duration::from_secs(1) / rps,
+                    next_slot: Mutex::new(Instant::now()),
+                }),
+        }
+    }
+
+    fn on_success(&self, latency: Option<Duration>) {
+        let mut state = self.state.lock().unwrap();
+        let is_slow = match (latency, state.smoothed_latency) {
+            (Some(latency), Some(smoothed)) => {
+                latency.as_secs_f64() > smoothed.as_secs_f64() * LATENCY_TOLERANCE
+            }
+            _ => false,
+        };
+        if let Some(latency) = latency {
+            state.smoothed_latency = Some(match state.smoothed_latency {
+                Some(smoothed) => {
+                    smoothed.mul_f64(1. - LATENCY_SMOOTHING) + latency.mul_f64(LATENCY_SMOOTHING)
+                }
+                None => latency,
+            });
+        }
+        if is_slow || state.limit >= state.max_limit {
+            return;
+        }
+        state.successes += 1;
+        if state.successes >= state.limit {
+            state.successes = 0;
+            state.limit += 1;
+            if state.pending_decrease > 0 {
+                state.pending_decrease -= 1;
+            } else {
+                self.semaphore.add_permits(1);
+            }
+        }
+    }
+
+    fn on_throttled(&self) {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let cooldown = state
+            .smoothed_latency
+            .unwrap_or_default()
+            .max(MIN_DECREASE_INTERVAL);
+        if state
+            .last_decrease
+            .is_some_and(|last| now.duration_since(last) < cooldown)
+        {
+            return;
+        }
+        let new_limit = ((state.limit as f64 * DECREASE_FACTOR) as usize).max(1);
+        let decrease = state.limit - new_limit;
+        state.limit = new_limit;
+        state.successes = 0;
+        state.last_decrease = Some(now);
+        let forgotten = self.semaphore.forget_permits(decrease);
+        state.pending_decrease += decrease - forgotten;
+        log::debug!("Request was throttled, lowering concurrency limit to {new_limit}");
+    }
+
+    /// Returns a permit to the semaphore, unless it has to be taken out of circulation after a decrease.
+    fn release(&self, permit: OwnedSemaphorePermit) {
+        let mut state = self.state.lock().unwrap();
+        if state.pending_decrease > 0 {
+            state.pending_decrease -= 1;
+            permit.forget();
+        }
+    }
+}
+
+struct RateLimit {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimit {
+    async fn wait(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap();
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot.into()).await;
+    }
+}
+
+/// A request sent through a `RequestLimiter`, which throttled attempts of the request are reported to.
+struct RequestContext {
+    host: Arc<HostLimiter>,
+    io_stats: Option<IOStatsRef>,
+    /// Whether any attempt of the request was throttled.
+    throttled: AtomicBool,
+}
+
+impl RequestContext {
+    fn on_throttled(&self) {
+        self.throttled.store(true, Ordering::Relaxed);
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_throttled_requests(1);
+        }
+        self.host.on_throttled();
+    }
+}
+
+/// Admission of a single request to a host. Dropping the permit without calling [`Self::finish`] releases it without
+/// affecting the host's concurrency limit.
+pub(crate) struct RequestPermit {
+    permit: Option<OwnedSemaphorePermit>,
+    request: Arc<RequestContext>,
+    start: Instant,
+    sample_latency: bool,
+}
+
+impl RequestPermit {
+    /// Runs `request` as the request that this permit admitted, so that attempts of it can be reported as throttled
+    /// with [`report_throttled_attempt`].
+    pub(crate) async fn scope<F: Future>(&self, request: F) -> F::Output {
+        CURRENT_REQUEST.scope(self.request.clone(), request).await
+    }
+
+    /// Feeds the result of the request back into the host's concurrency limit.
+    ///
+    /// Requests whose attempts were already reported as throttled with [`report_throttled_attempt`] neither grow
+    /// the limit nor decrease it again.
+    pub(crate) fn finish<T, E>(self, result: &Result<T, E>, is_throttled: impl FnOnce(&E) -> bool) {
+        if self.request.throttled.load(Ordering::Relaxed) {
+            return;
+        }
+        match result {
+            Ok(_) => self
+                .request
+                .host
+                .on_success(self.sample_latency.then(|| self.start.elapsed())),
+            Err(err) if is_throttled(err) => self.request.on_throttled(),
+            Err(_) => {}
+        }
+    }
+}
+
+impl Drop for RequestPermit {
+    fn drop(&mut self) {
+        if let Some(permit) = self.permit.take() {
+            self.request.host.release(permit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{report_throttled_attempt, RequestLimiter};
+    use crate::stats::IOStatsContext;
+
+    const THROTTLED: Result<(), bool> = Err(true);
+    const OK: Result<(), bool> = Ok(());
+
+    #[tokio::test]
+    async fn test_aimd_concurrency_limit() {
+        let limiter = RequestLimiter::new(8, None);
+        let io_stats = IOStatsContext::new("test_aimd_concurrency_limit");
+
+        let permit = limiter.acquire("bucket", Some(io_stats.clone())).await;
+        permit.finish(&THROTTLED, |throttled| *throttled);
+        assert_eq!(limiter.concurrency_limit("bucket"), 4);
+        assert_eq!(io_stats.load_throttled_requests(), 1);
+        // Hosts are limited independently.
+        assert_eq!(limiter.concurrency_limit("other-bucket"), 8);
+
+        // Throttles of requests that were in flight at the same time only decrease the limit once.
+        let permit = limiter.acquire("bucket", Some(io_stats.clone())).await;
+        permit.finish(&THROTTLED, |throttled| *throttled);
+        assert_eq!(limiter.concurrency_limit("bucket"), 4);
+        assert_eq!(io_stats.load_throttled_requests(), 2);
+
+        // Other errors don't affect the limit.
+        let permit = limiter.acquire("bucket", None).await;
+        permit.finish(&Err::<(), _>(false), |throttled| *throttled);
+        assert_eq!(limiter.concurrency_limit("bucket"), 4);
+
+        // The limit grows by one for every window of successful requests.
+        for _ in 0..4 {
+            limiter
+                .send("bucket", None, async { OK }, |_| false)
+                .await
+                .unwrap();
+        }
+        assert_eq!(limiter.concurrency_limit("bucket"), 5);
+
+        // Only `limit` requests can be in flight at once.
+        let permits =
+            futures::future::join_all((0..5).map(|_| limiter.acquire("bucket", None))).await;
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), limiter.acquire("bucket", None))
+                .await
+                .is_err()
+        );
+        drop(permits);
+        limiter.acquire("bucket", None).await;
+    }
+
+    #[tokio::test]
+    async fn test_report_throttled_attempt() {
+        let limiter = RequestLimiter::new(8, None);
+        let io_stats = IOStatsContext::new("test_report_throttled_attempt");
+
+        // Reporting a throttle outside of a request does nothing.
+        report_throttled_attempt();
+
+        // A request whose attempts are throttled and retried by an SDK backs off as soon as the first attempt is
+        // throttled, even though the request eventually succeeds.
+        limiter
+            .send(
+                "bucket",
+                Some(io_stats.clone()),
+                async {
+                    report_throttled_attempt();
+                    OK
+                },
+                |_| false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(limiter.concurrency_limit("bucket"), 4);
+        assert_eq!(io_stats.load_throttled_requests(), 1);
+
+        // A request that fails after its throttled attempts is only counted once.
+        let result = limiter
+            .send(
+                "other-bucket",
+                Some(io_stats.clone()),
+                async {
+                    report_throttled_attempt();
+                    THROTTLED
+                },
+                |throttled| *throttled,
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(limiter.concurrency_limit("other-bucket"), 4);
+        assert_eq!(io_stats.load_throttled_requests(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_requests_per_second_limit() {
+        let limiter = RequestLimiter::new(8, Some(100));
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire("bucket", None).await;
+        }
+        // The first request is sent immediately, and then one every 10ms.
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}
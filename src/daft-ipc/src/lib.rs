@@ -0,0 +1,72 @@
+#![feature(let_chains)]
+use common_error::DaftError;
+use snafu::Snafu;
+
+pub mod metadata;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod read;
+mod reader;
+pub mod write;
+
+pub use metadata::{read_ipc_metadata, read_ipc_schema, read_ipc_schema_bulk};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use read::IpcReadOptions;
+pub use write::write_ipc;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("{source}"))]
+    ArrowError { source: arrow2::error::Error },
+    #[snafu(display("File: {} is not a valid Arrow IPC file: {}", path, reason))]
+    InvalidIpcFile { path: String, reason: String },
+    #[snafu(display(
+        "Record batch {} is out of bounds for Arrow IPC file: {} with {} record batches",
+        index,
+        path,
+        num_batches
+    ))]
+    RecordBatchOutOfBounds {
+        path: String,
+        index: usize,
+        num_batches: usize,
+    },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<Error> for pyo3::PyErr {
+    fn from(value: Error) -> Self {
+        let daft_error: DaftError = value.into();
+        daft_error.into()
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_ipc))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_ipc_schema))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::write_ipc))?;
+    Ok(())
+}
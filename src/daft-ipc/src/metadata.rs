@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use arrow2::io::ipc::read::{read_file_metadata, FileMetadata};
+use common_error::DaftResult;
+use daft_core::schema::Schema;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use futures::{StreamExt, TryStreamExt};
+use snafu::ResultExt;
+
+use crate::{reader::RangesReader, ArrowSnafu, JoinSnafu};
+
+/// Magic bytes at the start and end of an Arrow IPC file.
+const ARROW_MAGIC: &[u8] = b"ARROW1";
+/// Size of the end of an Arrow IPC file, made up of the footer length and the magic bytes.
+const FOOTER_TAIL_SIZE: usize = 4 + ARROW_MAGIC.len();
+/// Number of bytes to fetch from the end of the file when reading the footer, which holds the schema and the
+/// locations of the record batches. Larger footers are fetched with a second request.
+const DEFAULT_FOOTER_READ_SIZE: usize = 64 * 1024;
+
+/// Reads the footer of the Arrow IPC file at `uri`, returning its metadata and a reader that holds the fetched bytes.
+pub(crate) async fn read_ipc_footer(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<(FileMetadata, RangesReader)> {
+    let size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    if size < ARROW_MAGIC.len() + FOOTER_TAIL_SIZE {
+        return Err(super::Error::InvalidIpcFile {
+            path: uri.to_string(),
+            reason: format!("file size of {size} bytes is too small"),
+        });
+    }
+
+    let mut reader = RangesReader::new(size);
+    let tail_start = size.saturating_sub(DEFAULT_FOOTER_READ_SIZE);
+    let tail =
+        crate::reader::fetch_range(uri, tail_start..size, &io_client, io_stats.clone()).await?;
+    let footer_len_bytes = &tail[tail.len() - FOOTER_TAIL_SIZE..tail.len() - ARROW_MAGIC.len()];
+    let footer_len = i32::from_le_bytes(footer_len_bytes.try_into().unwrap());
+    reader.insert(tail_start, tail);
+    if tail_start > 0 {
+        reader
+            .fetch(uri, 0..ARROW_MAGIC.len(), &io_client, io_stats.clone())
+            .await?;
+    }
+
+    // Fetch the rest of the footer if it didn't fit in the initial read.
+    let footer_start = usize::try_from(footer_len)
+        .ok()
+        .and_then(|footer_len| size.checked_sub(FOOTER_TAIL_SIZE + footer_len))
+        .ok_or_else(|| super::Error::InvalidIpcFile {
+            path: uri.to_string(),
+            reason: format!("footer length of {footer_len} bytes is out of bounds"),
+        })?;
+    if footer_start < tail_start {
+        reader
+            .fetch(uri, footer_start..tail_start, &io_client, io_stats)
+            .await?;
+    }
+
+    let metadata = read_file_metadata(&mut reader).context(ArrowSnafu)?;
+    Ok((metadata, reader))
+}
+
+/// Reads the metadata of the Arrow IPC file at `uri`, using ranged reads of its footer.
+pub async fn read_ipc_metadata(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<FileMetadata> {
+    let (metadata, _) = read_ipc_footer(uri, io_client, io_stats).await?;
+    Ok(metadata)
+}
+
+pub fn read_ipc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let runtime_handle = get_runtime(true)?;
+    let metadata = runtime_handle
+        .block_on_current_thread(async { read_ipc_metadata(uri, io_client, io_stats).await })?;
+    Schema::try_from(&metadata.schema)
+}
+
+pub fn read_ipc_schema_bulk(
+    uris: &[&str],
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Schema>> {
+    let runtime_handle = get_runtime(true)?;
+    let metadatas = runtime_handle.block_on_current_thread(async move {
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, io_client, io_stats) = (uri.to_string(), io_client.clone(), io_stats.clone());
+            tokio::task::spawn(async move { read_ipc_metadata(&uri, io_client, io_stats).await })
+        }));
+        task_stream
+            .buffered(num_parallel_tasks)
+            .try_collect::<Vec<_>>()
+            .await
+            .context(JoinSnafu)
+    })?;
+    metadatas
+        .into_iter()
+        .map(|metadata| Schema::try_from(&metadata?.schema))
+        .collect()
+}
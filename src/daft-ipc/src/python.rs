@@ -0,0 +1,98 @@
+pub mod pylib {
+    use std::sync::Arc;
+
+    use arrow2::io::ipc::write::Compression;
+    use common_error::DaftError;
+    use daft_core::python::schema::PySchema;
+    use daft_dsl::python::PyExpr;
+    use daft_file_reader::{read_file, ReadPushdowns};
+    use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
+    use daft_table::python::PyTable;
+    use pyo3::{pyfunction, PyResult, Python};
+
+    use crate::IpcReadOptions;
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyfunction]
+    pub fn read_ipc(
+        py: Python,
+        uri: &str,
+        columns: Option<Vec<&str>>,
+        num_rows: Option<usize>,
+        record_batches: Option<Vec<usize>>,
+        predicate: Option<PyExpr>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PyTable> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_ipc: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            Ok(read_file(
+                &IpcReadOptions { record_batches },
+                uri,
+                ReadPushdowns::new(columns.as_deref(), num_rows, predicate.map(|e| e.expr)),
+                io_client,
+                Some(io_stats),
+                multithreaded_io.unwrap_or(true),
+            )?
+            .into())
+        })
+    }
+
+    #[pyfunction]
+    pub fn read_ipc_schema(
+        py: Python,
+        uri: &str,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PySchema> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_ipc_schema: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let schema = crate::metadata::read_ipc_schema(uri, io_client, Some(io_stats))?;
+            Ok(Arc::new(schema).into())
+        })
+    }
+
+    #[pyfunction]
+    pub fn write_ipc(
+        py: Python,
+        tables: Vec<PyTable>,
+        uri: &str,
+        compression: Option<&str>,
+        io_config: Option<IOConfig>,
+    ) -> PyResult<()> {
+        let compression = match compression {
+            None => None,
+            Some(c) if c.eq_ignore_ascii_case("lz4") => Some(Compression::LZ4),
+            Some(c) if c.eq_ignore_ascii_case("zstd") => Some(Compression::ZSTD),
+            Some(c) => {
+                return Err(DaftError::ValueError(format!(
+                    "Unsupported Arrow IPC compression: {c}, expected one of: lz4, zstd"
+                ))
+                .into())
+            }
+        };
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("write_ipc: for uri {uri}"));
+
+            let io_client = get_io_client(true, io_config.unwrap_or_default().config.into())?;
+            let tables = tables.into_iter().map(|t| t.table).collect::<Vec<_>>();
+            Ok(crate::write::write_ipc(
+                &tables,
+                uri,
+                compression,
+                io_client,
+                Some(io_stats),
+            )?)
+        })
+    }
+}
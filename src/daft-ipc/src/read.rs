@@ -0,0 +1,346 @@
+use std::sync::Arc;
+
+use arrow2::io::ipc::read::{read_batch, read_file_dictionaries, Dictionaries, FileMetadata};
+use async_trait::async_trait;
+use common_error::DaftResult;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_file_reader::{FileFormatReader, ReadPushdowns};
+use daft_io::{IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{stream::BoxStream, StreamExt};
+use snafu::ResultExt;
+
+use crate::{
+    metadata::read_ipc_footer,
+    reader::{fetch_range, RangesReader},
+    ArrowSnafu, JoinSnafu,
+};
+
+/// Number of record batches of a file that are fetched and decoded concurrently.
+const NUM_RECORD_BATCHES_IN_FLIGHT: usize = 8;
+
+/// Options for reading Arrow IPC files, which are read as a table per record batch.
+#[derive(Clone, Debug, Default)]
+pub struct IpcReadOptions {
+    /// Indices of the record batches to read, in order, or `None` to read all of the record batches of the file.
+    pub record_batches: Option<Vec<usize>>,
+}
+
+#[async_trait]
+impl FileFormatReader for IpcReadOptions {
+    const FORMAT_NAME: &'static str = "Arrow IPC";
+
+    type Metadata = (FileMetadata, RangesReader);
+
+    async fn read_metadata(
+        &self,
+        uri: &str,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<(Self::Metadata, SchemaRef)> {
+        let (metadata, reader) = read_ipc_footer(uri, io_client, io_stats).await?;
+        let schema = Arc::new(Schema::try_from(&metadata.schema)?);
+        Ok(((metadata, reader), schema))
+    }
+
+    /// Fetches each record batch with a ranged read.
+    async fn decode(
+        &self,
+        uri: &str,
+        (metadata, mut reader): Self::Metadata,
+        read_schema: SchemaRef,
+        _pushdowns: &ReadPushdowns,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+        let num_batches = metadata.blocks.len();
+        let record_batches = self
+            .record_batches
+            .clone()
+            .unwrap_or_else(|| (0..num_batches).collect());
+        if let Some(&index) = record_batches.iter().find(|i| **i >= num_batches) {
+            return Err(super::Error::RecordBatchOutOfBounds {
+                path: uri.to_string(),
+                index,
+                num_batches,
+            }
+            .into());
+        }
+
+        // Dictionaries are shared by all record batches, so they're fetched and decoded up front.
+        for block in metadata.dictionaries.iter().flatten() {
+            let start = block.offset as usize;
+            let end = start + block.meta_data_length as usize + block.body_length as usize;
+            reader
+                .fetch(uri, start..end, &io_client, io_stats.clone())
+                .await?;
+        }
+        let dictionaries =
+            read_file_dictionaries(&mut reader, &metadata, &mut vec![]).context(ArrowSnafu)?;
+
+        // The columns of the read schema are in file order, as the arrow2 reader requires of the projection.
+        let projection: Arc<[usize]> = metadata
+            .schema
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| read_schema.fields.contains_key(&f.name))
+            .map(|(i, _)| i)
+            .collect();
+
+        let (uri, metadata, dictionaries) =
+            (uri.to_string(), Arc::new(metadata), Arc::new(dictionaries));
+        let stream = futures::stream::iter(record_batches)
+            .map(move |index| {
+                let (uri, io_client, io_stats, metadata, dictionaries, projection, read_schema) = (
+                    uri.clone(),
+                    io_client.clone(),
+                    io_stats.clone(),
+                    metadata.clone(),
+                    dictionaries.clone(),
+                    projection.clone(),
+                    read_schema.clone(),
+                );
+                async move {
+                    let block = metadata.blocks[index];
+                    let start = block.offset as usize;
+                    let end = start + block.meta_data_length as usize + block.body_length as usize;
+                    let bytes = fetch_range(&uri, start..end, &io_client, io_stats).await?;
+                    tokio::task::spawn_blocking(move || {
+                        let mut reader = RangesReader::new(metadata.size as usize);
+                        reader.insert(start, bytes);
+                        decode_record_batch(
+                            &mut reader,
+                            &dictionaries,
+                            &metadata,
+                            &projection,
+                            index,
+                            read_schema,
+                        )
+                    })
+                    .await
+                    .context(JoinSnafu)?
+                }
+            })
+            .buffered(NUM_RECORD_BATCHES_IN_FLIGHT);
+        Ok(stream.boxed())
+    }
+}
+
+fn decode_record_batch(
+    reader: &mut RangesReader,
+    dictionaries: &Dictionaries,
+    metadata: &FileMetadata,
+    projection: &[usize],
+    index: usize,
+    schema: SchemaRef,
+) -> DaftResult<Table> {
+    let chunk = read_batch(
+        reader,
+        dictionaries,
+        metadata,
+        Some(projection),
+        None,
+        index,
+        &mut vec![],
+        &mut vec![],
+    )
+    .context(ArrowSnafu)?;
+    let num_rows = chunk.len();
+    let columns = chunk
+        .into_arrays()
+        .into_iter()
+        .zip(schema.fields.values())
+        .map(|(array, field)| {
+            Series::try_from_field_and_arrow_array(
+                Arc::new(field.clone()),
+                cast_array_for_daft_if_needed(array),
+            )
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new_with_size(schema, columns, num_rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::io::ipc::write::Compression;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_file_reader::{read_file, read_files, ReadPushdowns};
+    use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+    use rstest::rstest;
+
+    use super::IpcReadOptions;
+    use crate::{read_ipc_schema, write_ipc};
+
+    fn make_table(start: i64, num_rows: i64) -> DaftResult<Table> {
+        let ids = (start..start + num_rows).collect::<Vec<_>>();
+        let names = ids.iter().map(|i| format!("name{i}")).collect::<Vec<_>>();
+        Table::from_nonempty_columns(vec![
+            Int64Array::from(("id", ids)).into_series(),
+            Utf8Array::from((
+                "name",
+                names
+                    .iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            ))
+            .into_series(),
+        ])
+    }
+
+    fn assert_tables_eq(left: &Table, right: &Table) {
+        assert_eq!(left.schema, right.schema);
+        assert_eq!(left.len(), right.len());
+        for i in 0..left.num_columns() {
+            assert_eq!(
+                left.get_column_by_index(i).unwrap(),
+                right.get_column_by_index(i).unwrap()
+            );
+        }
+    }
+
+    fn write_test_file(
+        dir: &tempfile::TempDir,
+        compression: Option<Compression>,
+    ) -> DaftResult<(String, Vec<Table>)> {
+        let uri = dir.path().join("test.arrow").to_string_lossy().to_string();
+        let tables = vec![make_table(0, 10)?, make_table(10, 5)?, make_table(15, 10)?];
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        write_ipc(&tables, &uri, compression, io_client, None)?;
+        Ok((uri, tables))
+    }
+
+    #[rstest]
+    fn test_ipc_roundtrip(
+        #[values(None, Some(Compression::LZ4), Some(Compression::ZSTD))] compression: Option<
+            Compression,
+        >,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let (uri, tables) = write_test_file(&dir, compression)?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let schema = read_ipc_schema(&uri, io_client.clone(), None)?;
+        assert_eq!(&schema, tables[0].schema.as_ref());
+
+        let table = read_file(
+            &IpcReadOptions::default(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_tables_eq(&table, &Table::concat(&tables)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ipc_write_larger_than_write_buffer() -> DaftResult<()> {
+        // The file is uploaded in several chunks while it's being serialized.
+        let dir = tempfile::tempdir()?;
+        let uri = dir.path().join("large.arrow").to_string_lossy().to_string();
+        let tables = vec![make_table(0, 500_000)?, make_table(500_000, 500_000)?];
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        write_ipc(&tables, &uri, None, io_client.clone(), None)?;
+        assert!(std::fs::metadata(dir.path().join("large.arrow"))?.len() > 8 * 1024 * 1024);
+
+        let table = read_file(
+            &IpcReadOptions::default(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_tables_eq(&table, &Table::concat(&tables)?);
+
+        // Tables with different schemas fail the write.
+        let mismatched = vec![make_table(0, 10)?, make_table(0, 10)?.get_columns(&["id"])?];
+        assert!(write_ipc(&mismatched, &uri, None, io_client, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ipc_read_pushdowns() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let (uri, tables) = write_test_file(&dir, None)?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        // Projection reorders the columns.
+        let table = read_file(
+            &IpcReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(Some(&["name", "id"]), None, None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["name", "id"]);
+        assert_eq!(table.len(), 25);
+
+        // Limit stops reading after the record batch that reaches it.
+        let table = read_file(
+            &IpcReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(None, Some(12), None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_tables_eq(&table, &Table::concat(&tables)?.head(12)?);
+
+        // Record batches select a subset of the file.
+        let table = read_file(
+            &IpcReadOptions {
+                record_batches: Some(vec![2, 0]),
+            },
+            &uri,
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_tables_eq(&table, &Table::concat(&[&tables[2], &tables[0]])?);
+
+        // Predicate columns don't have to be in the projection.
+        let table = read_file(
+            &IpcReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(Some(&["name"]), None, Some(col("id").gt_eq(lit(20)))),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["name"]);
+        assert_eq!(table.len(), 5);
+
+        let tables = read_files(
+            vec![
+                (
+                    uri.as_str(),
+                    IpcReadOptions {
+                        record_batches: Some(vec![1]),
+                    },
+                ),
+                (uri.as_str(), IpcReadOptions::default()),
+            ],
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            2,
+            true,
+        )?;
+        assert_eq!(
+            tables.iter().map(|t| t.len()).collect::<Vec<_>>(),
+            vec![5, 25]
+        );
+        Ok(())
+    }
+}
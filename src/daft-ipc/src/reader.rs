@@ -0,0 +1,131 @@
+use std::{
+    collections::BTreeMap,
+    io::{Read, Seek, SeekFrom},
+    ops::Range,
+};
+
+use bytes::Bytes;
+use daft_io::{IOClient, IOStatsRef};
+
+/// A reader over byte ranges of a file that have been fetched into memory.
+///
+/// The reader can seek to any offset of the file, so that it can be handed to the `arrow2` IPC readers in place of the
+/// full file, but it can only read bytes that lie within one of the fetched ranges.
+#[derive(Debug, Clone)]
+pub struct RangesReader {
+    ranges: BTreeMap<usize, Bytes>,
+    size: usize,
+    position: usize,
+}
+
+impl RangesReader {
+    pub fn new(size: usize) -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+            size,
+            position: 0,
+        }
+    }
+
+    pub fn insert(&mut self, start: usize, bytes: Bytes) {
+        self.ranges.insert(start, bytes);
+    }
+
+    /// Fetches the `range` of the file at `uri` and adds it to the reader.
+    pub async fn fetch(
+        &mut self,
+        uri: &str,
+        range: Range<usize>,
+        io_client: &IOClient,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let bytes = fetch_range(uri, range.clone(), io_client, io_stats).await?;
+        self.insert(range.start, bytes);
+        Ok(())
+    }
+
+    /// Returns the fetched bytes at `position`, up to the end of the range that contains it.
+    fn bytes_at(&self, position: usize) -> Option<&[u8]> {
+        let (start, bytes) = self.ranges.range(..=position).next_back()?;
+        bytes.get(position - start..).filter(|b| !b.is_empty())
+    }
+}
+
+impl Read for RangesReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+        let bytes = self.bytes_at(self.position).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!("Byte {} of the file was not fetched", self.position),
+            )
+        })?;
+        let len = bytes.len().min(buf.len());
+        buf[..len].copy_from_slice(&bytes[..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+impl Seek for RangesReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset as i64),
+            SeekFrom::End(offset) => (self.size as i64).checked_add(offset),
+            SeekFrom::Current(offset) => (self.position as i64).checked_add(offset),
+        };
+        match position {
+            Some(position) if position >= 0 => {
+                self.position = position as usize;
+                Ok(position as u64)
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+pub(crate) async fn fetch_range(
+    uri: &str,
+    range: Range<usize>,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<Bytes> {
+    Ok(io_client
+        .single_url_get(uri.to_string(), Some(range), io_stats)
+        .await?
+        .bytes()
+        .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Seek, SeekFrom};
+
+    use bytes::Bytes;
+
+    use super::RangesReader;
+
+    #[test]
+    fn test_ranges_reader_reads_fetched_ranges() {
+        let mut reader = RangesReader::new(10);
+        reader.insert(0, Bytes::from_static(b"ab"));
+        reader.insert(6, Bytes::from_static(b"ghij"));
+
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"ab");
+
+        reader.seek(SeekFrom::End(-3)).unwrap();
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, b"hij");
+
+        reader.seek(SeekFrom::Start(3)).unwrap();
+        assert!(reader.read_exact(&mut [0; 1]).is_err());
+    }
+}
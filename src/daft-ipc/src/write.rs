@@ -0,0 +1,167 @@
+use std::{io::Write, sync::Arc};
+
+use arrow2::{
+    chunk::Chunk,
+    io::ipc::write::{Compression, FileWriter, WriteOptions},
+};
+use bytes::Bytes;
+use common_error::{DaftError, DaftResult};
+use daft_core::{schema::Schema, utils::arrow::cast_array_from_daft_if_needed};
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::StreamExt;
+use snafu::ResultExt;
+use tokio::sync::mpsc;
+
+use crate::{ArrowSnafu, JoinSnafu};
+
+/// Size of the buffers that the file is serialized into before they're handed to the upload.
+const WRITE_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+/// Number of serialized buffers that may be waiting to be uploaded before serialization blocks.
+const MAX_BUFFERED_CHUNKS: usize = 2;
+
+/// A [`Write`] that sends what is written to an upload in chunks of [`WRITE_BUFFER_SIZE`].
+struct UploadWriter {
+    buffer: Vec<u8>,
+    sender: mpsc::Sender<daft_io::Result<Bytes>>,
+}
+
+impl UploadWriter {
+    fn send_buffer(&mut self) -> std::io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = Bytes::from(std::mem::take(&mut self.buffer));
+        self.sender
+            .blocking_send(Ok(chunk))
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::BrokenPipe, "upload has stopped"))
+    }
+}
+
+impl Write for UploadWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= WRITE_BUFFER_SIZE {
+            self.send_buffer()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.send_buffer()
+    }
+}
+
+/// Writes `tables` to an Arrow IPC file at `uri`, with one record batch per table.
+///
+/// All tables must have the same schema. The file is streamed to `uri` while it's being serialized, using a multipart
+/// upload where the store supports it, so that it never needs to be fully buffered in memory.
+pub fn write_ipc(
+    tables: &[Table],
+    uri: &str,
+    compression: Option<Compression>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    let schema = match tables.first() {
+        Some(table) => table.schema.clone(),
+        None => {
+            return Err(DaftError::ValueError(
+                "Need at least 1 Table to write an Arrow IPC file".to_string(),
+            ))
+        }
+    };
+    if let Some(field) = schema.fields.values().find(|f| f.dtype.is_python()) {
+        return Err(DaftError::TypeError(format!(
+            "Cannot write column {} of type {} to an Arrow IPC file",
+            field.name, field.dtype
+        )));
+    }
+
+    let runtime_handle = get_runtime(true)?;
+    let (sender, mut receiver) = mpsc::channel(MAX_BUFFERED_CHUNKS);
+    let owned_uri = uri.to_string();
+    let upload = runtime_handle.spawn(async move {
+        let data = futures::stream::poll_fn(move |cx| receiver.poll_recv(cx)).boxed();
+        io_client
+            .single_url_put_stream(&owned_uri, data, io_stats)
+            .await
+    });
+
+    let sink = UploadWriter {
+        buffer: Vec::with_capacity(WRITE_BUFFER_SIZE),
+        sender: sender.clone(),
+    };
+    let write_result = serialize_ipc(tables, &schema, compression, sink);
+    if let Err(err) = &write_result {
+        // Fail the upload rather than letting it finish with a truncated file.
+        let _ = sender.blocking_send(Err(daft_io::Error::Unhandled {
+            path: uri.to_string(),
+            msg: format!("Failed to serialize Arrow IPC file: {err}"),
+        }));
+    }
+    drop(sender);
+    let upload_result = runtime_handle
+        .block_on_current_thread(upload)
+        .context(JoinSnafu {})?;
+    // An error from the upload explains a failure to send the serialized data to it, so it takes precedence.
+    upload_result?;
+    write_result
+}
+
+/// Serializes `tables` as an Arrow IPC file into `sink`.
+fn serialize_ipc<W: Write>(
+    tables: &[Table],
+    schema: &Schema,
+    compression: Option<Compression>,
+    sink: W,
+) -> DaftResult<()> {
+    let mut sink = Some(sink);
+    let mut writer = None;
+    for table in tables {
+        if table.schema.as_ref() != schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Writing an Arrow IPC file requires all schemas to match, {} vs {}",
+                schema, table.schema
+            )));
+        }
+        let arrays = (0..table.num_columns())
+            .map(|i| {
+                let series = table.get_column_by_index(i)?;
+                Ok(cast_array_from_daft_if_needed(series.to_arrow()))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        if writer.is_none() {
+            // The schema of the file is taken from the arrays, which may differ from the Arrow schema of the Daft schema
+            // after casting.
+            let arrow_schema = arrow2::datatypes::Schema::from(
+                schema
+                    .fields
+                    .values()
+                    .zip(arrays.iter())
+                    .map(|(f, array)| {
+                        arrow2::datatypes::Field::new(&f.name, array.data_type().clone(), true)
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            let sink = sink
+                .take()
+                .expect("sink should only be taken for the first table");
+            writer = Some(
+                FileWriter::try_new(sink, arrow_schema, None, WriteOptions { compression })
+                    .context(ArrowSnafu)?,
+            );
+        }
+        let writer = writer
+            .as_mut()
+            .expect("writer should be created for the first table");
+        writer
+            .write(&Chunk::try_new(arrays).context(ArrowSnafu)?, None)
+            .context(ArrowSnafu)?;
+    }
+    let mut writer = writer.expect("writer should be created for the first table");
+    writer.finish().context(ArrowSnafu)?;
+    writer.into_inner().flush()?;
+    Ok(())
+}
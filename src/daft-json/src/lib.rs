@@ -16,11 +16,11 @@ pub mod python;
 pub mod read;
 pub mod schema;
 
-// pub use metadata::read_json_schema_bulk;
 pub use options::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 pub use read::{read_json, read_json_bulk};
+pub use schema::read_json_schema_bulk;
 
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -37,7 +37,30 @@ pub fn read_json_local(
     // We are trusting that the file is not modified or accessed by other systems while we are reading it.
     let mmap = unsafe { memmap2::Mmap::map(&file) }.context(StdIOSnafu)?;
 
-    let bytes = &mmap[..];
+    let (bytes, convert_options) = match read_options.as_ref().and_then(|opts| opts.byte_range) {
+        Some((start, end)) => {
+            // Infer the schema from the start of the file rather than from the range, so that all ranges of the file
+            // are read with the same schema.
+            let convert_options = match convert_options {
+                Some(JsonConvertOptions {
+                    schema: Some(_), ..
+                }) => convert_options,
+                _ => {
+                    let schema = Arc::new(Schema::try_from(&infer_schema(&mmap, None, None)?)?);
+                    Some(
+                        convert_options
+                            .unwrap_or_default()
+                            .with_schema(Some(schema)),
+                    )
+                }
+            };
+            (
+                &mmap[next_record_start(&mmap, start)..next_record_start(&mmap, end)],
+                convert_options,
+            )
+        }
+        None => (&mmap[..], convert_options),
+    };
     let reader = JsonReader::try_new(
         bytes,
         convert_options,
@@ -424,6 +447,16 @@ fn parse_raw_value<'a>(
     })
 }
 
+/// Returns the offset of the first record that begins at or after `pos`, i.e. just past the first newline at or after
+/// `pos - 1`, or the length of `bytes` if no record begins at or after `pos`.
+fn next_record_start(bytes: &[u8], pos: usize) -> usize {
+    if pos == 0 {
+        return 0;
+    }
+    let pos = pos.min(bytes.len());
+    memchr::memchr(NEWLINE, &bytes[pos - 1..]).map_or(bytes.len(), |i| pos + i)
+}
+
 fn next_line_position(input: &[u8]) -> Option<usize> {
     let pos = memchr::memchr(NEWLINE, input)?;
     if pos == 0 {
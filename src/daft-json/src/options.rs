@@ -165,6 +165,9 @@ impl_bincode_py_state_serialization!(JsonParseOptions);
 pub struct JsonReadOptions {
     pub buffer_size: Option<usize>,
     pub chunk_size: Option<usize>,
+    /// Byte range `(start, end)` of the file to read. Only the records that begin within the range are read, so
+    /// that a file can be split into byte ranges that are read independently.
+    pub byte_range: Option<(usize, usize)>,
 }
 
 impl JsonReadOptions {
@@ -172,21 +175,23 @@ impl JsonReadOptions {
         Self {
             buffer_size,
             chunk_size,
+            byte_range: None,
         }
     }
 
     pub fn with_buffer_size(self, buffer_size: Option<usize>) -> Self {
         Self {
             buffer_size,
-            chunk_size: self.chunk_size,
+            ..self
         }
     }
 
     pub fn with_chunk_size(self, chunk_size: Option<usize>) -> Self {
-        Self {
-            buffer_size: self.buffer_size,
-            chunk_size,
-        }
+        Self { chunk_size, ..self }
+    }
+
+    pub fn with_byte_range(self, byte_range: Option<(usize, usize)>) -> Self {
+        Self { byte_range, ..self }
     }
 }
 
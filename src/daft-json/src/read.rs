@@ -13,7 +13,7 @@ use snafu::{
 };
 use tokio::{
     fs::File,
-    io::{AsyncBufRead, AsyncBufReadExt, BufReader},
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncSeekExt, BufReader, SeekFrom},
     task::JoinHandle,
 };
 use tokio_util::io::StreamReader;
@@ -399,12 +399,31 @@ async fn read_json_single_into_stream(
         .to_arrow()?,
     };
 
+    let byte_range = read_options.as_ref().and_then(|opts| opts.byte_range);
+    if byte_range.is_some() && CompressionCodec::from_uri(uri).is_some() {
+        return Err(DaftError::ValueError(format!(
+            "Cannot read a byte range of compressed JSON file: {uri}"
+        )));
+    }
+    // Start reading from the byte before the range, so that a record which begins exactly at the start of the range
+    // is found after the preceding newline.
+    let read_start = byte_range.map_or(0, |(start, _)| start.saturating_sub(1));
+    let get_range = if read_start > 0 {
+        let size = io_client
+            .single_url_get_size(uri.to_string(), io_stats.clone())
+            .await?;
+        Some(read_start..size)
+    } else {
+        None
+    };
     let (reader, buffer_size, chunk_size): (Box<dyn AsyncBufRead + Unpin + Send>, usize, usize) =
         match io_client
-            .single_url_get(uri.to_string(), None, io_stats)
+            .single_url_get(uri.to_string(), get_range, io_stats)
             .await?
         {
             GetResult::File(file) => {
+                let mut file = File::open(file.path).await?;
+                file.seek(SeekFrom::Start(read_start as u64)).await?;
                 // Use user-provided buffer size, falling back to 8 * the user-provided chunk size if that exists, otherwise falling back to 512 KiB as the default.
                 let buffer_size = read_options
                     .as_ref()
@@ -414,10 +433,7 @@ async fn read_json_single_into_stream(
                     })
                     .unwrap_or(256 * 1024);
                 (
-                    Box::new(BufReader::with_capacity(
-                        buffer_size,
-                        File::open(file.path).await?,
-                    )),
+                    Box::new(BufReader::with_capacity(buffer_size, file)),
                     buffer_size,
                     read_options
                         .as_ref()
@@ -455,7 +471,19 @@ async fn read_json_single_into_stream(
         )),
         None => reader,
     };
-    let read_stream = read_into_line_chunk_stream(reader, convert_options.limit, chunk_size);
+    // Ranges after the start of the file begin after the first newline in the range, since JSON records can't contain
+    // unescaped newlines.
+    let (reader, max_bytes) = match byte_range {
+        Some((start, end)) if start > 0 => {
+            let mut reader = reader;
+            let skipped = reader.read_until(b'\n', &mut Vec::new()).await?;
+            (reader, Some(end.saturating_sub(read_start + skipped)))
+        }
+        Some((_, end)) => (reader, Some(end)),
+        None => (reader, None),
+    };
+    let read_stream =
+        read_into_line_chunk_stream(reader, convert_options.limit, max_bytes, chunk_size);
     let (projected_schema, schema_is_projection) = match convert_options.include_columns {
         Some(projection) => {
             let mut field_map = schema
@@ -484,14 +512,33 @@ async fn read_json_single_into_stream(
 fn read_into_line_chunk_stream<R>(
     reader: R,
     num_rows: Option<usize>,
+    max_bytes: Option<usize>,
     chunk_size: usize,
 ) -> impl LineChunkStream + Send
 where
     R: AsyncBufRead + Unpin + Send + 'static,
 {
     let num_rows = num_rows.unwrap_or(usize::MAX);
-    // Stream of unparsed json string record chunks.
-    let line_stream = tokio_stream::wrappers::LinesStream::new(reader.lines());
+    let max_bytes = max_bytes.unwrap_or(usize::MAX);
+    // Stream of unparsed json string records, stopping at the first record that begins at or after byte `max_bytes`.
+    let line_stream =
+        futures::stream::try_unfold((reader, 0), move |(mut reader, pos)| async move {
+            if pos >= max_bytes {
+                return Ok(None);
+            }
+            let mut line = String::new();
+            let bytes_read = reader.read_line(&mut line).await?;
+            if bytes_read == 0 {
+                return Ok(None);
+            }
+            if line.ends_with('\n') {
+                line.pop();
+                if line.ends_with('\r') {
+                    line.pop();
+                }
+            }
+            Ok(Some((line, (reader, pos + bytes_read))))
+        });
     line_stream
         .take(num_rows)
         .try_chunks(chunk_size)
@@ -569,8 +616,9 @@ mod tests {
         utils::arrow::{cast_array_for_daft_if_needed, cast_array_from_daft_if_needed},
     };
 
-    use daft_io::{IOClient, IOConfig};
+    use daft_io::{get_runtime, IOClient, IOConfig};
     use daft_table::Table;
+    use futures::TryStreamExt;
     use indexmap::IndexMap;
     use rstest::rstest;
 
@@ -580,7 +628,7 @@ mod tests {
     };
     use crate::{JsonConvertOptions, JsonReadOptions};
 
-    use super::read_json;
+    use super::{read_json, stream_json, tables_concat};
 
     fn check_equal_local_arrow2(
         path: &str,
@@ -920,6 +968,68 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    fn test_json_read_local_byte_ranges(
+        #[values(1, 17, 64, 1000)] range_size: usize,
+        #[values(false, true)] stream: bool,
+    ) -> DaftResult<()> {
+        let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"),);
+        let file_size = std::fs::metadata(&file)?.len() as usize;
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let tables = (0..file_size)
+            .step_by(range_size)
+            .map(|start| {
+                let read_options = Some(
+                    JsonReadOptions::default()
+                        .with_byte_range(Some((start, (start + range_size).min(file_size)))),
+                );
+                if stream {
+                    get_runtime(true)?.block_on_current_thread(async {
+                        stream_json(
+                            file.clone(),
+                            None,
+                            None,
+                            read_options,
+                            io_client.clone(),
+                            None,
+                            None,
+                        )
+                        .await?
+                        .try_collect::<Vec<_>>()
+                        .await
+                    })
+                } else {
+                    Ok(vec![read_json(
+                        file.as_ref(),
+                        None,
+                        None,
+                        read_options,
+                        io_client.clone(),
+                        None,
+                        true,
+                        None,
+                    )?])
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let table = tables_concat(
+            tables
+                .into_iter()
+                .flatten()
+                .filter(|t| !t.is_empty())
+                .collect(),
+        )?;
+        assert_eq!(table.len(), 20);
+        check_equal_local_arrow2(file.as_ref(), &table, None, None);
+
+        Ok(())
+    }
+
     #[test]
     fn test_json_read_local_throttled_streaming() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"),);
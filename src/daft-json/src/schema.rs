@@ -69,7 +69,7 @@ pub fn read_json_schema(
     })
 }
 
-pub async fn read_json_schema_bulk(
+pub fn read_json_schema_bulk(
     uris: &[&str],
     parse_options: Option<JsonParseOptions>,
     max_bytes: Option<usize>,
@@ -1,14 +1,24 @@
 use common_error::DaftResult;
+use daft_avro::AvroReadOptions;
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+use daft_dsl::optimization::get_required_columns;
+use daft_file_reader::{stream_file, ReadPushdowns};
 use daft_io::IOStatsRef;
+use daft_ipc::IpcReadOptions;
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 use daft_micropartition::MicroPartition;
+use daft_orc::OrcReadOptions;
 use daft_parquet::read::ParquetSchemaInferenceOptions;
 use daft_scan::{
-    file_format::{FileFormatConfig, ParquetSourceConfig},
+    file_format::{
+        FileFormatConfig, FixedWidthSourceConfig, ParquetSourceConfig, TextSourceConfig,
+        XlsxSourceConfig,
+    },
     storage_config::StorageConfig,
     ChunkSpec, ScanTask,
 };
+use daft_text::{FixedWidthReadOptions, TextReadOptions};
+use daft_xlsx::XlsxReadOptions;
 use futures::{Stream, StreamExt};
 use std::sync::Arc;
 use tokio_stream::wrappers::ReceiverStream;
@@ -126,6 +136,36 @@ async fn stream_scan_task(
         ),
     };
 
+    // Files of a ScanTask with a unioned schema may be missing columns that the filters reference, so the filters
+    // are applied after the tables are cast to the schema of the ScanTask instead of being pushed into the readers.
+    let post_read_filters = scan_task
+        .pushdowns
+        .filters
+        .clone()
+        .filter(|_| scan_task.union_by_name);
+    let (read_filters, read_limit) = match post_read_filters {
+        Some(_) => (None, None),
+        None => (
+            scan_task.pushdowns.filters.clone(),
+            scan_task.pushdowns.limit,
+        ),
+    };
+    let post_read_filter_columns = post_read_filters
+        .as_ref()
+        .map(get_required_columns)
+        .unwrap_or_default();
+    let file_column_names = file_column_names.map(|mut columns| {
+        for column in post_read_filter_columns.iter() {
+            if !columns.contains(&column.as_str()) {
+                columns.push(column.as_str());
+            }
+        }
+        columns
+    });
+    // Files with a unioned schema are read with their own schemas, since CSV and JSON readers require the provided
+    // schema and columns to be present in the file.
+    let read_by_name = scan_task.union_by_name;
+
     if scan_task.sources.len() != 1 {
         return Err(common_error::DaftError::TypeError(
             "Streaming reads only supported for single source ScanTasks".to_string(),
@@ -133,6 +173,7 @@ async fn stream_scan_task(
     }
     let source = scan_task.sources.first().unwrap();
     let url = source.get_path();
+    let chunk_spec = source.get_chunk_spec();
     let table_stream = match scan_task.storage_config.as_ref() {
         StorageConfig::Native(native_storage_config) => {
             let io_config = Arc::new(
@@ -157,9 +198,9 @@ async fn stream_scan_task(
                     let inference_options =
                         ParquetSchemaInferenceOptions::new(Some(*coerce_int96_timestamp_unit));
 
-                    if source.get_iceberg_delete_files().is_some() {
+                    if source.has_deletes() {
                         return Err(common_error::DaftError::TypeError(
-                            "Streaming reads not supported for Iceberg delete files".to_string(),
+                            "Streaming reads not supported for Iceberg delete files or Delta Lake deletion vectors".to_string(),
                         ));
                     }
 
@@ -177,9 +218,9 @@ async fn stream_scan_task(
                         url,
                         file_column_names.as_deref(),
                         None,
-                        scan_task.pushdowns.limit,
+                        read_limit,
                         row_groups,
-                        scan_task.pushdowns.filters.clone(),
+                        read_filters,
                         io_client.clone(),
                         io_stats,
                         &inference_options,
@@ -194,7 +235,7 @@ async fn stream_scan_task(
                 // Native CSV Reads
                 // ****************
                 FileFormatConfig::Csv(cfg) => {
-                    let schema_of_file = scan_task.schema.clone();
+                    let schema_of_file = scan_task.file_schema();
                     let col_names = if !cfg.has_headers {
                         Some(
                             schema_of_file
@@ -206,16 +247,18 @@ async fn stream_scan_task(
                     } else {
                         None
                     };
+                    let read_by_name = read_by_name && cfg.has_headers;
                     let convert_options = CsvConvertOptions::new_internal(
-                        scan_task.pushdowns.limit,
+                        read_limit,
                         file_column_names
                             .as_ref()
+                            .filter(|_| !read_by_name)
                             .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
                         col_names
                             .as_ref()
                             .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
-                        Some(schema_of_file),
-                        scan_task.pushdowns.filters.clone(),
+                        Some(schema_of_file).filter(|_| !read_by_name),
+                        read_filters,
                     );
                     let parse_options = CsvParseOptions::new_with_defaults(
                         cfg.has_headers,
@@ -226,8 +269,10 @@ async fn stream_scan_task(
                         cfg.escape_char,
                         cfg.comment,
                     )?;
+                    let byte_range = chunk_spec.and_then(ChunkSpec::byte_range);
                     let read_options =
-                        CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
+                        CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size)
+                            .with_byte_range(byte_range);
                     daft_csv::stream_csv(
                         url.to_string(),
                         Some(convert_options),
@@ -245,19 +290,22 @@ async fn stream_scan_task(
                 // Native JSON Reads
                 // ****************
                 FileFormatConfig::Json(cfg) => {
-                    let schema_of_file = scan_task.schema.clone();
+                    let schema_of_file = scan_task.file_schema();
                     let convert_options = JsonConvertOptions::new_internal(
-                        scan_task.pushdowns.limit,
+                        read_limit,
                         file_column_names
                             .as_ref()
+                            .filter(|_| !read_by_name)
                             .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
-                        Some(schema_of_file),
-                        scan_task.pushdowns.filters.clone(),
+                        Some(schema_of_file).filter(|_| !read_by_name),
+                        read_filters,
                     );
                     // let
                     let parse_options = JsonParseOptions::new_internal();
+                    let byte_range = chunk_spec.and_then(ChunkSpec::byte_range);
                     let read_options =
-                        JsonReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
+                        JsonReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size)
+                            .with_byte_range(byte_range);
 
                     daft_json::read::stream_json(
                         url.to_string(),
@@ -271,6 +319,123 @@ async fn stream_scan_task(
                     )
                     .await?
                 }
+
+                // **********************
+                // Native Arrow IPC Reads
+                // **********************
+                FileFormatConfig::Ipc(_) => {
+                    let options = IpcReadOptions {
+                        record_batches: chunk_spec
+                            .and_then(ChunkSpec::record_batches)
+                            .map(<[_]>::to_vec),
+                    };
+                    let pushdowns = ReadPushdowns::new(
+                        file_column_names.as_deref().filter(|_| !read_by_name),
+                        read_limit,
+                        read_filters,
+                    );
+                    stream_file(&options, url, pushdowns, io_client, io_stats).await?
+                }
+
+                // *****************
+                // Native Avro Reads
+                // *****************
+                FileFormatConfig::Avro(_) => {
+                    let options = AvroReadOptions {
+                        byte_range: chunk_spec.and_then(ChunkSpec::byte_range),
+                    };
+                    let pushdowns = ReadPushdowns::new(
+                        file_column_names.as_deref().filter(|_| !read_by_name),
+                        read_limit,
+                        read_filters,
+                    );
+                    stream_file(&options, url, pushdowns, io_client, io_stats).await?
+                }
+
+                // ****************
+                // Native ORC Reads
+                // ****************
+                FileFormatConfig::Orc(_) => {
+                    let options = OrcReadOptions {
+                        stripes: chunk_spec.and_then(ChunkSpec::stripes).map(<[_]>::to_vec),
+                    };
+                    let pushdowns = ReadPushdowns::new(
+                        file_column_names.as_deref().filter(|_| !read_by_name),
+                        read_limit,
+                        read_filters,
+                    );
+                    stream_file(&options, url, pushdowns, io_client, io_stats).await?
+                }
+
+                // *****************
+                // Native XLSX Reads
+                // *****************
+                FileFormatConfig::Xlsx(XlsxSourceConfig {
+                    sheet_name,
+                    sheet_index,
+                    header_row,
+                }) => {
+                    // Columns can only be matched by name if the sheets have header rows.
+                    let read_by_name = read_by_name && header_row.is_some();
+                    let options = XlsxReadOptions {
+                        sheet_name: sheet_name.clone(),
+                        sheet_index: *sheet_index,
+                        header_row: *header_row,
+                        schema: Some(scan_task.file_schema()).filter(|_| !read_by_name),
+                    };
+                    let pushdowns = ReadPushdowns::new(
+                        file_column_names.as_deref().filter(|_| !read_by_name),
+                        read_limit,
+                        read_filters,
+                    );
+                    stream_file(&options, url, pushdowns, io_client, io_stats).await?
+                }
+
+                // *****************
+                // Native Text Reads
+                // *****************
+                FileFormatConfig::Text(TextSourceConfig {
+                    skip_blank_lines,
+                    path_column,
+                }) => {
+                    let options = TextReadOptions {
+                        skip_blank_lines: *skip_blank_lines,
+                        path_column: path_column.clone(),
+                        byte_range: chunk_spec.and_then(ChunkSpec::byte_range),
+                    };
+                    let pushdowns =
+                        ReadPushdowns::new(file_column_names.as_deref(), read_limit, read_filters);
+                    stream_file(&options, url, pushdowns, io_client, io_stats).await?
+                }
+
+                // *****************************
+                // Native Fixed-Width Text Reads
+                // *****************************
+                FileFormatConfig::FixedWidth(FixedWidthSourceConfig { columns, skip_rows }) => {
+                    let options = FixedWidthReadOptions {
+                        columns: columns.clone(),
+                        skip_rows: *skip_rows,
+                        byte_range: chunk_spec.and_then(ChunkSpec::byte_range),
+                    };
+                    let pushdowns =
+                        ReadPushdowns::new(file_column_names.as_deref(), read_limit, read_filters);
+                    stream_file(&options, url, pushdowns, io_client, io_stats).await?
+                }
+
+                // ************************
+                // Native Whole Files Reads
+                // ************************
+                FileFormatConfig::Files(_) => {
+                    daft_text::stream_files(
+                        vec![url.to_string()],
+                        vec![source.get_size_bytes()],
+                        ReadPushdowns::new(file_column_names.as_deref(), read_limit, read_filters),
+                        io_client,
+                        io_stats,
+                        1,
+                    )
+                    .await?
+                }
                 #[cfg(feature = "python")]
                 FileFormatConfig::Database(_) => {
                     return Err(common_error::DaftError::TypeError(
@@ -295,14 +460,17 @@ async fn stream_scan_task(
 
     Ok(table_stream.map(move |table| {
         let table = table?;
-        let casted_table = table.cast_to_schema_with_fill(
-            scan_task.materialized_schema().as_ref(),
-            scan_task
-                .partition_spec()
-                .as_ref()
-                .map(|pspec| pspec.to_fill_map())
-                .as_ref(),
-        )?;
+        let fill_map = scan_task.partition_spec().map(|pspec| pspec.to_fill_map());
+        let casted_table = match &post_read_filters {
+            None => table.cast_to_schema_with_fill(
+                scan_task.materialized_schema().as_ref(),
+                fill_map.as_ref(),
+            )?,
+            Some(filters) => table
+                .cast_to_schema_with_fill(scan_task.schema.as_ref(), fill_map.as_ref())?
+                .filter(&[filters.clone()])?
+                .cast_to_schema(scan_task.materialized_schema().as_ref())?,
+        };
         let mp = Arc::new(MicroPartition::new_loaded(
             scan_task.materialized_schema().clone(),
             Arc::new(vec![casted_table]),
@@ -5,17 +5,26 @@ use std::{ops::Deref, sync::Mutex};
 
 use arrow2::io::parquet::read::schema::infer_schema_with_options;
 use common_error::DaftResult;
+use daft_avro::AvroReadOptions;
 use daft_core::prelude::*;
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
-use daft_dsl::ExprRef;
+use daft_dsl::{col, optimization::get_required_columns, ExprRef};
+use daft_file_reader::{FileFormatReader, ReadPushdowns};
+use daft_ipc::IpcReadOptions;
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
+use daft_orc::OrcReadOptions;
 use daft_parquet::read::{
     read_parquet_bulk, read_parquet_metadata_bulk, ParquetSchemaInferenceOptions,
 };
-use daft_scan::file_format::{CsvSourceConfig, FileFormatConfig, ParquetSourceConfig};
+use daft_scan::file_format::{
+    CsvSourceConfig, FileFormatConfig, FixedWidthSourceConfig, ParquetSourceConfig,
+    TextSourceConfig, XlsxSourceConfig,
+};
 use daft_scan::storage_config::{NativeStorageConfig, StorageConfig};
-use daft_scan::{ChunkSpec, DataSource, Pushdowns, ScanTask};
+use daft_scan::{read_deletion_vector, ChunkSpec, DataSource, Pushdowns, ScanTask};
 use daft_table::Table;
+use daft_text::{FixedWidthReadOptions, TextReadOptions};
+use daft_xlsx::XlsxReadOptions;
 
 use parquet2::metadata::FileMetaData;
 use snafu::ResultExt;
@@ -104,6 +113,36 @@ fn materialize_scan_task(
     let file_column_names =
         _get_file_column_names(pushdown_columns.as_deref(), scan_task.partition_spec());
 
+    // Files of a ScanTask with a unioned schema may be missing columns that the filters reference, so the filters
+    // are applied after the tables are cast to the schema of the ScanTask instead of being pushed into the readers.
+    let post_read_filters = scan_task
+        .pushdowns
+        .filters
+        .clone()
+        .filter(|_| scan_task.union_by_name);
+    let (read_filters, read_limit) = match post_read_filters {
+        Some(_) => (None, None),
+        None => (
+            scan_task.pushdowns.filters.clone(),
+            scan_task.pushdowns.limit,
+        ),
+    };
+    let post_read_filter_columns = post_read_filters
+        .as_ref()
+        .map(get_required_columns)
+        .unwrap_or_default();
+    let file_column_names = file_column_names.map(|mut columns| {
+        for column in post_read_filter_columns.iter() {
+            if !columns.contains(&column.as_str()) {
+                columns.push(column.as_str());
+            }
+        }
+        columns
+    });
+    // Files with a unioned schema are read with their own schemas, since CSV and JSON readers require the provided
+    // schema and columns to be present in the file.
+    let read_by_name = scan_task.union_by_name;
+
     let urls = scan_task.sources.iter().map(|s| s.get_path());
 
     let mut table_values = match scan_task.storage_config.as_ref() {
@@ -147,7 +186,7 @@ fn materialize_scan_task(
                         .into_iter()
                         .collect::<Vec<_>>();
 
-                    let delete_map = _read_delete_files(
+                    let mut delete_map = _read_delete_files(
                         iceberg_delete_files.as_slice(),
                         urls.as_slice(),
                         io_client.clone(),
@@ -157,6 +196,50 @@ fn materialize_scan_task(
                         &inference_options,
                     )
                     .context(DaftCoreComputeSnafu)?;
+                    // Rows deleted by Delta Lake deletion vectors are removed like those of positional delete files.
+                    for source in &scan_task.sources {
+                        if let Some(deletion_vector) = source.get_delta_deletion_vector() {
+                            let positions = daft_io::get_runtime(multithreaded_io)
+                                .and_then(|runtime| {
+                                    runtime.block_on_current_thread(read_deletion_vector(
+                                        deletion_vector,
+                                        io_client.clone(),
+                                        io_stats.clone(),
+                                    ))
+                                })
+                                .context(DaftCoreComputeSnafu)?;
+                            delete_map
+                                .entry(source.get_path().to_string())
+                                .or_default()
+                                .extend(positions);
+                        }
+                    }
+
+                    // Rows deleted by equality delete files are removed after the read, which requires the
+                    // equality columns and prevents the limit from being pushed into the reader.
+                    let has_equality_deletes = scan_task.sources.iter().any(|s| {
+                        s.get_iceberg_equality_delete_files()
+                            .is_some_and(|f| !f.is_empty())
+                    });
+                    let (file_column_names, read_limit) = if has_equality_deletes {
+                        let file_column_names = file_column_names.clone().map(|mut columns| {
+                            for column in scan_task
+                                .sources
+                                .iter()
+                                .flat_map(|s| s.get_iceberg_equality_delete_files())
+                                .flatten()
+                                .flat_map(|f| f.equality_columns.iter())
+                            {
+                                if !columns.contains(&column.as_str()) {
+                                    columns.push(column.as_str());
+                                }
+                            }
+                            columns
+                        });
+                        (file_column_names, None)
+                    } else {
+                        (file_column_names.clone(), read_limit)
+                    };
 
                     let row_groups = parquet_sources_to_row_groups(scan_task.sources.as_slice());
                     let metadatas = scan_task
@@ -164,15 +247,15 @@ fn materialize_scan_task(
                         .iter()
                         .map(|s| s.get_parquet_metadata().cloned())
                         .collect::<Option<Vec<_>>>();
-                    daft_parquet::read::read_parquet_bulk(
+                    let tables = daft_parquet::read::read_parquet_bulk(
                         urls.as_slice(),
                         file_column_names.as_deref(),
                         None,
-                        scan_task.pushdowns.limit,
+                        read_limit,
                         row_groups,
-                        scan_task.pushdowns.filters.clone(),
+                        read_filters,
                         io_client.clone(),
-                        io_stats,
+                        io_stats.clone(),
                         num_parallel_tasks,
                         multithreaded_io,
                         &inference_options,
@@ -181,14 +264,29 @@ fn materialize_scan_task(
                         Some(delete_map),
                         *chunk_size,
                     )
-                    .context(DaftCoreComputeSnafu)?
+                    .context(DaftCoreComputeSnafu)?;
+                    if has_equality_deletes {
+                        _apply_equality_deletes(
+                            tables,
+                            scan_task.sources.as_slice(),
+                            io_client.clone(),
+                            io_stats,
+                            num_parallel_tasks,
+                            multithreaded_io,
+                            &inference_options,
+                            field_id_mapping.clone(),
+                        )
+                        .context(DaftCoreComputeSnafu)?
+                    } else {
+                        tables
+                    }
                 }
 
                 // ****************
                 // Native CSV Reads
                 // ****************
                 FileFormatConfig::Csv(cfg) => {
-                    let schema_of_file = scan_task.schema.clone();
+                    let schema_of_file = scan_task.file_schema();
                     let col_names = if !cfg.has_headers {
                         Some(
                             schema_of_file
@@ -200,16 +298,18 @@ fn materialize_scan_task(
                     } else {
                         None
                     };
+                    let read_by_name = read_by_name && cfg.has_headers;
                     let convert_options = CsvConvertOptions::new_internal(
-                        scan_task.pushdowns.limit,
+                        read_limit,
                         file_column_names
                             .as_ref()
+                            .filter(|_| !read_by_name)
                             .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
                         col_names
                             .as_ref()
                             .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
-                        Some(schema_of_file),
-                        scan_task.pushdowns.filters.clone(),
+                        Some(schema_of_file).filter(|_| !read_by_name),
+                        read_filters,
                     );
                     let parse_options = CsvParseOptions::new_with_defaults(
                         cfg.has_headers,
@@ -222,7 +322,8 @@ fn materialize_scan_task(
                     )
                     .context(DaftCSVSnafu)?;
                     let read_options =
-                        CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
+                        CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size)
+                            .with_byte_range(sources_to_byte_range(scan_task.sources.as_slice()));
                     let uris = urls.collect::<Vec<_>>();
                     daft_csv::read_csv_bulk(
                         uris.as_slice(),
@@ -243,16 +344,18 @@ fn materialize_scan_task(
                 // ****************
                 FileFormatConfig::Json(cfg) => {
                     let convert_options = JsonConvertOptions::new_internal(
-                        scan_task.pushdowns.limit,
+                        read_limit,
                         file_column_names
                             .as_ref()
+                            .filter(|_| !read_by_name)
                             .map(|cols| cols.iter().map(|col| col.to_string()).collect()),
-                        Some(scan_task.schema.clone()),
-                        scan_task.pushdowns.filters.clone(),
+                        Some(scan_task.file_schema()).filter(|_| !read_by_name),
+                        read_filters,
                     );
                     let parse_options = JsonParseOptions::new_internal();
                     let read_options =
-                        JsonReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
+                        JsonReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size)
+                            .with_byte_range(sources_to_byte_range(scan_task.sources.as_slice()));
                     let uris = urls.collect::<Vec<_>>();
                     daft_json::read_json_bulk(
                         uris.as_slice(),
@@ -267,6 +370,153 @@ fn materialize_scan_task(
                     )
                     .context(DaftCoreComputeSnafu)?
                 }
+
+                // **********************
+                // Native Arrow IPC Reads
+                // **********************
+                FileFormatConfig::Ipc(_) => read_sources(
+                    &scan_task.sources,
+                    |chunk_spec| IpcReadOptions {
+                        record_batches: chunk_spec
+                            .and_then(ChunkSpec::record_batches)
+                            .map(<[_]>::to_vec),
+                    },
+                    ReadPushdowns::new(
+                        file_column_names.as_deref().filter(|_| !read_by_name),
+                        read_limit,
+                        read_filters,
+                    ),
+                    io_client,
+                    io_stats,
+                    multithreaded_io,
+                )?,
+
+                // *****************
+                // Native Avro Reads
+                // *****************
+                FileFormatConfig::Avro(_) => read_sources(
+                    &scan_task.sources,
+                    |chunk_spec| AvroReadOptions {
+                        byte_range: chunk_spec.and_then(ChunkSpec::byte_range),
+                    },
+                    ReadPushdowns::new(
+                        file_column_names.as_deref().filter(|_| !read_by_name),
+                        read_limit,
+                        read_filters,
+                    ),
+                    io_client,
+                    io_stats,
+                    multithreaded_io,
+                )?,
+
+                // ****************
+                // Native ORC Reads
+                // ****************
+                FileFormatConfig::Orc(_) => read_sources(
+                    &scan_task.sources,
+                    |chunk_spec| OrcReadOptions {
+                        stripes: chunk_spec.and_then(ChunkSpec::stripes).map(<[_]>::to_vec),
+                    },
+                    ReadPushdowns::new(
+                        file_column_names.as_deref().filter(|_| !read_by_name),
+                        read_limit,
+                        read_filters,
+                    ),
+                    io_client,
+                    io_stats,
+                    multithreaded_io,
+                )?,
+
+                // *****************
+                // Native XLSX Reads
+                // *****************
+                FileFormatConfig::Xlsx(XlsxSourceConfig {
+                    sheet_name,
+                    sheet_index,
+                    header_row,
+                }) => {
+                    // Columns can only be matched by name if the sheets have header rows.
+                    let read_by_name = read_by_name && header_row.is_some();
+                    let options = XlsxReadOptions {
+                        sheet_name: sheet_name.clone(),
+                        sheet_index: *sheet_index,
+                        header_row: *header_row,
+                        schema: Some(scan_task.file_schema()).filter(|_| !read_by_name),
+                    };
+                    read_sources(
+                        &scan_task.sources,
+                        |_| options.clone(),
+                        ReadPushdowns::new(
+                            file_column_names.as_deref().filter(|_| !read_by_name),
+                            read_limit,
+                            read_filters,
+                        ),
+                        io_client,
+                        io_stats,
+                        multithreaded_io,
+                    )?
+                }
+
+                // *****************
+                // Native Text Reads
+                // *****************
+                FileFormatConfig::Text(TextSourceConfig {
+                    skip_blank_lines,
+                    path_column,
+                }) => read_sources(
+                    &scan_task.sources,
+                    |chunk_spec| TextReadOptions {
+                        skip_blank_lines: *skip_blank_lines,
+                        path_column: path_column.clone(),
+                        byte_range: chunk_spec.and_then(ChunkSpec::byte_range),
+                    },
+                    ReadPushdowns::new(file_column_names.as_deref(), read_limit, read_filters),
+                    io_client,
+                    io_stats,
+                    multithreaded_io,
+                )?,
+
+                // *****************************
+                // Native Fixed-Width Text Reads
+                // *****************************
+                FileFormatConfig::FixedWidth(FixedWidthSourceConfig { columns, skip_rows }) => {
+                    read_sources(
+                        &scan_task.sources,
+                        |chunk_spec| FixedWidthReadOptions {
+                            columns: columns.clone(),
+                            skip_rows: *skip_rows,
+                            byte_range: chunk_spec.and_then(ChunkSpec::byte_range),
+                        },
+                        ReadPushdowns::new(file_column_names.as_deref(), read_limit, read_filters),
+                        io_client,
+                        io_stats,
+                        multithreaded_io,
+                    )?
+                }
+
+                // ************************
+                // Native Whole Files Reads
+                // ************************
+                FileFormatConfig::Files(_) => {
+                    let uris = urls.collect::<Vec<_>>();
+                    // Sizes from the listing of the files, which avoid fetching the sizes of files whose content
+                    // isn't read.
+                    let sizes = scan_task
+                        .sources
+                        .iter()
+                        .map(|s| s.get_size_bytes())
+                        .collect::<Vec<_>>();
+                    vec![daft_text::read_files(
+                        uris.as_slice(),
+                        sizes.as_slice(),
+                        ReadPushdowns::new(file_column_names.as_deref(), read_limit, read_filters),
+                        io_client,
+                        io_stats,
+                        8,
+                        multithreaded_io,
+                    )
+                    .context(DaftCoreComputeSnafu)?]
+                }
                 #[cfg(feature = "python")]
                 FileFormatConfig::Database(_) => {
                     return Err(common_error::DaftError::TypeError(
@@ -356,6 +606,39 @@ fn materialize_scan_task(
                     })
                     .collect::<crate::Result<Vec<_>>>()
                 })?,
+                FileFormatConfig::Ipc(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "Arrow IPC reads are only supported with native storage".to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Avro(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "Avro reads are only supported with native storage".to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Orc(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "ORC reads are only supported with native storage".to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Xlsx(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "XLSX reads are only supported with native storage".to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
+                FileFormatConfig::Text(_)
+                | FileFormatConfig::FixedWidth(_)
+                | FileFormatConfig::Files(_) => {
+                    return Err(common_error::DaftError::TypeError(
+                        "Text and whole file reads are only supported with native storage"
+                            .to_string(),
+                    ))
+                    .context(DaftCoreComputeSnafu);
+                }
                 FileFormatConfig::Database(daft_scan::file_format::DatabaseSourceConfig {
                     sql,
                     conn,
@@ -494,7 +777,13 @@ fn materialize_scan_task(
 
     table_values = table_values
         .iter()
-        .map(|tbl| tbl.cast_to_schema_with_fill(cast_to_schema.as_ref(), fill_map.as_ref()))
+        .map(|tbl| match &post_read_filters {
+            None => tbl.cast_to_schema_with_fill(cast_to_schema.as_ref(), fill_map.as_ref()),
+            Some(filters) => tbl
+                .cast_to_schema_with_fill(scan_task.schema.as_ref(), fill_map.as_ref())?
+                .filter(&[filters.clone()])?
+                .cast_to_schema(cast_to_schema.as_ref()),
+        })
         .collect::<DaftResult<Vec<_>>>()
         .context(DaftCoreComputeSnafu)?;
     Ok((table_values, cast_to_schema))
@@ -599,7 +888,13 @@ impl MicroPartition {
                     ..
                 }),
                 StorageConfig::Native(cfg),
-            ) => {
+            ) if !scan_task.sources.iter().any(|s| {
+                // Equality deletes and deletion vectors are only applied when materializing the ScanTask.
+                s.get_iceberg_equality_delete_files()
+                    .is_some_and(|f| !f.is_empty())
+                    || s.get_delta_deletion_vector().is_some()
+            }) =>
+            {
                 let uris = scan_task
                     .sources
                     .iter()
@@ -814,6 +1109,32 @@ fn prune_fields_from_schema(
     }
 }
 
+/// Returns the byte range to read from a ScanTask, which is only set for ScanTasks over a single byte range of a file.
+fn sources_to_byte_range(sources: &[DataSource]) -> Option<(usize, usize)> {
+    match sources {
+        [source] => source.get_chunk_spec().and_then(ChunkSpec::byte_range),
+        _ => None,
+    }
+}
+
+/// Reads each source of a ScanTask with the reader of its format, which `reader` creates from the chunk of the source
+/// to read.
+fn read_sources<R: FileFormatReader>(
+    sources: &[DataSource],
+    reader: impl Fn(Option<&ChunkSpec>) -> R,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+) -> crate::Result<Vec<Table>> {
+    let files = sources
+        .iter()
+        .map(|s| (s.get_path(), reader(s.get_chunk_spec())))
+        .collect();
+    daft_file_reader::read_files(files, pushdowns, io_client, io_stats, 8, multithreaded_io)
+        .context(DaftCoreComputeSnafu)
+}
+
 fn parquet_sources_to_row_groups(sources: &[DataSource]) -> Option<Vec<Option<Vec<i64>>>> {
     let row_groups = sources
         .iter()
@@ -1009,6 +1330,83 @@ fn _read_delete_files(
     Ok(delete_map)
 }
 
+/// Removes the rows of each table that are deleted by the Iceberg equality delete files of its source, where `tables`
+/// holds the table read from each of `sources`.
+#[allow(clippy::too_many_arguments)]
+fn _apply_equality_deletes(
+    tables: Vec<Table>,
+    sources: &[DataSource],
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+    multithreaded_io: bool,
+    schema_infer_options: &ParquetSchemaInferenceOptions,
+    field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+) -> DaftResult<Vec<Table>> {
+    let delete_files = sources
+        .iter()
+        .flat_map(|s| s.get_iceberg_equality_delete_files())
+        .flatten()
+        .map(|f| f.path.as_str())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    // The columns of equality delete files are identified by field IDs, like those of data files.
+    let delete_tables = read_parquet_bulk(
+        delete_files.as_slice(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        io_client,
+        io_stats,
+        num_parallel_tasks,
+        multithreaded_io,
+        schema_infer_options,
+        field_id_mapping,
+        None,
+        None,
+        None,
+    )?;
+    let delete_tables = delete_files
+        .into_iter()
+        .zip(delete_tables)
+        .collect::<HashMap<_, _>>();
+
+    tables
+        .into_iter()
+        .zip(sources)
+        .map(|(mut table, source)| {
+            for delete_file in source
+                .get_iceberg_equality_delete_files()
+                .into_iter()
+                .flatten()
+            {
+                let columns = delete_file
+                    .equality_columns
+                    .iter()
+                    .map(|name| col(name.as_str()))
+                    .collect::<Vec<_>>();
+                let delete_table = &delete_tables[delete_file.path.as_str()];
+                let delete_table = Table::from_nonempty_columns(
+                    delete_file
+                        .equality_columns
+                        .iter()
+                        .map(|name| {
+                            delete_table
+                                .get_column(name)?
+                                .cast(&table.get_column(name)?.data_type().clone())
+                        })
+                        .collect::<DaftResult<Vec<_>>>()?,
+                )?;
+                table = table.hash_join(&delete_table, &columns, &columns, JoinType::Anti)?;
+            }
+            Ok(table)
+        })
+        .collect()
+}
+
 #[allow(clippy::too_many_arguments)]
 fn _read_parquet_into_loaded_micropartition(
     io_client: Arc<IOClient>,
@@ -1263,10 +1661,14 @@ pub(crate) fn read_parquet_into_micropartition(
                     chunk_spec: rgs.map(ChunkSpec::Parquet),
                     size_bytes: Some(size_bytes),
                     iceberg_delete_files: None,
+                    iceberg_equality_delete_files: None,
+                    delta_deletion_vector: None,
                     metadata: None,
                     partition_spec: partition_spec.cloned(),
                     statistics: None,
                     parquet_metadata: Some(metadata),
+                    etag: None,
+                    last_modified: None,
                 })
                 .collect::<Vec<_>>(),
             FileFormatConfig::Parquet(ParquetSourceConfig {
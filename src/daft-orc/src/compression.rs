@@ -0,0 +1,126 @@
+use std::io::Read;
+
+use bytes::Bytes;
+
+use crate::{decode_error, proto::CompressionKind};
+
+/// Size of the header of each chunk of a compressed stream.
+const CHUNK_HEADER_SIZE: usize = 3;
+
+/// Decompresses a stream or a section of the file tail. Compressed streams are split into chunks of at most
+/// `block_size` decompressed bytes, which are each preceded by a header of their length and whether they're stored
+/// uncompressed because compressing them didn't make them smaller.
+pub(crate) fn decompress(
+    compression: CompressionKind,
+    block_size: usize,
+    data: Bytes,
+) -> crate::Result<Bytes> {
+    if compression == CompressionKind::None {
+        return Ok(data);
+    }
+    let mut decompressed = Vec::with_capacity(data.len());
+    let mut buf = data.as_ref();
+    while !buf.is_empty() {
+        if buf.len() < CHUNK_HEADER_SIZE {
+            return Err(decode_error("truncated compression chunk header"));
+        }
+        let header = u32::from_le_bytes([buf[0], buf[1], buf[2], 0]);
+        let (is_original, len) = (header & 1 == 1, (header >> 1) as usize);
+        buf = &buf[CHUNK_HEADER_SIZE..];
+        if buf.len() < len {
+            return Err(decode_error("truncated compression chunk"));
+        }
+        let (chunk, rest) = buf.split_at(len);
+        buf = rest;
+        if is_original {
+            decompressed.extend_from_slice(chunk);
+        } else {
+            decompress_chunk(compression, block_size, chunk, &mut decompressed)?;
+        }
+    }
+    Ok(decompressed.into())
+}
+
+fn decompress_chunk(
+    compression: CompressionKind,
+    block_size: usize,
+    chunk: &[u8],
+    out: &mut Vec<u8>,
+) -> crate::Result<()> {
+    let chunk_error = |e: &dyn std::fmt::Display| {
+        decode_error(format!("invalid {compression:?} compression chunk: {e}"))
+    };
+    match compression {
+        CompressionKind::None => out.extend_from_slice(chunk),
+        // Zlib chunks are raw deflate streams, without zlib headers.
+        CompressionKind::Zlib => {
+            flate2::read::DeflateDecoder::new(chunk)
+                .read_to_end(out)
+                .map_err(|e| chunk_error(&e))?;
+        }
+        CompressionKind::Snappy => out.extend_from_slice(
+            &snap::raw::Decoder::new()
+                .decompress_vec(chunk)
+                .map_err(|e| chunk_error(&e))?,
+        ),
+        // Lz4 chunks are raw lz4 blocks, without frames, of at most `block_size` decompressed bytes.
+        CompressionKind::Lz4 => {
+            let start = out.len();
+            out.resize(start + block_size, 0);
+            let len = lz4_flex::block::decompress_into(chunk, &mut out[start..])
+                .map_err(|e| chunk_error(&e))?;
+            out.truncate(start + len);
+        }
+        CompressionKind::Zstd => {
+            zstd::stream::copy_decode(chunk, out).map_err(|e| chunk_error(&e))?;
+        }
+        CompressionKind::Lzo => {
+            return Err(decode_error(
+                "LZO compressed ORC files are not supported".to_string(),
+            ))
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use bytes::Bytes;
+
+    use super::decompress;
+    use crate::proto::CompressionKind;
+
+    fn chunk_header(len: usize, is_original: bool) -> [u8; 3] {
+        let header = ((len as u32) << 1) | is_original as u32;
+        let bytes = header.to_le_bytes();
+        [bytes[0], bytes[1], bytes[2]]
+    }
+
+    #[test]
+    fn test_decompress_chunks() {
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(&[7; 100]).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        // A compressed chunk followed by an original chunk.
+        let mut data = chunk_header(compressed.len(), false).to_vec();
+        data.extend_from_slice(&compressed);
+        data.extend_from_slice(&chunk_header(3, true));
+        data.extend_from_slice(&[1, 2, 3]);
+        let decompressed = decompress(CompressionKind::Zlib, 1024, Bytes::from(data)).unwrap();
+        let mut expected = vec![7; 100];
+        expected.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(decompressed.as_ref(), expected.as_slice());
+
+        let compressed = lz4_flex::block::compress(&[5; 50]);
+        let mut data = chunk_header(compressed.len(), false).to_vec();
+        data.extend_from_slice(&compressed);
+        let decompressed = decompress(CompressionKind::Lz4, 1024, Bytes::from(data)).unwrap();
+        assert_eq!(decompressed.as_ref(), [5; 50].as_slice());
+
+        assert!(decompress(CompressionKind::Snappy, 1024, Bytes::from(vec![10, 0])).is_err());
+    }
+}
@@ -0,0 +1,449 @@
+//! Decoding of the streams of a stripe into Arrow arrays.
+//!
+//! See: https://orc.apache.org/specification/ORCv1/#column-encodings
+
+use std::{collections::HashMap, sync::Arc};
+
+use arrow2::{
+    array::{
+        growable::make_growable, Array, BinaryArray, BooleanArray, ListArray, MapArray,
+        PrimitiveArray, StructArray, Utf8Array,
+    },
+    bitmap::Bitmap,
+    buffer::Buffer,
+    offset::{Offset, Offsets, OffsetsBuffer},
+    types::Index,
+};
+use bytes::Bytes;
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_table::Table;
+
+use crate::{
+    decode_error,
+    proto::{ColumnEncoding, ColumnEncodingKind, StreamKind, Type, TypeKind},
+    rle::{decode_booleans, decode_bytes, decode_integers, read_i128_varint, RleVersion},
+    schema::children,
+};
+
+/// Seconds between the Unix epoch and 2015-01-01 00:00:00, which the seconds of timestamps are relative to.
+const TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+/// Kinds of streams that are needed to decode the values of columns.
+pub(crate) const VALUE_STREAM_KINDS: [StreamKind; 5] = [
+    StreamKind::Present,
+    StreamKind::Data,
+    StreamKind::Length,
+    StreamKind::DictionaryData,
+    StreamKind::Secondary,
+];
+
+/// The decompressed streams of the columns of a stripe that are read, by column id and kind.
+pub(crate) struct StripeStreams<'a> {
+    types: &'a [Type],
+    encodings: &'a [ColumnEncoding],
+    streams: HashMap<(usize, StreamKind), Bytes>,
+}
+
+impl<'a> StripeStreams<'a> {
+    pub fn new(
+        types: &'a [Type],
+        encodings: &'a [ColumnEncoding],
+        streams: HashMap<(usize, StreamKind), Bytes>,
+    ) -> Self {
+        Self {
+            types,
+            encodings,
+            streams,
+        }
+    }
+
+    /// Returns a stream of a column, which is empty if the writer omitted it because it had no values.
+    fn stream(&self, column: usize, kind: StreamKind) -> &[u8] {
+        self.streams
+            .get(&(column, kind))
+            .map(|bytes| bytes.as_ref())
+            .unwrap_or_default()
+    }
+
+    fn encoding(&self, column: usize) -> crate::Result<ColumnEncoding> {
+        self.encodings
+            .get(column)
+            .copied()
+            .ok_or_else(|| decode_error(format!("column {column} is missing its encoding")))
+    }
+
+    fn integers(
+        &self,
+        column: usize,
+        kind: StreamKind,
+        n: usize,
+        signed: bool,
+    ) -> crate::Result<Vec<i64>> {
+        let version = match self.encoding(column)?.kind {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::Dictionary => RleVersion::V1,
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2 => RleVersion::V2,
+        };
+        decode_integers(self.stream(column, kind), n, signed, version)
+    }
+
+    /// Decodes `n` rows of a column, including its nulls, into an array of the Arrow type of `dtype`.
+    pub fn decode_column(
+        &self,
+        column: usize,
+        dtype: &DataType,
+        n: usize,
+    ) -> crate::Result<Box<dyn Array>> {
+        let validity = match self.streams.get(&(column, StreamKind::Present)) {
+            Some(present) => Some(decode_booleans(present, n)?.into_iter().collect::<Bitmap>()),
+            None => None,
+        };
+        // The streams of a column only hold values for the rows where it isn't null.
+        let num_values = n - validity.as_ref().map_or(0, |v| v.unset_bits());
+        let values = self.decode_values(column, dtype, num_values)?;
+        Ok(match validity {
+            None => values,
+            Some(validity) if num_values == n => values.with_validity(Some(validity)),
+            Some(validity) => spread_nulls(values.as_ref(), &validity),
+        })
+    }
+
+    /// Decodes `n` non-null values of a column.
+    fn decode_values(
+        &self,
+        column: usize,
+        dtype: &DataType,
+        n: usize,
+    ) -> crate::Result<Box<dyn Array>> {
+        let arrow_dtype = dtype.to_arrow().map_err(|e| decode_error(e.to_string()))?;
+        let ty = &self.types[column];
+        Ok(match ty.kind {
+            TypeKind::Boolean => {
+                BooleanArray::from_slice(decode_booleans(self.stream(column, StreamKind::Data), n)?)
+                    .boxed()
+            }
+            TypeKind::Byte => PrimitiveArray::<i8>::from_vec(
+                decode_bytes(self.stream(column, StreamKind::Data), n)?
+                    .into_iter()
+                    .map(|v| v as i8)
+                    .collect(),
+            )
+            .boxed(),
+            TypeKind::Short => PrimitiveArray::<i16>::from_vec(
+                self.integers(column, StreamKind::Data, n, true)?
+                    .into_iter()
+                    .map(|v| v as i16)
+                    .collect(),
+            )
+            .boxed(),
+            TypeKind::Int => PrimitiveArray::<i32>::from_vec(
+                self.integers(column, StreamKind::Data, n, true)?
+                    .into_iter()
+                    .map(|v| v as i32)
+                    .collect(),
+            )
+            .boxed(),
+            TypeKind::Long => {
+                PrimitiveArray::<i64>::from_vec(self.integers(column, StreamKind::Data, n, true)?)
+                    .boxed()
+            }
+            TypeKind::Date => PrimitiveArray::<i32>::from_vec(
+                self.integers(column, StreamKind::Data, n, true)?
+                    .into_iter()
+                    .map(|v| v as i32)
+                    .collect(),
+            )
+            .to(arrow_dtype)
+            .boxed(),
+            TypeKind::Float => PrimitiveArray::<f32>::from_vec(
+                fixed_width_values(self.stream(column, StreamKind::Data), n)?
+                    .map(f32::from_le_bytes)
+                    .collect(),
+            )
+            .boxed(),
+            TypeKind::Double => PrimitiveArray::<f64>::from_vec(
+                fixed_width_values(self.stream(column, StreamKind::Data), n)?
+                    .map(f64::from_le_bytes)
+                    .collect(),
+            )
+            .boxed(),
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char => {
+                let (offsets, values) = self.decode_binary(column, n)?;
+                Utf8Array::<i64>::try_new(arrow_dtype, offsets, values, None)
+                    .map_err(|e| decode_error(e.to_string()))?
+                    .boxed()
+            }
+            TypeKind::Binary => {
+                let (offsets, values) = self.decode_binary(column, n)?;
+                BinaryArray::<i64>::new(arrow_dtype, offsets, values, None).boxed()
+            }
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                PrimitiveArray::<i64>::from_vec(self.decode_timestamps(column, n)?)
+                    .to(arrow_dtype)
+                    .boxed()
+            }
+            TypeKind::Decimal => {
+                let DataType::Decimal128(_, scale) = dtype else {
+                    return Err(mismatched_dtype(column, dtype));
+                };
+                PrimitiveArray::<i128>::from_vec(self.decode_decimals(column, n, *scale)?)
+                    .to(arrow_dtype)
+                    .boxed()
+            }
+            TypeKind::List => {
+                let DataType::List(child_dtype) = dtype else {
+                    return Err(mismatched_dtype(column, dtype));
+                };
+                let offsets = offsets_from_lengths::<i64>(&self.integers(
+                    column,
+                    StreamKind::Length,
+                    n,
+                    false,
+                )?)?;
+                let child = self.decode_column(
+                    children(self.types, column)?[0],
+                    child_dtype,
+                    offsets.last().to_usize(),
+                )?;
+                ListArray::<i64>::try_new(arrow_dtype, offsets, child, None)
+                    .map_err(|e| decode_error(e.to_string()))?
+                    .boxed()
+            }
+            TypeKind::Map => {
+                let DataType::Map(entries_dtype) = dtype else {
+                    return Err(mismatched_dtype(column, dtype));
+                };
+                let DataType::Struct(entry_fields) = entries_dtype.as_ref() else {
+                    return Err(mismatched_dtype(column, dtype));
+                };
+                let offsets = offsets_from_lengths::<i32>(&self.integers(
+                    column,
+                    StreamKind::Length,
+                    n,
+                    false,
+                )?)?;
+                let num_entries = offsets.last().to_usize();
+                let entries = children(self.types, column)?
+                    .into_iter()
+                    .zip(entry_fields)
+                    .map(|(child, field)| self.decode_column(child, &field.dtype, num_entries))
+                    .collect::<crate::Result<Vec<_>>>()?;
+                let entries_arrow_dtype = entries_dtype
+                    .to_arrow()
+                    .map_err(|e| decode_error(e.to_string()))?;
+                let entries = StructArray::try_new(entries_arrow_dtype, entries, None)
+                    .map_err(|e| decode_error(e.to_string()))?;
+                MapArray::try_new(arrow_dtype, offsets, entries.boxed(), None)
+                    .map_err(|e| decode_error(e.to_string()))?
+                    .boxed()
+            }
+            TypeKind::Struct => {
+                let DataType::Struct(fields) = dtype else {
+                    return Err(mismatched_dtype(column, dtype));
+                };
+                let values = children(self.types, column)?
+                    .into_iter()
+                    .zip(fields)
+                    .map(|(child, field)| self.decode_column(child, &field.dtype, n))
+                    .collect::<crate::Result<Vec<_>>>()?;
+                StructArray::try_new(arrow_dtype, values, None)
+                    .map_err(|e| decode_error(e.to_string()))?
+                    .boxed()
+            }
+            TypeKind::Union => {
+                return Err(crate::Error::UnsupportedType {
+                    kind: "union".to_string(),
+                })
+            }
+        })
+    }
+
+    /// Decodes `n` strings or byte sequences, which are either stored directly, as their concatenated bytes and their
+    /// lengths, or as indices into a dictionary of the distinct values of the stripe.
+    fn decode_binary(
+        &self,
+        column: usize,
+        n: usize,
+    ) -> crate::Result<(OffsetsBuffer<i64>, Buffer<u8>)> {
+        let encoding = self.encoding(column)?;
+        match encoding.kind {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::DirectV2 => {
+                let offsets = offsets_from_lengths::<i64>(&self.integers(
+                    column,
+                    StreamKind::Length,
+                    n,
+                    false,
+                )?)?;
+                let data = self.stream(column, StreamKind::Data);
+                let values = data.get(..offsets.last().to_usize()).ok_or_else(|| {
+                    decode_error(format!(
+                        "data of column {column} is shorter than its lengths"
+                    ))
+                })?;
+                Ok((offsets, values.to_vec().into()))
+            }
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2 => {
+                let dictionary_size = encoding.dictionary_size as usize;
+                let dictionary_offsets = offsets_from_lengths::<i64>(&self.integers(
+                    column,
+                    StreamKind::Length,
+                    dictionary_size,
+                    false,
+                )?)?;
+                let dictionary = self.stream(column, StreamKind::DictionaryData);
+                if dictionary.len() < dictionary_offsets.last().to_usize() {
+                    return Err(decode_error(format!(
+                        "dictionary of column {column} is shorter than its lengths"
+                    )));
+                }
+                let indices = self.integers(column, StreamKind::Data, n, false)?;
+                let mut values = vec![];
+                let mut lengths = Vec::with_capacity(n);
+                for index in indices {
+                    let index = usize::try_from(index)
+                        .ok()
+                        .filter(|index| *index < dictionary_size)
+                        .ok_or_else(|| {
+                            decode_error(format!(
+                                "dictionary index {index} of column {column} is out of bounds"
+                            ))
+                        })?;
+                    let (start, end) = dictionary_offsets.start_end(index);
+                    values.extend_from_slice(&dictionary[start..end]);
+                    lengths.push(end - start);
+                }
+                let offsets = Offsets::<i64>::try_from_lengths(lengths.into_iter())
+                    .map_err(|e| decode_error(e.to_string()))?;
+                Ok((offsets.into(), values.into()))
+            }
+        }
+    }
+
+    /// Decodes `n` timestamps into microseconds since the epoch. Timestamps are stored as their seconds since 2015 and
+    /// their nanoseconds, with the trailing zeros of the nanoseconds encoded in their 3 lowest bits.
+    fn decode_timestamps(&self, column: usize, n: usize) -> crate::Result<Vec<i64>> {
+        let seconds = self.integers(column, StreamKind::Data, n, true)?;
+        let nanos = self.integers(column, StreamKind::Secondary, n, false)?;
+        seconds
+            .into_iter()
+            .zip(nanos)
+            .map(|(seconds, nanos)| {
+                let nanos = nanos as u64;
+                let zeros = nanos & 0x7;
+                let nanos = if zeros == 0 {
+                    nanos >> 3
+                } else {
+                    (nanos >> 3) * 10u64.pow(zeros as u32 + 1)
+                };
+                let mut seconds = seconds + TIMESTAMP_BASE_SECONDS;
+                // Writers truncate the seconds of times before the epoch towards zero.
+                if seconds < 0 && nanos > 999_999 {
+                    seconds -= 1;
+                }
+                seconds
+                    .checked_mul(1_000_000)
+                    .and_then(|micros| micros.checked_add((nanos / 1000) as i64))
+                    .ok_or_else(|| {
+                        decode_error(format!("timestamp of column {column} is out of range"))
+                    })
+            })
+            .collect()
+    }
+
+    /// Decodes `n` decimals into their unscaled values at `scale`. Values are stored as unbounded varints, and each of
+    /// them has its own scale.
+    fn decode_decimals(&self, column: usize, n: usize, scale: usize) -> crate::Result<Vec<i128>> {
+        let mut data = self.stream(column, StreamKind::Data);
+        let scales = self.integers(column, StreamKind::Secondary, n, true)?;
+        scales
+            .into_iter()
+            .map(|value_scale| {
+                let value = read_i128_varint(&mut data)?;
+                let rescaled = if value_scale <= scale as i64 {
+                    10i128
+                        .checked_pow((scale as i64 - value_scale) as u32)
+                        .and_then(|factor| value.checked_mul(factor))
+                } else {
+                    10i128
+                        .checked_pow((value_scale - scale as i64) as u32)
+                        .map(|factor| value / factor)
+                };
+                rescaled.ok_or_else(|| {
+                    decode_error(format!(
+                        "decimal of column {column} doesn't fit in its precision"
+                    ))
+                })
+            })
+            .collect()
+    }
+}
+
+fn mismatched_dtype(column: usize, dtype: &DataType) -> crate::Error {
+    decode_error(format!("column {column} can't be decoded as {dtype}"))
+}
+
+/// Returns an iterator over the first `n` fixed-width values of a stream.
+fn fixed_width_values<const N: usize>(
+    data: &[u8],
+    n: usize,
+) -> crate::Result<impl Iterator<Item = [u8; N]> + '_> {
+    if data.len() < n * N {
+        return Err(decode_error(format!(
+            "expected {n} values of {N} bytes, found {} bytes",
+            data.len()
+        )));
+    }
+    Ok(data[..n * N]
+        .chunks_exact(N)
+        .map(|chunk| chunk.try_into().unwrap()))
+}
+
+fn offsets_from_lengths<O: Offset>(lengths: &[i64]) -> crate::Result<OffsetsBuffer<O>> {
+    let lengths = lengths
+        .iter()
+        .map(|length| usize::try_from(*length))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| decode_error("length is out of range"))?;
+    let offsets = Offsets::<O>::try_from_lengths(lengths.into_iter())
+        .map_err(|e| decode_error(e.to_string()))?;
+    Ok(offsets.into())
+}
+
+/// Spreads the values of a column over the rows where it isn't null, filling the other rows with nulls.
+fn spread_nulls(values: &dyn Array, validity: &Bitmap) -> Box<dyn Array> {
+    let mut growable = make_growable(&[values], true, validity.len());
+    let (mut offset, mut start) = (0, 0);
+    while start < validity.len() {
+        let is_valid = validity.get_bit(start);
+        let mut end = start + 1;
+        while end < validity.len() && validity.get_bit(end) == is_valid {
+            end += 1;
+        }
+        if is_valid {
+            growable.extend(0, offset, end - start);
+            offset += end - start;
+        } else {
+            growable.extend_validity(end - start);
+        }
+        start = end;
+    }
+    growable.as_box()
+}
+
+/// Decodes a stripe into a table of the top-level columns with ids `column_ids`, which have the fields of `schema`.
+pub(crate) fn decode_stripe(
+    streams: &StripeStreams,
+    column_ids: &[usize],
+    schema: SchemaRef,
+    num_rows: usize,
+) -> DaftResult<Table> {
+    let columns = column_ids
+        .iter()
+        .zip(schema.fields.values())
+        .map(|(column, field)| {
+            let array = streams.decode_column(*column, &field.dtype, num_rows)?;
+            Series::try_from_field_and_arrow_array(Arc::new(field.clone()), array)
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new_with_size(schema, columns, num_rows)
+}
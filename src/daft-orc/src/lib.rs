@@ -0,0 +1,93 @@
+//! Reader of ORC files, which reads stripes with ranged requests and decodes their streams into tables.
+//!
+//! arrow2's `io::orc` isn't used because it only deserializes flat boolean, numeric, string and binary columns, and
+//! the `orc-format` crate that it builds on only decompresses zlib streams. This crate also reads dates, timestamps,
+//! decimals, lists, maps and structs, and the snappy, lz4 and zstd codecs that ORC writers default to.
+//!
+//! The decoders are cross-checked against files written by the reference ORC writer with every supported codec and
+//! encoding in `tests/io/test_orc.py`.
+#![feature(let_chains)]
+use common_error::DaftError;
+use snafu::Snafu;
+
+mod compression;
+mod decode;
+pub mod metadata;
+mod proto;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod read;
+mod rle;
+mod schema;
+pub mod statistics;
+#[cfg(test)]
+mod test;
+
+pub use metadata::{read_orc_metadata, read_orc_schema, read_orc_schema_bulk, OrcMetadata};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use read::OrcReadOptions;
+pub use statistics::stripe_statistics_to_table_stats;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("File: {} is not a valid ORC file: {}", path, reason))]
+    InvalidOrcFile { path: String, reason: String },
+    #[snafu(display("Error decoding ORC data: {}", reason))]
+    DecodeError { reason: String },
+    #[snafu(display("ORC columns of type {} are not supported", kind))]
+    UnsupportedType { kind: String },
+    #[snafu(display(
+        "Stripe {} is out of bounds for ORC file: {} with {} stripes",
+        index,
+        path,
+        num_stripes
+    ))]
+    StripeOutOfBounds {
+        path: String,
+        index: usize,
+        num_stripes: usize,
+    },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<Error> for pyo3::PyErr {
+    fn from(value: Error) -> Self {
+        let daft_error: DaftError = value.into();
+        daft_error.into()
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+pub(crate) fn decode_error(reason: impl Into<String>) -> Error {
+    Error::DecodeError {
+        reason: reason.into(),
+    }
+}
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_orc))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_orc_schema))?;
+    Ok(())
+}
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use futures::{StreamExt, TryStreamExt};
+use snafu::ResultExt;
+
+use crate::{
+    compression::decompress,
+    proto::{
+        decode_stripe_statistics, ColumnStatistics, CompressionKind, Footer, PostScript,
+        StripeInformation, TypeKind,
+    },
+    schema::struct_fields,
+    JoinSnafu,
+};
+
+/// Magic bytes at the start of an ORC file, which are also stored in its postscript.
+const ORC_MAGIC: &[u8] = b"ORC";
+/// Number of bytes to fetch from the end of the file when reading its tail, which is made up of the metadata section,
+/// the footer, the postscript and the length of the postscript. Larger tails are fetched with a second request.
+const DEFAULT_TAIL_READ_SIZE: usize = 64 * 1024;
+
+/// Metadata of an ORC file, read from its tail.
+#[derive(Debug, Clone)]
+pub struct OrcMetadata {
+    pub(crate) compression: CompressionKind,
+    pub(crate) compression_block_size: usize,
+    pub(crate) footer: Footer,
+    /// Column statistics of each stripe, by column id. Empty if the file has no metadata section.
+    pub(crate) stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    /// Column ids of the top-level columns of the file, in the order of the fields of the schema.
+    pub(crate) column_ids: Vec<usize>,
+    schema: SchemaRef,
+    file_size: usize,
+}
+
+impl OrcMetadata {
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.footer.number_of_rows as usize
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.footer.stripes.len()
+    }
+
+    pub fn stripe_num_rows(&self, stripe: usize) -> usize {
+        self.footer.stripes[stripe].number_of_rows as usize
+    }
+
+    /// Size of the stripe in bytes, including its indexes and footer.
+    pub fn stripe_size(&self, stripe: usize) -> usize {
+        self.footer.stripes[stripe].total_length() as usize
+    }
+
+    pub fn file_size(&self) -> usize {
+        self.file_size
+    }
+
+    pub(crate) fn stripe(&self, stripe: usize) -> &StripeInformation {
+        &self.footer.stripes[stripe]
+    }
+}
+
+pub(crate) async fn fetch_range(
+    uri: &str,
+    range: std::ops::Range<usize>,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<Bytes> {
+    Ok(io_client
+        .single_url_get(uri.to_string(), Some(range), io_stats)
+        .await?
+        .bytes()
+        .await?)
+}
+
+/// Reads the metadata of the ORC file at `uri`, using ranged reads of its tail.
+pub async fn read_orc_metadata(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<OrcMetadata> {
+    Ok(read_orc_tail(uri, io_client, io_stats).await?)
+}
+
+async fn read_orc_tail(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<OrcMetadata> {
+    let invalid_file = |reason: String| super::Error::InvalidOrcFile {
+        path: uri.to_string(),
+        reason,
+    };
+    let size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    if size < ORC_MAGIC.len() + 1 {
+        return Err(invalid_file(format!(
+            "file size of {size} bytes is too small"
+        )));
+    }
+
+    let tail_start = size.saturating_sub(DEFAULT_TAIL_READ_SIZE);
+    let mut tail = fetch_range(uri, tail_start..size, &io_client, io_stats.clone()).await?;
+    let postscript_len = tail[tail.len() - 1] as usize;
+    let postscript_start = (size - 1)
+        .checked_sub(postscript_len)
+        .filter(|start| *start >= tail_start)
+        .ok_or_else(|| {
+            invalid_file(format!(
+                "postscript length of {postscript_len} bytes is out of bounds"
+            ))
+        })?;
+    let postscript = PostScript::decode(&tail[postscript_start - tail_start..tail.len() - 1])?;
+    if postscript.magic.as_deref().map(str::as_bytes) != Some(ORC_MAGIC) {
+        return Err(invalid_file(
+            "postscript is missing the ORC magic bytes".to_string(),
+        ));
+    }
+
+    // Fetch the rest of the footer and metadata section if they didn't fit in the initial read.
+    let footer_len = postscript.footer_length as usize;
+    let metadata_len = postscript.metadata_length as usize;
+    let metadata_start = postscript_start
+        .checked_sub(footer_len + metadata_len)
+        .ok_or_else(|| {
+            invalid_file(format!(
+                "footer length of {footer_len} bytes and metadata length of {metadata_len} bytes are out of bounds"
+            ))
+        })?;
+    let tail_start = if metadata_start < tail_start {
+        let head = fetch_range(uri, metadata_start..tail_start, &io_client, io_stats).await?;
+        let mut bytes = Vec::with_capacity(head.len() + tail.len());
+        bytes.extend_from_slice(&head);
+        bytes.extend_from_slice(&tail);
+        tail = bytes.into();
+        metadata_start
+    } else {
+        tail_start
+    };
+    let footer_start = metadata_start + metadata_len;
+
+    let compression = postscript.compression;
+    let compression_block_size = postscript.compression_block_size as usize;
+    let section = |start: usize, len: usize| {
+        decompress(
+            compression,
+            compression_block_size,
+            tail.slice(start - tail_start..start - tail_start + len),
+        )
+    };
+    let footer = Footer::decode(&section(footer_start, footer_len)?)?;
+    let stripe_statistics = decode_stripe_statistics(&section(metadata_start, metadata_len)?)?;
+
+    match footer.types.first() {
+        Some(root) if root.kind == TypeKind::Struct => {}
+        _ => {
+            return Err(invalid_file(
+                "the root type of the file isn't a struct".to_string(),
+            ))
+        }
+    }
+    let (column_ids, fields): (Vec<_>, Vec<_>) =
+        struct_fields(&footer.types, 0)?.into_iter().unzip();
+    let schema = Arc::new(Schema::new(fields).map_err(|e| invalid_file(e.to_string()))?);
+
+    Ok(OrcMetadata {
+        compression,
+        compression_block_size,
+        footer,
+        stripe_statistics,
+        column_ids,
+        schema,
+        file_size: size,
+    })
+}
+
+pub fn read_orc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let runtime_handle = get_runtime(true)?;
+    let metadata = runtime_handle
+        .block_on_current_thread(async { read_orc_metadata(uri, io_client, io_stats).await })?;
+    Schema::new(metadata.schema.fields.values().cloned().collect())
+}
+
+pub fn read_orc_schema_bulk(
+    uris: &[&str],
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Schema>> {
+    let runtime_handle = get_runtime(true)?;
+    let metadatas = runtime_handle.block_on_current_thread(async move {
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, io_client, io_stats) = (uri.to_string(), io_client.clone(), io_stats.clone());
+            tokio::task::spawn(async move { read_orc_metadata(&uri, io_client, io_stats).await })
+        }));
+        task_stream
+            .buffered(num_parallel_tasks)
+            .try_collect::<Vec<_>>()
+            .await
+            .context(JoinSnafu)
+    })?;
+    metadatas
+        .into_iter()
+        .map(|metadata| Schema::new(metadata?.schema.fields.values().cloned().collect()))
+        .collect()
+}
@@ -0,0 +1,610 @@
+//! Decoding of the Protocol Buffers messages that make up the metadata of ORC files, which are the postscript, the
+//! footer, the metadata section and the stripe footers.
+//!
+//! See: https://github.com/apache/orc/blob/main/proto/orc_proto.proto
+
+use crate::decode_error;
+
+/// Value of a field of a message, by wire type.
+#[derive(Debug, Clone, Copy)]
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+/// Reads an unsigned base 128 varint.
+pub(crate) fn read_varint(buf: &mut &[u8]) -> crate::Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let Some((&byte, rest)) = buf.split_first() else {
+            return Err(decode_error("unexpected end of data in varint"));
+        };
+        *buf = rest;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(decode_error("varint is too long"))
+}
+
+pub(crate) fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> crate::Result<&'a [u8]> {
+    if buf.len() < len {
+        return Err(decode_error("unexpected end of message"));
+    }
+    let (bytes, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(bytes)
+}
+
+/// Iterates over the fields of an encoded message, as pairs of field numbers and values.
+struct Fields<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Fields<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    fn next_field(&mut self) -> crate::Result<Option<(u64, WireValue<'a>)>> {
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+        let key = read_varint(&mut self.buf)?;
+        let value = match key & 0x7 {
+            0 => WireValue::Varint(read_varint(&mut self.buf)?),
+            1 => WireValue::Fixed64(u64::from_le_bytes(
+                take(&mut self.buf, 8)?.try_into().unwrap(),
+            )),
+            2 => {
+                let len = read_varint(&mut self.buf)? as usize;
+                WireValue::Bytes(take(&mut self.buf, len)?)
+            }
+            5 => WireValue::Fixed32(u32::from_le_bytes(
+                take(&mut self.buf, 4)?.try_into().unwrap(),
+            )),
+            wire_type => {
+                return Err(decode_error(format!(
+                    "unsupported protobuf wire type {wire_type}"
+                )))
+            }
+        };
+        Ok(Some((key >> 3, value)))
+    }
+}
+
+impl<'a> WireValue<'a> {
+    fn as_u64(self) -> crate::Result<u64> {
+        match self {
+            Self::Varint(value) | Self::Fixed64(value) => Ok(value),
+            Self::Fixed32(value) => Ok(value as u64),
+            Self::Bytes(_) => Err(decode_error("expected an integer field")),
+        }
+    }
+
+    fn as_i64(self) -> crate::Result<i64> {
+        Ok(zigzag_decode(self.as_u64()?))
+    }
+
+    fn as_f64(self) -> crate::Result<f64> {
+        match self {
+            Self::Fixed64(value) => Ok(f64::from_bits(value)),
+            _ => Err(decode_error("expected a double field")),
+        }
+    }
+
+    fn as_bytes(self) -> crate::Result<&'a [u8]> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes),
+            _ => Err(decode_error("expected a length-delimited field")),
+        }
+    }
+
+    fn as_string(self) -> crate::Result<String> {
+        String::from_utf8(self.as_bytes()?.to_vec())
+            .map_err(|_| decode_error("string field isn't valid UTF-8"))
+    }
+
+    /// Appends the values of a repeated integer field, which may be packed.
+    fn extend_u64s(self, values: &mut Vec<u64>) -> crate::Result<()> {
+        match self {
+            Self::Bytes(mut buf) => {
+                while !buf.is_empty() {
+                    values.push(read_varint(&mut buf)?);
+                }
+            }
+            value => values.push(value.as_u64()?),
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompressionKind {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+    pub compression_block_size: u64,
+    pub metadata_length: u64,
+    pub magic: Option<String>,
+}
+
+/// Default size of the compression chunks of files whose postscripts don't specify it.
+const DEFAULT_COMPRESSION_BLOCK_SIZE: u64 = 256 * 1024;
+
+impl PostScript {
+    pub fn decode(buf: &[u8]) -> crate::Result<Self> {
+        let mut postscript = Self {
+            footer_length: 0,
+            compression: CompressionKind::None,
+            compression_block_size: DEFAULT_COMPRESSION_BLOCK_SIZE,
+            metadata_length: 0,
+            magic: None,
+        };
+        let mut fields = Fields::new(buf);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                1 => postscript.footer_length = value.as_u64()?,
+                2 => {
+                    postscript.compression = match value.as_u64()? {
+                        0 => CompressionKind::None,
+                        1 => CompressionKind::Zlib,
+                        2 => CompressionKind::Snappy,
+                        3 => CompressionKind::Lzo,
+                        4 => CompressionKind::Lz4,
+                        5 => CompressionKind::Zstd,
+                        kind => {
+                            return Err(decode_error(format!("unknown compression kind {kind}")))
+                        }
+                    }
+                }
+                3 => postscript.compression_block_size = value.as_u64()?,
+                5 => postscript.metadata_length = value.as_u64()?,
+                8000 => postscript.magic = Some(value.as_string()?),
+                _ => {}
+            }
+        }
+        Ok(postscript)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(buf: &[u8]) -> crate::Result<Self> {
+        let mut stripe = Self::default();
+        let mut fields = Fields::new(buf);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                1 => stripe.offset = value.as_u64()?,
+                2 => stripe.index_length = value.as_u64()?,
+                3 => stripe.data_length = value.as_u64()?,
+                4 => stripe.footer_length = value.as_u64()?,
+                5 => stripe.number_of_rows = value.as_u64()?,
+                _ => {}
+            }
+        }
+        Ok(stripe)
+    }
+
+    /// Total size of the stripe in bytes, including its indexes and footer.
+    pub fn total_length(&self) -> u64 {
+        self.index_length + self.data_length + self.footer_length
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Type {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+}
+
+impl Type {
+    fn decode(buf: &[u8]) -> crate::Result<Self> {
+        let mut kind = None;
+        let mut subtypes = vec![];
+        let mut field_names = vec![];
+        let mut precision = None;
+        let mut scale = None;
+        let mut fields = Fields::new(buf);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                1 => {
+                    kind = Some(match value.as_u64()? {
+                        0 => TypeKind::Boolean,
+                        1 => TypeKind::Byte,
+                        2 => TypeKind::Short,
+                        3 => TypeKind::Int,
+                        4 => TypeKind::Long,
+                        5 => TypeKind::Float,
+                        6 => TypeKind::Double,
+                        7 => TypeKind::String,
+                        8 => TypeKind::Binary,
+                        9 => TypeKind::Timestamp,
+                        10 => TypeKind::List,
+                        11 => TypeKind::Map,
+                        12 => TypeKind::Struct,
+                        13 => TypeKind::Union,
+                        14 => TypeKind::Decimal,
+                        15 => TypeKind::Date,
+                        16 => TypeKind::Varchar,
+                        17 => TypeKind::Char,
+                        18 => TypeKind::TimestampInstant,
+                        kind => return Err(decode_error(format!("unknown type kind {kind}"))),
+                    })
+                }
+                2 => {
+                    let mut values = vec![];
+                    value.extend_u64s(&mut values)?;
+                    subtypes.extend(values.into_iter().map(|v| v as u32));
+                }
+                3 => field_names.push(value.as_string()?),
+                5 => precision = Some(value.as_u64()? as u32),
+                6 => scale = Some(value.as_u64()? as u32),
+                _ => {}
+            }
+        }
+        Ok(Self {
+            kind: kind.ok_or_else(|| decode_error("type is missing its kind"))?,
+            subtypes,
+            field_names,
+            precision,
+            scale,
+        })
+    }
+}
+
+/// Type-specific statistics of a column.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TypeStatistics {
+    Integer {
+        minimum: Option<i64>,
+        maximum: Option<i64>,
+    },
+    Double {
+        minimum: Option<f64>,
+        maximum: Option<f64>,
+    },
+    String {
+        minimum: Option<String>,
+        maximum: Option<String>,
+        lower_bound: Option<String>,
+        upper_bound: Option<String>,
+    },
+    /// Statistics of boolean columns, which count the number of true values.
+    Bucket { true_count: Option<u64> },
+    Decimal {
+        minimum: Option<String>,
+        maximum: Option<String>,
+    },
+    Date {
+        minimum: Option<i32>,
+        maximum: Option<i32>,
+    },
+    /// Bounds of timestamps in milliseconds since the epoch, in UTC for instants or as wall clock times otherwise.
+    Timestamp {
+        minimum_utc: Option<i64>,
+        maximum_utc: Option<i64>,
+    },
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ColumnStatistics {
+    pub number_of_values: Option<u64>,
+    pub has_null: Option<bool>,
+    pub type_statistics: Option<TypeStatistics>,
+}
+
+impl ColumnStatistics {
+    fn decode(buf: &[u8]) -> crate::Result<Self> {
+        let mut statistics = Self::default();
+        let mut fields = Fields::new(buf);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                1 => statistics.number_of_values = Some(value.as_u64()?),
+                2..=4 | 6 | 7 | 9 => {
+                    statistics.type_statistics =
+                        Some(Self::decode_type_statistics(number, value.as_bytes()?)?)
+                }
+                5 => {
+                    let mut counts = vec![];
+                    let mut fields = Fields::new(value.as_bytes()?);
+                    while let Some((number, value)) = fields.next_field()? {
+                        if number == 1 {
+                            value.extend_u64s(&mut counts)?;
+                        }
+                    }
+                    statistics.type_statistics = Some(TypeStatistics::Bucket {
+                        true_count: counts.first().copied(),
+                    });
+                }
+                10 => statistics.has_null = Some(value.as_u64()? != 0),
+                _ => {}
+            }
+        }
+        Ok(statistics)
+    }
+
+    fn decode_type_statistics(kind: u64, buf: &[u8]) -> crate::Result<TypeStatistics> {
+        let mut values: [Option<WireValue>; 5] = [None; 5];
+        let mut fields = Fields::new(buf);
+        while let Some((number, value)) = fields.next_field()? {
+            if (1..=5).contains(&number) {
+                values[number as usize - 1] = Some(value);
+            }
+        }
+        let [first, second, third, fourth, fifth] = values;
+        Ok(match kind {
+            2 => TypeStatistics::Integer {
+                minimum: first.map(WireValue::as_i64).transpose()?,
+                maximum: second.map(WireValue::as_i64).transpose()?,
+            },
+            3 => TypeStatistics::Double {
+                minimum: first.map(WireValue::as_f64).transpose()?,
+                maximum: second.map(WireValue::as_f64).transpose()?,
+            },
+            4 => TypeStatistics::String {
+                minimum: first.map(WireValue::as_string).transpose()?,
+                maximum: second.map(WireValue::as_string).transpose()?,
+                lower_bound: fourth.map(WireValue::as_string).transpose()?,
+                upper_bound: fifth.map(WireValue::as_string).transpose()?,
+            },
+            6 => TypeStatistics::Decimal {
+                minimum: first.map(WireValue::as_string).transpose()?,
+                maximum: second.map(WireValue::as_string).transpose()?,
+            },
+            7 => TypeStatistics::Date {
+                minimum: first.map(|v| v.as_i64().map(|v| v as i32)).transpose()?,
+                maximum: second.map(|v| v.as_i64().map(|v| v as i32)).transpose()?,
+            },
+            9 => TypeStatistics::Timestamp {
+                minimum_utc: third.map(WireValue::as_i64).transpose()?,
+                maximum_utc: fourth.map(WireValue::as_i64).transpose()?,
+            },
+            _ => unreachable!("statistics of field {kind} aren't type statistics"),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Footer {
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub number_of_rows: u64,
+}
+
+impl Footer {
+    pub fn decode(buf: &[u8]) -> crate::Result<Self> {
+        let mut footer = Self {
+            stripes: vec![],
+            types: vec![],
+            number_of_rows: 0,
+        };
+        let mut fields = Fields::new(buf);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                3 => footer
+                    .stripes
+                    .push(StripeInformation::decode(value.as_bytes()?)?),
+                4 => footer.types.push(Type::decode(value.as_bytes()?)?),
+                6 => footer.number_of_rows = value.as_u64()?,
+                _ => {}
+            }
+        }
+        Ok(footer)
+    }
+}
+
+/// Decodes the metadata section of a file into the column statistics of each of its stripes.
+pub(crate) fn decode_stripe_statistics(buf: &[u8]) -> crate::Result<Vec<Vec<ColumnStatistics>>> {
+    let mut stripe_statistics = vec![];
+    let mut fields = Fields::new(buf);
+    while let Some((number, value)) = fields.next_field()? {
+        if number == 1 {
+            let mut column_statistics = vec![];
+            let mut fields = Fields::new(value.as_bytes()?);
+            while let Some((number, value)) = fields.next_field()? {
+                if number == 1 {
+                    column_statistics.push(ColumnStatistics::decode(value.as_bytes()?)?);
+                }
+            }
+            stripe_statistics.push(column_statistics);
+        }
+    }
+    Ok(stripe_statistics)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Kinds of streams that aren't read, such as row indexes and bloom filters.
+    Other(u64),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Stream {
+    pub kind: StreamKind,
+    pub column: u32,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColumnEncodingKind {
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u32,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+}
+
+impl StripeFooter {
+    pub fn decode(buf: &[u8]) -> crate::Result<Self> {
+        let mut footer = Self {
+            streams: vec![],
+            columns: vec![],
+        };
+        let mut fields = Fields::new(buf);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                1 => {
+                    let mut stream = Stream {
+                        kind: StreamKind::Other(0),
+                        column: 0,
+                        length: 0,
+                    };
+                    let mut fields = Fields::new(value.as_bytes()?);
+                    while let Some((number, value)) = fields.next_field()? {
+                        match number {
+                            1 => {
+                                stream.kind = match value.as_u64()? {
+                                    0 => StreamKind::Present,
+                                    1 => StreamKind::Data,
+                                    2 => StreamKind::Length,
+                                    3 => StreamKind::DictionaryData,
+                                    5 => StreamKind::Secondary,
+                                    kind => StreamKind::Other(kind),
+                                }
+                            }
+                            2 => stream.column = value.as_u64()? as u32,
+                            3 => stream.length = value.as_u64()?,
+                            _ => {}
+                        }
+                    }
+                    footer.streams.push(stream);
+                }
+                2 => {
+                    let mut encoding = ColumnEncoding {
+                        kind: ColumnEncodingKind::Direct,
+                        dictionary_size: 0,
+                    };
+                    let mut fields = Fields::new(value.as_bytes()?);
+                    while let Some((number, value)) = fields.next_field()? {
+                        match number {
+                            1 => {
+                                encoding.kind = match value.as_u64()? {
+                                    0 => ColumnEncodingKind::Direct,
+                                    1 => ColumnEncodingKind::Dictionary,
+                                    2 => ColumnEncodingKind::DirectV2,
+                                    3 => ColumnEncodingKind::DictionaryV2,
+                                    kind => {
+                                        return Err(decode_error(format!(
+                                            "unknown column encoding {kind}"
+                                        )))
+                                    }
+                                }
+                            }
+                            2 => encoding.dictionary_size = value.as_u64()? as u32,
+                            _ => {}
+                        }
+                    }
+                    footer.columns.push(encoding);
+                }
+                _ => {}
+            }
+        }
+        Ok(footer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ColumnStatistics, Type, TypeKind, TypeStatistics};
+
+    #[test]
+    fn test_decode_type() {
+        // A struct with packed subtypes, and an unknown field that is skipped.
+        let buf = [
+            0x08, 0x0c, 0x12, 0x02, 0x01, 0x02, 0x1a, 0x01, b'a', 0x1a, 0x01, b'b', 0xa0, 0x06,
+            0x01,
+        ];
+        let ty = Type::decode(&buf).unwrap();
+        assert_eq!(ty.kind, TypeKind::Struct);
+        assert_eq!(ty.subtypes, vec![1, 2]);
+        assert_eq!(ty.field_names, vec!["a", "b"]);
+
+        // Unpacked subtypes are accepted too.
+        let ty = Type::decode(&[0x08, 0x0a, 0x10, 0x01]).unwrap();
+        assert_eq!(ty.kind, TypeKind::List);
+        assert_eq!(ty.subtypes, vec![1]);
+    }
+
+    #[test]
+    fn test_decode_column_statistics() {
+        // numberOfValues = 3, intStatistics = {minimum: -1, maximum: 2}, hasNull = true.
+        let buf = [0x08, 0x03, 0x12, 0x04, 0x08, 0x01, 0x10, 0x04, 0x50, 0x01];
+        let statistics = ColumnStatistics::decode(&buf).unwrap();
+        assert_eq!(statistics.number_of_values, Some(3));
+        assert_eq!(statistics.has_null, Some(true));
+        assert_eq!(
+            statistics.type_statistics,
+            Some(TypeStatistics::Integer {
+                minimum: Some(-1),
+                maximum: Some(2)
+            })
+        );
+    }
+}
@@ -0,0 +1,481 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_file_reader::{FileFormatReader, ReadPushdowns};
+use daft_io::{IOClient, IOStatsRef};
+use daft_stats::TruthValue;
+use daft_table::Table;
+use futures::{stream::BoxStream, StreamExt};
+use snafu::ResultExt;
+
+use crate::{
+    compression::decompress,
+    decode::{decode_stripe, StripeStreams, VALUE_STREAM_KINDS},
+    metadata::{fetch_range, read_orc_metadata, OrcMetadata},
+    proto::StripeFooter,
+    schema::column_subtree,
+    statistics::stripe_statistics_to_table_stats,
+    JoinSnafu,
+};
+
+/// Number of stripes of a file that are fetched and decoded concurrently.
+const NUM_STRIPES_IN_FLIGHT: usize = 4;
+/// Largest gap between the streams of a stripe that are fetched with a single request.
+const MAX_STREAM_GAP_SIZE: usize = 1024 * 1024;
+
+/// Options for reading ORC files, which are read as a table per stripe.
+#[derive(Clone, Debug, Default)]
+pub struct OrcReadOptions {
+    /// Indices of the stripes to read, in order, or `None` to read all of the stripes of the file.
+    pub stripes: Option<Vec<usize>>,
+}
+
+#[async_trait]
+impl FileFormatReader for OrcReadOptions {
+    const FORMAT_NAME: &'static str = "ORC";
+
+    type Metadata = OrcMetadata;
+
+    async fn read_metadata(
+        &self,
+        uri: &str,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<(Self::Metadata, SchemaRef)> {
+        let metadata = read_orc_metadata(uri, io_client, io_stats).await?;
+        let schema = metadata.schema();
+        Ok((metadata, schema))
+    }
+
+    /// Fetches only the streams of the columns that are read, and skips the stripes whose statistics show that no row
+    /// can match the predicate.
+    async fn decode(
+        &self,
+        uri: &str,
+        metadata: Self::Metadata,
+        read_schema: SchemaRef,
+        pushdowns: &ReadPushdowns,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+        let num_stripes = metadata.num_stripes();
+        let stripes = self
+            .stripes
+            .clone()
+            .unwrap_or_else(|| (0..num_stripes).collect());
+        if let Some(&index) = stripes.iter().find(|i| **i >= num_stripes) {
+            return Err(super::Error::StripeOutOfBounds {
+                path: uri.to_string(),
+                index,
+                num_stripes,
+            }
+            .into());
+        }
+        let stripes = match &pushdowns.predicate {
+            Some(predicate) => stripes
+                .into_iter()
+                .filter(|stripe| {
+                    stripe_statistics_to_table_stats(&metadata, *stripe)
+                        .and_then(|stats| Ok(stats.eval_expression(predicate)?))
+                        .map_or(true, |result| result.to_truth_value() != TruthValue::False)
+                })
+                .collect(),
+            None => stripes,
+        };
+
+        let file_schema = metadata.schema();
+        let read_all_columns = read_schema.fields.len() == file_schema.fields.len();
+        let column_ids = read_schema
+            .fields
+            .keys()
+            .map(|name| metadata.column_ids[file_schema.fields.get_index_of(name).unwrap()])
+            .collect::<Arc<[usize]>>();
+
+        let (uri, metadata) = (uri.to_string(), Arc::new(metadata));
+        let stream = futures::stream::iter(stripes)
+            .map(move |stripe| {
+                read_stripe(
+                    uri.clone(),
+                    metadata.clone(),
+                    stripe,
+                    column_ids.clone(),
+                    read_all_columns,
+                    read_schema.clone(),
+                    io_client.clone(),
+                    io_stats.clone(),
+                )
+            })
+            .buffered(NUM_STRIPES_IN_FLIGHT);
+        Ok(stream.boxed())
+    }
+}
+
+/// Byte ranges of a file that have been fetched.
+struct FetchedRanges(Vec<(usize, Bytes)>);
+
+impl FetchedRanges {
+    /// Returns the bytes of `range`, which must lie within one of the fetched ranges.
+    fn slice(&self, range: Range<usize>) -> Option<Bytes> {
+        self.0.iter().find_map(|(start, bytes)| {
+            (*start <= range.start && range.end <= start + bytes.len())
+                .then(|| bytes.slice(range.start - start..range.end - start))
+        })
+    }
+}
+
+/// Merges sorted byte ranges that are at most `MAX_STREAM_GAP_SIZE` apart, so that they can be fetched together.
+fn coalesce_ranges(ranges: impl Iterator<Item = Range<usize>>) -> Vec<Range<usize>> {
+    let mut coalesced: Vec<Range<usize>> = vec![];
+    for range in ranges {
+        match coalesced.last_mut() {
+            Some(last) if range.start <= last.end + MAX_STREAM_GAP_SIZE => {
+                last.end = last.end.max(range.end);
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
+/// Reads a stripe of the top-level columns with ids `column_ids`. The whole stripe is fetched at once when all columns
+/// are read, and otherwise its footer is fetched first to locate the streams of the columns.
+#[allow(clippy::too_many_arguments)]
+async fn read_stripe(
+    uri: String,
+    metadata: Arc<OrcMetadata>,
+    stripe: usize,
+    column_ids: Arc<[usize]>,
+    read_all_columns: bool,
+    schema: SchemaRef,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Table> {
+    let info = metadata.stripe(stripe).clone();
+    let data_start = (info.offset + info.index_length) as usize;
+    let footer_start = data_start + info.data_length as usize;
+    let footer_range = footer_start..footer_start + info.footer_length as usize;
+    if footer_range.end > metadata.file_size() {
+        return Err(super::Error::InvalidOrcFile {
+            path: uri,
+            reason: format!("stripe {stripe} is out of bounds"),
+        }
+        .into());
+    }
+
+    let mut fetched = if read_all_columns {
+        let range = data_start..footer_range.end;
+        let bytes = fetch_range(&uri, range.clone(), &io_client, io_stats.clone()).await?;
+        FetchedRanges(vec![(range.start, bytes)])
+    } else {
+        let bytes = fetch_range(&uri, footer_range.clone(), &io_client, io_stats.clone()).await?;
+        FetchedRanges(vec![(footer_range.start, bytes)])
+    };
+    let stripe_footer = StripeFooter::decode(&decompress(
+        metadata.compression,
+        metadata.compression_block_size,
+        fetched.slice(footer_range).unwrap(),
+    )?)?;
+
+    // Streams are laid out back to back from the start of the stripe, in the order of the stripe footer.
+    let types = &metadata.footer.types;
+    let read_column_ids = column_ids
+        .iter()
+        .map(|column| column_subtree(types, *column))
+        .collect::<crate::Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<HashSet<_>>();
+    let mut offset = info.offset as usize;
+    let mut stream_ranges = vec![];
+    for stream in &stripe_footer.streams {
+        let range = offset..offset + stream.length as usize;
+        offset = range.end;
+        if read_column_ids.contains(&(stream.column as usize))
+            && VALUE_STREAM_KINDS.contains(&stream.kind)
+            && !range.is_empty()
+        {
+            stream_ranges.push((stream.column as usize, stream.kind, range));
+        }
+    }
+    if offset > footer_start {
+        return Err(super::Error::InvalidOrcFile {
+            path: uri,
+            reason: format!("streams of stripe {stripe} overlap its footer"),
+        }
+        .into());
+    }
+
+    if !read_all_columns {
+        let ranges = coalesce_ranges(stream_ranges.iter().map(|(_, _, range)| range.clone()));
+        fetched = FetchedRanges(
+            futures::future::try_join_all(ranges.into_iter().map(|range| {
+                let (uri, io_client, io_stats) = (&uri, &io_client, io_stats.clone());
+                async move {
+                    let bytes = fetch_range(uri, range.clone(), io_client, io_stats).await?;
+                    Ok::<_, super::Error>((range.start, bytes))
+                }
+            }))
+            .await?,
+        );
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let streams = stream_ranges
+            .into_iter()
+            .map(|(column, kind, range)| {
+                let bytes = fetched.slice(range).ok_or_else(|| {
+                    crate::decode_error(format!("stream of column {column} wasn't fetched"))
+                })?;
+                let bytes =
+                    decompress(metadata.compression, metadata.compression_block_size, bytes)?;
+                Ok(((column, kind), bytes))
+            })
+            .collect::<crate::Result<HashMap<_, _>>>()?;
+        let streams = StripeStreams::new(&metadata.footer.types, &stripe_footer.columns, streams);
+        decode_stripe(
+            &streams,
+            &column_ids,
+            schema,
+            metadata.stripe_num_rows(stripe),
+        )
+    })
+    .await
+    .context(JoinSnafu)?
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_file_reader::{read_file, read_files, ReadPushdowns};
+    use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+    use rstest::rstest;
+
+    use super::OrcReadOptions;
+    use crate::{proto::CompressionKind, read_orc_schema, test::write_test_file};
+
+    fn column_values(table: &Table, name: &str) -> Vec<String> {
+        let column = table.get_column(name).unwrap();
+        (0..column.len())
+            .map(|i| column.str_value(i).unwrap().replace('\n', " "))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_orc_roundtrip(
+        #[values(CompressionKind::None, CompressionKind::Zlib)] compression: CompressionKind,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = write_test_file(&dir, compression);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let schema = read_orc_schema(&uri, io_client.clone(), None)?;
+        assert_eq!(
+            schema.fields.values().map(|f| &f.dtype).collect::<Vec<_>>(),
+            vec![
+                &DataType::Int64,
+                &DataType::Utf8,
+                &DataType::Utf8,
+                &DataType::Boolean,
+                &DataType::Float64,
+                &DataType::Decimal128(10, 2),
+                &DataType::Date,
+                &DataType::Timestamp(TimeUnit::Microseconds, None),
+                &DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string())),
+                &DataType::List(Box::new(DataType::Int64)),
+                &DataType::Map(Box::new(DataType::Struct(vec![
+                    Field::new("key", DataType::Utf8),
+                    Field::new("value", DataType::Float64),
+                ]))),
+                &DataType::Struct(vec![
+                    Field::new("x", DataType::Int64),
+                    Field::new("label", DataType::Utf8),
+                ]),
+            ]
+        );
+
+        let table = read_file(
+            &OrcReadOptions::default(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 8);
+        assert_eq!(
+            column_values(&table, "name"),
+            ["None", "bb", "ccc", "None", "bb", "ccc", "None", "bb"]
+        );
+        assert_eq!(
+            column_values(&table, "category"),
+            ["x", "y", "x", "y", "x", "y", "x", "y"]
+        );
+        assert_eq!(
+            column_values(&table, "flag"),
+            ["None", "false", "true", "false", "None", "false", "true", "false"]
+        );
+        assert_eq!(
+            column_values(&table, "price"),
+            ["None", "1.25", "25.00", "3.75", "50.00", "None", "75.00", "8.75"]
+        );
+        assert_eq!(
+            column_values(&table, "day")[..3],
+            ["1969-12-30", "1969-12-31", "1970-01-01"]
+        );
+        assert_eq!(
+            column_values(&table, "ts")[..4],
+            [
+                "1969-12-31 23:59:57.123456",
+                "1969-12-31 23:59:58.500",
+                "1969-12-31 23:59:59",
+                "1970-01-01 00:00:00.123456"
+            ]
+        );
+        assert_eq!(
+            column_values(&table, "ts_utc")[0],
+            "2023-11-14 22:13:20 +00:00"
+        );
+        assert_eq!(
+            column_values(&table, "tags"),
+            ["None", "[10]", "[20, 21]", "[]", "None", "[50, 51]", "[]", "[70]"]
+        );
+        assert_eq!(
+            column_values(&table, "attrs")[..3],
+            [
+                "[]",
+                "[{key: k, value: 0, }]",
+                "[{key: k, value: None, }, {key: k, value: None, }]"
+            ]
+        );
+        assert_eq!(
+            column_values(&table, "point")[..4],
+            [
+                "None",
+                "{x: -1, label: p, }",
+                "{x: -2, label: None, }",
+                "None"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_orc_read_pushdowns() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = write_test_file(&dir, CompressionKind::Zlib);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        // Projection reorders the columns, and only the streams of the projected columns are fetched.
+        let table = read_file(
+            &OrcReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(Some(&["point", "id"]), None, None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["point", "id"]);
+        assert_eq!(
+            column_values(&table, "id"),
+            ["0", "1", "2", "3", "4", "5", "6", "7"]
+        );
+        assert_eq!(column_values(&table, "point")[7], "{x: -7, label: p, }");
+
+        // Limit stops reading after the stripe that reaches it.
+        let table = read_file(
+            &OrcReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(None, Some(3), None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(column_values(&table, "id"), ["0", "1", "2"]);
+
+        // Stripes select a subset of the file.
+        let table = read_file(
+            &OrcReadOptions {
+                stripes: Some(vec![1, 0]),
+            },
+            &uri,
+            ReadPushdowns::new(Some(&["id"]), None, None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(
+            column_values(&table, "id"),
+            ["6", "7", "0", "1", "2", "3", "4", "5"]
+        );
+
+        // Predicate columns don't have to be in the projection.
+        let table = read_file(
+            &OrcReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(Some(&["name"]), None, Some(col("id").gt_eq(lit(5i64)))),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["name"]);
+        assert_eq!(column_values(&table, "name"), ["ccc", "None", "bb"]);
+
+        // Stripes that the statistics rule out are skipped.
+        let table = read_file(
+            &OrcReadOptions::default(),
+            &uri,
+            ReadPushdowns::new(None, None, Some(col("category").eq(lit("z")))),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.len(), 0);
+        assert_eq!(table.num_columns(), 12);
+
+        let tables = read_files(
+            vec![
+                (
+                    uri.as_str(),
+                    OrcReadOptions {
+                        stripes: Some(vec![1]),
+                    },
+                ),
+                (uri.as_str(), OrcReadOptions::default()),
+            ],
+            ReadPushdowns::new(Some(&["id"]), None, None),
+            io_client.clone(),
+            None,
+            2,
+            true,
+        )?;
+        assert_eq!(
+            tables.iter().map(|t| t.len()).collect::<Vec<_>>(),
+            vec![2, 8]
+        );
+
+        assert!(read_file(
+            &OrcReadOptions {
+                stripes: Some(vec![2]),
+            },
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )
+        .is_err());
+        Ok(())
+    }
+}
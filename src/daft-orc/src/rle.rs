@@ -0,0 +1,417 @@
+//! Decoders of the run-length encodings of ORC streams.
+//!
+//! See: https://orc.apache.org/specification/ORCv1/#run-length-encoding
+
+use crate::{
+    decode_error,
+    proto::{read_varint, zigzag_decode},
+};
+
+/// Version of the run-length encoding of integers, which depends on the encoding of the column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RleVersion {
+    V1,
+    V2,
+}
+
+fn next_byte(buf: &mut &[u8]) -> crate::Result<u8> {
+    let Some((&byte, rest)) = buf.split_first() else {
+        return Err(decode_error("unexpected end of run-length encoded stream"));
+    };
+    *buf = rest;
+    Ok(byte)
+}
+
+fn check_len<T>(values: &mut Vec<T>, n: usize) -> crate::Result<()> {
+    if values.len() < n {
+        return Err(decode_error(format!(
+            "expected {n} values in run-length encoded stream, found {}",
+            values.len()
+        )));
+    }
+    values.truncate(n);
+    Ok(())
+}
+
+/// Decodes `n` bytes of a byte run-length encoded stream, made up of runs of 3 to 130 copies of a byte and of literal
+/// sequences of 1 to 128 bytes.
+pub(crate) fn decode_bytes(mut buf: &[u8], n: usize) -> crate::Result<Vec<u8>> {
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n && !buf.is_empty() {
+        let header = next_byte(&mut buf)? as i8;
+        if header >= 0 {
+            let byte = next_byte(&mut buf)?;
+            values.extend(std::iter::repeat(byte).take(header as usize + 3));
+        } else {
+            let len = header.unsigned_abs() as usize;
+            if buf.len() < len {
+                return Err(decode_error("truncated literal run of bytes"));
+            }
+            values.extend_from_slice(&buf[..len]);
+            buf = &buf[len..];
+        }
+    }
+    check_len(&mut values, n)?;
+    Ok(values)
+}
+
+/// Decodes `n` booleans, which are packed into bytes from their most significant bits and then byte run-length encoded.
+pub(crate) fn decode_booleans(buf: &[u8], n: usize) -> crate::Result<Vec<bool>> {
+    let bytes = decode_bytes(buf, n.div_ceil(8))?;
+    Ok((0..n)
+        .map(|i| bytes[i / 8] & (0x80 >> (i % 8)) != 0)
+        .collect())
+}
+
+/// Decodes `n` integers of an integer run-length encoded stream. Unsigned integers are returned as their bits.
+pub(crate) fn decode_integers(
+    buf: &[u8],
+    n: usize,
+    signed: bool,
+    version: RleVersion,
+) -> crate::Result<Vec<i64>> {
+    match version {
+        RleVersion::V1 => decode_integers_v1(buf, n, signed),
+        RleVersion::V2 => decode_integers_v2(buf, n, signed),
+    }
+}
+
+fn read_integer_varint(buf: &mut &[u8], signed: bool) -> crate::Result<i64> {
+    let value = read_varint(buf)?;
+    Ok(if signed {
+        zigzag_decode(value)
+    } else {
+        value as i64
+    })
+}
+
+fn decode_integers_v1(mut buf: &[u8], n: usize, signed: bool) -> crate::Result<Vec<i64>> {
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n && !buf.is_empty() {
+        let header = next_byte(&mut buf)? as i8;
+        if header >= 0 {
+            // Runs of 3 to 130 values that differ by a constant delta.
+            let delta = next_byte(&mut buf)? as i8 as i64;
+            let base = read_integer_varint(&mut buf, signed)?;
+            values.extend((0..header as i64 + 3).map(|i| base.wrapping_add(i.wrapping_mul(delta))));
+        } else {
+            for _ in 0..header.unsigned_abs() {
+                values.push(read_integer_varint(&mut buf, signed)?);
+            }
+        }
+    }
+    check_len(&mut values, n)?;
+    Ok(values)
+}
+
+/// Returns the bit width of the values of a run of the version 2 encoding from its 5-bit encoded width.
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// Returns the smallest bit width that can be encoded in a run of the version 2 encoding and holds `bits` bits.
+fn closest_fixed_bits(bits: usize) -> usize {
+    match bits {
+        0 => 1,
+        1..=24 => bits,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Unpacks `len` big-endian values of `width` bits, which start at a byte boundary. Returns the values and consumes
+/// the bytes that hold them.
+fn unpack_bits(buf: &mut &[u8], len: usize, width: usize) -> crate::Result<Vec<u64>> {
+    let num_bytes = (len * width).div_ceil(8);
+    if buf.len() < num_bytes {
+        return Err(decode_error("truncated bit-packed run of integers"));
+    }
+    let (bytes, rest) = buf.split_at(num_bytes);
+    *buf = rest;
+    let mut values = Vec::with_capacity(len);
+    let mut bit = 0;
+    for _ in 0..len {
+        let mut value = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let byte = bytes[bit / 8];
+            let available = 8 - bit % 8;
+            let taken = available.min(remaining);
+            let bits = (byte as u64 >> (available - taken)) & ((1u64 << taken) - 1);
+            value = (value << taken) | bits;
+            remaining -= taken;
+            bit += taken;
+        }
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Reads a big-endian unsigned integer of `width` bytes.
+fn read_be_bytes(buf: &mut &[u8], width: usize) -> crate::Result<u64> {
+    if buf.len() < width || width > 8 {
+        return Err(decode_error("truncated big-endian integer"));
+    }
+    let value = buf[..width]
+        .iter()
+        .fold(0u64, |value, byte| (value << 8) | *byte as u64);
+    *buf = &buf[width..];
+    Ok(value)
+}
+
+fn decode_integers_v2(mut buf: &[u8], n: usize, signed: bool) -> crate::Result<Vec<i64>> {
+    let decode = |value: u64| {
+        if signed {
+            zigzag_decode(value)
+        } else {
+            value as i64
+        }
+    };
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n && !buf.is_empty() {
+        let header = next_byte(&mut buf)?;
+        match header >> 6 {
+            // Short repeat: a run of 3 to 10 copies of a value of 1 to 8 bytes.
+            0 => {
+                let width = ((header >> 3) & 0x7) as usize + 1;
+                let count = (header & 0x7) as usize + 3;
+                let value = decode(read_be_bytes(&mut buf, width)?);
+                values.extend(std::iter::repeat(value).take(count));
+            }
+            // Direct: up to 512 bit-packed values.
+            1 => {
+                let width = decode_bit_width((header >> 1) & 0x1f);
+                let len = ((((header & 1) as usize) << 8) | next_byte(&mut buf)? as usize) + 1;
+                values.extend(unpack_bits(&mut buf, len, width)?.into_iter().map(decode));
+            }
+            // Patched base: bit-packed offsets from a base value, with the high bits of outliers patched in.
+            2 => {
+                let width = decode_bit_width((header >> 1) & 0x1f);
+                let len = ((((header & 1) as usize) << 8) | next_byte(&mut buf)? as usize) + 1;
+                let third = next_byte(&mut buf)?;
+                let base_width = ((third >> 5) & 0x7) as usize + 1;
+                let patch_width = decode_bit_width(third & 0x1f);
+                let fourth = next_byte(&mut buf)?;
+                let patch_gap_width = ((fourth >> 5) & 0x7) as usize + 1;
+                let patch_list_len = (fourth & 0x1f) as usize;
+                if patch_width + patch_gap_width > 64 {
+                    return Err(decode_error("patch of patched base run is too wide"));
+                }
+
+                // The base is stored in sign-magnitude form.
+                let base = read_be_bytes(&mut buf, base_width)?;
+                let sign_mask = 1u64 << (base_width * 8 - 1);
+                let base = if base & sign_mask != 0 {
+                    -((base & !sign_mask) as i64)
+                } else {
+                    base as i64
+                };
+                let mut offsets = unpack_bits(&mut buf, len, width)?;
+                let patches = unpack_bits(
+                    &mut buf,
+                    patch_list_len,
+                    closest_fixed_bits(patch_width + patch_gap_width),
+                )?;
+                let patch_mask = if patch_width == 64 {
+                    u64::MAX
+                } else {
+                    (1u64 << patch_width) - 1
+                };
+                let mut position = 0;
+                for patch in patches {
+                    let gap = (patch >> patch_width) as usize;
+                    let patch = patch & patch_mask;
+                    position += gap;
+                    // Gaps longer than 255 are split into entries of 255 without patches.
+                    if gap == 255 && patch == 0 {
+                        continue;
+                    }
+                    let offset = offsets.get_mut(position).ok_or_else(|| {
+                        decode_error("patch of patched base run is out of bounds")
+                    })?;
+                    *offset |= patch.checked_shl(width as u32).unwrap_or(0);
+                }
+                values.extend(
+                    offsets
+                        .into_iter()
+                        .map(|offset| base.wrapping_add(offset as i64)),
+                );
+            }
+            // Delta: a base value followed by bit-packed deltas, whose direction is that of the first delta.
+            _ => {
+                let code = (header >> 1) & 0x1f;
+                let len = ((((header & 1) as usize) << 8) | next_byte(&mut buf)? as usize) + 1;
+                let base = read_integer_varint(&mut buf, signed)?;
+                let delta_base = zigzag_decode(read_varint(&mut buf)?);
+                values.push(base);
+                if code == 0 {
+                    // All deltas are equal to the delta base.
+                    let mut value = base;
+                    for _ in 1..len {
+                        value = value.wrapping_add(delta_base);
+                        values.push(value);
+                    }
+                } else if len > 1 {
+                    let mut value = base.wrapping_add(delta_base);
+                    values.push(value);
+                    let deltas = unpack_bits(&mut buf, len - 2, decode_bit_width(code))?;
+                    for delta in deltas {
+                        value = if delta_base < 0 {
+                            value.wrapping_sub(delta as i64)
+                        } else {
+                            value.wrapping_add(delta as i64)
+                        };
+                        values.push(value);
+                    }
+                }
+            }
+        }
+    }
+    check_len(&mut values, n)?;
+    Ok(values)
+}
+
+/// Reads a zigzag-encoded varint of up to 128 bits, which decimal values are encoded as.
+pub(crate) fn read_i128_varint(buf: &mut &[u8]) -> crate::Result<i128> {
+    let mut value = 0u128;
+    for shift in (0..128).step_by(7) {
+        let byte = next_byte(buf)?;
+        value |= ((byte & 0x7f) as u128) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value >> 1) as i128 ^ -((value & 1) as i128));
+        }
+    }
+    Err(decode_error("decimal value doesn't fit in 128 bits"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_booleans, decode_bytes, decode_integers, read_i128_varint, RleVersion};
+
+    // Examples of the encodings from the ORC specification.
+
+    #[test]
+    fn test_decode_bytes() {
+        assert_eq!(decode_bytes(&[0x61, 0x00], 100).unwrap(), vec![0; 100]);
+        assert_eq!(
+            decode_bytes(&[0xfe, 0x44, 0x45], 2).unwrap(),
+            vec![0x44, 0x45]
+        );
+        assert!(decode_bytes(&[0xfe, 0x44], 2).is_err());
+    }
+
+    #[test]
+    fn test_decode_booleans() {
+        assert_eq!(
+            decode_booleans(&[0xff, 0x80], 8).unwrap(),
+            vec![true, false, false, false, false, false, false, false]
+        );
+        assert_eq!(
+            decode_booleans(&[0xfe, 0xff, 0x40], 10).unwrap(),
+            vec![true, true, true, true, true, true, true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_decode_integers_v1() {
+        assert_eq!(
+            decode_integers(&[0x61, 0x00, 0x07], 100, false, RleVersion::V1).unwrap(),
+            vec![7; 100]
+        );
+        assert_eq!(
+            decode_integers(&[0x61, 0xff, 0x64], 100, false, RleVersion::V1).unwrap(),
+            (1..=100).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            decode_integers(
+                &[0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b],
+                5,
+                false,
+                RleVersion::V1
+            )
+            .unwrap(),
+            vec![2, 3, 6, 7, 11]
+        );
+        assert_eq!(
+            decode_integers(&[0xfe, 0x03, 0x04], 2, true, RleVersion::V1).unwrap(),
+            vec![-2, 2]
+        );
+    }
+
+    #[test]
+    fn test_decode_integers_v2() {
+        // Short repeat.
+        assert_eq!(
+            decode_integers(&[0x0a, 0x27, 0x10], 5, false, RleVersion::V2).unwrap(),
+            vec![10000; 5]
+        );
+        // Direct.
+        assert_eq!(
+            decode_integers(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4,
+                false,
+                RleVersion::V2
+            )
+            .unwrap(),
+            vec![23713, 43806, 57005, 48879]
+        );
+        // Patched base.
+        let mut expected = vec![2030, 2000, 2020, 1000000];
+        expected.extend((2040..=2190).step_by(10));
+        assert_eq!(
+            decode_integers(
+                &[
+                    0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+                    0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+                    0xfc, 0xe8
+                ],
+                20,
+                false,
+                RleVersion::V2
+            )
+            .unwrap(),
+            expected
+        );
+        // Delta.
+        assert_eq!(
+            decode_integers(
+                &[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46],
+                10,
+                false,
+                RleVersion::V2
+            )
+            .unwrap(),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        // Delta with a fixed negative delta, of signed values.
+        assert_eq!(
+            decode_integers(&[0xc0, 0x03, 0x02, 0x03], 4, true, RleVersion::V2).unwrap(),
+            vec![1, -1, -3, -5]
+        );
+    }
+
+    #[test]
+    fn test_read_i128_varint() {
+        let mut buf: &[u8] = &[0x03, 0x80, 0x01];
+        assert_eq!(read_i128_varint(&mut buf).unwrap(), -2);
+        assert_eq!(read_i128_varint(&mut buf).unwrap(), 64);
+        assert!(buf.is_empty());
+    }
+}
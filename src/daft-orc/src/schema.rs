@@ -0,0 +1,179 @@
+use daft_core::prelude::*;
+
+use crate::{
+    decode_error,
+    proto::{Type, TypeKind},
+};
+
+/// Precision and scale of decimals of files written by Hive 0.11, whose decimal types have neither.
+const DEFAULT_DECIMAL_PRECISION_AND_SCALE: (usize, usize) = (38, 18);
+
+fn get_type(types: &[Type], column: usize) -> crate::Result<&Type> {
+    types
+        .get(column)
+        .ok_or_else(|| decode_error(format!("column {column} is missing from the file's types")))
+}
+
+/// Returns the ids of the child columns of a column, which are the ids of their types.
+pub(crate) fn children(types: &[Type], column: usize) -> crate::Result<Vec<usize>> {
+    let children = get_type(types, column)?
+        .subtypes
+        .iter()
+        .map(|c| *c as usize)
+        .collect::<Vec<_>>();
+    // Types are listed in pre-order, so children always follow their parents.
+    if children.iter().any(|c| *c <= column || *c >= types.len()) {
+        return Err(decode_error(format!(
+            "column {column} has invalid children {children:?}"
+        )));
+    }
+    Ok(children)
+}
+
+/// Returns the ids of a column and of all of its descendants, which are the columns that have to be read to decode it.
+pub(crate) fn column_subtree(types: &[Type], column: usize) -> crate::Result<Vec<usize>> {
+    let mut columns = vec![column];
+    for child in children(types, column)? {
+        columns.extend(column_subtree(types, child)?);
+    }
+    Ok(columns)
+}
+
+/// Returns the Daft type of the column with id `column`.
+///
+/// Timestamps are read with microsecond precision, so that the far-off dates that are common in Hive tables, such as
+/// `9999-12-31`, are in the range of the type.
+pub(crate) fn to_daft_dtype(types: &[Type], column: usize) -> crate::Result<DataType> {
+    let ty = get_type(types, column)?;
+    let children = children(types, column)?;
+    let child_dtype = |i: usize| -> crate::Result<DataType> {
+        let child = children.get(i).ok_or_else(|| {
+            decode_error(format!(
+                "{:?} column {column} is missing child {i}",
+                ty.kind
+            ))
+        })?;
+        to_daft_dtype(types, *child)
+    };
+    Ok(match ty.kind {
+        TypeKind::Boolean => DataType::Boolean,
+        TypeKind::Byte => DataType::Int8,
+        TypeKind::Short => DataType::Int16,
+        TypeKind::Int => DataType::Int32,
+        TypeKind::Long => DataType::Int64,
+        TypeKind::Float => DataType::Float32,
+        TypeKind::Double => DataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => DataType::Utf8,
+        TypeKind::Binary => DataType::Binary,
+        TypeKind::Date => DataType::Date,
+        TypeKind::Timestamp => DataType::Timestamp(TimeUnit::Microseconds, None),
+        TypeKind::TimestampInstant => {
+            DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()))
+        }
+        TypeKind::Decimal => {
+            let (precision, scale) = match ty.precision {
+                Some(precision) if precision > 0 => {
+                    (precision as usize, ty.scale.unwrap_or(0) as usize)
+                }
+                _ => DEFAULT_DECIMAL_PRECISION_AND_SCALE,
+            };
+            DataType::Decimal128(precision, scale)
+        }
+        TypeKind::List => DataType::List(Box::new(child_dtype(0)?)),
+        TypeKind::Map => DataType::Map(Box::new(DataType::Struct(vec![
+            Field::new("key", child_dtype(0)?),
+            Field::new("value", child_dtype(1)?),
+        ]))),
+        TypeKind::Struct => DataType::Struct(
+            struct_fields(types, column)?
+                .into_iter()
+                .map(|(_, field)| field)
+                .collect(),
+        ),
+        TypeKind::Union => {
+            return Err(crate::Error::UnsupportedType {
+                kind: "union".to_string(),
+            })
+        }
+    })
+}
+
+/// Returns the fields of a struct column, as pairs of their column ids and Daft fields.
+pub(crate) fn struct_fields(types: &[Type], column: usize) -> crate::Result<Vec<(usize, Field)>> {
+    let ty = get_type(types, column)?;
+    let children = children(types, column)?;
+    if ty.field_names.len() != children.len() {
+        return Err(decode_error(format!(
+            "struct column {column} has {} field names for {} fields",
+            ty.field_names.len(),
+            children.len()
+        )));
+    }
+    children
+        .into_iter()
+        .zip(&ty.field_names)
+        .map(|(child, name)| Ok((child, Field::new(name, to_daft_dtype(types, child)?))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::struct_fields;
+    use crate::proto::{Type, TypeKind};
+
+    fn make_type(kind: TypeKind, subtypes: Vec<u32>, field_names: Vec<&str>) -> Type {
+        Type {
+            kind,
+            subtypes,
+            field_names: field_names.into_iter().map(String::from).collect(),
+            precision: None,
+            scale: None,
+        }
+    }
+
+    #[test]
+    fn test_struct_fields() {
+        let types = vec![
+            make_type(TypeKind::Struct, vec![1, 2, 4], vec!["id", "tags", "attrs"]),
+            make_type(TypeKind::Long, vec![], vec![]),
+            make_type(TypeKind::List, vec![3], vec![]),
+            make_type(TypeKind::Varchar, vec![], vec![]),
+            make_type(TypeKind::Map, vec![5, 6], vec![]),
+            make_type(TypeKind::String, vec![], vec![]),
+            Type {
+                precision: Some(10),
+                scale: Some(2),
+                ..make_type(TypeKind::Decimal, vec![], vec![])
+            },
+        ];
+        let fields = struct_fields(&types, 0).unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                (1, Field::new("id", DataType::Int64)),
+                (
+                    2,
+                    Field::new("tags", DataType::List(Box::new(DataType::Utf8)))
+                ),
+                (
+                    4,
+                    Field::new(
+                        "attrs",
+                        DataType::Map(Box::new(DataType::Struct(vec![
+                            Field::new("key", DataType::Utf8),
+                            Field::new("value", DataType::Decimal128(10, 2)),
+                        ])))
+                    )
+                ),
+            ]
+        );
+
+        let types = vec![
+            make_type(TypeKind::Struct, vec![1], vec!["u"]),
+            make_type(TypeKind::Union, vec![], vec![]),
+        ];
+        assert!(struct_fields(&types, 0).is_err());
+    }
+}
@@ -0,0 +1,267 @@
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_stats::{ColumnRangeStatistics, TableStatistics};
+use indexmap::IndexMap;
+
+use crate::{
+    metadata::OrcMetadata,
+    proto::{ColumnStatistics, TypeKind, TypeStatistics},
+};
+
+/// Converts the column statistics of a stripe into the statistics of its top-level columns. Columns whose type isn't
+/// comparable, or whose statistics are missing from the file, get missing statistics.
+pub fn stripe_statistics_to_table_stats(
+    metadata: &OrcMetadata,
+    stripe: usize,
+) -> DaftResult<TableStatistics> {
+    let stripe_statistics = metadata.stripe_statistics.get(stripe);
+    let columns = metadata
+        .schema()
+        .fields
+        .values()
+        .zip(&metadata.column_ids)
+        .map(|(field, column)| {
+            let stats = stripe_statistics
+                .and_then(|stats| stats.get(*column))
+                .filter(|_| ColumnRangeStatistics::supports_dtype(&field.dtype))
+                .and_then(|stats| {
+                    column_statistics_to_column_range_statistics(
+                        stats,
+                        metadata.footer.types[*column].kind,
+                        &field.dtype,
+                    )
+                    .ok()
+                })
+                .unwrap_or(ColumnRangeStatistics::Missing);
+            (field.name.clone(), stats)
+        })
+        .collect::<IndexMap<_, _>>();
+    Ok(TableStatistics { columns })
+}
+
+fn column_statistics_to_column_range_statistics(
+    stats: &ColumnStatistics,
+    kind: TypeKind,
+    dtype: &DataType,
+) -> DaftResult<ColumnRangeStatistics> {
+    let (lower, upper) = match &stats.type_statistics {
+        Some(TypeStatistics::Integer {
+            minimum: Some(minimum),
+            maximum: Some(maximum),
+        }) => (
+            Int64Array::from(("lower", [*minimum].as_slice()))
+                .into_series()
+                .cast(dtype)?,
+            Int64Array::from(("upper", [*maximum].as_slice()))
+                .into_series()
+                .cast(dtype)?,
+        ),
+        Some(TypeStatistics::Double {
+            minimum: Some(minimum),
+            maximum: Some(maximum),
+        }) if !minimum.is_nan() && !maximum.is_nan() => (
+            Float64Array::from(("lower", [*minimum].as_slice()))
+                .into_series()
+                .cast(dtype)?,
+            Float64Array::from(("upper", [*maximum].as_slice()))
+                .into_series()
+                .cast(dtype)?,
+        ),
+        // Long strings are truncated to lower and upper bounds in place of the minimum and maximum.
+        Some(TypeStatistics::String {
+            minimum,
+            maximum,
+            lower_bound,
+            upper_bound,
+        }) => match (
+            minimum.as_ref().or(lower_bound.as_ref()),
+            maximum.as_ref().or(upper_bound.as_ref()),
+        ) {
+            (Some(lower), Some(upper)) => (
+                Utf8Array::from(("lower", [lower.as_str()].as_slice())).into_series(),
+                Utf8Array::from(("upper", [upper.as_str()].as_slice())).into_series(),
+            ),
+            _ => return Ok(ColumnRangeStatistics::Missing),
+        },
+        Some(TypeStatistics::Bucket {
+            true_count: Some(true_count),
+        }) => {
+            let Some(number_of_values) = stats.number_of_values.filter(|n| *n > 0) else {
+                return Ok(ColumnRangeStatistics::Missing);
+            };
+            let lower = *true_count == number_of_values;
+            let upper = *true_count > 0;
+            (
+                BooleanArray::from(("lower", [lower].as_slice())).into_series(),
+                BooleanArray::from(("upper", [upper].as_slice())).into_series(),
+            )
+        }
+        Some(TypeStatistics::Decimal {
+            minimum: Some(minimum),
+            maximum: Some(maximum),
+        }) => {
+            let DataType::Decimal128(_, scale) = dtype else {
+                return Ok(ColumnRangeStatistics::Missing);
+            };
+            let (Some(lower), Some(upper)) = (
+                parse_decimal(minimum, *scale),
+                parse_decimal(maximum, *scale),
+            ) else {
+                return Ok(ColumnRangeStatistics::Missing);
+            };
+            let make_series = |name: &str, value: i128| {
+                Decimal128Array::new(
+                    Field::new(name, dtype.clone()),
+                    Int128Array::from((name, [value].as_slice())),
+                )
+                .into_series()
+            };
+            (make_series("lower", lower), make_series("upper", upper))
+        }
+        Some(TypeStatistics::Date {
+            minimum: Some(minimum),
+            maximum: Some(maximum),
+        }) => (
+            DateArray::new(
+                Field::new("lower", DataType::Date),
+                Int32Array::from(("lower", [*minimum].as_slice())),
+            )
+            .into_series(),
+            DateArray::new(
+                Field::new("upper", DataType::Date),
+                Int32Array::from(("upper", [*maximum].as_slice())),
+            )
+            .into_series(),
+        ),
+        // Timestamp statistics are in milliseconds, so the upper bound is widened to the end of its millisecond. Only
+        // the statistics of instants are used, since those of wall clock timestamps depend on the writer's time zone.
+        Some(TypeStatistics::Timestamp {
+            minimum_utc: Some(minimum),
+            maximum_utc: Some(maximum),
+        }) if kind == TypeKind::TimestampInstant => {
+            let (Some(lower), Some(upper)) = (
+                minimum.checked_mul(1000),
+                maximum
+                    .checked_mul(1000)
+                    .and_then(|upper| upper.checked_add(999)),
+            ) else {
+                return Ok(ColumnRangeStatistics::Missing);
+            };
+            (
+                TimestampArray::new(
+                    Field::new("lower", dtype.clone()),
+                    Int64Array::from(("lower", [lower].as_slice())),
+                )
+                .into_series(),
+                TimestampArray::new(
+                    Field::new("upper", dtype.clone()),
+                    Int64Array::from(("upper", [upper].as_slice())),
+                )
+                .into_series(),
+            )
+        }
+        _ => return Ok(ColumnRangeStatistics::Missing),
+    };
+    Ok(ColumnRangeStatistics::new(Some(lower), Some(upper))?)
+}
+
+/// Parses the string form of a decimal into its unscaled value at `scale`, returning `None` if it's malformed or
+/// doesn't fit.
+fn parse_decimal(value: &str, scale: usize) -> Option<i128> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    // Statistics are exact, so a fraction with more digits than the scale can only have trailing zeros past it.
+    let (fraction, rest) = fraction.split_at(fraction.len().min(scale));
+    if rest.chars().any(|c| c != '0') {
+        return None;
+    }
+    let mut unscaled = 0i128;
+    let padding = std::iter::repeat('0').take(scale - fraction.len());
+    for c in integer.chars().chain(fraction.chars()).chain(padding) {
+        unscaled = unscaled
+            .checked_mul(10)?
+            .checked_add(c.to_digit(10)? as i128)?;
+    }
+    Some(if negative { -unscaled } else { unscaled })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_io::{IOClient, IOConfig};
+    use daft_stats::ColumnRangeStatistics;
+
+    use super::{parse_decimal, stripe_statistics_to_table_stats};
+    use crate::{proto::CompressionKind, read_orc_metadata, test::write_test_file};
+
+    fn bounds(stats: &ColumnRangeStatistics) -> Option<(String, String)> {
+        match stats {
+            ColumnRangeStatistics::Loaded(lower, upper) => {
+                Some((lower.str_value(0).unwrap(), upper.str_value(0).unwrap()))
+            }
+            ColumnRangeStatistics::Missing => None,
+        }
+    }
+
+    #[test]
+    fn test_stripe_statistics() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = write_test_file(&dir, CompressionKind::None);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let metadata =
+            tokio::runtime::Runtime::new()?.block_on(read_orc_metadata(&uri, io_client, None))?;
+        assert_eq!(metadata.num_stripes(), 2);
+        assert_eq!(metadata.num_rows(), 8);
+
+        let stats = stripe_statistics_to_table_stats(&metadata, 0)?;
+        let column_bounds = |name: &str| bounds(&stats.columns[name]);
+        let some = |lower: &str, upper: &str| Some((lower.to_string(), upper.to_string()));
+        assert_eq!(column_bounds("id"), some("0", "5"));
+        assert_eq!(column_bounds("name"), some("bb", "ccc"));
+        assert_eq!(column_bounds("category"), some("x", "y"));
+        assert_eq!(column_bounds("flag"), some("false", "true"));
+        assert_eq!(column_bounds("score"), some("0", "2.5"));
+        assert_eq!(column_bounds("price"), some("1.25", "50.00"));
+        assert_eq!(column_bounds("day"), some("1969-12-30", "1970-01-04"));
+        assert_eq!(
+            column_bounds("ts_utc"),
+            some(
+                "2023-11-14 22:13:20 +00:00",
+                "2023-11-14 22:13:25.000999 +00:00"
+            )
+        );
+        // Wall clock timestamps and nested types don't have statistics.
+        assert_eq!(column_bounds("ts"), None);
+        assert_eq!(column_bounds("tags"), None);
+        assert_eq!(column_bounds("point"), None);
+
+        let stats = stripe_statistics_to_table_stats(&metadata, 1)?;
+        assert_eq!(bounds(&stats.columns["id"]), some("6", "7"));
+        assert_eq!(bounds(&stats.columns["price"]), some("8.75", "75.00"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("12.5", 2), Some(1250));
+        assert_eq!(parse_decimal("-0.05", 3), Some(-50));
+        assert_eq!(parse_decimal("7", 0), Some(7));
+        assert_eq!(parse_decimal("1.500", 1), Some(15));
+        assert_eq!(parse_decimal("1.55", 1), None);
+        assert_eq!(parse_decimal("1e3", 0), None);
+        assert_eq!(parse_decimal("", 2), None);
+    }
+}
@@ -0,0 +1,670 @@
+//! A minimal ORC writer for tests, which writes stripes of rows with uncompressed or zlib compressed streams, version 1
+//! run-length encodings, and stripe statistics.
+
+use std::io::Write;
+
+use crate::proto::CompressionKind;
+
+/// Seconds between the Unix epoch and 2015-01-01 00:00:00.
+const TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+#[derive(Debug, Clone)]
+pub(crate) enum TestType {
+    Boolean,
+    Long,
+    Double,
+    String,
+    /// A string column with dictionary encoding.
+    DictionaryString,
+    Decimal(u32, u32),
+    Date,
+    Timestamp,
+    TimestampInstant,
+    List(Box<TestType>),
+    Map(Box<TestType>, Box<TestType>),
+    Struct(Vec<(&'static str, TestType)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Boolean(bool),
+    Long(i64),
+    Double(f64),
+    String(&'static str),
+    /// An unscaled decimal and its scale.
+    Decimal(i128, u32),
+    Date(i32),
+    /// Seconds since the epoch and nanoseconds.
+    Timestamp(i64, u32),
+    List(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Struct(Vec<Value>),
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Builder of an encoded protobuf message.
+#[derive(Default)]
+struct Message(Vec<u8>);
+
+impl Message {
+    fn varint(mut self, number: u64, value: u64) -> Self {
+        write_varint(&mut self.0, number << 3);
+        write_varint(&mut self.0, value);
+        self
+    }
+
+    fn sint(self, number: u64, value: i64) -> Self {
+        self.varint(number, zigzag_encode(value))
+    }
+
+    fn double(mut self, number: u64, value: f64) -> Self {
+        write_varint(&mut self.0, (number << 3) | 1);
+        self.0.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    fn bytes(mut self, number: u64, value: &[u8]) -> Self {
+        write_varint(&mut self.0, (number << 3) | 2);
+        write_varint(&mut self.0, value.len() as u64);
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    fn message(self, number: u64, value: Message) -> Self {
+        self.bytes(number, &value.0)
+    }
+}
+
+fn encode_integers(values: &[i64], signed: bool) -> Vec<u8> {
+    let mut buf = vec![];
+    for chunk in values.chunks(128) {
+        buf.push((-(chunk.len() as i64)) as u8);
+        for value in chunk {
+            write_varint(
+                &mut buf,
+                if signed {
+                    zigzag_encode(*value)
+                } else {
+                    *value as u64
+                },
+            );
+        }
+    }
+    buf
+}
+
+fn encode_bytes(values: &[u8]) -> Vec<u8> {
+    let mut buf = vec![];
+    for chunk in values.chunks(128) {
+        buf.push((-(chunk.len() as i64)) as u8);
+        buf.extend_from_slice(chunk);
+    }
+    buf
+}
+
+fn encode_booleans(values: &[bool]) -> Vec<u8> {
+    let bytes = values
+        .chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, v)| byte | ((*v as u8) << (7 - i)))
+        })
+        .collect::<Vec<_>>();
+    encode_bytes(&bytes)
+}
+
+fn compress(compression: CompressionKind, data: &[u8]) -> Vec<u8> {
+    match compression {
+        CompressionKind::None => data.to_vec(),
+        CompressionKind::Zlib => {
+            let mut encoder =
+                flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+            encoder.write_all(data).unwrap();
+            let compressed = encoder.finish().unwrap();
+            let (chunk, is_original) = if compressed.len() < data.len() {
+                (compressed.as_slice(), 0)
+            } else {
+                (data, 1)
+            };
+            let header = ((chunk.len() as u32) << 1) | is_original;
+            let mut buf = header.to_le_bytes()[..3].to_vec();
+            buf.extend_from_slice(chunk);
+            buf
+        }
+        _ => unreachable!("test files are uncompressed or zlib compressed"),
+    }
+}
+
+/// A column of the file, with its values in the current stripe.
+struct Column {
+    ty: TestType,
+    children: Vec<usize>,
+    present: Vec<bool>,
+    values: Vec<Value>,
+}
+
+fn add_columns(columns: &mut Vec<Column>, ty: &TestType) -> usize {
+    let id = columns.len();
+    columns.push(Column {
+        ty: ty.clone(),
+        children: vec![],
+        present: vec![],
+        values: vec![],
+    });
+    let children = match ty {
+        TestType::List(child) => vec![add_columns(columns, child)],
+        TestType::Map(key, value) => vec![add_columns(columns, key), add_columns(columns, value)],
+        TestType::Struct(fields) => fields
+            .iter()
+            .map(|(_, ty)| add_columns(columns, ty))
+            .collect(),
+        _ => vec![],
+    };
+    columns[id].children = children;
+    id
+}
+
+fn add_value(columns: &mut [Column], id: usize, value: &Value) {
+    columns[id].present.push(value != &Value::Null);
+    let children = columns[id].children.clone();
+    match value {
+        Value::Null => return,
+        Value::List(values) => {
+            for value in values {
+                add_value(columns, children[0], value);
+            }
+        }
+        Value::Map(entries) => {
+            for (key, value) in entries {
+                add_value(columns, children[0], key);
+                add_value(columns, children[1], value);
+            }
+        }
+        Value::Struct(values) => {
+            for (child, value) in children.iter().zip(values) {
+                add_value(columns, *child, value);
+            }
+        }
+        _ => {}
+    }
+    columns[id].values.push(value.clone());
+}
+
+fn format_decimal(unscaled: i128, scale: u32) -> String {
+    let digits = unscaled.unsigned_abs().to_string();
+    let digits = format!("{digits:0>width$}", width = scale as usize + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale as usize);
+    let sign = if unscaled < 0 { "-" } else { "" };
+    if fraction.is_empty() {
+        format!("{sign}{integer}")
+    } else {
+        format!("{sign}{integer}.{fraction}")
+    }
+}
+
+impl Column {
+    /// Encodes the streams of the column, as pairs of their kinds and bytes, and its encoding.
+    fn encode(&self) -> (Vec<(u64, Vec<u8>)>, Message) {
+        const PRESENT: u64 = 0;
+        const DATA: u64 = 1;
+        const LENGTH: u64 = 2;
+        const DICTIONARY_DATA: u64 = 3;
+        const SECONDARY: u64 = 5;
+
+        let mut streams = vec![];
+        if self.present.contains(&false) {
+            streams.push((PRESENT, encode_booleans(&self.present)));
+        }
+        let mut encoding = Message::default().varint(1, 0);
+        let lengths = |lengths: Vec<i64>| (LENGTH, encode_integers(&lengths, false));
+        match &self.ty {
+            TestType::Boolean => {
+                let values = self
+                    .values
+                    .iter()
+                    .map(|v| matches!(v, Value::Boolean(true)))
+                    .collect::<Vec<_>>();
+                streams.push((DATA, encode_booleans(&values)));
+            }
+            TestType::Long | TestType::Date => {
+                let values = self
+                    .values
+                    .iter()
+                    .map(|v| match v {
+                        Value::Long(v) => *v,
+                        Value::Date(v) => *v as i64,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>();
+                streams.push((DATA, encode_integers(&values, true)));
+            }
+            TestType::Double => {
+                let mut data = vec![];
+                for value in &self.values {
+                    let Value::Double(v) = value else {
+                        unreachable!()
+                    };
+                    data.extend_from_slice(&v.to_le_bytes());
+                }
+                streams.push((DATA, data));
+            }
+            TestType::String => {
+                let mut data = vec![];
+                let mut lens = vec![];
+                for value in &self.values {
+                    let Value::String(v) = value else {
+                        unreachable!()
+                    };
+                    data.extend_from_slice(v.as_bytes());
+                    lens.push(v.len() as i64);
+                }
+                streams.push((DATA, data));
+                streams.push(lengths(lens));
+            }
+            TestType::DictionaryString => {
+                let mut dictionary = self
+                    .values
+                    .iter()
+                    .map(|v| match v {
+                        Value::String(v) => *v,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>();
+                dictionary.sort_unstable();
+                dictionary.dedup();
+                let indices = self
+                    .values
+                    .iter()
+                    .map(|v| match v {
+                        Value::String(v) => dictionary.binary_search(v).unwrap() as i64,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<_>>();
+                streams.push((DATA, encode_integers(&indices, false)));
+                streams.push((DICTIONARY_DATA, dictionary.concat().into_bytes()));
+                streams.push(lengths(dictionary.iter().map(|v| v.len() as i64).collect()));
+                encoding = Message::default()
+                    .varint(1, 1)
+                    .varint(2, dictionary.len() as u64);
+            }
+            TestType::Decimal(..) => {
+                let mut data = vec![];
+                let mut scales = vec![];
+                for value in &self.values {
+                    let Value::Decimal(unscaled, scale) = value else {
+                        unreachable!()
+                    };
+                    let zigzag = ((*unscaled << 1) ^ (*unscaled >> 127)) as u128;
+                    let mut v = zigzag;
+                    while v >= 0x80 {
+                        data.push((v as u8) | 0x80);
+                        v >>= 7;
+                    }
+                    data.push(v as u8);
+                    scales.push(*scale as i64);
+                }
+                streams.push((DATA, data));
+                streams.push((SECONDARY, encode_integers(&scales, true)));
+            }
+            TestType::Timestamp | TestType::TimestampInstant => {
+                let mut seconds = vec![];
+                let mut nanos = vec![];
+                for value in &self.values {
+                    let Value::Timestamp(secs, ns) = value else {
+                        unreachable!()
+                    };
+                    // Writers truncate the seconds of times before the epoch towards zero.
+                    let mut secs = *secs;
+                    if secs < 0 && *ns > 999_999 {
+                        secs += 1;
+                    }
+                    seconds.push(secs - TIMESTAMP_BASE_SECONDS);
+                    // Nanoseconds with at least 2 trailing zeros store the number of zeros minus 1 in their 3
+                    // lowest bits.
+                    let (mut trimmed, mut zeros) = (*ns as i64, 0);
+                    while trimmed != 0 && trimmed % 10 == 0 && zeros < 8 {
+                        trimmed /= 10;
+                        zeros += 1;
+                    }
+                    nanos.push(if zeros >= 2 {
+                        (trimmed << 3) | (zeros - 1)
+                    } else {
+                        (*ns as i64) << 3
+                    });
+                }
+                streams.push((DATA, encode_integers(&seconds, true)));
+                streams.push((SECONDARY, encode_integers(&nanos, false)));
+            }
+            TestType::List(_) | TestType::Map(..) => {
+                let lens = self
+                    .values
+                    .iter()
+                    .map(|v| match v {
+                        Value::List(v) => v.len() as i64,
+                        Value::Map(v) => v.len() as i64,
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                streams.push(lengths(lens));
+            }
+            TestType::Struct(_) => {}
+        }
+        (streams, encoding)
+    }
+
+    fn statistics(&self) -> Message {
+        let stats = Message::default()
+            .varint(1, self.values.len() as u64)
+            .varint(10, self.present.contains(&false) as u64);
+        if self.values.is_empty() {
+            return stats;
+        }
+        let ordered = |values: Vec<f64>| {
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            (min, max)
+        };
+        match &self.ty {
+            TestType::Boolean => {
+                let true_count = self
+                    .values
+                    .iter()
+                    .filter(|v| matches!(v, Value::Boolean(true)))
+                    .count();
+                stats.message(5, Message::default().varint(1, true_count as u64))
+            }
+            TestType::Long => {
+                let values = self.values.iter().map(|v| match v {
+                    Value::Long(v) => *v,
+                    _ => unreachable!(),
+                });
+                let (min, max) = (values.clone().min().unwrap(), values.max().unwrap());
+                stats.message(2, Message::default().sint(1, min).sint(2, max))
+            }
+            TestType::Double => {
+                let (min, max) = ordered(
+                    self.values
+                        .iter()
+                        .map(|v| match v {
+                            Value::Double(v) => *v,
+                            _ => unreachable!(),
+                        })
+                        .collect(),
+                );
+                stats.message(3, Message::default().double(1, min).double(2, max))
+            }
+            TestType::String | TestType::DictionaryString => {
+                let values = self.values.iter().map(|v| match v {
+                    Value::String(v) => *v,
+                    _ => unreachable!(),
+                });
+                let (min, max) = (values.clone().min().unwrap(), values.max().unwrap());
+                stats.message(
+                    4,
+                    Message::default()
+                        .bytes(1, min.as_bytes())
+                        .bytes(2, max.as_bytes()),
+                )
+            }
+            TestType::Decimal(_, scale) => {
+                let values = self.values.iter().map(|v| match v {
+                    Value::Decimal(unscaled, value_scale) => {
+                        unscaled * 10i128.pow(scale - value_scale)
+                    }
+                    _ => unreachable!(),
+                });
+                let (min, max) = (values.clone().min().unwrap(), values.max().unwrap());
+                stats.message(
+                    6,
+                    Message::default()
+                        .bytes(1, format_decimal(min, *scale).as_bytes())
+                        .bytes(2, format_decimal(max, *scale).as_bytes()),
+                )
+            }
+            TestType::Date => {
+                let values = self.values.iter().map(|v| match v {
+                    Value::Date(v) => *v as i64,
+                    _ => unreachable!(),
+                });
+                let (min, max) = (values.clone().min().unwrap(), values.max().unwrap());
+                stats.message(7, Message::default().sint(1, min).sint(2, max))
+            }
+            TestType::Timestamp | TestType::TimestampInstant => {
+                let values = self.values.iter().map(|v| match v {
+                    Value::Timestamp(secs, nanos) => secs * 1000 + (*nanos / 1_000_000) as i64,
+                    _ => unreachable!(),
+                });
+                let (min, max) = (values.clone().min().unwrap(), values.max().unwrap());
+                stats.message(
+                    9,
+                    Message::default()
+                        .sint(1, min)
+                        .sint(2, max)
+                        .sint(3, min)
+                        .sint(4, max),
+                )
+            }
+            TestType::List(_) | TestType::Map(..) | TestType::Struct(_) => stats,
+        }
+    }
+}
+
+fn type_messages(columns: &[Column]) -> Vec<Message> {
+    columns
+        .iter()
+        .map(|column| {
+            let kind = match &column.ty {
+                TestType::Boolean => 0,
+                TestType::Long => 4,
+                TestType::Double => 6,
+                TestType::String | TestType::DictionaryString => 7,
+                TestType::Timestamp => 9,
+                TestType::List(_) => 10,
+                TestType::Map(..) => 11,
+                TestType::Struct(_) => 12,
+                TestType::Decimal(..) => 14,
+                TestType::Date => 15,
+                TestType::TimestampInstant => 18,
+            };
+            let mut message = Message::default().varint(1, kind);
+            for child in &column.children {
+                message = message.varint(2, *child as u64);
+            }
+            match &column.ty {
+                TestType::Struct(fields) => {
+                    for (name, _) in fields {
+                        message = message.bytes(3, name.as_bytes());
+                    }
+                }
+                TestType::Decimal(precision, scale) => {
+                    message = message
+                        .varint(5, *precision as u64)
+                        .varint(6, *scale as u64);
+                }
+                _ => {}
+            }
+            message
+        })
+        .collect()
+}
+
+/// Writes an ORC file with a stripe per element of `stripes`, which are lists of rows of the values of `fields`.
+pub(crate) fn write_orc(
+    path: &std::path::Path,
+    fields: &[(&'static str, TestType)],
+    stripes: &[Vec<Vec<Value>>],
+    compression: CompressionKind,
+) {
+    let mut file = b"ORC".to_vec();
+    let mut stripe_infos = vec![];
+    let mut stripe_statistics = Message::default();
+    let mut types = vec![];
+    for rows in stripes {
+        let mut columns = vec![];
+        add_columns(&mut columns, &TestType::Struct(fields.to_vec()));
+        for row in rows {
+            add_value(&mut columns, 0, &Value::Struct(row.clone()));
+        }
+        types = type_messages(&columns);
+
+        // A row index stream, which readers skip, precedes the data streams.
+        let offset = file.len();
+        let index = compress(compression, &[0; 16]);
+        file.extend_from_slice(&index);
+        let mut footer = Message::default().message(
+            1,
+            Message::default()
+                .varint(1, 6)
+                .varint(2, 0)
+                .varint(3, index.len() as u64),
+        );
+        let mut encodings = vec![];
+        for (id, column) in columns.iter().enumerate() {
+            let (streams, encoding) = column.encode();
+            for (kind, data) in streams {
+                let data = compress(compression, &data);
+                file.extend_from_slice(&data);
+                footer = footer.message(
+                    1,
+                    Message::default()
+                        .varint(1, kind)
+                        .varint(2, id as u64)
+                        .varint(3, data.len() as u64),
+                );
+            }
+            encodings.push(encoding);
+        }
+        for encoding in encodings {
+            footer = footer.message(2, encoding);
+        }
+        let data_length = file.len() - offset - index.len();
+        let footer = compress(compression, &footer.0);
+        file.extend_from_slice(&footer);
+        stripe_infos.push(
+            Message::default()
+                .varint(1, offset as u64)
+                .varint(2, index.len() as u64)
+                .varint(3, data_length as u64)
+                .varint(4, footer.len() as u64)
+                .varint(5, rows.len() as u64),
+        );
+        stripe_statistics = stripe_statistics.message(
+            1,
+            columns.iter().fold(Message::default(), |message, column| {
+                message.message(1, column.statistics())
+            }),
+        );
+    }
+
+    let metadata = compress(compression, &stripe_statistics.0);
+    file.extend_from_slice(&metadata);
+    let num_rows = stripes.iter().map(|rows| rows.len()).sum::<usize>();
+    let mut footer = Message::default()
+        .varint(1, 3)
+        .varint(2, file.len() as u64 - 3);
+    for stripe in stripe_infos {
+        footer = footer.message(3, stripe);
+    }
+    for ty in types {
+        footer = footer.message(4, ty);
+    }
+    let footer = compress(compression, &footer.varint(6, num_rows as u64).0);
+    file.extend_from_slice(&footer);
+    let compression = match compression {
+        CompressionKind::None => 0,
+        CompressionKind::Zlib => 1,
+        _ => unreachable!(),
+    };
+    let postscript = Message::default()
+        .varint(1, footer.len() as u64)
+        .varint(2, compression)
+        .varint(3, 256 * 1024)
+        .varint(5, metadata.len() as u64)
+        .bytes(8000, b"ORC");
+    file.extend_from_slice(&postscript.0);
+    file.push(postscript.0.len() as u8);
+    std::fs::write(path, file).unwrap();
+}
+
+/// Fields of the test file, with a column of each kind of type.
+fn test_fields() -> Vec<(&'static str, TestType)> {
+    vec![
+        ("id", TestType::Long),
+        ("name", TestType::String),
+        ("category", TestType::DictionaryString),
+        ("flag", TestType::Boolean),
+        ("score", TestType::Double),
+        ("price", TestType::Decimal(10, 2)),
+        ("day", TestType::Date),
+        ("ts", TestType::Timestamp),
+        ("ts_utc", TestType::TimestampInstant),
+        ("tags", TestType::List(Box::new(TestType::Long))),
+        (
+            "attrs",
+            TestType::Map(Box::new(TestType::String), Box::new(TestType::Double)),
+        ),
+        (
+            "point",
+            TestType::Struct(vec![("x", TestType::Long), ("label", TestType::String)]),
+        ),
+    ]
+}
+
+/// Values of row `i` of the test file, with nulls at different rows in each column.
+fn test_row(i: i64) -> Vec<Value> {
+    let null_every = |n: i64, value: Value| if i % n == 0 { Value::Null } else { value };
+    vec![
+        Value::Long(i),
+        null_every(3, Value::String(["a", "bb", "ccc"][i as usize % 3])),
+        Value::String(["x", "y"][i as usize % 2]),
+        null_every(4, Value::Boolean(i % 2 == 0)),
+        Value::Double(i as f64 / 2.0),
+        // Decimals have their own scales, which differ from the scale of the column.
+        null_every(5, Value::Decimal(i as i128 * 125, (i % 2) as u32 + 1)),
+        Value::Date(i as i32 - 2),
+        Value::Timestamp(i - 3, [123_456_000, 500_000_000, 0][i as usize % 3]),
+        Value::Timestamp(1_700_000_000 + i, 0),
+        null_every(
+            4,
+            Value::List((0..i % 3).map(|j| Value::Long(i * 10 + j)).collect()),
+        ),
+        Value::Map(
+            (0..i % 3)
+                .map(|j| (Value::String("k"), null_every(2, Value::Double(j as f64))))
+                .collect(),
+        ),
+        null_every(
+            3,
+            Value::Struct(vec![Value::Long(-i), null_every(2, Value::String("p"))]),
+        ),
+    ]
+}
+
+/// Writes a test file with a stripe of 6 rows and a stripe of 2 rows.
+pub(crate) fn write_test_file(dir: &tempfile::TempDir, compression: CompressionKind) -> String {
+    let path = dir.path().join("test.orc");
+    write_orc(
+        &path,
+        &test_fields(),
+        &[
+            (0..6).map(test_row).collect(),
+            (6..8).map(test_row).collect(),
+        ],
+        compression,
+    );
+    path.to_string_lossy().to_string()
+}
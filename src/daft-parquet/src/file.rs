@@ -21,6 +21,7 @@ use snafu::ResultExt;
 
 use crate::{
     metadata::read_parquet_metadata,
+    page_decoding_error,
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
     statistics,
@@ -41,23 +42,43 @@ pub(crate) struct ParquetReaderBuilder {
     schema_inference_options: ParquetSchemaInferenceOptions,
     predicate: Option<ExprRef>,
     chunk_size: Option<usize>,
+    verify_checksums: bool,
 }
 use parquet2::read::decompress;
 
 fn streaming_decompression<S: futures::Stream<Item = parquet2::error::Result<CompressedPage>>>(
     input: S,
+    uri: String,
 ) -> impl futures::Stream<Item = parquet2::error::Result<Page>> {
     async_stream::stream! {
         let mut buffer = vec![];
 
         for await compressed_page in input {
-            let compressed_page = compressed_page?;
+            let compressed_page = compressed_page.map_err(|err| with_object_path(err, &uri))?;
             yield decompress(compressed_page, &mut buffer);
         }
         drop(buffer);
     }
 }
 
+/// Converts page checksum mismatches into a [`daft_io::Error::ChecksumMismatch`] that names the corrupted object,
+/// wrapped in an IO error to survive the conversion into arrow2 errors, see [`crate::unwrap_checksum_mismatch`].
+fn with_object_path(err: parquet2::error::Error, uri: &str) -> parquet2::error::Error {
+    match err {
+        parquet2::error::Error::ChecksumMismatch { expected, actual } => {
+            parquet2::error::Error::IoError(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                daft_io::Error::ChecksumMismatch {
+                    path: uri.to_string(),
+                    expected: format!("page CRC32 {expected:08x}"),
+                    actual: format!("{actual:08x}"),
+                },
+            ))
+        }
+        err => err,
+    }
+}
+
 pub struct StreamIterator<S> {
     curr: Option<Page>,
     src: tokio::sync::Mutex<S>,
@@ -204,6 +225,7 @@ impl ParquetReaderBuilder {
         let size = io_client
             .single_url_get_size(uri.into(), io_stats.clone())
             .await?;
+        let verify_checksums = io_client.verify_checksums(uri);
         let metadata =
             read_parquet_metadata(uri, size, io_client, io_stats, field_id_mapping).await?;
         Ok(ParquetReaderBuilder {
@@ -216,6 +238,7 @@ impl ParquetReaderBuilder {
             schema_inference_options: Default::default(),
             predicate: None,
             chunk_size: None,
+            verify_checksums,
         })
     }
 
@@ -293,6 +316,7 @@ impl ParquetReaderBuilder {
             arrow_schema,
             row_ranges,
             self.chunk_size,
+            self.verify_checksums,
         )
     }
 }
@@ -310,6 +334,7 @@ pub(crate) struct ParquetFileReader {
     arrow_schema: arrow2::datatypes::SchemaRef,
     row_ranges: Arc<Vec<RowGroupRange>>,
     chunk_size: Option<usize>,
+    verify_checksums: bool,
 }
 
 impl ParquetFileReader {
@@ -325,6 +350,7 @@ impl ParquetFileReader {
         arrow_schema: arrow2::datatypes::Schema,
         row_ranges: Vec<RowGroupRange>,
         chunk_size: Option<usize>,
+        verify_checksums: bool,
     ) -> super::Result<Self> {
         Ok(ParquetFileReader {
             uri,
@@ -332,6 +358,7 @@ impl ParquetFileReader {
             arrow_schema: arrow_schema.into(),
             row_ranges: Arc::new(row_ranges),
             chunk_size,
+            verify_checksums,
         })
     }
 
@@ -405,6 +432,7 @@ impl ParquetFileReader {
         )?);
 
         let chunk_size = self.chunk_size.unwrap_or(Self::DEFAULT_CHUNK_SIZE);
+        let verify_checksums = self.verify_checksums;
         let (senders, receivers): (Vec<_>, Vec<_>) = self
             .row_ranges
             .iter()
@@ -473,14 +501,17 @@ impl ParquetFileReader {
                                             vec![],
                                             Arc::new(|_, _| true),
                                             Self::MAX_HEADER_SIZE,
+                                            verify_checksums,
                                         )
                                         .with_context(
                                             |_| UnableToCreateParquetPageStreamSnafu::<String> {
                                                 path: uri.clone(),
                                             },
                                         )?;
-                                    let page_stream =
-                                        streaming_decompression(compressed_page_stream);
+                                    let page_stream = streaming_decompression(
+                                        compressed_page_stream,
+                                        uri.clone(),
+                                    );
                                     let pinned_stream = Box::pin(page_stream);
                                     decompressed_iters
                                         .push(StreamIterator::new(pinned_stream, rt_handle.clone()))
@@ -492,7 +523,8 @@ impl ParquetFileReader {
                                     Some(chunk_size),
                                     num_rows,
                                     num_values,
-                                )?;
+                                )
+                                .map_err(page_decoding_error)?;
                                 Ok(arr_iter)
                             })
                         });
@@ -548,6 +580,7 @@ impl ParquetFileReader {
         ranges: Arc<RangesContainer>,
     ) -> DaftResult<Table> {
         let metadata = self.metadata;
+        let verify_checksums = self.verify_checksums;
         let all_handles = self
             .arrow_schema
             .fields
@@ -623,13 +656,17 @@ impl ParquetFileReader {
                                         vec![],
                                         Arc::new(|_, _| true),
                                         Self::MAX_HEADER_SIZE,
+                                        verify_checksums,
                                     )
                                     .with_context(|_| {
                                         UnableToCreateParquetPageStreamSnafu::<String> {
                                             path: owned_uri.clone(),
                                         }
                                     })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
+                                let page_stream = streaming_decompression(
+                                    compressed_page_stream,
+                                    owned_uri.clone(),
+                                );
                                 let pinned_stream = Box::pin(page_stream);
                                 decompressed_iters
                                     .push(StreamIterator::new(pinned_stream, rt_handle.clone()))
@@ -650,8 +687,8 @@ impl ParquetFileReader {
                                     let mut all_arrays = vec![];
                                     let mut curr_index = 0;
 
-                                    for arr in arr_iter? {
-                                        let arr = arr?;
+                                    for arr in arr_iter.map_err(page_decoding_error)? {
+                                        let arr = arr.map_err(page_decoding_error)?;
                                         if (curr_index + arr.len()) < row_range.start {
                                             // throw arrays less than what we need
                                             curr_index += arr.len();
@@ -734,6 +771,7 @@ impl ParquetFileReader {
         ranges: Arc<RangesContainer>,
     ) -> DaftResult<(Vec<Vec<Box<dyn arrow2::array::Array>>>, usize)> {
         let metadata = self.metadata;
+        let verify_checksums = self.verify_checksums;
         let all_handles = self
             .arrow_schema
             .fields
@@ -807,13 +845,17 @@ impl ParquetFileReader {
                                         vec![],
                                         Arc::new(|_, _| true),
                                         Self::MAX_HEADER_SIZE,
+                                        verify_checksums,
                                     )
                                     .with_context(|_| {
                                         UnableToCreateParquetPageStreamSnafu::<String> {
                                             path: owned_uri.clone(),
                                         }
                                     })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
+                                let page_stream = streaming_decompression(
+                                    compressed_page_stream,
+                                    owned_uri.clone(),
+                                );
                                 let pinned_stream = Box::pin(page_stream);
                                 decompressed_iters
                                     .push(StreamIterator::new(pinned_stream, rt_handle.clone()))
@@ -834,8 +876,8 @@ impl ParquetFileReader {
                                     let mut all_arrays = vec![];
                                     let mut curr_index = 0;
 
-                                    for arr in arr_iter? {
-                                        let arr = arr?;
+                                    for arr in arr_iter.map_err(page_decoding_error)? {
+                                        let arr = arr.map_err(page_decoding_error)?;
                                         if (curr_index + arr.len()) < row_range.start {
                                             // throw arrays less than what we need
                                             curr_index += arr.len();
@@ -218,4 +218,33 @@ impl From<daft_io::Error> for Error {
     }
 }
 
+/// Unwraps the [`daft_io::Error::ChecksumMismatch`] of a corrupted page from the arrow2 IO error it was carried in
+/// while decoding pages, so that callers can match on it. Returns any other error unchanged.
+pub(crate) fn unwrap_checksum_mismatch(
+    err: arrow2::error::Error,
+) -> std::result::Result<daft_io::Error, arrow2::error::Error> {
+    match err {
+        arrow2::error::Error::Io(io_err)
+            if io_err
+                .get_ref()
+                .is_some_and(|inner| inner.is::<daft_io::Error>()) =>
+        {
+            let inner = io_err
+                .into_inner()
+                .and_then(|inner| inner.downcast::<daft_io::Error>().ok())
+                .expect("IO error should wrap a daft_io::Error");
+            Ok(*inner)
+        }
+        err => Err(err),
+    }
+}
+
+/// Converts an arrow2 error raised while decoding pages into a [`DaftError`], see [`unwrap_checksum_mismatch`].
+pub(crate) fn page_decoding_error(err: arrow2::error::Error) -> DaftError {
+    match unwrap_checksum_mismatch(err) {
+        Ok(err) => err.into(),
+        Err(err) => err.into(),
+    }
+}
+
 type Result<T, E = Error> = std::result::Result<T, E>;
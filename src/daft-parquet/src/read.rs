@@ -902,6 +902,27 @@ pub fn read_parquet_schema(
     Ok((schema, metadata))
 }
 
+pub fn read_parquet_schema_bulk(
+    uris: &[&str],
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    schema_inference_options: ParquetSchemaInferenceOptions,
+    field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+) -> DaftResult<Vec<Schema>> {
+    let runtime_handle = get_runtime(true)?;
+    let metadatas = runtime_handle.block_on_current_thread(async {
+        read_parquet_metadata_bulk(uris, io_client, io_stats, field_id_mapping).await
+    })?;
+    metadatas
+        .iter()
+        .map(|metadata| {
+            let arrow_schema =
+                infer_schema_with_options(metadata, &Some(schema_inference_options.into()))?;
+            Schema::try_from(&arrow_schema)
+        })
+        .collect()
+}
+
 pub async fn read_parquet_metadata(
     uri: &str,
     io_client: Arc<IOClient>,
@@ -1110,6 +1131,37 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_parquet_read_from_memory() -> DaftResult<()> {
+        let file = "memory://daft-parquet-tests/mvp.parquet";
+        let data = std::fs::read(get_local_parquet_path())?;
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let runtime_handle = daft_io::get_runtime(true)?;
+        runtime_handle.block_on_current_thread(io_client.single_url_put(
+            file,
+            data.into(),
+            None,
+        ))?;
+
+        let table = read_parquet(
+            file,
+            None,
+            None,
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            true,
+            Default::default(),
+            None,
+        )?;
+        assert_eq!(table.len(), 100);
+
+        Ok(())
+    }
+
     #[test]
     fn test_file_metadata_serialize_roundtrip() -> DaftResult<()> {
         let file = get_local_parquet_path();
@@ -77,8 +77,12 @@ pub(crate) fn arrow_column_iters_to_table_iter(
     let mut index_so_far = 0;
     let owned_schema_ref = schema_ref.clone();
     par_lock_step_iter.into_iter().map(move |chunk| {
-        let chunk = chunk.with_context(|_| {
-            super::UnableToCreateChunkFromStreamingFileReaderSnafu { path: uri.clone() }
+        let chunk = chunk.map_err(|source| match super::unwrap_checksum_mismatch(source) {
+            Ok(err) => err.into(),
+            Err(source) => super::Error::UnableToCreateChunkFromStreamingFileReader {
+                path: uri.clone(),
+                source,
+            },
         })?;
         let all_series = chunk
             .into_iter()
@@ -10,8 +10,9 @@ use crate::{
     partitioning::{
         HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec,
     },
+    plan_cache::{global_plan_result_cache, plan_fingerprint, substitute_globally_cached_results},
     sink_info::{OutputFileInfo, SinkInfo},
-    source_info::SourceInfo,
+    source_info::{InMemoryInfo, SourceInfo},
     LogicalPlanRef,
 };
 use common_daft_config::DaftPlanningConfig;
@@ -29,7 +30,6 @@ use daft_scan::{PhysicalScanInfo, Pushdowns, ScanOperatorRef};
 #[cfg(feature = "python")]
 use {
     crate::sink_info::{CatalogInfo, IcebergCatalogInfo},
-    crate::source_info::InMemoryInfo,
     common_daft_config::PyDaftPlanningConfig,
     daft_core::python::schema::PySchema,
     daft_dsl::python::PyExpr,
@@ -480,6 +480,29 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Cache the materialized results of this builder's plan, keyed by the plan's fingerprint, so that later plans
+    /// containing an identical subtree scan the cached results instead of recomputing them.
+    ///
+    /// Returns a builder that scans the cached results.
+    pub fn cache(&self, results: InMemoryInfo) -> DaftResult<Self> {
+        if let Some(fingerprint) = plan_fingerprint(&self.plan)? {
+            global_plan_result_cache()
+                .lock()
+                .unwrap()
+                .insert(fingerprint, results.clone());
+        }
+        let logical_plan: LogicalPlan =
+            logical_ops::Source::new(self.plan.schema(), SourceInfo::InMemory(results).into())
+                .into();
+        Ok(Self::new(logical_plan.into(), self.config.clone()))
+    }
+
+    /// Substitute the subtrees of this builder's plan that have cached results with scans of those results.
+    pub fn with_cached_results(&self) -> DaftResult<Self> {
+        let plan = substitute_globally_cached_results(self.plan.clone())?.data;
+        Ok(Self::new(plan, self.config.clone()))
+    }
+
     pub fn build(&self) -> Arc<LogicalPlan> {
         self.plan.clone()
     }
@@ -794,6 +817,39 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.schema().into())
     }
 
+    pub fn cache(
+        &self,
+        partition_key: &str,
+        cache_entry: &PyAny,
+        num_partitions: usize,
+        size_bytes: usize,
+        num_rows: usize,
+    ) -> PyResult<Self> {
+        let results = InMemoryInfo::new(
+            self.builder.schema(),
+            partition_key.into(),
+            cache_entry.to_object(cache_entry.py()),
+            num_partitions,
+            size_bytes,
+            num_rows,
+            None,
+        );
+        Ok(self.builder.cache(results)?.into())
+    }
+
+    #[staticmethod]
+    pub fn set_result_cache_capacity_bytes(capacity_bytes: usize) {
+        global_plan_result_cache()
+            .lock()
+            .unwrap()
+            .set_capacity_bytes(capacity_bytes);
+    }
+
+    #[staticmethod]
+    pub fn clear_result_cache() {
+        global_plan_result_cache().lock().unwrap().clear();
+    }
+
     /// Optimize the underlying logical plan, returning a new plan builder containing the optimized plan.
     pub fn optimize(&self, py: Python) -> PyResult<Self> {
         py.allow_threads(|| {
@@ -802,8 +858,8 @@ impl PyLogicalPlanBuilder {
             let optimizer_config = OptimizerConfig { enable_actor_pool_projections: self.builder.config.as_ref().map(|planning_cfg| planning_cfg.enable_actor_pool_projections).unwrap_or(default_optimizer_config.enable_actor_pool_projections), ..default_optimizer_config };
             let optimizer = Optimizer::new(optimizer_config);
 
-            // Run LogicalPlan optimizations
-            let unoptimized_plan = self.builder.build();
+            // Substitute previously cached results, then run LogicalPlan optimizations
+            let unoptimized_plan = self.builder.with_cached_results()?.build();
             let optimized_plan = optimizer.optimize(
                 unoptimized_plan,
                 |new_plan, rule_batch, pass, transformed, seen| {
@@ -33,6 +33,7 @@ impl TreeDisplay for crate::physical_plan::PhysicalPlan {
             crate::PhysicalPlan::Unpivot(unpivot) => unpivot.display_as(level),
             crate::PhysicalPlan::Sort(sort) => sort.display_as(level),
             crate::PhysicalPlan::Split(split) => split.display_as(level),
+            crate::PhysicalPlan::SkewSplit(split) => split.display_as(level),
             crate::PhysicalPlan::Sample(sample) => sample.display_as(level),
             crate::PhysicalPlan::MonotonicallyIncreasingId(id) => id.display_as(level),
             crate::PhysicalPlan::Coalesce(coalesce) => coalesce.display_as(level),
@@ -50,6 +51,7 @@ impl TreeDisplay for crate::physical_plan::PhysicalPlan {
             crate::PhysicalPlan::TabularWriteParquet(write) => write.display_as(level),
             crate::PhysicalPlan::TabularWriteJson(write) => write.display_as(level),
             crate::PhysicalPlan::TabularWriteCsv(write) => write.display_as(level),
+            crate::PhysicalPlan::TabularWriteIpc(write) => write.display_as(level),
             #[cfg(feature = "python")]
             crate::PhysicalPlan::IcebergWrite(write) => write.display_as(level),
             #[cfg(feature = "python")]
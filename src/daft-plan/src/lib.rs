@@ -13,6 +13,7 @@ pub mod physical_ops;
 mod physical_optimization;
 mod physical_plan;
 mod physical_planner;
+mod plan_cache;
 mod sink_info;
 pub mod source_info;
 #[cfg(test)]
@@ -29,6 +30,10 @@ pub use physical_planner::{
     logical_to_physical, populate_aggregation_stages, AdaptivePlanner, MaterializedResults,
     QueryStageOutput,
 };
+pub use plan_cache::{
+    global_plan_result_cache, plan_fingerprint, substitute_globally_cached_results,
+    PlanFingerprint, PlanResultCache, DEFAULT_PLAN_RESULT_CACHE_SIZE_BYTES,
+};
 pub use sink_info::{OutputFileInfo, SinkInfo};
 pub use source_info::{FileInfo, FileInfos, InMemoryInfo, SourceInfo};
 
@@ -39,7 +44,9 @@ pub use sink_info::{DeltaLakeCatalogInfo, IcebergCatalogInfo, LanceCatalogInfo};
 #[cfg(feature = "python")]
 use {
     daft_scan::file_format::{
-        CsvSourceConfig, JsonSourceConfig, ParquetSourceConfig, PyFileFormatConfig,
+        AvroSourceConfig, CsvSourceConfig, FilesSourceConfig, FixedWidthSourceConfig,
+        IpcSourceConfig, JsonSourceConfig, OrcSourceConfig, ParquetSourceConfig,
+        PyFileFormatConfig, TextSourceConfig, XlsxSourceConfig,
     },
     daft_scan::storage_config::{NativeStorageConfig, PyStorageConfig, PythonStorageConfig},
 };
@@ -53,6 +60,13 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<PyFileFormatConfig>()?;
     parent.add_class::<ParquetSourceConfig>()?;
     parent.add_class::<JsonSourceConfig>()?;
+    parent.add_class::<IpcSourceConfig>()?;
+    parent.add_class::<AvroSourceConfig>()?;
+    parent.add_class::<OrcSourceConfig>()?;
+    parent.add_class::<XlsxSourceConfig>()?;
+    parent.add_class::<TextSourceConfig>()?;
+    parent.add_class::<FixedWidthSourceConfig>()?;
+    parent.add_class::<FilesSourceConfig>()?;
     parent.add_class::<CsvSourceConfig>()?;
     parent.add_class::<DatabaseSourceConfig>()?;
     parent.add_class::<FileInfos>()?;
@@ -7,8 +7,8 @@ use crate::LogicalPlan;
 use super::{
     logical_plan_tracker::LogicalPlanTracker,
     rules::{
-        DropRepartition, OptimizerRule, PushDownFilter, PushDownLimit, PushDownProjection,
-        SplitActorPoolProjects,
+        AnswerAggregationFromMetadata, DropRepartition, OptimizerRule, PushDownAggregation,
+        PushDownFilter, PushDownLimit, PushDownProjection, SplitActorPoolProjects,
     },
 };
 use common_treenode::Transformed;
@@ -121,6 +121,22 @@ impl Optimizer {
             RuleExecutionStrategy::FixedPoint(Some(3)),
         ));
 
+        // --- Aggregation pushdowns ---
+        // This runs once after the filter and projection pushdowns, since a partial Aggregate below a Join
+        // would otherwise block filters from being pushed through the Join.
+        rule_batches.push(RuleBatch::new(
+            vec![Box::new(PushDownAggregation::new())],
+            RuleExecutionStrategy::Once,
+        ));
+
+        // --- Metadata-only aggregations ---
+        // This runs once after the aggregation pushdowns, so that partial Aggregates pushed onto a Source can also be
+        // answered from its metadata. It materializes the Source's ScanTasks, so it shouldn't run repeatedly.
+        rule_batches.push(RuleBatch::new(
+            vec![Box::new(AnswerAggregationFromMetadata::new())],
+            RuleExecutionStrategy::Once,
+        ));
+
         // --- Limit pushdowns ---
         // This needs to be separate from PushDownProjection because otherwise the limit and
         // projection just keep swapping places, preventing optimization
@@ -0,0 +1,729 @@
+#[cfg(not(feature = "python"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{collections::HashSet, sync::Arc};
+
+use common_error::DaftResult;
+use daft_core::prelude::*;
+use daft_dsl::{col, AggExpr, Expr, ExprRef};
+use daft_scan::{
+    scan_task_iters::read_footer_metadata, DataSource, MaterializedScanOperator,
+    PartitionTransform, PhysicalScanInfo, ScanOperatorRef, ScanTask, ScanTaskRef,
+};
+use daft_stats::ColumnRangeStatistics;
+use daft_table::Table;
+use itertools::Itertools;
+
+#[cfg(not(feature = "python"))]
+use crate::source_info::InMemoryInfo;
+use crate::{
+    logical_ops::{Aggregate, Concat, Source},
+    physical_planner::populate_aggregation_stages,
+    source_info::SourceInfo,
+    LogicalPlan, LogicalPlanBuilder,
+};
+
+use super::OptimizerRule;
+use common_treenode::{Transformed, TreeNode};
+
+/// Optimization rule for answering aggregations over a scan from the scan's metadata.
+///
+/// Count(All), Min and Max aggregations over a Source without filter or limit pushdowns, that are either ungrouped or
+/// grouped by identity partition columns, can be computed from the row counts in each ScanTask's metadata, the column
+/// ranges in its statistics and the values in its partition spec, without reading any data. The partial aggregations
+/// computed from metadata are put into an in-memory Source, ScanTasks lacking the required metadata are still scanned,
+/// and the partial aggregations of both are then merged:
+///
+/// Aggregate-Source -> Project-Aggregate-Concat(InMemorySource, Aggregate-Source(remaining ScanTasks))
+///
+/// Note that this materializes the Source's ScanTasks during optimization, and reads the footers of the Parquet and ORC
+/// files they read, so it only applies to scan operators whose ScanTasks can carry metadata.
+#[derive(Default, Debug)]
+pub struct AnswerAggregationFromMetadata {}
+
+impl AnswerAggregationFromMetadata {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for AnswerAggregationFromMetadata {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_up(|node| self.try_optimize_node(node))
+    }
+}
+
+impl AnswerAggregationFromMetadata {
+    fn try_optimize_node(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            groupby,
+            ..
+        }) = plan.as_ref()
+        else {
+            return Ok(Transformed::no(plan));
+        };
+        let LogicalPlan::Source(Source {
+            output_schema,
+            source_info,
+        }) = input.as_ref()
+        else {
+            return Ok(Transformed::no(plan));
+        };
+        let SourceInfo::Physical(scan_info) = source_info.as_ref() else {
+            return Ok(Transformed::no(plan));
+        };
+        // Metadata only describes the full contents of the ScanTasks, and listing the ScanTasks is only worthwhile
+        // if they can have metadata.
+        if scan_info.pushdowns.filters.is_some()
+            || scan_info.pushdowns.limit.is_some()
+            || !scan_info.scan_op.0.can_provide_metadata()
+        {
+            return Ok(Transformed::no(plan));
+        }
+        let partition_cols = scan_info
+            .partitioning_keys
+            .iter()
+            .filter(|pfield| matches!(pfield.transform, None | Some(PartitionTransform::Identity)))
+            .map(|pfield| pfield.field.name.as_str())
+            .collect::<HashSet<_>>();
+        let Some(groupby_names) = groupby
+            .iter()
+            .map(|e| match e.as_ref() {
+                Expr::Column(name) if partition_cols.contains(name.as_ref()) => {
+                    Some(name.to_string())
+                }
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(Transformed::no(plan));
+        };
+        if aggregations.is_empty()
+            || !aggregations
+                .iter()
+                .all(|agg| is_answerable(agg, output_schema, &partition_cols))
+        {
+            return Ok(Transformed::no(plan));
+        }
+
+        let (first_stage_aggs, second_stage_aggs, final_exprs) =
+            populate_aggregation_stages(aggregations, output_schema, groupby);
+        let first_stage_aggs = first_stage_aggs
+            .into_iter()
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .collect::<Vec<_>>();
+
+        let Some((metadata_table, remaining_scan_tasks)) =
+            metadata_table(scan_info, &groupby_names, &first_stage_aggs, output_schema)?
+        else {
+            return Ok(Transformed::no(plan));
+        };
+        let metadata_plan = metadata_source(metadata_table)?.build();
+
+        let groupby = groupby_names.into_iter().map(col).collect::<Vec<_>>();
+        let first_stage_input = if remaining_scan_tasks.is_empty() {
+            metadata_plan
+        } else {
+            let remaining_scan_op = MaterializedScanOperator::new(
+                remaining_scan_tasks,
+                scan_info.source_schema.clone(),
+                scan_info.partitioning_keys.clone(),
+            );
+            let scanned_plan = LogicalPlanBuilder::table_scan(
+                ScanOperatorRef(Arc::new(remaining_scan_op)),
+                Some(scan_info.pushdowns.clone()),
+            )?
+            .aggregate(
+                first_stage_aggs
+                    .into_iter()
+                    .map(|(_, agg)| Expr::Agg(agg).into())
+                    .collect(),
+                groupby.clone(),
+            )?
+            .build();
+            LogicalPlan::from(Concat::try_new(metadata_plan, scanned_plan)?).into()
+        };
+        let new_plan = LogicalPlanBuilder::new(first_stage_input, None)
+            .aggregate(
+                second_stage_aggs
+                    .into_iter()
+                    .sorted_by(|(a, _), (b, _)| a.cmp(b))
+                    .map(|(_, agg)| Expr::Agg(agg).into())
+                    .collect(),
+                groupby,
+            )?
+            .select(final_exprs)?
+            .build();
+        Ok(Transformed::yes(new_plan))
+    }
+}
+
+/// Number of Parquet or ORC footers read at a time to fill in the metadata of ScanTasks.
+const NUM_PARALLEL_FOOTER_READS: usize = 128;
+
+/// Computes the group keys and first-stage aggregations of every ScanTask of the scan that has the required metadata,
+/// as a Table with a row per ScanTask, and returns it along with the remaining ScanTasks that must still be scanned.
+///
+/// Returns None if no ScanTask has the required metadata.
+fn metadata_table(
+    scan_info: &PhysicalScanInfo,
+    groupby: &[String],
+    first_stage_aggs: &[(Arc<str>, AggExpr)],
+    schema: &Schema,
+) -> DaftResult<Option<(Table, Vec<ScanTaskRef>)>> {
+    let scan_tasks = scan_info
+        .scan_op
+        .0
+        .to_scan_tasks(scan_info.pushdowns.clone())?
+        .collect::<DaftResult<Vec<_>>>()?;
+    let scan_tasks = read_footer_metadata(scan_tasks, NUM_PARALLEL_FOOTER_READS)?;
+    let mut metadata_rows = vec![];
+    let mut remaining_scan_tasks = vec![];
+    for scan_task in scan_tasks {
+        if scan_task.num_rows() == Some(0) {
+            // Empty ScanTasks don't contribute to any group.
+            continue;
+        }
+        match metadata_row(&scan_task, groupby, first_stage_aggs, schema)? {
+            Some(row) => metadata_rows.push(row),
+            None => remaining_scan_tasks.push(scan_task),
+        }
+    }
+    if metadata_rows.is_empty() {
+        return Ok(None);
+    }
+    let num_columns = groupby.len() + first_stage_aggs.len();
+    let columns = (0..num_columns)
+        .map(|i| Series::concat(&metadata_rows.iter().map(|row| &row[i]).collect::<Vec<_>>()))
+        .collect::<DaftResult<Vec<_>>>()?;
+    Ok(Some((
+        Table::from_nonempty_columns(columns)?,
+        remaining_scan_tasks,
+    )))
+}
+
+/// Creates an in-memory Source over a Table, by putting it into the current runner's partition set cache.
+///
+/// In-memory Sources are executed from the runner's cache, so this goes through Python the same way
+/// `DataFrame._from_tables` does.
+#[cfg(feature = "python")]
+fn metadata_source(table: Table) -> DaftResult<LogicalPlanBuilder> {
+    use daft_table::python::PyTable;
+    use pyo3::prelude::*;
+
+    let schema = table.schema.clone();
+    let num_rows = table.len();
+    let size_bytes = table.size_bytes()?;
+    Python::with_gil(|py| {
+        let micropartition = py
+            .import(pyo3::intern!(py, "daft.table"))?
+            .getattr(pyo3::intern!(py, "MicroPartition"))?
+            .call_method1(pyo3::intern!(py, "_from_pytable"), (PyTable::from(table),))?;
+        let partition_set = py
+            .import(pyo3::intern!(py, "daft.runners.pyrunner"))?
+            .getattr(pyo3::intern!(py, "LocalPartitionSet"))?
+            .call0()?;
+        partition_set.call_method1(
+            pyo3::intern!(py, "set_partition_from_table"),
+            (0, micropartition),
+        )?;
+        let cache_entry = py
+            .import(pyo3::intern!(py, "daft.context"))?
+            .call_method0(pyo3::intern!(py, "get_context"))?
+            .call_method0(pyo3::intern!(py, "runner"))?
+            .call_method1(
+                pyo3::intern!(py, "put_partition_set_into_cache"),
+                (partition_set,),
+            )?;
+        let cache_key: String = cache_entry.getattr(pyo3::intern!(py, "key"))?.extract()?;
+        LogicalPlanBuilder::in_memory_scan(
+            &cache_key,
+            cache_entry.into(),
+            schema,
+            1,
+            size_bytes,
+            num_rows,
+        )
+    })
+}
+
+/// Creates an in-memory Source over a Table.
+///
+/// Without Python there's no runner to hold the Table, so the Source only describes it, and can't be executed.
+#[cfg(not(feature = "python"))]
+fn metadata_source(table: Table) -> DaftResult<LogicalPlanBuilder> {
+    static METADATA_SOURCE_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let source_info = SourceInfo::InMemory(InMemoryInfo {
+        source_schema: table.schema.clone(),
+        cache_key: format!(
+            "metadata-{}",
+            METADATA_SOURCE_ID_COUNTER.fetch_add(1, Ordering::SeqCst)
+        ),
+        num_partitions: 1,
+        size_bytes: table.size_bytes()?,
+        num_rows: table.len(),
+        clustering_spec: None,
+    });
+    let plan: LogicalPlan = Source::new(table.schema.clone(), source_info.into()).into();
+    Ok(LogicalPlanBuilder::new(plan.into(), None))
+}
+
+/// Returns the name of the column that an aggregation's input refers to, if it's a (possibly aliased) column.
+fn agg_column(expr: &ExprRef) -> Option<&str> {
+    match expr.as_ref() {
+        Expr::Column(name) => Some(name.as_ref()),
+        Expr::Alias(expr, _) => agg_column(expr),
+        _ => None,
+    }
+}
+
+/// Whether an aggregation can be answered from ScanTask metadata.
+fn is_answerable(agg: &AggExpr, schema: &Schema, partition_cols: &HashSet<&str>) -> bool {
+    match agg {
+        AggExpr::Count(e, CountMode::All) => agg_column(e).is_some(),
+        AggExpr::Min(e) | AggExpr::Max(e) => match agg_column(e) {
+            // Partition values are exact, as are column statistics for types whose statistics are never truncated.
+            Some(name) if partition_cols.contains(name) => true,
+            Some(name) => schema.get_field(name).is_ok_and(|field| {
+                field.dtype.is_numeric()
+                    || field.dtype.is_temporal()
+                    || field.dtype == DataType::Boolean
+            }),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// Computes the group key values and first-stage aggregation values for a ScanTask from its metadata, returning None
+/// if the ScanTask lacks the metadata required to do so.
+fn metadata_row(
+    scan_task: &ScanTask,
+    groupby: &[String],
+    first_stage_aggs: &[(Arc<str>, AggExpr)],
+    schema: &Schema,
+) -> DaftResult<Option<Vec<Series>>> {
+    // Row counts and statistics don't account for rows deleted by Iceberg delete files.
+    if scan_task.pushdowns.filters.is_some()
+        || scan_task.num_rows().is_none()
+        || scan_task.sources.iter().any(DataSource::has_deletes)
+    {
+        return Ok(None);
+    }
+    let partition_value = |name: &str| {
+        scan_task
+            .partition_spec()
+            .and_then(|spec| spec.keys.get_column(name).ok())
+    };
+    let mut row = Vec::with_capacity(groupby.len() + first_stage_aggs.len());
+    for name in groupby {
+        let Some(value) = partition_value(name) else {
+            return Ok(None);
+        };
+        row.push(value.cast(&schema.get_field(name)?.dtype)?.rename(name));
+    }
+    for (id, agg) in first_stage_aggs {
+        let value = match agg {
+            AggExpr::Count(..) => {
+                UInt64Array::from((id.as_ref(), vec![scan_task.num_rows().unwrap() as u64]))
+                    .into_series()
+            }
+            AggExpr::Min(e) | AggExpr::Max(e) => {
+                let name = agg_column(e).unwrap();
+                if let Some(value) = partition_value(name) {
+                    value.clone()
+                } else if let Some(ColumnRangeStatistics::Loaded(lower, upper)) = scan_task
+                    .statistics
+                    .as_ref()
+                    .and_then(|stats| stats.columns.get(name))
+                {
+                    if matches!(agg, AggExpr::Min(..)) {
+                        lower.clone()
+                    } else {
+                        upper.clone()
+                    }
+                } else {
+                    return Ok(None);
+                }
+            }
+            _ => unreachable!("Only Count, Min and Max aggregations can be answered from metadata"),
+        };
+        row.push(value.cast(&agg.to_field(schema)?.dtype)?.rename(id));
+    }
+    Ok(Some(row))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_treenode::{TreeNode, TreeNodeRecursion};
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_scan::{
+        file_format::FileFormatConfig, storage_config::NativeStorageConfig,
+        storage_config::StorageConfig, DataSource, MaterializedScanOperator, PartitionField,
+        Pushdowns, ScanOperator, ScanOperatorRef, ScanTask, ScanTaskRef,
+    };
+    use daft_stats::{ColumnRangeStatistics, PartitionSpec, TableMetadata, TableStatistics};
+    use daft_table::Table;
+    use indexmap::IndexMap;
+    use itertools::Itertools;
+
+    use super::metadata_table;
+    use crate::{
+        logical_ops::{Aggregate, Source},
+        logical_optimization::{
+            rules::{AnswerAggregationFromMetadata, OptimizerRule},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        physical_planner::populate_aggregation_stages,
+        source_info::SourceInfo,
+        LogicalPlan, LogicalPlanBuilder,
+    };
+
+    fn schema() -> SchemaRef {
+        Arc::new(
+            Schema::new(vec![
+                Field::new("a", DataType::Int64),
+                Field::new("b", DataType::Int64),
+            ])
+            .unwrap(),
+        )
+    }
+
+    /// Create a ScanTask over a single file with the provided row count, range statistics for column "a", and
+    /// partition value for column "b".
+    fn scan_task(
+        num_rows: Option<usize>,
+        a_range: Option<(i64, i64)>,
+        b_partition: Option<i64>,
+    ) -> Arc<ScanTask> {
+        let statistics = a_range.map(|(lower, upper)| TableStatistics {
+            columns: IndexMap::from([(
+                "a".to_string(),
+                ColumnRangeStatistics::new(
+                    Some(Int64Array::from(("a", vec![lower])).into_series()),
+                    Some(Int64Array::from(("a", vec![upper])).into_series()),
+                )
+                .unwrap(),
+            )]),
+        });
+        let partition_spec = b_partition.map(|value| PartitionSpec {
+            keys: Table::from_nonempty_columns(vec![
+                Int64Array::from(("b", vec![value])).into_series()
+            ])
+            .unwrap(),
+        });
+        Arc::new(ScanTask::new(
+            vec![DataSource::File {
+                path: "/foo".to_string(),
+                chunk_spec: None,
+                size_bytes: None,
+                iceberg_delete_files: None,
+                iceberg_equality_delete_files: None,
+                delta_deletion_vector: None,
+                metadata: num_rows.map(|length| TableMetadata { length }),
+                partition_spec,
+                statistics,
+                parquet_metadata: None,
+                etag: None,
+                last_modified: None,
+            }],
+            Arc::new(FileFormatConfig::Json(Default::default())),
+            schema(),
+            Arc::new(StorageConfig::Native(
+                NativeStorageConfig::new_internal(true, None).into(),
+            )),
+            Pushdowns::default(),
+        ))
+    }
+
+    fn scan_node(scan_tasks: Vec<Arc<ScanTask>>, pushdowns: Pushdowns) -> LogicalPlanBuilder {
+        let partitioning_keys =
+            vec![PartitionField::new(Field::new("b", DataType::Int64), None, None).unwrap()];
+        let scan_op = MaterializedScanOperator::new(scan_tasks, schema(), partitioning_keys);
+        LogicalPlanBuilder::table_scan(ScanOperatorRef(Arc::new(scan_op)), Some(pushdowns)).unwrap()
+    }
+
+    fn optimize(plan: Arc<LogicalPlan>) -> DaftResult<Arc<LogicalPlan>> {
+        Ok(AnswerAggregationFromMetadata::new()
+            .try_optimize(plan)?
+            .data)
+    }
+
+    /// Returns the number of ScanTasks of each Source in the plan.
+    fn num_scan_tasks(plan: &Arc<LogicalPlan>) -> DaftResult<Vec<usize>> {
+        let mut num_scan_tasks = vec![];
+        plan.apply(|node| {
+            if let LogicalPlan::Source(Source { source_info, .. }) = node.as_ref()
+                && let SourceInfo::Physical(scan_info) = source_info.as_ref()
+            {
+                num_scan_tasks.push(
+                    scan_info
+                        .scan_op
+                        .0
+                        .to_scan_tasks(scan_info.pushdowns.clone())?
+                        .count(),
+                );
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        Ok(num_scan_tasks)
+    }
+
+    /// Returns the number of rows of each in-memory Source in the plan.
+    fn in_memory_num_rows(plan: &Arc<LogicalPlan>) -> DaftResult<Vec<usize>> {
+        let mut num_rows = vec![];
+        plan.apply(|node| {
+            if let LogicalPlan::Source(Source { source_info, .. }) = node.as_ref()
+                && let SourceInfo::InMemory(info) = source_info.as_ref()
+            {
+                num_rows.push(info.num_rows);
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        Ok(num_rows)
+    }
+
+    /// Returns the metadata-derived partial aggregations for an Aggregate over a Source.
+    fn metadata_values(plan: &Arc<LogicalPlan>) -> DaftResult<Table> {
+        let LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            groupby,
+            ..
+        }) = plan.as_ref()
+        else {
+            panic!("Expected Aggregate, got:\n{}", plan.repr_ascii(false));
+        };
+        let LogicalPlan::Source(Source {
+            output_schema,
+            source_info,
+        }) = input.as_ref()
+        else {
+            panic!("Expected Source, got:\n{}", input.repr_ascii(false));
+        };
+        let SourceInfo::Physical(scan_info) = source_info.as_ref() else {
+            panic!(
+                "Expected physical Source, got:\n{}",
+                input.repr_ascii(false)
+            );
+        };
+        let groupby_names = groupby.iter().map(|e| e.name().to_string()).collect_vec();
+        let (first_stage_aggs, _, _) =
+            populate_aggregation_stages(aggregations, output_schema, groupby);
+        let first_stage_aggs = first_stage_aggs
+            .into_iter()
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .collect_vec();
+        Ok(
+            metadata_table(scan_info, &groupby_names, &first_stage_aggs, output_schema)?
+                .unwrap()
+                .0,
+        )
+    }
+
+    /// Tests that count, min and max are answered from metadata when all ScanTasks have it, without scanning any.
+    #[test]
+    fn count_min_max_answered_from_metadata() -> DaftResult<()> {
+        let plan = scan_node(
+            vec![
+                scan_task(Some(3), Some((1, 5)), None),
+                scan_task(Some(2), Some((-2, 4)), None),
+            ],
+            Pushdowns::default(),
+        )
+        .aggregate(
+            vec![
+                col("a").count(CountMode::All),
+                col("a").min().alias("a_min"),
+                col("a").max().alias("a_max"),
+            ],
+            vec![],
+        )?
+        .build();
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(num_scan_tasks(&optimized)?, Vec::<usize>::new());
+        assert_eq!(in_memory_num_rows(&optimized)?, vec![2]);
+        let metadata = metadata_values(&plan)?;
+        let mut values = (0..metadata.num_columns())
+            .map(|i| {
+                let s = metadata.get_column_by_index(i).unwrap();
+                s.cast(&DataType::Int64)
+                    .unwrap()
+                    .i64()
+                    .unwrap()
+                    .as_slice()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, vec![vec![1, -2], vec![3, 2], vec![5, 4]]);
+        assert_eq!(
+            optimized.schema().names(),
+            vec!["a".to_string(), "a_min".to_string(), "a_max".to_string()]
+        );
+        Ok(())
+    }
+
+    /// Tests that ScanTasks lacking metadata are still scanned, and merged with the metadata-derived aggregations.
+    #[test]
+    fn aggregation_partially_answered_from_metadata() -> DaftResult<()> {
+        let plan = scan_node(
+            vec![
+                scan_task(Some(3), Some((1, 5)), None),
+                scan_task(None, Some((-2, 4)), None),
+                scan_task(Some(4), None, None),
+                scan_task(Some(0), None, None),
+            ],
+            Pushdowns::default(),
+        )
+        .aggregate(
+            vec![
+                col("a").count(CountMode::All),
+                col("a").max().alias("a_max"),
+            ],
+            vec![],
+        )?
+        .build();
+        let optimized = optimize(plan)?;
+        assert!(matches!(
+            optimized.children()[0].children()[0],
+            LogicalPlan::Concat(..)
+        ));
+        assert_eq!(num_scan_tasks(&optimized)?, vec![2]);
+        assert_eq!(in_memory_num_rows(&optimized)?, vec![1]);
+        Ok(())
+    }
+
+    /// Tests that aggregations grouped by identity partition columns are answered from partition values.
+    #[test]
+    fn grouped_aggregation_answered_from_partition_values() -> DaftResult<()> {
+        let plan = scan_node(
+            vec![
+                scan_task(Some(3), None, Some(10)),
+                scan_task(Some(2), None, Some(20)),
+            ],
+            Pushdowns::default(),
+        )
+        .aggregate(
+            vec![
+                col("a").count(CountMode::All),
+                col("b").max().alias("b_max"),
+            ],
+            vec![col("b")],
+        )?
+        .build();
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(num_scan_tasks(&optimized)?, Vec::<usize>::new());
+        assert_eq!(
+            metadata_values(&plan)?.get_column("b")?.i64()?.as_slice(),
+            [10, 20]
+        );
+        Ok(())
+    }
+
+    /// Tests that aggregations aren't answered from metadata when a filter was pushed into the scan, or when an
+    /// aggregation can't be computed from metadata.
+    #[test]
+    fn aggregation_not_answered_from_metadata() -> DaftResult<()> {
+        let filtered = scan_node(
+            vec![scan_task(Some(3), Some((1, 5)), None)],
+            Pushdowns::default().with_filters(Some(col("a").lt(lit(2)))),
+        )
+        .aggregate(vec![col("a").count(CountMode::All)], vec![])?
+        .build();
+        assert_optimized_plan_with_rules_eq(
+            filtered.clone(),
+            filtered,
+            vec![Box::new(AnswerAggregationFromMetadata::new())],
+        )?;
+        let sum = scan_node(
+            vec![scan_task(Some(3), Some((1, 5)), None)],
+            Pushdowns::default(),
+        )
+        .aggregate(
+            vec![
+                col("a").count(CountMode::All),
+                col("a").sum().alias("a_sum"),
+            ],
+            vec![],
+        )?
+        .build();
+        assert_optimized_plan_with_rules_eq(
+            sum.clone(),
+            sum,
+            vec![Box::new(AnswerAggregationFromMetadata::new())],
+        )?;
+        let non_partition_groupby = scan_node(
+            vec![scan_task(Some(3), Some((1, 5)), None)],
+            Pushdowns::default(),
+        )
+        .aggregate(vec![col("b").count(CountMode::All)], vec![col("a")])?
+        .build();
+        assert_optimized_plan_with_rules_eq(
+            non_partition_groupby.clone(),
+            non_partition_groupby,
+            vec![Box::new(AnswerAggregationFromMetadata::new())],
+        )?;
+        Ok(())
+    }
+
+    /// A ScanOperator whose ScanTasks never have metadata, and which must not be listed.
+    #[derive(Debug)]
+    struct NoMetadataScanOperator;
+
+    impl ScanOperator for NoMetadataScanOperator {
+        fn schema(&self) -> SchemaRef {
+            schema()
+        }
+        fn partitioning_keys(&self) -> &[PartitionField] {
+            &[]
+        }
+        fn can_absorb_filter(&self) -> bool {
+            false
+        }
+        fn can_absorb_select(&self) -> bool {
+            false
+        }
+        fn can_absorb_limit(&self) -> bool {
+            false
+        }
+        fn can_provide_metadata(&self) -> bool {
+            false
+        }
+        fn multiline_display(&self) -> Vec<String> {
+            vec!["NoMetadataScanOperator".to_string()]
+        }
+        fn to_scan_tasks(
+            &self,
+            _pushdowns: Pushdowns,
+        ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>>>> {
+            panic!("ScanTasks of a scan without metadata shouldn't be listed during optimization")
+        }
+    }
+
+    /// Tests that the ScanTasks of a scan operator that can't provide metadata, e.g. over CSV files, aren't listed.
+    #[test]
+    fn scan_without_metadata_not_listed() -> DaftResult<()> {
+        let plan = LogicalPlanBuilder::table_scan(
+            ScanOperatorRef(Arc::new(NoMetadataScanOperator)),
+            None,
+        )?
+        .aggregate(vec![col("a").count(CountMode::All)], vec![])?
+        .build();
+        assert_optimized_plan_with_rules_eq(
+            plan.clone(),
+            plan,
+            vec![Box::new(AnswerAggregationFromMetadata::new())],
+        )?;
+        Ok(())
+    }
+}
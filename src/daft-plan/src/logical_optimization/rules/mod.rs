@@ -1,11 +1,15 @@
+mod answer_aggregation_from_metadata;
 mod drop_repartition;
+mod push_down_aggregation;
 mod push_down_filter;
 mod push_down_limit;
 mod push_down_projection;
 mod rule;
 mod split_actor_pool_projects;
 
+pub use answer_aggregation_from_metadata::AnswerAggregationFromMetadata;
 pub use drop_repartition::DropRepartition;
+pub use push_down_aggregation::PushDownAggregation;
 pub use push_down_filter::PushDownFilter;
 pub use push_down_limit::PushDownLimit;
 pub use push_down_projection::PushDownProjection;
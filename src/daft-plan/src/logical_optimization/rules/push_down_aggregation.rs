@@ -0,0 +1,537 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::join::JoinType;
+use daft_dsl::{col, optimization::get_required_columns, AggExpr, Expr, ExprRef, SketchType};
+use itertools::Itertools;
+
+use crate::{
+    logical_ops::{Aggregate, Join, Project},
+    physical_planner::populate_aggregation_stages,
+    LogicalPlan,
+};
+
+use super::OptimizerRule;
+use common_treenode::{Transformed, TreeNode};
+
+/// Optimization rule for pushing partial Aggregations below Joins (eager aggregation).
+///
+/// For an Aggregate whose input is a Join, if every aggregation only references columns from one side
+/// of the join and the group keys include that side's join keys, the first stage of the aggregation is
+/// evaluated on that side before the join, and the remaining stages are evaluated after the join:
+///
+/// Aggregate-Join(left, right) -> Project-Aggregate-Join(Aggregate-left, right)
+///
+/// Since the partial aggregation groups by the join keys, every partial group joins against exactly the
+/// same rows as the input rows it replaces, so the result is unchanged while the join sees far fewer rows
+/// for many-to-one relationships.
+#[derive(Default, Debug)]
+pub struct PushDownAggregation {}
+
+impl PushDownAggregation {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for PushDownAggregation {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        // Rewrite bottom-up so that the final Aggregate we produce above the Join is never revisited.
+        plan.transform_up(|node| self.try_optimize_node(node))
+    }
+}
+
+/// Which side of the join a partial aggregation is pushed into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinSide {
+    Left,
+    Right,
+}
+
+impl PushDownAggregation {
+    fn try_optimize_node(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            groupby,
+            ..
+        }) = plan.as_ref()
+        else {
+            return Ok(Transformed::no(plan));
+        };
+        let LogicalPlan::Join(join) = input.as_ref() else {
+            return Ok(Transformed::no(plan));
+        };
+        if aggregations.is_empty() || !aggregations.iter().all(is_decomposable) {
+            return Ok(Transformed::no(plan));
+        }
+        // Only plain column group keys can be mapped onto the inputs of the join.
+        let Some(groupby_names) = groupby
+            .iter()
+            .map(|e| match e.as_ref() {
+                Expr::Column(name) => Some(name.to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(Transformed::no(plan));
+        };
+
+        let left_names = HashSet::<_>::from_iter(join.left.schema().names());
+        let right_names = HashSet::<_>::from_iter(join.right.schema().names());
+        let agg_cols = aggregations
+            .iter()
+            .flat_map(|agg| get_required_columns(&Expr::Agg(agg.clone()).into()))
+            .collect::<HashSet<_>>();
+        // Columns shared by both sides are common join keys, which the join output takes from the left side.
+        let side = if agg_cols.is_subset(&left_names) {
+            JoinSide::Left
+        } else if agg_cols.is_subset(&right_names) && agg_cols.is_disjoint(&left_names) {
+            JoinSide::Right
+        } else {
+            return Ok(Transformed::no(plan));
+        };
+        // Pushing a partial aggregation into a side is only valid if that side's rows are preserved by the join.
+        let side_preserved = match join.join_type {
+            JoinType::Inner => true,
+            JoinType::Left => side == JoinSide::Left,
+            JoinType::Right => side == JoinSide::Right,
+            JoinType::Outer | JoinType::Anti | JoinType::Semi => false,
+        };
+        if !side_preserved {
+            return Ok(Transformed::no(plan));
+        }
+
+        let (side_input, side_on, side_names) = match side {
+            JoinSide::Left => (&join.left, &join.left_on, &left_names),
+            JoinSide::Right => (&join.right, &join.right_on, &right_names),
+        };
+        // The group keys must include the pushed side's join keys, so that each partial group joins
+        // against the same rows as the rows it was aggregated from.
+        let Some(side_on_names) = side_on
+            .iter()
+            .map(|e| match e.as_ref() {
+                Expr::Column(name) => Some(name.to_string()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(Transformed::no(plan));
+        };
+        if !side_on_names
+            .iter()
+            .all(|name| groupby_names.contains(name))
+        {
+            return Ok(Transformed::no(plan));
+        }
+        // Don't push below a side that is already aggregated on the same keys, e.g. on repeated optimization.
+        if matches!(side_input.as_ref(), LogicalPlan::Aggregate(..)) {
+            return Ok(Transformed::no(plan));
+        }
+
+        let side_groupby = groupby_names
+            .iter()
+            .filter(|name| side_names.contains(*name))
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        let (first_stage_aggs, second_stage_aggs, final_exprs) =
+            populate_aggregation_stages(aggregations, &side_input.schema(), groupby);
+
+        let partial_agg: LogicalPlan = Aggregate::try_new(
+            side_input.clone(),
+            sorted_agg_exprs(&first_stage_aggs),
+            side_groupby,
+        )?
+        .into();
+        let (new_left, new_right) = match side {
+            JoinSide::Left => (partial_agg.into(), join.right.clone()),
+            JoinSide::Right => (join.left.clone(), partial_agg.into()),
+        };
+        let new_join = Join::try_new(
+            new_left,
+            new_right,
+            join.left_on.clone(),
+            join.right_on.clone(),
+            join.join_type,
+            join.join_strategy,
+        )?;
+        // Bail if the join had to rename any of the partial aggregation columns.
+        let new_join_schema = new_join.output_schema.clone();
+        if !first_stage_aggs
+            .keys()
+            .all(|id| new_join_schema.get_field(id).is_ok())
+        {
+            return Ok(Transformed::no(plan));
+        }
+        let final_agg: LogicalPlan = Aggregate::try_new(
+            LogicalPlan::from(new_join).into(),
+            sorted_agg_exprs(&second_stage_aggs),
+            groupby_names.into_iter().map(col).collect(),
+        )?
+        .into();
+        let new_plan: LogicalPlan = Project::try_new(final_agg.into(), final_exprs)?.into();
+        Ok(Transformed::yes(new_plan.into()))
+    }
+}
+
+/// Aggregation stage expressions ordered by their semantic ID, so that the rewritten plan is deterministic.
+fn sorted_agg_exprs(aggs: &HashMap<Arc<str>, AggExpr>) -> Vec<ExprRef> {
+    aggs.iter()
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, agg)| Expr::Agg(agg.clone()).into())
+        .collect()
+}
+
+/// Whether an aggregation can be split into a partial aggregation below a join and a merging aggregation above it.
+///
+/// Approximate percentiles are split into DDSketches below the join that are merged above it, but approximate distinct
+/// counts aren't, since merging HyperLogLog sketches gives their estimate rather than a sketch that the physical
+/// planner could merge again in multi-partition stages.
+fn is_decomposable(agg: &AggExpr) -> bool {
+    matches!(
+        agg,
+        AggExpr::Count(..)
+            | AggExpr::Sum(..)
+            | AggExpr::Min(..)
+            | AggExpr::Max(..)
+            | AggExpr::ApproxPercentile(..)
+            | AggExpr::ApproxSketch(_, SketchType::DDSketch)
+            | AggExpr::MergeSketch(_, SketchType::DDSketch)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, AggExpr, ExprRef, SketchType};
+
+    use common_daft_config::DaftExecutionConfig;
+
+    use crate::{
+        logical_ops::{Aggregate, Join, Project},
+        logical_optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::PushDownAggregation,
+            test::assert_optimized_plan_with_rules_eq,
+            Optimizer,
+        },
+        physical_plan::PhysicalPlan,
+        physical_planner::logical_to_physical,
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan, LogicalPlanBuilder,
+    };
+    use daft_scan::ScanOperator;
+
+    /// Helper that creates an optimizer with the PushDownAggregation rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![Box::new(PushDownAggregation::new())],
+        )
+    }
+
+    fn optimize(plan: Arc<LogicalPlan>) -> DaftResult<Arc<LogicalPlan>> {
+        let optimizer = Optimizer::with_rule_batches(
+            vec![RuleBatch::new(
+                vec![Box::new(PushDownAggregation::new())],
+                RuleExecutionStrategy::Once,
+            )],
+            Default::default(),
+        );
+        Ok(optimizer
+            .optimize_with_rules(optimizer.rule_batches[0].rules.as_slice(), plan)?
+            .data)
+    }
+
+    struct JoinInputs {
+        left_scan_op: Arc<dyn ScanOperator>,
+        right_scan_op: Arc<dyn ScanOperator>,
+    }
+
+    impl JoinInputs {
+        fn new() -> Self {
+            Self {
+                left_scan_op: dummy_scan_operator(vec![
+                    Field::new("id", DataType::Int64),
+                    Field::new("amount", DataType::Int64),
+                ]),
+                right_scan_op: dummy_scan_operator(vec![
+                    Field::new("id", DataType::Int64),
+                    Field::new("name", DataType::Utf8),
+                    Field::new("score", DataType::Float64),
+                ]),
+            }
+        }
+
+        fn left(&self) -> LogicalPlanBuilder {
+            dummy_scan_node(self.left_scan_op.clone())
+        }
+
+        fn right(&self) -> LogicalPlanBuilder {
+            dummy_scan_node(self.right_scan_op.clone())
+        }
+
+        fn join_and_aggregate(
+            &self,
+            join_type: JoinType,
+            aggs: Vec<ExprRef>,
+            groupby: Vec<ExprRef>,
+        ) -> DaftResult<Arc<LogicalPlan>> {
+            self.join_and_aggregate_inputs(self.left(), self.right(), join_type, aggs, groupby)
+        }
+
+        fn join_and_aggregate_inputs(
+            &self,
+            left: LogicalPlanBuilder,
+            right: LogicalPlanBuilder,
+            join_type: JoinType,
+            aggs: Vec<ExprRef>,
+            groupby: Vec<ExprRef>,
+        ) -> DaftResult<Arc<LogicalPlan>> {
+            Ok(left
+                .join(
+                    right.build(),
+                    vec![col("id")],
+                    vec![col("id")],
+                    join_type,
+                    None,
+                )?
+                .aggregate(aggs, groupby)?
+                .build())
+        }
+    }
+
+    /// Tests that aggregations grouped by the join key are partially aggregated below an inner join.
+    ///
+    /// Aggregate-Join(left, right) -> Project-Aggregate-Join(Aggregate-left, right)
+    #[test]
+    fn agg_pushed_below_inner_join() -> DaftResult<()> {
+        let inputs = JoinInputs::new();
+        let plan = inputs.join_and_aggregate(
+            JoinType::Inner,
+            vec![
+                col("amount").sum(),
+                col("amount").count(CountMode::Valid).alias("cnt"),
+                col("amount").min().alias("lo"),
+                col("amount").max().alias("hi"),
+            ],
+            vec![col("id"), col("name")],
+        )?;
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(optimized.schema(), plan.schema());
+        let LogicalPlan::Project(Project { input, .. }) = optimized.as_ref() else {
+            panic!("Expected Project, got:\n{}", optimized.repr_ascii(false));
+        };
+        let LogicalPlan::Aggregate(Aggregate { input, groupby, .. }) = input.as_ref() else {
+            panic!("Expected Aggregate, got:\n{}", input.repr_ascii(false));
+        };
+        assert_eq!(groupby, &vec![col("id"), col("name")]);
+        let LogicalPlan::Join(Join { left, right, .. }) = input.as_ref() else {
+            panic!("Expected Join, got:\n{}", input.repr_ascii(false));
+        };
+        assert_eq!(right, &inputs.right().build());
+        let LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            groupby,
+            ..
+        }) = left.as_ref()
+        else {
+            panic!("Expected Aggregate, got:\n{}", left.repr_ascii(false));
+        };
+        assert_eq!(groupby, &vec![col("id")]);
+        assert_eq!(aggregations.len(), 4);
+        assert_eq!(input, &inputs.left().build());
+        Ok(())
+    }
+
+    /// Tests that aggregations over right-side columns are partially aggregated on the right side.
+    #[test]
+    fn agg_pushed_below_inner_join_right_side() -> DaftResult<()> {
+        let inputs = JoinInputs::new();
+        let plan = inputs.join_and_aggregate(
+            JoinType::Inner,
+            vec![col("score").sum()],
+            vec![col("id")],
+        )?;
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(optimized.schema(), plan.schema());
+        let LogicalPlan::Project(Project { input, .. }) = optimized.as_ref() else {
+            panic!("Expected Project, got:\n{}", optimized.repr_ascii(false));
+        };
+        let LogicalPlan::Aggregate(Aggregate { input, .. }) = input.as_ref() else {
+            panic!("Expected Aggregate, got:\n{}", input.repr_ascii(false));
+        };
+        let LogicalPlan::Join(Join { left, right, .. }) = input.as_ref() else {
+            panic!("Expected Join, got:\n{}", input.repr_ascii(false));
+        };
+        assert_eq!(left, &inputs.left().build());
+        assert!(matches!(right.as_ref(), LogicalPlan::Aggregate(..)));
+        Ok(())
+    }
+
+    /// Tests that aggregations are not pushed below a join if the group keys don't include the join keys.
+    #[test]
+    fn agg_not_pushed_without_join_key_in_groupby() -> DaftResult<()> {
+        let inputs = JoinInputs::new();
+        let plan = inputs.join_and_aggregate(
+            JoinType::Inner,
+            vec![col("amount").sum()],
+            vec![col("name")],
+        )?;
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations are not pushed into the non-preserved side of an outer join.
+    #[test]
+    fn agg_not_pushed_into_non_preserved_side() -> DaftResult<()> {
+        let inputs = JoinInputs::new();
+        let plan =
+            inputs.join_and_aggregate(JoinType::Left, vec![col("score").sum()], vec![col("id")])?;
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        let inputs = JoinInputs::new();
+        let plan = inputs.join_and_aggregate(
+            JoinType::Outer,
+            vec![col("amount").sum()],
+            vec![col("id")],
+        )?;
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that non-decomposable aggregations are not pushed below a join.
+    #[test]
+    fn agg_not_pushed_for_non_decomposable_agg() -> DaftResult<()> {
+        let inputs = JoinInputs::new();
+        let plan = inputs.join_and_aggregate(
+            JoinType::Inner,
+            vec![
+                col("amount").sum(),
+                col("amount").agg_list().alias("amounts"),
+            ],
+            vec![col("id")],
+        )?;
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        // Merged HyperLogLog sketches can't be merged again.
+        let plan = inputs.join_and_aggregate(
+            JoinType::Inner,
+            vec![col("amount").approx_count_distinct()],
+            vec![col("id")],
+        )?;
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that approximate percentiles are split into sketches below the join that are merged above it.
+    ///
+    /// Aggregate(ApproxPercentile)-Join(left, right)
+    /// -> Project(SketchPercentile)-Aggregate(MergeSketch)-Join(Aggregate(ApproxSketch)-left, right)
+    #[test]
+    fn approx_percentiles_pushed_below_join() -> DaftResult<()> {
+        let inputs = JoinInputs::new();
+        let plan = inputs.join_and_aggregate(
+            JoinType::Inner,
+            vec![col("amount").approx_percentiles(&[0.5], false)],
+            vec![col("id"), col("name")],
+        )?;
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(optimized.schema(), plan.schema());
+        let LogicalPlan::Project(Project { input, .. }) = optimized.as_ref() else {
+            panic!("Expected Project, got:\n{}", optimized.repr_ascii(false));
+        };
+        let LogicalPlan::Aggregate(Aggregate {
+            input,
+            aggregations,
+            ..
+        }) = input.as_ref()
+        else {
+            panic!("Expected Aggregate, got:\n{}", input.repr_ascii(false));
+        };
+        assert!(matches!(
+            aggregations.as_slice(),
+            [AggExpr::MergeSketch(_, SketchType::DDSketch)]
+        ));
+        let LogicalPlan::Join(Join { left, .. }) = input.as_ref() else {
+            panic!("Expected Join, got:\n{}", input.repr_ascii(false));
+        };
+        let LogicalPlan::Aggregate(Aggregate { aggregations, .. }) = left.as_ref() else {
+            panic!("Expected Aggregate, got:\n{}", left.repr_ascii(false));
+        };
+        assert!(matches!(
+            aggregations.as_slice(),
+            [AggExpr::ApproxSketch(_, SketchType::DDSketch)]
+        ));
+        Ok(())
+    }
+
+    /// Tests that a plan with pushed-down aggregations over multi-partition inputs can be translated into a
+    /// physical plan, which splits both the partial and the final aggregations into multi-partition stages.
+    #[test]
+    fn agg_pushed_below_join_multi_partition_physical_plan() -> DaftResult<()> {
+        let inputs = JoinInputs::new();
+        let plan = inputs.join_and_aggregate_inputs(
+            inputs.left().into_partitions(3)?,
+            inputs.right().into_partitions(2)?,
+            JoinType::Inner,
+            vec![
+                col("amount").sum(),
+                col("amount").count(CountMode::Valid).alias("cnt"),
+                col("amount").max().alias("hi"),
+                col("score").approx_count_distinct().alias("distinct"),
+            ],
+            vec![col("id"), col("name")],
+        )?;
+        // The approximate aggregation keeps the whole aggregation above the join.
+        let optimized = optimize(plan.clone())?;
+        assert_eq!(optimized, plan);
+        let physical_plan = logical_to_physical(optimized, DaftExecutionConfig::default().into())?;
+        assert_eq!(count_physical_aggregates(&physical_plan), 2);
+
+        let plan = inputs.join_and_aggregate_inputs(
+            inputs.left().into_partitions(3)?,
+            inputs.right().into_partitions(2)?,
+            JoinType::Inner,
+            vec![
+                col("amount").sum(),
+                col("amount").count(CountMode::Valid).alias("cnt"),
+                col("amount").max().alias("hi"),
+                col("amount")
+                    .approx_percentiles(&[0.5], false)
+                    .alias("median"),
+            ],
+            vec![col("id"), col("name")],
+        )?;
+        let optimized = optimize(plan.clone())?;
+        assert_ne!(optimized, plan);
+        let physical_plan = logical_to_physical(optimized, DaftExecutionConfig::default().into())?;
+        assert!(physical_plan.clustering_spec().num_partitions() > 1);
+        // Both the partial aggregation below the join and the final aggregation above it are planned in two stages.
+        assert_eq!(count_physical_aggregates(&physical_plan), 4);
+        Ok(())
+    }
+
+    fn count_physical_aggregates(plan: &PhysicalPlan) -> usize {
+        let own = usize::from(matches!(plan, PhysicalPlan::Aggregate(..)));
+        own + plan
+            .children()
+            .into_iter()
+            .map(count_physical_aggregates)
+            .sum::<usize>()
+    }
+}
@@ -7,10 +7,11 @@ use common_error::DaftResult;
 use daft_core::join::JoinType;
 use daft_dsl::{
     col,
+    functions::FunctionExpr,
     optimization::{
         conjuct, get_required_columns, replace_columns_with_expressions, split_conjuction,
     },
-    ExprRef,
+    Expr, ExprRef,
 };
 use daft_scan::{rewrite_predicate_for_partitioning, PredicateGroups};
 
@@ -253,10 +254,7 @@ impl PushDownFilter {
             }
             LogicalPlan::Join(child_join) => {
                 // TODO(Kevin): add more filter pushdowns for joins
-                // Example 1:
-                //      For `foo JOIN bar ON foo.a == (bar.b + 2) WHERE a > 0`, the filter `a > 0` is pushed down to the left side, but can also be pushed down to the right side as `(b + 2) > 0`
-                //
-                // Example 2:
+                // Example:
                 //      A predicate `(a AND b) OR (c AND d)` is equivalent to `((a AND b) OR (c AND d)) AND (a OR c)`, and `a OR c` could potentially be pushed down.
 
                 // if a filter is pushed down on one side, would it preserve the output of the join+filter?
@@ -276,6 +274,14 @@ impl PushDownFilter {
                 let left_cols = HashSet::<_>::from_iter(child_join.left.schema().names());
                 let right_cols = HashSet::<_>::from_iter(child_join.right.schema().names());
 
+                // Mappings from each side's join key columns to the other side's join key expressions, used to
+                // infer equivalent predicates on the other side of the join (e.g. `l.id > 5` => `r.id > 5` for
+                // `l JOIN r ON l.id = r.id`).
+                let left_to_right_keys =
+                    join_key_mapping(&child_join.left_on, &child_join.right_on);
+                let right_to_left_keys =
+                    join_key_mapping(&child_join.right_on, &child_join.left_on);
+
                 for predicate in split_conjuction(&filter.predicate).into_iter().cloned() {
                     let pred_cols = HashSet::<_>::from_iter(get_required_columns(&predicate));
 
@@ -294,6 +300,14 @@ impl PushDownFilter {
                         }
                         (true, false) => {
                             if left_preserved {
+                                // Rows of the right side that can only join with rows filtered out on the left side
+                                // can't contribute to the output, so we can also filter the right side on the
+                                // equivalent predicate.
+                                if let Some(inferred) =
+                                    infer_predicate(&predicate, &pred_cols, &left_to_right_keys)
+                                {
+                                    right_pushdowns.push(inferred);
+                                }
                                 left_pushdowns.push(predicate);
                             } else {
                                 kept_predicates.push(predicate);
@@ -301,6 +315,11 @@ impl PushDownFilter {
                         }
                         (false, true) => {
                             if right_preserved {
+                                if let Some(inferred) =
+                                    infer_predicate(&predicate, &pred_cols, &right_to_left_keys)
+                                {
+                                    left_pushdowns.push(inferred);
+                                }
                                 right_pushdowns.push(predicate);
                             } else {
                                 kept_predicates.push(predicate);
@@ -309,6 +328,16 @@ impl PushDownFilter {
                     }
                 }
 
+                // Null join keys never match in an inner join, so if we're pushing filters into the join's inputs
+                // anyway, we also filter out null keys on both sides for any key that isn't already constrained by
+                // a pushed-down predicate.
+                if child_join.join_type == JoinType::Inner
+                    && (!left_pushdowns.is_empty() || !right_pushdowns.is_empty())
+                {
+                    add_not_null_key_predicates(&mut left_pushdowns, &child_join.left_on);
+                    add_not_null_key_predicates(&mut right_pushdowns, &child_join.right_on);
+                }
+
                 let left_pushdowns = conjuct(left_pushdowns);
                 let right_pushdowns = conjuct(right_pushdowns);
 
@@ -350,6 +379,64 @@ impl PushDownFilter {
     }
 }
 
+/// Maps the names of the plain-column join keys in `from_on` to the corresponding join key expressions in `to_on`.
+fn join_key_mapping(from_on: &[ExprRef], to_on: &[ExprRef]) -> HashMap<String, ExprRef> {
+    let mut mapping = HashMap::new();
+    for (from, to) in from_on.iter().zip(to_on.iter()) {
+        if let Expr::Column(name) = from.as_ref() {
+            mapping
+                .entry(name.to_string())
+                .or_insert_with(|| to.clone());
+        }
+    }
+    mapping
+}
+
+/// Rewrites a predicate that only references join keys of one side of a join into the equivalent predicate on the
+/// other side of the join, returning None if the predicate references non-key columns or contains a UDF.
+fn infer_predicate(
+    predicate: &ExprRef,
+    pred_cols: &HashSet<String>,
+    key_mapping: &HashMap<String, ExprRef>,
+) -> Option<ExprRef> {
+    if pred_cols.is_empty()
+        || !pred_cols.iter().all(|col| key_mapping.contains_key(col))
+        || predicate.exists(|e| {
+            matches!(
+                e.as_ref(),
+                Expr::Function {
+                    func: FunctionExpr::Python(..),
+                    ..
+                }
+            )
+        })
+    {
+        return None;
+    }
+    Some(replace_columns_with_expressions(
+        predicate.clone(),
+        key_mapping,
+    ))
+}
+
+/// Adds an `IS NOT NULL` predicate for each join key that isn't already referenced by one of the provided predicates.
+fn add_not_null_key_predicates(predicates: &mut Vec<ExprRef>, join_on: &[ExprRef]) {
+    let constrained_cols = predicates
+        .iter()
+        .flat_map(get_required_columns)
+        .collect::<HashSet<_>>();
+    for key in join_on {
+        let key_cols = get_required_columns(key);
+        if key_cols.iter().all(|col| constrained_cols.contains(col)) {
+            continue;
+        }
+        let not_null = key.clone().not_null();
+        if !predicates.contains(&not_null) {
+            predicates.push(not_null);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -680,17 +767,33 @@ mod tests {
             )?
             .filter(pred.clone())?
             .build();
+        // Null join keys are also filtered out on both sides of inner joins.
+        let (left_pred, right_pred) = if how == JoinType::Inner {
+            (
+                pred.clone().and(col("b").not_null()),
+                Some(col("b").not_null()),
+            )
+        } else {
+            (pred.clone(), None)
+        };
         let expected_left_filter_scan = if push_into_left_scan {
             dummy_scan_node_with_pushdowns(
                 left_scan_op.clone(),
-                Pushdowns::default().with_filters(Some(pred.clone())),
+                Pushdowns::default().with_filters(Some(left_pred)),
             )
         } else {
-            left_scan_plan.filter(pred.clone())?
+            left_scan_plan.filter(left_pred)?
+        };
+        let expected_right_scan = match right_pred {
+            Some(right_pred) => dummy_scan_node_with_pushdowns(
+                right_scan_op.clone(),
+                Pushdowns::default().with_filters(Some(right_pred)),
+            ),
+            None => right_scan_plan,
         };
         let expected = expected_left_filter_scan
             .join(
-                &right_scan_plan,
+                &expected_right_scan,
                 join_on.clone(),
                 join_on.clone(),
                 how,
@@ -732,15 +835,31 @@ mod tests {
             )?
             .filter(pred.clone())?
             .build();
+        // Null join keys are also filtered out on both sides of inner joins.
+        let (left_pred, right_pred) = if how == JoinType::Inner {
+            (
+                Some(col("b").not_null()),
+                pred.clone().and(col("b").not_null()),
+            )
+        } else {
+            (None, pred.clone())
+        };
         let expected_right_filter_scan = if push_into_right_scan {
             dummy_scan_node_with_pushdowns(
                 right_scan_op.clone(),
-                Pushdowns::default().with_filters(Some(pred.clone())),
+                Pushdowns::default().with_filters(Some(right_pred)),
             )
         } else {
-            right_scan_plan.filter(pred.clone())?
+            right_scan_plan.filter(right_pred)?
+        };
+        let expected_left_scan = match left_pred {
+            Some(left_pred) => dummy_scan_node_with_pushdowns(
+                left_scan_op.clone(),
+                Pushdowns::default().with_filters(Some(left_pred)),
+            ),
+            None => left_scan_plan,
         };
-        let expected = left_scan_plan
+        let expected = expected_left_scan
             .join(
                 &expected_right_filter_scan,
                 join_on.clone(),
@@ -891,4 +1010,135 @@ mod tests {
         assert_optimized_plan_eq(plan, expected)?;
         Ok(())
     }
+
+    /// Tests that a Filter on a join key is inferred onto the other side's join key, for both IS IN and range
+    /// predicates.
+    ///
+    /// Filter(a)-Join(l, r ON a = b) -> Join(Filter(a)-l, Filter(b)-r)
+    #[rstest]
+    fn filter_inferred_across_join_keys(
+        #[values(JoinType::Inner, JoinType::Left, JoinType::Anti, JoinType::Semi)] how: JoinType,
+        #[values(false, true)] range_pred: bool,
+    ) -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("b", DataType::Int64),
+            Field::new("d", DataType::Float64),
+        ]);
+        let left_scan_plan = dummy_scan_node(left_scan_op.clone());
+        let right_scan_plan = dummy_scan_node(right_scan_op.clone());
+        let pred_on = |key: &str| {
+            if range_pred {
+                col(key).between(lit(1), lit(10))
+            } else {
+                col(key).is_in(lit(Int64Array::from(("items", vec![1, 2])).into_series()))
+            }
+        };
+        let plan = left_scan_plan
+            .join(&right_scan_plan, vec![col("a")], vec![col("b")], how, None)?
+            .filter(pred_on("a"))?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            left_scan_op,
+            Pushdowns::default().with_filters(Some(pred_on("a"))),
+        )
+        .join(
+            dummy_scan_node_with_pushdowns(
+                right_scan_op,
+                Pushdowns::default().with_filters(Some(pred_on("b"))),
+            ),
+            vec![col("a")],
+            vec![col("b")],
+            how,
+            None,
+        )?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a Filter on the right join key of a right join is inferred onto the left join key.
+    #[test]
+    fn filter_inferred_across_join_keys_right_join() -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("b", DataType::Int64),
+            Field::new("d", DataType::Float64),
+        ]);
+        let plan = dummy_scan_node(left_scan_op.clone())
+            .join(
+                dummy_scan_node(right_scan_op.clone()),
+                vec![col("a")],
+                vec![col("b")],
+                JoinType::Right,
+                None,
+            )?
+            .filter(col("b").gt(lit(5)))?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            left_scan_op,
+            Pushdowns::default().with_filters(Some(col("a").gt(lit(5)))),
+        )
+        .join(
+            dummy_scan_node_with_pushdowns(
+                right_scan_op,
+                Pushdowns::default().with_filters(Some(col("b").gt(lit(5)))),
+            ),
+            vec![col("a")],
+            vec![col("b")],
+            JoinType::Right,
+            None,
+        )?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that predicates referencing non-key columns aren't inferred across the join, while null join keys are
+    /// filtered out of the other side of an inner join.
+    #[test]
+    fn filter_not_inferred_across_join_for_non_key_columns() -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("b", DataType::Int64),
+            Field::new("d", DataType::Float64),
+        ]);
+        let pred = col("a").lt(col("c"));
+        let plan = dummy_scan_node(left_scan_op.clone())
+            .join(
+                dummy_scan_node(right_scan_op.clone()),
+                vec![col("a")],
+                vec![col("b")],
+                JoinType::Inner,
+                None,
+            )?
+            .filter(pred.clone())?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            left_scan_op,
+            Pushdowns::default().with_filters(Some(pred)),
+        )
+        .join(
+            dummy_scan_node_with_pushdowns(
+                right_scan_op,
+                Pushdowns::default().with_filters(Some(col("b").not_null())),
+            ),
+            vec![col("a")],
+            vec![col("b")],
+            JoinType::Inner,
+            None,
+        )?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
 }
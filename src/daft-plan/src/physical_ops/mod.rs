@@ -16,6 +16,7 @@ mod hash_join;
 #[cfg(feature = "python")]
 mod iceberg_write;
 mod in_memory;
+mod ipc;
 mod json;
 #[cfg(feature = "python")]
 mod lance_write;
@@ -27,6 +28,7 @@ mod project;
 mod reduce;
 mod sample;
 mod scan;
+mod skew_split;
 mod sort;
 mod sort_merge_join;
 mod split;
@@ -49,6 +51,7 @@ pub use hash_join::HashJoin;
 #[cfg(feature = "python")]
 pub use iceberg_write::IcebergWrite;
 pub use in_memory::InMemoryScan;
+pub use ipc::TabularWriteIpc;
 pub use json::TabularWriteJson;
 #[cfg(feature = "python")]
 pub use lance_write::LanceWrite;
@@ -60,6 +63,7 @@ pub use project::Project;
 pub use reduce::ReduceMerge;
 pub use sample::Sample;
 pub use scan::TabularScan;
+pub use skew_split::SkewSplit;
 pub use sort::Sort;
 pub use sort_merge_join::SortMergeJoin;
 pub use split::Split;
@@ -0,0 +1,61 @@
+use crate::physical_plan::PhysicalPlanRef;
+use serde::{Deserialize, Serialize};
+
+/// Splits skewed partitions of one side of a partition-wise join into multiple output partitions, replicating the
+/// corresponding partitions of the other side of the join so that the two sides stay aligned.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SkewSplit {
+    // Upstream node.
+    pub input: PhysicalPlanRef,
+    // For each input partition, the number of output partitions that it's turned into.
+    pub num_outputs: Vec<usize>,
+    // For each input partition, whether it's replicated into its output partitions instead of split by rows.
+    pub replicate: Vec<bool>,
+}
+
+impl SkewSplit {
+    pub(crate) fn new(
+        input: PhysicalPlanRef,
+        num_outputs: Vec<usize>,
+        replicate: Vec<bool>,
+    ) -> Self {
+        Self {
+            input,
+            num_outputs,
+            replicate,
+        }
+    }
+
+    pub fn output_num_partitions(&self) -> usize {
+        self.num_outputs.iter().sum()
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "SkewSplit: Input num partitions = {}",
+            self.num_outputs.len()
+        ));
+        res.push(format!(
+            "Output num partitions = {}",
+            self.output_num_partitions()
+        ));
+        let split = self
+            .num_outputs
+            .iter()
+            .zip(self.replicate.iter())
+            .enumerate()
+            .filter(|(_, (num_outputs, _))| **num_outputs > 1)
+            .map(|(i, (num_outputs, replicate))| {
+                format!(
+                    "{i} -> {num_outputs} ({})",
+                    if *replicate { "replicated" } else { "split" }
+                )
+            })
+            .collect::<Vec<_>>();
+        res.push(format!("Skewed partitions = [{}]", split.join(", ")));
+        res
+    }
+}
+
+crate::impl_default_tree_display!(SkewSplit);
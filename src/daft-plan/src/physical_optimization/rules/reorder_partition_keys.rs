@@ -141,6 +141,7 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                 PhysicalPlan::Pivot(..) |
                 PhysicalPlan::TabularWriteCsv(..) |
                 PhysicalPlan::TabularWriteJson(..) |
+                PhysicalPlan::TabularWriteIpc(..) |
                 PhysicalPlan::TabularWriteParquet(..) => Ok(Transformed::no(c.propagate())),
 
                 // the rest should have been dealt with earlier
@@ -149,6 +150,7 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                 PhysicalPlan::TabularScan(..) |
                 PhysicalPlan::EmptyScan(..) |
                 PhysicalPlan::Split(..) |
+                PhysicalPlan::SkewSplit(..) |
                 PhysicalPlan::Coalesce(..) |
                 PhysicalPlan::FanoutRandom(..) |
                 PhysicalPlan::FanoutByRange(..) |
@@ -26,6 +26,7 @@ pub enum PhysicalPlan {
     Unpivot(Unpivot),
     Sort(Sort),
     Split(Split),
+    SkewSplit(SkewSplit),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
     Coalesce(Coalesce),
@@ -44,6 +45,7 @@ pub enum PhysicalPlan {
     TabularWriteParquet(TabularWriteParquet),
     TabularWriteJson(TabularWriteJson),
     TabularWriteCsv(TabularWriteCsv),
+    TabularWriteIpc(TabularWriteIpc),
     #[cfg(feature = "python")]
     IcebergWrite(IcebergWrite),
     #[cfg(feature = "python")]
@@ -147,6 +149,10 @@ impl PhysicalPlan {
             }) => {
                 ClusteringSpec::Unknown(UnknownClusteringConfig::new(*output_num_partitions)).into()
             }
+            Self::SkewSplit(skew_split) => ClusteringSpec::Unknown(UnknownClusteringConfig::new(
+                skew_split.output_num_partitions(),
+            ))
+            .into(),
             Self::Coalesce(Coalesce { num_to, .. }) => {
                 ClusteringSpec::Unknown(UnknownClusteringConfig::new(*num_to)).into()
             }
@@ -207,6 +213,12 @@ impl PhysicalPlan {
                     // TODO(Clark): Consolidate this logic with the planner logic when we push the partition spec
                     // to be an entirely planner-side concept.
                     1 => input_clustering_spec,
+                    // Adaptively coalesced or skew-split inputs are no longer hash partitioned on the join keys.
+                    num_partitions
+                        if matches!(input_clustering_spec.as_ref(), ClusteringSpec::Unknown(_)) =>
+                    {
+                        ClusteringSpec::Unknown(UnknownClusteringConfig::new(num_partitions)).into()
+                    }
                     num_partitions => ClusteringSpec::Hash(HashClusteringConfig::new(
                         num_partitions,
                         left_on.clone(),
@@ -235,6 +247,7 @@ impl PhysicalPlan {
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => input.clustering_spec(),
             Self::TabularWriteCsv(TabularWriteCsv { input, .. }) => input.clustering_spec(),
             Self::TabularWriteJson(TabularWriteJson { input, .. }) => input.clustering_spec(),
+            Self::TabularWriteIpc(TabularWriteIpc { input, .. }) => input.clustering_spec(),
             #[cfg(feature = "python")]
             Self::IcebergWrite(_) | Self::DeltaLakeWrite(_) | Self::LanceWrite(_) => {
                 ClusteringSpec::Unknown(UnknownClusteringConfig::new(1)).into()
@@ -332,6 +345,21 @@ impl PhysicalPlan {
             | Self::Sort(Sort { input, .. })
             | Self::Split(Split { input, .. })
             | Self::Pivot(Pivot { input, .. }) => input.approximate_stats(),
+            Self::SkewSplit(SkewSplit {
+                input, replicate, ..
+            }) => {
+                let input_stats = input.approximate_stats();
+                if replicate.iter().any(|replicate| *replicate) {
+                    // Replicated partitions are duplicated in the output.
+                    ApproxStats {
+                        upper_bound_rows: None,
+                        upper_bound_bytes: None,
+                        ..input_stats
+                    }
+                } else {
+                    input_stats
+                }
+            }
             Self::Concat(Concat { input, other }) => {
                 &input.approximate_stats() + &other.approximate_stats()
             }
@@ -400,9 +428,10 @@ impl PhysicalPlan {
             }
             // Post-write DataFrame will contain paths to files that were written.
             // TODO(Clark): Estimate output size via root directory and estimates for # of partitions given partitioning column.
-            Self::TabularWriteParquet(_) | Self::TabularWriteCsv(_) | Self::TabularWriteJson(_) => {
-                ApproxStats::empty()
-            }
+            Self::TabularWriteParquet(_)
+            | Self::TabularWriteCsv(_)
+            | Self::TabularWriteJson(_)
+            | Self::TabularWriteIpc(_) => ApproxStats::empty(),
             #[cfg(feature = "python")]
             Self::IcebergWrite(_) | Self::DeltaLakeWrite(_) | Self::LanceWrite(_) => {
                 ApproxStats::empty()
@@ -423,6 +452,7 @@ impl PhysicalPlan {
             Self::Sample(Sample { input, .. }) => vec![input],
             Self::Sort(Sort { input, .. }) => vec![input],
             Self::Split(Split { input, .. }) => vec![input],
+            Self::SkewSplit(SkewSplit { input, .. }) => vec![input],
             Self::Coalesce(Coalesce { input, .. }) => vec![input],
             Self::Flatten(Flatten { input }) => vec![input],
             Self::FanoutRandom(FanoutRandom { input, .. }) => vec![input],
@@ -434,6 +464,7 @@ impl PhysicalPlan {
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => vec![input],
             Self::TabularWriteCsv(TabularWriteCsv { input, .. }) => vec![input],
             Self::TabularWriteJson(TabularWriteJson { input, .. }) => vec![input],
+            Self::TabularWriteIpc(TabularWriteIpc { input, .. }) => vec![input],
             #[cfg(feature = "python")]
             Self::IcebergWrite(IcebergWrite { input, .. }) => vec![input],
             #[cfg(feature = "python")]
@@ -475,6 +506,7 @@ impl PhysicalPlan {
                 Self::Sample(Sample { fraction, with_replacement, seed, .. }) => Self::Sample(Sample::new(input.clone(), *fraction, *with_replacement, *seed)),
                 Self::Sort(Sort { sort_by, descending, num_partitions, .. }) => Self::Sort(Sort::new(input.clone(), sort_by.clone(), descending.clone(), *num_partitions)),
                 Self::Split(Split { input_num_partitions, output_num_partitions, .. }) => Self::Split(Split::new(input.clone(), *input_num_partitions, *output_num_partitions)),
+                Self::SkewSplit(SkewSplit { num_outputs, replicate, .. }) => Self::SkewSplit(SkewSplit::new(input.clone(), num_outputs.clone(), replicate.clone())),
                 Self::Coalesce(Coalesce { num_from, num_to, .. }) => Self::Coalesce(Coalesce::new(input.clone(), *num_from, *num_to)),
                 Self::Flatten(..) => Self::Flatten(Flatten::new(input.clone())),
                 Self::FanoutRandom(FanoutRandom { num_partitions, .. }) => Self::FanoutRandom(FanoutRandom::new(input.clone(), *num_partitions)),
@@ -485,6 +517,7 @@ impl PhysicalPlan {
                 Self::TabularWriteParquet(TabularWriteParquet { schema, file_info, .. }) => Self::TabularWriteParquet(TabularWriteParquet::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteCsv(TabularWriteCsv { schema, file_info, .. }) => Self::TabularWriteCsv(TabularWriteCsv::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteJson(TabularWriteJson { schema, file_info, .. }) => Self::TabularWriteJson(TabularWriteJson::new(schema.clone(), file_info.clone(), input.clone())),
+                Self::TabularWriteIpc(TabularWriteIpc { schema, file_info, .. }) => Self::TabularWriteIpc(TabularWriteIpc::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { column_name, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::new(input.clone(), column_name)),
                 #[cfg(feature = "python")]
                 Self::IcebergWrite(IcebergWrite { schema, iceberg_info, .. }) => Self::IcebergWrite(IcebergWrite::new(schema.clone(), iceberg_info.clone(), input.clone())),
@@ -529,6 +562,7 @@ impl PhysicalPlan {
             Self::Sample(..) => "Sample",
             Self::Sort(..) => "Sort",
             Self::Split(..) => "Split",
+            Self::SkewSplit(..) => "SkewSplit",
             Self::Coalesce(..) => "Coalesce",
             Self::Flatten(..) => "Flatten",
             Self::FanoutRandom(..) => "FanoutRandom",
@@ -544,6 +578,7 @@ impl PhysicalPlan {
             Self::TabularWriteParquet(..) => "TabularWriteParquet",
             Self::TabularWriteCsv(..) => "TabularWriteCsv",
             Self::TabularWriteJson(..) => "TabularWriteJson",
+            Self::TabularWriteIpc(..) => "TabularWriteIpc",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
             #[cfg(feature = "python")]
             Self::IcebergWrite(..) => "IcebergWrite",
@@ -569,6 +604,7 @@ impl PhysicalPlan {
             Self::Sample(sample) => sample.multiline_display(),
             Self::Sort(sort) => sort.multiline_display(),
             Self::Split(split) => split.multiline_display(),
+            Self::SkewSplit(skew_split) => skew_split.multiline_display(),
             Self::Coalesce(coalesce) => coalesce.multiline_display(),
             Self::Flatten(flatten) => flatten.multiline_display(),
             Self::FanoutRandom(fanout_random) => fanout_random.multiline_display(),
@@ -586,6 +622,7 @@ impl PhysicalPlan {
             }
             Self::TabularWriteCsv(tabular_write_csv) => tabular_write_csv.multiline_display(),
             Self::TabularWriteJson(tabular_write_json) => tabular_write_json.multiline_display(),
+            Self::TabularWriteIpc(tabular_write_ipc) => tabular_write_ipc.multiline_display(),
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
@@ -0,0 +1,407 @@
+use std::collections::HashMap;
+
+use common_daft_config::DaftExecutionConfig;
+use daft_core::join::JoinType;
+
+use crate::physical_ops::{
+    BroadcastJoin, Coalesce, FanoutByHash, HashJoin, InMemoryScan, ReduceMerge, SkewSplit,
+};
+use crate::physical_plan::{PhysicalPlan, PhysicalPlanRef};
+
+/// Whether the provided plan only reads already-materialized results, possibly through the partition-rebalancing
+/// ops that adaptive join planning inserts.
+pub(super) fn is_materialized(plan: &PhysicalPlan) -> bool {
+    match plan {
+        PhysicalPlan::InMemoryScan(..) => true,
+        PhysicalPlan::Coalesce(Coalesce { input, .. })
+        | PhysicalPlan::SkewSplit(SkewSplit { input, .. }) => is_materialized(input),
+        _ => false,
+    }
+}
+
+/// Re-plan a hash join using the observed sizes of its materialized inputs.
+///
+/// - If one side has been materialized and turned out to be small enough to broadcast, the join is switched to a
+///   broadcast join, which avoids shuffling the other side.
+/// - If both sides have been materialized, tiny partitions are coalesced up to the target partition size, and
+///   skewed partitions are split into sub-partitions that are joined against replicas of the other side's partition.
+///
+/// `partition_sizes` maps the cache key of each materialized result to its observed per-partition sizes in bytes.
+pub(super) fn adapt_hash_join(
+    plan: PhysicalPlanRef,
+    partition_sizes: &HashMap<String, Vec<usize>>,
+    cfg: &DaftExecutionConfig,
+) -> PhysicalPlanRef {
+    let PhysicalPlan::HashJoin(join) = plan.as_ref() else {
+        return plan;
+    };
+    try_broadcast(join, cfg)
+        .or_else(|| try_rebalance(join, partition_sizes, cfg))
+        .unwrap_or(plan)
+}
+
+fn in_memory_info(plan: &PhysicalPlan) -> Option<&crate::source_info::InMemoryInfo> {
+    match plan {
+        PhysicalPlan::InMemoryScan(InMemoryScan { in_memory_info, .. }) => Some(in_memory_info),
+        _ => None,
+    }
+}
+
+/// Strip a pending hash repartitioning of a join input, since a broadcast join doesn't need it.
+///
+/// Only `ReduceMerge(FanoutByHash)` is stripped, since that is the only repartitioning that the hash join translation
+/// in `translate.rs` inserts above its inputs. A range repartitioning (`FanoutByRange`) or `Split` above a join input
+/// comes from the query itself, e.g. a sort or an explicit `repartition`, and is kept as requested.
+fn strip_hash_repartition(plan: &PhysicalPlanRef) -> PhysicalPlanRef {
+    match plan.as_ref() {
+        PhysicalPlan::ReduceMerge(ReduceMerge { input }) => match input.as_ref() {
+            PhysicalPlan::FanoutByHash(FanoutByHash { input, .. }) => input.clone(),
+            _ => plan.clone(),
+        },
+        _ => plan.clone(),
+    }
+}
+
+fn try_broadcast(join: &HashJoin, cfg: &DaftExecutionConfig) -> Option<PhysicalPlanRef> {
+    let threshold = cfg.broadcast_join_size_bytes_threshold;
+    let left_size_bytes = in_memory_info(&join.left).map(|info| info.size_bytes);
+    let right_size_bytes = in_memory_info(&join.right).map(|info| info.size_bytes);
+    // NOTE: The broadcasting side must not be the side that we're outer joining by; see the join strategy selection
+    // in the planner for the same conditions.
+    let (broadcaster, receiver, is_swapped) = match (left_size_bytes, right_size_bytes) {
+        (Some(left_size_bytes), None)
+            if left_size_bytes <= threshold
+                && matches!(join.join_type, JoinType::Inner | JoinType::Right) =>
+        {
+            (
+                join.left.clone(),
+                strip_hash_repartition(&join.right),
+                false,
+            )
+        }
+        (None, Some(right_size_bytes))
+            if right_size_bytes <= threshold
+                && matches!(
+                    join.join_type,
+                    JoinType::Inner | JoinType::Left | JoinType::Anti | JoinType::Semi
+                ) =>
+        {
+            (join.right.clone(), strip_hash_repartition(&join.left), true)
+        }
+        _ => return None,
+    };
+    log::info!(
+        "AQE: Switching hash join to broadcast join based on observed input sizes ({:?} bytes, {:?} bytes)",
+        left_size_bytes,
+        right_size_bytes
+    );
+    Some(
+        PhysicalPlan::BroadcastJoin(BroadcastJoin::new(
+            broadcaster,
+            receiver,
+            join.left_on.clone(),
+            join.right_on.clone(),
+            join.join_type,
+            is_swapped,
+        ))
+        .arced(),
+    )
+}
+
+fn try_rebalance(
+    join: &HashJoin,
+    partition_sizes: &HashMap<String, Vec<usize>>,
+    cfg: &DaftExecutionConfig,
+) -> Option<PhysicalPlanRef> {
+    let left_sizes = partition_sizes.get(&in_memory_info(&join.left)?.cache_key)?;
+    let right_sizes = partition_sizes.get(&in_memory_info(&join.right)?.cache_key)?;
+    let num_partitions = left_sizes.len();
+    if num_partitions <= 1 || right_sizes.len() != num_partitions {
+        return None;
+    }
+    let target_size_bytes = cfg.aqe_target_partition_size_bytes.max(1);
+
+    let mut left = join.left.clone();
+    let mut right = join.right.clone();
+    let mut left_sizes = left_sizes.clone();
+    let mut right_sizes = right_sizes.clone();
+    let mut changed = false;
+
+    // Coalesce tiny partitions. Both sides are merged with the same boundaries, so the partitions stay aligned.
+    let total_size_bytes = left_sizes.iter().sum::<usize>() + right_sizes.iter().sum::<usize>();
+    let num_to = total_size_bytes
+        .div_ceil(target_size_bytes)
+        .clamp(1, num_partitions);
+    if num_to < num_partitions {
+        log::info!("AQE: Coalescing hash join inputs from {num_partitions} to {num_to} partitions");
+        left = PhysicalPlan::Coalesce(Coalesce::new(left, num_partitions, num_to)).arced();
+        right = PhysicalPlan::Coalesce(Coalesce::new(right, num_partitions, num_to)).arced();
+        left_sizes = coalesce_sizes(&left_sizes, num_to);
+        right_sizes = coalesce_sizes(&right_sizes, num_to);
+        changed = true;
+    }
+
+    // Split skewed partitions.
+    if let Some(((left_outputs, left_replicate), (right_outputs, right_replicate))) =
+        skew_split_spec(&left_sizes, &right_sizes, join.join_type, cfg)
+    {
+        log::info!("AQE: Splitting skewed hash join partitions");
+        left = PhysicalPlan::SkewSplit(SkewSplit::new(left, left_outputs, left_replicate)).arced();
+        right =
+            PhysicalPlan::SkewSplit(SkewSplit::new(right, right_outputs, right_replicate)).arced();
+        changed = true;
+    }
+
+    changed.then(|| {
+        PhysicalPlan::HashJoin(HashJoin::new(
+            left,
+            right,
+            join.left_on.clone(),
+            join.right_on.clone(),
+            join.join_type,
+        ))
+        .arced()
+    })
+}
+
+/// Compute the partition sizes after coalescing, using the same contiguous grouping as the coalesce op.
+fn coalesce_sizes(sizes: &[usize], num_to: usize) -> Vec<usize> {
+    let num_from = sizes.len();
+    (0..num_to)
+        .map(|i| {
+            let start = (num_from * i).div_ceil(num_to);
+            let end = (num_from * (i + 1)).div_ceil(num_to);
+            sizes[start..end].iter().sum()
+        })
+        .collect()
+}
+
+type SkewSplitSpec = (Vec<usize>, Vec<bool>);
+
+/// Determine how to split skewed partitions of the join inputs, returning the number of output partitions and
+/// replication flags for each side, or None if no partition is skewed.
+fn skew_split_spec(
+    left_sizes: &[usize],
+    right_sizes: &[usize],
+    join_type: JoinType,
+    cfg: &DaftExecutionConfig,
+) -> Option<(SkewSplitSpec, SkewSplitSpec)> {
+    fn median(sizes: &[usize]) -> usize {
+        let mut sorted = sizes.to_vec();
+        sorted.sort_unstable();
+        sorted[sorted.len() / 2]
+    }
+    let target_size_bytes = cfg.aqe_target_partition_size_bytes.max(1);
+    let is_skewed = |size: usize, median: usize| {
+        size > target_size_bytes && (size as f64) > cfg.aqe_skewed_partition_factor * median as f64
+    };
+    // A side can only be split if each of its rows only needs to see the other side's partition once, i.e. if we're
+    // not outer joining by the other side.
+    let can_split_left = matches!(
+        join_type,
+        JoinType::Inner | JoinType::Left | JoinType::Semi | JoinType::Anti
+    );
+    let can_split_right = matches!(join_type, JoinType::Inner | JoinType::Right);
+    let left_median = median(left_sizes);
+    let right_median = median(right_sizes);
+
+    let mut left_spec = (vec![], vec![]);
+    let mut right_spec = (vec![], vec![]);
+    let mut any_skewed = false;
+    for (&left_size, &right_size) in left_sizes.iter().zip(right_sizes.iter()) {
+        let left_skewed = can_split_left && is_skewed(left_size, left_median);
+        let right_skewed = can_split_right && is_skewed(right_size, right_median);
+        let (left_split, right_split) = if left_skewed && (!right_skewed || left_size >= right_size)
+        {
+            let num_outputs = left_size.div_ceil(target_size_bytes);
+            ((num_outputs, false), (num_outputs, true))
+        } else if right_skewed {
+            let num_outputs = right_size.div_ceil(target_size_bytes);
+            ((num_outputs, true), (num_outputs, false))
+        } else {
+            ((1, false), (1, false))
+        };
+        any_skewed |= left_split.0 > 1;
+        left_spec.0.push(left_split.0);
+        left_spec.1.push(left_split.1);
+        right_spec.0.push(right_split.0);
+        right_spec.1.push(right_split.1);
+    }
+    any_skewed.then_some((left_spec, right_spec))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use daft_core::prelude::*;
+    use daft_dsl::col;
+
+    use super::adapt_hash_join;
+    use crate::partitioning::{ClusteringSpec, HashClusteringConfig};
+    use crate::physical_ops::{FanoutByHash, HashJoin, InMemoryScan, ReduceMerge};
+    use crate::physical_plan::{PhysicalPlan, PhysicalPlanRef};
+    use crate::source_info::InMemoryInfo;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new("a", DataType::Int64)]).unwrap())
+    }
+
+    fn cfg() -> DaftExecutionConfig {
+        DaftExecutionConfig {
+            broadcast_join_size_bytes_threshold: 100,
+            aqe_target_partition_size_bytes: 100,
+            aqe_skewed_partition_factor: 5.0,
+            ..Default::default()
+        }
+    }
+
+    fn in_memory_scan(cache_key: &str, sizes: &[usize]) -> PhysicalPlanRef {
+        let num_partitions = sizes.len();
+        let clustering_spec: Arc<ClusteringSpec> =
+            ClusteringSpec::Hash(HashClusteringConfig::new(num_partitions, vec![col("a")])).into();
+        let info = InMemoryInfo {
+            source_schema: schema(),
+            cache_key: cache_key.to_string(),
+            #[cfg(feature = "python")]
+            cache_entry: pyo3::Python::with_gil(|py| py.None()),
+            num_partitions,
+            size_bytes: sizes.iter().sum(),
+            num_rows: num_partitions,
+            clustering_spec: Some(clustering_spec.clone()),
+        };
+        PhysicalPlan::InMemoryScan(InMemoryScan::new(schema(), info, clustering_spec)).arced()
+    }
+
+    fn hash_join(
+        left: PhysicalPlanRef,
+        right: PhysicalPlanRef,
+        join_type: JoinType,
+    ) -> PhysicalPlanRef {
+        PhysicalPlan::HashJoin(HashJoin::new(
+            left,
+            right,
+            vec![col("a")],
+            vec![col("a")],
+            join_type,
+        ))
+        .arced()
+    }
+
+    fn sizes(entries: &[(&str, &[usize])]) -> HashMap<String, Vec<usize>> {
+        entries
+            .iter()
+            .map(|(key, sizes)| (key.to_string(), sizes.to_vec()))
+            .collect()
+    }
+
+    /// A hash join whose unmaterialized side is still pending a shuffle should be switched to a broadcast join if
+    /// the materialized side turns out to be small.
+    #[test]
+    fn switches_to_broadcast_join() {
+        let pending = PhysicalPlan::ReduceMerge(ReduceMerge::new(
+            PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                in_memory_scan("other", &[1000]),
+                4,
+                vec![col("a")],
+            ))
+            .arced(),
+        ))
+        .arced();
+        let small = in_memory_scan("small", &[10, 10, 10, 10]);
+
+        let plan = adapt_hash_join(
+            hash_join(pending.clone(), small.clone(), JoinType::Left),
+            &HashMap::new(),
+            &cfg(),
+        );
+        let PhysicalPlan::BroadcastJoin(join) = plan.as_ref() else {
+            panic!("Expected broadcast join, got {}", plan.name());
+        };
+        assert_eq!(join.broadcaster, small);
+        assert!(matches!(
+            join.receiver.as_ref(),
+            PhysicalPlan::InMemoryScan(..)
+        ));
+        assert!(join.is_swapped);
+
+        // Can't broadcast the side that we're outer joining by.
+        let plan = adapt_hash_join(
+            hash_join(small.clone(), pending, JoinType::Left),
+            &HashMap::new(),
+            &cfg(),
+        );
+        assert!(matches!(plan.as_ref(), PhysicalPlan::HashJoin(..)));
+    }
+
+    #[test]
+    fn coalesces_tiny_partitions() {
+        let left = in_memory_scan("left", &[200, 200, 200, 200]);
+        let right = in_memory_scan("right", &[200, 200, 200, 200]);
+        let observed = sizes(&[("left", &[10, 10, 10, 10]), ("right", &[20, 20, 20, 20])]);
+
+        let plan = adapt_hash_join(hash_join(left, right, JoinType::Inner), &observed, &cfg());
+        let PhysicalPlan::HashJoin(join) = plan.as_ref() else {
+            panic!("Expected hash join, got {}", plan.name());
+        };
+        for side in [&join.left, &join.right] {
+            let PhysicalPlan::Coalesce(coalesce) = side.as_ref() else {
+                panic!("Expected coalesce, got {}", side.name());
+            };
+            assert_eq!((coalesce.num_from, coalesce.num_to), (4, 2));
+        }
+        assert_eq!(plan.clustering_spec().num_partitions(), 2);
+    }
+
+    #[test]
+    fn splits_skewed_partitions() {
+        let left = in_memory_scan("left", &[1, 1, 1, 1]);
+        let right = in_memory_scan("right", &[1, 1, 1, 1]);
+        let observed = sizes(&[
+            ("left", &[100, 100, 1000, 100]),
+            ("right", &[100, 100, 100, 100]),
+        ]);
+
+        let plan = adapt_hash_join(
+            hash_join(left.clone(), right.clone(), JoinType::Inner),
+            &observed,
+            &cfg(),
+        );
+        let PhysicalPlan::HashJoin(join) = plan.as_ref() else {
+            panic!("Expected hash join, got {}", plan.name());
+        };
+        let (PhysicalPlan::SkewSplit(left_split), PhysicalPlan::SkewSplit(right_split)) =
+            (join.left.as_ref(), join.right.as_ref())
+        else {
+            panic!("Expected skew splits on both sides of the join");
+        };
+        assert_eq!(left_split.num_outputs, vec![1, 1, 10, 1]);
+        assert_eq!(left_split.replicate, vec![false; 4]);
+        assert_eq!(right_split.num_outputs, vec![1, 1, 10, 1]);
+        assert_eq!(right_split.replicate, vec![false, false, true, false]);
+        assert_eq!(plan.clustering_spec().num_partitions(), 13);
+
+        // The right side can't be split for a left join, and neither side can be split for an outer join.
+        let observed = sizes(&[
+            ("left", &[100, 100, 100, 100]),
+            ("right", &[100, 100, 1000, 100]),
+        ]);
+        for join_type in [JoinType::Left, JoinType::Outer] {
+            let plan = adapt_hash_join(
+                hash_join(left.clone(), right.clone(), join_type),
+                &observed,
+                &cfg(),
+            );
+            let PhysicalPlan::HashJoin(join) = plan.as_ref() else {
+                panic!("Expected hash join, got {}", plan.name());
+            };
+            assert!(matches!(join.left.as_ref(), PhysicalPlan::InMemoryScan(..)));
+            assert!(matches!(
+                join.right.as_ref(),
+                PhysicalPlan::InMemoryScan(..)
+            ));
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::{physical_optimization::optimizer::PhysicalOptimizer, physical_plan::
 
 use crate::physical_planner::planner::PhysicalPlanTranslator;
 use common_treenode::TreeNode;
+mod adaptive;
 mod planner;
 pub use planner::{AdaptivePlanner, MaterializedResults, QueryStageOutput};
 mod translate;
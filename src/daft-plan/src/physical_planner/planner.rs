@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_daft_config::DaftExecutionConfig;
@@ -5,7 +6,7 @@ use common_error::DaftResult;
 use common_treenode::{Transformed, TreeNode, TreeNodeRewriter, TreeNodeVisitor};
 use serde::{Deserialize, Serialize};
 
-use crate::logical_ops::Source;
+use crate::logical_ops::{Join as LogicalJoin, Source};
 use crate::logical_optimization::Optimizer;
 use crate::logical_plan::LogicalPlan;
 
@@ -15,6 +16,7 @@ use crate::LogicalPlanRef;
 
 use common_treenode::{DynTreeNode, TreeNodeRecursion};
 
+use super::adaptive::{adapt_hash_join, is_materialized};
 use super::translate::translate_single_logical_node;
 pub(super) struct PhysicalPlanTranslator {
     pub physical_children: Vec<Arc<PhysicalPlan>>,
@@ -34,11 +36,13 @@ impl TreeNodeVisitor for PhysicalPlanTranslator {
     }
 }
 
-pub(super) struct QueryStagePhysicalPlanTranslator {
+pub(super) struct QueryStagePhysicalPlanTranslator<'a> {
     pub physical_children: Vec<Arc<PhysicalPlan>>,
     pub root: Arc<LogicalPlan>,
     pub cfg: Arc<DaftExecutionConfig>,
     pub source_id: Option<usize>,
+    // Observed per-partition sizes of materialized results, keyed by cache key.
+    pub partition_sizes: &'a HashMap<String, Vec<usize>>,
 }
 
 fn is_query_stage_boundary(plan: &PhysicalPlan) -> bool {
@@ -56,7 +60,7 @@ fn is_query_stage_boundary(plan: &PhysicalPlan) -> bool {
     }
 }
 
-impl TreeNodeRewriter for QueryStagePhysicalPlanTranslator {
+impl TreeNodeRewriter for QueryStagePhysicalPlanTranslator<'_> {
     type Node = Arc<LogicalPlan>;
 
     fn f_down(&mut self, node: Self::Node) -> DaftResult<common_treenode::Transformed<Self::Node>> {
@@ -66,6 +70,14 @@ impl TreeNodeRewriter for QueryStagePhysicalPlanTranslator {
     fn f_up(&mut self, node: Self::Node) -> DaftResult<common_treenode::Transformed<Self::Node>> {
         let translated_pplan =
             translate_single_logical_node(&node, &mut self.physical_children, &self.cfg)?;
+        // Re-plan joins whose strategy wasn't fixed by the user based on the sizes of already-materialized inputs.
+        let translated_pplan = match node.as_ref() {
+            LogicalPlan::Join(LogicalJoin {
+                join_strategy: None,
+                ..
+            }) => adapt_hash_join(translated_pplan, self.partition_sizes, &self.cfg),
+            _ => translated_pplan,
+        };
 
         let is_query_stage_boundary = is_query_stage_boundary(&translated_pplan);
         let is_root_node = Arc::ptr_eq(&node, &self.root);
@@ -101,20 +113,20 @@ impl TreeNodeRewriter for QueryStagePhysicalPlanTranslator {
                         Right,
                     }
 
-                    let run_next: RunNext = match (left.as_ref(), right.as_ref()) {
-                        (PhysicalPlan::InMemoryScan(..), PhysicalPlan::InMemoryScan(..)) => {
+                    let run_next: RunNext = match (is_materialized(left), is_materialized(right)) {
+                        (true, true) => {
                             // both are in memory, emit as is.
                             RunNext::Parent
                         }
-                        (PhysicalPlan::InMemoryScan(..), _) => {
+                        (true, false) => {
                             // we know the left, so let's run the right
                             RunNext::Right
                         }
-                        (_, PhysicalPlan::InMemoryScan(..)) => {
+                        (false, true) => {
                             // we know the right, so let's run the left
                             RunNext::Left
                         }
-                        (_, _) => {
+                        (false, false) => {
                             // both sides are not in memory, so we should rank which side to run
                             let left_stats = left.approximate_stats();
                             let right_stats = right.approximate_stats();
@@ -283,12 +295,15 @@ enum AdaptivePlannerStatus {
 pub struct MaterializedResults {
     pub source_id: usize,
     pub in_memory_info: InMemoryInfo,
+    // Size in bytes of each materialized partition, if known.
+    pub partition_size_bytes: Option<Vec<usize>>,
 }
 
 pub struct AdaptivePlanner {
     logical_plan: LogicalPlanRef,
     cfg: Arc<DaftExecutionConfig>,
     status: AdaptivePlannerStatus,
+    partition_sizes: HashMap<String, Vec<usize>>,
 }
 
 impl AdaptivePlanner {
@@ -297,6 +312,7 @@ impl AdaptivePlanner {
             logical_plan,
             cfg,
             status: AdaptivePlannerStatus::Ready,
+            partition_sizes: HashMap::new(),
         }
     }
 
@@ -308,6 +324,7 @@ impl AdaptivePlanner {
             root: self.logical_plan.clone(),
             cfg: self.cfg.clone(),
             source_id: None,
+            partition_sizes: &self.partition_sizes,
         };
         let output = self.logical_plan.clone().rewrite(&mut rewriter)?;
         let physical_plan = rewriter
@@ -348,6 +365,13 @@ impl AdaptivePlanner {
     pub fn update(&mut self, mat_results: MaterializedResults) -> DaftResult<()> {
         assert_eq!(self.status, AdaptivePlannerStatus::WaitingForStats);
 
+        if let Some(partition_size_bytes) = &mat_results.partition_size_bytes {
+            self.partition_sizes.insert(
+                mat_results.in_memory_info.cache_key.clone(),
+                partition_size_bytes.clone(),
+            );
+        }
+
         let mut rewriter = ReplacePlaceholdersWithMaterializedResult {
             mat_results: Some(mat_results),
         };
@@ -53,6 +53,20 @@ pub(super) fn translate_single_logical_node(
                     cfg.scan_tasks_min_size_bytes,
                     cfg.scan_tasks_max_size_bytes,
                 );
+                let scan_tasks = daft_scan::scan_task_iters::split_by_byte_ranges(
+                    scan_tasks,
+                    cfg.scan_tasks_max_size_bytes,
+                );
+                let scan_tasks = daft_scan::scan_task_iters::split_by_record_batches(
+                    scan_tasks,
+                    cfg.scan_tasks_min_size_bytes,
+                    cfg.scan_tasks_max_size_bytes,
+                );
+                let scan_tasks = daft_scan::scan_task_iters::split_by_stripes(
+                    scan_tasks,
+                    cfg.scan_tasks_min_size_bytes,
+                    cfg.scan_tasks_max_size_bytes,
+                );
 
                 // Apply transformations on the ScanTasks to optimize
                 let scan_tasks =
@@ -702,6 +716,30 @@ pub(super) fn translate_single_logical_node(
                             ))
                             .arced())
                         }
+                        FileFormat::Ipc => Ok(PhysicalPlan::TabularWriteIpc(TabularWriteIpc::new(
+                            schema.clone(),
+                            file_info.clone(),
+                            input_physical,
+                        ))
+                        .arced()),
+                        FileFormat::Avro => Err(common_error::DaftError::ValueError(
+                            "Avro sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Orc => Err(common_error::DaftError::ValueError(
+                            "ORC sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Xlsx => Err(common_error::DaftError::ValueError(
+                            "XLSX sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Text => Err(common_error::DaftError::ValueError(
+                            "Text sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::FixedWidth => Err(common_error::DaftError::ValueError(
+                            "Fixed-width text sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Files => Err(common_error::DaftError::ValueError(
+                            "Files sink not yet implemented".to_string(),
+                        )),
                         FileFormat::Database => Err(common_error::DaftError::ValueError(
                             "Database sink not yet implemented".to_string(),
                         )),
@@ -946,6 +984,43 @@ pub fn populate_aggregation_stages(
                     ));
                 final_exprs.push(col(second_stage_id).alias(output_name));
             }
+            // DDSketch aggregations are left by partial aggregations that were pushed below a join, and are merged
+            // into DDSketches again. HyperLogLog sketches can't be, since merging them gives their estimate.
+            ApproxSketch(e, SketchType::DDSketch) => {
+                let sketch_id = agg_expr.semantic_id(schema).id;
+                let merge_id = MergeSketch(col(sketch_id.clone()), SketchType::DDSketch)
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(sketch_id.clone())
+                    .or_insert(ApproxSketch(
+                        e.alias(sketch_id.clone()),
+                        SketchType::DDSketch,
+                    ));
+                second_stage_aggs
+                    .entry(merge_id.clone())
+                    .or_insert(MergeSketch(
+                        col(sketch_id).alias(merge_id.clone()),
+                        SketchType::DDSketch,
+                    ));
+                final_exprs.push(col(merge_id).alias(output_name));
+            }
+            MergeSketch(e, SketchType::DDSketch) => {
+                let merge_id = agg_expr.semantic_id(schema).id;
+                let merge_of_merge_id = MergeSketch(col(merge_id.clone()), SketchType::DDSketch)
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(merge_id.clone())
+                    .or_insert(MergeSketch(e.alias(merge_id.clone()), SketchType::DDSketch));
+                second_stage_aggs
+                    .entry(merge_of_merge_id.clone())
+                    .or_insert(MergeSketch(
+                        col(merge_id).alias(merge_of_merge_id.clone()),
+                        SketchType::DDSketch,
+                    ));
+                final_exprs.push(col(merge_of_merge_id).alias(output_name));
+            }
             ApproxSketch(..) => {
                 unimplemented!("User-facing approx_sketch aggregation is not implemented")
             }
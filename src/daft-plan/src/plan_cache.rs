@@ -0,0 +1,636 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Utc};
+use common_error::DaftResult;
+use common_treenode::Transformed;
+use daft_core::schema::SchemaRef;
+use daft_scan::{
+    file_format::FileFormatConfig, storage_config::StorageConfig, ChunkSpec, DataSource,
+    DeletionVector, IcebergEqualityDeleteFile, PartitionField, Pushdowns,
+};
+use lazy_static::lazy_static;
+
+use crate::{
+    logical_ops::{Sample, Source},
+    partitioning::ClusteringSpec,
+    source_info::{InMemoryInfo, PlaceHolderInfo, SourceInfo},
+    LogicalPlan,
+};
+
+/// Default capacity of the process-wide plan result cache: 1 GiB.
+pub const DEFAULT_PLAN_RESULT_CACHE_SIZE_BYTES: usize = 1024 * 1024 * 1024;
+
+lazy_static! {
+    static ref PLAN_RESULT_CACHE: Mutex<PlanResultCache> =
+        Mutex::new(PlanResultCache::new(DEFAULT_PLAN_RESULT_CACHE_SIZE_BYTES));
+}
+
+/// Get the process-wide plan result cache, shared by all DataFrames.
+pub fn global_plan_result_cache() -> &'static Mutex<PlanResultCache> {
+    &PLAN_RESULT_CACHE
+}
+
+/// Substitute the largest subtrees of the plan that have results in the process-wide plan result cache with scans of
+/// those results.
+///
+/// The plan is fingerprinted without holding the cache's lock, since that lists the files of the plan's Sources.
+pub fn substitute_globally_cached_results(
+    plan: Arc<LogicalPlan>,
+) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+    if global_plan_result_cache().lock().unwrap().is_empty() {
+        return Ok(Transformed::no(plan));
+    }
+    let mut fingerprints = HashMap::new();
+    fingerprint_subtrees(plan.as_ref(), &mut fingerprints)?;
+    Ok(global_plan_result_cache()
+        .lock()
+        .unwrap()
+        .substitute_fingerprinted(plan, &fingerprints))
+}
+
+/// A fingerprint of the results of a logical plan, such that two independently built plans that compute the same
+/// results have equal fingerprints.
+///
+/// Fingerprints are hashed by a precomputed 64-bit hash, but compared by a canonical description of their plan, so
+/// that plans whose hashes collide are never mistaken for each other.
+#[derive(Debug, Clone)]
+pub struct PlanFingerprint {
+    hash: u64,
+    key: Arc<PlanKey>,
+}
+
+impl PlanFingerprint {
+    fn new(key: PlanKey) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Self {
+            hash: hasher.finish(),
+            key: key.into(),
+        }
+    }
+}
+
+impl PartialEq for PlanFingerprint {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && (Arc::ptr_eq(&self.key, &other.key) || self.key == other.key)
+    }
+}
+
+impl Eq for PlanFingerprint {}
+
+impl Hash for PlanFingerprint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+/// A canonical description of the results of a logical plan.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum PlanKey {
+    InMemory {
+        schema: SchemaRef,
+        info: InMemoryInfo,
+    },
+    Scan {
+        schema: SchemaRef,
+        scan_tasks: Vec<ScanTaskKey>,
+        source_schema: SchemaRef,
+        partitioning_keys: Vec<PartitionField>,
+        pushdowns: Pushdowns,
+    },
+    /// A node with its children replaced by schema-preserving placeholders, and the fingerprints of those children.
+    Node {
+        node: LogicalPlan,
+        children: Vec<PlanFingerprint>,
+    },
+}
+
+/// How a scan task would read its files.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct ScanTaskKey {
+    file_format_config: Arc<FileFormatConfig>,
+    storage_config: Arc<StorageConfig>,
+    files: Vec<ScanFileKey>,
+}
+
+/// A version of a file that a scan task would read.
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct ScanFileKey {
+    path: String,
+    chunk_spec: Option<ChunkSpec>,
+    size_bytes: u64,
+    etag: Option<String>,
+    last_modified: Option<DateTime<Utc>>,
+    iceberg_delete_files: Option<Vec<String>>,
+    iceberg_equality_delete_files: Option<Vec<IcebergEqualityDeleteFile>>,
+    delta_deletion_vector: Option<DeletionVector>,
+    num_rows: Option<usize>,
+}
+
+/// Fingerprint a logical plan, such that two independently built plans that compute the same results have the same
+/// fingerprint.
+///
+/// Sources are fingerprinted by the files that they would scan (their paths, sizes, ETags, last-modified times and
+/// delete files) and how they would be read (their format and storage configs), rather than by the identity of their
+/// scan operator, so this lists the files of every Source in the plan.
+/// Returns None if the plan's results can't be identified by its fingerprint, e.g. if it contains an unseeded Sample
+/// or a Source whose files don't report their size, or neither an ETag nor a last-modified time.
+pub fn plan_fingerprint(plan: &Arc<LogicalPlan>) -> DaftResult<Option<PlanFingerprint>> {
+    fingerprint_subtrees(plan.as_ref(), &mut HashMap::new())
+}
+
+/// Fingerprint every subtree of the plan, recording the fingerprints of fingerprintable subtrees by node address.
+fn fingerprint_subtrees(
+    plan: &LogicalPlan,
+    fingerprints: &mut HashMap<*const LogicalPlan, PlanFingerprint>,
+) -> DaftResult<Option<PlanFingerprint>> {
+    let key = match plan {
+        LogicalPlan::Source(Source {
+            output_schema,
+            source_info,
+        }) => match source_info.as_ref() {
+            SourceInfo::InMemory(info) => PlanKey::InMemory {
+                schema: output_schema.clone(),
+                info: info.clone(),
+            },
+            SourceInfo::Physical(scan_info) => {
+                let Some(scan_tasks) = scan_task_keys(scan_info)? else {
+                    return Ok(None);
+                };
+                PlanKey::Scan {
+                    schema: output_schema.clone(),
+                    scan_tasks,
+                    source_schema: scan_info.source_schema.clone(),
+                    partitioning_keys: scan_info.partitioning_keys.clone(),
+                    pushdowns: scan_info.pushdowns.clone(),
+                }
+            }
+            SourceInfo::PlaceHolder(_) => return Ok(None),
+        },
+        LogicalPlan::Sample(Sample { seed: None, .. }) => return Ok(None),
+        _ => {
+            let placeholders = plan
+                .children()
+                .into_iter()
+                .map(|child| {
+                    let schema = child.schema();
+                    let placeholder = PlaceHolderInfo {
+                        source_schema: schema.clone(),
+                        clustering_spec: ClusteringSpec::unknown().into(),
+                        source_id: 0,
+                    };
+                    Arc::new(LogicalPlan::Source(Source::new(
+                        schema,
+                        SourceInfo::PlaceHolder(placeholder).into(),
+                    )))
+                })
+                .collect::<Vec<_>>();
+            let mut children = vec![];
+            let mut fingerprintable = true;
+            for child in plan.children() {
+                match fingerprint_subtrees(child, fingerprints)? {
+                    Some(fingerprint) => children.push(fingerprint),
+                    None => fingerprintable = false,
+                }
+            }
+            if !fingerprintable {
+                return Ok(None);
+            }
+            PlanKey::Node {
+                node: plan.with_new_children(&placeholders),
+                children,
+            }
+        }
+    };
+    let fingerprint = PlanFingerprint::new(key);
+    fingerprints.insert(plan as *const LogicalPlan, fingerprint.clone());
+    Ok(Some(fingerprint))
+}
+
+/// Describe the files that a scan would read and how it would read them, returning None if any of them can't be
+/// identified, i.e. doesn't report its size or a version of its contents.
+fn scan_task_keys(scan_info: &daft_scan::PhysicalScanInfo) -> DaftResult<Option<Vec<ScanTaskKey>>> {
+    let mut keys = vec![];
+    for scan_task in scan_info
+        .scan_op
+        .0
+        .to_scan_tasks(scan_info.pushdowns.clone())?
+    {
+        let scan_task = scan_task?;
+        let mut files = vec![];
+        for source in scan_task.sources.iter() {
+            let DataSource::File {
+                path,
+                chunk_spec,
+                size_bytes: Some(size_bytes),
+                iceberg_delete_files,
+                iceberg_equality_delete_files,
+                delta_deletion_vector,
+                metadata,
+                etag,
+                last_modified,
+                ..
+            } = source
+            else {
+                return Ok(None);
+            };
+            // An overwritten file can keep its path and size, so its contents must be versioned.
+            if etag.is_none() && last_modified.is_none() {
+                return Ok(None);
+            }
+            files.push(ScanFileKey {
+                path: path.clone(),
+                chunk_spec: chunk_spec.clone(),
+                size_bytes: *size_bytes,
+                etag: etag.clone(),
+                last_modified: *last_modified,
+                iceberg_delete_files: iceberg_delete_files.clone(),
+                iceberg_equality_delete_files: iceberg_equality_delete_files.clone(),
+                delta_deletion_vector: delta_deletion_vector.clone(),
+                num_rows: metadata.as_ref().map(|metadata| metadata.length),
+            });
+        }
+        keys.push(ScanTaskKey {
+            file_format_config: scan_task.file_format_config.clone(),
+            storage_config: scan_task.storage_config.clone(),
+            files,
+        });
+    }
+    Ok(Some(keys))
+}
+
+struct CachedResult {
+    info: InMemoryInfo,
+    last_used: u64,
+}
+
+/// A cache of materialized plan results, keyed by plan fingerprint and evicted least-recently-used first once the
+/// total size of the cached results exceeds its capacity.
+pub struct PlanResultCache {
+    capacity_bytes: usize,
+    size_bytes: usize,
+    entries: HashMap<PlanFingerprint, CachedResult>,
+    clock: u64,
+}
+
+impl PlanResultCache {
+    pub fn new(capacity_bytes: usize) -> Self {
+        Self {
+            capacity_bytes,
+            size_bytes: 0,
+            entries: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    pub fn capacity_bytes(&self) -> usize {
+        self.capacity_bytes
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Set the capacity of the cache, evicting results until they fit within it.
+    pub fn set_capacity_bytes(&mut self, capacity_bytes: usize) {
+        self.capacity_bytes = capacity_bytes;
+        self.evict_to(capacity_bytes);
+    }
+
+    /// Get the cached results for a plan fingerprint, marking them as recently used.
+    pub fn get(&mut self, fingerprint: &PlanFingerprint) -> Option<InMemoryInfo> {
+        self.clock += 1;
+        let entry = self.entries.get_mut(fingerprint)?;
+        entry.last_used = self.clock;
+        Some(entry.info.clone())
+    }
+
+    /// Cache the results for a plan fingerprint, evicting the least recently used results to make room for them.
+    /// Returns false if the results are larger than the cache's capacity, in which case they aren't cached.
+    pub fn insert(&mut self, fingerprint: PlanFingerprint, info: InMemoryInfo) -> bool {
+        self.remove(&fingerprint);
+        if info.size_bytes > self.capacity_bytes {
+            return false;
+        }
+        self.evict_to(self.capacity_bytes - info.size_bytes);
+        self.clock += 1;
+        self.size_bytes += info.size_bytes;
+        self.entries.insert(
+            fingerprint,
+            CachedResult {
+                info,
+                last_used: self.clock,
+            },
+        );
+        true
+    }
+
+    pub fn remove(&mut self, fingerprint: &PlanFingerprint) -> Option<InMemoryInfo> {
+        let entry = self.entries.remove(fingerprint)?;
+        self.size_bytes -= entry.info.size_bytes;
+        Some(entry.info)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.size_bytes = 0;
+    }
+
+    fn evict_to(&mut self, size_bytes: usize) {
+        while self.size_bytes > size_bytes {
+            let lru = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .unwrap()
+                .0
+                .clone();
+            self.remove(&lru);
+        }
+    }
+
+    /// Substitute the largest subtrees of the plan that have cached results with scans of those results.
+    pub fn substitute_cached_results(
+        &mut self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        if self.is_empty() {
+            return Ok(Transformed::no(plan));
+        }
+        let mut fingerprints = HashMap::new();
+        fingerprint_subtrees(plan.as_ref(), &mut fingerprints)?;
+        Ok(self.substitute_fingerprinted(plan, &fingerprints))
+    }
+
+    fn substitute_fingerprinted(
+        &mut self,
+        plan: Arc<LogicalPlan>,
+        fingerprints: &HashMap<*const LogicalPlan, PlanFingerprint>,
+    ) -> Transformed<Arc<LogicalPlan>> {
+        match self.substitute_node(plan.as_ref(), fingerprints) {
+            Some(new_plan) => Transformed::yes(new_plan.into()),
+            None => Transformed::no(plan),
+        }
+    }
+
+    fn substitute_node(
+        &mut self,
+        plan: &LogicalPlan,
+        fingerprints: &HashMap<*const LogicalPlan, PlanFingerprint>,
+    ) -> Option<LogicalPlan> {
+        if let LogicalPlan::Source(Source { source_info, .. }) = plan
+            && matches!(source_info.as_ref(), SourceInfo::InMemory(_))
+        {
+            return None;
+        }
+        if let Some(info) = fingerprints
+            .get(&(plan as *const LogicalPlan))
+            .and_then(|fingerprint| self.get(fingerprint))
+        {
+            return Some(LogicalPlan::Source(Source::new(
+                plan.schema(),
+                SourceInfo::InMemory(info).into(),
+            )));
+        }
+        let children = plan
+            .children()
+            .into_iter()
+            .map(|child| (child, self.substitute_node(child, fingerprints)))
+            .collect::<Vec<_>>();
+        if children.iter().all(|(_, new_child)| new_child.is_none()) {
+            return None;
+        }
+        let children = children
+            .into_iter()
+            .map(|(child, new_child)| Arc::new(new_child.unwrap_or_else(|| child.clone())))
+            .collect::<Vec<_>>();
+        Some(plan.with_new_children(&children))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::DateTime;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_scan::{
+        file_format::{FileFormatConfig, JsonSourceConfig},
+        storage_config::{NativeStorageConfig, StorageConfig},
+        DataSource, MaterializedScanOperator, Pushdowns, ScanOperatorRef, ScanTask,
+    };
+
+    use crate::{
+        logical_ops::Source,
+        plan_cache::{plan_fingerprint, PlanFingerprint},
+        source_info::SourceInfo,
+        InMemoryInfo, LogicalPlan, LogicalPlanBuilder, PlanResultCache,
+    };
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new("a", DataType::Int64)]).unwrap())
+    }
+
+    /// Create a scan node over files with the provided paths and sizes, backed by a fresh scan operator.
+    fn scan_node(files: &[(&str, Option<u64>)]) -> LogicalPlanBuilder {
+        let files = files
+            .iter()
+            .map(|(path, size_bytes)| (*path, *size_bytes, Some(0)))
+            .collect::<Vec<_>>();
+        versioned_scan_node(&files, FileFormatConfig::Json(Default::default()))
+    }
+
+    /// Create a scan node over files with the provided paths, sizes and last-modified times in seconds since the Unix
+    /// epoch, backed by a fresh scan operator.
+    fn versioned_scan_node(
+        files: &[(&str, Option<u64>, Option<i64>)],
+        file_format_config: FileFormatConfig,
+    ) -> LogicalPlanBuilder {
+        let file_format_config = Arc::new(file_format_config);
+        let scan_tasks = files
+            .iter()
+            .map(|(path, size_bytes, last_modified)| {
+                Arc::new(ScanTask::new(
+                    vec![DataSource::File {
+                        path: path.to_string(),
+                        chunk_spec: None,
+                        size_bytes: *size_bytes,
+                        iceberg_delete_files: None,
+                        iceberg_equality_delete_files: None,
+                        delta_deletion_vector: None,
+                        metadata: None,
+                        partition_spec: None,
+                        statistics: None,
+                        parquet_metadata: None,
+                        etag: None,
+                        last_modified: last_modified
+                            .and_then(|secs| DateTime::from_timestamp(secs, 0)),
+                    }],
+                    file_format_config.clone(),
+                    schema(),
+                    Arc::new(StorageConfig::Native(
+                        NativeStorageConfig::new_internal(true, None).into(),
+                    )),
+                    Pushdowns::default(),
+                ))
+            })
+            .collect();
+        let scan_op = MaterializedScanOperator::new(scan_tasks, schema(), vec![]);
+        LogicalPlanBuilder::table_scan(ScanOperatorRef(Arc::new(scan_op)), None).unwrap()
+    }
+
+    fn in_memory_info(cache_key: &str, size_bytes: usize) -> InMemoryInfo {
+        InMemoryInfo {
+            source_schema: schema(),
+            cache_key: cache_key.to_string(),
+            #[cfg(feature = "python")]
+            cache_entry: pyo3::Python::with_gil(|py| py.None()),
+            num_partitions: 1,
+            size_bytes,
+            num_rows: 1,
+            clustering_spec: None,
+        }
+    }
+
+    /// Tests that independently built plans over the same files have the same fingerprint, and that plans over
+    /// changed files or with different operators don't.
+    #[test]
+    fn fingerprint_identifies_plan_results() -> DaftResult<()> {
+        let files = [("/foo/1.json", Some(10)), ("/foo/2.json", Some(20))];
+        let plan = |files: &[(&str, Option<u64>)], threshold: i64| {
+            scan_node(files)
+                .filter(col("a").lt(lit(threshold)))
+                .unwrap()
+                .build()
+        };
+        let fingerprint = plan_fingerprint(&plan(&files, 2))?;
+        assert!(fingerprint.is_some());
+        assert_eq!(plan_fingerprint(&plan(&files, 2))?, fingerprint);
+        assert_ne!(plan_fingerprint(&plan(&files, 3))?, fingerprint);
+        let resized = [("/foo/1.json", Some(10)), ("/foo/2.json", Some(21))];
+        assert_ne!(plan_fingerprint(&plan(&resized, 2))?, fingerprint);
+        let missing_size = [("/foo/1.json", Some(10)), ("/foo/2.json", None)];
+        assert_eq!(plan_fingerprint(&plan(&missing_size, 2))?, None);
+        let sampled = scan_node(&files).sample(0.5, false, None)?.build();
+        assert_eq!(plan_fingerprint(&sampled)?, None);
+        Ok(())
+    }
+
+    /// Tests that plans over files that were overwritten with contents of the same size, that can't be versioned, or
+    /// that are read with a different config don't have the same fingerprint.
+    #[test]
+    fn fingerprint_identifies_file_versions() -> DaftResult<()> {
+        let json = || FileFormatConfig::Json(Default::default());
+        let files = [("/foo/1.json", Some(10), Some(100))];
+        let fingerprint = plan_fingerprint(&versioned_scan_node(&files, json()).build())?;
+        assert!(fingerprint.is_some());
+        assert_eq!(
+            plan_fingerprint(&versioned_scan_node(&files, json()).build())?,
+            fingerprint
+        );
+        let overwritten = [("/foo/1.json", Some(10), Some(200))];
+        assert_ne!(
+            plan_fingerprint(&versioned_scan_node(&overwritten, json()).build())?,
+            fingerprint
+        );
+        let unversioned = [("/foo/1.json", Some(10), None)];
+        assert_eq!(
+            plan_fingerprint(&versioned_scan_node(&unversioned, json()).build())?,
+            None
+        );
+        let reconfigured = FileFormatConfig::Json(JsonSourceConfig::new_internal(Some(1024), None));
+        assert_ne!(
+            plan_fingerprint(&versioned_scan_node(&files, reconfigured).build())?,
+            fingerprint
+        );
+        Ok(())
+    }
+
+    /// Tests that the cache evicts the least recently used results once it exceeds its capacity.
+    #[test]
+    fn cache_evicts_least_recently_used() {
+        let fingerprint = |path: &str| {
+            plan_fingerprint(&scan_node(&[(path, Some(10))]).build())
+                .unwrap()
+                .unwrap()
+        };
+        let mut cache = PlanResultCache::new(100);
+        assert!(cache.insert(fingerprint("/foo/1.json"), in_memory_info("1", 40)));
+        assert!(cache.insert(fingerprint("/foo/2.json"), in_memory_info("2", 40)));
+        assert!(cache.get(&fingerprint("/foo/1.json")).is_some());
+        assert!(cache.insert(fingerprint("/foo/3.json"), in_memory_info("3", 40)));
+        assert_eq!(cache.get(&fingerprint("/foo/2.json")), None);
+        assert!(cache.get(&fingerprint("/foo/1.json")).is_some());
+        assert!(cache.get(&fingerprint("/foo/3.json")).is_some());
+        assert_eq!(cache.size_bytes(), 80);
+        assert!(!cache.insert(fingerprint("/foo/4.json"), in_memory_info("4", 101)));
+        assert_eq!(cache.len(), 2);
+        cache.set_capacity_bytes(50);
+        assert_eq!(cache.get(&fingerprint("/foo/1.json")), None);
+        assert_eq!(cache.size_bytes(), 40);
+    }
+
+    /// Tests that a plan whose fingerprint's hash collides with a cached plan's doesn't get the cached plan's results.
+    #[test]
+    fn cache_distinguishes_colliding_fingerprints() -> DaftResult<()> {
+        let cached = plan_fingerprint(&scan_node(&[("/foo/1.json", Some(10))]).build())?.unwrap();
+        let other = plan_fingerprint(&scan_node(&[("/foo/2.json", Some(10))]).build())?.unwrap();
+        let colliding = PlanFingerprint {
+            hash: cached.hash,
+            key: other.key,
+        };
+        let mut cache = PlanResultCache::new(100);
+        cache.insert(cached.clone(), in_memory_info("1", 10));
+        assert_eq!(cache.get(&colliding), None);
+        assert!(cache.get(&cached).is_some());
+        Ok(())
+    }
+
+    /// Tests that cached subtrees of a plan are substituted with scans of their results.
+    #[test]
+    fn cached_subtree_substituted() -> DaftResult<()> {
+        let files = [("/foo/1.json", Some(10))];
+        let filtered = scan_node(&files).filter(col("a").lt(lit(2)))?;
+        let mut cache = PlanResultCache::new(100);
+        cache.insert(
+            plan_fingerprint(&filtered.build())?.unwrap(),
+            in_memory_info("filtered", 10),
+        );
+
+        let plan = scan_node(&files)
+            .filter(col("a").lt(lit(2)))?
+            .limit(5, false)?
+            .build();
+        let substituted = cache.substitute_cached_results(plan)?;
+        assert!(substituted.transformed);
+        let expected: Arc<LogicalPlan> = LogicalPlanBuilder::new(
+            LogicalPlan::Source(Source::new(
+                schema(),
+                SourceInfo::InMemory(in_memory_info("filtered", 10)).into(),
+            ))
+            .into(),
+            None,
+        )
+        .limit(5, false)?
+        .build();
+        assert_eq!(substituted.data, expected);
+
+        let uncached = scan_node(&files).filter(col("a").lt(lit(3)))?.build();
+        assert!(!cache.substitute_cached_results(uncached)?.transformed);
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use common_error::DaftResult;
 use daft_core::schema::SchemaRef;
 
 use crate::{
-    file_format::{FileFormatConfig, ParquetSourceConfig},
+    file_format::{FileFormatConfig, IpcSourceConfig, OrcSourceConfig, ParquetSourceConfig},
     storage_config::StorageConfig,
     ChunkSpec, DataSource, PartitionField, Pushdowns, ScanOperator, ScanTask, ScanTaskRef,
 };
@@ -50,6 +50,9 @@ impl ScanOperator for AnonymousScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_provide_metadata(&self) -> bool {
+        self.file_format_config.provides_metadata()
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![
@@ -71,30 +74,46 @@ impl ScanOperator for AnonymousScanOperator {
         let schema = self.schema.clone();
         let storage_config = self.storage_config.clone();
 
-        let row_groups = if let FileFormatConfig::Parquet(ParquetSourceConfig {
-            row_groups: Some(row_groups),
-            ..
-        }) = self.file_format_config.as_ref()
-        {
-            row_groups.clone()
-        } else {
-            std::iter::repeat(None).take(files.len()).collect()
+        let chunk_specs: Vec<Option<ChunkSpec>> = match self.file_format_config.as_ref() {
+            FileFormatConfig::Parquet(ParquetSourceConfig {
+                row_groups: Some(row_groups),
+                ..
+            }) => row_groups
+                .iter()
+                .map(|rg| rg.clone().map(ChunkSpec::Parquet))
+                .collect(),
+            FileFormatConfig::Ipc(IpcSourceConfig {
+                record_batches: Some(record_batches),
+            }) => record_batches
+                .iter()
+                .map(|rb| rb.clone().map(ChunkSpec::Ipc))
+                .collect(),
+            FileFormatConfig::Orc(OrcSourceConfig {
+                stripes: Some(stripes),
+            }) => stripes
+                .iter()
+                .map(|s| s.clone().map(ChunkSpec::Orc))
+                .collect(),
+            _ => std::iter::repeat(None).take(files.len()).collect(),
         };
 
         // Create one ScanTask per file.
-        Ok(Box::new(files.into_iter().zip(row_groups).map(
-            move |(f, rg)| {
-                let chunk_spec = rg.map(ChunkSpec::Parquet);
+        Ok(Box::new(files.into_iter().zip(chunk_specs).map(
+            move |(f, chunk_spec)| {
                 Ok(ScanTask::new(
                     vec![DataSource::File {
                         path: f.to_string(),
                         chunk_spec,
                         size_bytes: None,
                         iceberg_delete_files: None,
+                        iceberg_equality_delete_files: None,
+                        delta_deletion_vector: None,
                         metadata: None,
                         partition_spec: None,
                         statistics: None,
                         parquet_metadata: None,
+                        etag: None,
+                        last_modified: None,
                     }],
                     file_format_config.clone(),
                     schema.clone(),
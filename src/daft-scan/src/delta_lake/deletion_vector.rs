@@ -0,0 +1,344 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_io::{IOClient, IOStatsRef};
+use serde::{Deserialize, Serialize};
+
+/// Magic number that precedes serialized deletion vectors.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+/// Cookie of serialized roaring bitmaps that have run containers.
+const SERIAL_COOKIE: u32 = 12347;
+/// Cookie of serialized roaring bitmaps that don't have run containers.
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+/// Maximum cardinality of array containers of roaring bitmaps, above which containers are bitmaps.
+const MAX_ARRAY_CONTAINER_CARDINALITY: usize = 4096;
+/// Z85 alphabet, which Delta Lake uses to encode inline deletion vectors and the UUIDs of deletion vector files.
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+fn invalid_deletion_vector(reason: impl Into<String>) -> DaftError {
+    DaftError::ValueError(format!(
+        "Invalid Delta Lake deletion vector: {}",
+        reason.into()
+    ))
+}
+
+/// Where the serialized bitmap of a deletion vector is stored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeletionVectorStorage {
+    /// Stored in the file at `path`, starting at `offset`.
+    File { path: String, offset: u64 },
+    /// Stored in the transaction log itself.
+    Inline(Vec<u8>),
+}
+
+/// A Delta Lake deletion vector, which marks the rows of a data file that are deleted by their positions in the file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DeletionVector {
+    pub storage: DeletionVectorStorage,
+    pub size_in_bytes: u32,
+    /// Number of deleted rows.
+    pub cardinality: u64,
+}
+
+/// Decodes Z85 text whose length is a multiple of 5.
+fn decode_z85(text: &str) -> DaftResult<Vec<u8>> {
+    if text.len() % 5 != 0 {
+        return Err(invalid_deletion_vector(format!(
+            "Z85 text of length {} isn't a multiple of 5",
+            text.len()
+        )));
+    }
+    let mut decoded = Vec::with_capacity(text.len() / 5 * 4);
+    for chunk in text.as_bytes().chunks(5) {
+        let mut value = 0u64;
+        for c in chunk {
+            let digit = Z85_ALPHABET
+                .iter()
+                .position(|a| a == c)
+                .ok_or_else(|| invalid_deletion_vector(format!("invalid Z85 character {c}")))?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| invalid_deletion_vector("Z85 value overflows 32 bits"))?;
+        decoded.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+impl DeletionVector {
+    /// Resolves the descriptor of a deletion vector from the transaction log of the table at `table_uri`.
+    ///
+    /// See: https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vector-descriptor-schema
+    pub(crate) fn from_descriptor(
+        table_uri: &str,
+        storage_type: &str,
+        path_or_inline_dv: &str,
+        offset: Option<u64>,
+        size_in_bytes: u32,
+        cardinality: u64,
+    ) -> DaftResult<Self> {
+        let storage = match storage_type {
+            // Relative paths are a random prefix followed by the Z85-encoded UUID of the file.
+            "u" => {
+                let split = path_or_inline_dv
+                    .len()
+                    .checked_sub(20)
+                    .ok_or_else(|| invalid_deletion_vector("relative path is too short"))?;
+                let (prefix, encoded_uuid) = path_or_inline_dv.split_at(split);
+                let uuid: [u8; 16] = decode_z85(encoded_uuid)?.try_into().unwrap();
+                let hex = format!("{:032x}", u128::from_be_bytes(uuid));
+                let file_name = format!(
+                    "deletion_vector_{}-{}-{}-{}-{}.bin",
+                    &hex[0..8],
+                    &hex[8..12],
+                    &hex[12..16],
+                    &hex[16..20],
+                    &hex[20..32]
+                );
+                let path = match prefix {
+                    "" => format!("{table_uri}/{file_name}"),
+                    prefix => format!("{table_uri}/{prefix}/{file_name}"),
+                };
+                DeletionVectorStorage::File {
+                    path,
+                    offset: offset.unwrap_or(1),
+                }
+            }
+            "p" => DeletionVectorStorage::File {
+                path: path_or_inline_dv.to_string(),
+                offset: offset.unwrap_or(1),
+            },
+            "i" => {
+                let mut data = decode_z85(path_or_inline_dv)?;
+                data.truncate(size_in_bytes as usize);
+                DeletionVectorStorage::Inline(data)
+            }
+            _ => {
+                return Err(invalid_deletion_vector(format!(
+                    "unknown storage type {storage_type}"
+                )))
+            }
+        };
+        Ok(Self {
+            storage,
+            size_in_bytes,
+            cardinality,
+        })
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> DaftResult<&'a [u8]> {
+        if self.buf.len() < n {
+            return Err(invalid_deletion_vector("unexpected end of bitmap"));
+        }
+        let (taken, rest) = self.buf.split_at(n);
+        self.buf = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> DaftResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> DaftResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> DaftResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Appends the values of a 32-bit roaring bitmap in the portable serialization format to `values`, adding `high` to
+/// each of them.
+///
+/// See: https://github.com/RoaringBitmap/RoaringFormatSpec
+fn decode_roaring_bitmap(reader: &mut Reader, high: u64, values: &mut Vec<i64>) -> DaftResult<()> {
+    let cookie = reader.u32()?;
+    let (num_containers, run_flags) = if cookie & 0xffff == SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        let run_flags = reader.take(num_containers.div_ceil(8))?;
+        (num_containers, Some(run_flags))
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else {
+        return Err(invalid_deletion_vector(format!(
+            "unknown roaring bitmap cookie {cookie}"
+        )));
+    };
+    let headers = (0..num_containers)
+        .map(|_| Ok((reader.u16()?, reader.u16()? as usize + 1)))
+        .collect::<DaftResult<Vec<_>>>()?;
+    // Offsets of the containers are only written when they're needed for random access.
+    if run_flags.is_none() || num_containers >= 4 {
+        reader.take(num_containers * 4)?;
+    }
+    for (i, (key, cardinality)) in headers.into_iter().enumerate() {
+        let base = high | ((key as u64) << 16);
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+        if is_run {
+            let num_runs = reader.u16()?;
+            for _ in 0..num_runs {
+                let start = reader.u16()? as u64;
+                let length = reader.u16()? as u64;
+                values.extend((start..=start + length).map(|v| (base | v) as i64));
+            }
+        } else if cardinality <= MAX_ARRAY_CONTAINER_CARDINALITY {
+            for _ in 0..cardinality {
+                values.push((base | reader.u16()? as u64) as i64);
+            }
+        } else {
+            for word_index in 0..1024u64 {
+                let mut word = reader.u64()?;
+                while word != 0 {
+                    let bit = word.trailing_zeros() as u64;
+                    values.push((base | (word_index * 64 + bit)) as i64);
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Decodes the positions of the deleted rows of a serialized deletion vector, which is a magic number followed by a
+/// 64-bit roaring bitmap that is made of 32-bit roaring bitmaps of the values with the same 32 high bits.
+fn decode_deletion_vector(data: &[u8]) -> DaftResult<Vec<i64>> {
+    let mut reader = Reader { buf: data };
+    let magic = reader.u32()?;
+    if magic != DELETION_VECTOR_MAGIC {
+        return Err(invalid_deletion_vector(format!(
+            "unexpected magic number {magic}"
+        )));
+    }
+    let num_bitmaps = reader.u64()?;
+    let mut values = vec![];
+    for _ in 0..num_bitmaps {
+        let high = (reader.u32()? as u64) << 32;
+        decode_roaring_bitmap(&mut reader, high, &mut values)?;
+    }
+    Ok(values)
+}
+
+/// Reads the positions of the rows that are deleted by a deletion vector.
+pub async fn read_deletion_vector(
+    deletion_vector: &DeletionVector,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<i64>> {
+    let positions = match &deletion_vector.storage {
+        DeletionVectorStorage::Inline(data) => decode_deletion_vector(data)?,
+        DeletionVectorStorage::File { path, offset } => {
+            // Deletion vectors in files are prefixed by their sizes and followed by their checksums.
+            let start = *offset as usize;
+            let end = start + 4 + deletion_vector.size_in_bytes as usize + 4;
+            let bytes = io_client
+                .single_url_get(path.clone(), Some(start..end), io_stats)
+                .await?
+                .bytes()
+                .await?;
+            if bytes.len() != end - start {
+                return Err(invalid_deletion_vector(format!(
+                    "{path} is too short to hold a deletion vector at offset {offset}"
+                )));
+            }
+            let (data, checksum) = bytes[4..].split_at(deletion_vector.size_in_bytes as usize);
+            if crc32fast::hash(data) != u32::from_be_bytes(checksum.try_into().unwrap()) {
+                return Err(invalid_deletion_vector(format!(
+                    "checksum mismatch in {path} at offset {offset}"
+                )));
+            }
+            decode_deletion_vector(data)?
+        }
+    };
+    if positions.len() as u64 != deletion_vector.cardinality {
+        return Err(invalid_deletion_vector(format!(
+            "expected {} deleted rows, but found {}",
+            deletion_vector.cardinality,
+            positions.len()
+        )));
+    }
+    Ok(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        decode_deletion_vector, decode_z85, DeletionVector, DeletionVectorStorage,
+        DELETION_VECTOR_MAGIC, SERIAL_COOKIE, SERIAL_COOKIE_NO_RUNCONTAINER,
+    };
+
+    #[test]
+    fn test_decode_z85() {
+        // Example from the Z85 specification.
+        assert_eq!(
+            decode_z85("HelloWorld").unwrap(),
+            vec![0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b]
+        );
+        assert!(decode_z85("Hello").is_ok());
+        assert!(decode_z85("Hell").is_err());
+        assert!(decode_z85("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_relative_path() {
+        let dv = DeletionVector::from_descriptor(
+            "s3://bucket/table",
+            "u",
+            "ab^-aqEH.-t@S}K{vb[*k^",
+            Some(4),
+            40,
+            6,
+        )
+        .unwrap();
+        assert_eq!(
+            dv.storage,
+            DeletionVectorStorage::File {
+                path:
+                    "s3://bucket/table/ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin"
+                        .to_string(),
+                offset: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_deletion_vector() {
+        let mut data = DELETION_VECTOR_MAGIC.to_le_bytes().to_vec();
+        // Two 32-bit bitmaps, of the values with high bits 0 and 1.
+        data.extend(2u64.to_le_bytes());
+
+        // An array container with the values 3 and 7.
+        data.extend(0u32.to_le_bytes());
+        data.extend(SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        data.extend(1u32.to_le_bytes());
+        data.extend([0u16.to_le_bytes(), 1u16.to_le_bytes()].concat());
+        data.extend(0u32.to_le_bytes());
+        data.extend([3u16.to_le_bytes(), 7u16.to_le_bytes()].concat());
+
+        // A run container with the values 10 through 12 of the second key.
+        data.extend(1u32.to_le_bytes());
+        data.extend(SERIAL_COOKIE.to_le_bytes());
+        data.push(1);
+        data.extend([1u16.to_le_bytes(), 2u16.to_le_bytes()].concat());
+        data.extend([1u16.to_le_bytes(), 10u16.to_le_bytes(), 2u16.to_le_bytes()].concat());
+
+        let high = 1i64 << 32;
+        assert_eq!(
+            decode_deletion_vector(&data).unwrap(),
+            vec![
+                3,
+                7,
+                high | (1 << 16) | 10,
+                high | (1 << 16) | 11,
+                high | (1 << 16) | 12
+            ]
+        );
+    }
+}
@@ -0,0 +1,606 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use arrow2::array::{
+    Array, BooleanArray, ListArray, MapArray, PrimitiveArray, StructArray, Utf8Array,
+};
+use common_error::{DaftError, DaftResult};
+use daft_io::{IOClient, IOStatsRef, RuntimeRef};
+use daft_parquet::read::ParquetSchemaInferenceOptions;
+use futures::{StreamExt, TryStreamExt};
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Number of commit files that are read concurrently when replaying the transaction log.
+const COMMIT_READ_PARALLELISM: usize = 32;
+
+/// Reader features of the protocol that don't prevent reading tables, or that are handled by the reader.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+fn invalid_log(table_uri: &str, reason: impl Into<String>) -> DaftError {
+    DaftError::ValueError(format!(
+        "Invalid Delta Lake transaction log of {table_uri}: {}",
+        reason.into()
+    ))
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Protocol {
+    pub min_reader_version: i32,
+    pub reader_features: Option<Vec<String>>,
+}
+
+impl Protocol {
+    /// Returns an error if reading the table requires features that aren't supported.
+    ///
+    /// See: https://github.com/delta-io/delta/blob/master/PROTOCOL.md#table-features
+    fn check_supported(&self) -> DaftResult<()> {
+        if self.min_reader_version > 3 {
+            return Err(DaftError::ValueError(format!(
+                "Delta Lake tables of reader version {} are not supported",
+                self.min_reader_version
+            )));
+        }
+        if let Some(feature) = self
+            .reader_features
+            .iter()
+            .flatten()
+            .find(|f| !SUPPORTED_READER_FEATURES.contains(&f.as_str()))
+        {
+            return Err(DaftError::ValueError(format!(
+                "Delta Lake tables with the reader feature {feature} are not supported"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Metadata {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+}
+
+/// Descriptor of the deletion vector of a data file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DeletionVectorDescriptor {
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    pub offset: Option<i64>,
+    pub size_in_bytes: i64,
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Returns the identifier of the deletion vector, which tells apart the versions of a data file with different
+    /// deletion vectors.
+    fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{offset}", self.storage_type, self.path_or_inline_dv),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+}
+
+/// A data file that is added to the table.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Add {
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: HashMap<String, Option<String>>,
+    pub size: i64,
+    /// Time at which the file was created, in milliseconds since the Unix epoch.
+    pub modification_time: Option<i64>,
+    /// JSON-encoded statistics of the file.
+    pub stats: Option<String>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Remove {
+    path: String,
+    deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitInfo {
+    timestamp: Option<i64>,
+    in_commit_timestamp: Option<i64>,
+}
+
+/// An action of a commit or checkpoint. Actions that don't affect reads are ignored.
+///
+/// See: https://github.com/delta-io/delta/blob/master/PROTOCOL.md#actions
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    add: Option<Add>,
+    remove: Option<Remove>,
+    meta_data: Option<Metadata>,
+    protocol: Option<Protocol>,
+    commit_info: Option<CommitInfo>,
+}
+
+/// Kind of a file of the `_delta_log` directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LogFileKind {
+    Commit,
+    /// Part of a checkpoint, which is written in a single part or in multiple numbered parts.
+    Checkpoint {
+        part: u32,
+        num_parts: u32,
+    },
+}
+
+/// Parses the version and kind of a file of the `_delta_log` directory from its name. Returns `None` for other files,
+/// such as checksums, compacted commits and UUID-named checkpoints.
+pub(crate) fn parse_log_file_name(path: &str) -> Option<(i64, LogFileKind)> {
+    let name = path.rsplit('/').next()?;
+    let (version, rest) = name.split_once('.')?;
+    if version.len() != 20 || !version.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let version = version.parse().ok()?;
+    let kind = match rest.split('.').collect::<Vec<_>>()[..] {
+        ["json"] => LogFileKind::Commit,
+        ["checkpoint", "parquet"] => LogFileKind::Checkpoint {
+            part: 1,
+            num_parts: 1,
+        },
+        ["checkpoint", part, num_parts, "parquet"] if part.len() == 10 && num_parts.len() == 10 => {
+            LogFileKind::Checkpoint {
+                part: part.parse().ok()?,
+                num_parts: num_parts.parse().ok()?,
+            }
+        }
+        _ => return None,
+    };
+    Some((version, kind))
+}
+
+/// Converts the value at `index` of an array of a checkpoint to JSON, so that the actions of checkpoints are parsed
+/// like the actions of commits.
+fn arrow_to_json(array: &dyn Array, index: usize) -> Value {
+    if array.is_null(index) {
+        return Value::Null;
+    }
+    let any = array.as_any();
+    if let Some(array) = any.downcast_ref::<StructArray>() {
+        Value::Object(
+            array
+                .fields()
+                .iter()
+                .zip(array.values())
+                .map(|(field, values)| (field.name.clone(), arrow_to_json(values.as_ref(), index)))
+                .collect(),
+        )
+    } else if let Some(array) = any.downcast_ref::<MapArray>() {
+        let (start, end) = array.offsets().start_end(index);
+        let Some(entries) = array.field().as_any().downcast_ref::<StructArray>() else {
+            return Value::Null;
+        };
+        let [keys, values] = entries.values() else {
+            return Value::Null;
+        };
+        Value::Object(
+            (start..end)
+                .filter_map(|i| match arrow_to_json(keys.as_ref(), i) {
+                    Value::String(key) => Some((key, arrow_to_json(values.as_ref(), i))),
+                    _ => None,
+                })
+                .collect(),
+        )
+    } else if let Some(array) = any.downcast_ref::<ListArray<i64>>() {
+        let (start, end) = array.offsets().start_end(index);
+        Value::Array(
+            (start..end)
+                .map(|i| arrow_to_json(array.values().as_ref(), i))
+                .collect(),
+        )
+    } else if let Some(array) = any.downcast_ref::<Utf8Array<i64>>() {
+        Value::String(array.value(index).to_string())
+    } else if let Some(array) = any.downcast_ref::<BooleanArray>() {
+        Value::Bool(array.value(index))
+    } else if let Some(array) = any.downcast_ref::<PrimitiveArray<i32>>() {
+        Value::from(array.value(index))
+    } else if let Some(array) = any.downcast_ref::<PrimitiveArray<i64>>() {
+        Value::from(array.value(index))
+    } else {
+        Value::Null
+    }
+}
+
+/// A snapshot of a table at a version, from replaying its transaction log.
+#[derive(Debug, Clone)]
+pub(crate) struct LogSnapshot {
+    pub version: i64,
+    pub metadata: Metadata,
+    /// Live data files of the table.
+    pub files: Vec<Add>,
+}
+
+/// Files of the `_delta_log` directory of a table.
+struct LogListing {
+    /// Commit files and their modification times in milliseconds, keyed by version.
+    commits: BTreeMap<i64, (String, Option<i64>)>,
+    /// Parts of checkpoints, keyed by version and number of parts.
+    checkpoints: BTreeMap<(i64, u32), Vec<String>>,
+}
+
+/// Reader of the transaction log of a table.
+pub(crate) struct DeltaLog<'a> {
+    table_uri: &'a str,
+    io_client: Arc<IOClient>,
+    io_runtime: RuntimeRef,
+    io_stats: IOStatsRef,
+    multithreaded_io: bool,
+}
+
+impl<'a> DeltaLog<'a> {
+    pub fn new(
+        table_uri: &'a str,
+        io_client: Arc<IOClient>,
+        io_runtime: RuntimeRef,
+        io_stats: IOStatsRef,
+        multithreaded_io: bool,
+    ) -> Self {
+        Self {
+            table_uri,
+            io_client,
+            io_runtime,
+            io_stats,
+            multithreaded_io,
+        }
+    }
+
+    fn list(&self) -> DaftResult<LogListing> {
+        let files = self.io_runtime.block_on_current_thread(async {
+            self.io_client
+                .glob(
+                    format!("{}/_delta_log/*", self.table_uri),
+                    None,
+                    None,
+                    None,
+                    Some(self.io_stats.clone()),
+                    None,
+                )
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+        })?;
+        let mut commits = BTreeMap::new();
+        let mut checkpoints = BTreeMap::<_, Vec<_>>::new();
+        for file in files {
+            match parse_log_file_name(&file.filepath) {
+                Some((version, LogFileKind::Commit)) => {
+                    let last_modified = file.last_modified.map(|t| t.timestamp_millis());
+                    commits.insert(version, (file.filepath, last_modified));
+                }
+                Some((version, LogFileKind::Checkpoint { num_parts, .. })) => {
+                    checkpoints
+                        .entry((version, num_parts))
+                        .or_default()
+                        .push(file.filepath);
+                }
+                None => {}
+            }
+        }
+        if commits.is_empty() && checkpoints.is_empty() {
+            return Err(DaftError::ValueError(format!(
+                "No Delta Lake transaction log found in {}/_delta_log",
+                self.table_uri
+            )));
+        }
+        Ok(LogListing {
+            commits,
+            checkpoints,
+        })
+    }
+
+    async fn read_commit(&self, path: String) -> DaftResult<Vec<Action>> {
+        let bytes = self
+            .io_client
+            .single_url_get(path.clone(), None, Some(self.io_stats.clone()))
+            .await?
+            .bytes()
+            .await?;
+        std::str::from_utf8(&bytes)
+            .map_err(|e| invalid_log(self.table_uri, format!("{path}: {e}")))?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .map_err(|e| invalid_log(self.table_uri, format!("{path}: {e}")))
+            })
+            .collect()
+    }
+
+    fn read_checkpoint(&self, parts: &[String]) -> DaftResult<Vec<Action>> {
+        let mut actions = vec![];
+        for path in parts {
+            let table = daft_parquet::read::read_parquet(
+                path,
+                None,
+                None,
+                None,
+                None,
+                None,
+                self.io_client.clone(),
+                Some(self.io_stats.clone()),
+                self.multithreaded_io,
+                ParquetSchemaInferenceOptions::default(),
+                None,
+            )?;
+            let columns = ["add", "remove", "metaData", "protocol"]
+                .into_iter()
+                .filter_map(|name| Some((name, table.get_column(name).ok()?.to_arrow())))
+                .collect::<Vec<_>>();
+            for row in 0..table.len() {
+                let action = Value::Object(
+                    columns
+                        .iter()
+                        .map(|(name, array)| (name.to_string(), arrow_to_json(array.as_ref(), row)))
+                        .collect(),
+                );
+                actions.push(
+                    serde_json::from_value(action)
+                        .map_err(|e| invalid_log(self.table_uri, format!("{path}: {e}")))?,
+                );
+            }
+        }
+        Ok(actions)
+    }
+
+    /// Returns the timestamp of a commit in milliseconds, which is its in-commit timestamp if the table has them, or
+    /// the modification time of the commit file otherwise.
+    fn commit_timestamp(&self, path: &str, last_modified: Option<i64>) -> DaftResult<i64> {
+        let actions = self
+            .io_runtime
+            .block_on_current_thread(self.read_commit(path.to_string()))?;
+        let commit_info = actions.into_iter().find_map(|a| a.commit_info);
+        commit_info
+            .as_ref()
+            .and_then(|c| c.in_commit_timestamp)
+            .or(last_modified)
+            .or(commit_info.and_then(|c| c.timestamp))
+            .ok_or_else(|| invalid_log(self.table_uri, format!("{path} has no timestamp")))
+    }
+
+    /// Returns the latest version whose commit is at or before `timestamp_ms`.
+    fn version_at_timestamp(&self, listing: &LogListing, timestamp_ms: i64) -> DaftResult<i64> {
+        let versions = listing.commits.keys().copied().collect::<Vec<_>>();
+        // Binary search for the number of commits at or before the timestamp, since commit timestamps increase.
+        let (mut low, mut high) = (0, versions.len());
+        while low < high {
+            let mid = (low + high) / 2;
+            let (path, last_modified) = &listing.commits[&versions[mid]];
+            if self.commit_timestamp(path, *last_modified)? <= timestamp_ms {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        match low {
+            0 => Err(DaftError::ValueError(format!(
+                "The Delta Lake table {} has no versions at or before the timestamp {timestamp_ms}",
+                self.table_uri
+            ))),
+            n => Ok(versions[n - 1]),
+        }
+    }
+
+    /// Replays the transaction log to the snapshot at `version`, or at the latest version at or before
+    /// `timestamp_ms`, or at the latest version if neither is given.
+    pub fn snapshot(
+        &self,
+        version: Option<i64>,
+        timestamp_ms: Option<i64>,
+    ) -> DaftResult<LogSnapshot> {
+        let listing = self.list()?;
+        let latest_version = listing
+            .commits
+            .keys()
+            .chain(listing.checkpoints.keys().map(|(v, _)| v))
+            .copied()
+            .max()
+            .unwrap_or_default();
+        let version = match (version, timestamp_ms) {
+            (Some(version), _) if version > latest_version || version < 0 => {
+                return Err(DaftError::ValueError(format!(
+                    "Version {version} doesn't exist in the Delta Lake table {}, whose latest version is {latest_version}",
+                    self.table_uri
+                )))
+            }
+            (Some(version), _) => version,
+            (None, Some(timestamp_ms)) => self.version_at_timestamp(&listing, timestamp_ms)?,
+            (None, None) => latest_version,
+        };
+
+        // Start from the latest complete checkpoint at or before the version, and replay the commits after it.
+        let checkpoint = listing
+            .checkpoints
+            .iter()
+            .rev()
+            .find(|((v, num_parts), parts)| *v <= version && parts.len() == *num_parts as usize);
+        let first_commit = checkpoint.map_or(0, |((v, _), _)| v + 1);
+        let commit_paths = (first_commit..=version)
+            .map(|v| {
+                listing
+                    .commits
+                    .get(&v)
+                    .map(|(path, _)| path.clone())
+                    .ok_or_else(|| {
+                        invalid_log(
+                            self.table_uri,
+                            format!("missing commit of version {v} to read version {version}"),
+                        )
+                    })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut actions = match checkpoint {
+            Some((_, parts)) => {
+                let mut parts = parts.clone();
+                parts.sort();
+                self.read_checkpoint(&parts)?
+            }
+            None => vec![],
+        };
+        let commits = self.io_runtime.block_on_current_thread(
+            futures::stream::iter(commit_paths.into_iter().map(|path| self.read_commit(path)))
+                .buffered(COMMIT_READ_PARALLELISM)
+                .try_collect::<Vec<_>>(),
+        )?;
+        actions.extend(commits.into_iter().flatten());
+
+        // Versions of a data file with different deletion vectors are different files.
+        let mut files = IndexMap::new();
+        let mut protocol = None;
+        let mut metadata = None;
+        for action in actions {
+            if let Some(add) = action.add {
+                let id = add.deletion_vector.as_ref().map(|dv| dv.unique_id());
+                files.insert((add.path.clone(), id), add);
+            }
+            if let Some(remove) = action.remove {
+                let id = remove.deletion_vector.as_ref().map(|dv| dv.unique_id());
+                files.shift_remove(&(remove.path, id));
+            }
+            if action.protocol.is_some() {
+                protocol = action.protocol;
+            }
+            if action.meta_data.is_some() {
+                metadata = action.meta_data;
+            }
+        }
+        protocol
+            .ok_or_else(|| invalid_log(self.table_uri, "missing protocol"))?
+            .check_supported()?;
+        Ok(LogSnapshot {
+            version,
+            metadata: metadata.ok_or_else(|| invalid_log(self.table_uri, "missing metadata"))?,
+            files: files.into_values().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::{
+        array::{Array, MapArray, PrimitiveArray, StructArray, Utf8Array},
+        datatypes::{DataType, Field},
+        offset::OffsetsBuffer,
+    };
+    use serde_json::json;
+
+    use super::{arrow_to_json, parse_log_file_name, LogFileKind, Protocol};
+
+    #[test]
+    fn test_parse_log_file_name() {
+        assert_eq!(
+            parse_log_file_name("s3://b/t/_delta_log/00000000000000000012.json"),
+            Some((12, LogFileKind::Commit))
+        );
+        assert_eq!(
+            parse_log_file_name("/t/_delta_log/00000000000000000010.checkpoint.parquet"),
+            Some((
+                10,
+                LogFileKind::Checkpoint {
+                    part: 1,
+                    num_parts: 1
+                }
+            ))
+        );
+        assert_eq!(
+            parse_log_file_name(
+                "/t/_delta_log/00000000000000000010.checkpoint.0000000002.0000000003.parquet"
+            ),
+            Some((
+                10,
+                LogFileKind::Checkpoint {
+                    part: 2,
+                    num_parts: 3
+                }
+            ))
+        );
+        for name in [
+            "00000000000000000010.crc",
+            "00000000000000000010.checkpoint.80a083e8-7026-4e79-81be-64bd76c43a11.parquet",
+            "00000000000000000001.00000000000000000009.compacted.json",
+            "_last_checkpoint",
+            "12.json",
+        ] {
+            assert_eq!(parse_log_file_name(name), None);
+        }
+    }
+
+    #[test]
+    fn test_arrow_to_json() {
+        let entries = StructArray::new(
+            DataType::Struct(vec![
+                Field::new("key", DataType::LargeUtf8, false),
+                Field::new("value", DataType::LargeUtf8, true),
+            ]),
+            vec![
+                Utf8Array::<i64>::from_slice(["x", "y"]).boxed(),
+                Utf8Array::<i64>::from([Some("1"), None]).boxed(),
+            ],
+            None,
+        );
+        let map_type = DataType::Map(
+            Box::new(Field::new("entries", entries.data_type().clone(), false)),
+            false,
+        );
+        let partition_values = MapArray::new(
+            map_type.clone(),
+            OffsetsBuffer::try_from(vec![0, 2]).unwrap(),
+            entries.boxed(),
+            None,
+        );
+        let add = StructArray::new(
+            DataType::Struct(vec![
+                Field::new("path", DataType::LargeUtf8, true),
+                Field::new("size", DataType::Int64, true),
+                Field::new("partitionValues", map_type, true),
+            ]),
+            vec![
+                Utf8Array::<i64>::from_slice(["a.parquet"]).boxed(),
+                PrimitiveArray::from_slice([10i64]).boxed(),
+                partition_values.boxed(),
+            ],
+            None,
+        );
+        assert_eq!(
+            arrow_to_json(&add, 0),
+            json!({"path": "a.parquet", "size": 10, "partitionValues": {"x": "1", "y": null}})
+        );
+    }
+
+    #[test]
+    fn test_check_protocol() {
+        let protocol = |version, features: &[&str]| Protocol {
+            min_reader_version: version,
+            reader_features: Some(features.iter().map(|f| f.to_string()).collect()),
+        };
+        assert!(protocol(3, &["deletionVectors", "columnMapping"])
+            .check_supported()
+            .is_ok());
+        assert!(protocol(3, &["v2Checkpoint"]).check_supported().is_err());
+        assert!(protocol(4, &[]).check_supported().is_err());
+    }
+}
@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use chrono::DateTime;
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::TimeUnit, schema::SchemaRef};
+use daft_io::IOStatsContext;
+use daft_stats::{
+    ColumnRangeStatistics, PartitionSpec, TableMetadata as ScanTaskMetadata, TableStatistics,
+};
+use daft_table::Table;
+use indexmap::IndexMap;
+use itertools::Itertools;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    file_format::{FileFormatConfig, ParquetSourceConfig},
+    hive::unescape_path_name,
+    iceberg::{may_match, normalize_path},
+    storage_config::StorageConfig,
+    DataSource, PartitionField, Pushdowns, ScanOperator, ScanTask, ScanTaskRef,
+};
+
+mod deletion_vector;
+mod log;
+mod schema;
+mod values;
+
+pub use deletion_vector::{read_deletion_vector, DeletionVector};
+use log::{Add, DeltaLog, LogSnapshot};
+use schema::DeltaSchema;
+use values::{parse_partition_value, parse_stats_bound};
+
+/// Statistics of a data file, from the `stats` of its `add` action. Bounds are keyed by the physical names of columns.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileStats {
+    num_records: Option<i64>,
+    #[serde(default)]
+    min_values: serde_json::Map<String, Value>,
+    #[serde(default)]
+    max_values: serde_json::Map<String, Value>,
+}
+
+/// Scans a Delta Lake table by replaying its transaction log directly, without a catalog.
+#[derive(Debug)]
+pub struct DeltaLakeScanOperator {
+    table_uri: String,
+    version: i64,
+    delta_schema: DeltaSchema,
+    schema: SchemaRef,
+    partitioning_keys: Vec<PartitionField>,
+    files: Vec<Add>,
+    parquet_config: Arc<FileFormatConfig>,
+    storage_config: Arc<StorageConfig>,
+}
+
+impl DeltaLakeScanOperator {
+    /// Creates a scan of the table at `table_uri` at the version `version`, or at the latest version at or before
+    /// `timestamp_ms`, or at its latest version if neither is given.
+    pub fn try_new(
+        table_uri: &str,
+        version: Option<i64>,
+        timestamp_ms: Option<i64>,
+        storage_config: Arc<StorageConfig>,
+    ) -> DaftResult<Self> {
+        if version.is_some() && timestamp_ms.is_some() {
+            return Err(DaftError::ValueError(
+                "Only one of a version and a timestamp can be given to read a Delta Lake table"
+                    .to_string(),
+            ));
+        }
+        let table_uri = table_uri.trim_end_matches('/').to_string();
+        let (io_runtime, io_client) = storage_config.get_io_client_and_runtime()?;
+        let io_stats =
+            IOStatsContext::new(format!("DeltaLakeScanOperator::try_new for {table_uri}"));
+        let LogSnapshot {
+            version,
+            metadata,
+            files,
+        } = DeltaLog::new(
+            &table_uri,
+            io_client,
+            io_runtime,
+            io_stats,
+            storage_config.multithreaded_io(),
+        )
+        .snapshot(version, timestamp_ms)?;
+
+        let delta_schema = DeltaSchema::parse(&metadata.schema_string)?;
+        let schema = Arc::new(delta_schema.to_daft_schema()?);
+        let partitioning_keys = metadata
+            .partition_columns
+            .iter()
+            .map(|name| {
+                let field = schema.get_field(name)?;
+                PartitionField::new(field.clone(), None, None)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let parquet_config = Arc::new(FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit: TimeUnit::Nanoseconds,
+            field_id_mapping: delta_schema.field_id_mapping()?.map(Arc::new),
+            row_groups: None,
+            chunk_size: None,
+        }));
+        Ok(Self {
+            table_uri,
+            version,
+            delta_schema,
+            schema,
+            partitioning_keys,
+            files,
+            parquet_config,
+            storage_config,
+        })
+    }
+
+    /// Returns the URI of a file of the table from its path in the transaction log, which is a URI-encoded path that
+    /// is either absolute or relative to the root of the table. Relative paths encode colons, so paths with colons
+    /// have schemes.
+    fn file_uri(&self, path: &str) -> String {
+        if path.contains(':') {
+            normalize_path(&unescape_path_name(path))
+        } else {
+            format!("{}/{}", self.table_uri, unescape_path_name(path))
+        }
+    }
+
+    /// Returns the partition values of a data file as a partition spec.
+    fn partition_spec(&self, file: &Add) -> DaftResult<Option<PartitionSpec>> {
+        if self.partitioning_keys.is_empty() {
+            return Ok(None);
+        }
+        let columns = self
+            .partitioning_keys
+            .iter()
+            .map(|pfield| {
+                let name = &pfield.field.name;
+                let physical_name = self
+                    .delta_schema
+                    .field(name)
+                    .map_or(name.as_str(), |f| f.physical_name());
+                let value = file.partition_values.get(physical_name).cloned().flatten();
+                parse_partition_value(name, &pfield.field.dtype, value.as_deref())
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Some(PartitionSpec {
+            keys: Table::from_nonempty_columns(columns)?,
+        }))
+    }
+
+    /// Returns the ranges of the values of the top-level primitive columns of a data file, where the ranges of
+    /// partition columns are their partition values.
+    fn file_statistics(
+        &self,
+        stats: &FileStats,
+        partition_spec: Option<&PartitionSpec>,
+    ) -> DaftResult<TableStatistics> {
+        let mut columns = IndexMap::new();
+        for field in &self.delta_schema.fields {
+            let daft_field = self.schema.get_field(&field.name)?;
+            let partition_value = partition_spec
+                .and_then(|spec| spec.keys.get_column(&field.name).ok())
+                .filter(|value| value.validity().map_or(true, |v| v.unset_bits() == 0));
+            let bound = |bounds: &serde_json::Map<String, Value>, is_upper| {
+                bounds.get(field.physical_name()).and_then(|value| {
+                    parse_stats_bound(&field.name, &daft_field.dtype, value, is_upper)
+                })
+            };
+            let column_stats = match partition_value {
+                Some(value) => {
+                    ColumnRangeStatistics::new(Some(value.clone()), Some(value.clone()))?
+                }
+                None => match (
+                    bound(&stats.min_values, false),
+                    bound(&stats.max_values, true),
+                ) {
+                    (Some(lower), Some(upper)) => {
+                        ColumnRangeStatistics::new(Some(lower), Some(upper))?
+                    }
+                    _ => ColumnRangeStatistics::Missing,
+                },
+            };
+            columns.insert(field.name.clone(), column_stats);
+        }
+        Ok(TableStatistics { columns })
+    }
+}
+
+impl ScanOperator for DeltaLakeScanOperator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &self.partitioning_keys
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+    fn can_absorb_select(&self) -> bool {
+        true
+    }
+    fn can_absorb_limit(&self) -> bool {
+        false
+    }
+    fn can_provide_metadata(&self) -> bool {
+        // Row counts and column statistics come from the stats of the add actions in the transaction log.
+        true
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("DeltaLakeScanOperator({})", self.table_uri),
+            format!("Version = {}", self.version),
+            format!("Schema = {}", self.schema.short_string()),
+        ];
+        if !self.partitioning_keys.is_empty() {
+            lines.push(format!(
+                "Partitioning keys = [{}]",
+                self.partitioning_keys
+                    .iter()
+                    .map(|pf| pf.to_string())
+                    .join(", ")
+            ));
+        }
+        lines.extend(self.storage_config.multiline_display());
+
+        lines
+    }
+
+    fn to_scan_tasks(
+        &self,
+        pushdowns: Pushdowns,
+    ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>> + 'static>> {
+        let limit_files = pushdowns.filters.is_none() && pushdowns.partition_filters.is_none();
+        let mut rows_left = pushdowns.limit.unwrap_or(0) as i64;
+        let mut scan_tasks = vec![];
+        for file in &self.files {
+            if limit_files && pushdowns.limit.is_some() && rows_left <= 0 {
+                break;
+            }
+
+            let partition_spec = self.partition_spec(file)?;
+            if let Some(partition_filters) = &pushdowns.partition_filters
+                && let Some(partition_spec) = &partition_spec
+                && !partition_spec.satisfies(partition_filters)?
+            {
+                continue;
+            }
+            // Statistics that can't be parsed are ignored, since they're only used to prune files.
+            let stats = file
+                .stats
+                .as_deref()
+                .and_then(|stats| serde_json::from_str::<FileStats>(stats).ok())
+                .unwrap_or_default();
+            let statistics = self.file_statistics(&stats, partition_spec.as_ref())?;
+            if let Some(filters) = &pushdowns.filters
+                && !may_match(&statistics, filters)
+            {
+                continue;
+            }
+
+            let deletion_vector = file
+                .deletion_vector
+                .as_ref()
+                .map(|dv| {
+                    DeletionVector::from_descriptor(
+                        &self.table_uri,
+                        &dv.storage_type,
+                        &dv.path_or_inline_dv,
+                        dv.offset.map(|offset| offset as u64),
+                        dv.size_in_bytes as u32,
+                        dv.cardinality as u64,
+                    )
+                })
+                .transpose()?;
+            // Row counts of files with deletion vectors don't account for their deleted rows.
+            let num_rows = stats.num_records.filter(|_| deletion_vector.is_none());
+            if let Some(num_rows) = num_rows {
+                rows_left -= num_rows;
+            }
+            scan_tasks.push(Ok(ScanTask::new(
+                vec![DataSource::File {
+                    path: self.file_uri(&file.path),
+                    chunk_spec: None,
+                    size_bytes: Some(file.size as u64),
+                    iceberg_delete_files: None,
+                    iceberg_equality_delete_files: None,
+                    delta_deletion_vector: deletion_vector,
+                    metadata: num_rows.map(|num_rows| ScanTaskMetadata {
+                        length: num_rows as usize,
+                    }),
+                    partition_spec,
+                    statistics: Some(statistics),
+                    parquet_metadata: None,
+                    etag: None,
+                    last_modified: file
+                        .modification_time
+                        .and_then(DateTime::from_timestamp_millis),
+                }],
+                self.parquet_config.clone(),
+                self.schema.clone(),
+                self.storage_config.clone(),
+                pushdowns.clone(),
+            )
+            .into()));
+        }
+        Ok(Box::new(scan_tasks.into_iter()))
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Metadata key of the physical name of a column of a table with column mapping.
+const PHYSICAL_NAME_KEY: &str = "delta.columnMapping.physicalName";
+/// Metadata key of the field ID of a column of a table with column mapping.
+const COLUMN_MAPPING_ID_KEY: &str = "delta.columnMapping.id";
+
+fn invalid_schema(reason: impl Into<String>) -> DaftError {
+    DaftError::ValueError(format!(
+        "Invalid Delta Lake table schema: {}",
+        reason.into()
+    ))
+}
+
+/// Type of the Delta Lake table schema, which is serialized like Spark SQL types.
+///
+/// See: https://github.com/delta-io/delta/blob/master/PROTOCOL.md#schema-serialization-format
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "Value")]
+pub(crate) enum DeltaType {
+    Primitive(String),
+    Struct(Vec<DeltaField>),
+    Array(Box<DeltaType>),
+    Map {
+        key: Box<DeltaType>,
+        value: Box<DeltaType>,
+    },
+}
+
+impl TryFrom<Value> for DeltaType {
+    type Error = DaftError;
+
+    fn try_from(value: Value) -> DaftResult<Self> {
+        let field = |object: &serde_json::Map<String, Value>, name: &str| -> DaftResult<Self> {
+            object
+                .get(name)
+                .cloned()
+                .ok_or_else(|| invalid_schema(format!("type is missing the {name} field")))?
+                .try_into()
+        };
+        match value {
+            Value::String(name) => Ok(Self::Primitive(name)),
+            Value::Object(object) => match object.get("type").and_then(Value::as_str) {
+                Some("struct") => Ok(Self::Struct(
+                    serde_json::from_value(object.get("fields").cloned().unwrap_or_default())
+                        .map_err(|e| invalid_schema(e.to_string()))?,
+                )),
+                Some("array") => Ok(Self::Array(Box::new(field(&object, "elementType")?))),
+                Some("map") => Ok(Self::Map {
+                    key: Box::new(field(&object, "keyType")?),
+                    value: Box::new(field(&object, "valueType")?),
+                }),
+                _ => Err(invalid_schema(format!("unknown type {:?}", object))),
+            },
+            _ => Err(invalid_schema(format!("unknown type {value}"))),
+        }
+    }
+}
+
+impl DeltaType {
+    pub fn to_daft_dtype(&self) -> DaftResult<DataType> {
+        Ok(match self {
+            Self::Primitive(name) => match name.as_str() {
+                "string" => DataType::Utf8,
+                "long" => DataType::Int64,
+                "integer" => DataType::Int32,
+                "short" => DataType::Int16,
+                "byte" => DataType::Int8,
+                "float" => DataType::Float32,
+                "double" => DataType::Float64,
+                "boolean" => DataType::Boolean,
+                "binary" => DataType::Binary,
+                "date" => DataType::Date,
+                "timestamp" => DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string())),
+                "timestamp_ntz" => DataType::Timestamp(TimeUnit::Microseconds, None),
+                _ if let Some((precision, scale)) = name
+                    .strip_prefix("decimal(")
+                    .and_then(|p| p.strip_suffix(')'))
+                    .and_then(|p| p.split_once(','))
+                    && let (Ok(precision), Ok(scale)) =
+                        (precision.trim().parse(), scale.trim().parse()) =>
+                {
+                    DataType::Decimal128(precision, scale)
+                }
+                _ => return Err(invalid_schema(format!("unknown type {name}"))),
+            },
+            Self::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(DeltaField::to_daft_field)
+                    .collect::<DaftResult<_>>()?,
+            ),
+            Self::Array(element) => DataType::List(Box::new(element.to_daft_dtype()?)),
+            Self::Map { key, value } => DataType::Map(Box::new(DataType::Struct(vec![
+                Field::new("key", key.to_daft_dtype()?),
+                Field::new("value", value.to_daft_dtype()?),
+            ]))),
+        })
+    }
+
+    /// Adds the Daft fields of the struct fields nested in this type to `mapping`, keyed by their column mapping IDs.
+    fn collect_field_ids(&self, mapping: &mut BTreeMap<i32, Field>) -> DaftResult<()> {
+        match self {
+            Self::Primitive(_) => {}
+            Self::Struct(fields) => {
+                for field in fields {
+                    if let Some(id) = field.column_mapping_id() {
+                        mapping.insert(id, field.to_daft_field()?);
+                    }
+                    field.field_type.collect_field_ids(mapping)?;
+                }
+            }
+            Self::Array(element) => element.collect_field_ids(mapping)?,
+            Self::Map { key, value } => {
+                key.collect_field_ids(mapping)?;
+                value.collect_field_ids(mapping)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct DeltaField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: DeltaType,
+    #[serde(default)]
+    pub metadata: serde_json::Map<String, Value>,
+}
+
+impl DeltaField {
+    fn to_daft_field(&self) -> DaftResult<Field> {
+        Ok(Field::new(
+            self.name.as_str(),
+            self.field_type.to_daft_dtype()?,
+        ))
+    }
+
+    /// Returns the name of the column in data files and in the statistics and partition values of the transaction
+    /// log, which differs from its logical name in tables with column mapping.
+    pub fn physical_name(&self) -> &str {
+        self.metadata
+            .get(PHYSICAL_NAME_KEY)
+            .and_then(Value::as_str)
+            .unwrap_or(&self.name)
+    }
+
+    fn column_mapping_id(&self) -> Option<i32> {
+        self.metadata
+            .get(COLUMN_MAPPING_ID_KEY)
+            .and_then(Value::as_i64)
+            .and_then(|id| i32::try_from(id).ok())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct DeltaSchema {
+    pub fields: Vec<DeltaField>,
+}
+
+impl DeltaSchema {
+    /// Parses the `schemaString` of the table metadata.
+    pub fn parse(schema_string: &str) -> DaftResult<Self> {
+        serde_json::from_str(schema_string).map_err(|e| invalid_schema(e.to_string()))
+    }
+
+    pub fn to_daft_schema(&self) -> DaftResult<Schema> {
+        Schema::new(
+            self.fields
+                .iter()
+                .map(DeltaField::to_daft_field)
+                .collect::<DaftResult<_>>()?,
+        )
+    }
+
+    /// Returns the mapping of the column mapping IDs of all struct fields of the schema, including nested fields, to
+    /// their Daft fields. Data files of tables with column mapping name their columns by physical names, so they're
+    /// resolved by IDs instead. Returns `None` if the table doesn't use column mapping.
+    pub fn field_id_mapping(&self) -> DaftResult<Option<BTreeMap<i32, Field>>> {
+        let mut mapping = BTreeMap::new();
+        DeltaType::Struct(self.fields.clone()).collect_field_ids(&mut mapping)?;
+        Ok((!mapping.is_empty()).then_some(mapping))
+    }
+
+    pub fn field(&self, name: &str) -> Option<&DeltaField> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::DeltaSchema;
+
+    #[test]
+    fn test_parse_schema() {
+        let schema = DeltaSchema::parse(
+            r#"{"type": "struct", "fields": [
+                {"name": "id", "type": "long", "nullable": false, "metadata": {}},
+                {"name": "ts", "type": "timestamp", "nullable": true, "metadata": {}},
+                {"name": "price", "type": "decimal(10,2)", "nullable": true, "metadata": {}},
+                {"name": "tags", "type": {"type": "array", "elementType": "string", "containsNull": true}, "nullable": true, "metadata": {}},
+                {"name": "attrs", "type": {"type": "map", "keyType": "string", "valueType": "integer", "valueContainsNull": true}, "nullable": true, "metadata": {}}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            schema.to_daft_schema().unwrap(),
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new(
+                    "ts",
+                    DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()))
+                ),
+                Field::new("price", DataType::Decimal128(10, 2)),
+                Field::new("tags", DataType::List(Box::new(DataType::Utf8))),
+                Field::new(
+                    "attrs",
+                    DataType::Map(Box::new(DataType::Struct(vec![
+                        Field::new("key", DataType::Utf8),
+                        Field::new("value", DataType::Int32),
+                    ])))
+                ),
+            ])
+            .unwrap()
+        );
+        assert_eq!(schema.field_id_mapping().unwrap(), None);
+        assert!(DeltaSchema::parse(
+            r#"{"type": "struct", "fields": [{"name": "v", "type": "variant"}]}"#
+        )
+        .unwrap()
+        .to_daft_schema()
+        .is_err());
+    }
+
+    #[test]
+    fn test_column_mapping() {
+        let schema = DeltaSchema::parse(
+            r#"{"type": "struct", "fields": [
+                {"name": "a", "type": {"type": "struct", "fields": [
+                    {"name": "b", "type": "integer", "nullable": true, "metadata": {
+                        "delta.columnMapping.id": 2, "delta.columnMapping.physicalName": "col-b"
+                    }}
+                ]}, "nullable": true, "metadata": {
+                    "delta.columnMapping.id": 1, "delta.columnMapping.physicalName": "col-a"
+                }}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(schema.fields[0].physical_name(), "col-a");
+        let mapping = schema.field_id_mapping().unwrap().unwrap();
+        assert_eq!(mapping.get(&2), Some(&Field::new("b", DataType::Int32)));
+        assert_eq!(
+            mapping.get(&1),
+            Some(&Field::new(
+                "a",
+                DataType::Struct(vec![Field::new("b", DataType::Int32)])
+            ))
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use arrow2::array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use serde_json::Value;
+
+/// Length of the prefixes of strings that writers keep in statistics, beyond which bounds may be truncated.
+const STATS_STRING_PREFIX_LENGTH: usize = 32;
+
+/// Parses a timestamp into microseconds since the epoch. Timestamps are either written like `1970-01-01 00:00:00.123`
+/// or in ISO 8601 with an offset, like `1970-01-01T00:00:00.123Z`.
+fn parse_timestamp(value: &str) -> Option<i64> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.timestamp_micros());
+    }
+    ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .map(|timestamp| timestamp.and_utc().timestamp_micros())
+}
+
+/// Parses a decimal like `-12.5` into its unscaled value at `scale`, returning `None` if it has more fractional digits
+/// than `scale`.
+fn parse_decimal(value: &str, scale: usize) -> Option<i128> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > scale
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+        || integer.is_empty() && fraction.is_empty()
+    {
+        return None;
+    }
+    let unscaled = format!("{integer}{fraction:0<scale$}")
+        .parse::<i128>()
+        .ok()?;
+    Some(if negative { -unscaled } else { unscaled })
+}
+
+/// Parses a partition value or a bound of the statistics of the transaction log into a series of a single value of
+/// type `dtype`. Returns `None` if the value isn't valid for `dtype`, or if values of `dtype` can't be parsed.
+///
+/// See: https://github.com/delta-io/delta/blob/master/PROTOCOL.md#partition-value-serialization
+pub(crate) fn parse_value(name: &str, dtype: &DataType, value: &str) -> Option<Series> {
+    let arrow_type = dtype.to_arrow().ok()?;
+    let array: Box<dyn Array> = match dtype {
+        DataType::Utf8 => Box::new(Utf8Array::<i64>::from_slice([value])),
+        DataType::Binary => Box::new(BinaryArray::<i64>::from_slice([value.as_bytes()])),
+        DataType::Boolean => Box::new(BooleanArray::from_slice([value.parse::<bool>().ok()?])),
+        DataType::Int8 => Box::new(PrimitiveArray::from_slice([value.parse::<i8>().ok()?])),
+        DataType::Int16 => Box::new(PrimitiveArray::from_slice([value.parse::<i16>().ok()?])),
+        DataType::Int32 => Box::new(PrimitiveArray::from_slice([value.parse::<i32>().ok()?])),
+        DataType::Int64 => Box::new(PrimitiveArray::from_slice([value.parse::<i64>().ok()?])),
+        DataType::Float32 => Box::new(PrimitiveArray::from_slice([value.parse::<f32>().ok()?])),
+        DataType::Float64 => Box::new(PrimitiveArray::from_slice([value.parse::<f64>().ok()?])),
+        DataType::Date => {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+            let days = date
+                .signed_duration_since(DateTime::UNIX_EPOCH.date_naive())
+                .num_days() as i32;
+            Box::new(PrimitiveArray::from_slice([days]).to(arrow_type))
+        }
+        DataType::Timestamp(TimeUnit::Microseconds, _) => {
+            Box::new(PrimitiveArray::from_slice([parse_timestamp(value)?]).to(arrow_type))
+        }
+        DataType::Decimal128(_, scale) => {
+            Box::new(PrimitiveArray::from_slice([parse_decimal(value, *scale)?]).to(arrow_type))
+        }
+        _ => return None,
+    };
+    array_to_series(name, dtype, array)
+}
+
+fn array_to_series(name: &str, dtype: &DataType, array: Box<dyn Array>) -> Option<Series> {
+    Series::try_from_field_and_arrow_array(
+        Arc::new(Field::new(name, dtype.clone())),
+        cast_array_for_daft_if_needed(array),
+    )
+    .ok()
+}
+
+/// Parses a bound of the `minValues` or `maxValues` of the statistics of a data file. Returns `None` if the bound
+/// isn't valid for `dtype`, or if it may not bound the values of the file, such as truncated strings.
+pub(crate) fn parse_stats_bound(
+    name: &str,
+    dtype: &DataType,
+    value: &Value,
+    is_upper: bool,
+) -> Option<Series> {
+    let value = match value {
+        Value::String(value) => value.clone(),
+        Value::Number(value) => value.to_string(),
+        Value::Bool(value) => value.to_string(),
+        _ => return None,
+    };
+    match dtype {
+        DataType::Utf8 if value.chars().count() >= STATS_STRING_PREFIX_LENGTH => None,
+        // Timestamps are truncated to milliseconds, so upper bounds are rounded up to the end of their milliseconds.
+        DataType::Timestamp(TimeUnit::Microseconds, _) if is_upper => {
+            let micros = parse_timestamp(&value)?.checked_add(999)?;
+            let array = PrimitiveArray::from_slice([micros]).to(dtype.to_arrow().ok()?);
+            array_to_series(name, dtype, Box::new(array))
+        }
+        _ => parse_value(name, dtype, &value),
+    }
+}
+
+/// Parses a partition value of the transaction log, where `None` is a null value.
+pub(crate) fn parse_partition_value(
+    name: &str,
+    dtype: &DataType,
+    value: Option<&str>,
+) -> DaftResult<Series> {
+    match value {
+        // Empty partition values are nulls, regardless of their types.
+        None | Some("") => Ok(Series::full_null(name, dtype, 1)),
+        Some(value) => parse_value(name, dtype, value).ok_or_else(|| {
+            DaftError::ValueError(format!(
+                "Invalid Delta Lake partition value {value:?} of column {name} of type {dtype}"
+            ))
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use serde_json::json;
+
+    use super::{
+        parse_decimal, parse_partition_value, parse_stats_bound, parse_timestamp, parse_value,
+    };
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01 00:00:01"), Some(1_000_000));
+        assert_eq!(parse_timestamp("1970-01-01 00:00:00.000123"), Some(123));
+        assert_eq!(parse_timestamp("1970-01-01T00:00:01.5Z"), Some(1_500_000));
+        assert_eq!(parse_timestamp("1970-01-01T01:00:00.000+01:00"), Some(0));
+        assert_eq!(parse_timestamp("1970-01-01"), None);
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("12.5", 2), Some(1250));
+        assert_eq!(parse_decimal("-0.01", 2), Some(-1));
+        assert_eq!(parse_decimal("7", 0), Some(7));
+        assert_eq!(parse_decimal("1.500", 1), Some(15));
+        assert_eq!(parse_decimal("1.25", 1), None);
+        assert_eq!(parse_decimal("1e3", 1), None);
+        assert_eq!(parse_decimal("-", 1), None);
+    }
+
+    #[test]
+    fn test_parse_value() {
+        let series = parse_value("d", &DataType::Date, "1970-01-03").unwrap();
+        assert_eq!(series.data_type(), &DataType::Date);
+        assert_eq!(series.len(), 1);
+        assert!(parse_value("i", &DataType::Int32, "1.5").is_none());
+        assert!(parse_value("s", &DataType::Null, "a").is_none());
+
+        let series = parse_partition_value("i", &DataType::Int64, Some("")).unwrap();
+        assert!(series.validity().is_some_and(|v| v.unset_bits() == 1));
+        let series = parse_partition_value("s", &DataType::Utf8, Some("a")).unwrap();
+        assert!(series.validity().is_none());
+        assert!(parse_partition_value("b", &DataType::Boolean, Some("yes")).is_err());
+    }
+
+    #[test]
+    fn test_parse_stats_bound() {
+        let ts = DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()));
+        let lower = parse_stats_bound("t", &ts, &json!("1970-01-01T00:00:00.001Z"), false).unwrap();
+        let upper = parse_stats_bound("t", &ts, &json!("1970-01-01T00:00:00.001Z"), true).unwrap();
+        assert_eq!(
+            lower.cast(&DataType::Int64).unwrap().i64().unwrap().get(0),
+            Some(1000)
+        );
+        assert_eq!(
+            upper.cast(&DataType::Int64).unwrap().i64().unwrap().get(0),
+            Some(1999)
+        );
+
+        assert!(parse_stats_bound("d", &DataType::Decimal128(5, 1), &json!(12.5), true).is_some());
+        assert!(parse_stats_bound("i", &DataType::Int64, &json!(3), true).is_some());
+        assert!(parse_stats_bound("s", &DataType::Utf8, &json!("a".repeat(32)), false).is_none());
+        assert!(parse_stats_bound("s", &DataType::Utf8, &json!(null), false).is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use daft_core::datatypes::{Field, TimeUnit};
 use daft_io::FileFormat;
+use daft_text::FixedWidthColumn;
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 use std::{collections::BTreeMap, sync::Arc};
@@ -9,7 +10,10 @@ use common_py_serde::impl_bincode_py_state_serialization;
 #[cfg(feature = "python")]
 use {
     common_py_serde::{deserialize_py_object, serialize_py_object},
-    daft_core::python::{datatype::PyTimeUnit, field::PyField},
+    daft_core::python::{
+        datatype::{PyDataType, PyTimeUnit},
+        field::PyField,
+    },
     pyo3::{pyclass, pyclass::CompareOp, pymethods, IntoPy, PyObject, PyResult, Python},
 };
 
@@ -19,6 +23,13 @@ impl From<&FileFormatConfig> for FileFormat {
             FileFormatConfig::Parquet(_) => Self::Parquet,
             FileFormatConfig::Csv(_) => Self::Csv,
             FileFormatConfig::Json(_) => Self::Json,
+            FileFormatConfig::Ipc(_) => Self::Ipc,
+            FileFormatConfig::Avro(_) => Self::Avro,
+            FileFormatConfig::Orc(_) => Self::Orc,
+            FileFormatConfig::Xlsx(_) => Self::Xlsx,
+            FileFormatConfig::Text(_) => Self::Text,
+            FileFormatConfig::FixedWidth(_) => Self::FixedWidth,
+            FileFormatConfig::Files(_) => Self::Files,
             #[cfg(feature = "python")]
             FileFormatConfig::Database(_) => Self::Database,
             #[cfg(feature = "python")]
@@ -33,6 +44,13 @@ pub enum FileFormatConfig {
     Parquet(ParquetSourceConfig),
     Csv(CsvSourceConfig),
     Json(JsonSourceConfig),
+    Ipc(IpcSourceConfig),
+    Avro(AvroSourceConfig),
+    Orc(OrcSourceConfig),
+    Xlsx(XlsxSourceConfig),
+    Text(TextSourceConfig),
+    FixedWidth(FixedWidthSourceConfig),
+    Files(FilesSourceConfig),
     #[cfg(feature = "python")]
     Database(DatabaseSourceConfig),
     #[cfg(feature = "python")]
@@ -51,6 +69,13 @@ impl FileFormatConfig {
             Parquet(_) => "Parquet",
             Csv(_) => "Csv",
             Json(_) => "Json",
+            Ipc(_) => "Ipc",
+            Avro(_) => "Avro",
+            Orc(_) => "Orc",
+            Xlsx(_) => "Xlsx",
+            Text(_) => "Text",
+            FixedWidth(_) => "FixedWidth",
+            Files(_) => "Files",
             #[cfg(feature = "python")]
             Database(_) => "Database",
             #[cfg(feature = "python")]
@@ -58,11 +83,24 @@ impl FileFormatConfig {
         }
     }
 
+    /// Whether ScanTasks over files of this format can carry row counts or column statistics in their metadata, read
+    /// from the files' footers with [`crate::scan_task_iters::read_footer_metadata`].
+    pub fn provides_metadata(&self) -> bool {
+        matches!(self, Self::Parquet(_) | Self::Orc(_) | Self::Files(_))
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         match self {
             Self::Parquet(source) => source.multiline_display(),
             Self::Csv(source) => source.multiline_display(),
             Self::Json(source) => source.multiline_display(),
+            Self::Ipc(source) => source.multiline_display(),
+            Self::Avro(source) => source.multiline_display(),
+            Self::Orc(source) => source.multiline_display(),
+            Self::Xlsx(source) => source.multiline_display(),
+            Self::Text(source) => source.multiline_display(),
+            Self::FixedWidth(source) => source.multiline_display(),
+            Self::Files(source) => source.multiline_display(),
             #[cfg(feature = "python")]
             Self::Database(source) => source.multiline_display(),
             #[cfg(feature = "python")]
@@ -300,6 +338,366 @@ impl JsonSourceConfig {
 
 impl_bincode_py_state_serialization!(JsonSourceConfig);
 
+/// Configuration for an Arrow IPC (Feather V2) data source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct IpcSourceConfig {
+    pub record_batches: Option<Vec<Option<Vec<usize>>>>,
+}
+
+impl IpcSourceConfig {
+    pub fn new_internal(record_batches: Option<Vec<Option<Vec<usize>>>>) -> Self {
+        Self { record_batches }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(record_batches) = &self.record_batches {
+            res.push(format!(
+                "Record Batches = {{{}}}",
+                record_batches
+                    .iter()
+                    .map(|rb| {
+                        rb.as_ref()
+                            .map(|rb| {
+                                rb.iter()
+                                    .map(|i| i.to_string())
+                                    .collect::<Vec<String>>()
+                                    .join(",")
+                            })
+                            .unwrap_or_else(|| "None".to_string())
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ));
+        }
+        res
+    }
+}
+
+impl Default for IpcSourceConfig {
+    fn default() -> Self {
+        Self::new_internal(None)
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl IpcSourceConfig {
+    /// Create a config for an Arrow IPC data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `record_batches` - Indices of the record batches to read from each file, or None to read all of them.
+    #[new]
+    fn new(record_batches: Option<Vec<Option<Vec<usize>>>>) -> Self {
+        Self::new_internal(record_batches)
+    }
+}
+
+impl_bincode_py_state_serialization!(IpcSourceConfig);
+
+/// Configuration for an Avro data source.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct AvroSourceConfig {}
+
+impl AvroSourceConfig {
+    pub fn new_internal() -> Self {
+        Self {}
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AvroSourceConfig {
+    /// Create a config for an Avro data source.
+    #[new]
+    fn new() -> Self {
+        Self::new_internal()
+    }
+}
+
+impl_bincode_py_state_serialization!(AvroSourceConfig);
+
+/// Configuration for an ORC data source.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct OrcSourceConfig {
+    pub stripes: Option<Vec<Option<Vec<usize>>>>,
+}
+
+impl OrcSourceConfig {
+    pub fn new_internal(stripes: Option<Vec<Option<Vec<usize>>>>) -> Self {
+        Self { stripes }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(stripes) = &self.stripes {
+            res.push(format!(
+                "Stripes = {{{}}}",
+                stripes
+                    .iter()
+                    .map(|s| {
+                        s.as_ref()
+                            .map(|s| {
+                                s.iter()
+                                    .map(|i| i.to_string())
+                                    .collect::<Vec<String>>()
+                                    .join(",")
+                            })
+                            .unwrap_or_else(|| "None".to_string())
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ));
+        }
+        res
+    }
+}
+
+impl Default for OrcSourceConfig {
+    fn default() -> Self {
+        Self::new_internal(None)
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl OrcSourceConfig {
+    /// Create a config for an ORC data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `stripes` - Indices of the stripes to read from each file, or None to read all of them.
+    #[new]
+    fn new(stripes: Option<Vec<Option<Vec<usize>>>>) -> Self {
+        Self::new_internal(stripes)
+    }
+}
+
+impl_bincode_py_state_serialization!(OrcSourceConfig);
+
+/// Configuration for an XLSX data source, which is read as a row per row of a sheet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct XlsxSourceConfig {
+    pub sheet_name: Option<String>,
+    pub sheet_index: Option<usize>,
+    pub header_row: Option<usize>,
+}
+
+impl XlsxSourceConfig {
+    pub fn new_internal(
+        sheet_name: Option<String>,
+        sheet_index: Option<usize>,
+        header_row: Option<usize>,
+    ) -> Self {
+        Self {
+            sheet_name,
+            sheet_index,
+            header_row,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        if let Some(sheet_name) = &self.sheet_name {
+            res.push(format!("Sheet name = {}", sheet_name));
+        }
+        if let Some(sheet_index) = self.sheet_index {
+            res.push(format!("Sheet index = {}", sheet_index));
+        }
+        match self.header_row {
+            Some(header_row) => res.push(format!("Header row = {}", header_row)),
+            None => res.push("Header row = None".to_string()),
+        }
+        res
+    }
+}
+
+impl Default for XlsxSourceConfig {
+    fn default() -> Self {
+        Self::new_internal(None, None, Some(0))
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl XlsxSourceConfig {
+    /// Create a config for an XLSX data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet_name` - Name of the sheet to read, which takes precedence over `sheet_index`.
+    /// * `sheet_index` - Index of the sheet to read, starting from 0. The first sheet is read if neither is set.
+    /// * `header_row` - Index of the row that holds the column names, starting from 0, or None if there's no header.
+    #[new]
+    #[pyo3(signature = (sheet_name=None, sheet_index=None, header_row=0))]
+    fn new(
+        sheet_name: Option<String>,
+        sheet_index: Option<usize>,
+        header_row: Option<usize>,
+    ) -> Self {
+        Self::new_internal(sheet_name, sheet_index, header_row)
+    }
+}
+
+impl_bincode_py_state_serialization!(XlsxSourceConfig);
+
+/// Configuration for a text data source, which is read as a row per line.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct TextSourceConfig {
+    pub skip_blank_lines: bool,
+    pub path_column: Option<String>,
+}
+
+impl TextSourceConfig {
+    pub fn new_internal(skip_blank_lines: bool, path_column: Option<String>) -> Self {
+        Self {
+            skip_blank_lines,
+            path_column,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Skip blank lines = {}", self.skip_blank_lines));
+        if let Some(path_column) = &self.path_column {
+            res.push(format!("Path column = {}", path_column));
+        }
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl TextSourceConfig {
+    /// Create a config for a text data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_blank_lines` - Whether to skip lines that are empty or only hold whitespace.
+    /// * `path_column` - Name of a column to add with the path of the file that each line was read from.
+    #[new]
+    #[pyo3(signature = (skip_blank_lines=false, path_column=None))]
+    fn new(skip_blank_lines: bool, path_column: Option<String>) -> Self {
+        Self::new_internal(skip_blank_lines, path_column)
+    }
+}
+
+impl_bincode_py_state_serialization!(TextSourceConfig);
+
+/// Configuration for a fixed-width text data source, which is read as a row per line split into columns by character
+/// positions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub struct FixedWidthSourceConfig {
+    pub columns: Vec<FixedWidthColumn>,
+    pub skip_rows: usize,
+}
+
+impl FixedWidthSourceConfig {
+    pub fn new_internal(columns: Vec<FixedWidthColumn>, skip_rows: usize) -> Self {
+        Self { columns, skip_rows }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Columns = {{{}}}",
+            self.columns
+                .iter()
+                .map(|c| format!(
+                    "{}: [{}, {}) {}",
+                    c.name,
+                    c.start,
+                    c.start + c.width,
+                    c.dtype
+                ))
+                .collect::<Vec<String>>()
+                .join(",")
+        ));
+        res.push(format!("Skip rows = {}", self.skip_rows));
+        res
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl FixedWidthSourceConfig {
+    /// Create a config for a fixed-width text data source.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - Name, position of the first character starting from 0, number of characters and type of each column.
+    /// * `skip_rows` - Number of lines to skip at the start of each file, such as header lines.
+    #[new]
+    #[pyo3(signature = (columns, skip_rows=0))]
+    fn new(columns: Vec<(String, usize, usize, PyDataType)>, skip_rows: usize) -> Self {
+        Self::new_internal(
+            columns
+                .into_iter()
+                .map(|(name, start, width, dtype)| FixedWidthColumn {
+                    name,
+                    start,
+                    width,
+                    dtype: dtype.into(),
+                })
+                .collect(),
+            skip_rows,
+        )
+    }
+
+    #[getter]
+    fn columns(&self) -> Vec<(String, usize, usize, PyDataType)> {
+        self.columns
+            .iter()
+            .map(|c| (c.name.clone(), c.start, c.width, c.dtype.clone().into()))
+            .collect()
+    }
+
+    #[getter]
+    fn skip_rows(&self) -> usize {
+        self.skip_rows
+    }
+}
+
+impl_bincode_py_state_serialization!(FixedWidthSourceConfig);
+
+/// Configuration for a data source of whole files, which is read as a row per file with its path, size and content.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct FilesSourceConfig {}
+
+impl FilesSourceConfig {
+    pub fn new_internal() -> Self {
+        Self {}
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl FilesSourceConfig {
+    /// Create a config for a data source of whole files.
+    #[new]
+    fn new() -> Self {
+        Self::new_internal()
+    }
+}
+
+impl_bincode_py_state_serialization!(FilesSourceConfig);
+
 /// Configuration for a Database data source.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg(feature = "python")]
@@ -391,6 +789,48 @@ impl PyFileFormatConfig {
         Self(Arc::new(FileFormatConfig::Json(config)))
     }
 
+    /// Create an Arrow IPC file format config.
+    #[staticmethod]
+    fn from_ipc_config(config: IpcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Ipc(config)))
+    }
+
+    /// Create an Avro file format config.
+    #[staticmethod]
+    fn from_avro_config(config: AvroSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Avro(config)))
+    }
+
+    /// Create an ORC file format config.
+    #[staticmethod]
+    fn from_orc_config(config: OrcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Orc(config)))
+    }
+
+    /// Create an XLSX file format config.
+    #[staticmethod]
+    fn from_xlsx_config(config: XlsxSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Xlsx(config)))
+    }
+
+    /// Create a text file format config.
+    #[staticmethod]
+    fn from_text_config(config: TextSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Text(config)))
+    }
+
+    /// Create a fixed-width text file format config.
+    #[staticmethod]
+    fn from_fixed_width_config(config: FixedWidthSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::FixedWidth(config)))
+    }
+
+    /// Create a whole files format config.
+    #[staticmethod]
+    fn from_files_config(config: FilesSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Files(config)))
+    }
+
     /// Create a Database file format config.
     #[staticmethod]
     fn from_database_config(config: DatabaseSourceConfig) -> Self {
@@ -406,6 +846,13 @@ impl PyFileFormatConfig {
             Parquet(config) => config.clone().into_py(py),
             Csv(config) => config.clone().into_py(py),
             Json(config) => config.clone().into_py(py),
+            Ipc(config) => config.clone().into_py(py),
+            Avro(config) => config.clone().into_py(py),
+            Orc(config) => config.clone().into_py(py),
+            Xlsx(config) => config.clone().into_py(py),
+            Text(config) => config.clone().into_py(py),
+            FixedWidth(config) => config.clone().into_py(py),
+            Files(config) => config.clone().into_py(py),
             Database(config) => config.clone().into_py(py),
             PythonFunction => py.None(),
         }
@@ -1,17 +1,26 @@
 use std::{sync::Arc, vec};
 
 use common_error::{DaftError, DaftResult};
-use daft_core::schema::SchemaRef;
+use daft_core::{
+    datatypes::Field,
+    schema::{Schema, SchemaRef},
+};
 use daft_csv::CsvParseOptions;
 use daft_io::{
     parse_url, FileFormat, FileMetadata, IOClient, IOStatsContext, IOStatsRef, RuntimeRef,
 };
 use daft_parquet::read::ParquetSchemaInferenceOptions;
+use daft_stats::{PartitionSpec, TableMetadata};
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use itertools::Itertools;
 use snafu::Snafu;
 
 use crate::{
-    file_format::{CsvSourceConfig, FileFormatConfig, ParquetSourceConfig},
+    file_format::{
+        CsvSourceConfig, FileFormatConfig, FixedWidthSourceConfig, IpcSourceConfig,
+        OrcSourceConfig, ParquetSourceConfig, TextSourceConfig, XlsxSourceConfig,
+    },
+    hive::{hive_partition_values, infer_hive_partitions},
     storage_config::StorageConfig,
     ChunkSpec, DataSource, PartitionField, Pushdowns, ScanOperator, ScanTask, ScanTaskRef,
 };
@@ -21,6 +30,8 @@ pub struct GlobScanOperator {
     file_format_config: Arc<FileFormatConfig>,
     schema: SchemaRef,
     storage_config: Arc<StorageConfig>,
+    partitioning_keys: Vec<PartitionField>,
+    union_by_name: bool,
 }
 
 /// Wrapper struct that implements a sync Iterator for a BoxStream
@@ -125,13 +136,164 @@ fn run_glob_parallel(
     Ok(iterator)
 }
 
+/// Number of files that are read concurrently when inferring per-file schemas.
+const SCHEMA_INFERENCE_PARALLELISM: usize = 128;
+
+/// Infers the schema of each of the files at `filepaths`.
+fn infer_file_schemas(
+    filepaths: &[String],
+    file_format_config: &FileFormatConfig,
+    io_client: Arc<IOClient>,
+    io_stats: IOStatsRef,
+) -> DaftResult<Vec<Schema>> {
+    match file_format_config {
+        FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit,
+            field_id_mapping,
+            ..
+        }) => daft_parquet::read::read_parquet_schema_bulk(
+            filepaths
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            io_client,
+            Some(io_stats),
+            ParquetSchemaInferenceOptions {
+                coerce_int96_timestamp_unit: *coerce_int96_timestamp_unit,
+            },
+            field_id_mapping.clone(),
+        ),
+        FileFormatConfig::Csv(CsvSourceConfig {
+            delimiter,
+            has_headers,
+            double_quote,
+            quote,
+            escape_char,
+            comment,
+            allow_variable_columns,
+            ..
+        }) => {
+            let parse_options = CsvParseOptions::new_with_defaults(
+                *has_headers,
+                *delimiter,
+                *double_quote,
+                *quote,
+                *allow_variable_columns,
+                *escape_char,
+                *comment,
+            )?;
+            let schemas = daft_csv::read_csv_schema_bulk(
+                filepaths
+                    .iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+                Some(parse_options),
+                None,
+                io_client,
+                Some(io_stats),
+                SCHEMA_INFERENCE_PARALLELISM,
+            )?;
+            Ok(schemas.into_iter().map(|(schema, _)| schema).collect())
+        }
+        FileFormatConfig::Json(_) => daft_json::read_json_schema_bulk(
+            filepaths
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            None,
+            None,
+            io_client,
+            Some(io_stats),
+            SCHEMA_INFERENCE_PARALLELISM,
+        ),
+        FileFormatConfig::Ipc(_) => daft_ipc::read_ipc_schema_bulk(
+            filepaths
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            io_client,
+            Some(io_stats),
+            SCHEMA_INFERENCE_PARALLELISM,
+        ),
+        FileFormatConfig::Avro(_) => daft_avro::read_avro_schema_bulk(
+            filepaths
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            io_client,
+            Some(io_stats),
+            SCHEMA_INFERENCE_PARALLELISM,
+        ),
+        FileFormatConfig::Orc(_) => daft_orc::read_orc_schema_bulk(
+            filepaths
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            io_client,
+            Some(io_stats),
+            SCHEMA_INFERENCE_PARALLELISM,
+        ),
+        FileFormatConfig::Xlsx(XlsxSourceConfig {
+            sheet_name,
+            sheet_index,
+            header_row,
+        }) => daft_xlsx::read_xlsx_schema_bulk(
+            filepaths
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .as_slice(),
+            daft_xlsx::XlsxReadOptions {
+                sheet_name: sheet_name.clone(),
+                sheet_index: *sheet_index,
+                header_row: *header_row,
+                schema: None,
+            },
+            io_client,
+            Some(io_stats),
+            SCHEMA_INFERENCE_PARALLELISM,
+        ),
+        // Text files, fixed-width text files and whole files have fixed schemas, so they don't have to be read.
+        FileFormatConfig::Text(TextSourceConfig { path_column, .. }) => filepaths
+            .iter()
+            .map(|_| daft_text::text_schema(path_column.as_deref()))
+            .collect(),
+        FileFormatConfig::FixedWidth(FixedWidthSourceConfig { columns, .. }) => filepaths
+            .iter()
+            .map(|_| daft_text::fixed_width_schema(columns))
+            .collect(),
+        FileFormatConfig::Files(_) => Ok(filepaths
+            .iter()
+            .map(|_| daft_text::files_schema())
+            .collect()),
+        #[cfg(feature = "python")]
+        FileFormatConfig::Database(_) => Err(DaftError::ValueError(
+            "Cannot glob a database source".to_string(),
+        )),
+        #[cfg(feature = "python")]
+        FileFormatConfig::PythonFunction => Err(DaftError::ValueError(
+            "Cannot glob a PythonFunction source".to_string(),
+        )),
+    }
+}
+
 impl GlobScanOperator {
+    #[allow(clippy::too_many_arguments)]
     pub fn try_new(
         glob_paths: &[&str],
         file_format_config: Arc<FileFormatConfig>,
         storage_config: Arc<StorageConfig>,
         infer_schema: bool,
         schema: Option<SchemaRef>,
+        hive_partitioning: bool,
+        union_by_name: bool,
+        max_schema_inference_files: Option<usize>,
     ) -> DaftResult<Self> {
         let first_glob_path = match glob_paths.first() {
             None => Err(DaftError::ValueError(
@@ -165,76 +327,40 @@ impl GlobScanOperator {
             .into()),
         }?;
 
+        // Files matched by all of the glob paths, which the schema (when unioning by name) and the partition columns
+        // are inferred from.
+        let sample_filepaths = if (infer_schema && union_by_name) || hive_partitioning {
+            run_glob_parallel(
+                glob_paths.iter().map(|s| s.to_string()).collect(),
+                io_client.clone(),
+                io_runtime.clone(),
+                Some(io_stats.clone()),
+                file_format,
+            )?
+            .take(max_schema_inference_files.unwrap_or(usize::MAX).max(1))
+            .map(|f| f.map(|f| f.filepath))
+            .collect::<DaftResult<Vec<_>>>()?
+        } else {
+            vec![]
+        };
+
+        let schema_hint = schema.clone();
         let schema = match infer_schema {
             true => {
-                let inferred_schema = match file_format_config.as_ref() {
-                    FileFormatConfig::Parquet(ParquetSourceConfig {
-                        coerce_int96_timestamp_unit,
-                        field_id_mapping,
-                        ..
-                    }) => {
-                        let io_stats = IOStatsContext::new(format!(
-                            "GlobScanOperator constructor read_parquet_schema: for uri {first_filepath}"
-                        ));
-
-                        let (schema, _metadata) = daft_parquet::read::read_parquet_schema(
-                            first_filepath.as_str(),
-                            io_client.clone(),
-                            Some(io_stats),
-                            ParquetSchemaInferenceOptions {
-                                coerce_int96_timestamp_unit: *coerce_int96_timestamp_unit,
-                            },
-                            field_id_mapping.clone(),
-                        )?;
-
-                        schema
-                    }
-                    FileFormatConfig::Csv(CsvSourceConfig {
-                        delimiter,
-                        has_headers,
-                        double_quote,
-                        quote,
-                        escape_char,
-                        comment,
-                        allow_variable_columns,
-                        ..
-                    }) => {
-                        let (schema, _) = daft_csv::metadata::read_csv_schema(
-                            first_filepath.as_str(),
-                            Some(CsvParseOptions::new_with_defaults(
-                                *has_headers,
-                                *delimiter,
-                                *double_quote,
-                                *quote,
-                                *allow_variable_columns,
-                                *escape_char,
-                                *comment,
-                            )?),
-                            None,
-                            io_client,
-                            Some(io_stats),
-                        )?;
-                        schema
-                    }
-                    FileFormatConfig::Json(_) => daft_json::schema::read_json_schema(
-                        first_filepath.as_str(),
-                        None,
-                        None,
+                let inferred_schema = if union_by_name {
+                    // Merge the schemas of the files matched by all of the glob paths, so that columns which are
+                    // added or widened in later files aren't dropped.
+                    infer_file_schemas(&sample_filepaths, &file_format_config, io_client, io_stats)?
+                        .iter()
+                        .try_fold(Schema::empty(), |acc, schema| acc.union_by_name(schema))?
+                } else {
+                    infer_file_schemas(
+                        &[first_filepath.clone()],
+                        &file_format_config,
                         io_client,
-                        Some(io_stats),
-                    )?,
-                    #[cfg(feature = "python")]
-                    FileFormatConfig::Database(_) => {
-                        return Err(DaftError::ValueError(
-                            "Cannot glob a database source".to_string(),
-                        ))
-                    }
-                    #[cfg(feature = "python")]
-                    FileFormatConfig::PythonFunction => {
-                        return Err(DaftError::ValueError(
-                            "Cannot glob a PythonFunction source".to_string(),
-                        ))
-                    }
+                        io_stats,
+                    )?
+                    .remove(0)
                 };
                 match schema {
                     Some(hint) => Arc::new(inferred_schema.apply_hints(&hint)?),
@@ -243,11 +369,47 @@ impl GlobScanOperator {
             }
             false => schema.expect("Schema must be provided if infer_schema is false"),
         };
+
+        // Partition columns are discovered from the directories of the sampled files, and are typed according to the
+        // schema if it contains them, or inferred from their values otherwise.
+        let partitioning_keys = if hive_partitioning {
+            infer_hive_partitions(&sample_filepaths)
+                .into_iter()
+                .map(|(key, inferred_dtype)| {
+                    let dtype = match schema
+                        .get_field(&key)
+                        .ok()
+                        .or_else(|| schema_hint.as_ref()?.get_field(&key).ok())
+                    {
+                        Some(field) => field.dtype.clone(),
+                        None => inferred_dtype,
+                    };
+                    PartitionField::new(Field::new(key, dtype), None, None)
+                })
+                .collect::<DaftResult<Vec<_>>>()?
+        } else {
+            vec![]
+        };
+        let schema = if partitioning_keys.is_empty() {
+            schema
+        } else {
+            let mut fields = schema.fields.values().cloned().collect::<Vec<_>>();
+            for pfield in partitioning_keys.iter() {
+                match schema.fields.get_index_of(&pfield.field.name) {
+                    Some(index) => fields[index] = pfield.field.clone(),
+                    None => fields.push(pfield.field.clone()),
+                }
+            }
+            Arc::new(Schema::new(fields)?)
+        };
+
         Ok(Self {
             glob_paths: glob_paths.iter().map(|s| s.to_string()).collect(),
             file_format_config,
             schema,
             storage_config,
+            partitioning_keys,
+            union_by_name,
         })
     }
 }
@@ -258,7 +420,7 @@ impl ScanOperator for GlobScanOperator {
     }
 
     fn partitioning_keys(&self) -> &[PartitionField] {
-        &[]
+        &self.partitioning_keys
     }
 
     fn can_absorb_filter(&self) -> bool {
@@ -270,12 +432,24 @@ impl ScanOperator for GlobScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_provide_metadata(&self) -> bool {
+        self.file_format_config.provides_metadata()
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![
             "GlobScanOperator".to_string(),
             format!("Glob paths = [{}]", self.glob_paths.join(", ")),
         ];
+        if !self.partitioning_keys.is_empty() {
+            lines.push(format!(
+                "Partitioning keys = [{}]",
+                self.partitioning_keys
+                    .iter()
+                    .map(|pf| pf.to_string())
+                    .join(", ")
+            ));
+        }
         lines.extend(self.file_format_config.multiline_display());
         lines.extend(self.storage_config.multiline_display());
 
@@ -304,47 +478,97 @@ impl ScanOperator for GlobScanOperator {
         let file_format_config = self.file_format_config.clone();
         let schema = self.schema.clone();
         let storage_config = self.storage_config.clone();
+        let partitioning_keys = self.partitioning_keys.clone();
+        let union_by_name = self.union_by_name;
 
-        let row_groups = if let FileFormatConfig::Parquet(ParquetSourceConfig {
-            row_groups: Some(row_groups),
-            ..
-        }) = self.file_format_config.as_ref()
-        {
-            Some(row_groups.clone())
-        } else {
-            None
+        let chunk_specs = match self.file_format_config.as_ref() {
+            FileFormatConfig::Parquet(ParquetSourceConfig {
+                row_groups: Some(row_groups),
+                ..
+            }) => Some(
+                row_groups
+                    .iter()
+                    .map(|rg| rg.clone().map(ChunkSpec::Parquet))
+                    .collect::<Vec<_>>(),
+            ),
+            FileFormatConfig::Ipc(IpcSourceConfig {
+                record_batches: Some(record_batches),
+            }) => Some(
+                record_batches
+                    .iter()
+                    .map(|rb| rb.clone().map(ChunkSpec::Ipc))
+                    .collect::<Vec<_>>(),
+            ),
+            FileFormatConfig::Orc(OrcSourceConfig {
+                stripes: Some(stripes),
+            }) => Some(
+                stripes
+                    .iter()
+                    .map(|s| s.clone().map(ChunkSpec::Orc))
+                    .collect::<Vec<_>>(),
+            ),
+            _ => None,
         };
+        // Whole files are read as a row per file.
+        let metadata = matches!(self.file_format_config.as_ref(), FileFormatConfig::Files(_))
+            .then_some(TableMetadata { length: 1 });
 
-        // Create one ScanTask per file
-        Ok(Box::new(files.enumerate().map(move |(idx, f)| {
+        // Create one ScanTask per file, skipping files whose partition values don't satisfy the partition filters
+        Ok(Box::new(files.enumerate().filter_map(move |(idx, f)| {
             let FileMetadata {
                 filepath: path,
                 size: size_bytes,
+                etag,
+                last_modified,
                 ..
-            } = f?;
+            } = match f {
+                Ok(f) => f,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let partition_spec = if partitioning_keys.is_empty() {
+                None
+            } else {
+                let partition_spec = match hive_partition_values(&path, &partitioning_keys) {
+                    Ok(keys) => PartitionSpec { keys },
+                    Err(e) => return Some(Err(e)),
+                };
+                if let Some(partition_filters) = &pushdowns.partition_filters {
+                    match partition_spec.satisfies(partition_filters) {
+                        Ok(true) => {}
+                        Ok(false) => return None,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                Some(partition_spec)
+            };
 
-            let row_group = row_groups
+            let chunk_spec = chunk_specs
                 .as_ref()
-                .and_then(|rgs| rgs.get(idx).cloned())
+                .and_then(|cs| cs.get(idx).cloned())
                 .flatten();
-            let chunk_spec = row_group.map(ChunkSpec::Parquet);
-            Ok(ScanTask::new(
+            Some(Ok(ScanTask::new(
                 vec![DataSource::File {
                     path: path.to_string(),
                     chunk_spec,
                     size_bytes,
                     iceberg_delete_files: None,
-                    metadata: None,
-                    partition_spec: None,
+                    iceberg_equality_delete_files: None,
+                    delta_deletion_vector: None,
+                    metadata: metadata.clone(),
+                    partition_spec,
                     statistics: None,
                     parquet_metadata: None,
+                    etag,
+                    last_modified,
                 }],
                 file_format_config.clone(),
                 schema.clone(),
                 storage_config.clone(),
                 pushdowns.clone(),
             )
-            .into())
+            .with_union_by_name(union_by_name)
+            .into()))
         })))
     }
 }
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Utf8Array},
+    series::IntoSeries,
+};
+use daft_table::Table;
+
+use crate::PartitionField;
+
+/// Value that Hive writes in place of null partition values.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Decodes the `%XX` escapes that Hive uses for special characters in partition keys and values.
+pub(crate) fn unescape_path_name(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(byte) = name
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Parses the Hive-style `key=value` directory segments of `path` into partition keys and values, in the order in
+/// which they appear. Null partition values are returned as `None`, and only the first value of a key is kept.
+pub(crate) fn parse_hive_partitions(path: &str) -> Vec<(String, Option<String>)> {
+    let path = path.split_once("://").map_or(path, |(_, path)| path);
+    let Some((dirs, _)) = path.rsplit_once('/') else {
+        return vec![];
+    };
+    let mut partitions: Vec<(String, Option<String>)> = vec![];
+    for segment in dirs.split('/') {
+        let Some((key, value)) = segment.split_once('=') else {
+            continue;
+        };
+        let key = unescape_path_name(key);
+        if key.is_empty() || partitions.iter().any(|(k, _)| *k == key) {
+            continue;
+        }
+        let value = match unescape_path_name(value) {
+            value if value.is_empty() || value == HIVE_DEFAULT_PARTITION => None,
+            value => Some(value),
+        };
+        partitions.push((key, value));
+    }
+    partitions
+}
+
+/// Infers the type of a partition column from its values: columns whose values are all integers or all numbers are
+/// typed as such, and all other columns are kept as strings. Null values don't affect the type.
+pub(crate) fn infer_partition_dtype<'a>(
+    values: impl IntoIterator<Item = Option<&'a str>>,
+) -> DataType {
+    let mut dtype = None;
+    for value in values.into_iter().flatten() {
+        let value_dtype = if value.parse::<i64>().is_ok() {
+            DataType::Int64
+        } else if value.parse::<f64>().is_ok() && value.bytes().any(|b| b.is_ascii_digit()) {
+            // Only accept values with digits, so that strings like "inf" or "nan" aren't parsed as floats.
+            DataType::Float64
+        } else {
+            return DataType::Utf8;
+        };
+        dtype = match dtype {
+            Some(DataType::Int64) | None => Some(value_dtype),
+            dtype => dtype,
+        };
+    }
+    dtype.unwrap_or(DataType::Utf8)
+}
+
+/// Infers the partition columns of a Hive-partitioned dataset from the paths of its files: the partition keys are
+/// the union of the keys of all of the paths, in the order in which they first appear, and each is typed according to
+/// its values across all of the paths.
+pub(crate) fn infer_hive_partitions(paths: &[String]) -> Vec<(String, DataType)> {
+    let mut values: Vec<(String, Vec<Option<String>>)> = vec![];
+    for path in paths {
+        for (key, value) in parse_hive_partitions(path) {
+            match values.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, key_values)) => key_values.push(value),
+                None => values.push((key, vec![value])),
+            }
+        }
+    }
+    values
+        .into_iter()
+        .map(|(key, key_values)| {
+            let dtype = infer_partition_dtype(key_values.iter().map(|v| v.as_deref()));
+            (key, dtype)
+        })
+        .collect()
+}
+
+/// Builds the single-row table of the values of `partition_fields` encoded in the Hive-style directories of `path`,
+/// cast to the types of the fields. Partition keys that are missing from `path` are null.
+///
+/// The partition fields are inferred from a sample of the files, so this fails if `path` has a partition key that
+/// isn't one of them or a value that doesn't fit the type of its field, rather than dropping the value.
+pub(crate) fn hive_partition_values(
+    path: &str,
+    partition_fields: &[PartitionField],
+) -> DaftResult<Table> {
+    let partitions = parse_hive_partitions(path);
+    if let Some((key, _)) = partitions
+        .iter()
+        .find(|(key, _)| !partition_fields.iter().any(|f| f.field.name == *key))
+    {
+        return Err(DaftError::ValueError(format!(
+            "Hive partition key `{key}` of {path} does not appear in the files that the partition columns were \
+             inferred from. Increase `max_schema_inference_files`, or set it to None to infer them from all files."
+        )));
+    }
+    let partitions: HashMap<String, Option<String>> = partitions.into_iter().collect();
+    let columns = partition_fields
+        .iter()
+        .map(|pfield| {
+            let value = partitions.get(&pfield.field.name).cloned().flatten();
+            let column = Utf8Array::from_iter(&pfield.field.name, vec![value.clone()].into_iter())
+                .into_series()
+                .cast(&pfield.field.dtype)?;
+            if let Some(value) = value
+                && column.validity().is_some_and(|validity| validity.unset_bits() > 0)
+            {
+                return Err(DaftError::ValueError(format!(
+                    "Hive partition value `{value}` of {path} can not be cast to the type {} inferred for the `{}` \
+                     partition column. Increase `max_schema_inference_files`, or set it to None to infer the type \
+                     from all files.",
+                    pfield.field.dtype, pfield.field.name,
+                )));
+            }
+            Ok(column)
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::from_nonempty_columns(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::datatypes::{DataType, Field};
+
+    use super::{
+        hive_partition_values, infer_hive_partitions, infer_partition_dtype, parse_hive_partitions,
+    };
+    use crate::PartitionField;
+
+    #[test]
+    fn test_parse_hive_partitions() {
+        assert_eq!(
+            parse_hive_partitions(
+                "s3://bucket/table/date=2024-01-01/region=us%2Fwest/part-0.parquet"
+            ),
+            vec![
+                ("date".to_string(), Some("2024-01-01".to_string())),
+                ("region".to_string(), Some("us/west".to_string())),
+            ]
+        );
+        assert_eq!(
+            parse_hive_partitions("file:///tmp/a=__HIVE_DEFAULT_PARTITION__/a=2/b=/c=1.csv"),
+            vec![("a".to_string(), None), ("b".to_string(), None)]
+        );
+        assert_eq!(parse_hive_partitions("s3://bucket/data.parquet"), vec![]);
+    }
+
+    #[test]
+    fn test_infer_partition_dtype() {
+        assert_eq!(infer_partition_dtype([Some("2024")]), DataType::Int64);
+        assert_eq!(infer_partition_dtype([Some("-1.5")]), DataType::Float64);
+        assert_eq!(infer_partition_dtype([Some("nan")]), DataType::Utf8);
+        assert_eq!(infer_partition_dtype([Some("2024-01-01")]), DataType::Utf8);
+        assert_eq!(infer_partition_dtype([None]), DataType::Utf8);
+        assert_eq!(
+            infer_partition_dtype([Some("1"), None, Some("2.5")]),
+            DataType::Float64
+        );
+        assert_eq!(
+            infer_partition_dtype([Some("1"), Some("a"), Some("2.5")]),
+            DataType::Utf8
+        );
+    }
+
+    #[test]
+    fn test_infer_hive_partitions() {
+        let paths = [
+            "s3://bucket/table/year=2024/part-0.parquet",
+            "s3://bucket/table/year=2023/month=12/part-0.parquet",
+            "s3://bucket/table/year=2022/month=ab/part-0.parquet",
+        ]
+        .map(String::from);
+        assert_eq!(
+            infer_hive_partitions(&paths),
+            vec![
+                ("year".to_string(), DataType::Int64),
+                ("month".to_string(), DataType::Utf8),
+            ]
+        );
+        assert_eq!(infer_hive_partitions(&paths[..2])[1].1, DataType::Int64);
+    }
+
+    #[test]
+    fn test_hive_partition_values_rejects_values_outside_sample() {
+        let fields =
+            [PartitionField::new(Field::new("year", DataType::Int64), None, None).unwrap()];
+        let values = hive_partition_values("s3://bucket/year=2024/a.parquet", &fields).unwrap();
+        assert_eq!(values.len(), 1);
+        let values = hive_partition_values("s3://bucket/year=/a.parquet", &fields).unwrap();
+        assert!(!values
+            .get_column("year")
+            .unwrap()
+            .validity()
+            .unwrap()
+            .get_bit(0));
+
+        // Values that don't fit the inferred type and keys that weren't inferred aren't silently dropped.
+        assert!(hive_partition_values("s3://bucket/year=latest/a.parquet", &fields).is_err());
+        assert!(hive_partition_values("s3://bucket/year=2024/month=1/a.parquet", &fields).is_err());
+    }
+}
@@ -0,0 +1,290 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_avro::{read_avro_values, AvroValue};
+use daft_io::{IOClient, IOStatsRef};
+
+use super::normalize_path;
+
+/// Status of a manifest entry whose file was deleted from the table in the snapshot that wrote the manifest.
+const STATUS_DELETED: i64 = 2;
+/// Status of a manifest entry whose file was added to the table in the snapshot that wrote the manifest.
+const STATUS_ADDED: i64 = 1;
+
+fn invalid_manifest(path: &str, reason: &str) -> DaftError {
+    DaftError::ValueError(format!("Invalid Iceberg manifest {path}: {reason}"))
+}
+
+/// Bounds of the values of a partition field across the files of a manifest.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FieldSummary {
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+/// A manifest of a snapshot, from the snapshot's manifest list.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ManifestFile {
+    pub path: String,
+    pub partition_spec_id: i32,
+    /// Whether the manifest tracks delete files rather than data files.
+    pub is_deletes: bool,
+    pub sequence_number: i64,
+    /// Summaries of the partition fields of the manifest's files, in the order of the fields of its partition spec.
+    pub partitions: Option<Vec<FieldSummary>>,
+}
+
+impl ManifestFile {
+    /// Returns a manifest of a table of format version 1 that lists its manifests in its snapshots.
+    pub fn from_path(path: String, partition_spec_id: i32) -> Self {
+        Self {
+            path,
+            partition_spec_id,
+            is_deletes: false,
+            sequence_number: 0,
+            partitions: None,
+        }
+    }
+
+    fn from_avro(value: &AvroValue, manifest_list: &str) -> DaftResult<Self> {
+        let invalid = |reason: &str| invalid_manifest(manifest_list, reason);
+        let partitions = value
+            .field("partitions")
+            .and_then(AvroValue::as_array)
+            .map(|summaries| {
+                summaries
+                    .iter()
+                    .map(|s| FieldSummary {
+                        lower_bound: s
+                            .field("lower_bound")
+                            .and_then(AvroValue::as_bytes)
+                            .map(<[u8]>::to_vec),
+                        upper_bound: s
+                            .field("upper_bound")
+                            .and_then(AvroValue::as_bytes)
+                            .map(<[u8]>::to_vec),
+                    })
+                    .collect()
+            });
+        Ok(Self {
+            path: value
+                .field("manifest_path")
+                .and_then(AvroValue::as_str)
+                .ok_or_else(|| invalid("missing manifest_path"))?
+                .to_string(),
+            partition_spec_id: value
+                .field("partition_spec_id")
+                .and_then(AvroValue::as_long)
+                .ok_or_else(|| invalid("missing partition_spec_id"))?
+                as i32,
+            is_deletes: value.field("content").and_then(AvroValue::as_long) == Some(1),
+            sequence_number: value
+                .field("sequence_number")
+                .and_then(AvroValue::as_long)
+                .unwrap_or(0),
+            partitions,
+        })
+    }
+}
+
+/// Content of a file tracked by a manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+/// A live data or delete file of a manifest.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DataFile {
+    pub content: FileContent,
+    pub file_path: String,
+    pub file_format: String,
+    pub partition_spec_id: i32,
+    /// Partition values of the file, in the order of the fields of its partition spec.
+    pub partition: Vec<AvroValue>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    /// Data sequence number of the file, which orders it relative to delete files.
+    pub sequence_number: i64,
+    /// Serialized lower and upper bounds of the values of columns, keyed by field ID.
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+    /// Field IDs of the equality columns of an equality delete file.
+    pub equality_ids: Vec<i32>,
+}
+
+impl DataFile {
+    /// Parses a manifest entry, returning `None` if its file is deleted.
+    fn from_avro(value: &AvroValue, manifest: &ManifestFile) -> DaftResult<Option<Self>> {
+        let invalid = |reason: &str| invalid_manifest(&manifest.path, reason);
+        let status = value
+            .field("status")
+            .and_then(AvroValue::as_long)
+            .ok_or_else(|| invalid("missing status"))?;
+        if status == STATUS_DELETED {
+            return Ok(None);
+        }
+        // Sequence numbers of added files are inherited from the manifest.
+        let sequence_number = match value.field("sequence_number").and_then(AvroValue::as_long) {
+            Some(sequence_number) if status != STATUS_ADDED || sequence_number != 0 => {
+                sequence_number
+            }
+            _ => manifest.sequence_number,
+        };
+        let data_file = value
+            .field("data_file")
+            .ok_or_else(|| invalid("missing data_file"))?;
+        let content = match data_file.field("content").and_then(AvroValue::as_long) {
+            None | Some(0) => FileContent::Data,
+            Some(1) => FileContent::PositionDeletes,
+            Some(2) => FileContent::EqualityDeletes,
+            Some(content) => return Err(invalid(&format!("unknown file content {content}"))),
+        };
+        let bounds = |name: &str| -> HashMap<i32, Vec<u8>> {
+            data_file
+                .field(name)
+                .and_then(AvroValue::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| {
+                    let key = entry.field("key")?.as_long()?;
+                    let value = entry.field("value")?.as_bytes()?;
+                    Some((key as i32, value.to_vec()))
+                })
+                .collect()
+        };
+        let partition = match data_file.field("partition") {
+            Some(AvroValue::Record(fields)) => fields.iter().map(|(_, v)| v.clone()).collect(),
+            _ => vec![],
+        };
+        Ok(Some(Self {
+            content,
+            file_path: data_file
+                .field("file_path")
+                .and_then(AvroValue::as_str)
+                .ok_or_else(|| invalid("missing file_path"))?
+                .to_string(),
+            file_format: data_file
+                .field("file_format")
+                .and_then(AvroValue::as_str)
+                .unwrap_or("PARQUET")
+                .to_uppercase(),
+            partition_spec_id: manifest.partition_spec_id,
+            partition,
+            record_count: data_file
+                .field("record_count")
+                .and_then(AvroValue::as_long)
+                .ok_or_else(|| invalid("missing record_count"))?,
+            file_size_in_bytes: data_file
+                .field("file_size_in_bytes")
+                .and_then(AvroValue::as_long)
+                .ok_or_else(|| invalid("missing file_size_in_bytes"))?,
+            sequence_number,
+            lower_bounds: bounds("lower_bounds"),
+            upper_bounds: bounds("upper_bounds"),
+            equality_ids: data_file
+                .field("equality_ids")
+                .and_then(AvroValue::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| id.as_long().map(|id| id as i32))
+                .collect(),
+        }))
+    }
+}
+
+/// Reads the manifests of a snapshot from its manifest list.
+pub(crate) async fn read_manifest_list(
+    path: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<ManifestFile>> {
+    let (_, values) = read_avro_values(&normalize_path(path), io_client, io_stats).await?;
+    values
+        .iter()
+        .map(|value| ManifestFile::from_avro(value, path))
+        .collect()
+}
+
+/// Reads the live files of a manifest.
+pub(crate) async fn read_manifest(
+    manifest: &ManifestFile,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Vec<DataFile>> {
+    let (_, values) =
+        read_avro_values(&normalize_path(&manifest.path), io_client, io_stats).await?;
+    values
+        .iter()
+        .filter_map(|value| DataFile::from_avro(value, manifest).transpose())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_avro::AvroValue;
+
+    use super::{DataFile, FileContent, ManifestFile};
+
+    fn record(fields: Vec<(&str, AvroValue)>) -> AvroValue {
+        AvroValue::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_parse_manifest_entry() {
+        let manifest = ManifestFile {
+            path: "m.avro".to_string(),
+            partition_spec_id: 1,
+            is_deletes: true,
+            sequence_number: 5,
+            partitions: None,
+        };
+        let data_file = record(vec![
+            ("content", AvroValue::Int(2)),
+            ("file_path", AvroValue::String("d.parquet".to_string())),
+            ("file_format", AvroValue::String("parquet".to_string())),
+            ("partition", record(vec![("x", AvroValue::Int(3))])),
+            ("record_count", AvroValue::Long(10)),
+            ("file_size_in_bytes", AvroValue::Long(100)),
+            (
+                "lower_bounds",
+                AvroValue::Array(vec![record(vec![
+                    ("key", AvroValue::Int(1)),
+                    ("value", AvroValue::Bytes(vec![1, 0, 0, 0])),
+                ])]),
+            ),
+            ("upper_bounds", AvroValue::Null),
+            (
+                "equality_ids",
+                AvroValue::Array(vec![AvroValue::Int(1), AvroValue::Int(2)]),
+            ),
+        ]);
+        let entry = record(vec![
+            ("status", AvroValue::Int(1)),
+            ("sequence_number", AvroValue::Null),
+            ("data_file", data_file.clone()),
+        ]);
+        let file = DataFile::from_avro(&entry, &manifest).unwrap().unwrap();
+        assert_eq!(file.content, FileContent::EqualityDeletes);
+        assert_eq!(file.file_format, "PARQUET");
+        assert_eq!(file.partition, vec![AvroValue::Int(3)]);
+        // The sequence number of an added file is inherited from its manifest.
+        assert_eq!(file.sequence_number, 5);
+        assert_eq!(file.lower_bounds.get(&1), Some(&vec![1, 0, 0, 0]));
+        assert!(file.upper_bounds.is_empty());
+        assert_eq!(file.equality_ids, vec![1, 2]);
+
+        let deleted = record(vec![
+            ("status", AvroValue::Int(2)),
+            ("data_file", data_file),
+        ]);
+        assert_eq!(DataFile::from_avro(&deleted, &manifest).unwrap(), None);
+    }
+}
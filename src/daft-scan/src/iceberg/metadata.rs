@@ -0,0 +1,513 @@
+use std::collections::BTreeMap;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{PartitionField, PartitionTransform};
+
+fn invalid_metadata(reason: impl Into<String>) -> DaftError {
+    DaftError::ValueError(format!("Invalid Iceberg table metadata: {}", reason.into()))
+}
+
+/// Primitive type of the Iceberg table spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PrimitiveType {
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Decimal { precision: usize, scale: usize },
+    Date,
+    Time,
+    Timestamp,
+    Timestamptz,
+    TimestampNs,
+    TimestamptzNs,
+    String,
+    Uuid,
+    Fixed(usize),
+    Binary,
+}
+
+impl PrimitiveType {
+    fn parse(name: &str) -> DaftResult<Self> {
+        let parse_params = |params: &str| -> Option<Vec<usize>> {
+            params
+                .split(',')
+                .map(|p| p.trim().parse().ok())
+                .collect::<Option<Vec<_>>>()
+        };
+        Ok(match name {
+            "boolean" => Self::Boolean,
+            "int" => Self::Int,
+            "long" => Self::Long,
+            "float" => Self::Float,
+            "double" => Self::Double,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "timestamp" => Self::Timestamp,
+            "timestamptz" => Self::Timestamptz,
+            "timestamp_ns" => Self::TimestampNs,
+            "timestamptz_ns" => Self::TimestamptzNs,
+            "string" => Self::String,
+            "uuid" => Self::Uuid,
+            "binary" => Self::Binary,
+            _ if let Some(params) = name
+                .strip_prefix("decimal(")
+                .and_then(|p| p.strip_suffix(')'))
+                .and_then(parse_params)
+                && let [precision, scale] = params[..] =>
+            {
+                Self::Decimal { precision, scale }
+            }
+            _ if let Some(params) = name
+                .strip_prefix("fixed[")
+                .and_then(|p| p.strip_suffix(']'))
+                .and_then(parse_params)
+                && let [size] = params[..] =>
+            {
+                Self::Fixed(size)
+            }
+            _ => return Err(invalid_metadata(format!("unknown type {name}"))),
+        })
+    }
+
+    pub fn to_daft_dtype(&self) -> DataType {
+        match self {
+            Self::Boolean => DataType::Boolean,
+            Self::Int => DataType::Int32,
+            Self::Long => DataType::Int64,
+            Self::Float => DataType::Float32,
+            Self::Double => DataType::Float64,
+            Self::Decimal { precision, scale } => DataType::Decimal128(*precision, *scale),
+            Self::Date => DataType::Date,
+            Self::Time => DataType::Time(TimeUnit::Microseconds),
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Microseconds, None),
+            Self::Timestamptz => {
+                DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()))
+            }
+            Self::TimestampNs => DataType::Timestamp(TimeUnit::Nanoseconds, None),
+            Self::TimestamptzNs => {
+                DataType::Timestamp(TimeUnit::Nanoseconds, Some("UTC".to_string()))
+            }
+            Self::String => DataType::Utf8,
+            Self::Uuid => DataType::FixedSizeBinary(16),
+            Self::Fixed(size) => DataType::FixedSizeBinary(*size),
+            Self::Binary => DataType::Binary,
+        }
+    }
+}
+
+/// Type of the Iceberg table spec, which is a primitive type or a nested type whose fields are identified by IDs.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "Value")]
+pub(crate) enum IcebergType {
+    Primitive(PrimitiveType),
+    Struct(Vec<NestedField>),
+    List {
+        element_id: i32,
+        element: Box<IcebergType>,
+    },
+    Map {
+        key_id: i32,
+        key: Box<IcebergType>,
+        value_id: i32,
+        value: Box<IcebergType>,
+    },
+}
+
+impl TryFrom<Value> for IcebergType {
+    type Error = DaftError;
+
+    fn try_from(value: Value) -> DaftResult<Self> {
+        let field = |object: &serde_json::Map<String, Value>, name: &str| {
+            object
+                .get(name)
+                .cloned()
+                .ok_or_else(|| invalid_metadata(format!("type is missing the {name} field")))
+        };
+        let id = |object: &serde_json::Map<String, Value>, name: &str| -> DaftResult<i32> {
+            field(object, name)?
+                .as_i64()
+                .and_then(|id| i32::try_from(id).ok())
+                .ok_or_else(|| invalid_metadata(format!("invalid {name}")))
+        };
+        match value {
+            Value::String(name) => Ok(Self::Primitive(PrimitiveType::parse(&name)?)),
+            Value::Object(object) => match object.get("type").and_then(Value::as_str) {
+                Some("struct") => Ok(Self::Struct(
+                    serde_json::from_value(field(&object, "fields")?)
+                        .map_err(|e| invalid_metadata(e.to_string()))?,
+                )),
+                Some("list") => Ok(Self::List {
+                    element_id: id(&object, "element-id")?,
+                    element: Box::new(field(&object, "element")?.try_into()?),
+                }),
+                Some("map") => Ok(Self::Map {
+                    key_id: id(&object, "key-id")?,
+                    key: Box::new(field(&object, "key")?.try_into()?),
+                    value_id: id(&object, "value-id")?,
+                    value: Box::new(field(&object, "value")?.try_into()?),
+                }),
+                _ => Err(invalid_metadata(format!("unknown type {:?}", object))),
+            },
+            _ => Err(invalid_metadata(format!("unknown type {value}"))),
+        }
+    }
+}
+
+impl IcebergType {
+    pub fn to_daft_dtype(&self) -> DataType {
+        match self {
+            Self::Primitive(primitive) => primitive.to_daft_dtype(),
+            Self::Struct(fields) => {
+                DataType::Struct(fields.iter().map(NestedField::to_daft_field).collect())
+            }
+            Self::List { element, .. } => DataType::List(Box::new(element.to_daft_dtype())),
+            Self::Map { key, value, .. } => DataType::Map(Box::new(DataType::Struct(vec![
+                Field::new("key", key.to_daft_dtype()),
+                Field::new("value", value.to_daft_dtype()),
+            ]))),
+        }
+    }
+
+    /// Adds the Daft fields of the nested fields of this type to `mapping`, keyed by their field IDs.
+    fn collect_field_ids(&self, mapping: &mut BTreeMap<i32, Field>) {
+        match self {
+            Self::Primitive(_) => {}
+            Self::Struct(fields) => {
+                for field in fields {
+                    mapping.insert(field.id, field.to_daft_field());
+                    field.field_type.collect_field_ids(mapping);
+                }
+            }
+            Self::List {
+                element_id,
+                element,
+            } => {
+                mapping.insert(*element_id, Field::new("element", element.to_daft_dtype()));
+                element.collect_field_ids(mapping);
+            }
+            Self::Map {
+                key_id,
+                key,
+                value_id,
+                value,
+            } => {
+                mapping.insert(*key_id, Field::new("key", key.to_daft_dtype()));
+                mapping.insert(*value_id, Field::new("value", value.to_daft_dtype()));
+                key.collect_field_ids(mapping);
+                value.collect_field_ids(mapping);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct NestedField {
+    pub id: i32,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: IcebergType,
+}
+
+impl NestedField {
+    fn to_daft_field(&self) -> Field {
+        Field::new(self.name.as_str(), self.field_type.to_daft_dtype())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct IcebergSchema {
+    #[serde(default)]
+    pub schema_id: i32,
+    pub fields: Vec<NestedField>,
+}
+
+impl IcebergSchema {
+    pub fn to_daft_schema(&self) -> DaftResult<Schema> {
+        Schema::new(self.fields.iter().map(NestedField::to_daft_field).collect())
+    }
+
+    /// Returns the mapping of the IDs of all fields of the schema, including nested fields, to their Daft fields.
+    pub fn field_id_mapping(&self) -> BTreeMap<i32, Field> {
+        let mut mapping = BTreeMap::new();
+        IcebergType::Struct(self.fields.clone()).collect_field_ids(&mut mapping);
+        mapping
+    }
+
+    /// Returns the top-level field with the ID `id`.
+    pub fn field(&self, id: i32) -> Option<&NestedField> {
+        self.fields.iter().find(|f| f.id == id)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct IcebergPartitionField {
+    pub source_id: i32,
+    pub name: String,
+    pub transform: String,
+}
+
+impl IcebergPartitionField {
+    fn daft_transform(&self) -> Option<PartitionTransform> {
+        let param = |prefix: &str| {
+            self.transform
+                .strip_prefix(prefix)
+                .and_then(|p| p.strip_suffix(']'))
+                .and_then(|p| p.parse().ok())
+        };
+        match self.transform.as_str() {
+            "identity" => Some(PartitionTransform::Identity),
+            "year" => Some(PartitionTransform::Year),
+            "month" => Some(PartitionTransform::Month),
+            "day" => Some(PartitionTransform::Day),
+            "hour" => Some(PartitionTransform::Hour),
+            "void" => Some(PartitionTransform::Void),
+            _ if let Some(n) = param("bucket[") => Some(PartitionTransform::IcebergBucket(n)),
+            _ if let Some(w) = param("truncate[") => Some(PartitionTransform::IcebergTruncate(w)),
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the partition values of this field, given the type of its source field.
+    pub fn result_type(&self, source_type: &PrimitiveType) -> PrimitiveType {
+        match self.daft_transform() {
+            Some(
+                PartitionTransform::Year
+                | PartitionTransform::Month
+                | PartitionTransform::Hour
+                | PartitionTransform::IcebergBucket(_),
+            ) => PrimitiveType::Int,
+            Some(PartitionTransform::Day) => PrimitiveType::Date,
+            _ => source_type.clone(),
+        }
+    }
+
+    /// Returns the Daft partition field of this field, or `None` if its source field isn't a primitive top-level
+    /// field of `schema`.
+    pub fn to_daft_partition_field(
+        &self,
+        schema: &IcebergSchema,
+    ) -> DaftResult<Option<PartitionField>> {
+        let Some(NestedField {
+            name: source_name,
+            field_type: IcebergType::Primitive(source_type),
+            ..
+        }) = schema.field(self.source_id)
+        else {
+            return Ok(None);
+        };
+        let result_type = self.result_type(source_type);
+        Ok(Some(PartitionField::new(
+            Field::new(self.name.as_str(), result_type.to_daft_dtype()),
+            Some(Field::new(
+                source_name.as_str(),
+                source_type.to_daft_dtype(),
+            )),
+            self.daft_transform(),
+        )?))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct IcebergPartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<IcebergPartitionField>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Snapshot {
+    pub snapshot_id: i64,
+    pub schema_id: Option<i32>,
+    /// Manifest list of the snapshot, which is absent in tables of format version 1 that list their manifests in
+    /// `manifests` instead.
+    pub manifest_list: Option<String>,
+    pub manifests: Option<Vec<String>>,
+}
+
+/// Metadata of an Iceberg table, from its `*.metadata.json` file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct TableMetadata {
+    pub location: String,
+    #[serde(default)]
+    schemas: Vec<IcebergSchema>,
+    /// Schema of tables of format version 1 that don't list their schemas.
+    schema: Option<IcebergSchema>,
+    current_schema_id: Option<i32>,
+    #[serde(default)]
+    partition_specs: Vec<IcebergPartitionSpec>,
+    /// Partition fields of tables of format version 1 that don't list their partition specs.
+    partition_spec: Option<Vec<IcebergPartitionField>>,
+    #[serde(default)]
+    default_spec_id: i32,
+    current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    snapshots: Vec<Snapshot>,
+}
+
+impl TableMetadata {
+    pub fn parse(bytes: &[u8]) -> DaftResult<Self> {
+        serde_json::from_slice(bytes).map_err(|e| invalid_metadata(e.to_string()))
+    }
+
+    pub fn schema(&self, schema_id: Option<i32>) -> DaftResult<&IcebergSchema> {
+        match schema_id.or(self.current_schema_id) {
+            Some(id) if let Some(schema) = self.schemas.iter().find(|s| s.schema_id == id) => {
+                Ok(schema)
+            }
+            _ => self
+                .schema
+                .as_ref()
+                .or(self.schemas.last())
+                .ok_or_else(|| invalid_metadata("missing schema")),
+        }
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<IcebergPartitionSpec> {
+        match self.partition_specs.iter().find(|s| s.spec_id == spec_id) {
+            Some(spec) => Some(spec.clone()),
+            None if spec_id == 0 => {
+                self.partition_spec
+                    .as_ref()
+                    .map(|fields| IcebergPartitionSpec {
+                        spec_id,
+                        fields: fields.clone(),
+                    })
+            }
+            None => None,
+        }
+    }
+
+    pub fn default_spec_id(&self) -> i32 {
+        self.default_spec_id
+    }
+
+    pub fn default_partition_spec(&self) -> Option<IcebergPartitionSpec> {
+        self.partition_spec(self.default_spec_id)
+    }
+
+    /// Returns the snapshot with the ID `snapshot_id`, or the current snapshot if no ID is given. Tables without a
+    /// current snapshot have no data.
+    pub fn snapshot(&self, snapshot_id: Option<i64>) -> DaftResult<Option<&Snapshot>> {
+        match snapshot_id {
+            Some(id) => self
+                .snapshots
+                .iter()
+                .find(|s| s.snapshot_id == id)
+                .map(Some)
+                .ok_or_else(|| {
+                    DaftError::ValueError(format!(
+                        "Snapshot {id} doesn't exist in the Iceberg table"
+                    ))
+                }),
+            None => Ok(self
+                .current_snapshot_id
+                .and_then(|id| self.snapshots.iter().find(|s| s.snapshot_id == id))),
+        }
+    }
+}
+
+/// Returns the version of a metadata file from its name, which is either `v<version>.metadata.json` or
+/// `<version>-<uuid>.metadata.json`.
+pub(crate) fn metadata_file_version(path: &str) -> Option<u64> {
+    let name = path.rsplit('/').next()?.strip_suffix(".metadata.json")?;
+    let name = name.strip_prefix('v').unwrap_or(name);
+    name.split('-').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::{metadata_file_version, TableMetadata};
+    use crate::PartitionTransform;
+
+    #[test]
+    fn test_parse_table_metadata() {
+        let metadata = TableMetadata::parse(
+            br#"{
+                "format-version": 2,
+                "location": "s3://bucket/table",
+                "current-schema-id": 1,
+                "schemas": [
+                    {"type": "struct", "schema-id": 0, "fields": [{"id": 1, "name": "old", "required": false, "type": "int"}]},
+                    {"type": "struct", "schema-id": 1, "fields": [
+                        {"id": 1, "name": "id", "required": true, "type": "long"},
+                        {"id": 2, "name": "ts", "required": false, "type": "timestamptz"},
+                        {"id": 3, "name": "price", "required": false, "type": "decimal(10, 2)"},
+                        {"id": 4, "name": "tags", "required": false, "type": {
+                            "type": "list", "element-id": 5, "element": "string", "element-required": false
+                        }}
+                    ]}
+                ],
+                "default-spec-id": 0,
+                "partition-specs": [{"spec-id": 0, "fields": [
+                    {"source-id": 2, "field-id": 1000, "name": "ts_day", "transform": "day"},
+                    {"source-id": 1, "field-id": 1001, "name": "id_bucket", "transform": "bucket[16]"}
+                ]}],
+                "current-snapshot-id": 7,
+                "snapshots": [{"snapshot-id": 7, "timestamp-ms": 0, "manifest-list": "s3://bucket/table/metadata/snap-7.avro"}]
+            }"#,
+        )
+        .unwrap();
+
+        let schema = metadata.schema(None).unwrap();
+        assert_eq!(
+            schema.to_daft_schema().unwrap(),
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new(
+                    "ts",
+                    DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()))
+                ),
+                Field::new("price", DataType::Decimal128(10, 2)),
+                Field::new("tags", DataType::List(Box::new(DataType::Utf8))),
+            ])
+            .unwrap()
+        );
+        assert_eq!(
+            schema.field_id_mapping().get(&5),
+            Some(&Field::new("element", DataType::Utf8))
+        );
+        assert_eq!(metadata.schema(Some(0)).unwrap().fields[0].name, "old");
+
+        let spec = metadata.default_partition_spec().unwrap();
+        let fields = spec
+            .fields
+            .iter()
+            .map(|f| f.to_daft_partition_field(schema).unwrap().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(fields[0].field, Field::new("ts_day", DataType::Date));
+        assert_eq!(fields[0].transform, Some(PartitionTransform::Day));
+        assert_eq!(fields[1].field, Field::new("id_bucket", DataType::Int32));
+        assert_eq!(
+            fields[1].transform,
+            Some(PartitionTransform::IcebergBucket(16))
+        );
+
+        assert_eq!(metadata.snapshot(None).unwrap().unwrap().snapshot_id, 7);
+        assert!(metadata.snapshot(Some(8)).is_err());
+    }
+
+    #[test]
+    fn test_metadata_file_version() {
+        assert_eq!(
+            metadata_file_version("s3://b/t/metadata/v12.metadata.json"),
+            Some(12)
+        );
+        assert_eq!(
+            metadata_file_version("/t/metadata/00003-6a1b-4c2d.metadata.json"),
+            Some(3)
+        );
+        assert_eq!(metadata_file_version("/t/metadata/snap-1.avro"), None);
+    }
+}
@@ -0,0 +1,594 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::TimeUnit, schema::SchemaRef};
+use daft_io::{IOClient, IOStatsContext, IOStatsRef};
+use daft_stats::{
+    ColumnRangeStatistics, PartitionSpec, TableMetadata as ScanTaskMetadata, TableStatistics,
+    TruthValue,
+};
+use daft_table::Table;
+use futures::{StreamExt, TryStreamExt};
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+use crate::{
+    file_format::{AvroSourceConfig, FileFormatConfig, ParquetSourceConfig},
+    storage_config::StorageConfig,
+    DataSource, IcebergEqualityDeleteFile, PartitionField, Pushdowns, ScanOperator, ScanTask,
+    ScanTaskRef,
+};
+
+mod manifest;
+mod metadata;
+mod values;
+
+use manifest::{read_manifest, read_manifest_list, DataFile, FileContent, ManifestFile};
+use metadata::{metadata_file_version, IcebergSchema, IcebergType, PrimitiveType, TableMetadata};
+use values::{literal_to_series, Literal};
+
+/// Number of manifests that are read concurrently when planning a scan.
+const MANIFEST_READ_PARALLELISM: usize = 32;
+
+/// Field ID of the `file_path` column of positional delete files.
+const DELETE_FILE_PATH_FIELD_ID: i32 = 2147483546;
+
+/// Names and types of the partition values of the fields of a partition spec, which are `None` for fields whose
+/// source fields aren't primitive top-level fields of the table schema.
+type PartitionTypes = Vec<Option<(String, PrimitiveType)>>;
+
+/// Scans an Iceberg table by reading its metadata, manifest lists and manifests directly, without a catalog.
+#[derive(Debug)]
+pub struct IcebergScanOperator {
+    metadata_location: String,
+    metadata: TableMetadata,
+    snapshot_id: Option<i64>,
+    iceberg_schema: IcebergSchema,
+    schema: SchemaRef,
+    partitioning_keys: Vec<PartitionField>,
+    parquet_config: Arc<FileFormatConfig>,
+    storage_config: Arc<StorageConfig>,
+}
+
+/// Converts a local path written by Java writers, such as `file:/path`, to a URL that Daft can read.
+pub(crate) fn normalize_path(path: &str) -> String {
+    match path.strip_prefix("file:") {
+        Some(rest) if !rest.starts_with("//") => format!("file://{rest}"),
+        _ => path.to_string(),
+    }
+}
+
+/// Returns the location of the current metadata file of a table, given either a metadata file or the root of the
+/// table, whose `metadata` directory either has a `version-hint.text` file or metadata files named by version.
+async fn find_metadata_file(
+    table_location: &str,
+    io_client: Arc<IOClient>,
+    io_stats: IOStatsRef,
+) -> DaftResult<String> {
+    let location = normalize_path(table_location);
+    if location.ends_with(".metadata.json") {
+        return Ok(location);
+    }
+    let metadata_dir = format!("{}/metadata", location.trim_end_matches('/'));
+    if let Ok(hint) = io_client
+        .single_url_get(
+            format!("{metadata_dir}/version-hint.text"),
+            None,
+            Some(io_stats.clone()),
+        )
+        .await
+    {
+        let hint = hint.bytes().await?;
+        if let Some(version) = std::str::from_utf8(&hint)
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            return Ok(format!("{metadata_dir}/v{version}.metadata.json"));
+        }
+    }
+    let files = io_client
+        .glob(
+            format!("{metadata_dir}/*.metadata.json"),
+            None,
+            None,
+            None,
+            Some(io_stats),
+            None,
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    files
+        .into_iter()
+        .filter_map(|f| Some((metadata_file_version(&f.filepath)?, f.filepath)))
+        .max_by_key(|(version, _)| *version)
+        .map(|(_, path)| path)
+        .ok_or_else(|| {
+            DaftError::ValueError(format!("No Iceberg metadata files found in {metadata_dir}"))
+        })
+}
+
+impl IcebergScanOperator {
+    /// Creates a scan of the snapshot `snapshot_id` of a table, or of its current snapshot if no ID is given, where
+    /// `table_location` is either a `*.metadata.json` file or the root of the table.
+    pub fn try_new(
+        table_location: &str,
+        snapshot_id: Option<i64>,
+        storage_config: Arc<StorageConfig>,
+    ) -> DaftResult<Self> {
+        let (io_runtime, io_client) = storage_config.get_io_client_and_runtime()?;
+        let io_stats =
+            IOStatsContext::new(format!("IcebergScanOperator::try_new for {table_location}"));
+        let metadata_location = io_runtime.block_on_current_thread(find_metadata_file(
+            table_location,
+            io_client.clone(),
+            io_stats.clone(),
+        ))?;
+        let metadata_bytes = io_runtime.block_on_current_thread(async {
+            io_client
+                .single_url_get(metadata_location.clone(), None, Some(io_stats))
+                .await?
+                .bytes()
+                .await
+        })?;
+        let metadata = TableMetadata::parse(&metadata_bytes)?;
+
+        // Scans of past snapshots use the schema that was current when the snapshot was written.
+        let snapshot_schema_id = match snapshot_id {
+            Some(_) => metadata.snapshot(snapshot_id)?.and_then(|s| s.schema_id),
+            None => None,
+        };
+        let iceberg_schema = metadata.schema(snapshot_schema_id)?.clone();
+        let schema = Arc::new(iceberg_schema.to_daft_schema()?);
+        let partitioning_keys = match metadata.default_partition_spec() {
+            Some(spec) => spec
+                .fields
+                .iter()
+                .map(|f| f.to_daft_partition_field(&iceberg_schema))
+                .filter_map_ok(|f| f)
+                .collect::<DaftResult<Vec<_>>>()?,
+            None => vec![],
+        };
+        let parquet_config = Arc::new(FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit: TimeUnit::Nanoseconds,
+            field_id_mapping: Some(Arc::new(iceberg_schema.field_id_mapping())),
+            row_groups: None,
+            chunk_size: None,
+        }));
+        Ok(Self {
+            metadata_location,
+            metadata,
+            snapshot_id,
+            iceberg_schema,
+            schema,
+            partitioning_keys,
+            parquet_config,
+            storage_config,
+        })
+    }
+
+    fn partition_types(&self, spec_id: i32) -> PartitionTypes {
+        let Some(spec) = self.metadata.partition_spec(spec_id) else {
+            return vec![];
+        };
+        spec.fields
+            .iter()
+            .map(|f| match self.iceberg_schema.field(f.source_id) {
+                Some(source) if let IcebergType::Primitive(source_type) = &source.field_type => {
+                    Some((f.name.clone(), f.result_type(source_type)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns whether the files of a data manifest may satisfy the partition filters, according to the bounds of
+    /// their partition values.
+    fn manifest_may_match(
+        &self,
+        manifest: &ManifestFile,
+        partition_types: &PartitionTypes,
+        pushdowns: &Pushdowns,
+    ) -> DaftResult<bool> {
+        let (Some(partition_filters), Some(summaries)) =
+            (&pushdowns.partition_filters, &manifest.partitions)
+        else {
+            return Ok(true);
+        };
+        if manifest.partition_spec_id != self.metadata.default_spec_id()
+            || summaries.len() != partition_types.len()
+        {
+            return Ok(true);
+        }
+        let mut columns = IndexMap::new();
+        for (ty, summary) in partition_types.iter().zip(summaries) {
+            let Some((name, ty)) = ty else {
+                continue;
+            };
+            let bound = |bytes: &Option<Vec<u8>>| {
+                bytes
+                    .as_ref()
+                    .and_then(|b| Literal::from_bytes(ty, b))
+                    .map(|v| literal_to_series(name, ty, Some(&v)))
+                    .transpose()
+            };
+            let stats = match (bound(&summary.lower_bound)?, bound(&summary.upper_bound)?) {
+                (Some(lower), Some(upper)) => ColumnRangeStatistics::new(Some(lower), Some(upper))?,
+                _ => ColumnRangeStatistics::Missing,
+            };
+            columns.insert(name.clone(), stats);
+        }
+        Ok(may_match(&TableStatistics { columns }, partition_filters))
+    }
+
+    /// Returns the ranges of the values of the top-level primitive columns of a data file.
+    fn file_statistics(&self, file: &DataFile) -> DaftResult<TableStatistics> {
+        let mut columns = IndexMap::new();
+        for field in &self.iceberg_schema.fields {
+            let stats = match &field.field_type {
+                IcebergType::Primitive(ty) => {
+                    let bound = |bounds: &HashMap<i32, Vec<u8>>| {
+                        bounds
+                            .get(&field.id)
+                            .and_then(|b| Literal::from_bytes(ty, b))
+                            .map(|v| literal_to_series(&field.name, ty, Some(&v)))
+                            .transpose()
+                    };
+                    match (bound(&file.lower_bounds)?, bound(&file.upper_bounds)?) {
+                        (Some(lower), Some(upper)) => {
+                            ColumnRangeStatistics::new(Some(lower), Some(upper))?
+                        }
+                        _ => ColumnRangeStatistics::Missing,
+                    }
+                }
+                _ => ColumnRangeStatistics::Missing,
+            };
+            columns.insert(field.name.clone(), stats);
+        }
+        Ok(TableStatistics { columns })
+    }
+
+    /// Returns the equality delete file of a delete file, whose equality columns are named by the table schema.
+    fn equality_delete_file(&self, file: &DataFile) -> DaftResult<IcebergEqualityDeleteFile> {
+        let equality_columns = file
+            .equality_ids
+            .iter()
+            .map(|id| {
+                self.iceberg_schema
+                    .field(*id)
+                    .map(|f| f.name.clone())
+                    .ok_or_else(|| {
+                        DaftError::ValueError(format!(
+                            "Iceberg equality deletes on field {id} are not supported, since it isn't a top-level column of the table schema"
+                        ))
+                    })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(IcebergEqualityDeleteFile {
+            path: file.file_path.clone(),
+            equality_columns,
+        })
+    }
+}
+
+/// Returns whether rows with the given column ranges may satisfy `filter`. Filters that can't be evaluated on the
+/// ranges may be satisfied.
+pub(crate) fn may_match(stats: &TableStatistics, filter: &daft_dsl::ExprRef) -> bool {
+    stats
+        .eval_expression(filter)
+        .map_or(true, |result| result.to_truth_value() != TruthValue::False)
+}
+
+/// Returns the partition values of a file as a partition spec, given the types of the values of its partition spec.
+fn partition_spec(
+    partition_types: &PartitionTypes,
+    partition: &[daft_avro::AvroValue],
+) -> DaftResult<Option<PartitionSpec>> {
+    let columns = partition_types
+        .iter()
+        .zip(partition)
+        .filter_map(|(ty, value)| {
+            let (name, ty) = ty.as_ref()?;
+            Some(literal_to_series(
+                name,
+                ty,
+                Literal::from_avro(value).as_ref(),
+            ))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    if columns.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(PartitionSpec {
+        keys: Table::from_nonempty_columns(columns)?,
+    }))
+}
+
+/// Returns whether a delete file applies to a data file, given the sequence numbers and partitions of both files.
+///
+/// Positional deletes apply to data files of the same or earlier sequence numbers, whereas equality deletes only apply
+/// to data files of earlier sequence numbers. Deletes of unpartitioned specs apply to data files of all partitions.
+///
+/// See: https://iceberg.apache.org/spec/#scan-planning
+fn delete_applies(delete: &DataFile, data: &DataFile) -> bool {
+    let sequence_number_applies = match delete.content {
+        FileContent::PositionDeletes => delete.sequence_number >= data.sequence_number,
+        FileContent::EqualityDeletes => delete.sequence_number > data.sequence_number,
+        FileContent::Data => false,
+    };
+    let partition_applies = delete.partition.is_empty()
+        || (delete.partition_spec_id == data.partition_spec_id
+            && delete.partition == data.partition);
+    // Positional delete files that only delete rows of a single data file have equal bounds of their file paths.
+    let file_path_applies = delete.content != FileContent::PositionDeletes
+        || match (
+            delete.lower_bounds.get(&DELETE_FILE_PATH_FIELD_ID),
+            delete.upper_bounds.get(&DELETE_FILE_PATH_FIELD_ID),
+        ) {
+            (Some(lower), Some(upper)) if lower == upper => {
+                lower.as_slice() == data.file_path.as_bytes()
+            }
+            _ => true,
+        };
+    sequence_number_applies && partition_applies && file_path_applies
+}
+
+impl ScanOperator for IcebergScanOperator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &self.partitioning_keys
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+    fn can_absorb_select(&self) -> bool {
+        true
+    }
+    fn can_absorb_limit(&self) -> bool {
+        false
+    }
+    fn can_provide_metadata(&self) -> bool {
+        // Row counts and column statistics come from the table's manifests.
+        true
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("IcebergScanOperator({})", self.metadata.location),
+            format!("Metadata location = {}", self.metadata_location),
+        ];
+        if let Some(snapshot_id) = self.snapshot_id {
+            lines.push(format!("Snapshot ID = {snapshot_id}"));
+        }
+        lines.push(format!("Schema = {}", self.schema.short_string()));
+        if !self.partitioning_keys.is_empty() {
+            lines.push(format!(
+                "Partitioning keys = [{}]",
+                self.partitioning_keys
+                    .iter()
+                    .map(|pf| pf.to_string())
+                    .join(", ")
+            ));
+        }
+        lines.extend(self.storage_config.multiline_display());
+
+        lines
+    }
+
+    fn to_scan_tasks(
+        &self,
+        pushdowns: Pushdowns,
+    ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>> + 'static>> {
+        let Some(snapshot) = self.metadata.snapshot(self.snapshot_id)? else {
+            return Ok(Box::new(std::iter::empty()));
+        };
+        let (io_runtime, io_client) = self.storage_config.get_io_client_and_runtime()?;
+        let io_stats = IOStatsContext::new(format!(
+            "IcebergScanOperator::to_scan_tasks for {}",
+            self.metadata_location
+        ));
+
+        let manifests = match (&snapshot.manifest_list, &snapshot.manifests) {
+            (Some(manifest_list), _) => io_runtime.block_on_current_thread(read_manifest_list(
+                manifest_list,
+                io_client.clone(),
+                Some(io_stats.clone()),
+            ))?,
+            (None, Some(manifests)) => manifests
+                .iter()
+                .map(|path| ManifestFile::from_path(path.clone(), self.metadata.default_spec_id()))
+                .collect(),
+            (None, None) => vec![],
+        };
+
+        // Skip the data manifests whose partitions can't satisfy the partition filters.
+        let default_partition_types = self.partition_types(self.metadata.default_spec_id());
+        let mut pruned_manifests = Vec::with_capacity(manifests.len());
+        for manifest in manifests {
+            if manifest.is_deletes
+                || self.manifest_may_match(&manifest, &default_partition_types, &pushdowns)?
+            {
+                pruned_manifests.push(manifest);
+            }
+        }
+        let files = io_runtime.block_on_current_thread(
+            futures::stream::iter(
+                pruned_manifests
+                    .iter()
+                    .map(|m| read_manifest(m, io_client.clone(), Some(io_stats.clone()))),
+            )
+            .buffered(MANIFEST_READ_PARALLELISM)
+            .try_collect::<Vec<_>>(),
+        )?;
+        let (data_files, delete_files): (Vec<_>, Vec<_>) = files
+            .into_iter()
+            .flatten()
+            .partition(|f| f.content == FileContent::Data);
+        if let Some(f) = delete_files.iter().find(|f| f.file_format != "PARQUET") {
+            return Err(DaftError::ValueError(format!(
+                "Iceberg delete files of format {} are not supported",
+                f.file_format
+            )));
+        }
+
+        let avro_config = Arc::new(FileFormatConfig::Avro(AvroSourceConfig::new_internal()));
+        let mut partition_types =
+            HashMap::from([(self.metadata.default_spec_id(), default_partition_types)]);
+        let limit_files = pushdowns.filters.is_none() && pushdowns.partition_filters.is_none();
+        let mut rows_left = pushdowns.limit.unwrap_or(0) as i64;
+        let mut scan_tasks = vec![];
+        for file in &data_files {
+            if limit_files && pushdowns.limit.is_some() && rows_left <= 0 {
+                break;
+            }
+
+            let partition_types = partition_types
+                .entry(file.partition_spec_id)
+                .or_insert_with(|| self.partition_types(file.partition_spec_id));
+            let partition_spec = partition_spec(partition_types, &file.partition)?;
+            // Partition filters refer to the fields of the default spec, so only files of that spec are pruned.
+            if file.partition_spec_id == self.metadata.default_spec_id()
+                && let Some(partition_filters) = &pushdowns.partition_filters
+                && let Some(partition_spec) = &partition_spec
+                && !partition_spec.satisfies(partition_filters)?
+            {
+                continue;
+            }
+            let statistics = self.file_statistics(file)?;
+            if let Some(filters) = &pushdowns.filters
+                && !may_match(&statistics, filters)
+            {
+                continue;
+            }
+
+            let applicable_deletes = delete_files
+                .iter()
+                .filter(|d| delete_applies(d, file))
+                .collect::<Vec<_>>();
+            let positional_deletes = applicable_deletes
+                .iter()
+                .filter(|d| d.content == FileContent::PositionDeletes)
+                .map(|d| d.file_path.clone())
+                .collect::<Vec<_>>();
+            let equality_deletes = applicable_deletes
+                .iter()
+                .filter(|d| d.content == FileContent::EqualityDeletes)
+                .map(|d| self.equality_delete_file(d))
+                .collect::<DaftResult<Vec<_>>>()?;
+            let has_deletes = !applicable_deletes.is_empty();
+
+            let file_format_config = match file.file_format.as_str() {
+                "PARQUET" => self.parquet_config.clone(),
+                "AVRO" if !has_deletes => avro_config.clone(),
+                format => {
+                    return Err(DaftError::ValueError(format!(
+                        "Iceberg data files of format {format}{} are not supported",
+                        if has_deletes {
+                            " with delete files"
+                        } else {
+                            ""
+                        }
+                    )))
+                }
+            };
+
+            // Row counts of files with deletes don't account for their deleted rows.
+            if !has_deletes {
+                rows_left -= file.record_count;
+            }
+            scan_tasks.push(Ok(ScanTask::new(
+                vec![DataSource::File {
+                    path: file.file_path.clone(),
+                    chunk_spec: None,
+                    size_bytes: Some(file.file_size_in_bytes as u64),
+                    iceberg_delete_files: (!positional_deletes.is_empty())
+                        .then_some(positional_deletes),
+                    iceberg_equality_delete_files: (!equality_deletes.is_empty())
+                        .then_some(equality_deletes),
+                    delta_deletion_vector: None,
+                    metadata: (!has_deletes).then_some(ScanTaskMetadata {
+                        length: file.record_count as usize,
+                    }),
+                    partition_spec,
+                    statistics: Some(statistics),
+                    parquet_metadata: None,
+                    etag: None,
+                    last_modified: None,
+                }],
+                file_format_config,
+                self.schema.clone(),
+                self.storage_config.clone(),
+                pushdowns.clone(),
+            )
+            .into()));
+        }
+        Ok(Box::new(scan_tasks.into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use daft_avro::AvroValue;
+
+    use super::{
+        delete_applies, manifest::DataFile, manifest::FileContent, normalize_path,
+        DELETE_FILE_PATH_FIELD_ID,
+    };
+
+    fn file(content: FileContent, sequence_number: i64, partition: Vec<AvroValue>) -> DataFile {
+        DataFile {
+            content,
+            file_path: "s3://bucket/table/data/a.parquet".to_string(),
+            file_format: "PARQUET".to_string(),
+            partition_spec_id: 0,
+            partition,
+            record_count: 10,
+            file_size_in_bytes: 100,
+            sequence_number,
+            lower_bounds: HashMap::new(),
+            upper_bounds: HashMap::new(),
+            equality_ids: vec![],
+        }
+    }
+
+    #[test]
+    fn test_delete_applies() {
+        let data = file(FileContent::Data, 2, vec![AvroValue::Int(1)]);
+
+        let positional = file(FileContent::PositionDeletes, 2, vec![AvroValue::Int(1)]);
+        assert!(delete_applies(&positional, &data));
+        let equality = file(FileContent::EqualityDeletes, 2, vec![AvroValue::Int(1)]);
+        assert!(!delete_applies(&equality, &data));
+        let equality = file(FileContent::EqualityDeletes, 3, vec![AvroValue::Int(1)]);
+        assert!(delete_applies(&equality, &data));
+
+        // Deletes of other partitions don't apply, unless they're unpartitioned.
+        let other_partition = file(FileContent::PositionDeletes, 3, vec![AvroValue::Int(2)]);
+        assert!(!delete_applies(&other_partition, &data));
+        let unpartitioned = file(FileContent::EqualityDeletes, 3, vec![]);
+        assert!(delete_applies(&unpartitioned, &data));
+
+        // Positional deletes of a single other data file don't apply.
+        let mut other_file = file(FileContent::PositionDeletes, 3, vec![AvroValue::Int(1)]);
+        for bounds in [&mut other_file.lower_bounds, &mut other_file.upper_bounds] {
+            bounds.insert(
+                DELETE_FILE_PATH_FIELD_ID,
+                b"s3://bucket/table/data/b.parquet".to_vec(),
+            );
+        }
+        assert!(!delete_applies(&other_file, &data));
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path("file:/tmp/t"), "file:///tmp/t");
+        assert_eq!(normalize_path("file:///tmp/t"), "file:///tmp/t");
+        assert_eq!(normalize_path("s3://b/t"), "s3://b/t");
+    }
+}
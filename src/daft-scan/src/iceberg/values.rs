@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, PrimitiveArray, Utf8Array},
+    datatypes::DataType as ArrowType,
+};
+use common_error::{DaftError, DaftResult};
+use daft_avro::AvroValue;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+
+use super::metadata::PrimitiveType;
+
+/// A single non-null value of an Iceberg primitive type, such as a bound of a column or a partition value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Literal {
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    /// Unscaled value of a decimal.
+    Decimal(i128),
+    String(String),
+    Binary(Vec<u8>),
+}
+
+impl Literal {
+    /// Decodes a value from Iceberg's binary single-value serialization, which is used for the bounds of columns in
+    /// manifests. Returns `None` if the value isn't valid for `ty`.
+    ///
+    /// See: https://iceberg.apache.org/spec/#binary-single-value-serialization
+    pub fn from_bytes(ty: &PrimitiveType, bytes: &[u8]) -> Option<Self> {
+        Some(match ty {
+            PrimitiveType::Boolean => Self::Boolean(*bytes.first()? != 0),
+            PrimitiveType::Int | PrimitiveType::Date => {
+                Self::Int(i32::from_le_bytes(bytes.try_into().ok()?))
+            }
+            PrimitiveType::Long
+            | PrimitiveType::Time
+            | PrimitiveType::Timestamp
+            | PrimitiveType::Timestamptz
+            | PrimitiveType::TimestampNs
+            | PrimitiveType::TimestamptzNs => match bytes.len() {
+                // Columns that were promoted from int to long may have bounds that were written as ints.
+                4 => Self::Long(i32::from_le_bytes(bytes.try_into().ok()?) as i64),
+                _ => Self::Long(i64::from_le_bytes(bytes.try_into().ok()?)),
+            },
+            PrimitiveType::Float => Self::Float(f32::from_le_bytes(bytes.try_into().ok()?)),
+            PrimitiveType::Double => match bytes.len() {
+                4 => Self::Double(f32::from_le_bytes(bytes.try_into().ok()?) as f64),
+                _ => Self::Double(f64::from_le_bytes(bytes.try_into().ok()?)),
+            },
+            PrimitiveType::Decimal { .. } => {
+                if bytes.len() > 16 {
+                    return None;
+                }
+                // Unscaled values are big-endian two's-complement integers of the minimum number of bytes.
+                let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+                    0xff
+                } else {
+                    0
+                };
+                let mut buf = [fill; 16];
+                buf[16 - bytes.len()..].copy_from_slice(bytes);
+                Self::Decimal(i128::from_be_bytes(buf))
+            }
+            PrimitiveType::String => Self::String(String::from_utf8(bytes.to_vec()).ok()?),
+            PrimitiveType::Uuid | PrimitiveType::Fixed(_) | PrimitiveType::Binary => {
+                Self::Binary(bytes.to_vec())
+            }
+        })
+    }
+
+    /// Converts a partition value from a manifest, returning `None` for nulls.
+    pub fn from_avro(value: &AvroValue) -> Option<Self> {
+        Some(match value {
+            AvroValue::Boolean(v) => Self::Boolean(*v),
+            AvroValue::Int(v) => Self::Int(*v),
+            AvroValue::Long(v) => Self::Long(*v),
+            AvroValue::Float(v) => Self::Float(*v),
+            AvroValue::Double(v) => Self::Double(*v),
+            AvroValue::Decimal(v) => Self::Decimal(*v),
+            AvroValue::String(v) | AvroValue::Enum(v) => Self::String(v.clone()),
+            AvroValue::Bytes(v) | AvroValue::Fixed(v) => Self::Binary(v.clone()),
+            _ => return None,
+        })
+    }
+}
+
+/// Returns a series of a single value of type `ty`, which is null if `value` is `None`.
+pub(crate) fn literal_to_series(
+    name: &str,
+    ty: &PrimitiveType,
+    value: Option<&Literal>,
+) -> DaftResult<Series> {
+    let dtype = ty.to_daft_dtype();
+    let Some(value) = value else {
+        return Ok(Series::full_null(name, &dtype, 1));
+    };
+    let arrow_type = dtype.to_arrow()?;
+    let array: Box<dyn Array> = match (ty, value) {
+        (PrimitiveType::Boolean, Literal::Boolean(v)) => Box::new(BooleanArray::from_slice([*v])),
+        (PrimitiveType::Int | PrimitiveType::Date, Literal::Int(v)) => {
+            Box::new(PrimitiveArray::from_slice([*v]).to(arrow_type))
+        }
+        (
+            PrimitiveType::Long
+            | PrimitiveType::Time
+            | PrimitiveType::Timestamp
+            | PrimitiveType::Timestamptz
+            | PrimitiveType::TimestampNs
+            | PrimitiveType::TimestamptzNs,
+            Literal::Long(v),
+        ) => Box::new(PrimitiveArray::from_slice([*v]).to(arrow_type)),
+        (PrimitiveType::Long, Literal::Int(v)) => Box::new(PrimitiveArray::from_slice([*v as i64])),
+        (PrimitiveType::Float, Literal::Float(v)) => Box::new(PrimitiveArray::from_slice([*v])),
+        (PrimitiveType::Double, Literal::Double(v)) => Box::new(PrimitiveArray::from_slice([*v])),
+        (PrimitiveType::Double, Literal::Float(v)) => {
+            Box::new(PrimitiveArray::from_slice([*v as f64]))
+        }
+        (PrimitiveType::Decimal { .. }, Literal::Decimal(v)) => {
+            Box::new(PrimitiveArray::from_slice([*v]).to(arrow_type))
+        }
+        (PrimitiveType::String, Literal::String(v)) => {
+            Box::new(Utf8Array::<i64>::from_slice([v.as_str()]))
+        }
+        (PrimitiveType::Binary, Literal::Binary(v)) => {
+            Box::new(BinaryArray::<i64>::from_slice([v.as_slice()]))
+        }
+        (PrimitiveType::Uuid | PrimitiveType::Fixed(_), Literal::Binary(v))
+            if let ArrowType::FixedSizeBinary(size) = arrow_type
+                && v.len() == size =>
+        {
+            Box::new(FixedSizeBinaryArray::new(
+                arrow_type,
+                v.clone().into(),
+                None,
+            ))
+        }
+        _ => {
+            return Err(DaftError::TypeError(format!(
+                "Iceberg value {:?} is not of type {:?}",
+                value, ty
+            )))
+        }
+    };
+    Series::try_from_field_and_arrow_array(
+        Arc::new(Field::new(name, dtype)),
+        cast_array_for_daft_if_needed(array),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::{literal_to_series, Literal};
+    use crate::iceberg::metadata::PrimitiveType;
+
+    #[test]
+    fn test_decode_bounds() {
+        assert_eq!(
+            Literal::from_bytes(&PrimitiveType::Int, &[0x01, 0x01, 0, 0]),
+            Some(Literal::Int(257))
+        );
+        assert_eq!(
+            Literal::from_bytes(&PrimitiveType::Long, &[0xff, 0xff, 0xff, 0xff]),
+            Some(Literal::Long(-1))
+        );
+        assert_eq!(
+            Literal::from_bytes(
+                &PrimitiveType::Decimal {
+                    precision: 10,
+                    scale: 2
+                },
+                &[0xff, 0x00]
+            ),
+            Some(Literal::Decimal(-256))
+        );
+        assert_eq!(
+            Literal::from_bytes(&PrimitiveType::String, b"abc"),
+            Some(Literal::String("abc".to_string()))
+        );
+        assert_eq!(Literal::from_bytes(&PrimitiveType::Double, &[0; 3]), None);
+    }
+
+    #[test]
+    fn test_literal_to_series() {
+        let series = literal_to_series("d", &PrimitiveType::Date, Some(&Literal::Int(3))).unwrap();
+        assert_eq!(series.data_type(), &PrimitiveType::Date.to_daft_dtype());
+        assert_eq!(series.len(), 1);
+
+        let series = literal_to_series("s", &PrimitiveType::String, None).unwrap();
+        assert_eq!(series.data_type(), &DataType::Utf8);
+        assert!(series.validity().is_some_and(|v| v.unset_bits() == 1));
+
+        assert!(literal_to_series("i", &PrimitiveType::Int, Some(&Literal::Long(1))).is_err());
+    }
+}
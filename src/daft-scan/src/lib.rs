@@ -7,6 +7,7 @@ use std::{
     sync::Arc,
 };
 
+use chrono::{DateTime, Utc};
 use common_display::DisplayAs;
 use common_error::{DaftError, DaftResult};
 use daft_core::{
@@ -22,9 +23,16 @@ use serde::{Deserialize, Serialize};
 
 mod anonymous;
 pub use anonymous::AnonymousScanOperator;
+mod delta_lake;
 pub mod file_format;
+pub use delta_lake::{read_deletion_vector, DeletionVector, DeltaLakeScanOperator};
 mod glob;
+mod hive;
+mod iceberg;
+pub use iceberg::IcebergScanOperator;
+mod materialized;
 use common_daft_config::DaftExecutionConfig;
+pub use materialized::MaterializedScanOperator;
 pub mod scan_task_iters;
 
 #[cfg(feature = "python")]
@@ -99,10 +107,17 @@ impl From<Error> for pyo3::PyErr {
 }
 
 /// Specification of a subset of a file to be read.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ChunkSpec {
     /// Selection of Parquet row groups.
     Parquet(Vec<i64>),
+    /// Byte range `[start, end)` of a line-delimited file or Avro file, which is read as the records (or Avro blocks)
+    /// that begin within the range.
+    Bytes { start: usize, end: usize },
+    /// Selection of Arrow IPC record batches.
+    Ipc(Vec<usize>),
+    /// Selection of ORC stripes.
+    Orc(Vec<usize>),
 }
 
 impl ChunkSpec {
@@ -112,9 +127,51 @@ impl ChunkSpec {
             Self::Parquet(chunks) => {
                 res.push(format!("Chunks = {:?}", chunks));
             }
+            Self::Bytes { start, end } => {
+                res.push(format!("Byte range = {}..{}", start, end));
+            }
+            Self::Ipc(record_batches) => {
+                res.push(format!("Record batches = {:?}", record_batches));
+            }
+            Self::Orc(stripes) => {
+                res.push(format!("Stripes = {:?}", stripes));
+            }
         }
         res
     }
+
+    /// Returns the byte range of a [`ChunkSpec::Bytes`].
+    pub fn byte_range(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Bytes { start, end } => Some((*start, *end)),
+            _ => None,
+        }
+    }
+
+    /// Returns the record batches of a [`ChunkSpec::Ipc`].
+    pub fn record_batches(&self) -> Option<&[usize]> {
+        match self {
+            Self::Ipc(record_batches) => Some(record_batches),
+            _ => None,
+        }
+    }
+
+    /// Returns the stripes of a [`ChunkSpec::Orc`].
+    pub fn stripes(&self) -> Option<&[usize]> {
+        match self {
+            Self::Orc(stripes) => Some(stripes),
+            _ => None,
+        }
+    }
+}
+
+/// An Iceberg equality delete file, which deletes the rows of data files whose values in all of the equality columns
+/// are equal to those of one of its rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IcebergEqualityDeleteFile {
+    pub path: String,
+    /// Names of the equality columns in the schema of the table.
+    pub equality_columns: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -124,10 +181,15 @@ pub enum DataSource {
         chunk_spec: Option<ChunkSpec>,
         size_bytes: Option<u64>,
         iceberg_delete_files: Option<Vec<String>>,
+        iceberg_equality_delete_files: Option<Vec<IcebergEqualityDeleteFile>>,
+        delta_deletion_vector: Option<DeletionVector>,
         metadata: Option<TableMetadata>,
         partition_spec: Option<PartitionSpec>,
         statistics: Option<TableStatistics>,
         parquet_metadata: Option<Arc<FileMetaData>>,
+        /// ETag and last-modified time of the file when it was listed, which change whenever it's overwritten.
+        etag: Option<String>,
+        last_modified: Option<DateTime<Utc>>,
     },
     Database {
         path: String,
@@ -219,6 +281,37 @@ impl DataSource {
         }
     }
 
+    pub fn get_iceberg_equality_delete_files(&self) -> Option<&Vec<IcebergEqualityDeleteFile>> {
+        match self {
+            Self::File {
+                iceberg_equality_delete_files,
+                ..
+            } => iceberg_equality_delete_files.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn get_delta_deletion_vector(&self) -> Option<&DeletionVector> {
+        match self {
+            Self::File {
+                delta_deletion_vector,
+                ..
+            } => delta_deletion_vector.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether any rows of this source are deleted by Iceberg delete files or a Delta Lake deletion vector, in
+    /// which case its metadata and statistics don't account for the deleted rows.
+    pub fn has_deletes(&self) -> bool {
+        self.get_iceberg_delete_files()
+            .is_some_and(|f| !f.is_empty())
+            || self
+                .get_iceberg_equality_delete_files()
+                .is_some_and(|f| !f.is_empty())
+            || self.get_delta_deletion_vector().is_some()
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         match self {
@@ -227,10 +320,14 @@ impl DataSource {
                 chunk_spec,
                 size_bytes,
                 iceberg_delete_files,
+                iceberg_equality_delete_files,
+                delta_deletion_vector,
                 metadata,
                 partition_spec,
                 statistics,
                 parquet_metadata: _,
+                etag: _,
+                last_modified: _,
             } => {
                 res.push(format!("Path = {}", path));
                 if let Some(chunk_spec) = chunk_spec {
@@ -245,6 +342,21 @@ impl DataSource {
                 if let Some(iceberg_delete_files) = iceberg_delete_files {
                     res.push(format!("Iceberg delete files = {:?}", iceberg_delete_files));
                 }
+                if let Some(iceberg_equality_delete_files) = iceberg_equality_delete_files {
+                    res.push(format!(
+                        "Iceberg equality delete files = {:?}",
+                        iceberg_equality_delete_files
+                            .iter()
+                            .map(|f| &f.path)
+                            .collect::<Vec<_>>()
+                    ));
+                }
+                if let Some(delta_deletion_vector) = delta_deletion_vector {
+                    res.push(format!(
+                        "Delta deletion vector cardinality = {}",
+                        delta_deletion_vector.cardinality
+                    ));
+                }
                 if let Some(metadata) = metadata {
                     res.push(format!(
                         "Metadata = {}",
@@ -357,6 +469,11 @@ pub struct ScanTask {
     pub size_bytes_on_disk: Option<u64>,
     pub metadata: Option<TableMetadata>,
     pub statistics: Option<TableStatistics>,
+
+    /// Whether `schema` is the union by name of the schemas of the DataSources, in which case a DataSource may be
+    /// missing some of its columns. Such DataSources are read by column name and cast to `schema`, with missing
+    /// columns filled with nulls.
+    pub union_by_name: bool,
 }
 pub type ScanTaskRef = Arc<ScanTask>;
 
@@ -407,6 +524,14 @@ impl ScanTask {
             size_bytes_on_disk,
             metadata,
             statistics,
+            union_by_name: false,
+        }
+    }
+
+    pub fn with_union_by_name(self, union_by_name: bool) -> Self {
+        Self {
+            union_by_name,
+            ..self
         }
     }
 
@@ -451,7 +576,8 @@ impl ScanTask {
             sc1.schema.clone(),
             sc1.storage_config.clone(),
             sc1.pushdowns.clone(),
-        ))
+        )
+        .with_union_by_name(sc1.union_by_name || sc2.union_by_name))
     }
 
     pub fn materialized_schema(&self) -> SchemaRef {
@@ -469,6 +595,23 @@ impl ScanTask {
         }
     }
 
+    /// Obtain the schema of the data stored in the files of the ScanTask, which excludes the partition columns whose
+    /// values are filled in from the [`PartitionSpec`] rather than read from the files.
+    pub fn file_schema(&self) -> SchemaRef {
+        match self.partition_spec() {
+            None => self.schema.clone(),
+            Some(partition_spec) => Arc::new(Schema {
+                fields: self
+                    .schema
+                    .fields
+                    .clone()
+                    .into_iter()
+                    .filter(|(name, _)| !partition_spec.keys.schema.fields.contains_key(name))
+                    .collect(),
+            }),
+        }
+    }
+
     /// Obtain an accurate, exact num_rows from the ScanTask, or `None` if this is not possible
     pub fn num_rows(&self) -> Option<usize> {
         if self.pushdowns.filters.is_some() {
@@ -504,6 +647,14 @@ impl ScanTask {
                         .map_or_else(|| Cow::Owned(DaftExecutionConfig::default()), Cow::Borrowed);
                     let inflation_factor = match self.file_format_config.as_ref() {
                         FileFormatConfig::Parquet(_) => config.parquet_inflation_factor,
+                        FileFormatConfig::Ipc(_) => 1.0,
+                        FileFormatConfig::Avro(_) => config.parquet_inflation_factor,
+                        FileFormatConfig::Orc(_) => config.parquet_inflation_factor,
+                        FileFormatConfig::Xlsx(_) => config.parquet_inflation_factor,
+                        FileFormatConfig::Text(_) | FileFormatConfig::FixedWidth(_) => {
+                            config.csv_inflation_factor
+                        }
+                        FileFormatConfig::Files(_) => 1.0,
                         FileFormatConfig::Csv(_) | FileFormatConfig::Json(_) => {
                             config.csv_inflation_factor
                         }
@@ -663,9 +814,9 @@ Pushdowns = {pushdowns}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct PartitionField {
-    field: Field,
-    source_field: Option<Field>,
-    transform: Option<PartitionTransform>,
+    pub field: Field,
+    pub source_field: Option<Field>,
+    pub transform: Option<PartitionTransform>,
 }
 
 impl PartitionField {
@@ -757,6 +908,8 @@ pub trait ScanOperator: Send + Sync + Debug {
     fn can_absorb_filter(&self) -> bool;
     fn can_absorb_select(&self) -> bool;
     fn can_absorb_limit(&self) -> bool;
+    /// Whether this operator's ScanTasks may carry row counts or column statistics in their metadata.
+    fn can_provide_metadata(&self) -> bool;
     fn multiline_display(&self) -> Vec<String>;
     fn to_scan_tasks(
         &self,
@@ -978,10 +1131,14 @@ mod test {
                 chunk_spec: None,
                 size_bytes: None,
                 iceberg_delete_files: None,
+                iceberg_equality_delete_files: None,
+                delta_deletion_vector: None,
                 metadata: None,
                 partition_spec: None,
                 statistics: None,
                 parquet_metadata: None,
+                etag: None,
+                last_modified: None,
             })
             .collect_vec();
 
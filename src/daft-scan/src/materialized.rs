@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::schema::SchemaRef;
+
+use crate::{PartitionField, Pushdowns, ScanOperator, ScanTask, ScanTaskRef};
+
+/// A ScanOperator over a fixed set of ScanTasks that have already been materialized, e.g. by an optimizer rule
+/// that answered part of a query from the ScanTasks' metadata and only needs to scan the rest.
+#[derive(Debug)]
+pub struct MaterializedScanOperator {
+    scan_tasks: Vec<ScanTaskRef>,
+    schema: SchemaRef,
+    partitioning_keys: Vec<PartitionField>,
+}
+
+impl MaterializedScanOperator {
+    pub fn new(
+        scan_tasks: Vec<ScanTaskRef>,
+        schema: SchemaRef,
+        partitioning_keys: Vec<PartitionField>,
+    ) -> Self {
+        Self {
+            scan_tasks,
+            schema,
+            partitioning_keys,
+        }
+    }
+
+    pub fn scan_tasks(&self) -> &[ScanTaskRef] {
+        &self.scan_tasks
+    }
+}
+
+impl ScanOperator for MaterializedScanOperator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn partitioning_keys(&self) -> &[PartitionField] {
+        &self.partitioning_keys
+    }
+
+    fn can_absorb_filter(&self) -> bool {
+        false
+    }
+    fn can_absorb_select(&self) -> bool {
+        false
+    }
+    fn can_absorb_limit(&self) -> bool {
+        false
+    }
+    fn can_provide_metadata(&self) -> bool {
+        self.scan_tasks
+            .iter()
+            .any(|scan_task| scan_task.num_rows().is_some())
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "MaterializedScanOperator".to_string(),
+            format!("Num scan tasks = {}", self.scan_tasks.len()),
+        ]
+    }
+
+    fn to_scan_tasks(
+        &self,
+        pushdowns: Pushdowns,
+    ) -> DaftResult<Box<dyn Iterator<Item = DaftResult<ScanTaskRef>>>> {
+        let scan_tasks = self.scan_tasks.clone();
+        Ok(Box::new(scan_tasks.into_iter().map(move |scan_task| {
+            if scan_task.pushdowns == pushdowns {
+                Ok(scan_task)
+            } else {
+                Ok(Arc::new(ScanTask::new(
+                    scan_task.sources.clone(),
+                    scan_task.file_format_config.clone(),
+                    scan_task.schema.clone(),
+                    scan_task.storage_config.clone(),
+                    pushdowns.clone(),
+                )))
+            }
+        })))
+    }
+}
@@ -74,6 +74,7 @@ pub mod pylib {
     use crate::file_format::PyFileFormatConfig;
     use crate::glob::GlobScanOperator;
     use crate::storage_config::PyStorageConfig;
+    use crate::{delta_lake::DeltaLakeScanOperator, iceberg::IcebergScanOperator};
     use common_daft_config::PyDaftExecutionConfig;
 
     use super::PythonTablesFactoryArgs;
@@ -112,6 +113,8 @@ pub mod pylib {
         }
 
         #[staticmethod]
+        #[pyo3(signature = (glob_path, file_format_config, storage_config, infer_schema, schema=None, hive_partitioning=false, union_by_name=false, max_schema_inference_files=None))]
+        #[allow(clippy::too_many_arguments)]
         pub fn glob_scan(
             py: Python,
             glob_path: Vec<&str>,
@@ -119,6 +122,9 @@ pub mod pylib {
             storage_config: PyStorageConfig,
             infer_schema: bool,
             schema: Option<PySchema>,
+            hive_partitioning: bool,
+            union_by_name: bool,
+            max_schema_inference_files: Option<usize>,
         ) -> PyResult<Self> {
             py.allow_threads(|| {
                 let operator = Arc::new(GlobScanOperator::try_new(
@@ -127,6 +133,51 @@ pub mod pylib {
                     storage_config.into(),
                     infer_schema,
                     schema.map(|s| s.schema),
+                    hive_partitioning,
+                    union_by_name,
+                    max_schema_inference_files,
+                )?);
+                Ok(ScanOperatorHandle {
+                    scan_op: ScanOperatorRef(operator),
+                })
+            })
+        }
+
+        #[staticmethod]
+        #[pyo3(signature = (table_location, storage_config, snapshot_id=None))]
+        pub fn iceberg_scan(
+            py: Python,
+            table_location: &str,
+            storage_config: PyStorageConfig,
+            snapshot_id: Option<i64>,
+        ) -> PyResult<Self> {
+            py.allow_threads(|| {
+                let operator = Arc::new(IcebergScanOperator::try_new(
+                    table_location,
+                    snapshot_id,
+                    storage_config.into(),
+                )?);
+                Ok(ScanOperatorHandle {
+                    scan_op: ScanOperatorRef(operator),
+                })
+            })
+        }
+
+        #[staticmethod]
+        #[pyo3(signature = (table_uri, storage_config, version=None, timestamp_ms=None))]
+        pub fn deltalake_scan(
+            py: Python,
+            table_uri: &str,
+            storage_config: PyStorageConfig,
+            version: Option<i64>,
+            timestamp_ms: Option<i64>,
+        ) -> PyResult<Self> {
+            py.allow_threads(|| {
+                let operator = Arc::new(DeltaLakeScanOperator::try_new(
+                    table_uri,
+                    version,
+                    timestamp_ms,
+                    storage_config.into(),
                 )?);
                 Ok(ScanOperatorHandle {
                     scan_op: ScanOperatorRef(operator),
@@ -232,6 +283,11 @@ pub mod pylib {
         fn can_absorb_select(&self) -> bool {
             self.can_absorb_select
         }
+        fn can_provide_metadata(&self) -> bool {
+            // Python scan operators don't declare whether their ScanTasks carry metadata, so don't list them at
+            // optimization time on the chance that they do.
+            false
+        }
 
         fn multiline_display(&self) -> Vec<String> {
             let lines = vec![format!("PythonScanOperator: {}", self.display_name)];
@@ -313,30 +369,21 @@ pub mod pylib {
             partition_values: Option<PyTable>,
             stats: Option<PyTable>,
         ) -> PyResult<Option<Self>> {
-            if let Some(ref pvalues) = partition_values
+            let has_partition_values = partition_values.is_some();
+            let pspec = PartitionSpec {
+                keys: partition_values
+                    .map(|p| p.table)
+                    .unwrap_or_else(|| Table::empty(None).unwrap()),
+            };
+            if has_partition_values
                 && let Some(Some(ref partition_filters)) =
                     pushdowns.as_ref().map(|p| &p.0.partition_filters)
+                && !pspec.satisfies(partition_filters)?
             {
-                let table = &pvalues.table;
-                let eval_pred = table.eval_expression_list(&[partition_filters.clone()])?;
-                assert_eq!(eval_pred.num_columns(), 1);
-                let series = eval_pred.get_column_by_index(0)?;
-                assert_eq!(series.data_type(), &daft_core::datatypes::DataType::Boolean);
-                let boolean = series.bool()?;
-                assert_eq!(boolean.len(), 1);
-                let value = boolean.get(0);
-                match value {
-                    None | Some(false) => return Ok(None),
-                    Some(true) => {}
-                }
+                return Ok(None);
             }
             // TODO(Clark): Filter out scan tasks with pushed down filters + table stats?
 
-            let pspec = PartitionSpec {
-                keys: partition_values
-                    .map(|p| p.table)
-                    .unwrap_or_else(|| Table::empty(None).unwrap()),
-            };
             let statistics = stats
                 .map(|s| TableStatistics::from_stats_table(&s.table))
                 .transpose()?;
@@ -348,10 +395,14 @@ pub mod pylib {
                 chunk_spec: None,
                 size_bytes,
                 iceberg_delete_files,
+                iceberg_equality_delete_files: None,
+                delta_deletion_vector: None,
                 metadata,
                 partition_spec: Some(pspec),
                 statistics,
                 parquet_metadata: None,
+                etag: None,
+                last_modified: None,
             };
 
             let scan_task = ScanTask::new(
@@ -1,13 +1,25 @@
 use std::sync::Arc;
 
+use arrow2::io::parquet::read::schema::infer_schema_with_options;
 use common_daft_config::DaftExecutionConfig;
 use common_error::DaftResult;
-use daft_io::IOStatsContext;
-use daft_parquet::read::read_parquet_metadata;
+use daft_compression::CompressionCodec;
+use daft_core::schema::Schema;
+use daft_csv::{sample_has_multiline_records, CsvParseOptions};
+use daft_io::{get_runtime, IOStatsContext};
+use daft_ipc::read_ipc_metadata;
+use daft_orc::{read_orc_metadata, stripe_statistics_to_table_stats};
+use daft_parquet::{
+    read::{read_parquet_metadata, ParquetSchemaInferenceOptions},
+    row_group_metadata_to_table_stats,
+};
+use daft_stats::TableMetadata;
+use futures::{StreamExt, TryStreamExt};
 use parquet2::metadata::RowGroupList;
 
 use crate::{
     file_format::{FileFormatConfig, ParquetSourceConfig},
+    iceberg::may_match,
     storage_config::StorageConfig,
     ChunkSpec, DataSource, Pushdowns, ScanTask, ScanTaskRef,
 };
@@ -113,6 +125,13 @@ impl<'a> MergeByFileSize<'a> {
             && other.storage_config == accumulator.storage_config
             && other.pushdowns == accumulator.pushdowns;
 
+        // Byte ranges of a file are read with per-task read options, so they can't be merged with other ScanTasks
+        let has_byte_ranges = [other, accumulator.as_ref()].iter().any(|t| {
+            t.sources
+                .iter()
+                .any(|s| matches!(s.get_chunk_spec(), Some(ChunkSpec::Bytes { .. })))
+        });
+
         // Merge only if the resultant accumulator is smaller than the targeted upper bound
         let sum_smaller_than_max_size_bytes = if let Some(child_bytes) =
             other.estimate_in_memory_size_bytes(Some(self.cfg))
@@ -124,7 +143,7 @@ impl<'a> MergeByFileSize<'a> {
             false
         };
 
-        child_matches_accumulator && sum_smaller_than_max_size_bytes
+        child_matches_accumulator && sum_smaller_than_max_size_bytes && !has_byte_ranges
     }
 }
 
@@ -217,9 +236,7 @@ pub fn split_by_row_groups(
                     ) && source
                         .get_size_bytes()
                         .map_or(true, |s| s > max_size_bytes as u64)
-                      && source
-                        .get_iceberg_delete_files()
-                        .map_or(true, |f| f.is_empty())
+                      && !source.has_deletes()
                     {
                         let (io_runtime, io_client) =
                             t.storage_config.get_io_client_and_runtime()?;
@@ -306,3 +323,600 @@ pub fn split_by_row_groups(
         )
     }
 }
+
+/// Number of bytes at the start of a CSV file that are checked for records spanning multiple lines before splitting it
+/// into byte ranges, which matches the amount read for schema inference.
+const CSV_SPLIT_SAMPLE_BYTES: usize = 1024 * 1024;
+
+/// Returns whether `scan_task` reads a CSV file that can't be split into byte ranges, because records at its start
+/// span multiple lines. See [`daft_csv::sample_has_multiline_records`].
+fn has_multiline_csv_records(
+    scan_task: &ScanTask,
+    path: &str,
+    size_bytes: usize,
+) -> DaftResult<bool> {
+    let FileFormatConfig::Csv(cfg) = scan_task.file_format_config.as_ref() else {
+        return Ok(false);
+    };
+    let parse_options = CsvParseOptions::new_with_defaults(
+        cfg.has_headers,
+        cfg.delimiter,
+        cfg.double_quote,
+        cfg.quote,
+        cfg.allow_variable_columns,
+        cfg.escape_char,
+        cfg.comment,
+    )?;
+    let (runtime, io_client) = scan_task.storage_config.get_io_client_and_runtime()?;
+    let io_stats = IOStatsContext::new(format!("sample_has_multiline_records for {:#?}", path));
+    runtime.block_on_current_thread(sample_has_multiline_records(
+        path,
+        CSV_SPLIT_SAMPLE_BYTES.min(size_bytes),
+        &parse_options,
+        io_client,
+        Some(io_stats),
+    ))
+}
+
+/// Splits ScanTasks over large uncompressed CSV, JSON, text, fixed-width text and Avro files into ScanTasks over byte
+/// ranges of at most `max_size_bytes` of the file, which can be read in parallel.
+pub fn split_by_byte_ranges(scan_tasks: BoxScanTaskIter, max_size_bytes: usize) -> BoxScanTaskIter {
+    Box::new(
+        scan_tasks
+            .map(move |t| -> DaftResult<BoxScanTaskIter> {
+                let t = t?;
+
+                /* Only split CSV, JSON, text, fixed-width text and Avro tasks if they:
+                    - have one source
+                    - use native storage config
+                    - have no specified chunk spec or number of rows
+                    - have a known size past split threshold
+                    - are not compressed
+                    - are not CSV files with records spanning multiple lines
+                */
+                if let (
+                    FileFormatConfig::Csv(_)
+                    | FileFormatConfig::Json(_)
+                    | FileFormatConfig::Text(_)
+                    | FileFormatConfig::FixedWidth(_)
+                    | FileFormatConfig::Avro(_),
+                    StorageConfig::Native(_),
+                    [source],
+                    Some(None),
+                    None,
+                    Some(size_bytes),
+                ) = (
+                    t.file_format_config.as_ref(),
+                    t.storage_config.as_ref(),
+                    &t.sources[..],
+                    t.sources.first().map(DataSource::get_chunk_spec),
+                    t.pushdowns.limit,
+                    t.sources.first().and_then(DataSource::get_size_bytes),
+                ) && size_bytes > max_size_bytes as u64
+                    && CompressionCodec::from_uri(source.get_path()).is_none()
+                    && !has_multiline_csv_records(&t, source.get_path(), size_bytes as usize)?
+                {
+                    let size_bytes = size_bytes as usize;
+                    // Split into evenly sized ranges so that the last range isn't much smaller than the others.
+                    let num_ranges = size_bytes.div_ceil(max_size_bytes.max(1));
+                    let range_size = size_bytes.div_ceil(num_ranges);
+                    let new_tasks = (0..size_bytes)
+                        .step_by(range_size)
+                        .map(|start| {
+                            let end = (start + range_size).min(size_bytes);
+                            let mut new_source = source.clone();
+                            if let DataSource::File {
+                                chunk_spec,
+                                size_bytes,
+                                metadata,
+                                ..
+                            } = &mut new_source
+                            {
+                                *chunk_spec = Some(ChunkSpec::Bytes { start, end });
+                                *size_bytes = Some((end - start) as u64);
+                                // The number of rows in a byte range isn't known.
+                                *metadata = None;
+                            } else {
+                                unreachable!(
+                                    "CSV, JSON, text, fixed-width text and Avro file formats should only be used with DataSource::File"
+                                );
+                            }
+                            Ok(ScanTask::new(
+                                vec![new_source],
+                                t.file_format_config.clone(),
+                                t.schema.clone(),
+                                t.storage_config.clone(),
+                                t.pushdowns.clone(),
+                            )
+                            .into())
+                        })
+                        .collect::<Vec<_>>();
+                    Ok(Box::new(new_tasks.into_iter()))
+                } else {
+                    Ok(Box::new(std::iter::once(Ok(t))))
+                }
+            })
+            .flat_map(|t| t.unwrap_or_else(|e| Box::new(std::iter::once(Err(e))))),
+    )
+}
+
+/// Splits ScanTasks over large Arrow IPC files into ScanTasks over groups of the file's record batches, with each
+/// group holding at least `min_size_bytes` of record batches where possible.
+pub fn split_by_record_batches(
+    scan_tasks: BoxScanTaskIter,
+    min_size_bytes: usize,
+    max_size_bytes: usize,
+) -> BoxScanTaskIter {
+    Box::new(
+        scan_tasks
+            .map(move |t| -> DaftResult<BoxScanTaskIter> {
+                let t = t?;
+
+                /* Only split Arrow IPC tasks if they:
+                    - have one source
+                    - use native storage config
+                    - have no specified chunk spec or number of rows
+                    - have size past split threshold
+                */
+                if let (
+                    FileFormatConfig::Ipc(_),
+                    StorageConfig::Native(_),
+                    [source],
+                    Some(None),
+                    None,
+                ) = (
+                    t.file_format_config.as_ref(),
+                    t.storage_config.as_ref(),
+                    &t.sources[..],
+                    t.sources.first().map(DataSource::get_chunk_spec),
+                    t.pushdowns.limit,
+                ) && source
+                    .get_size_bytes()
+                    .map_or(true, |s| s > max_size_bytes as u64)
+                {
+                    let (io_runtime, io_client) = t.storage_config.get_io_client_and_runtime()?;
+
+                    let path = source.get_path();
+
+                    let io_stats =
+                        IOStatsContext::new(format!("split_by_record_batches for {:#?}", path));
+
+                    let file = io_runtime.block_on_current_thread(read_ipc_metadata(
+                        path,
+                        io_client,
+                        Some(io_stats),
+                    ))?;
+
+                    let mut new_tasks: Vec<DaftResult<ScanTaskRef>> = Vec::new();
+                    let mut curr_record_batches = Vec::new();
+                    let mut curr_size_bytes = 0;
+
+                    let num_record_batches = file.blocks.len();
+                    for (i, block) in file.blocks.iter().enumerate() {
+                        curr_record_batches.push(i);
+                        curr_size_bytes += block.meta_data_length.max(0) as usize
+                            + block.body_length.max(0) as usize;
+
+                        if curr_size_bytes >= min_size_bytes || i == num_record_batches - 1 {
+                            let mut new_source = source.clone();
+                            if let DataSource::File {
+                                chunk_spec,
+                                size_bytes,
+                                metadata,
+                                ..
+                            } = &mut new_source
+                            {
+                                *chunk_spec = Some(ChunkSpec::Ipc(std::mem::take(
+                                    &mut curr_record_batches,
+                                )));
+                                *size_bytes = Some(curr_size_bytes as u64);
+                                // The footer doesn't record the number of rows in each record batch.
+                                *metadata = None;
+                            } else {
+                                unreachable!(
+                                    "Arrow IPC file format should only be used with DataSource::File"
+                                );
+                            }
+                            curr_size_bytes = 0;
+
+                            new_tasks.push(Ok(ScanTask::new(
+                                vec![new_source],
+                                t.file_format_config.clone(),
+                                t.schema.clone(),
+                                t.storage_config.clone(),
+                                t.pushdowns.clone(),
+                            )
+                            .into()));
+                        }
+                    }
+
+                    if new_tasks.is_empty() {
+                        // Files without record batches are read as a single empty ScanTask.
+                        Ok(Box::new(std::iter::once(Ok(t))))
+                    } else {
+                        Ok(Box::new(new_tasks.into_iter()))
+                    }
+                } else {
+                    Ok(Box::new(std::iter::once(Ok(t))))
+                }
+            })
+            .flat_map(|t| t.unwrap_or_else(|e| Box::new(std::iter::once(Err(e))))),
+    )
+}
+
+/// Splits ScanTasks over large ORC files into ScanTasks over groups of the file's stripes, with each group holding at
+/// least `min_size_bytes` of stripes where possible. Stripes whose statistics show that none of their rows can satisfy
+/// the filters of the ScanTask are skipped, and the statistics of the remaining stripes are attached to their tasks.
+pub fn split_by_stripes(
+    scan_tasks: BoxScanTaskIter,
+    min_size_bytes: usize,
+    max_size_bytes: usize,
+) -> BoxScanTaskIter {
+    Box::new(
+        scan_tasks
+            .map(move |t| -> DaftResult<BoxScanTaskIter> {
+                let t = t?;
+
+                /* Only split ORC tasks if they:
+                    - have one source
+                    - use native storage config
+                    - have no specified chunk spec or number of rows
+                    - have size past split threshold
+                */
+                if let (
+                    FileFormatConfig::Orc(_),
+                    StorageConfig::Native(_),
+                    [source],
+                    Some(None),
+                    None,
+                ) = (
+                    t.file_format_config.as_ref(),
+                    t.storage_config.as_ref(),
+                    &t.sources[..],
+                    t.sources.first().map(DataSource::get_chunk_spec),
+                    t.pushdowns.limit,
+                ) && source
+                    .get_size_bytes()
+                    .map_or(true, |s| s > max_size_bytes as u64)
+                {
+                    let (io_runtime, io_client) = t.storage_config.get_io_client_and_runtime()?;
+
+                    let path = source.get_path();
+
+                    let io_stats = IOStatsContext::new(format!("split_by_stripes for {:#?}", path));
+
+                    let file = io_runtime.block_on_current_thread(read_orc_metadata(
+                        path,
+                        io_client,
+                        Some(io_stats),
+                    ))?;
+
+                    let mut new_tasks: Vec<DaftResult<ScanTaskRef>> = Vec::new();
+                    let mut curr_stripes = Vec::new();
+                    let mut curr_statistics = Vec::new();
+                    let mut curr_size_bytes = 0;
+                    let mut curr_num_rows = 0;
+
+                    let num_stripes = file.num_stripes();
+                    for i in 0..num_stripes {
+                        // Statistics that can't be cast to the schema of the task, e.g. because it was given by the
+                        // user, are dropped.
+                        let statistics = stripe_statistics_to_table_stats(&file, i)?
+                            .cast_to_schema(t.schema.clone())
+                            .ok();
+                        let is_pruned = matches!(
+                            (&statistics, &t.pushdowns.filters),
+                            (Some(statistics), Some(filters)) if !may_match(statistics, filters)
+                        );
+                        if !is_pruned {
+                            curr_stripes.push(i);
+                            curr_statistics.push(statistics);
+                            curr_size_bytes += file.stripe_size(i);
+                            curr_num_rows += file.stripe_num_rows(i);
+                        }
+
+                        if !curr_stripes.is_empty()
+                            && (curr_size_bytes >= min_size_bytes || i == num_stripes - 1)
+                        {
+                            let mut new_source = source.clone();
+                            if let DataSource::File {
+                                chunk_spec,
+                                size_bytes,
+                                metadata,
+                                statistics,
+                                ..
+                            } = &mut new_source
+                            {
+                                *chunk_spec =
+                                    Some(ChunkSpec::Orc(std::mem::take(&mut curr_stripes)));
+                                *size_bytes = Some(curr_size_bytes as u64);
+                                *metadata = Some(TableMetadata {
+                                    length: curr_num_rows,
+                                });
+                                *statistics = std::mem::take(&mut curr_statistics)
+                                    .into_iter()
+                                    .reduce(|acc, stats| match (acc, stats) {
+                                        (Some(acc), Some(stats)) => acc.union(&stats).ok(),
+                                        _ => None,
+                                    })
+                                    .flatten();
+                            } else {
+                                unreachable!(
+                                    "ORC file format should only be used with DataSource::File"
+                                );
+                            }
+                            curr_size_bytes = 0;
+                            curr_num_rows = 0;
+
+                            new_tasks.push(Ok(ScanTask::new(
+                                vec![new_source],
+                                t.file_format_config.clone(),
+                                t.schema.clone(),
+                                t.storage_config.clone(),
+                                t.pushdowns.clone(),
+                            )
+                            .into()));
+                        }
+                    }
+
+                    if num_stripes == 0 {
+                        // Files without stripes are read as a single empty ScanTask.
+                        Ok(Box::new(std::iter::once(Ok(t))))
+                    } else {
+                        Ok(Box::new(new_tasks.into_iter()))
+                    }
+                } else {
+                    Ok(Box::new(std::iter::once(Ok(t))))
+                }
+            })
+            .flat_map(|t| t.unwrap_or_else(|e| Box::new(std::iter::once(Err(e))))),
+    )
+}
+
+/// Fills in the row counts and column statistics of ScanTasks over whole Parquet and ORC files from the files' footers,
+/// reading up to `num_parallel_tasks` footers at a time.
+///
+/// ScanTasks are listed without reading any file, so their metadata is otherwise only known once they've been split by
+/// row groups or stripes. ScanTasks that already have metadata, or that read only part of a file, are left as they are.
+pub fn read_footer_metadata(
+    scan_tasks: Vec<ScanTaskRef>,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<ScanTaskRef>> {
+    let runtime = get_runtime(true)?;
+    runtime.block_on_current_thread(
+        futures::stream::iter(scan_tasks.into_iter().map(read_task_footer_metadata))
+            .buffered(num_parallel_tasks)
+            .try_collect(),
+    )
+}
+
+async fn read_task_footer_metadata(t: ScanTaskRef) -> DaftResult<ScanTaskRef> {
+    let (
+        StorageConfig::Native(_),
+        [source @ DataSource::File {
+            chunk_spec: None,
+            metadata: None,
+            ..
+        }],
+    ) = (t.storage_config.as_ref(), &t.sources[..])
+    else {
+        return Ok(t);
+    };
+    if source.has_deletes() {
+        return Ok(t);
+    }
+    let (_, io_client) = t.storage_config.get_io_client_and_runtime()?;
+    let path = source.get_path();
+    let io_stats = IOStatsContext::new(format!("read_footer_metadata for {:#?}", path));
+
+    let mut new_source = source.clone();
+    let DataSource::File {
+        metadata,
+        statistics,
+        parquet_metadata,
+        ..
+    } = &mut new_source
+    else {
+        unreachable!("matched DataSource::File above");
+    };
+    match t.file_format_config.as_ref() {
+        FileFormatConfig::Parquet(ParquetSourceConfig {
+            coerce_int96_timestamp_unit,
+            field_id_mapping,
+            ..
+        }) => {
+            let file =
+                read_parquet_metadata(path, io_client, Some(io_stats), field_id_mapping.clone())
+                    .await?;
+            let arrow_schema = infer_schema_with_options(
+                &file,
+                &Some(
+                    ParquetSchemaInferenceOptions::new(Some(*coerce_int96_timestamp_unit)).into(),
+                ),
+            )?;
+            let file_schema = Schema::try_from(&arrow_schema)?;
+            let mut file_statistics = None;
+            for rg in file.row_groups.values() {
+                let rg_statistics = row_group_metadata_to_table_stats(rg, &file_schema)?;
+                file_statistics = Some(match file_statistics {
+                    None => rg_statistics,
+                    Some(acc) => rg_statistics.union(&acc)?,
+                });
+            }
+            *metadata = Some(TableMetadata {
+                length: file.num_rows,
+            });
+            // Statistics that can't be cast to the schema of the task, e.g. because it was given by the user, are
+            // dropped.
+            *statistics = file_statistics.and_then(|s| s.cast_to_schema(t.schema.clone()).ok());
+            *parquet_metadata = Some(Arc::new(file));
+        }
+        FileFormatConfig::Orc(_) => {
+            let file = read_orc_metadata(path, io_client, Some(io_stats)).await?;
+            let mut file_statistics = None;
+            for i in 0..file.num_stripes() {
+                let stripe_statistics = stripe_statistics_to_table_stats(&file, i)?;
+                file_statistics = Some(match file_statistics {
+                    None => stripe_statistics,
+                    Some(acc) => stripe_statistics.union(&acc)?,
+                });
+            }
+            *metadata = Some(TableMetadata {
+                length: file.num_rows(),
+            });
+            *statistics = file_statistics.and_then(|s| s.cast_to_schema(t.schema.clone()).ok());
+        }
+        _ => return Ok(t),
+    }
+    Ok(ScanTask::new(
+        vec![new_source],
+        t.file_format_config.clone(),
+        t.schema.clone(),
+        t.storage_config.clone(),
+        t.pushdowns.clone(),
+    )
+    .with_union_by_name(t.union_by_name)
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::schema::Schema;
+    use itertools::Itertools;
+
+    use super::split_by_byte_ranges;
+    use crate::{
+        file_format::{CsvSourceConfig, FileFormatConfig, JsonSourceConfig},
+        storage_config::{NativeStorageConfig, StorageConfig},
+        ChunkSpec, DataSource, Pushdowns, ScanTask, ScanTaskRef,
+    };
+
+    fn make_scan_task(
+        path: &str,
+        size_bytes: u64,
+        file_format_config: FileFormatConfig,
+    ) -> ScanTaskRef {
+        ScanTask::new(
+            vec![DataSource::File {
+                path: path.to_string(),
+                chunk_spec: None,
+                size_bytes: Some(size_bytes),
+                iceberg_delete_files: None,
+                iceberg_equality_delete_files: None,
+                delta_deletion_vector: None,
+                metadata: None,
+                partition_spec: None,
+                statistics: None,
+                parquet_metadata: None,
+                etag: None,
+                last_modified: None,
+            }],
+            Arc::new(file_format_config),
+            Arc::new(Schema::empty()),
+            Arc::new(StorageConfig::Native(Arc::new(
+                NativeStorageConfig::new_internal(false, None),
+            ))),
+            Pushdowns::default(),
+        )
+        .into()
+    }
+
+    fn make_json_scan_task(path: &str, size_bytes: u64) -> ScanTaskRef {
+        make_scan_task(
+            path,
+            size_bytes,
+            FileFormatConfig::Json(JsonSourceConfig::new_internal(None, None)),
+        )
+    }
+
+    fn make_csv_scan_task(file: &str) -> DaftResult<ScanTaskRef> {
+        let path = format!("{}/../daft-csv/test/{}", env!("CARGO_MANIFEST_DIR"), file);
+        let size_bytes = std::fs::metadata(&path)?.len();
+        Ok(make_scan_task(
+            &path,
+            size_bytes,
+            FileFormatConfig::Csv(CsvSourceConfig {
+                delimiter: None,
+                has_headers: true,
+                double_quote: true,
+                quote: None,
+                escape_char: None,
+                comment: None,
+                allow_variable_columns: false,
+                buffer_size: None,
+                chunk_size: None,
+            }),
+        ))
+    }
+
+    #[test]
+    fn test_split_by_byte_ranges() -> DaftResult<()> {
+        let scan_tasks = vec![
+            Ok(make_json_scan_task("large.jsonl", 250)),
+            Ok(make_json_scan_task("large.jsonl.gz", 250)),
+            Ok(make_json_scan_task("small.jsonl", 50)),
+        ];
+        let split = split_by_byte_ranges(Box::new(scan_tasks.into_iter()), 100)
+            .collect::<DaftResult<Vec<_>>>()?;
+        let chunk_specs = split
+            .iter()
+            .map(|t| {
+                (
+                    t.sources[0].get_path().to_string(),
+                    t.sources[0].get_chunk_spec().cloned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            chunk_specs,
+            vec![
+                (
+                    "large.jsonl".to_string(),
+                    Some(ChunkSpec::Bytes { start: 0, end: 84 })
+                ),
+                (
+                    "large.jsonl".to_string(),
+                    Some(ChunkSpec::Bytes {
+                        start: 84,
+                        end: 168
+                    })
+                ),
+                (
+                    "large.jsonl".to_string(),
+                    Some(ChunkSpec::Bytes {
+                        start: 168,
+                        end: 250
+                    })
+                ),
+                // Compressed files can't be split.
+                ("large.jsonl.gz".to_string(), None),
+                ("small.jsonl".to_string(), None),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_by_byte_ranges_skips_multiline_csv() -> DaftResult<()> {
+        let scan_tasks = vec![
+            make_csv_scan_task("iris_tiny.csv"),
+            make_csv_scan_task("quoted_record_lines.csv"),
+        ];
+        let split = split_by_byte_ranges(Box::new(scan_tasks.into_iter()), 100)
+            .collect::<DaftResult<Vec<_>>>()?;
+        let num_tasks = split
+            .iter()
+            .counts_by(|t| t.sources[0].get_path().to_string());
+        assert!(num_tasks
+            .iter()
+            .any(|(path, &n)| path.ends_with("/iris_tiny.csv") && n > 1));
+        // Records with quoted newlines may straddle any boundary, so the file is read as a whole.
+        assert!(num_tasks
+            .iter()
+            .any(|(path, &n)| path.ends_with("/quoted_record_lines.csv") && n == 1));
+        Ok(())
+    }
+}
@@ -51,6 +51,15 @@ impl StorageConfig {
         }
     }
 
+    /// Returns whether I/O of this storage config runs on the multithreaded runtime.
+    pub fn multithreaded_io(&self) -> bool {
+        match self {
+            Self::Native(cfg) => cfg.multithreaded_io,
+            #[cfg(feature = "python")]
+            Self::Python(_) => true,
+        }
+    }
+
     pub fn var_name(&self) -> &'static str {
         match self {
             Self::Native(_) => "Native",
@@ -63,6 +63,7 @@ impl AdaptivePhysicalPlanScheduler {
         num_partitions: usize,
         size_bytes: usize,
         num_rows: usize,
+        partition_size_bytes: Option<Vec<usize>>,
         py: Python,
     ) -> PyResult<()> {
         let cache_entry = cache_entry.into();
@@ -80,6 +81,7 @@ impl AdaptivePhysicalPlanScheduler {
             self.planner.update(MaterializedResults {
                 in_memory_info,
                 source_id,
+                partition_size_bytes,
             })?;
             Ok(())
         })
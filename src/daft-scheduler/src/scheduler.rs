@@ -469,6 +469,18 @@ fn physical_plan_to_partition_tasks(
                 .call1((upstream_iter, *input_num_partitions, *output_num_partitions))?;
             Ok(py_iter.into())
         }
+        PhysicalPlan::SkewSplit(SkewSplit {
+            input,
+            num_outputs,
+            replicate,
+        }) => {
+            let upstream_iter = physical_plan_to_partition_tasks(input, py, psets)?;
+            let py_iter = py
+                .import(pyo3::intern!(py, "daft.execution.physical_plan"))?
+                .getattr(pyo3::intern!(py, "skew_split"))?
+                .call1((upstream_iter, num_outputs.clone(), replicate.clone()))?;
+            Ok(py_iter.into())
+        }
         PhysicalPlan::Flatten(Flatten { input }) => {
             let upstream_iter = physical_plan_to_partition_tasks(input, py, psets)?;
             let py_iter = py
@@ -754,6 +766,27 @@ fn physical_plan_to_partition_tasks(
             partition_cols,
             io_config,
         ),
+        PhysicalPlan::TabularWriteIpc(TabularWriteIpc {
+            schema,
+            file_info:
+                OutputFileInfo {
+                    root_dir,
+                    file_format,
+                    partition_cols,
+                    compression,
+                    io_config,
+                },
+            input,
+        }) => tabular_write(
+            py,
+            physical_plan_to_partition_tasks(input, py, psets)?,
+            file_format,
+            schema,
+            root_dir,
+            compression,
+            partition_cols,
+            io_config,
+        ),
         #[cfg(feature = "python")]
         PhysicalPlan::IcebergWrite(IcebergWrite {
             schema: _,
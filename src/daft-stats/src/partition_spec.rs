@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
+use common_error::{DaftError, DaftResult};
 use daft_core::array::ops::{DaftCompare, DaftLogical};
+use daft_core::datatypes::DataType;
 use daft_dsl::{ExprRef, Literal};
 use daft_table::Table;
 
@@ -29,6 +31,23 @@ impl PartitionSpec {
             })
             .collect()
     }
+
+    /// Returns whether the partition values satisfy the provided filter on partition columns. Partitions for which
+    /// the filter evaluates to null don't satisfy it.
+    pub fn satisfies(&self, partition_filter: &ExprRef) -> DaftResult<bool> {
+        let eval_pred = self
+            .keys
+            .eval_expression_list(&[partition_filter.clone()])?;
+        let series = eval_pred.get_column_by_index(0)?;
+        if series.data_type() != &DataType::Boolean || series.len() != 1 {
+            return Err(DaftError::ValueError(format!(
+                "Partition filter {partition_filter} must evaluate to a single boolean for a partition, but got {} value(s) of type {}",
+                series.len(),
+                series.data_type()
+            )));
+        }
+        Ok(series.bool()?.get(0).unwrap_or(false))
+    }
 }
 
 impl PartialEq for PartitionSpec {
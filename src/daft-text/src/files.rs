@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_file_reader::{collect_tables, ReadPushdowns};
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{stream::BoxStream, StreamExt};
+use snafu::ResultExt;
+
+use crate::JoinSnafu;
+
+/// Name of the column that holds the path of each file.
+pub const PATH_COLUMN_NAME: &str = "path";
+/// Name of the column that holds the size of each file in bytes.
+pub const SIZE_COLUMN_NAME: &str = "size";
+/// Name of the column that holds the content of each file.
+pub const CONTENT_COLUMN_NAME: &str = "content";
+
+/// Maximum number of files in each table of a stream of whole files, which bounds the content that a table holds.
+pub const FILES_PER_TABLE: usize = 64;
+
+/// Returns the schema of tables of whole files, with a row per file.
+pub fn files_schema() -> Schema {
+    Schema::new(vec![
+        Field::new(PATH_COLUMN_NAME, DataType::Utf8),
+        Field::new(SIZE_COLUMN_NAME, DataType::Int64),
+        Field::new(CONTENT_COLUMN_NAME, DataType::Binary),
+    ])
+    .expect("Schema of whole files has unique field names")
+}
+
+/// Reads the files at `uris` as a table with a row per file.
+///
+/// `sizes` holds the size of each file if it's already known, such as from the listing of the files. The content of
+/// the files is only downloaded if the content column is projected or is required by the predicate, and their sizes
+/// are only fetched if they're needed and unknown.
+pub fn read_files(
+    uris: &[&str],
+    sizes: &[Option<u64>],
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+    multithreaded_io: bool,
+) -> DaftResult<Table> {
+    let limit = pushdowns.limit;
+    let uris = uris.iter().map(|uri| uri.to_string()).collect();
+    let (tables, schema) = stream_files_into_tables(
+        uris,
+        sizes.to_vec(),
+        pushdowns,
+        io_client,
+        io_stats,
+        num_parallel_tasks,
+    )?;
+    let runtime_handle = get_runtime(multithreaded_io)?;
+    runtime_handle.block_on_current_thread(collect_tables(tables, schema, limit))
+}
+
+/// Streams the files at `uris` as tables of up to [`FILES_PER_TABLE`] rows. See [`read_files`].
+pub async fn stream_files(
+    uris: Vec<String>,
+    sizes: Vec<Option<u64>>,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+    let (tables, _) = stream_files_into_tables(
+        uris,
+        sizes,
+        pushdowns,
+        io_client,
+        io_stats,
+        num_parallel_tasks,
+    )?;
+    Ok(tables)
+}
+
+/// Returns a stream of tables of the files at `uris` and the schema of the tables.
+fn stream_files_into_tables(
+    uris: Vec<String>,
+    sizes: Vec<Option<u64>>,
+    pushdowns: ReadPushdowns,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+) -> DaftResult<(BoxStream<'static, DaftResult<Table>>, SchemaRef)> {
+    let (read_schema, output_schema) =
+        pushdowns.read_and_output_schemas(Arc::new(files_schema()), "whole")?;
+    let read_content = read_schema.fields.contains_key(CONTENT_COLUMN_NAME);
+    let read_size = read_schema.fields.contains_key(SIZE_COLUMN_NAME);
+
+    // Each file is a row, so the limit can be applied to the files if no rows are filtered out by the predicate.
+    let num_files = pushdowns.decode_limit().unwrap_or(usize::MAX);
+
+    let tables = futures::stream::iter(uris.into_iter().zip(sizes).take(num_files))
+        // Reads are only spawned once the buffer below polls them.
+        .map(move |(uri, size)| {
+            let (io_client, io_stats) = (io_client.clone(), io_stats.clone());
+            tokio::task::spawn(async move {
+                let (size, content) = if read_content {
+                    let content = io_client
+                        .single_url_get(uri.clone(), None, io_stats)
+                        .await?
+                        .bytes()
+                        .await?;
+                    (Some(content.len() as u64), Some(content))
+                } else if read_size && size.is_none() {
+                    let size = io_client.single_url_get_size(uri.clone(), io_stats).await?;
+                    (Some(size as u64), None)
+                } else {
+                    (size, None)
+                };
+                Ok::<_, daft_io::Error>((uri, size, content))
+            })
+        })
+        // Limit the number of file reads we have in flight at any given time.
+        .buffered(num_parallel_tasks)
+        .map(|result| Ok::<_, DaftError>(result.context(JoinSnafu)??))
+        .chunks(FILES_PER_TABLE)
+        .map(move |files| {
+            let files = files.into_iter().collect::<DaftResult<Vec<_>>>()?;
+            let series = read_schema
+                .fields
+                .values()
+                .map(|field| match field.name.as_str() {
+                    PATH_COLUMN_NAME => Utf8Array::from_values(
+                        PATH_COLUMN_NAME,
+                        files.iter().map(|(uri, _, _)| uri),
+                    )
+                    .into_series(),
+                    SIZE_COLUMN_NAME => Int64Array::from_iter(
+                        SIZE_COLUMN_NAME,
+                        files
+                            .iter()
+                            .map(|(_, size, _)| size.map(|size| size as i64)),
+                    )
+                    .into_series(),
+                    _ => BinaryArray::from_iter(
+                        CONTENT_COLUMN_NAME,
+                        files.iter().map(|(_, _, content)| content.as_deref()),
+                    )
+                    .into_series(),
+                })
+                .collect();
+            Table::new_with_size(read_schema.clone(), series, files.len())
+        });
+    Ok((pushdowns.apply(tables), output_schema))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_dsl::{col, lit};
+    use daft_file_reader::{collect_tables, ReadPushdowns};
+    use daft_io::{get_runtime, IOClient, IOConfig};
+    use futures::TryStreamExt;
+
+    use super::{read_files, stream_files, FILES_PER_TABLE};
+
+    #[test]
+    fn test_read_files() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uris = ["a.bin", "b.bin"]
+            .iter()
+            .zip([&b"hello"[..], &b"hi"[..]])
+            .map(|(name, content)| {
+                let path = dir.path().join(name);
+                std::fs::write(&path, content).unwrap();
+                path.to_string_lossy().to_string()
+            })
+            .collect::<Vec<_>>();
+        let uris = uris.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_files(
+            &uris,
+            &[None, None],
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            2,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["path", "size", "content"]);
+        assert_eq!(table.get_column("path")?.utf8()?.get(1), Some(uris[1]));
+        assert_eq!(table.get_column("size")?.i64()?.get(0), Some(5));
+        assert_eq!(
+            table.get_column("content")?.binary()?.get(1),
+            Some(&b"hi"[..])
+        );
+
+        let table = read_files(
+            &uris,
+            &[None, None],
+            ReadPushdowns::new(Some(&["path"]), Some(1), Some(col("size").lt(lit(3)))),
+            io_client,
+            None,
+            2,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["path"]);
+        assert_eq!(table.get_column("path")?.utf8()?.get(0), Some(uris[1]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_files_without_content() -> DaftResult<()> {
+        // Files aren't accessed if their content isn't read and their sizes are known.
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let table = read_files(
+            &["/does/not/exist.bin"],
+            &[Some(42)],
+            ReadPushdowns::new(Some(&["size", "path"]), None, None),
+            io_client,
+            None,
+            1,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["size", "path"]);
+        assert_eq!(table.get_column("size")?.i64()?.get(0), Some(42));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_files_in_chunks() -> DaftResult<()> {
+        let uris = (0..FILES_PER_TABLE * 2 + 1)
+            .map(|i| format!("/does/not/exist/{i}.bin"))
+            .collect::<Vec<_>>();
+        let sizes = vec![Some(1); uris.len()];
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let tables = get_runtime(true)?.block_on_current_thread(async {
+            stream_files(
+                uris,
+                sizes,
+                ReadPushdowns::new(Some(&["path"]), Some(FILES_PER_TABLE + 1), None),
+                io_client,
+                None,
+                4,
+            )
+            .await?
+            .try_collect::<Vec<_>>()
+            .await
+        })?;
+        // Only the files within the limit are read.
+        assert_eq!(
+            tables.iter().map(|table| table.len()).collect::<Vec<_>>(),
+            vec![FILES_PER_TABLE, 1]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_decoding::deserialize::{deserialize_column, ByteRecordGeneric};
+use daft_file_reader::{FileFormatReader, ReadPushdowns};
+use daft_io::{IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{stream::BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::read::LineReader;
+
+/// A column of a fixed-width text file, which holds the characters `[start, start + width)` of each line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FixedWidthColumn {
+    pub name: String,
+    /// Position of the first character of the column in each line, starting from 0.
+    pub start: usize,
+    /// Number of characters of the column.
+    pub width: usize,
+    pub dtype: DataType,
+}
+
+/// Options for reading fixed-width text files, which are read as a row per non-blank line.
+#[derive(Clone, Debug, Default)]
+pub struct FixedWidthReadOptions {
+    pub columns: Vec<FixedWidthColumn>,
+    /// Number of lines to skip at the start of the file, such as header lines.
+    pub skip_rows: usize,
+    /// Byte range `[start, end)` of the file to read, which is read as the lines that begin within the range. Lines
+    /// are only skipped by `skip_rows` in ranges that start at the beginning of the file.
+    pub byte_range: Option<(usize, usize)>,
+}
+
+/// Returns the schema of tables read from fixed-width text files with the given columns.
+pub fn fixed_width_schema(columns: &[FixedWidthColumn]) -> DaftResult<Schema> {
+    if let Some(column) = columns.iter().find(|c| c.width == 0) {
+        return Err(DaftError::ValueError(format!(
+            "Fixed-width column {} must have a positive width",
+            column.name
+        )));
+    }
+    Schema::new(
+        columns
+            .iter()
+            .map(|c| Field::new(c.name.as_str(), c.dtype.clone()))
+            .collect(),
+    )
+}
+
+/// A line of a fixed-width text file with the byte ranges of the fields that are read from it, which are `None` for
+/// fields that are blank or past the end of the line.
+struct FixedWidthRecord {
+    line: String,
+    fields: Vec<Option<(usize, usize)>>,
+}
+
+impl FixedWidthRecord {
+    /// Splits `line` into the fields of `columns`, whose surrounding whitespace is trimmed.
+    fn new(line: String, columns: &[FixedWidthColumn]) -> Self {
+        // Columns are positioned by characters, whose byte offsets only differ from their indices in non-ASCII lines.
+        let char_offsets = (!line.is_ascii()).then(|| {
+            line.char_indices()
+                .map(|(offset, _)| offset)
+                .chain(std::iter::once(line.len()))
+                .collect::<Vec<_>>()
+        });
+        let byte_offset = |index: usize| match &char_offsets {
+            Some(char_offsets) => char_offsets[index.min(char_offsets.len() - 1)],
+            None => index.min(line.len()),
+        };
+        let fields = columns
+            .iter()
+            .map(|column| {
+                let (start, end) = (
+                    byte_offset(column.start),
+                    byte_offset(column.start + column.width),
+                );
+                let field = &line[start..end];
+                let trimmed = field.trim_start();
+                let start = start + field.len() - trimmed.len();
+                let end = start + trimmed.trim_end().len();
+                (start < end).then_some((start, end))
+            })
+            .collect();
+        Self { line, fields }
+    }
+}
+
+impl ByteRecordGeneric for FixedWidthRecord {
+    fn get(&self, index: usize) -> Option<&[u8]> {
+        let (start, end) = self.fields.get(index).copied().flatten()?;
+        Some(&self.line.as_bytes()[start..end])
+    }
+}
+
+#[async_trait]
+impl FileFormatReader for FixedWidthReadOptions {
+    const FORMAT_NAME: &'static str = "fixed-width";
+
+    type Metadata = ();
+
+    async fn read_metadata(
+        &self,
+        _uri: &str,
+        _io_client: Arc<IOClient>,
+        _io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<(Self::Metadata, SchemaRef)> {
+        Ok(((), Arc::new(fixed_width_schema(&self.columns)?)))
+    }
+
+    /// Decodes the non-blank lines of the file.
+    async fn decode(
+        &self,
+        uri: &str,
+        _metadata: Self::Metadata,
+        read_schema: SchemaRef,
+        pushdowns: &ReadPushdowns,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+        let read_columns = self
+            .columns
+            .iter()
+            .filter(|c| read_schema.fields.contains_key(&c.name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut line_reader = LineReader::try_new(
+            uri,
+            self.byte_range,
+            true,
+            pushdowns.decode_limit().unwrap_or(usize::MAX),
+            io_client,
+            io_stats,
+        )
+        .await?;
+        if self.byte_range.map_or(true, |(start, _)| start == 0) {
+            line_reader.skip_lines(self.skip_rows).await?;
+        }
+
+        let tables = line_reader.into_chunks().map(move |lines| {
+            let records = lines?
+                .into_iter()
+                .map(|line| FixedWidthRecord::new(line, &read_columns))
+                .collect::<Vec<_>>();
+            let series = read_schema
+                .fields
+                .values()
+                .enumerate()
+                .map(|(i, field)| {
+                    let array = deserialize_column(&records, i, field.dtype.to_arrow()?, 0)?;
+                    Series::try_from_field_and_arrow_array(
+                        Arc::new(field.clone()),
+                        cast_array_for_daft_if_needed(array),
+                    )
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Table::new_with_size(read_schema.clone(), series, records.len())
+        });
+        Ok(tables.boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_file_reader::{read_file, ReadPushdowns};
+    use daft_io::{IOClient, IOConfig};
+
+    use super::{FixedWidthColumn, FixedWidthReadOptions};
+
+    fn column(name: &str, start: usize, width: usize, dtype: DataType) -> FixedWidthColumn {
+        FixedWidthColumn {
+            name: name.to_string(),
+            start,
+            width,
+            dtype,
+        }
+    }
+
+    fn test_options() -> FixedWidthReadOptions {
+        FixedWidthReadOptions {
+            columns: vec![
+                column("id", 0, 4, DataType::Int64),
+                column("name", 4, 6, DataType::Utf8),
+                column("amount", 10, 8, DataType::Float64),
+                column("day", 18, 10, DataType::Date),
+            ],
+            skip_rows: 1,
+            byte_range: None,
+        }
+    }
+
+    fn write_test_file(dir: &tempfile::TempDir) -> String {
+        let path = dir.path().join("test.txt");
+        let lines = [
+            "ID  NAME  AMOUNT  DAY       ",
+            "   1ann       1.502024-01-31",
+            "   2bé      -20.00",
+            "",
+            "   3       1000.002024-02-29",
+            "  40carlos        2024-03-01\r",
+        ];
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_fixed_width_read() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = write_test_file(&dir);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_file(
+            &test_options(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["id", "name", "amount", "day"]);
+        assert_eq!(table.len(), 4);
+        let ids = table.get_column("id")?.i64()?;
+        assert_eq!(
+            ids.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(3), Some(40)]
+        );
+        // Fields are positioned by characters rather than bytes, and blank fields are null.
+        let names = table.get_column("name")?.utf8()?;
+        assert_eq!(
+            names.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("ann"), Some("bé"), None, Some("carlos")]
+        );
+        let amounts = table.get_column("amount")?.f64()?;
+        assert_eq!(
+            amounts.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1.5), Some(-20.0), Some(1000.0), None]
+        );
+        // Fields past the end of a line are null.
+        assert_eq!(table.get_column("day")?.str_value(1)?, "None");
+        assert_eq!(table.get_column("day")?.str_value(3)?, "2024-03-01");
+
+        // Predicate columns don't have to be in the projection.
+        let table = read_file(
+            &test_options(),
+            &uri,
+            ReadPushdowns::new(Some(&["name"]), Some(1), Some(col("amount").gt(lit(0.0)))),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["name"]);
+        assert_eq!(table.get_column("name")?.utf8()?.get(0), Some("ann"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_width_read_byte_ranges() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = write_test_file(&dir);
+        let file_size = std::fs::metadata(&uri)?.len() as usize;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        // Adjacent byte ranges read each line exactly once, and only the first skips the header.
+        let mut ids = vec![];
+        for start in (0..file_size).step_by(20) {
+            let options = FixedWidthReadOptions {
+                byte_range: Some((start, (start + 20).min(file_size))),
+                ..test_options()
+            };
+            let table = read_file(
+                &options,
+                &uri,
+                ReadPushdowns::new(Some(&["id"]), None, None),
+                io_client.clone(),
+                None,
+                true,
+            )?;
+            ids.extend(
+                table
+                    .get_column("id")?
+                    .i64()?
+                    .into_iter()
+                    .map(|v| v.copied()),
+            );
+        }
+        assert_eq!(ids, vec![Some(1), Some(2), Some(3), Some(40)]);
+
+        let options = FixedWidthReadOptions {
+            columns: vec![column("id", 0, 0, DataType::Int64)],
+            ..Default::default()
+        };
+        assert!(read_file(
+            &options,
+            &uri,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )
+        .is_err());
+        Ok(())
+    }
+}
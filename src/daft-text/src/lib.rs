@@ -0,0 +1,41 @@
+//! Readers of unstructured files, either as a row per line of text or as a row per whole file, and of fixed-width
+//! text files, as a row per line split into columns by character positions.
+use common_error::DaftError;
+use snafu::Snafu;
+
+pub mod files;
+pub mod fixed_width;
+pub mod read;
+
+pub use files::{files_schema, read_files, stream_files};
+pub use fixed_width::{fixed_width_schema, FixedWidthColumn, FixedWidthReadOptions};
+pub use read::{text_schema, TextReadOptions};
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("{source}"))]
+    StdIOError { source: std::io::Error },
+    #[snafu(display("File: {} has a line that isn't valid UTF-8 at byte {}", path, offset))]
+    InvalidUtf8 { path: String, offset: usize },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,420 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_error::{DaftError, DaftResult};
+use daft_compression::CompressionCodec;
+use daft_core::prelude::*;
+use daft_file_reader::{FileFormatReader, ReadPushdowns};
+use daft_io::{GetResult, IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{stream::BoxStream, Stream, StreamExt};
+use snafu::ResultExt;
+use tokio::{
+    fs::File,
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncSeekExt, BufReader, SeekFrom},
+};
+use tokio_util::io::StreamReader;
+
+use crate::StdIOSnafu;
+
+/// Name of the column that holds the lines of a text file.
+pub const TEXT_COLUMN_NAME: &str = "text";
+/// Maximum number of lines in each table that a text file is read as.
+const CHUNK_SIZE: usize = 64 * 1024;
+/// Size of the buffer that text files are read through.
+const BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Options for reading text files as a row per line.
+#[derive(Clone, Debug, Default)]
+pub struct TextReadOptions {
+    /// Whether to skip lines that are empty or only hold whitespace.
+    pub skip_blank_lines: bool,
+    /// Name of a column that holds the path of the file that each line was read from, if any.
+    pub path_column: Option<String>,
+    /// Byte range `[start, end)` of the file to read, which is read as the lines that begin within the range.
+    pub byte_range: Option<(usize, usize)>,
+}
+
+/// Returns the schema of tables read from text files, with a [`TEXT_COLUMN_NAME`] column and an optional path column.
+pub fn text_schema(path_column: Option<&str>) -> DaftResult<Schema> {
+    let mut fields = vec![Field::new(TEXT_COLUMN_NAME, DataType::Utf8)];
+    if let Some(path_column) = path_column {
+        fields.push(Field::new(path_column, DataType::Utf8));
+    }
+    Schema::new(fields)
+}
+
+#[async_trait]
+impl FileFormatReader for TextReadOptions {
+    const FORMAT_NAME: &'static str = "text";
+
+    type Metadata = ();
+
+    async fn read_metadata(
+        &self,
+        _uri: &str,
+        _io_client: Arc<IOClient>,
+        _io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<(Self::Metadata, SchemaRef)> {
+        Ok(((), Arc::new(text_schema(self.path_column.as_deref())?)))
+    }
+
+    async fn decode(
+        &self,
+        uri: &str,
+        _metadata: Self::Metadata,
+        read_schema: SchemaRef,
+        pushdowns: &ReadPushdowns,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+        let line_reader = LineReader::try_new(
+            uri,
+            self.byte_range,
+            self.skip_blank_lines,
+            pushdowns.decode_limit().unwrap_or(usize::MAX),
+            io_client,
+            io_stats,
+        )
+        .await?;
+
+        let uri: Arc<str> = Arc::from(uri);
+        let tables = line_reader.into_chunks().map(move |lines| {
+            let lines = lines?;
+            let num_rows = lines.len();
+            let series = read_schema
+                .fields
+                .values()
+                .map(|field| {
+                    if field.name == TEXT_COLUMN_NAME {
+                        Utf8Array::from_values(TEXT_COLUMN_NAME, lines.iter()).into_series()
+                    } else {
+                        Utf8Array::from_values(
+                            field.name.as_str(),
+                            std::iter::repeat(uri.as_ref()).take(num_rows),
+                        )
+                        .into_series()
+                    }
+                })
+                .collect();
+            Table::new_with_size(read_schema.clone(), series, num_rows)
+        });
+        Ok(tables.boxed())
+    }
+}
+
+/// Opens the text file at `uri` for reading from `read_start`, decompressing it if its extension is that of a
+/// compression codec.
+async fn open_text_file(
+    uri: &str,
+    read_start: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Box<dyn AsyncBufRead + Unpin + Send>> {
+    let get_range = if read_start > 0 {
+        let size = io_client
+            .single_url_get_size(uri.to_string(), io_stats.clone())
+            .await?;
+        Some(read_start..size)
+    } else {
+        None
+    };
+    let reader: Box<dyn AsyncBufRead + Unpin + Send> = match io_client
+        .single_url_get(uri.to_string(), get_range, io_stats)
+        .await?
+    {
+        GetResult::File(file) => {
+            let mut file = File::open(file.path).await.context(StdIOSnafu)?;
+            file.seek(SeekFrom::Start(read_start as u64))
+                .await
+                .context(StdIOSnafu)?;
+            Box::new(BufReader::with_capacity(BUFFER_SIZE, file))
+        }
+        GetResult::Stream(stream, ..) => Box::new(BufReader::with_capacity(
+            BUFFER_SIZE,
+            StreamReader::new(stream),
+        )),
+    };
+    Ok(match CompressionCodec::from_uri(uri) {
+        Some(compression) => Box::new(BufReader::with_capacity(
+            BUFFER_SIZE,
+            compression.to_decoder(reader),
+        )),
+        None => reader,
+    })
+}
+
+/// State of the reading of the lines of a text file.
+pub(crate) struct LineReader {
+    reader: Box<dyn AsyncBufRead + Unpin + Send>,
+    uri: String,
+    skip_blank_lines: bool,
+    /// Number of bytes read so far.
+    position: usize,
+    /// Lines that begin at or after this many bytes aren't read.
+    max_bytes: usize,
+    /// Number of lines that are left to read.
+    rows_left: usize,
+    eof: bool,
+}
+
+impl LineReader {
+    /// Opens the text file at `uri` for reading at most `rows_left` of its lines, which are only those that begin
+    /// within `byte_range` if it's given.
+    pub(crate) async fn try_new(
+        uri: &str,
+        byte_range: Option<(usize, usize)>,
+        skip_blank_lines: bool,
+        rows_left: usize,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        if byte_range.is_some() && CompressionCodec::from_uri(uri).is_some() {
+            return Err(DaftError::ValueError(format!(
+                "Cannot read a byte range of compressed text file: {uri}"
+            )));
+        }
+        // Start reading from the byte before the range, so that a line which begins exactly at the start of the range
+        // is found after the preceding newline.
+        let read_start = byte_range.map_or(0, |(start, _)| start.saturating_sub(1));
+        let mut reader = open_text_file(uri, read_start, io_client, io_stats).await?;
+        // Ranges after the start of the file begin after the first newline in the range.
+        let (position, max_bytes) = match byte_range {
+            Some((start, end)) if start > 0 => {
+                let skipped = reader
+                    .read_until(b'\n', &mut Vec::new())
+                    .await
+                    .context(StdIOSnafu)?;
+                (read_start + skipped, end)
+            }
+            Some((_, end)) => (0, end),
+            None => (0, usize::MAX),
+        };
+        Ok(Self {
+            reader,
+            uri: uri.to_string(),
+            skip_blank_lines,
+            position,
+            max_bytes,
+            rows_left,
+            eof: false,
+        })
+    }
+
+    /// Returns a stream of the chunks of lines that are left to read.
+    pub(crate) fn into_chunks(self) -> impl Stream<Item = crate::Result<Vec<String>>> {
+        futures::stream::try_unfold(self, |mut line_reader| async move {
+            Ok(line_reader
+                .next_chunk()
+                .await?
+                .map(|lines| (lines, line_reader)))
+        })
+    }
+
+    /// Skips the next `num_lines` lines, such as the header lines at the start of a file, whether they're blank or not.
+    pub(crate) async fn skip_lines(&mut self, num_lines: usize) -> crate::Result<()> {
+        for _ in 0..num_lines {
+            if self.position >= self.max_bytes || self.eof {
+                break;
+            }
+            let bytes_read = self
+                .reader
+                .read_until(b'\n', &mut Vec::new())
+                .await
+                .context(StdIOSnafu)?;
+            self.eof = bytes_read == 0;
+            self.position += bytes_read;
+        }
+        Ok(())
+    }
+
+    /// Reads the next chunk of at most [`CHUNK_SIZE`] lines, or `None` if there are no more lines to read.
+    async fn next_chunk(&mut self) -> crate::Result<Option<Vec<String>>> {
+        let mut lines = vec![];
+        let mut buf = vec![];
+        while lines.len() < CHUNK_SIZE
+            && self.rows_left > 0
+            && self.position < self.max_bytes
+            && !self.eof
+        {
+            buf.clear();
+            let bytes_read = self
+                .reader
+                .read_until(b'\n', &mut buf)
+                .await
+                .context(StdIOSnafu)?;
+            if bytes_read == 0 {
+                self.eof = true;
+                break;
+            }
+            let line_start = self.position;
+            self.position += bytes_read;
+            if buf.ends_with(b"\n") {
+                buf.pop();
+                if buf.ends_with(b"\r") {
+                    buf.pop();
+                }
+            }
+            let line = std::str::from_utf8(&buf).map_err(|_| crate::Error::InvalidUtf8 {
+                path: self.uri.clone(),
+                offset: line_start,
+            })?;
+            if self.skip_blank_lines && line.trim().is_empty() {
+                continue;
+            }
+            lines.push(line.to_string());
+            self.rows_left -= 1;
+        }
+        Ok((!lines.is_empty()).then_some(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_file_reader::{read_file, ReadPushdowns};
+    use daft_io::{IOClient, IOConfig};
+    use rstest::rstest;
+
+    use super::TextReadOptions;
+
+    fn write_test_file(dir: &tempfile::TempDir, lines: &[&str]) -> String {
+        let path = dir.path().join("test.txt");
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn text_values(table: &daft_table::Table) -> Vec<String> {
+        table
+            .get_column("text")
+            .unwrap()
+            .utf8()
+            .unwrap()
+            .as_arrow()
+            .values_iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    #[rstest]
+    fn test_text_read_compressed(
+        #[values(None, Some("br"), Some("bz2"), Some("gz"), Some("xz"), Some("zst"))]
+        compression: Option<&str>,
+    ) -> DaftResult<()> {
+        let file = format!(
+            "{}/../daft-json/test/iris_tiny.jsonl{}",
+            env!("CARGO_MANIFEST_DIR"),
+            compression.map_or("".to_string(), |ext| format!(".{}", ext))
+        );
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_file(
+            &TextReadOptions::default(),
+            &file,
+            ReadPushdowns::default(),
+            io_client,
+            None,
+            true,
+        )?;
+        let expected = std::fs::read_to_string(format!(
+            "{}/../daft-json/test/iris_tiny.jsonl",
+            env!("CARGO_MANIFEST_DIR")
+        ))?;
+        assert_eq!(text_values(&table), expected.lines().collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_read_options() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = write_test_file(&dir, &["a", "", "b\r", "  ", "c"]);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_file(
+            &TextReadOptions::default(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(text_values(&table), vec!["a", "", "b", "  ", "c"]);
+
+        let options = TextReadOptions {
+            skip_blank_lines: true,
+            path_column: Some("path".to_string()),
+            byte_range: None,
+        };
+        let table = read_file(
+            &options,
+            &uri,
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["text", "path"]);
+        assert_eq!(text_values(&table), vec!["a", "b", "c"]);
+        assert_eq!(table.get_column("path")?.utf8()?.get(0), Some(uri.as_str()));
+
+        // Blank lines don't count towards the limit.
+        let table = read_file(
+            &options,
+            &uri,
+            ReadPushdowns::new(Some(&["text"]), Some(2), None),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["text"]);
+        assert_eq!(text_values(&table), vec!["a", "b"]);
+
+        // Predicate columns don't have to be in the projection.
+        let table = read_file(
+            &options,
+            &uri,
+            ReadPushdowns::new(Some(&["path"]), None, Some(col("text").eq(lit("c")))),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["path"]);
+        assert_eq!(table.len(), 1);
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_text_read_byte_ranges(
+        #[values(1, 2, 3, 5, 8, 100)] range_size: usize,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let lines = ["aa", "b", "", "cccc", "dd", "e"];
+        let uri = write_test_file(&dir, &lines);
+        let file_size = std::fs::metadata(&uri)?.len() as usize;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        // Adjacent byte ranges read each line exactly once.
+        let mut values = vec![];
+        for start in (0..file_size).step_by(range_size) {
+            let options = TextReadOptions {
+                byte_range: Some((start, (start + range_size).min(file_size))),
+                ..Default::default()
+            };
+            let table = read_file(
+                &options,
+                &uri,
+                ReadPushdowns::default(),
+                io_client.clone(),
+                None,
+                true,
+            )?;
+            values.extend(text_values(&table));
+        }
+        assert_eq!(values, lines);
+        Ok(())
+    }
+}
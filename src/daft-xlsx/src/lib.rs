@@ -0,0 +1,65 @@
+//! Reader of the sheets of Excel workbooks in the XLSX format, which are read as a row per row of the sheet.
+use common_error::DaftError;
+use snafu::Snafu;
+
+#[cfg(feature = "python")]
+pub mod python;
+pub mod read;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+pub use read::{read_xlsx_schema, read_xlsx_schema_bulk, XlsxReadOptions};
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("{source}"))]
+    IOError { source: daft_io::Error },
+    #[snafu(display("File: {} is not a valid XLSX file: {}", path, source))]
+    InvalidXlsxFile {
+        path: String,
+        source: calamine::XlsxError,
+    },
+    #[snafu(display(
+        "Sheet {} doesn't exist in XLSX file: {}; existing sheets = {:?}",
+        sheet,
+        path,
+        sheet_names
+    ))]
+    SheetNotFound {
+        path: String,
+        sheet: String,
+        sheet_names: Vec<String>,
+    },
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> DaftError {
+        match err {
+            Error::IOError { source } => source.into(),
+            _ => DaftError::External(err.into()),
+        }
+    }
+}
+
+impl From<daft_io::Error> for Error {
+    fn from(err: daft_io::Error) -> Self {
+        Error::IOError { source: err }
+    }
+}
+
+#[cfg(feature = "python")]
+impl From<Error> for pyo3::PyErr {
+    fn from(value: Error) -> Self {
+        let daft_error: DaftError = value.into();
+        daft_error.into()
+    }
+}
+
+#[cfg(feature = "python")]
+pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_xlsx))?;
+    parent.add_wrapped(wrap_pyfunction!(python::pylib::read_xlsx_schema))?;
+    Ok(())
+}
@@ -0,0 +1,98 @@
+pub mod pylib {
+    use std::sync::Arc;
+
+    use daft_core::python::schema::PySchema;
+    use daft_dsl::python::PyExpr;
+    use daft_file_reader::{read_file, ReadPushdowns};
+    use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
+    use daft_table::python::PyTable;
+    use pyo3::{pyfunction, PyResult, Python};
+
+    use crate::read::XlsxReadOptions;
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyfunction]
+    #[pyo3(signature = (
+        uri,
+        columns=None,
+        num_rows=None,
+        sheet_name=None,
+        sheet_index=None,
+        header_row=0,
+        predicate=None,
+        io_config=None,
+        multithreaded_io=None
+    ))]
+    pub fn read_xlsx(
+        py: Python,
+        uri: &str,
+        columns: Option<Vec<&str>>,
+        num_rows: Option<usize>,
+        sheet_name: Option<String>,
+        sheet_index: Option<usize>,
+        header_row: Option<usize>,
+        predicate: Option<PyExpr>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PyTable> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_xlsx: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let options = XlsxReadOptions {
+                sheet_name,
+                sheet_index,
+                header_row,
+                schema: None,
+            };
+            Ok(read_file(
+                &options,
+                uri,
+                ReadPushdowns::new(columns.as_deref(), num_rows, predicate.map(|e| e.expr)),
+                io_client,
+                Some(io_stats),
+                multithreaded_io.unwrap_or(true),
+            )?
+            .into())
+        })
+    }
+
+    #[pyfunction]
+    #[pyo3(signature = (
+        uri,
+        sheet_name=None,
+        sheet_index=None,
+        header_row=0,
+        io_config=None,
+        multithreaded_io=None
+    ))]
+    pub fn read_xlsx_schema(
+        py: Python,
+        uri: &str,
+        sheet_name: Option<String>,
+        sheet_index: Option<usize>,
+        header_row: Option<usize>,
+        io_config: Option<IOConfig>,
+        multithreaded_io: Option<bool>,
+    ) -> PyResult<PySchema> {
+        py.allow_threads(|| {
+            let io_stats = IOStatsContext::new(format!("read_xlsx_schema: for uri {uri}"));
+
+            let io_client = get_io_client(
+                multithreaded_io.unwrap_or(true),
+                io_config.unwrap_or_default().config.into(),
+            )?;
+            let options = XlsxReadOptions {
+                sheet_name,
+                sheet_index,
+                header_row,
+                schema: None,
+            };
+            let schema = crate::read::read_xlsx_schema(uri, options, io_client, Some(io_stats))?;
+            Ok(Arc::new(schema).into())
+        })
+    }
+}
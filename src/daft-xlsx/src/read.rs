@@ -0,0 +1,561 @@
+use std::{collections::HashSet, io::Cursor, sync::Arc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+use common_error::DaftResult;
+use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
+use daft_decoding::{
+    deserialize::{deserialize_column, ByteRecordGeneric},
+    inference::{infer, merge_schema},
+};
+use daft_file_reader::{FileFormatReader, ReadPushdowns};
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use snafu::ResultExt;
+
+use crate::{InvalidXlsxFileSnafu, JoinSnafu};
+
+/// Number of rows of a sheet that are decoded into each table.
+const CHUNK_SIZE: usize = 64 * 1024;
+const DEFAULT_COLUMN_PREFIX: &str = "column_";
+/// Largest integer up to which all integers can be represented exactly as floats, which are how numbers are stored.
+const MAX_EXACT_FLOAT_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// Options for reading a sheet of an XLSX file.
+#[derive(Clone, Debug)]
+pub struct XlsxReadOptions {
+    /// Name of the sheet to read, which takes precedence over `sheet_index`.
+    pub sheet_name: Option<String>,
+    /// Index of the sheet to read, starting from 0. The first sheet is read if neither the name nor the index is set.
+    pub sheet_index: Option<usize>,
+    /// Index of the row of the sheet that holds the column names, starting from 0. Only the rows below it are read. If
+    /// it's `None`, all rows are read and the columns are named `column_1`, `column_2`, etc.
+    pub header_row: Option<usize>,
+    /// Schema of the sheet, whose types apply to the columns of the same names rather than being inferred. If there's
+    /// no header row, the columns are named by the fields of the schema in order.
+    pub schema: Option<SchemaRef>,
+}
+
+impl Default for XlsxReadOptions {
+    fn default() -> Self {
+        Self {
+            sheet_name: None,
+            sheet_index: None,
+            header_row: Some(0),
+            schema: None,
+        }
+    }
+}
+
+#[async_trait]
+impl FileFormatReader for XlsxReadOptions {
+    const FORMAT_NAME: &'static str = "XLSX";
+
+    /// Rows of the sheet below its header row, and the schema of the sheet.
+    type Metadata = (Vec<Vec<Data>>, SchemaRef);
+
+    async fn read_metadata(
+        &self,
+        uri: &str,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<(Self::Metadata, SchemaRef)> {
+        let sheet = read_sheet(uri, self.clone(), io_client, io_stats).await?;
+        let schema = Arc::new(sheet.schema);
+        Ok(((sheet.rows, schema.clone()), schema))
+    }
+
+    /// Decodes the rows of the sheet in chunks of [`CHUNK_SIZE`] rows.
+    async fn decode(
+        &self,
+        _uri: &str,
+        (rows, file_schema): Self::Metadata,
+        read_schema: SchemaRef,
+        pushdowns: &ReadPushdowns,
+        _io_client: Arc<IOClient>,
+        _io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<BoxStream<'static, DaftResult<Table>>> {
+        let projection = read_schema
+            .fields
+            .keys()
+            .map(|name| file_schema.fields.get_index_of(name).unwrap())
+            .collect::<Vec<_>>();
+        let num_rows = pushdowns
+            .decode_limit()
+            .map_or(rows.len(), |limit| limit.min(rows.len()));
+        let rows = Arc::new(rows);
+        let tables = futures::stream::iter((0..num_rows).step_by(CHUNK_SIZE)).map(move |start| {
+            let rows = &rows[start..(start + CHUNK_SIZE).min(num_rows)];
+            decode_rows(rows, &projection, read_schema.clone())
+        });
+        Ok(tables.boxed())
+    }
+}
+
+/// Decodes the cells of the columns at `projection` of `rows` into a table of schema `schema`.
+fn decode_rows(rows: &[Vec<Data>], projection: &[usize], schema: SchemaRef) -> DaftResult<Table> {
+    let records = rows
+        .iter()
+        .map(|row| {
+            XlsxRecord(
+                projection
+                    .iter()
+                    .zip(schema.fields.values())
+                    .map(|(i, field)| cell_text(&row[*i], Some(field.dtype == DataType::Date)))
+                    .collect(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let series = schema
+        .fields
+        .values()
+        .enumerate()
+        .map(|(i, field)| {
+            let array = deserialize_column(&records, i, field.dtype.to_arrow()?, 0)?;
+            Series::try_from_field_and_arrow_array(
+                Arc::new(field.clone()),
+                cast_array_for_daft_if_needed(array),
+            )
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Table::new_with_size(schema, series, records.len())
+}
+
+/// The text of the cells of a row, which is decoded like the fields of a CSV record.
+struct XlsxRecord(Vec<Option<String>>);
+
+impl ByteRecordGeneric for XlsxRecord {
+    fn get(&self, index: usize) -> Option<&[u8]> {
+        self.0.get(index)?.as_deref().map(str::as_bytes)
+    }
+}
+
+/// Returns the text that the type of `cell` is inferred from and its value is decoded from, which is `None` for empty
+/// and error cells. Date and time cells are written as dates if `as_date` is true and as timestamps if it's false, and
+/// as dates only if they're at midnight if it's `None`.
+fn cell_text(cell: &Data, as_date: Option<bool>) -> Option<String> {
+    match cell {
+        Data::Empty | Data::Error(_) => None,
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => Some(s.clone()),
+        Data::Int(i) => Some(i.to_string()),
+        // Numbers are stored as floats, so integral numbers are written as integers for them to be inferred as such.
+        Data::Float(f) if f.fract() == 0.0 && f.abs() <= MAX_EXACT_FLOAT_INTEGER => {
+            Some((*f as i64).to_string())
+        }
+        Data::Float(f) => Some(f.to_string()),
+        Data::Bool(b) => Some(b.to_string()),
+        Data::DateTime(dt) if dt.is_duration() => dt.as_duration().map(|d| d.to_string()),
+        Data::DateTime(dt) => dt.as_datetime().map(|dt| {
+            if as_date.unwrap_or(dt.time() == chrono::NaiveTime::MIN) {
+                dt.format("%Y-%m-%d").to_string()
+            } else {
+                dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+            }
+        }),
+    }
+}
+
+/// The rows of a sheet below its header row.
+struct Sheet {
+    schema: Schema,
+    rows: Vec<Vec<Data>>,
+}
+
+/// Fetches the XLSX file at `uri` and parses the sheet that is selected by `options`.
+async fn read_sheet(
+    uri: &str,
+    options: XlsxReadOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Sheet> {
+    let bytes = io_client
+        .single_url_get(uri.to_string(), None, io_stats)
+        .await?
+        .bytes()
+        .await?;
+    let uri = uri.to_string();
+    // Workbooks are compressed XML, whose parsing is CPU-bound.
+    tokio::task::spawn_blocking(move || parse_sheet(&uri, bytes, &options))
+        .await
+        .context(JoinSnafu)?
+}
+
+fn parse_sheet(uri: &str, bytes: Bytes, options: &XlsxReadOptions) -> DaftResult<Sheet> {
+    let mut workbook: Xlsx<_> =
+        open_workbook_from_rs(Cursor::new(bytes)).context(InvalidXlsxFileSnafu { path: uri })?;
+    let sheet_names = workbook.sheet_names();
+    let sheet_name = match (&options.sheet_name, options.sheet_index) {
+        (Some(sheet_name), _) => sheet_names.iter().find(|name| *name == sheet_name),
+        (None, sheet_index) => sheet_names.get(sheet_index.unwrap_or(0)),
+    }
+    .cloned()
+    .ok_or_else(|| crate::Error::SheetNotFound {
+        path: uri.to_string(),
+        sheet: match (&options.sheet_name, options.sheet_index) {
+            (Some(sheet_name), _) => sheet_name.clone(),
+            (None, sheet_index) => format!("at index {}", sheet_index.unwrap_or(0)),
+        },
+        sheet_names: sheet_names.clone(),
+    })?;
+    let range = workbook
+        .worksheet_range(&sheet_name)
+        .context(InvalidXlsxFileSnafu { path: uri })?;
+
+    // The range of a sheet starts at its first non-empty row and column.
+    let first_row = range.start().map_or(0, |(row, _)| row as usize);
+    let header = options
+        .header_row
+        .and_then(|header_row| header_row.checked_sub(first_row))
+        .and_then(|header_row| range.rows().nth(header_row));
+    let names = (0..range.width())
+        .map(|i| {
+            let name = match (header, &options.schema) {
+                (Some(header), _) => cell_text(&header[i], None),
+                (None, Some(schema)) if options.header_row.is_none() => {
+                    schema.fields.get_index(i).map(|(name, _)| name.clone())
+                }
+                _ => None,
+            };
+            name.filter(|name| !name.is_empty())
+                .unwrap_or_else(|| format!("{}{}", DEFAULT_COLUMN_PREFIX, i + 1))
+        })
+        .collect::<Vec<_>>();
+    let rows = range
+        .rows()
+        .skip(
+            options
+                .header_row
+                .map_or(0, |header_row| (header_row + 1).saturating_sub(first_row)),
+        )
+        .filter(|row| row.iter().any(|cell| !matches!(cell, Data::Empty)))
+        .map(|row| row.to_vec())
+        .collect::<Vec<_>>();
+
+    // Types are inferred from the text of the cells, like the fields of CSV files.
+    let mut column_types = vec![HashSet::new(); names.len()];
+    for row in &rows {
+        for (cell, types) in row.iter().zip(column_types.iter_mut()) {
+            match cell_text(cell, None) {
+                Some(text) => types.insert(infer(text.as_bytes())),
+                None => types.insert(arrow2::datatypes::DataType::Null),
+            };
+        }
+    }
+    column_types.iter_mut().for_each(merge_datetime_types);
+    let fields = merge_schema(&names, &mut column_types)
+        .iter()
+        .map(|field| {
+            match options
+                .schema
+                .as_ref()
+                .and_then(|schema| schema.fields.get(&field.name))
+            {
+                Some(schema_field) => schema_field.clone(),
+                None => Field::from(field),
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(Sheet {
+        schema: Schema::new(fields)?,
+        rows,
+    })
+}
+
+/// Merges the types of the date and time cells of a column, which are inferred as dates for cells at midnight and as
+/// timestamps of the precision of their fractional seconds otherwise, into a timestamp type of the finest precision.
+fn merge_datetime_types(types: &mut HashSet<arrow2::datatypes::DataType>) {
+    use arrow2::datatypes::{DataType, TimeUnit};
+
+    let precision = |time_unit: &TimeUnit| match time_unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    };
+    let Some(time_unit) = types
+        .iter()
+        .filter_map(|dtype| match dtype {
+            DataType::Timestamp(time_unit, None) => Some(*time_unit),
+            _ => None,
+        })
+        .max_by_key(precision)
+    else {
+        return;
+    };
+    types.retain(|dtype| !matches!(dtype, DataType::Date32 | DataType::Timestamp(_, None)));
+    types.insert(DataType::Timestamp(time_unit, None));
+}
+
+/// Reads the schema of the sheet of the XLSX file at `uri` that is selected by `options`, inferring the types of its
+/// columns from all of its rows.
+pub fn read_xlsx_schema(
+    uri: &str,
+    options: XlsxReadOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let runtime_handle = get_runtime(true)?;
+    let sheet = runtime_handle
+        .block_on_current_thread(async { read_sheet(uri, options, io_client, io_stats).await })?;
+    Ok(sheet.schema)
+}
+
+pub fn read_xlsx_schema_bulk(
+    uris: &[&str],
+    options: XlsxReadOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<Schema>> {
+    let runtime_handle = get_runtime(true)?;
+    let sheets = runtime_handle.block_on_current_thread(async move {
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, options, io_client, io_stats) = (
+                uri.to_string(),
+                options.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move { read_sheet(&uri, options, io_client, io_stats).await })
+        }));
+        task_stream
+            .buffered(num_parallel_tasks)
+            .try_collect::<Vec<_>>()
+            .await
+            .context(JoinSnafu)
+    })?;
+    sheets.into_iter().map(|sheet| Ok(sheet?.schema)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, sync::Arc};
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{col, lit};
+    use daft_file_reader::{read_file, read_files, ReadPushdowns};
+    use daft_io::{IOClient, IOConfig};
+    use rstest::rstest;
+
+    use super::{read_xlsx_schema, XlsxReadOptions};
+
+    /// Writes an XLSX file whose sheets have the given names and `<sheetData>` contents. Cells of style 1 are dates
+    /// and cells of style 2 are timestamps.
+    fn write_workbook(path: &std::path::Path, sheets: &[(&str, &str)]) {
+        let mut zip = zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+        let options = zip::write::FileOptions::default();
+        let mut add_file = |name: &str, contents: &str| {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(contents.as_bytes()).unwrap();
+        };
+        let ns = r#"xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main""#;
+        let rel_ns = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+        add_file(
+            "xl/workbook.xml",
+            &format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?><workbook {ns} xmlns:r="{rel_ns}"><sheets>{}</sheets></workbook>"#,
+                sheets
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (name, _))| format!(
+                        r#"<sheet name="{name}" sheetId="{}" r:id="rId{}"/>"#,
+                        i + 1,
+                        i + 1
+                    ))
+                    .collect::<Vec<_>>()
+                    .concat()
+            ),
+        );
+        add_file(
+            "xl/_rels/workbook.xml.rels",
+            &format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{}</Relationships>"#,
+                (1..=sheets.len())
+                    .map(|i| format!(
+                        r#"<Relationship Id="rId{i}" Type="{rel_ns}/worksheet" Target="worksheets/sheet{i}.xml"/>"#
+                    ))
+                    .collect::<Vec<_>>()
+                    .concat()
+            ),
+        );
+        add_file(
+            "xl/styles.xml",
+            &format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?><styleSheet {ns}><cellXfs count="3"><xf numFmtId="0"/><xf numFmtId="14"/><xf numFmtId="22"/></cellXfs></styleSheet>"#
+            ),
+        );
+        for (i, (_, sheet_data)) in sheets.iter().enumerate() {
+            add_file(
+                &format!("xl/worksheets/sheet{}.xml", i + 1),
+                &format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?><worksheet {ns}><sheetData>{sheet_data}</sheetData></worksheet>"#
+                ),
+            );
+        }
+        zip.finish().unwrap();
+    }
+
+    fn string_cell(reference: &str, value: &str) -> String {
+        format!(r#"<c r="{reference}" t="inlineStr"><is><t>{value}</t></is></c>"#)
+    }
+
+    fn write_test_file(dir: &tempfile::TempDir) -> String {
+        let path = dir.path().join("test.xlsx");
+        let header = [
+            ("A", "id"),
+            ("B", "name"),
+            ("C", "amount"),
+            ("D", "day"),
+            ("E", "flag"),
+        ]
+        .iter()
+        .map(|(column, name)| string_cell(&format!("{column}2"), name))
+        .collect::<String>();
+        let data = format!(
+            r#"<row r="1">{}</row><row r="2">{header}</row>
+            <row r="3"><c r="A3"><v>1</v></c>{}<c r="C3"><v>1.5</v></c><c r="D3" s="1"><v>45322</v></c><c r="E3" t="b"><v>1</v></c></row>
+            <row r="4"><c r="A4"><v>2</v></c>{}<c r="C4"><v>-20</v></c><c r="D4" s="2"><v>45322.5</v></c></row>
+            <row r="6"><c r="A6"><v>3</v></c><c r="E6" t="b"><v>0</v></c></row>"#,
+            string_cell("A1", "Quarterly report"),
+            string_cell("B3", "ann"),
+            string_cell("B4", ""),
+        );
+        write_workbook(&path, &[("Summary", ""), ("Data", &data)]);
+        path.to_string_lossy().to_string()
+    }
+
+    fn test_options() -> XlsxReadOptions {
+        XlsxReadOptions {
+            sheet_name: Some("Data".to_string()),
+            header_row: Some(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_xlsx_read() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = write_test_file(&dir);
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let table = read_file(
+            &test_options(),
+            &uri,
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            true,
+        )?;
+        assert_eq!(
+            table.schema.as_ref(),
+            &Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+                Field::new("amount", DataType::Float64),
+                // Dates are merged with timestamps in the same column.
+                Field::new("day", DataType::Timestamp(TimeUnit::Seconds, None)),
+                Field::new("flag", DataType::Boolean),
+            ])?
+        );
+        // Empty rows are skipped.
+        assert_eq!(table.len(), 3);
+        let names = table.get_column("name")?.utf8()?;
+        assert_eq!(
+            names.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("ann"), Some(""), None]
+        );
+        let amounts = table.get_column("amount")?.f64()?;
+        assert_eq!(
+            amounts.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1.5), Some(-20.0), None]
+        );
+        assert_eq!(
+            table.get_column("day")?.str_value(0)?,
+            "2024-01-31 00:00:00"
+        );
+        assert_eq!(
+            table.get_column("day")?.str_value(1)?,
+            "2024-01-31 12:00:00"
+        );
+        assert_eq!(table.get_column("flag")?.str_value(2)?, "false");
+
+        // Predicate columns don't have to be in the projection.
+        let table = read_file(
+            &test_options(),
+            &uri,
+            ReadPushdowns::new(
+                Some(&["name", "id"]),
+                Some(1),
+                Some(col("amount").lt(lit(0.0))),
+            ),
+            io_client,
+            None,
+            true,
+        )?;
+        assert_eq!(table.schema.names(), vec!["name", "id"]);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get_column("id")?.str_value(0)?, "2");
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_xlsx_read_sheet_selection(
+        #[values(None, Some(0), Some(1))] header_row: Option<usize>,
+    ) -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.xlsx");
+        let data = format!(
+            r#"<row r="1">{}<c r="B1"><v>2024</v></c></row><row r="2"><c r="A2"><v>1</v></c><c r="B2"><v>2</v></c></row>"#,
+            string_cell("A1", "a"),
+        );
+        write_workbook(&path, &[("First", &data), ("Second", "")]);
+        let uri = path.to_string_lossy().to_string();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let options = XlsxReadOptions {
+            sheet_index: Some(0),
+            header_row,
+            ..Default::default()
+        };
+        let schema = read_xlsx_schema(&uri, options.clone(), io_client.clone(), None)?;
+        let tables = read_files(
+            vec![(uri.as_str(), options.clone()), (uri.as_str(), options)],
+            ReadPushdowns::default(),
+            io_client.clone(),
+            None,
+            2,
+            true,
+        )?;
+        match header_row {
+            Some(0) => {
+                assert_eq!(schema.names(), vec!["a", "2024"]);
+                assert_eq!(tables[0].len(), 1);
+            }
+            Some(_) => {
+                assert_eq!(schema.names(), vec!["1", "2"]);
+                assert_eq!(tables[1].len(), 0);
+            }
+            None => {
+                // Without a header row, the columns of mixed types are read as strings.
+                assert_eq!(
+                    schema,
+                    Schema::new(vec![
+                        Field::new("column_1", DataType::Utf8),
+                        Field::new("column_2", DataType::Int64),
+                    ])?
+                );
+                assert_eq!(tables[0].len(), 2);
+            }
+        }
+
+        let options = XlsxReadOptions {
+            sheet_name: Some("Missing".to_string()),
+            ..Default::default()
+        };
+        assert!(read_xlsx_schema(&uri, options, io_client, None).is_err());
+        Ok(())
+    }
+}
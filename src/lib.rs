@@ -110,6 +110,10 @@ pub mod pylib {
         daft_parquet::register_modules(_py, m)?;
         daft_csv::register_modules(_py, m)?;
         daft_json::register_modules(_py, m)?;
+        daft_avro::register_modules(_py, m)?;
+        daft_orc::register_modules(_py, m)?;
+        daft_xlsx::register_modules(_py, m)?;
+        daft_ipc::register_modules(_py, m)?;
         daft_plan::register_modules(_py, m)?;
         daft_micropartition::register_modules(_py, m)?;
         daft_scan::register_modules(_py, m)?;
@@ -43,6 +43,10 @@ pub enum Error {
     #[error("Operation would exceed memory use threshold")]
     WouldOverAllocate,
 
+    /// When the CRC32 checksum of a page doesn't match the checksum in its header
+    #[error("Page checksum mismatch: expected CRC32 {expected:08x} but got {actual:08x}")]
+    ChecksumMismatch { expected: u32, actual: u32 },
+
     /// When a transport error occurs when reading data
     #[error("Transport error: {0}")]
     Transport(String),
@@ -81,7 +81,7 @@ fn read_dict_page<R: Read + Seek>(
 ) -> Result<CompressedDictPage, Error> {
     let page_header = read_page(reader, start, length, buffer, data)?;
 
-    let page = finish_page(page_header, data, compression, descriptor, None)?;
+    let page = finish_page(page_header, data, compression, descriptor, None, false)?;
     if let CompressedPage::Dict(page) = page {
         Ok(page)
     } else {
@@ -146,6 +146,7 @@ impl<R: Read + Seek> IndexedPageReader<R> {
             self.compression,
             &self.descriptor,
             Some(selected_rows),
+            false,
         )
     }
 
@@ -85,6 +85,9 @@ pub struct PageReader<R: Read> {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Whether to verify the CRC32 checksums recorded in page headers
+    verify_checksums: bool,
 }
 
 impl<R: Read> PageReader<R> {
@@ -121,9 +124,16 @@ impl<R: Read> PageReader<R> {
             pages_filter,
             scratch,
             max_page_size,
+            verify_checksums: false,
         }
     }
 
+    /// Sets whether to verify the CRC32 checksums recorded in page headers, which is off by default.
+    pub fn with_checksum_verification(mut self, verify_checksums: bool) -> Self {
+        self.verify_checksums = verify_checksums;
+        self
+    }
+
     /// Returns the reader and this Readers' interval buffer
     pub fn into_inner(self) -> (R, Vec<u8>) {
         (self.reader, self.scratch)
@@ -217,6 +227,7 @@ pub(super) fn build_page<R: Read>(
         reader.compression,
         &reader.descriptor,
         None,
+        reader.verify_checksums,
     )
     .map(Some)
 }
@@ -227,7 +238,11 @@ pub(super) fn finish_page(
     compression: Compression,
     descriptor: &Descriptor,
     selected_rows: Option<Vec<Interval>>,
+    verify_checksums: bool,
 ) -> Result<CompressedPage> {
+    if verify_checksums {
+        verify_page_crc(&page_header, data)?;
+    }
     let type_ = page_header.type_.try_into()?;
     let uncompressed_page_size = page_header.uncompressed_page_size.try_into()?;
     match type_ {
@@ -281,6 +296,18 @@ pub(super) fn finish_page(
     }
 }
 
+/// Verifies the page's CRC32 checksum, which covers the page's (compressed) data, if the writer recorded one.
+fn verify_page_crc(page_header: &ParquetPageHeader, data: &[u8]) -> Result<()> {
+    if let Some(crc) = page_header.crc {
+        let expected = crc as u32;
+        let actual = crc32fast::hash(data);
+        if actual != expected {
+            return Err(Error::ChecksumMismatch { expected, actual });
+        }
+    }
+    Ok(())
+}
+
 pub(super) fn get_page_header(header: &ParquetPageHeader) -> Result<Option<DataPageHeader>> {
     let type_ = header.type_.try_into()?;
     Ok(match type_ {
@@ -304,3 +331,59 @@ pub(super) fn get_page_header(header: &ParquetPageHeader) -> Result<Option<DataP
         _ => None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use parquet_format_safe::{DictionaryPageHeader, Encoding, PageType};
+
+    use super::*;
+    use crate::schema::types::{PhysicalType, PrimitiveType};
+
+    #[test]
+    fn test_verify_page_crc() {
+        let data = b"page data";
+        let header = |crc: Option<i32>| {
+            ParquetPageHeader::new(PageType::DATA_PAGE, 9, 9, crc, None, None, None, None)
+        };
+        assert!(verify_page_crc(&header(None), data).is_ok());
+        assert!(verify_page_crc(&header(Some(crc32fast::hash(data) as i32)), data).is_ok());
+        assert!(matches!(
+            verify_page_crc(&header(Some(0)), data),
+            Err(Error::ChecksumMismatch { expected: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_finish_page_verifies_crc_only_if_enabled() {
+        let descriptor = Descriptor {
+            primitive_type: PrimitiveType::from_physical("a".to_string(), PhysicalType::Int32),
+            max_def_level: 0,
+            max_rep_level: 0,
+        };
+        let header = ParquetPageHeader::new(
+            PageType::DICTIONARY_PAGE,
+            9,
+            9,
+            Some(0),
+            None,
+            None,
+            Some(DictionaryPageHeader::new(1, Encoding::PLAIN, None)),
+            None,
+        );
+        let finish = |verify_checksums| {
+            finish_page(
+                header.clone(),
+                &mut b"page data".to_vec(),
+                Compression::Uncompressed,
+                &descriptor,
+                None,
+                verify_checksums,
+            )
+        };
+        assert!(matches!(finish(false), Ok(CompressedPage::Dict(_))));
+        assert!(matches!(
+            finish(true),
+            Err(Error::ChecksumMismatch { expected: 0, .. })
+        ));
+    }
+}
@@ -48,15 +48,19 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
         scratch,
         pages_filter,
         max_header_size,
+        false,
     ))
 }
 
+/// Returns an owned stream of compressed data pages from a reader that begins at the start of the column,
+/// verifying the CRC32 checksums recorded in page headers if `verify_checksums` is set.
 pub fn get_owned_page_stream_from_column_start<R: AsyncRead + Unpin + Send>(
     column_metadata: &ColumnChunkMetaData,
     reader: R,
     scratch: Vec<u8>,
     pages_filter: PageFilter,
     max_header_size: usize,
+    verify_checksums: bool,
 ) -> Result<impl Stream<Item = Result<CompressedPage>>> {
     let page_metadata: PageMetaData = column_metadata.into();
     Ok(_get_owned_page_stream(
@@ -67,6 +71,7 @@ pub fn get_owned_page_stream_from_column_start<R: AsyncRead + Unpin + Send>(
         scratch,
         pages_filter,
         max_header_size,
+        verify_checksums,
     ))
 }
 
@@ -88,9 +93,11 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
         scratch,
         pages_filter,
         max_page_size,
+        false,
     ))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn _get_page_stream<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
     total_num_values: i64,
@@ -99,6 +106,7 @@ fn _get_page_stream<R: AsyncRead + Unpin + Send>(
     mut scratch: Vec<u8>,
     pages_filter: PageFilter,
     max_page_size: usize,
+    verify_checksums: bool,
 ) -> impl Stream<Item = Result<CompressedPage>> + '_ {
     let mut seen_values = 0i64;
     try_stream! {
@@ -142,11 +150,13 @@ fn _get_page_stream<R: AsyncRead + Unpin + Send>(
                 compression,
                 &descriptor,
                 None,
+                verify_checksums,
             )?;
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn _get_owned_page_stream<R: AsyncRead + Unpin + Send>(
     mut reader: R,
     total_num_values: i64,
@@ -155,6 +165,7 @@ fn _get_owned_page_stream<R: AsyncRead + Unpin + Send>(
     mut scratch: Vec<u8>,
     pages_filter: PageFilter,
     max_page_size: usize,
+    verify_checksums: bool,
 ) -> impl Stream<Item = Result<CompressedPage>> {
     let mut seen_values = 0i64;
     try_stream! {
@@ -199,6 +210,7 @@ fn _get_owned_page_stream<R: AsyncRead + Unpin + Send>(
                 compression,
                 &descriptor,
                 None,
+                verify_checksums,
             )?;
         }
     }
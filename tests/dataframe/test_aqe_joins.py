@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+import daft
+from daft import col
+
+
+def make_df(keys: list[int], num_partitions: int) -> daft.DataFrame:
+    # Exploding hides the size of the data from the static planner, so that it plans a hash join which Adaptive Query
+    # Execution then re-plans once it has materialized the inputs.
+    return (
+        daft.from_pydict({"key": [[k] for k in keys], "idx": list(range(len(keys)))})
+        .into_partitions(num_partitions)
+        .explode(col("key"))
+    )
+
+
+def expected_inner_join(left_keys: list[int], right_keys: list[int]) -> list[tuple[int, int, int]]:
+    return sorted(
+        (lk, li, ri) for li, lk in enumerate(left_keys) for ri, rk in enumerate(right_keys) if lk == rk
+    )
+
+
+def collect_join(left: daft.DataFrame, right: daft.DataFrame) -> tuple[list[tuple[int, int, int]], int]:
+    df = left.join(right, on="key").collect()
+    result = df.to_pydict()
+    rows = sorted(zip(result["key"], result["idx"], result["right.idx"]))
+    return rows, df._result.num_partitions()
+
+
+def test_aqe_switches_to_broadcast_join():
+    left_keys = [1, 2, 3, 4]
+    right_keys = [i % 10 for i in range(1000)]
+    with daft.execution_config_ctx(enable_aqe=True):
+        rows, num_partitions = collect_join(make_df(left_keys, 4), make_df(right_keys, 2))
+    assert rows == expected_inner_join(left_keys, right_keys)
+    # A hash join would have repartitioned both sides into 4 partitions, while a broadcast join keeps the 2 partitions
+    # of the larger side.
+    assert num_partitions == 2
+
+
+def test_aqe_coalesces_hash_join_partitions():
+    left_keys = list(range(100))
+    right_keys = list(range(0, 200, 2))
+    with daft.execution_config_ctx(enable_aqe=True, broadcast_join_size_bytes_threshold=0):
+        rows, num_partitions = collect_join(make_df(left_keys, 8), make_df(right_keys, 8))
+    assert rows == expected_inner_join(left_keys, right_keys)
+    # All partitions fit into a single partition of the default target size.
+    assert num_partitions == 1
+
+
+def test_aqe_splits_skewed_hash_join_partitions():
+    left_keys = [0] * 10000 + list(range(1, 100))
+    right_keys = list(range(100))
+    with daft.execution_config_ctx(
+        enable_aqe=True,
+        broadcast_join_size_bytes_threshold=0,
+        aqe_target_partition_size_bytes=1024,
+        aqe_skewed_partition_factor=2.0,
+    ):
+        rows, num_partitions = collect_join(make_df(left_keys, 8), make_df(right_keys, 8))
+    assert rows == expected_inner_join(left_keys, right_keys)
+    # The partition holding all rows with key 0 is split into multiple partitions.
+    assert num_partitions > 8
@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import os
+
+import pytest
+
+import daft
+from daft import col
+from daft.daft import LogicalPlanBuilder as _LogicalPlanBuilder
+
+
+@pytest.fixture(autouse=True)
+def clear_result_cache():
+    _LogicalPlanBuilder.clear_result_cache()
+    yield
+    _LogicalPlanBuilder.clear_result_cache()
+
+
+def _pipeline(path: str) -> daft.DataFrame:
+    return daft.read_parquet(path).where(col("a") > 1).with_column("b", col("a") * 2)
+
+
+def test_cache_reused_by_identical_plan(tmp_path) -> None:
+    daft.from_pydict({"a": [1, 2, 3]}).write_parquet(str(tmp_path))
+    cached = _pipeline(str(tmp_path)).cache()
+
+    df = _pipeline(str(tmp_path)).sort("a")
+    assert "Filter" not in df._builder.optimize().pretty_print()
+    assert df.to_pydict() == cached.sort("a").to_pydict() == {"a": [2, 3], "b": [4, 6]}
+
+
+def test_cache_not_reused_after_files_change(tmp_path) -> None:
+    daft.from_pydict({"a": [1, 2, 3]}).write_parquet(str(tmp_path))
+    _pipeline(str(tmp_path)).cache()
+    daft.from_pydict({"a": [4]}).write_parquet(str(tmp_path))
+
+    df = _pipeline(str(tmp_path)).sort("a")
+    assert "Filter" in df._builder.optimize().pretty_print()
+    assert df.to_pydict() == {"a": [2, 3, 4], "b": [4, 6, 8]}
+
+
+def test_cache_not_reused_after_same_size_overwrite(tmp_path) -> None:
+    path = tmp_path / "data.csv"
+    path.write_text("a\n1\n2\n3\n")
+    daft.read_csv(str(path)).where(col("a") > 1).cache()
+    path.write_text("a\n4\n5\n6\n")
+    stat = path.stat()
+    os.utime(path, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000_000))
+
+    df = daft.read_csv(str(path)).where(col("a") > 1)
+    assert "Filter" in df._builder.optimize().pretty_print()
+    assert df.to_pydict() == {"a": [4, 5, 6]}
+
+
+def test_cache_not_reused_with_different_format_config(tmp_path) -> None:
+    path = tmp_path / "data.csv"
+    path.write_text("a\n1\n2\n")
+    daft.read_csv(str(path), comment="#").cache()
+
+    df = daft.read_csv(str(path))
+    assert "GlobScanOperator" in df._builder.optimize().pretty_print()
+    assert df.to_pydict() == {"a": [1, 2]}
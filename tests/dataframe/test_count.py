@@ -1,5 +1,7 @@
 # corner cases for df.count()
 
+import pyarrow as pa
+import pyarrow.parquet as papq
 import pytest
 
 import daft
@@ -28,3 +30,26 @@ def test_count_mixed_star() -> None:
     df = daft.from_pydict({"a": [1, 2, 3, 4], "b": [1, None, 3, None]})
     with pytest.raises(ValueError, match=r"Cannot call count\(\) with both \* and column names"):
         df.count("*", "a")
+
+
+def test_count_parquet_from_metadata(tmp_path) -> None:
+    for i in range(3):
+        papq.write_table(pa.table({"a": list(range(i * 10, i * 10 + 5 + i))}), tmp_path / f"{i}.parquet")
+    df = daft.read_parquet(str(tmp_path / "*.parquet"))
+
+    # The row counts are read from the Parquet footers while optimizing, so the files aren't scanned.
+    assert "GlobScanOperator" not in df.count()._builder.optimize().pretty_print()
+    assert df.count().to_pydict() == {"count": [5 + 6 + 7]}
+    assert df.count_rows() == 5 + 6 + 7
+    assert df.agg(col("a").min().alias("min"), col("a").max().alias("max")).to_pydict() == {
+        "min": [0],
+        "max": [26],
+    }
+
+
+def test_count_parquet_with_filter_scans_files(tmp_path) -> None:
+    papq.write_table(pa.table({"a": [1, 2, 3, 4]}), tmp_path / "0.parquet")
+    df = daft.read_parquet(str(tmp_path / "*.parquet")).where(col("a") > 2)
+
+    assert "GlobScanOperator" in df.count()._builder.optimize().pretty_print()
+    assert df.count().to_pydict() == {"count": [2]}
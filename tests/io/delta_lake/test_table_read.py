@@ -1,6 +1,10 @@
 from __future__ import annotations
 
+import io
+import re
 import sys
+import time
+from datetime import datetime, timezone
 
 import pyarrow as pa
 import pytest
@@ -94,3 +98,89 @@ def test_deltalake_read_row_group_splits_with_limit(tmp_path, base_table):
         df = df.limit(2)
         df.collect()
         assert len(df) == 2, "Length of non-materialized data when read through deltalake should be correct"
+
+
+def test_deltalake_read_version(tmp_path, base_table):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    deltalake.write_deltalake(path, base_table)
+    deltalake.write_deltalake(path, base_table, mode="append")
+
+    assert len(daft.read_deltalake(str(path)).collect()) == 2 * len(base_table)
+    df = daft.read_deltalake(str(path), version=0)
+    assert_pyarrow_tables_equal(df.to_arrow(), base_table)
+
+    with pytest.raises(ValueError):
+        daft.read_deltalake(str(path), version=2).collect()
+
+
+def test_deltalake_read_timestamp(tmp_path, base_table):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    deltalake.write_deltalake(path, base_table)
+    time.sleep(0.01)
+    between_commits = datetime.now(timezone.utc)
+    time.sleep(0.01)
+    deltalake.write_deltalake(path, base_table, mode="append")
+
+    df = daft.read_deltalake(str(path), timestamp=between_commits)
+    assert_pyarrow_tables_equal(df.to_arrow(), base_table)
+    df = daft.read_deltalake(str(path), timestamp=between_commits.isoformat())
+    assert_pyarrow_tables_equal(df.to_arrow(), base_table)
+
+
+def test_deltalake_read_checkpoint(tmp_path, base_table):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    deltalake.write_deltalake(path, base_table)
+    deltalake.write_deltalake(path, base_table, mode="overwrite")
+    deltalake.DeltaTable(path).create_checkpoint()
+    deltalake.write_deltalake(path, base_table, mode="append")
+
+    # The removed files of the overwrite are tombstones of the checkpoint, and the append is replayed after it.
+    df = daft.read_deltalake(str(path))
+    assert_pyarrow_tables_equal(df.to_arrow(), pa.concat_tables([base_table, base_table]))
+
+
+def test_deltalake_read_limit_pushdown(tmp_path, base_table):
+    deltalake = pytest.importorskip("deltalake")
+    path = tmp_path / "some_table"
+    for _ in range(3):
+        deltalake.write_deltalake(path, base_table, mode="append")
+
+    def num_scan_tasks(df: daft.DataFrame) -> str:
+        out = io.StringIO()
+        df.explain(show_all=True, file=out)
+        return re.search(r"Num Scan Tasks = (\d+)", out.getvalue()).group(1)
+
+    # Scan tasks aren't merged, so every file is a scan task unless the limit prunes it.
+    with daft.execution_config_ctx(scan_tasks_min_size_bytes=0):
+        assert num_scan_tasks(daft.read_deltalake(str(path))) == "3"
+        df = daft.read_deltalake(str(path)).limit(2)
+        assert num_scan_tasks(df) == "1"
+        assert len(df.to_pydict()["a"]) == 2
+
+
+@pytest.mark.s3
+def test_deltalake_read_s3_credentials_from_env(s3_path, base_table, monkeypatch):
+    from daft.io._delta_lake import _with_s3_credentials_from_env
+
+    deltalake = pytest.importorskip("deltalake")
+    path, io_config, catalog_table = s3_path
+    if catalog_table is not None:
+        pytest.skip("catalog tables provide their own credentials")
+    deltalake.write_deltalake(path, base_table, storage_options=io_config_to_storage_options(io_config, path))
+
+    monkeypatch.setenv("AWS_ACCESS_KEY_ID", io_config.s3.key_id)
+    monkeypatch.setenv("AWS_SECRET_ACCESS_KEY", io_config.s3.access_key)
+    monkeypatch.setenv("AWS_SESSION_TOKEN", io_config.s3.session_token)
+    monkeypatch.setenv("AWS_REGION", io_config.s3.region_name)
+    endpoint_only = daft.io.IOConfig(s3=daft.io.S3Config(endpoint_url=io_config.s3.endpoint_url, use_ssl=False))
+    resolved = _with_s3_credentials_from_env(endpoint_only)
+    assert resolved.s3.key_id == io_config.s3.key_id
+    assert resolved.s3.access_key == io_config.s3.access_key
+    assert resolved.s3.region_name == io_config.s3.region_name
+    assert resolved.s3.endpoint_url == io_config.s3.endpoint_url
+
+    df = daft.read_deltalake(path, io_config=endpoint_only)
+    assert_pyarrow_tables_equal(df.to_arrow(), base_table)
@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+pyiceberg = pytest.importorskip("pyiceberg")
+
+PYARROW_LE_8_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) < (8, 0, 0)
+pytestmark = pytest.mark.skipif(PYARROW_LE_8_0_0, reason="iceberg only supported if pyarrow >= 8.0.0")
+
+
+from pyiceberg.catalog.sql import SqlCatalog
+
+import daft
+
+
+@pytest.fixture(scope="function")
+def local_catalog(tmpdir):
+    catalog = SqlCatalog(
+        "default",
+        **{
+            "uri": f"sqlite:///{tmpdir}/pyiceberg_catalog.db",
+            "warehouse": f"file://{tmpdir}",
+        },
+    )
+    catalog.create_namespace("default")
+    return catalog
+
+
+def test_read_from_metadata_location(local_catalog):
+    data = pa.table({"x": [1, 2, 3, 4, 5], "y": ["a", "b", "c", "d", "e"]})
+    table = local_catalog.create_table("default.test", data.schema)
+    table.append(data)
+
+    df = daft.read_iceberg(table.metadata_location)
+    assert df.to_arrow() == daft.read_iceberg(table).to_arrow()
+    assert df.sort("x").to_pydict() == data.to_pydict()
+    # Locations can be passed by the same keyword as table objects.
+    assert daft.read_iceberg(pyiceberg_table=table.metadata_location).to_arrow() == df.to_arrow()
+
+
+def test_read_from_table_location(local_catalog):
+    data = pa.table({"x": [1, 2, 3]})
+    table = local_catalog.create_table("default.test", data.schema)
+    table.append(data)
+
+    # Without a version hint, the metadata file of the highest version is read.
+    df = daft.read_iceberg(table.location())
+    assert df.sort("x").to_pydict() == {"x": [1, 2, 3]}
+
+
+def test_read_snapshot(local_catalog):
+    data = pa.table({"x": [1, 2, 3]})
+    table = local_catalog.create_table("default.test", data.schema)
+    table.append(data)
+    first_snapshot_id = table.current_snapshot().snapshot_id
+    table.append(data)
+
+    assert len(daft.read_iceberg(table.metadata_location).to_pydict()["x"]) == 6
+    df = daft.read_iceberg(table.metadata_location, snapshot_id=first_snapshot_id)
+    assert df.sort("x").to_pydict() == {"x": [1, 2, 3]}
+
+
+def test_read_with_pushdowns(local_catalog):
+    table = local_catalog.create_table("default.test", pa.schema([("x", pa.int64()), ("y", pa.string())]))
+    # Each append writes a data file whose column bounds are used to prune it.
+    for i in range(3):
+        table.append(pa.table({"x": [i * 10 + j for j in range(10)], "y": [str(i)] * 10}))
+
+    df = daft.read_iceberg(table.metadata_location).where(daft.col("x") >= 25)
+    assert sorted(df.to_pydict()["x"]) == list(range(25, 30))
+
+    df = daft.read_iceberg(table.metadata_location).select("y").limit(5)
+    assert len(df.to_pydict()["y"]) == 5
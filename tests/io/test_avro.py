@@ -0,0 +1,126 @@
+from __future__ import annotations
+
+import bz2
+import json
+import lzma
+import zlib
+
+import pyarrow as pa
+import pytest
+
+import daft
+from daft.daft import read_avro, read_avro_schema
+
+SCHEMA = {
+    "type": "record",
+    "name": "Row",
+    "fields": [
+        {"name": "id", "type": "long"},
+        {"name": "data", "type": ["null", "string"]},
+        {"name": "tags", "type": {"type": "array", "items": "string"}},
+    ],
+}
+SYNC_MARKER = b"0123456789abcdef"
+
+
+def _encode_long(value: int) -> bytes:
+    n = (value << 1) ^ (value >> 63)
+    out = bytearray()
+    while n >= 0x80:
+        out.append((n & 0x7F) | 0x80)
+        n >>= 7
+    out.append(n)
+    return bytes(out)
+
+
+def _encode_bytes(value: bytes) -> bytes:
+    return _encode_long(len(value)) + value
+
+
+def _encode_row(id: int) -> bytes:
+    # Every 10th row has a null `data` field, which is the first branch of the union.
+    data = _encode_long(0) if id % 10 == 0 else _encode_long(1) + _encode_bytes(b"aaa")
+    tags = _encode_long(1) + _encode_bytes(str(id).encode()) + _encode_long(0)
+    return _encode_long(id) + data + tags
+
+
+def _compress(codec: str, data: bytes) -> bytes:
+    if codec == "null":
+        return data
+    elif codec == "deflate":
+        compressor = zlib.compressobj(wbits=-15)
+        return compressor.compress(data) + compressor.flush()
+    elif codec == "snappy":
+        # Snappy blocks are followed by the big-endian CRC32 checksum of the uncompressed data.
+        return pa.compress(data, codec="snappy", asbytes=True) + zlib.crc32(data).to_bytes(4, "big")
+    elif codec == "zstandard":
+        return pa.compress(data, codec="zstd", asbytes=True)
+    elif codec == "bzip2":
+        return bz2.compress(data)
+    elif codec == "xz":
+        return lzma.compress(data)
+    raise ValueError(f"Unknown codec: {codec}")
+
+
+def _write_avro(path: str, codec: str, num_blocks: int, rows_per_block: int) -> None:
+    """Writes an Avro object container file with the given codec, since fastavro isn't a test dependency"""
+    out = bytearray(b"Obj\x01")
+    out += _encode_long(2)
+    out += _encode_bytes(b"avro.schema") + _encode_bytes(json.dumps(SCHEMA).encode())
+    out += _encode_bytes(b"avro.codec") + _encode_bytes(codec.encode())
+    out += _encode_long(0) + SYNC_MARKER
+    for block in range(num_blocks):
+        data = b"".join(_encode_row(id) for id in range(block * rows_per_block, (block + 1) * rows_per_block))
+        data = _compress(codec, data)
+        out += _encode_long(rows_per_block) + _encode_long(len(data)) + data + SYNC_MARKER
+    with open(path, "wb") as f:
+        f.write(out)
+
+
+def _expected(ids) -> dict:
+    return {
+        "id": list(ids),
+        "data": [None if id % 10 == 0 else "aaa" for id in ids],
+        "tags": [[str(id)] for id in ids],
+    }
+
+
+@pytest.fixture(scope="function")
+def avro_file(tmpdir):
+    """Writes 1 Avro file with 10 blocks, each of 10 rows"""
+    path = str(tmpdir / "file.avro")
+    _write_avro(path, "null", num_blocks=10, rows_per_block=10)
+    return path
+
+
+@pytest.mark.parametrize("codec", ["null", "deflate", "snappy", "zstandard", "bzip2", "xz"])
+def test_read_avro(tmpdir, codec):
+    path = str(tmpdir / "file.avro")
+    _write_avro(path, codec, num_blocks=3, rows_per_block=10)
+    df = daft.read_avro(path)
+    assert df.schema()["tags"].dtype == daft.DataType.list(daft.DataType.string())
+    assert df.to_pydict() == _expected(range(30))
+
+
+def test_read_avro_pushdowns(avro_file):
+    df = daft.read_avro(avro_file).where(daft.col("id") >= 95).select("data")
+    assert df.to_pydict() == {"data": ["aaa"] * 5}
+
+    df = daft.read_avro(avro_file).limit(15)
+    assert df.to_pydict()["id"] == list(range(15))
+
+
+def test_split_avro_read(avro_file):
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=100,
+    ):
+        df = daft.read_avro(avro_file)
+        assert df.num_partitions() > 1, "Should have more than 1 partition since we will split the file"
+        assert df.to_pydict() == _expected(range(100))
+
+
+def test_read_avro_native(avro_file):
+    assert read_avro_schema(avro_file).names() == ["id", "data", "tags"]
+    table = read_avro(avro_file, columns=["data"], num_rows=2)
+    assert table.to_arrow_record_batch().to_pydict() == {"data": [None, "aaa"]}
@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+import pytest
+
+import daft
+from daft import DataType, col
+
+
+def _write_parquet(root, directory: str, values: list[int]) -> None:
+    path = root / directory
+    path.mkdir(parents=True)
+    papq.write_table(pa.table({"x": values}), str(path / "data.parquet"))
+
+
+def _rows(df) -> list[tuple]:
+    data = df.to_pydict()
+    return sorted(zip(*data.values()), key=lambda row: tuple((v is None, v) for v in row))
+
+
+@pytest.fixture(scope="function")
+def hive_dataset(tmp_path):
+    """Writes a Parquet file to each of 4 Hive-style partition directories, one of which has a null region"""
+    _write_parquet(tmp_path, "year=2023/region=eu", [1, 2])
+    _write_parquet(tmp_path, "year=2023/region=us", [3, 4])
+    _write_parquet(tmp_path, "year=2024/region=us", [5, 6])
+    _write_parquet(tmp_path, "year=2024/region=__HIVE_DEFAULT_PARTITION__", [7, 8])
+    return tmp_path
+
+
+def test_read_parquet_hive_partitioning(hive_dataset):
+    df = daft.read_parquet(f"{hive_dataset}/**/*.parquet", hive_partitioning=True)
+    assert df.schema().column_names() == ["x", "year", "region"]
+    assert df.schema()["year"].dtype == DataType.int64()
+    assert df.schema()["region"].dtype == DataType.string()
+    assert _rows(df) == [
+        (1, 2023, "eu"),
+        (2, 2023, "eu"),
+        (3, 2023, "us"),
+        (4, 2023, "us"),
+        (5, 2024, "us"),
+        (6, 2024, "us"),
+        (7, 2024, None),
+        (8, 2024, None),
+    ]
+
+
+def test_read_parquet_hive_partitioning_prunes_files(hive_dataset):
+    with daft.execution_config_ctx(scan_tasks_min_size_bytes=0, scan_tasks_max_size_bytes=0):
+        df = daft.read_parquet(f"{hive_dataset}/**/*.parquet", hive_partitioning=True)
+        assert df.num_partitions() == 4
+
+        # Files in partitions that don't satisfy the filter are skipped without being read.
+        df = df.where((col("year") == 2024) & (col("region") == "us"))
+        assert df.num_partitions() == 1
+        assert _rows(df) == [(5, 2024, "us"), (6, 2024, "us")]
+
+
+def test_read_parquet_hive_partitioning_infers_keys_from_all_files(tmp_path):
+    _write_parquet(tmp_path, "year=2023", [1])
+    _write_parquet(tmp_path, "year=2024/month=1", [2])
+    _write_parquet(tmp_path, "year=2024/month=2.5", [3])
+
+    df = daft.read_parquet(f"{tmp_path}/**/*.parquet", hive_partitioning=True)
+    assert df.schema().column_names() == ["x", "year", "month"]
+    assert df.schema()["month"].dtype == DataType.float64()
+    assert _rows(df) == [(1, 2023, None), (2, 2024, 1.0), (3, 2024, 2.5)]
+
+
+def test_read_parquet_hive_partitioning_fails_on_values_outside_sample(tmp_path):
+    _write_parquet(tmp_path, "year=2023", [1])
+    _write_parquet(tmp_path, "year=latest", [2])
+
+    paths = [f"{tmp_path}/year=2023/data.parquet", f"{tmp_path}/year=latest/data.parquet"]
+
+    # Partition columns inferred from only the first file type `year` as an integer, which the second file's value
+    # doesn't fit, so the read fails rather than returning a null.
+    df = daft.read_parquet(paths, hive_partitioning=True, max_schema_inference_files=1)
+    with pytest.raises(Exception, match="latest"):
+        df.collect()
+
+    df = daft.read_parquet(paths, hive_partitioning=True)
+    assert df.schema()["year"].dtype == DataType.string()
+    assert _rows(df) == [(1, "2023"), (2, "latest")]
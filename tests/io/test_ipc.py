@@ -0,0 +1,84 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+import daft
+from daft.daft import read_ipc, read_ipc_schema, write_ipc
+from daft.table import MicroPartition
+
+
+@pytest.fixture(scope="function")
+def ipc_file(tmpdir):
+    """Writes 1 Arrow IPC file with 10 record batches, each of 10 rows"""
+    tbl = pa.table({"id": list(range(100)), "data": ["aaa"] * 100})
+    path = str(tmpdir / "file.arrow")
+    with pa.ipc.new_file(path, tbl.schema) as writer:
+        for batch in tbl.to_batches(max_chunksize=10):
+            writer.write_batch(batch)
+    return path
+
+
+def test_read_ipc(ipc_file):
+    df = daft.read_ipc(ipc_file)
+    assert df.to_arrow().equals(pa.ipc.open_file(ipc_file).read_all().combine_chunks())
+
+
+def test_read_ipc_pushdowns(ipc_file):
+    df = daft.read_ipc(ipc_file).where(daft.col("id") >= 95).select("data")
+    assert df.to_pydict() == {"data": ["aaa"] * 5}
+
+    df = daft.read_ipc(ipc_file).limit(15)
+    assert df.to_pydict()["id"] == list(range(15))
+
+
+def test_split_ipc_read(ipc_file):
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=10,
+    ):
+        df = daft.read_ipc(ipc_file)
+        assert df.num_partitions() == 10, "Should have 10 partitions since we will split the file"
+        assert df.to_pydict()["id"] == list(range(100))
+
+
+@pytest.mark.parametrize("compression", [None, "lz4", "zstd"])
+def test_write_ipc_roundtrip(tmpdir, compression):
+    path = str(tmpdir / "file.arrow")
+    data = {"id": [1, 2, 3], "data": ["a", None, "c"]}
+    table = MicroPartition.from_pydict(data)
+    write_ipc([table.to_table()._table], path, compression=compression)
+
+    assert pa.ipc.open_file(path).read_all().to_pydict() == data
+    assert read_ipc_schema(path).names() == ["id", "data"]
+    assert read_ipc(path, columns=["data"], num_rows=2).to_arrow_record_batch().to_pydict() == {"data": ["a", None]}
+
+
+@pytest.mark.parametrize("compression", [None, "lz4", "zstd"])
+def test_dataframe_write_ipc(tmp_path, compression):
+    data = {"id": list(range(10)), "group": ["a", "b"] * 5}
+    df = daft.from_pydict(data).into_partitions(2)
+
+    output_files = df.write_ipc(tmp_path, compression=compression)
+    assert len(output_files) == 2
+    assert all(path.endswith(".arrow") for path in output_files.to_pydict()["path"])
+    assert daft.read_ipc(f"{tmp_path}/*.arrow").sort("id").to_pydict() == data
+
+
+def test_dataframe_write_ipc_with_partitioning(tmp_path):
+    data = {"id": list(range(10)), "group": ["a", "b"] * 5}
+    output_files = daft.from_pydict(data).write_ipc(tmp_path, partition_cols=["group"])
+    assert sorted(output_files.to_pydict()["group"]) == ["a", "b"]
+    for path, group in zip(output_files.to_pydict()["path"], output_files.to_pydict()["group"]):
+        assert f"group={group}" in path
+        assert set(pa.ipc.open_file(path).read_all().to_pydict()["group"]) == {group}
+
+    df = daft.read_ipc(f"{tmp_path}/**/*.arrow")
+    assert df.sort("id").to_pydict() == data
+
+
+def test_dataframe_write_empty_ipc(tmp_path):
+    df = daft.from_pydict({"id": [1, 2]}).where(daft.col("id") > 2)
+    output_files = df.write_ipc(tmp_path)
+    assert len(output_files) == 1
+    assert pa.ipc.open_file(output_files.to_pydict()["path"][0]).read_all().num_rows == 0
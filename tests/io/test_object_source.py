@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from daft.io import ObjectInfo, ObjectSource, register_object_source
+
+
+class DictObjectSource(ObjectSource):
+    def __init__(self, scheme: str):
+        self.prefix = f"{scheme}://"
+        self.objects: dict[str, bytes] = {}
+
+    def get(self, path, range=None):
+        if path not in self.objects:
+            raise FileNotFoundError(path)
+        data = self.objects[path]
+        return data if range is None else data[range[0] : range[1]]
+
+    def get_size(self, path):
+        if path not in self.objects:
+            raise FileNotFoundError(path)
+        return len(self.objects[path])
+
+    def put(self, path, data):
+        self.objects[path] = data
+
+    def ls(self, path, posix=True):
+        prefix = path if path.endswith("/") else path + "/"
+        entries = {}
+        for key, data in self.objects.items():
+            if key == path:
+                return [ObjectInfo(path=key, size=len(data))]
+            if not key.startswith(prefix):
+                continue
+            child, sep, _ = key[len(prefix) :].partition("/")
+            if posix and sep:
+                entries[prefix + child + "/"] = ObjectInfo(path=prefix + child + "/", is_dir=True)
+            else:
+                entries[key] = ObjectInfo(path=key, size=len(data))
+        return list(entries.values())
+
+
+def test_read_csv_from_custom_object_source():
+    source = DictObjectSource("dictblob")
+    source.put("dictblob://bucket/data/a.csv", b"x,y\n1,a\n2,b\n")
+    source.put("dictblob://bucket/data/b.csv", b"x,y\n3,c\n")
+    register_object_source("dictblob", source)
+
+    df = daft.read_csv("dictblob://bucket/data/*.csv").sort("x")
+    assert df.to_pydict() == {"x": [1, 2, 3], "y": ["a", "b", "c"]}
+
+
+def test_read_from_missing_custom_object():
+    register_object_source("dictblob2", DictObjectSource("dictblob2"))
+    with pytest.raises(FileNotFoundError):
+        daft.read_csv("dictblob2://bucket/missing.csv").collect()
+
+
+def test_register_builtin_scheme_fails():
+    with pytest.raises(Exception, match="built-in scheme"):
+        register_object_source("s3", DictObjectSource("s3"))
@@ -0,0 +1,162 @@
+from __future__ import annotations
+
+import datetime
+import decimal
+
+import pyarrow as pa
+import pytest
+
+orc = pytest.importorskip("pyarrow.orc")
+
+import daft
+from daft.daft import read_orc, read_orc_schema
+
+
+@pytest.fixture(scope="function")
+def orc_file(tmpdir):
+    """Writes 1 ORC file with many small stripes of sorted ids"""
+    tbl = pa.table({"id": list(range(10_000)), "data": ["aaa"] * 10_000})
+    path = str(tmpdir / "file.orc")
+    orc.write_table(tbl, path, stripe_size=4096, batch_size=100)
+    assert orc.ORCFile(path).nstripes > 1
+    return path
+
+
+@pytest.mark.parametrize("compression", ["uncompressed", "zlib", "snappy", "lz4", "zstd"])
+def test_read_orc(tmpdir, compression):
+    tbl = pa.table(
+        {
+            "id": pa.array([1, 2, None], type=pa.int32()),
+            "name": ["a", None, "ccc"],
+            "price": pa.array([decimal.Decimal("1.25"), None, decimal.Decimal("-3.50")], type=pa.decimal128(10, 2)),
+            "day": [datetime.date(2024, 1, 1), datetime.date(1969, 12, 31), None],
+            "ts": pa.array(
+                [datetime.datetime(2024, 1, 1, 12, 30, 0, 123456), None, datetime.datetime(1960, 6, 1)],
+                type=pa.timestamp("us"),
+            ),
+            "tags": [["x", "y"], [], None],
+            "attrs": pa.array([[("k", 1)], None, []], type=pa.map_(pa.string(), pa.int64())),
+            "point": [{"x": 1.0, "y": 2.0}, None, {"x": 3.0, "y": None}],
+        }
+    )
+    path = str(tmpdir / "file.orc")
+    orc.write_table(tbl, path, compression=compression)
+
+    df = daft.read_orc(path)
+    assert df.schema()["price"].dtype == daft.DataType.decimal128(10, 2)
+    assert df.schema()["ts"].dtype == daft.DataType.timestamp(daft.TimeUnit.us())
+    assert df.to_arrow().to_pydict() == tbl.to_pydict()
+
+
+def make_encodings_table(num_rows: int) -> pa.Table:
+    """Makes a table whose columns are written with each of the run-length encodings of ORC integer streams"""
+
+    def with_nulls(values):
+        return [None if i % 37 == 5 else v for i, v in enumerate(values)]
+
+    # Deterministic pseudo-random values, which are written as direct runs.
+    random = [(i * 2654435761) % (1 << 48) - (1 << 47) for i in range(num_rows)]
+    return pa.table(
+        {
+            # Runs of repeated values, which are written as short repeat runs and byte runs.
+            "repeated": pa.array(with_nulls([i // 100 for i in range(num_rows)]), type=pa.int64()),
+            "byte": pa.array(with_nulls([(i // 7) % 256 - 128 for i in range(num_rows)]), type=pa.int8()),
+            "bool": with_nulls([i % 3 == 0 for i in range(num_rows)]),
+            # Monotonic values, which are written as delta runs.
+            "delta": pa.array(list(range(-3 * num_rows, 0, 3)), type=pa.int32()),
+            "direct": pa.array(with_nulls(random), type=pa.int64()),
+            # Small values with rare large outliers, which are written as patched base runs.
+            "patched": pa.array([i % 16 if i % 97 else (1 << 40) + i for i in range(num_rows)], type=pa.int64()),
+            "short": pa.array(with_nulls([r % (1 << 15) for r in random]), type=pa.int16()),
+            "float": pa.array(with_nulls([i * 0.5 for i in range(num_rows)]), type=pa.float32()),
+            "double": with_nulls([r / 7 for r in random]),
+            # Low-cardinality strings are dictionary encoded, unless dictionaries are disabled.
+            "category": with_nulls([f"category-{i % 10}" for i in range(num_rows)]),
+            "unique": with_nulls([f"value-{r}" for r in random]),
+            "binary": with_nulls([bytes([i % 256]) * (i % 5) for i in range(num_rows)]),
+            "price": pa.array(
+                with_nulls([decimal.Decimal(r) / 10_000 for r in random]),
+                type=pa.decimal128(20, 4),
+            ),
+            "day": with_nulls([datetime.date(1960, 1, 1) + datetime.timedelta(days=i * 7) for i in range(num_rows)]),
+            "ts": pa.array(
+                with_nulls(
+                    [
+                        datetime.datetime(1960, 1, 1) + datetime.timedelta(seconds=r % (1 << 32), microseconds=i * 10)
+                        for i, r in enumerate(random)
+                    ]
+                ),
+                type=pa.timestamp("us"),
+            ),
+            "tags": with_nulls([[j for j in range(i % 4)] for i in range(num_rows)]),
+            "attrs": pa.array(
+                with_nulls([[(f"k{j}", j) for j in range(i % 3)] for i in range(num_rows)]),
+                type=pa.map_(pa.string(), pa.int32()),
+            ),
+            "point": with_nulls(
+                [{"x": r / 3, "y": None if i % 11 == 0 else f"y{i % 13}"} for i, r in enumerate(random)]
+            ),
+        }
+    )
+
+
+@pytest.mark.parametrize("compression", ["uncompressed", "zlib", "snappy", "lz4", "zstd"])
+@pytest.mark.parametrize(
+    "file_version",
+    # Version 0.11 files use the version 1 run-length encoding of integers, and version 0.12 files use version 2.
+    ["0.11", "0.12"],
+)
+@pytest.mark.parametrize(
+    "dictionary_key_size_threshold",
+    # Strings are stored directly with a threshold of 0, and in a dictionary with a threshold of 1.
+    [0.0, 1.0],
+)
+def test_read_orc_encodings(tmpdir, compression, file_version, dictionary_key_size_threshold):
+    """Cross-checks the reader against files written by the reference ORC writer with every supported compression
+    codec and encoding, over multiple stripes and compression chunks."""
+    tbl = make_encodings_table(5_000)
+    path = str(tmpdir / "file.orc")
+    orc.write_table(
+        tbl,
+        path,
+        compression=compression,
+        file_version=file_version,
+        dictionary_key_size_threshold=dictionary_key_size_threshold,
+        compression_block_size=4096,
+        stripe_size=64 * 1024,
+    )
+    assert orc.ORCFile(path).nstripes > 1
+
+    df = daft.read_orc(path)
+    assert df.to_arrow().to_pydict() == tbl.to_pydict()
+
+
+def test_read_orc_pushdowns(orc_file):
+    df = daft.read_orc(orc_file).where(daft.col("id") >= 9_995).select("data")
+    assert df.to_pydict() == {"data": ["aaa"] * 5}
+
+    df = daft.read_orc(orc_file).limit(15)
+    assert df.to_pydict()["id"] == list(range(15))
+
+
+def test_split_orc_read(orc_file):
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=10,
+    ):
+        df = daft.read_orc(orc_file)
+        assert df.num_partitions() == orc.ORCFile(orc_file).nstripes, "Should have a partition per stripe"
+        assert df.to_pydict()["id"] == list(range(10_000))
+
+        # Stripes whose ids are all below the filter are pruned by their statistics.
+        df = daft.read_orc(orc_file).where(daft.col("id") >= 9_995)
+        assert df.num_partitions() == 1
+        assert df.to_pydict()["id"] == list(range(9_995, 10_000))
+
+
+def test_read_orc_native(orc_file):
+    assert read_orc_schema(orc_file).names() == ["id", "data"]
+    table = read_orc(orc_file, columns=["data"], num_rows=2)
+    assert table.to_arrow_record_batch().to_pydict() == {"data": ["aaa", "aaa"]}
+    table = read_orc(orc_file, stripes=[1], predicate=(daft.col("id") < 0)._expr)
+    assert len(table) == 0
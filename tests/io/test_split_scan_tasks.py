@@ -25,3 +25,42 @@ def test_split_parquet_read(parquet_files):
         df = daft.read_parquet(str(parquet_files))
         assert df.num_partitions() == 10, "Should have 10 partitions since we will split the file"
         assert df.to_pydict() == {"data": ["aaa"] * 100}
+
+
+@pytest.fixture(scope="function")
+def csv_with_quoted_record_lines(tmpdir):
+    """Writes 1 CSV file whose quoted fields hold lines that look like records of the file"""
+    rows = ["id,note"]
+    for i in range(100):
+        note = "\n".join(f"{i}{j},{j}" for j in range(10)) if i % 3 == 0 else "plain"
+        rows.append(f'{i},"{note}"')
+    path = tmpdir / "file.csv"
+    path.write_text("\n".join(rows) + "\n", encoding="utf-8")
+
+    return str(path)
+
+
+def test_split_csv_read(tmpdir):
+    path = tmpdir / "file.csv"
+    path.write_text("id,data\n" + "".join(f"{i},aaa\n" for i in range(100)), encoding="utf-8")
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=100,
+    ):
+        df = daft.read_csv(str(path))
+        assert df.num_partitions() > 1, "Should have multiple partitions since we will split the file"
+        assert df.sort("id").to_pydict() == {"id": list(range(100)), "data": ["aaa"] * 100}
+
+
+def test_split_csv_read_quoted_record_lines(csv_with_quoted_record_lines):
+    unsplit = daft.read_csv(csv_with_quoted_record_lines).to_pydict()
+    assert len(unsplit["id"]) == 100
+
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=100,
+    ):
+        df = daft.read_csv(csv_with_quoted_record_lines)
+        assert df.num_partitions() == 1, "Should not split a CSV file with records spanning multiple lines"
+        assert df.count_rows() == len(unsplit["id"])
+        assert df.sort("id").to_pydict() == unsplit
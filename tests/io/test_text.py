@@ -0,0 +1,125 @@
+from __future__ import annotations
+
+import gzip
+
+import pytest
+
+import daft
+
+
+@pytest.fixture(scope="function")
+def text_file(tmpdir):
+    """Writes 1 text file with 100 lines, where every 10th line is blank"""
+    path = str(tmpdir / "file.txt")
+    with open(path, "w") as f:
+        f.write("".join("\n" if i % 10 == 0 else f"line {i}\n" for i in range(100)))
+    return path
+
+
+def test_read_text(text_file):
+    df = daft.read_text(text_file)
+    assert df.schema().column_names() == ["text"]
+    lines = df.to_pydict()["text"]
+    assert len(lines) == 100
+    assert lines[:2] == ["", "line 1"]
+
+
+def test_read_text_options(text_file):
+    df = daft.read_text(text_file, skip_blank_lines=True, path_column="path")
+    data = df.to_pydict()
+    assert len(data["text"]) == 90
+    assert data["path"][0].endswith("file.txt")
+
+
+def test_read_text_compressed(tmpdir):
+    path = str(tmpdir / "file.txt.gz")
+    with gzip.open(path, "wt") as f:
+        f.write("a\r\nb\nc")
+    assert daft.read_text(path).to_pydict() == {"text": ["a", "b", "c"]}
+
+
+def test_read_text_pushdowns(text_file):
+    df = daft.read_text(text_file).where(daft.col("text") == "line 42")
+    assert df.to_pydict() == {"text": ["line 42"]}
+
+    df = daft.read_text(text_file).limit(15)
+    assert len(df.to_pydict()["text"]) == 15
+
+
+def test_split_text_read(text_file):
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=100,
+    ):
+        df = daft.read_text(text_file)
+        assert df.num_partitions() > 1, "Should have more than 1 partition since we will split the file"
+        assert df.to_pydict()["text"] == daft.read_text(text_file).to_pydict()["text"]
+
+
+def test_read_files(tmpdir):
+    for name, content in [("a.bin", b"hello"), ("b.bin", b"hi")]:
+        with open(str(tmpdir / name), "wb") as f:
+            f.write(content)
+
+    df = daft.read_files(str(tmpdir / "*.bin")).sort("path")
+    assert df.schema().column_names() == ["path", "size", "content"]
+    data = df.to_pydict()
+    assert data["size"] == [5, 2]
+    assert data["content"] == [b"hello", b"hi"]
+
+    df = daft.read_files(str(tmpdir / "*.bin")).where(daft.col("size") < 3).select("path")
+    assert [path.endswith("b.bin") for path in df.to_pydict()["path"]] == [True]
+
+
+@pytest.fixture(scope="function")
+def fixed_width_file(tmpdir):
+    """Writes 1 fixed-width text file with a header line and 100 rows"""
+    path = str(tmpdir / "file.txt")
+    with open(path, "w") as f:
+        f.write("ID   NAME    AMOUNT\n")
+        f.write("".join(f"{i:>5}{'name' + str(i):<8}{i * 1.5:>6.1f}\n" for i in range(100)))
+    return path
+
+
+FIXED_WIDTH_COLUMNS = [
+    ("id", 0, 5, daft.DataType.int64()),
+    ("name", 5, 8, daft.DataType.string()),
+    ("amount", 13, 6, daft.DataType.float64()),
+]
+
+
+def test_read_fixed_width(fixed_width_file):
+    df = daft.read_fixed_width(fixed_width_file, columns=FIXED_WIDTH_COLUMNS, skip_rows=1)
+    assert df.schema().column_names() == ["id", "name", "amount"]
+    assert df.schema()["id"].dtype == daft.DataType.int64()
+    data = df.to_pydict()
+    assert data["id"] == list(range(100))
+    assert data["name"][:2] == ["name0", "name1"]
+    assert data["amount"][:2] == [0.0, 1.5]
+
+
+def test_read_fixed_width_short_lines(tmpdir):
+    path = str(tmpdir / "file.txt")
+    with open(path, "w") as f:
+        f.write("    1abc\n\n    2\n")
+    df = daft.read_fixed_width(path, columns=FIXED_WIDTH_COLUMNS)
+    assert df.to_pydict() == {"id": [1, 2], "name": ["abc", None], "amount": [None, None]}
+
+
+def test_read_fixed_width_pushdowns(fixed_width_file):
+    df = daft.read_fixed_width(fixed_width_file, columns=FIXED_WIDTH_COLUMNS, skip_rows=1)
+    df = df.where(daft.col("amount") > 145.0).select("name")
+    assert df.to_pydict() == {"name": ["name97", "name98", "name99"]}
+
+    df = daft.read_fixed_width(fixed_width_file, columns=FIXED_WIDTH_COLUMNS, skip_rows=1).limit(15)
+    assert df.to_pydict()["id"] == list(range(15))
+
+
+def test_split_fixed_width_read(fixed_width_file):
+    with daft.execution_config_ctx(
+        scan_tasks_min_size_bytes=1,
+        scan_tasks_max_size_bytes=100,
+    ):
+        df = daft.read_fixed_width(fixed_width_file, columns=FIXED_WIDTH_COLUMNS, skip_rows=1)
+        assert df.num_partitions() > 1, "Should have more than 1 partition since we will split the file"
+        assert df.to_pydict()["id"] == list(range(100))
@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+import json
+
+import pyarrow as pa
+import pyarrow.parquet as papq
+
+import daft
+from daft import DataType, col
+
+
+def _write_csv(path, header: list[str], rows: list[list]) -> str:
+    with open(path, "w") as f:
+        f.write(",".join(header) + "\n")
+        for row in rows:
+            f.write(",".join("" if v is None else str(v) for v in row) + "\n")
+    return str(path)
+
+
+def _write_json(path, rows: list[dict]) -> str:
+    with open(path, "w") as f:
+        for row in rows:
+            f.write(json.dumps(row) + "\n")
+    return str(path)
+
+
+def test_read_parquet_union_by_name_fills_missing_columns_with_nulls(tmp_path):
+    papq.write_table(pa.table({"id": [1, 2], "a": ["x", "y"]}), str(tmp_path / "0.parquet"))
+    papq.write_table(pa.table({"b": [True], "id": [3]}), str(tmp_path / "1.parquet"))
+
+    df = daft.read_parquet(f"{tmp_path}/*.parquet", union_by_name=True)
+    assert sorted(df.schema().column_names()) == ["a", "b", "id"]
+    assert df.select("id", "a", "b").sort("id").to_pydict() == {
+        "id": [1, 2, 3],
+        "a": ["x", "y", None],
+        "b": [None, None, True],
+    }
+
+
+def test_read_parquet_union_by_name_promotes_types(tmp_path):
+    papq.write_table(pa.table({"x": pa.array([1, 2], type=pa.int32())}), str(tmp_path / "0.parquet"))
+    papq.write_table(pa.table({"x": pa.array([3, 4], type=pa.int64())}), str(tmp_path / "1.parquet"))
+    papq.write_table(pa.table({"x": pa.array([2.5], type=pa.float64())}), str(tmp_path / "2.parquet"))
+
+    df = daft.read_parquet(f"{tmp_path}/*.parquet", union_by_name=True)
+    assert df.schema()["x"].dtype == DataType.float64()
+    assert sorted(df.to_pydict()["x"]) == [1.0, 2.0, 2.5, 3.0, 4.0]
+
+
+def test_read_parquet_union_by_name_filters_promoted_column(tmp_path):
+    papq.write_table(pa.table({"x": pa.array([1, 2, 3], type=pa.int32())}), str(tmp_path / "0.parquet"))
+    papq.write_table(pa.table({"x": pa.array([4, 5], type=pa.int64()), "y": ["d", "e"]}), str(tmp_path / "1.parquet"))
+
+    df = daft.read_parquet(f"{tmp_path}/*.parquet", union_by_name=True)
+    assert df.schema()["x"].dtype == DataType.int64()
+
+    # The filter is pushed into the reads of both files, including the one whose column has the narrower type.
+    assert df.where(col("x") >= 2).sort("x").to_pydict() == {"x": [2, 3, 4, 5], "y": [None, None, "d", "e"]}
+    assert df.where(col("y").is_null()).sort("x").to_pydict() == {"x": [1, 2, 3], "y": [None, None, None]}
+
+
+def test_read_csv_union_by_name(tmp_path):
+    _write_csv(tmp_path / "0.csv", ["id", "value"], [[1, 1], [2, 2]])
+    _write_csv(tmp_path / "1.csv", ["value", "id", "name"], [[2.5, 3, "c"]])
+
+    df = daft.read_csv(f"{tmp_path}/*.csv", union_by_name=True)
+    assert sorted(df.schema().column_names()) == ["id", "name", "value"]
+    assert df.schema()["value"].dtype == DataType.float64()
+    assert df.where(col("value") > 1.5).select("id", "value", "name").sort("id").to_pydict() == {
+        "id": [2, 3],
+        "value": [2.0, 2.5],
+        "name": [None, "c"],
+    }
+
+
+def test_read_json_union_by_name(tmp_path):
+    _write_json(tmp_path / "0.jsonl", [{"id": 1, "value": 1}, {"id": 2, "value": 2}])
+    _write_json(tmp_path / "1.jsonl", [{"id": 3, "value": 2.5, "name": "c"}])
+
+    df = daft.read_json(f"{tmp_path}/*.jsonl", union_by_name=True)
+    assert df.schema()["value"].dtype == DataType.float64()
+    assert df.where(col("value") > 1.5).select("id", "value", "name").sort("id").to_pydict() == {
+        "id": [2, 3],
+        "value": [2.0, 2.5],
+        "name": [None, "c"],
+    }
+
+
+def test_read_csv_union_by_name_max_schema_inference_files(tmp_path):
+    _write_csv(tmp_path / "0.csv", ["id"], [[1]])
+    _write_csv(tmp_path / "1.csv", ["id", "name"], [[2, "b"]])
+
+    # Only one of the files is sampled, so the schema is that file's schema.
+    df = daft.read_csv(f"{tmp_path}/*.csv", union_by_name=True, max_schema_inference_files=1)
+    assert df.schema().column_names() in (["id"], ["id", "name"])
+    assert sorted(df.to_pydict()["id"]) == [1, 2]
+
+    df = daft.read_csv(f"{tmp_path}/*.csv", union_by_name=True, max_schema_inference_files=None)
+    assert sorted(df.schema().column_names()) == ["id", "name"]
@@ -0,0 +1,125 @@
+from __future__ import annotations
+
+import datetime
+import zipfile
+
+import pytest
+
+import daft
+from daft.daft import read_xlsx, read_xlsx_schema
+
+NS = 'xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"'
+REL_NS = "http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+
+
+def _cell(ref, value):
+    if value is None:
+        return ""
+    if isinstance(value, bool):
+        return f'<c r="{ref}" t="b"><v>{int(value)}</v></c>'
+    if isinstance(value, (int, float)):
+        return f'<c r="{ref}"><v>{value}</v></c>'
+    if isinstance(value, datetime.date):
+        # Dates are stored as days since 1899-12-30, with cell style 1 formatting them as dates.
+        days = (value - datetime.date(1899, 12, 30)).days
+        return f'<c r="{ref}" s="1"><v>{days}</v></c>'
+    return f'<c r="{ref}" t="inlineStr"><is><t>{value}</t></is></c>'
+
+
+def write_xlsx(path, sheets):
+    """Writes an XLSX file with a sheet per (name, rows) pair, where rows are lists of cell values"""
+    with zipfile.ZipFile(path, "w") as zf:
+        zf.writestr(
+            "xl/workbook.xml",
+            f'<workbook {NS} xmlns:r="{REL_NS}"><sheets>'
+            + "".join(
+                f'<sheet name="{name}" sheetId="{i + 1}" r:id="rId{i + 1}"/>' for i, (name, _) in enumerate(sheets)
+            )
+            + "</sheets></workbook>",
+        )
+        zf.writestr(
+            "xl/_rels/workbook.xml.rels",
+            '<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">'
+            + "".join(
+                f'<Relationship Id="rId{i + 1}" Type="{REL_NS}/worksheet" Target="worksheets/sheet{i + 1}.xml"/>'
+                for i in range(len(sheets))
+            )
+            + "</Relationships>",
+        )
+        zf.writestr(
+            "xl/styles.xml",
+            f'<styleSheet {NS}><cellXfs count="2"><xf numFmtId="0"/><xf numFmtId="14"/></cellXfs></styleSheet>',
+        )
+        for i, (_, rows) in enumerate(sheets):
+            sheet_data = "".join(
+                f'<row r="{r + 1}">'
+                + "".join(_cell(f"{chr(ord('A') + c)}{r + 1}", value) for c, value in enumerate(row))
+                + "</row>"
+                for r, row in enumerate(rows)
+            )
+            zf.writestr(
+                f"xl/worksheets/sheet{i + 1}.xml",
+                f"<worksheet {NS}><sheetData>{sheet_data}</sheetData></worksheet>",
+            )
+
+
+@pytest.fixture(scope="function")
+def xlsx_file(tmpdir):
+    """Writes 1 XLSX file with an empty first sheet and a second sheet with a header row and 100 rows"""
+    path = str(tmpdir / "file.xlsx")
+    rows = [["id", "name", "day", "active"]] + [
+        [i, f"name{i}", datetime.date(2024, 1, 1) + datetime.timedelta(days=i), i % 2 == 0] for i in range(100)
+    ]
+    write_xlsx(path, [("Notes", []), ("Data", rows)])
+    return path
+
+
+def test_read_xlsx(xlsx_file):
+    df = daft.read_xlsx(xlsx_file, sheet_name="Data")
+    schema = df.schema()
+    assert schema.column_names() == ["id", "name", "day", "active"]
+    assert schema["id"].dtype == daft.DataType.int64()
+    assert schema["day"].dtype == daft.DataType.date()
+    assert schema["active"].dtype == daft.DataType.bool()
+    data = df.to_pydict()
+    assert data["id"] == list(range(100))
+    assert data["day"][:2] == [datetime.date(2024, 1, 1), datetime.date(2024, 1, 2)]
+    assert data["active"][:2] == [True, False]
+
+    assert daft.read_xlsx(xlsx_file, sheet_index=1).to_pydict() == data
+
+
+def test_read_xlsx_header_row(tmpdir):
+    path = str(tmpdir / "file.xlsx")
+    write_xlsx(path, [("Sheet1", [["Report"], ["a", "b"], [1, 2.5], [None, "x"]])])
+
+    df = daft.read_xlsx(path, header_row=1)
+    assert df.to_pydict() == {"a": [1, None], "b": ["2.5", "x"]}
+
+    df = daft.read_xlsx(path, header_row=None)
+    assert df.schema().column_names() == ["column_1", "column_2"]
+    assert len(df.to_pydict()["column_1"]) == 4
+
+
+def test_read_xlsx_pushdowns(xlsx_file):
+    df = daft.read_xlsx(xlsx_file, sheet_name="Data").where(daft.col("id") >= 97).select("name")
+    assert df.to_pydict() == {"name": ["name97", "name98", "name99"]}
+
+    df = daft.read_xlsx(xlsx_file, sheet_name="Data").limit(15)
+    assert df.to_pydict()["id"] == list(range(15))
+
+
+def test_read_xlsx_union_by_name(tmpdir):
+    paths = [str(tmpdir / "a.xlsx"), str(tmpdir / "b.xlsx")]
+    write_xlsx(paths[0], [("Sheet1", [["id", "a"], [1, "x"]])])
+    write_xlsx(paths[1], [("Sheet1", [["b", "id"], [2.5, 2]])])
+    df = daft.read_xlsx(paths, union_by_name=True).sort("id")
+    assert df.to_pydict() == {"id": [1, 2], "a": ["x", None], "b": [None, 2.5]}
+
+
+def test_read_xlsx_native(xlsx_file):
+    assert read_xlsx_schema(xlsx_file, sheet_name="Data").names() == ["id", "name", "day", "active"]
+    table = read_xlsx(xlsx_file, columns=["name"], num_rows=2, sheet_index=1)
+    assert table.to_arrow_record_batch().to_pydict() == {"name": ["name0", "name1"]}
+    with pytest.raises(Exception, match="Missing"):
+        read_xlsx_schema(xlsx_file, sheet_name="Missing")